@@ -0,0 +1,321 @@
+use std::{mem, rc::Rc};
+
+use dces::prelude::*;
+
+use crate::{
+    shell::now,
+    tree::Tree,
+    utils::{Easing, Interpolate},
+    widget_base::{mark_as_dirty, StatesContext},
+};
+
+type Ecm = EntityComponentManager<Tree, StringComponentStore>;
+
+/// The duration of an animation in milliseconds if no other duration is set.
+pub const DEFAULT_ANIMATION_DURATION: f64 = 250.0;
+
+/// Is called with the animated widget after an animation is finished.
+pub type AnimationHandlerFn = dyn Fn(&mut StatesContext, Entity) + 'static;
+
+/// Identifies a started animation, e.g. to cancel it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnimationId(u32);
+
+/// Describes the animation of a property of a widget from a start to an end value over a duration. Numbers,
+/// `Brush`, `Thickness` and all other properties that implement `Interpolate` could be animated. The animation is
+/// started with `WidgetContainer::animate` and is advanced on each frame until it is finished.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let id = ctx.widget().animate(
+///     Animation::new("opacity", 1.0)
+///         .from(0.0)
+///         .duration(300.0)
+///         .easing(Easing::EaseInOut)
+///         .on_finished(|states, entity| states.get_mut::<MainState>(entity).shown()),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Animation<T> {
+    key: String,
+    from: Option<T>,
+    to: T,
+    duration: f64,
+    delay: f64,
+    easing: Easing,
+    on_finished: Option<Rc<AnimationHandlerFn>>,
+}
+
+impl<T> Animation<T>
+where
+    T: Component + Clone + Interpolate,
+{
+    /// Creates an animation of the property with the given key to the given value. It runs from the current value of
+    /// the property if no start value is set.
+    pub fn new(key: impl Into<String>, to: T) -> Self {
+        Animation {
+            key: key.into(),
+            from: None,
+            to,
+            duration: DEFAULT_ANIMATION_DURATION,
+            delay: 0.0,
+            easing: Easing::default(),
+            on_finished: None,
+        }
+    }
+
+    /// Sets the start value.
+    pub fn from(mut self, from: T) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the duration in milliseconds.
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the delay in milliseconds before the animation starts.
+    pub fn delay(mut self, delay: f64) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the easing curve, e.g. `Easing::EaseInOut` or `"spring"`.
+    pub fn easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Sets the handler that is called after the animation is finished. It is not called if the animation is
+    /// canceled or replaced by another animation of the property.
+    pub fn on_finished<H: Fn(&mut StatesContext, Entity) + 'static>(mut self, handler: H) -> Self {
+        self.on_finished = Some(Rc::new(handler));
+        self
+    }
+
+    /// Gets the key of the animated property.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Gets the start value, `None` if the animation runs from the current value.
+    pub fn start_value(&self) -> Option<&T> {
+        self.from.as_ref()
+    }
+
+    /// Gets the end value.
+    pub fn end_value(&self) -> &T {
+        &self.to
+    }
+}
+
+// An animation of a property of a widget that is currently running.
+#[derive(Clone)]
+struct RunningAnimation {
+    id: AnimationId,
+    entity: Entity,
+    key: String,
+    start: f64,
+    duration: f64,
+    delay: f64,
+    easing: Easing,
+    on_finished: Option<Rc<AnimationHandlerFn>>,
+
+    // sets the value at the given eased progress
+    apply: Rc<dyn Fn(&mut Ecm, f64)>,
+}
+
+/// The result of advancing the animations of a window by one frame.
+#[derive(Clone, Default)]
+pub struct AnimationFrame {
+    /// Are animations still running after this frame?
+    pub running: bool,
+
+    /// The handlers of the animations that are finished on this frame with their animated widgets.
+    pub finished: Vec<(Entity, Rc<AnimationHandlerFn>)>,
+}
+
+/// `Animations` holds the running property animations of a window. It's stored as `animations` component of the root
+/// widget, the animations are advanced on each frame until they are finished or canceled.
+#[derive(Clone, Default)]
+pub struct Animations {
+    running: Vec<RunningAnimation>,
+    next_id: u32,
+}
+
+impl Animations {
+    /// Checks if no animation is running.
+    pub fn is_empty(&self) -> bool {
+        self.running.is_empty()
+    }
+
+    /// Checks if the animation with the given id is still running.
+    pub fn is_running(&self, id: AnimationId) -> bool {
+        self.running.iter().any(|r| r.id == id)
+    }
+
+    /// Starts the animation of the property of the given widget from the given value. A running animation of the
+    /// property is replaced.
+    pub fn start<T>(&mut self, entity: Entity, animation: Animation<T>, from: T) -> AnimationId
+    where
+        T: Component + Clone + Interpolate,
+    {
+        self.stop(entity, &animation.key);
+
+        let id = AnimationId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+
+        let property = animation.key.clone();
+        let to = animation.to;
+
+        self.running.push(RunningAnimation {
+            id,
+            entity,
+            key: animation.key,
+            start: now(),
+            duration: animation.duration,
+            delay: animation.delay,
+            easing: animation.easing,
+            on_finished: animation.on_finished,
+            apply: Rc::new(move |ecm: &mut Ecm, progress: f64| {
+                if let Ok(value) = ecm.component_store_mut().get_mut::<T>(&property, entity) {
+                    *value = if progress >= 1.0 {
+                        to.clone()
+                    } else {
+                        from.interpolate(&to, progress)
+                    };
+                }
+            }),
+        });
+
+        id
+    }
+
+    /// Cancels the animation with the given id. The property keeps its current value. Returns `false` if the
+    /// animation is already finished.
+    pub fn cancel(&mut self, id: AnimationId) -> bool {
+        let len = self.running.len();
+        self.running.retain(|r| r.id != id);
+        len != self.running.len()
+    }
+
+    /// Cancels the animation of the given property of the widget.
+    pub fn stop(&mut self, entity: Entity, key: &str) {
+        self.running.retain(|r| r.entity != entity || r.key != key);
+    }
+
+    /// Removes all animations of the given widget.
+    pub fn remove_widget(&mut self, entity: Entity) {
+        self.running.retain(|r| r.entity != entity);
+    }
+
+    /// Advances all running animations of the window and marks the changed widgets as dirty. Returns the handlers of
+    /// the finished animations, they are called by the caller with the states of the window.
+    pub fn run(ecm: &mut Ecm) -> AnimationFrame {
+        let root = ecm.entity_store().root();
+
+        let running = match ecm
+            .component_store_mut()
+            .get_mut::<Animations>("animations", root)
+        {
+            Ok(animations) if !animations.is_empty() => mem::take(&mut animations.running),
+            _ => return AnimationFrame::default(),
+        };
+
+        let time = now();
+        let mut frame = AnimationFrame::default();
+        let mut unfinished = vec![];
+
+        for running_animation in running {
+            let progress = match progress(
+                time - running_animation.start,
+                running_animation.delay,
+                running_animation.duration,
+            ) {
+                Some(progress) => progress,
+                None => {
+                    unfinished.push(running_animation);
+                    continue;
+                }
+            };
+
+            let eased = if progress < 1.0 {
+                running_animation.easing.ease(progress)
+            } else {
+                1.0
+            };
+
+            (running_animation.apply)(ecm, eased);
+            mark_as_dirty(&running_animation.key, running_animation.entity, ecm);
+
+            if progress < 1.0 {
+                unfinished.push(running_animation);
+            } else if let Some(on_finished) = running_animation.on_finished {
+                frame.finished.push((running_animation.entity, on_finished));
+            }
+        }
+
+        if let Ok(animations) = ecm
+            .component_store_mut()
+            .get_mut::<Animations>("animations", root)
+        {
+            animations.running = unfinished;
+            frame.running = !animations.is_empty();
+        }
+
+        frame
+    }
+}
+
+// -- Helpers --
+
+// Calculates the linear progress (0.0 - 1.0) after the given elapsed time, `None` if the delay is not elapsed.
+fn progress(elapsed: f64, delay: f64, duration: f64) -> Option<f64> {
+    let elapsed = elapsed - delay;
+
+    if elapsed < 0.0 {
+        return None;
+    }
+
+    if duration > 0.0 {
+        Some((elapsed / duration).min(1.0))
+    } else {
+        Some(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(progress(50.0, 100.0, 200.0), None);
+        assert_eq!(progress(200.0, 100.0, 200.0), Some(0.5));
+        assert_eq!(progress(400.0, 100.0, 200.0), Some(1.0));
+        assert_eq!(progress(0.0, 0.0, 0.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_start_and_cancel() {
+        let mut animations = Animations::default();
+
+        let first = animations.start(Entity(1), Animation::new("opacity", 1.0), 0.0);
+        let second = animations.start(Entity(2), Animation::new("opacity", 1.0), 0.0);
+        assert_ne!(first, second);
+
+        // the running animation of the property is replaced
+        let third = animations.start(Entity(1), Animation::new("opacity", 0.5), 1.0);
+        assert!(!animations.is_running(first));
+        assert!(animations.is_running(third));
+
+        assert!(animations.cancel(second));
+        assert!(!animations.cancel(second));
+
+        animations.remove_widget(Entity(1));
+        assert!(animations.is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+//! This module contains the animation of widget properties.
+//!
+pub use self::animations::*;
+pub use self::transitions::*;
+
+mod animations;
+mod transitions;
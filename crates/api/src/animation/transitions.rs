@@ -0,0 +1,155 @@
+use std::{collections::HashSet, mem, rc::Rc};
+
+use dces::prelude::*;
+
+use crate::{
+    shell::now,
+    tree::Tree,
+    utils::{Interpolate, Transition},
+    widget_base::mark_as_dirty,
+};
+
+type Ecm = EntityComponentManager<Tree, StringComponentStore>;
+
+// A transition of a property of a widget that is currently running.
+#[derive(Clone)]
+struct RunningTransition {
+    entity: Entity,
+    key: String,
+    start: f64,
+    transition: Transition,
+
+    // sets the value at the given eased progress
+    apply: Rc<dyn Fn(&mut Ecm, f64)>,
+}
+
+/// `Transitions` holds the running property transitions of a window. It's stored as `transitions` component of the
+/// root widget. The transitions are started if the theme changes a property that is listed in the `transition`
+/// property of the style, e.g. `"transition": "background 150ms ease, padding 0.2s linear"`, and are advanced on each
+/// frame until they are finished.
+#[derive(Clone, Default)]
+pub struct Transitions {
+    running: Vec<RunningTransition>,
+
+    // the widgets that are styled once, the first style of a widget is applied without transition
+    styled: HashSet<Entity>,
+}
+
+impl Transitions {
+    /// Checks if no transition is running.
+    pub fn is_empty(&self) -> bool {
+        self.running.is_empty()
+    }
+
+    /// Checks if a transition of the given property of the widget is running.
+    pub fn is_running(&self, entity: Entity, key: &str) -> bool {
+        self.running
+            .iter()
+            .any(|r| r.entity == entity && r.key == key)
+    }
+
+    /// Checks if a style with transitions was applied to the given widget before.
+    pub fn is_styled(&self, entity: Entity) -> bool {
+        self.styled.contains(&entity)
+    }
+
+    /// Marks the widget as styled, following style changes of the widget are transitioned.
+    pub fn set_styled(&mut self, entity: Entity) {
+        self.styled.insert(entity);
+    }
+
+    /// Starts the transition of the given property of the widget from the given to the given value. A running
+    /// transition of the property is replaced.
+    pub fn start<T>(&mut self, entity: Entity, key: &str, from: T, to: T, transition: Transition)
+    where
+        T: Component + Clone + Interpolate,
+    {
+        self.cancel(entity, key);
+
+        let property = key.to_string();
+
+        self.running.push(RunningTransition {
+            entity,
+            key: key.to_string(),
+            start: now(),
+            transition,
+            apply: Rc::new(move |ecm: &mut Ecm, progress: f64| {
+                if let Ok(value) = ecm.component_store_mut().get_mut::<T>(&property, entity) {
+                    *value = if progress >= 1.0 {
+                        to.clone()
+                    } else {
+                        from.interpolate(&to, progress)
+                    };
+                }
+            }),
+        });
+    }
+
+    /// Stops the transition of the given property of the widget. The property keeps its current value.
+    pub fn cancel(&mut self, entity: Entity, key: &str) {
+        self.running.retain(|r| r.entity != entity || r.key != key);
+    }
+
+    /// Removes all transitions of the given widget.
+    pub fn remove_widget(&mut self, entity: Entity) {
+        self.running.retain(|r| r.entity != entity);
+        self.styled.remove(&entity);
+    }
+
+    /// Advances all running transitions of the window and marks the changed widgets as dirty. Returns `true` if
+    /// transitions are still running after this frame.
+    pub fn run(ecm: &mut Ecm) -> bool {
+        let root = ecm.entity_store().root();
+
+        let running = match ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("transitions", root)
+        {
+            Ok(transitions) if !transitions.is_empty() => mem::take(&mut transitions.running),
+            _ => return false,
+        };
+
+        let time = now();
+        let mut unfinished = vec![];
+
+        for running_transition in running {
+            let transition = &running_transition.transition;
+            let elapsed = time - running_transition.start - transition.delay;
+
+            if elapsed < 0.0 {
+                unfinished.push(running_transition);
+                continue;
+            }
+
+            let progress = if transition.duration > 0.0 {
+                (elapsed / transition.duration).min(1.0)
+            } else {
+                1.0
+            };
+
+            let eased = if progress < 1.0 {
+                transition.easing.ease(progress)
+            } else {
+                1.0
+            };
+
+            (running_transition.apply)(ecm, eased);
+            mark_as_dirty(&running_transition.key, running_transition.entity, ecm);
+
+            if progress < 1.0 {
+                unfinished.push(running_transition);
+            }
+        }
+
+        if let Ok(transitions) = ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("transitions", root)
+        {
+            transitions.running = unfinished;
+
+            return !transitions.is_empty();
+        }
+
+        false
+    }
+}
@@ -1,7 +1,7 @@
 use std::{
     cell::{Cell, RefCell},
     collections::BTreeMap,
-    rc::Rc,
+    rc::{Rc, Weak},
     sync::mpsc,
 };
 
@@ -13,11 +13,33 @@ use crate::{
     event::*,
     layout::*,
     render_object::*,
-    shell::{ShellRequest, WindowRequest},
-    utils::Point,
+    services::{Fonts, Windows},
+    shell::{GestureRecognizer, Monitor, ShellRequest, ToolTipService, WindowMode, WindowRequest},
+    utils::{Point, Rectangle},
     widget_base::*,
 };
 
+/// A window that is opened by another window with `Context::show_owned_window` or `Context::show_modal_window`. It
+/// is closed together with its owner.
+#[derive(Clone)]
+pub struct OwnedWindow {
+    /// The event queue of the owned window, it's dropped if the window is closed.
+    pub event_queue: Weak<RefCell<EventQueue>>,
+
+    /// Sends requests to the owned window.
+    pub window_sender: mpsc::Sender<WindowRequest>,
+
+    /// Blocks the input of the owner while the window is open.
+    pub modal: bool,
+}
+
+impl OwnedWindow {
+    /// Checks if the owned window is still open.
+    pub fn is_open(&self) -> bool {
+        self.event_queue.upgrade().is_some()
+    }
+}
+
 /// Temporary solution to share dependencies. Will be refactored soon.
 #[derive(Clone)]
 pub struct ContextProvider {
@@ -31,6 +53,20 @@ pub struct ContextProvider {
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
     pub first_run: Rc<Cell<bool>>,
+    pub update_requests: Rc<RefCell<Vec<Entity>>>,
+    pub scheduled_updates: Rc<RefCell<Vec<(f64, Entity)>>>,
+    pub tool_tip: Rc<RefCell<ToolTipService>>,
+    pub gestures: Rc<RefCell<GestureRecognizer>>,
+    pub hovered_widgets: Rc<RefCell<Vec<Entity>>>,
+    pub cursor: Rc<Cell<CursorIcon>>,
+    pub screen_bounds: Rc<RefCell<BTreeMap<Entity, Rectangle>>>,
+    pub dirty_region: Rc<Cell<Option<Rectangle>>>,
+    pub fonts: Fonts,
+    pub windows: Windows,
+    pub window_position: Rc<Cell<Point>>,
+    pub window_mode: Rc<Cell<WindowMode>>,
+    pub monitors: Rc<RefCell<Vec<Monitor>>>,
+    pub owned_windows: Rc<RefCell<Vec<OwnedWindow>>>,
 }
 
 impl ContextProvider {
@@ -39,6 +75,8 @@ impl ContextProvider {
         window_sender: mpsc::Sender<WindowRequest>,
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
+        fonts: Fonts,
+        windows: Windows,
     ) -> Self {
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
@@ -51,6 +89,70 @@ impl ContextProvider {
             shell_sender,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
+            update_requests: Rc::new(RefCell::new(vec![])),
+            scheduled_updates: Rc::new(RefCell::new(vec![])),
+            tool_tip: Rc::new(RefCell::new(ToolTipService::default())),
+            gestures: Rc::new(RefCell::new(GestureRecognizer::default())),
+            hovered_widgets: Rc::new(RefCell::new(vec![])),
+            cursor: Rc::new(Cell::new(CursorIcon::Default)),
+            screen_bounds: Rc::new(RefCell::new(BTreeMap::new())),
+            dirty_region: Rc::new(Cell::new(None)),
+            fonts,
+            windows,
+            window_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            window_mode: Rc::new(Cell::new(WindowMode::Normal)),
+            monitors: Rc::new(RefCell::new(vec![])),
+            owned_windows: Rc::new(RefCell::new(vec![])),
         }
     }
+
+    /// Checks if a modal window that is owned by the window is open. The input of the window is blocked until all its
+    /// modal windows are closed.
+    pub fn has_modal_window(&self) -> bool {
+        let mut owned_windows = self.owned_windows.borrow_mut();
+        owned_windows.retain(|owned_window| owned_window.is_open());
+        owned_windows.iter().any(|owned_window| owned_window.modal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_modal_window() {
+        let (window_sender, _) = mpsc::channel();
+        let (shell_sender, _) = mpsc::channel();
+        let provider = ContextProvider::new(
+            window_sender.clone(),
+            shell_sender,
+            "",
+            Fonts::default(),
+            Windows::new(),
+        );
+
+        assert!(!provider.has_modal_window());
+
+        let tool_window = Rc::new(RefCell::new(EventQueue::new()));
+        let dialog = Rc::new(RefCell::new(EventQueue::new()));
+
+        provider.owned_windows.borrow_mut().push(OwnedWindow {
+            event_queue: Rc::downgrade(&tool_window),
+            window_sender: window_sender.clone(),
+            modal: false,
+        });
+        assert!(!provider.has_modal_window());
+
+        provider.owned_windows.borrow_mut().push(OwnedWindow {
+            event_queue: Rc::downgrade(&dialog),
+            window_sender,
+            modal: true,
+        });
+        assert!(provider.has_modal_window());
+
+        // closing the dialog unblocks the input
+        drop(dialog);
+        assert!(!provider.has_modal_window());
+        assert_eq!(provider.owned_windows.borrow().len(), 1);
+    }
 }
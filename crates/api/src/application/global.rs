@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use dces::prelude::Entity;
 
-use crate::{shell::Key, theming::Theme};
+use crate::{shell::Key, theming::Theme, utils::Point};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 /// The `Global` struct is used to define global `properties` that could be access application width.
@@ -16,6 +16,9 @@ pub struct Global {
     /// Stores the state of the keyboard
     pub keyboard_state: KeyboardState,
 
+    /// The last known position of the mouse on the window.
+    pub mouse_position: Point,
+
     /// The current window theme
     pub theme: Theme,
 }
@@ -1,11 +1,12 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::{path::PathBuf, sync::mpsc};
 
 use dces::prelude::Entity;
 
 use crate::{
-    shell::{Shell, ShellRequest},
+    services::{Fonts, ThemeWatcher, Windows},
+    shell::{Shell, ShellRequest, CONSOLE},
     theming::Theme,
     widget_base::BuildContext,
 };
@@ -27,6 +28,9 @@ pub struct Application {
     shell: Shell<WindowAdapter>,
     name: Box<str>,
     theme: Theme,
+    fonts: Fonts,
+    windows: Windows,
+    theme_watcher: Option<ThemeWatcher>,
 }
 
 impl Default for Application {
@@ -47,6 +51,27 @@ impl Application {
         self
     }
 
+    /// Development mode: loads the theme from the given RON files that extend the default theme and reloads it on
+    /// each change of the files without restarting the application.
+    pub fn watch_theme<P: Into<PathBuf>>(mut self, files: impl IntoIterator<Item = P>) -> Self {
+        let theme_watcher = ThemeWatcher::new(crate::theme::default_theme_config(), files);
+
+        match theme_watcher.theme() {
+            Ok(theme) => self.theme = theme,
+            Err(e) => CONSOLE.log(e),
+        }
+
+        self.theme_watcher = Some(theme_watcher);
+        self
+    }
+
+    /// Registers a font with the given family name for all windows of the application. Fonts could also be
+    /// registered at runtime with the `Fonts` service.
+    pub fn font(self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
+        self.fonts.register(family, font_file);
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -59,14 +84,31 @@ impl Application {
             theme: crate::theme::default_theme(),
             #[cfg(feature = "light")]
             theme: crate::theme::light_theme(),
+            fonts: Fonts::default(),
+            windows: Windows::new(),
+            theme_watcher: None,
         }
     }
 
-    /// Creates a new window and add it to the application.
-    pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
+    /// Creates a new window with the theme of the application and add it to the application. Windows could be
+    /// addressed by the `id` of their root `Window` widget with the `Windows` service.
+    pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(self, create_fn: F) -> Self {
+        let theme = self.theme.clone();
+        self.window_with_theme(theme, create_fn)
+    }
+
+    /// Creates a new window with its own theme and add it to the application.
+    pub fn window_with_theme<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        mut self,
+        theme: Theme,
+        create_fn: F,
+    ) -> Self {
         let (adapter, settings, receiver) = create_window(
             self.name.clone(),
-            self.theme.clone(),
+            theme,
+            self.fonts.clone(),
+            self.windows.clone(),
+            self.theme_watcher.clone(),
             self.request_sender.clone(),
             create_fn,
         );
@@ -79,6 +121,12 @@ impl Application {
         self
     }
 
+    /// Gets the shell of the application, e.g. to step the windows of the headless shell and to read their
+    /// framebuffers in tests.
+    pub fn shell(&mut self) -> &mut Shell<WindowAdapter> {
+        &mut self.shell
+    }
+
     /// Starts the application and run it until quit is requested.
     pub fn run(mut self) {
         self.shell.run();
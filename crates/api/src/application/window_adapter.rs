@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, sync::mpsc};
+use std::{cell::RefCell, sync::mpsc};
 
 use dces::prelude::*;
 
@@ -7,9 +7,12 @@ use crate::{
     event::*,
     properties::*,
     render,
-    services::Settings,
+    services::{
+        DragDropService, FocusManager, Fonts, NotificationService, Notifications, Settings,
+        Shortcuts, ThemeWatcher, Windows,
+    },
     shell,
-    shell::{ShellRequest, WindowRequest, WindowSettings},
+    shell::{Monitor, ShellRequest, WindowIcon, WindowRequest, WindowSettings},
     systems::*,
     tree::Tree,
     utils::{Point, Rectangle},
@@ -30,6 +33,18 @@ impl WindowAdapter {
     ) -> Self {
         WindowAdapter { world, ctx }
     }
+
+    /// Gets the entity component manager of the window, e.g. to read the properties of widgets in tests.
+    pub fn entity_component_manager(
+        &mut self,
+    ) -> &mut EntityComponentManager<Tree, StringComponentStore> {
+        self.world.entity_component_manager()
+    }
+
+    /// Gets the dependencies that are shared by the systems and states of the window.
+    pub(crate) fn context_provider(&self) -> &ContextProvider {
+        &self.ctx
+    }
 }
 
 impl WindowAdapter {
@@ -40,6 +55,20 @@ impl WindowAdapter {
             .root
             .unwrap()
     }
+
+    // the input of the window is blocked while one of its modal windows is open
+    fn is_blocked(&self) -> bool {
+        self.ctx.has_modal_window()
+    }
+}
+
+impl Drop for WindowAdapter {
+    fn drop(&mut self) {
+        // owned windows are closed with their owner
+        for owned_window in self.ctx.owned_windows.borrow().iter() {
+            let _ = owned_window.window_sender.send(WindowRequest::Close);
+        }
+    }
 }
 
 impl shell::WindowAdapter for WindowAdapter {
@@ -55,7 +84,56 @@ impl shell::WindowAdapter for WindowAdapter {
             );
     }
 
+    fn moved(&mut self, x: f64, y: f64) {
+        self.ctx.window_position.set(Point::new(x, y));
+    }
+
+    fn mode_changed(&mut self, mode: shell::WindowMode) {
+        self.ctx.window_mode.set(mode);
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ModeChanged(mode),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
+    fn scale_factor_changed(&mut self, scale_factor: f64) {
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ScaleFactorChanged(scale_factor),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
+    fn monitors_changed(&mut self, monitors: Vec<Monitor>) {
+        *self.ctx.monitors.borrow_mut() = monitors;
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::MonitorsChanged,
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
     fn mouse(&mut self, x: f64, y: f64) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         self.ctx.mouse_position.set(Point::new(x, y));
         self.ctx.event_queue.borrow_mut().register_event(
@@ -66,17 +144,23 @@ impl shell::WindowAdapter for WindowAdapter {
         )
     }
 
-    fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+    fn scroll(&mut self, delta: shell::ScrollDelta) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
-        self.ctx.event_queue.borrow_mut().register_event(
-            ScrollEvent {
-                delta: Point::new(delta_x, delta_y),
-            },
-            root,
-        )
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event(ScrollEvent { delta }, root)
     }
 
     fn mouse_event(&mut self, event: shell::MouseEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         match event.state {
             shell::ButtonState::Up => {
@@ -110,6 +194,10 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn key_event(&mut self, event: shell::KeyEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         match event.state {
             shell::ButtonState::Up => self
@@ -125,6 +213,63 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
+    fn external_drag_event(&mut self, event: shell::ExternalDragEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+        let position = self.ctx.mouse_position.get();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                ExternalDragEvent { position, event },
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
+    fn ime_event(&mut self, event: shell::ImeEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event(CompositionEvent { event }, root);
+    }
+
+    fn touch_event(&mut self, event: shell::TouchEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+
+        self.ctx.event_queue.borrow_mut().register_event(
+            TouchEvent {
+                id: event.id,
+                phase: event.phase,
+                position: event.position,
+            },
+            root,
+        );
+    }
+
+    fn tray_event(&mut self, event: shell::TrayEvent) {
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(TrayIconEvent { event }, EventStrategy::Direct, root);
+    }
+
     fn quit_event(&mut self) {
         let root = self.root();
 
@@ -134,6 +279,19 @@ impl shell::WindowAdapter for WindowAdapter {
             .register_event(SystemEvent::Quit, root);
     }
 
+    fn color_scheme_changed(&mut self, color_scheme: shell::ColorScheme) {
+        let root = self.root();
+
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ColorSchemeChanged(color_scheme),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
     fn active(&mut self, active: bool) {
         let root = self.root();
 
@@ -152,10 +310,14 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 }
 
-/// Creates a `WindowAdapter` and a `WindowSettings` object from a window builder closure.
+/// Creates a `WindowAdapter` and a `WindowSettings` object from a window builder closure. The window is added to the
+/// given `Windows` service if its root widget has an id.
 pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     app_name: impl Into<String>,
     theme: Theme,
+    fonts: Fonts,
+    windows: Windows,
+    theme_watcher: Option<ThemeWatcher>,
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
@@ -177,7 +339,56 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .register("settings", Settings::new(app_name.clone()));
     };
 
-    let context_provider = ContextProvider::new(sender, request_sender, app_name);
+    registry
+        .borrow_mut()
+        .register("clipboard", shell::Clipboard::new());
+
+    registry
+        .borrow_mut()
+        .register("drag_drop", DragDropService::new());
+
+    registry
+        .borrow_mut()
+        .register("focus_manager", FocusManager::new());
+
+    registry
+        .borrow_mut()
+        .register("shortcuts", Shortcuts::new());
+
+    registry.borrow_mut().register("fonts", fonts.clone());
+
+    if let Some(theme_watcher) = theme_watcher {
+        registry
+            .borrow_mut()
+            .register("theme_watcher", theme_watcher);
+    }
+
+    let context_provider = ContextProvider::new(
+        sender,
+        request_sender,
+        app_name,
+        fonts.clone(),
+        windows.clone(),
+    );
+
+    let notification_service = NotificationService::new(
+        context_provider.update_requests.clone(),
+        context_provider.window_sender.clone(),
+    );
+
+    // desktop notifications that could not be shown are passed as toasts to the notification service
+    registry.borrow_mut().register(
+        "desktop_notifications",
+        Notifications::new(
+            context_provider.application_name.clone(),
+            context_provider.window_sender.clone(),
+            notification_service.sender(),
+        ),
+    );
+
+    registry
+        .borrow_mut()
+        .register("notifications", notification_service);
 
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
@@ -213,6 +424,23 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         window
     };
 
+    let id = world
+        .entity_component_manager()
+        .component_store()
+        .get::<String>("id", window)
+        .ok()
+        .cloned();
+
+    registry.borrow_mut().register(
+        "windows",
+        windows.register(
+            id,
+            window,
+            &context_provider.event_queue,
+            context_provider.window_sender.clone(),
+        ),
+    );
+
     let constraint = *world
         .entity_component_manager()
         .component_store()
@@ -225,19 +453,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .get::<Point>("position", window)
         .unwrap();
 
-    let mut fonts = HashMap::new();
-    fonts.insert(
-        "Roboto-Regular".to_string(),
-        crate::theme::fonts::ROBOTO_REGULAR_FONT,
-    );
-    fonts.insert(
-        "Roboto-Medium".to_string(),
-        crate::theme::fonts::ROBOTO_MEDIUM_FONT,
-    );
-    fonts.insert(
-        "MaterialIcons-Regular".to_string(),
-        crate::theme::fonts::MATERIAL_ICONS_FONT,
-    );
+    context_provider.window_position.set(position);
 
     let settings = WindowSettings {
         title: world
@@ -263,7 +479,13 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .unwrap(),
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
-        fonts,
+        fonts: fonts.to_map().into_iter().collect(),
+        icon: world
+            .entity_component_manager()
+            .component_store()
+            .get::<render::Image>("icon", window)
+            .ok()
+            .and_then(window_icon),
     };
 
     let mut global = Global::default();
@@ -321,3 +543,23 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         receiver,
     )
 }
+
+/// Converts an image to the icon of a window or a tray icon. Returns `None` if the image is empty.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn window_icon(image: &render::Image) -> Option<WindowIcon> {
+    if image.data().is_empty() {
+        return None;
+    }
+
+    Some(WindowIcon::new(
+        image.width() as u32,
+        image.height() as u32,
+        image.data().to_vec(),
+    ))
+}
+
+/// The pixels of an image are not accessible on the web, the icon of a page is its favicon.
+#[cfg(target_arch = "wasm32")]
+pub fn window_icon(_: &render::Image) -> Option<WindowIcon> {
+    None
+}
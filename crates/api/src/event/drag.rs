@@ -0,0 +1,325 @@
+use std::{any::Any, fmt, path::PathBuf, rc::Rc};
+
+use dces::prelude::Entity;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    shell,
+    shell::DropData,
+    utils::*,
+};
+
+/// The payload of a drag-and-drop operation. It holds a typed data object and a text that describes the data, the
+/// text is shown by the drag ghost that follows the pointer.
+///
+/// # Example
+///
+/// ```rust
+/// let data = DragData::new(task_id).text("Write docs");
+///
+/// if let Some(task_id) = data.get::<u32>() {
+///     println!("{}", task_id);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DragData {
+    data: Rc<dyn Any>,
+    text: String,
+}
+
+impl DragData {
+    /// Creates a new drag data with the given data object.
+    pub fn new<T: Any>(data: T) -> Self {
+        DragData {
+            data: Rc::new(data),
+            text: String::default(),
+        }
+    }
+
+    /// Builder method that sets the text that describes the data.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Gets the text that describes the data.
+    pub fn get_text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Gets the data object if it has the given type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.data.downcast_ref::<T>()
+    }
+
+    /// Checks if the data object has the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.data.is::<T>()
+    }
+
+    /// Checks if the data is dragged from another application.
+    pub fn is_external(&self) -> bool {
+        self.is::<DropData>()
+    }
+
+    /// Gets the paths of the files that are dragged from another application, e.g. from a file manager.
+    pub fn get_files(&self) -> Option<&[PathBuf]> {
+        match self.get::<DropData>()? {
+            DropData::Files(files) => Some(files.as_slice()),
+            DropData::Text(_) => None,
+        }
+    }
+}
+
+impl From<DropData> for DragData {
+    fn from(data: DropData) -> Self {
+        let text = match &data {
+            DropData::Files(files) => files
+                .iter()
+                .map(|file| {
+                    file.file_name()
+                        .unwrap_or_else(|| file.as_os_str())
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+            DropData::Text(text) => text.clone(),
+        };
+
+        DragData::new(data).text(text)
+    }
+}
+
+impl fmt::Debug for DragData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragData")
+            .field("text", &self.text)
+            .finish()
+    }
+}
+
+/// Represents the current state of a drag-and-drop operation in a drag event.
+#[derive(Clone, Debug)]
+pub struct Drag {
+    /// The widget that has started the drag.
+    pub source: Entity,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The dragged data.
+    pub data: DragData,
+}
+
+/// `DragStartEvent` starts a drag-and-drop operation with the given data. It is pushed by the source widget, e.g.
+/// by `Context::start_drag`, the drag ends on the next mouse up or if Escape is pressed.
+#[derive(Event)]
+pub struct DragStartEvent {
+    /// The widget that starts the drag.
+    pub source: Entity,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The dragged data.
+    pub data: DragData,
+}
+
+/// `DragEnterEvent` occurs if a widget has accepted a drag that was outside of it before.
+#[derive(Event)]
+pub struct DragEnterEvent(pub Drag);
+
+/// `DragOverEvent` occurs when the mouse is moved over a widget during a drag. The widget accepts the drag by a
+/// handler that returns `true`, otherwise the event bubbles to the parent.
+#[derive(Event)]
+pub struct DragOverEvent(pub Drag);
+
+/// `DragLeaveEvent` occurs if the drag leaves the widget that has accepted it or if the drag is canceled.
+#[derive(Event)]
+pub struct DragLeaveEvent(pub Drag);
+
+/// `DropEvent` occurs when the mouse is released over a widget that has accepted the drag.
+#[derive(Event)]
+pub struct DropEvent(pub Drag);
+
+/// `DragEndEvent` is sent to the source widget after the drag is finished. It contains the flag if the data was
+/// dropped on a target.
+#[derive(Event)]
+pub struct DragEndEvent(pub Drag, pub bool);
+
+/// `ExternalDragEvent` is pushed by the window if files or text of another application are dragged over the window
+/// or dropped on it. The window is the source of the drag, the drag data is converted from the `DropData` of the
+/// shell, the widgets get it by the common drag over and drop events.
+#[derive(Event)]
+pub struct ExternalDragEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The drag event of the shell.
+    pub event: shell::ExternalDragEvent,
+}
+
+/// Defines the drag handler function. For a drag over or drop handler the return value accepts the drag.
+pub type DragHandlerFunction = dyn Fn(&mut StatesContext, Drag) -> bool + 'static;
+
+/// Defines the drag end handler function.
+pub type DragEndHandlerFunction = dyn Fn(&mut StatesContext, Drag, bool) + 'static;
+
+macro_rules! drag_event_handler {
+    ($event:ident, $handler:ident) => {
+        #[derive(IntoHandler)]
+        pub struct $handler {
+            handler: Rc<DragHandlerFunction>,
+        }
+
+        impl EventHandler for $handler {
+            fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+                event.downcast_ref::<$event>().ok().map_or(false, |event| {
+                    (self.handler)(state_context, event.0.clone())
+                })
+            }
+
+            fn handles_event(&self, event: &EventBox) -> bool {
+                event.is_type::<$event>()
+            }
+        }
+    };
+}
+
+drag_event_handler!(DragEnterEvent, DragEnterEventHandler);
+drag_event_handler!(DragOverEvent, DragOverEventHandler);
+drag_event_handler!(DragLeaveEvent, DragLeaveEventHandler);
+drag_event_handler!(DropEvent, DropEventHandler);
+
+/// Used to handle drag start events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragStartEventHandler {
+    handler: Rc<DragHandlerFunction>,
+}
+
+impl EventHandler for DragStartEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragStartEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(
+                    state_context,
+                    Drag {
+                        source: event.source,
+                        position: event.position,
+                        data: event.data.clone(),
+                    },
+                )
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragStartEvent>()
+    }
+}
+
+/// Used to handle drag end events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEndEventHandler {
+    handler: Rc<DragEndHandlerFunction>,
+}
+
+impl EventHandler for DragEndEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragEndEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.0.clone(), event.1);
+                false
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEndEvent>()
+    }
+}
+
+pub trait DragDropHandler: Sized + Widget {
+    /// Inserts a handler that is called if the widget has started a drag.
+    fn on_drag_start<H: Fn(&mut StatesContext, Drag) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragStartEventHandler {
+            handler: Rc::new(move |states, drag| {
+                handler(states, drag);
+                false
+            }),
+        })
+    }
+
+    /// Inserts a handler that is called if the widget has accepted a drag that was outside of it before.
+    fn on_drag_enter<H: Fn(&mut StatesContext, Drag) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragEnterEventHandler {
+            handler: Rc::new(move |states, drag| {
+                handler(states, drag);
+                false
+            }),
+        })
+    }
+
+    /// Inserts a drag over handler. Return `true` to accept the drag, e.g. if the data has the expected type.
+    fn on_drag_over<H: Fn(&mut StatesContext, Drag) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragOverEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if an accepted drag leaves the widget or is canceled.
+    fn on_drag_leave<H: Fn(&mut StatesContext, Drag) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragLeaveEventHandler {
+            handler: Rc::new(move |states, drag| {
+                handler(states, drag);
+                false
+            }),
+        })
+    }
+
+    /// Inserts a drop handler. Return `true` if the data is taken by the widget.
+    fn on_drop<H: Fn(&mut StatesContext, Drag) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called on the source widget after the drag is finished. It gets the flag if the
+    /// data was dropped on a target.
+    fn on_drag_end<H: Fn(&mut StatesContext, Drag, bool) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_data_from_drop_data() {
+        let files = vec![
+            PathBuf::from("/home/user/notes.txt"),
+            PathBuf::from("image.png"),
+        ];
+        let data = DragData::from(DropData::Files(files.clone()));
+
+        assert!(data.is_external());
+        assert_eq!(data.get_text(), "notes.txt, image.png");
+        assert_eq!(data.get_files(), Some(files.as_slice()));
+
+        let data = DragData::from(DropData::Text("OrbTk".to_string()));
+        assert!(data.is_external());
+        assert_eq!(data.get_text(), "OrbTk");
+        assert_eq!(data.get_files(), None);
+
+        let data = DragData::new(42_u32);
+        assert!(!data.is_external());
+        assert_eq!(data.get_files(), None);
+    }
+}
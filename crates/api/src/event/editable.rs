@@ -13,10 +13,27 @@ crate::trigger_event!(
     on_activate
 );
 
+crate::trigger_event!(DeleteEvent, DeleteEventHandler, DeleteHandler, on_delete);
+
+crate::trigger_event!(FinishEvent, FinishEventHandler, FinishHandler, on_finish);
+
+/// Describes a change of the selection of an items widget.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SelectionChange {
+    /// All selected indices after the change.
+    pub selected: Vec<usize>,
+
+    /// The indices that are selected by the change.
+    pub added: Vec<usize>,
+
+    /// The indices that are deselected by the change.
+    pub removed: Vec<usize>,
+}
+
 #[derive(Clone, Event)]
-pub struct SelectionChangedEvent(pub Entity, pub Vec<usize>);
+pub struct SelectionChangedEvent(pub Entity, pub SelectionChange);
 
-pub type WindowHandlerFn = dyn Fn(&mut StatesContext, Entity, Vec<usize>) + 'static;
+pub type WindowHandlerFn = dyn Fn(&mut StatesContext, Entity, SelectionChange) + 'static;
 
 #[derive(IntoHandler)]
 pub struct SelectionChangedEventHandler {
@@ -40,7 +57,7 @@ impl EventHandler for SelectionChangedEventHandler {
 
 pub trait SelectionChangedHandler: Sized + Widget {
     /// Inserts a click handler.
-    fn on_selection_changed<H: Fn(&mut StatesContext, Entity, Vec<usize>) + 'static>(
+    fn on_selection_changed<H: Fn(&mut StatesContext, Entity, SelectionChange) + 'static>(
         self,
         handler: H,
     ) -> Self {
@@ -50,6 +67,188 @@ pub trait SelectionChangedHandler: Sized + Widget {
     }
 }
 
+#[derive(Clone, Event)]
+/// This event occurs when a tab is closed by its close button. It contains the index of the closed tab.
+pub struct TabClosedEvent(pub Entity, pub usize);
+
+pub type TabClosedHandlerFn = dyn Fn(&mut StatesContext, Entity, usize) + 'static;
+
+#[derive(IntoHandler)]
+pub struct TabClosedEventHandler {
+    pub handler: Rc<TabClosedHandlerFn>,
+}
+
+impl EventHandler for TabClosedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TabClosedEvent>() {
+            (self.handler)(states, event.0, event.1);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TabClosedEvent>()
+    }
+}
+
+pub trait TabClosedHandler: Sized + Widget {
+    /// Inserts a tab closed handler.
+    fn on_tab_closed<H: Fn(&mut StatesContext, Entity, usize) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TabClosedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[derive(Clone, Event)]
+/// This event occurs when a segment of a breadcrumb bar is activated. It contains the index of the segment.
+pub struct SegmentActivatedEvent(pub Entity, pub usize);
+
+pub type SegmentActivatedHandlerFn = dyn Fn(&mut StatesContext, Entity, usize) + 'static;
+
+#[derive(IntoHandler)]
+pub struct SegmentActivatedEventHandler {
+    pub handler: Rc<SegmentActivatedHandlerFn>,
+}
+
+impl EventHandler for SegmentActivatedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SegmentActivatedEvent>() {
+            (self.handler)(states, event.0, event.1);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SegmentActivatedEvent>()
+    }
+}
+
+pub trait SegmentActivatedHandler: Sized + Widget {
+    /// Inserts a segment activated handler.
+    fn on_segment_activated<H: Fn(&mut StatesContext, Entity, usize) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SegmentActivatedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[derive(Clone, Event)]
+/// This event occurs when an item of a reorderable list is dragged to a new position. It contains the entity of the
+/// list, the old and the new index of the item.
+pub struct ItemMovedEvent(pub Entity, pub usize, pub usize);
+
+pub type ItemMovedHandlerFn = dyn Fn(&mut StatesContext, Entity, usize, usize) + 'static;
+
+#[derive(IntoHandler)]
+pub struct ItemMovedEventHandler {
+    pub handler: Rc<ItemMovedHandlerFn>,
+}
+
+impl EventHandler for ItemMovedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ItemMovedEvent>() {
+            (self.handler)(states, event.0, event.1, event.2);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ItemMovedEvent>()
+    }
+}
+
+pub trait ItemMovedHandler: Sized + Widget {
+    /// Inserts an item moved handler. It gets the entity of the list, the old and the new index of the item.
+    fn on_item_moved<H: Fn(&mut StatesContext, Entity, usize, usize) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ItemMovedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[derive(Clone, Event)]
+/// This event occurs when a menu item is activated. It contains the entity and the id of the item.
+pub struct ActivatedEvent(pub Entity, pub String);
+
+pub type ActivatedHandlerFn = dyn Fn(&mut StatesContext, Entity, String) + 'static;
+
+#[derive(IntoHandler)]
+pub struct ActivatedEventHandler {
+    pub handler: Rc<ActivatedHandlerFn>,
+}
+
+impl EventHandler for ActivatedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ActivatedEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ActivatedEvent>()
+    }
+}
+
+pub trait ActivatedHandler: Sized + Widget {
+    /// Inserts an activated handler.
+    fn on_activated<H: Fn(&mut StatesContext, Entity, String) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(ActivatedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[derive(Clone, Event)]
+/// This event occurs when a link is clicked. It contains the entity of the link and the target url.
+pub struct NavigateEvent(pub Entity, pub String);
+
+pub type NavigateHandlerFn = dyn Fn(&mut StatesContext, Entity, String) + 'static;
+
+#[derive(IntoHandler)]
+pub struct NavigateEventHandler {
+    pub handler: Rc<NavigateHandlerFn>,
+}
+
+impl EventHandler for NavigateEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<NavigateEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<NavigateEvent>()
+    }
+}
+
+pub trait NavigateHandler: Sized + Widget {
+    /// Inserts a navigate handler.
+    fn on_navigate<H: Fn(&mut StatesContext, Entity, String) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(NavigateEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
 #[derive(Clone, Event)]
 /// This event occurs when a property of a widget is updated.
 pub struct ChangedEvent(pub Entity, pub String);
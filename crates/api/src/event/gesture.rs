@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+pub use crate::shell::{GestureSettings, SwipeDirection};
+
+/// `DoubleClickEvent` occurs when the left mouse button is clicked twice in a short time on the same position.
+#[derive(Event)]
+pub struct DoubleClickEvent {
+    /// Indicates the position of the second click.
+    pub position: Point,
+}
+
+/// `LongPressEvent` occurs when the left mouse button is pressed without moving until the long press time is elapsed.
+#[derive(Event)]
+pub struct LongPressEvent {
+    /// Indicates the position of the press.
+    pub position: Point,
+}
+
+/// `SwipeEvent` occurs when the mouse is moved fast with pressed left mouse button.
+#[derive(Event)]
+pub struct SwipeEvent {
+    /// Indicates the position where the swipe has started.
+    pub position: Point,
+
+    /// Indicates the main direction of the swipe.
+    pub direction: SwipeDirection,
+
+    /// Indicates the velocity of the swipe in pixels per millisecond.
+    pub velocity: f64,
+}
+
+/// Defines the swipe handler function.
+pub type SwipeHandlerFunction = dyn Fn(&mut StatesContext, SwipeDirection, f64) -> bool + 'static;
+
+/// Used to handle double click events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DoubleClickEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DoubleClickEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DoubleClickEvent>()
+    }
+}
+
+/// Used to handle long press events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct LongPressEventHandler {
+    pub handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for LongPressEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<LongPressEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LongPressEvent>()
+    }
+}
+
+/// Used to handle swipe events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct SwipeEventHandler {
+    handler: Rc<SwipeHandlerFunction>,
+}
+
+impl EventHandler for SwipeEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<SwipeEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.direction, event.velocity)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SwipeEvent>()
+    }
+}
+
+/// The gestures are recognized by the `GestureRecognizer` of the window and delivered to the top most widget under
+/// the mouse that handles them.
+pub trait GestureHandler: Sized + Widget {
+    /// Inserts a double click handler.
+    fn on_double_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a long press handler.
+    fn on_long_press<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(LongPressEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a swipe handler. It gets the direction and the velocity of the swipe.
+    fn on_swipe<H: Fn(&mut StatesContext, SwipeDirection, f64) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SwipeEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+pub use crate::shell::ImeEvent;
+
+/// `CompositionEvent` occurs when the composition of the input method editor is started, changed or committed.
+#[derive(Event)]
+pub struct CompositionEvent {
+    pub event: ImeEvent,
+}
+
+/// Defines the composition handler function.
+pub type CompositionHandlerFunction = dyn Fn(&mut StatesContext, ImeEvent) -> bool + 'static;
+
+/// Used to handle composition events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct CompositionEventHandler {
+    handler: Rc<CompositionHandlerFunction>,
+}
+
+impl EventHandler for CompositionEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<CompositionEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.event.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<CompositionEvent>()
+    }
+}
+
+/// Like key events the composition events are handled by the focused widget, its children and its parents first.
+pub trait CompositionHandler: Sized + Widget {
+    /// Inserts a composition handler.
+    fn on_composition<H: Fn(&mut StatesContext, ImeEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(CompositionEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -65,3 +65,37 @@ pub trait KeyDownHandler: Sized + Widget {
         )
     }
 }
+
+/// This event is sent direct to the target of a shortcut that is registered by `Shortcuts::register_event`. It
+/// contains the name of the shortcut.
+#[derive(Clone, Event)]
+pub struct ShortcutEvent(pub String);
+
+pub type ShortcutHandlerFn = dyn Fn(&mut StatesContext, String) -> bool + 'static;
+
+#[derive(IntoHandler)]
+pub struct ShortcutEventHandler {
+    handler: Rc<ShortcutHandlerFn>,
+}
+
+impl EventHandler for ShortcutEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ShortcutEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(states, event.0.clone()))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ShortcutEvent>()
+    }
+}
+
+pub trait ShortcutHandler: Sized + Widget {
+    /// Inserts a shortcut handler. It gets the name of the triggered shortcut.
+    fn on_shortcut<H: Fn(&mut StatesContext, String) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(ShortcutEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
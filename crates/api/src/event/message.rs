@@ -0,0 +1,72 @@
+use std::{any::Any, rc::Rc};
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// `MessageEvent` occurs if another window of the application sends a message to the window with the `Windows`
+/// service, e.g. a tool palette that passes the picked color to the main window. It is handled by the root `Window`
+/// widget of the receiving window.
+#[derive(Clone, Event)]
+pub struct MessageEvent {
+    /// The id of the sending window, or `None` if the message is sent by a window without id.
+    pub sender: Option<String>,
+
+    message: Rc<dyn Any>,
+}
+
+impl MessageEvent {
+    /// Creates a new message event.
+    pub fn new<M: Any>(sender: Option<String>, message: M) -> Self {
+        MessageEvent {
+            sender,
+            message: Rc::new(message),
+        }
+    }
+
+    /// Checks if the message is of the given type.
+    pub fn is<M: Any>(&self) -> bool {
+        self.message.is::<M>()
+    }
+
+    /// Gets the message if it is of the given type.
+    pub fn downcast_ref<M: Any>(&self) -> Option<&M> {
+        self.message.downcast_ref::<M>()
+    }
+}
+
+/// Defines the message handler function.
+pub type MessageHandlerFunction = dyn Fn(&mut StatesContext, MessageEvent) -> bool + 'static;
+
+/// Used to handle messages of other windows. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MessageEventHandler {
+    handler: Rc<MessageHandlerFunction>,
+}
+
+impl EventHandler for MessageEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MessageEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.clone()))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MessageEvent>()
+    }
+}
+
+/// Messages are sent directly to the root widget of the receiving window.
+pub trait MessageHandler: Sized + Widget {
+    /// Inserts a message handler.
+    fn on_message<H: Fn(&mut StatesContext, MessageEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MessageEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
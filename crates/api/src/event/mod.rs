@@ -6,22 +6,34 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::drag::*;
 pub use self::editable::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
+pub use self::gesture::*;
+pub use self::ime::*;
 pub use self::key::*;
+pub use self::message::*;
 pub use self::mouse::*;
 pub use self::system::*;
+pub use self::touch::*;
+pub use self::tray::*;
 pub use self::window::*;
 
+mod drag;
 mod editable;
 mod event_handler;
 mod event_queue;
 mod focus;
+mod gesture;
+mod ime;
 mod key;
+mod message;
 mod mouse;
 mod system;
+mod touch;
+mod tray;
 mod window;
 
 /// Defines the strategy of an event how it moves through the tree.
@@ -7,14 +7,22 @@ use crate::{
     utils::*,
 };
 
-/// Checks if the given point is inside of a widget.
+pub use crate::shell::{CursorIcon, ScrollDelta};
+
+/// Checks if the given point is inside of a widget. The point is mapped through the inverse render transform of the
+/// widget and its parents, points that are clipped away by a parent are outside.
 pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>) -> bool {
     let enabled = widget.get::<bool>("enabled");
 
-    if !enabled {
+    if !enabled || widget.is_clipped(mouse_position) {
         return false;
     }
 
+    let mouse_position = match widget.global_transform().invert() {
+        Some(transform) => transform.apply(mouse_position),
+        None => return false,
+    };
+
     let bounds = widget.get::<Rectangle>("bounds");
     let position = widget.get::<Point>("position");
 
@@ -33,11 +41,25 @@ pub struct MouseMoveEvent {
     pub position: Point,
 }
 
-/// `ScrollEvent` occurs when the mouse wheel is moved.
+/// `MouseEnterEvent` occurs when the mouse is moved into the bounds of a widget.
+#[derive(Event)]
+pub struct MouseEnterEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `MouseLeaveEvent` occurs when the mouse is moved out of the bounds of a widget.
+#[derive(Event)]
+pub struct MouseLeaveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `ScrollEvent` occurs when the mouse wheel is moved or a trackpad is scrolled.
 #[derive(Event)]
 pub struct ScrollEvent {
-    /// Indicates the scroll offset x and y.
-    pub delta: Point,
+    /// Indicates the vertical and horizontal scroll delta in lines and in pixels.
+    pub delta: ScrollDelta,
 }
 
 /// Represents the current mouse state of an mouse event.
@@ -98,6 +120,9 @@ pub type PositionHandlerFunction = dyn Fn(&mut StatesContext, Point) -> bool + '
 /// Defines the global bouse handler function.
 pub type GlobalMouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) + 'static;
 
+/// Defines the scroll handler function.
+pub type ScrollHandlerFunction = dyn Fn(&mut StatesContext, ScrollDelta) -> bool + 'static;
+
 /// Used to handle click events. Could be attached to a widget.
 pub struct ClickEventHandler {
     handler: Rc<PositionHandlerFunction>,
@@ -125,7 +150,7 @@ impl EventHandler for ClickEventHandler {
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseDownEventHandler {
-    handler: Rc<MouseHandlerFunction>,
+    pub handler: Rc<MouseHandlerFunction>,
 }
 
 impl EventHandler for MouseDownEventHandler {
@@ -223,10 +248,48 @@ impl EventHandler for MouseMoveEventHandler {
     }
 }
 
+/// Used to handle mouse enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseEnterEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseEnterEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseEnterEvent>()
+    }
+}
+
+/// Used to handle mouse leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseLeaveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseLeaveEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseLeaveEvent>()
+    }
+}
+
 /// Used to handle scroll events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct ScrollEventHandler {
-    handler: Rc<PositionHandlerFunction>,
+    handler: Rc<ScrollHandlerFunction>,
 }
 
 impl EventHandler for ScrollEventHandler {
@@ -278,8 +341,35 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
-    /// Insert a mouse up handler.
-    fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+    /// Inserts a mouse enter handler. It is called when the mouse is moved into the bounds of the widget.
+    fn on_mouse_enter<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a mouse leave handler. It is called when the mouse is moved out of the bounds of the widget.
+    fn on_mouse_leave<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// Handles the mouse wheel and trackpad scrolling of a widget. The scroll events are delivered to the top most widget
+/// under the mouse that handles them.
+pub trait ScrollHandler: Sized + Widget {
+    /// Inserts a scroll handler. It gets the vertical and horizontal delta in lines and in pixels.
+    fn on_scroll<H: Fn(&mut StatesContext, ScrollDelta) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
         self.insert_handler(ScrollEventHandler {
             handler: Rc::new(handler),
         })
@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+pub use crate::shell::TouchPhase;
+
+/// `TouchEvent` occurs when a touch point of the window is started, moved, ended or cancelled.
+#[derive(Event)]
+pub struct TouchEvent {
+    /// Indicates the id of the touch point. It is unique while the finger touches the screen.
+    pub id: u64,
+
+    /// Indicates the phase of the touch point.
+    pub phase: TouchPhase,
+
+    /// Indicates the position of the touch point.
+    pub position: Point,
+}
+
+/// `PinchEvent` occurs when the first two touch points of the window are moved apart or together.
+#[derive(Event)]
+pub struct PinchEvent {
+    /// Indicates the center between the two touch points.
+    pub position: Point,
+
+    /// Indicates the scale factor since the last pinch event, e.g. `2.0` if the distance of the points is doubled.
+    pub scale: f64,
+}
+
+/// `RotateEvent` occurs when the first two touch points of the window are rotated around each other.
+#[derive(Event)]
+pub struct RotateEvent {
+    /// Indicates the center between the two touch points.
+    pub position: Point,
+
+    /// Indicates the angle in radians since the last rotate event. Positive angles are clockwise.
+    pub angle: f64,
+}
+
+/// Defines the touch handler function.
+pub type TouchHandlerFunction =
+    dyn Fn(&mut StatesContext, u64, TouchPhase, Point) -> bool + 'static;
+
+/// Defines the pinch and rotate handler function.
+pub type TransformHandlerFunction = dyn Fn(&mut StatesContext, Point, f64) -> bool + 'static;
+
+/// Used to handle touch events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchEventHandler {
+    handler: Rc<TouchHandlerFunction>,
+}
+
+impl EventHandler for TouchEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TouchEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.id, event.phase, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchEvent>()
+    }
+}
+
+/// Used to handle pinch events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PinchEventHandler {
+    handler: Rc<TransformHandlerFunction>,
+}
+
+impl EventHandler for PinchEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<PinchEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.position, event.scale)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PinchEvent>()
+    }
+}
+
+/// Used to handle rotate events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct RotateEventHandler {
+    handler: Rc<TransformHandlerFunction>,
+}
+
+impl EventHandler for RotateEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<RotateEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.position, event.angle)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<RotateEvent>()
+    }
+}
+
+/// The touch events are delivered to the top most widget under the touch point that handles them. Pinches and
+/// rotations are recognized by the `GestureRecognizer` of the window from its first two touch points.
+pub trait TouchHandler: Sized + Widget {
+    /// Inserts a touch handler. It gets the id, the phase and the position of the touch point.
+    fn on_touch<H: Fn(&mut StatesContext, u64, TouchPhase, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a pinch handler. It gets the center and the scale factor of the pinch.
+    fn on_pinch<H: Fn(&mut StatesContext, Point, f64) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(PinchEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a rotate handler. It gets the center and the angle of the rotation.
+    fn on_rotate<H: Fn(&mut StatesContext, Point, f64) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(RotateEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+pub use crate::shell::{TrayEvent, TrayIcon, TrayMenuItem, WindowIcon};
+
+/// `TrayIconEvent` occurs if the user clicks on the tray icon of the window or activates an item of its context menu.
+/// It is handled by the root `Window` widget of the window that shows the tray icon.
+#[derive(Clone, Event)]
+pub struct TrayIconEvent {
+    pub event: TrayEvent,
+}
+
+/// Defines the tray icon handler function.
+pub type TrayIconHandlerFunction = dyn Fn(&mut StatesContext, TrayEvent) -> bool + 'static;
+
+/// Used to handle the events of a tray icon. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TrayIconEventHandler {
+    handler: Rc<TrayIconHandlerFunction>,
+}
+
+impl EventHandler for TrayIconEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TrayIconEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.event.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TrayIconEvent>()
+    }
+}
+
+/// The events of a tray icon are sent directly to the root widget of the window that shows the tray icon.
+pub trait TrayIconHandler: Sized + Widget {
+    /// Inserts a tray icon handler.
+    fn on_tray_event<H: Fn(&mut StatesContext, TrayEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TrayIconEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
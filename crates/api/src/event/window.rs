@@ -2,12 +2,20 @@ use std::rc::Rc;
 
 use super::*;
 
-use crate::{proc_macros::*, widget_base::*};
+use crate::{
+    proc_macros::*,
+    shell::{ColorScheme, WindowMode},
+    widget_base::*,
+};
 
 #[derive(Clone, Event)]
 pub enum WindowEvent {
     Resize { width: f64, height: f64 },
     ActiveChanged(bool),
+    ColorSchemeChanged(ColorScheme),
+    ScaleFactorChanged(f64),
+    MonitorsChanged,
+    ModeChanged(WindowMode),
     None,
 }
 
@@ -0,0 +1,165 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
+
+use super::{collapse, component, component_or_default, component_try_mut, is_collapsed, Layout};
+
+/// Places its children on the explicit position of their `x` and `y` attached properties. The children are not
+/// aligned or stretched, each child gets its desired size. Overlapping children are stacked by their `z_index`.
+#[derive(Default)]
+pub struct CanvasLayout {
+    desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
+}
+
+impl CanvasLayout {
+    pub fn new() -> Self {
+        CanvasLayout::default()
+    }
+}
+
+impl Layout for CanvasLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
+        }
+
+        let mut dirty = self.desired_size.borrow().dirty();
+        let mut size: (f64, f64) = (0.0, 0.0);
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() {
+                    dirty = true;
+                }
+
+                if child_desired_size.width() <= 0.0 || child_desired_size.height() <= 0.0 {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+                let x: f64 = component_or_default(ecm, child, "x");
+                let y: f64 = component_or_default(ecm, child, "y");
+
+                size.0 = size
+                    .0
+                    .max(x + margin.left() + child_desired_size.width() + margin.right());
+                size.1 = size
+                    .1
+                    .max(y + margin.top() + child_desired_size.height() + margin.bottom());
+            }
+        }
+
+        let mut desired = self.desired_size.borrow_mut();
+        desired.set_size(size.0, size.1);
+        desired.set_dirty(dirty);
+
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            // children keep their desired size and are only moved by their position
+            if let Some(halign) = component_try_mut::<Alignment>(ecm, child, "h_align") {
+                *halign = Alignment::Start;
+            }
+
+            if let Some(valign) = component_try_mut::<Alignment>(ecm, child, "v_align") {
+                *valign = Alignment::Start;
+            }
+
+            if let Some(child_layout) = layouts.get(&child) {
+                child_layout.arrange(render_context_2_d, size, child, ecm, layouts, theme);
+            }
+
+            let child_margin: Thickness = component(ecm, child, "margin");
+            let x: f64 = component_or_default(ecm, child, "x");
+            let y: f64 = component_or_default(ecm, child, "y");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(x + child_margin.left());
+                child_bounds.set_y(y + child_margin.top());
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        self.desired_size.borrow_mut().set_dirty(false);
+
+        size
+    }
+}
+
+impl From<CanvasLayout> for Box<dyn Layout> {
+    fn from(layout: CanvasLayout) -> Self {
+        Box::new(layout)
+    }
+}
@@ -7,20 +7,24 @@ use dces::prelude::*;
 
 use crate::{
     properties::Constraint,
-    render::Image,
-    render::RenderContext2D,
+    render::{Image, RenderContext2D, VectorImage},
+    render_object::display_text,
     theming::*,
     tree::Tree,
     utils::prelude::*,
     widget_base::{mark_as_dirty, WidgetContainer},
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{collapse, component, component_or_default, component_try_mut, is_collapsed, Layout};
 
 /// Fixed size layout is defined by fixed bounds like the size of an image or the size of a text.
+///
+/// An image with a `stretch` other than `None` keeps the given size or the size of its parent, the size of the
+/// image is only used as fallback.
 #[derive(Default)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
     old_alignment: Cell<(Alignment, Alignment)>,
 }
 
@@ -39,9 +43,10 @@ impl Layout for FixedSizeLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return *self.desired_size.borrow();
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
         }
 
         let widget = WidgetContainer::new(entity, ecm, theme, None);
@@ -55,9 +60,18 @@ impl Layout for FixedSizeLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
+        let stretch = widget.try_clone::<Stretch>("stretch").unwrap_or_default();
+
         let size = widget
             .try_get::<Image>("image")
+            .filter(|image| image.width() > 0.0)
             .map(|image| (image.width(), image.height()))
+            .or_else(|| {
+                widget
+                    .try_get::<VectorImage>("vector_image")
+                    .filter(|vector_image| !vector_image.is_empty())
+                    .map(|vector_image| (vector_image.width(), vector_image.height()))
+            })
             .or_else(|| {
                 widget.try_get::<String16>("text").and_then(|text| {
                     let font = widget.get::<String>("font");
@@ -76,11 +90,9 @@ impl Layout for FixedSizeLayout {
                                 (text_metrics.width, text_metrics.height)
                             })
                     } else {
-                        let text_metrics = render_context_2_d.measure(
-                            text.to_string().as_str(),
-                            *font_size,
-                            font.as_str(),
-                        );
+                        let text = display_text(&widget, text);
+                        let text_metrics =
+                            render_context_2_d.measure(text.as_str(), *font_size, font.as_str());
 
                         Some((text_metrics.width, text_metrics.height))
                     }
@@ -102,7 +114,13 @@ impl Layout for FixedSizeLayout {
             });
 
         if let Some(size) = size {
-            if let Some(constraint) = component_try_mut::<Constraint>(ecm, entity, "constraint") {
+            if stretch != Stretch::None {
+                let mut desired_size = self.desired_size.borrow_mut();
+                desired_size.set_width(size.0 as f64);
+                desired_size.set_height(size.1 as f64);
+            } else if let Some(constraint) =
+                component_try_mut::<Constraint>(ecm, entity, "constraint")
+            {
                 constraint.set_width(size.0 as f64);
                 constraint.set_height(size.1 as f64);
             }
@@ -138,20 +156,33 @@ impl Layout for FixedSizeLayout {
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64) {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return (0.0, 0.0);
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
+        }
+
+        let mut size = self.desired_size.borrow().size();
+
+        if component_or_default::<Stretch>(ecm, entity, "stretch") != Stretch::None {
+            let halign: Alignment = component(ecm, entity, "h_align");
+            let valign: Alignment = component(ecm, entity, "v_align");
+            let margin: Thickness = component(ecm, entity, "margin");
+            let constraint: Constraint = component(ecm, entity, "constraint");
+
+            size = constraint.perform((
+                halign.align_measure(parent_size.0, size.0, margin.left(), margin.right()),
+                valign.align_measure(parent_size.1, size.1, margin.top(), margin.bottom()),
+            ));
         }
 
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
-            bounds.set_width(self.desired_size.borrow().width());
-            bounds.set_height(self.desired_size.borrow().height());
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
         }
 
         mark_as_dirty("bounds", entity, ecm);
@@ -159,22 +190,12 @@ impl Layout for FixedSizeLayout {
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
             if let Some(child_layout) = layouts.get(&child) {
-                child_layout.arrange(
-                    render_context_2_d,
-                    (
-                        self.desired_size.borrow().width(),
-                        self.desired_size.borrow().height(),
-                    ),
-                    child,
-                    ecm,
-                    layouts,
-                    theme,
-                );
+                child_layout.arrange(render_context_2_d, size, child, ecm, layouts, theme);
             }
         }
 
         self.desired_size.borrow_mut().set_dirty(false);
-        self.desired_size.borrow().size()
+        size
     }
 }
 
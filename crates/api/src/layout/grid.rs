@@ -7,13 +7,14 @@ use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, Layout};
+use super::{collapse, component, component_try_mut, is_collapsed, try_component, Layout};
 
 /// Orders its children in a grid layout with columns and rows. If no columns and rows are defined
 /// the grid layout could also be used as an alignment layout.
 #[derive(Default)]
 pub struct GridLayout {
     desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
     children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
     old_alignment: Cell<(Alignment, Alignment)>,
 }
@@ -71,49 +72,50 @@ impl GridLayout {
         (y, height)
     }
 
-    fn calculate_column_width(
+    // calculates the content sizes of all columns and rows by the desired sizes of the children
+    fn measure_tracks(
         &self,
-        child: Entity,
-        column: Column,
-        grid_column: usize,
-        column_widths: &mut BTreeMap<usize, f64>,
-        margin: Thickness,
-    ) {
-        if column.width != ColumnWidth::Auto {
-            return;
-        }
-        let child_width = self.children_sizes.borrow().get(&child).unwrap().0;
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        entity: Entity,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let columns: Vec<Track> = try_component::<Columns>(ecm, entity, "columns")
+            .map(|columns| columns.iter().map(|c| Track::from(c.width)).collect())
+            .unwrap_or_default();
+        let rows: Vec<Track> = try_component::<Rows>(ecm, entity, "rows")
+            .map(|rows| rows.iter().map(|r| Track::from(r.height)).collect())
+            .unwrap_or_default();
 
-        if let Some(width) = column_widths.get(&grid_column) {
-            if *width < child_width + margin.left() + margin.right() {
-                column_widths.insert(grid_column, child_width + margin.left() + margin.right());
-            }
-        } else {
-            column_widths.insert(grid_column, child_width + margin.left() + margin.right());
-        }
-    }
+        let mut column_cells = vec![];
+        let mut row_cells = vec![];
 
-    fn calculate_row_height(
-        &self,
-        child: Entity,
-        row: Row,
-        grid_row: usize,
-        row_heights: &mut BTreeMap<usize, f64>,
-        margin: Thickness,
-    ) {
-        if row.height != RowHeight::Auto {
-            return;
-        }
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            let size = match self.children_sizes.borrow().get(&child) {
+                // collapsed children are ignored
+                Some(size) if size.0 > 0.0 && size.1 > 0.0 => *size,
+                _ => continue,
+            };
 
-        let child_height = self.children_sizes.borrow().get(&child).unwrap().1;
+            let margin: Thickness = component(ecm, child, "margin");
 
-        if let Some(height) = row_heights.get(&grid_row) {
-            if *height < child_height + margin.top() + margin.bottom() {
-                row_heights.insert(grid_row, child_height + margin.top() + margin.bottom());
-            }
-        } else {
-            row_heights.insert(grid_row, child_height + margin.top() + margin.bottom());
+            column_cells.push(GridCell {
+                start: try_component::<usize>(ecm, child, "column").unwrap_or(0),
+                span: try_component::<usize>(ecm, child, "column_span").unwrap_or(1),
+                size: size.0 + margin.left() + margin.right(),
+            });
+
+            row_cells.push(GridCell {
+                start: try_component::<usize>(ecm, child, "row").unwrap_or(0),
+                span: try_component::<usize>(ecm, child, "row_span").unwrap_or(1),
+                size: size.1 + margin.top() + margin.bottom(),
+            });
         }
+
+        (
+            measure_tracks(&columns, &column_cells),
+            measure_tracks(&rows, &row_cells),
+        )
     }
 
     fn calculate_columns(
@@ -250,9 +252,10 @@ impl Layout for GridLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return *self.desired_size.borrow();
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
         }
 
         let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
@@ -286,6 +289,17 @@ impl Layout for GridLayout {
             }
         }
 
+        // a grid with columns or rows is as large as the sum of its columns or rows
+        let (column_sizes, row_sizes) = self.measure_tracks(ecm, entity);
+
+        if !column_sizes.is_empty() {
+            desired_size.0 = column_sizes.iter().sum();
+        }
+
+        if !row_sizes.is_empty() {
+            desired_size.1 = row_sizes.iter().sum();
+        }
+
         self.desired_size
             .borrow_mut()
             .set_size(desired_size.0, desired_size.1);
@@ -309,9 +323,8 @@ impl Layout for GridLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64) {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return (0.0, 0.0);
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
         }
 
         if !self.desired_size.borrow().dirty() {
@@ -339,38 +352,25 @@ impl Layout for GridLayout {
         ));
 
         let mut columns_cache = Vec::new();
-        let mut column_widths = BTreeMap::new();
         let mut rows_cache = Vec::new();
-        let mut row_heights = BTreeMap::new();
-
-        // calculates the auto column widths
-
-        for index in 0..ecm.entity_store().children[&entity].len() {
-            let child = ecm.entity_store().children[&entity][index];
 
-            let margin: Thickness = component(ecm, entity, "margin");
+        // calculates the auto column widths and row heights
+        let (column_sizes, row_sizes) = self.measure_tracks(ecm, entity);
+        let mut column_widths = BTreeMap::new();
+        let mut row_heights = BTreeMap::new();
 
-            if let Ok(grid_column) = ecm.component_store().get::<usize>("column", child) {
-                if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
-                    if let Some(column) = columns.get(*grid_column) {
-                        self.calculate_column_width(
-                            child,
-                            *column,
-                            *grid_column,
-                            &mut column_widths,
-                            margin,
-                        );
-                    }
+        if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
+            for (grid_column, column) in columns.iter().enumerate() {
+                if column.width == ColumnWidth::Auto {
+                    column_widths.insert(grid_column, column_sizes[grid_column]);
                 }
             }
+        }
 
-            if let Ok(grid_row) = ecm.component_store().get::<usize>("row", child) {
-                let grid_row = *grid_row;
-
-                if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
-                    if let Some(row) = rows.get(grid_row) {
-                        self.calculate_row_height(child, *row, grid_row, &mut row_heights, margin);
-                    }
+        if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
+            for (grid_row, row) in rows.iter().enumerate() {
+                if row.height == RowHeight::Auto {
+                    row_heights.insert(grid_row, row_sizes[grid_row]);
                 }
             }
         }
@@ -525,3 +525,132 @@ impl Into<Box<dyn Layout>> for GridLayout {
         Box::new(self)
     }
 }
+
+// --- Helpers --
+
+// Describes how the size of a column or row is calculated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Track {
+    Auto,
+    Stretch,
+    Fixed(f64),
+}
+
+impl From<ColumnWidth> for Track {
+    fn from(width: ColumnWidth) -> Self {
+        match width {
+            ColumnWidth::Auto => Track::Auto,
+            ColumnWidth::Stretch => Track::Stretch,
+            ColumnWidth::Width(width) => Track::Fixed(width),
+        }
+    }
+}
+
+impl From<RowHeight> for Track {
+    fn from(height: RowHeight) -> Self {
+        match height {
+            RowHeight::Auto => Track::Auto,
+            RowHeight::Stretch => Track::Stretch,
+            RowHeight::Height(height) => Track::Fixed(height),
+        }
+    }
+}
+
+// The position, span and size (including margin) of a child in one direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct GridCell {
+    start: usize,
+    span: usize,
+    size: f64,
+}
+
+// Calculates the content size of each track. Fixed tracks keep their size, auto and stretch tracks are as large
+// as their largest child. Children that span multiple tracks enlarge the last auto (or stretch) track of their
+// span, if the spanned tracks are too small.
+fn measure_tracks(tracks: &[Track], cells: &[GridCell]) -> Vec<f64> {
+    let mut sizes: Vec<f64> = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(size) => *size,
+            _ => 0.0,
+        })
+        .collect();
+
+    for cell in cells.iter().filter(|cell| cell.span <= 1) {
+        if let (Some(track), Some(size)) = (tracks.get(cell.start), sizes.get_mut(cell.start)) {
+            if !matches!(track, Track::Fixed(_)) {
+                *size = size.max(cell.size);
+            }
+        }
+    }
+
+    for cell in cells.iter().filter(|cell| cell.span > 1) {
+        let end = (cell.start + cell.span).min(tracks.len());
+
+        if cell.start >= end {
+            continue;
+        }
+
+        let missing = cell.size - sizes[cell.start..end].iter().sum::<f64>();
+
+        if missing <= 0.0 {
+            continue;
+        }
+
+        let target = (cell.start..end)
+            .rev()
+            .find(|index| tracks[*index] == Track::Auto)
+            .or_else(|| {
+                (cell.start..end)
+                    .rev()
+                    .find(|index| tracks[*index] == Track::Stretch)
+            });
+
+        if let Some(target) = target {
+            sizes[target] += missing;
+        }
+    }
+
+    sizes
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(start: usize, span: usize, size: f64) -> GridCell {
+        GridCell { start, span, size }
+    }
+
+    #[test]
+    fn test_measure_tracks() {
+        let tracks = [Track::Auto, Track::Fixed(50.0), Track::Stretch];
+        let cells = [
+            cell(0, 1, 30.0),
+            cell(0, 1, 40.0),
+            cell(1, 1, 80.0),
+            cell(2, 1, 20.0),
+        ];
+
+        assert_eq!(measure_tracks(&tracks, &cells), vec![40.0, 50.0, 20.0]);
+    }
+
+    #[test]
+    fn test_measure_tracks_span() {
+        let tracks = [Track::Auto, Track::Fixed(50.0), Track::Auto];
+
+        // fits into the spanned tracks
+        let cells = [cell(0, 1, 40.0), cell(0, 2, 60.0)];
+        assert_eq!(measure_tracks(&tracks, &cells), vec![40.0, 50.0, 0.0]);
+
+        // the last auto track of the span is enlarged
+        let cells = [cell(0, 1, 40.0), cell(0, 3, 120.0)];
+        assert_eq!(measure_tracks(&tracks, &cells), vec![40.0, 50.0, 30.0]);
+
+        // spans over the end are clipped
+        let cells = [cell(2, 4, 10.0)];
+        assert_eq!(measure_tracks(&tracks, &cells), vec![0.0, 50.0, 10.0]);
+    }
+}
@@ -1,24 +1,36 @@
-use std::{any::Any, collections::BTreeMap};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
 
 use dces::prelude::*;
 
-use crate::{render::RenderContext2D, theming::*, tree::Tree, utils::*};
+use crate::{
+    render::RenderContext2D, theming::*, tree::Tree, utils::*, widget_base::mark_as_dirty,
+};
 
 pub use self::absolute::*;
+pub use self::canvas::*;
 pub use self::fixed_size::*;
 pub use self::grid::*;
 pub use self::padding::*;
 pub use self::popup::*;
+pub use self::rich_text::*;
 pub use self::stack::*;
 pub use self::text_selection::*;
+pub use self::wrap::*;
 
 mod absolute;
+mod canvas;
 mod fixed_size;
 mod grid;
 mod padding;
 mod popup;
+mod rich_text;
 mod stack;
 mod text_selection;
+mod wrap;
 
 /// A layout is used to dynamic order the children of a widget.
 pub trait Layout: Any {
@@ -87,3 +99,42 @@ fn component_try_mut<'a, C: Component>(
         .get_mut::<C>(component, entity)
         .ok()
 }
+
+// Checks if the widget is collapsed and takes no space in the layout. If the widget was collapsed or expanded since the
+// last measure its desired size is marked as dirty, so its parents measure and arrange their children again.
+fn is_collapsed(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    desired_size: &RefCell<DirtySize>,
+    collapsed: &Cell<bool>,
+) -> bool {
+    let is_collapsed =
+        component_or_default::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed;
+
+    if is_collapsed != collapsed.get() {
+        collapsed.set(is_collapsed);
+        desired_size.borrow_mut().set_dirty(true);
+    }
+
+    is_collapsed
+}
+
+// Resets the desired size and the bounds of a collapsed widget to zero.
+fn collapse(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    desired_size: &RefCell<DirtySize>,
+) -> (f64, f64) {
+    desired_size.borrow_mut().set_size(0.0, 0.0);
+    desired_size.borrow_mut().set_dirty(false);
+
+    if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+        if bounds.width() != 0.0 || bounds.height() != 0.0 {
+            bounds.set_width(0.0);
+            bounds.set_height(0.0);
+            mark_as_dirty("bounds", entity, ecm);
+        }
+    }
+
+    (0.0, 0.0)
+}
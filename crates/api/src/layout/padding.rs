@@ -7,12 +7,13 @@ use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, Layout};
+use super::{collapse, component, component_try_mut, is_collapsed, Layout};
 
 /// Add padding to the widget.
 #[derive(Default)]
 pub struct PaddingLayout {
     desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
     old_alignment: Cell<(Alignment, Alignment)>,
     old_parent_size: Cell<(f64, f64)>,
 }
@@ -32,9 +33,10 @@ impl Layout for PaddingLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return *self.desired_size.borrow();
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
         }
 
         let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
@@ -108,9 +110,8 @@ impl Layout for PaddingLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64) {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return (0.0, 0.0);
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
         }
 
         if !self.desired_size.borrow().dirty() && parent_size == self.old_parent_size.get() {
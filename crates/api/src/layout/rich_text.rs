@@ -0,0 +1,137 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    properties::Constraint,
+    render::RenderContext2D,
+    render_object::{rich_text_measure, rich_text_style},
+    theming::*,
+    tree::Tree,
+    utils::prelude::*,
+    widget_base::{mark_as_dirty, WidgetContainer},
+};
+
+use super::{collapse, component, component_try_mut, is_collapsed, Layout};
+
+/// The rich text layout wraps the lines of a `rich_text` at the available width. The desired width is the width of
+/// the text without wrapping, the height depends on the width of the last arrangement.
+#[derive(Default)]
+pub struct RichTextLayout {
+    desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
+}
+
+impl RichTextLayout {
+    pub fn new() -> Self {
+        RichTextLayout::default()
+    }
+}
+
+impl Layout for RichTextLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
+        }
+
+        let widget = WidgetContainer::new(entity, ecm, theme, None);
+        let rich_text = widget.clone::<RichText>("rich_text");
+        let style = rich_text_style(&widget);
+        let bounds = widget.clone::<Rectangle>("bounds");
+
+        let (_, line_size) =
+            rich_text.layout(&style, f64::MAX, rich_text_measure(render_context_2_d));
+        let height = if bounds.width() > 0.0 {
+            let (_, wrapped_size) = rich_text.layout(
+                &style,
+                bounds.width(),
+                rich_text_measure(render_context_2_d),
+            );
+            wrapped_size.1
+        } else {
+            line_size.1
+        };
+
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let size = constraint.perform((line_size.0, height));
+
+        let mut desired_size = self.desired_size.borrow_mut();
+        desired_size.set_width(size.0);
+        desired_size.set_height(size.1);
+
+        *desired_size
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        // the lines could not be wider than the available space of the parent
+        let width = constraint
+            .perform((
+                halign.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                0.0,
+            ))
+            .0
+            .min(parent_size.0 - margin.left() - margin.right())
+            .max(0.0);
+
+        let height = {
+            let widget = WidgetContainer::new(entity, ecm, theme, None);
+            let rich_text = widget.clone::<RichText>("rich_text");
+            let style = rich_text_style(&widget);
+
+            let (_, wrapped_size) =
+                rich_text.layout(&style, width, rich_text_measure(render_context_2_d));
+            wrapped_size.1
+        };
+
+        let size = constraint.perform((width, height));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+impl Into<Box<dyn Layout>> for RichTextLayout {
+    fn into(self) -> Box<dyn Layout> {
+        Box::new(self)
+    }
+}
@@ -7,12 +7,16 @@ use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
 
-use super::{component, component_or_default, component_try_mut, Layout};
+use super::{
+    collapse, component, component_or_default, component_try_mut, is_collapsed, try_component,
+    Layout,
+};
 
 /// Stacks visual the children widgets vertical or horizontal.
 #[derive(Default)]
 pub struct StackLayout {
     desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
     old_alignment: Cell<(Alignment, Alignment)>,
 }
 
@@ -35,7 +39,7 @@ impl Layout for StackLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
             let mut desired = self.desired_size.borrow_mut();
             desired.set_size(0.0, 0.0);
             return *desired;
@@ -55,6 +59,7 @@ impl Layout for StackLayout {
 
         let nchildren = ecm.entity_store().children[&entity].len();
         let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let (spacing_indices, nvisible) = spacing_indices(&visible_children(ecm, entity));
 
         for index in 0..nchildren {
             let child = ecm.entity_store().children[&entity][index];
@@ -71,8 +76,10 @@ impl Layout for StackLayout {
                     }
                 };
 
-                if spacing != 0.0 && nchildren > 1 {
-                    apply_spacing(&mut child_margin, spacing, orientation, index, nchildren);
+                if spacing != 0.0 && nvisible > 1 {
+                    if let Some(index) = spacing_indices[index] {
+                        apply_spacing(&mut child_margin, spacing, orientation, index, nvisible);
+                    }
                 }
 
                 accumulate_desired_size(
@@ -104,9 +111,8 @@ impl Layout for StackLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64) {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return (0.0, 0.0);
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
         }
 
         if !self.desired_size.borrow().dirty() {
@@ -139,6 +145,7 @@ impl Layout for StackLayout {
         let available_size = size;
         let nchildren = ecm.entity_store().children[&entity].len();
         let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let (spacing_indices, nvisible) = spacing_indices(&visible_children(ecm, entity));
 
         for index in 0..nchildren {
             let child = ecm.entity_store().children[&entity][index];
@@ -170,8 +177,10 @@ impl Layout for StackLayout {
                 }
             };
 
-            if spacing != 0.0 && nchildren > 1 {
-                apply_spacing(&mut child_margin, spacing, orientation, index, nchildren);
+            if spacing != 0.0 && nvisible > 1 {
+                if let Some(index) = spacing_indices[index] {
+                    apply_spacing(&mut child_margin, spacing, orientation, index, nvisible);
+                }
             }
 
             let child_halign: Alignment = component(ecm, child, "h_align");
@@ -273,6 +282,40 @@ fn apply_arrangement(
     *size_counter += size;
 }
 
+// Returns for each child if it is not collapsed.
+fn visible_children(
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Vec<bool> {
+    ecm.entity_store().children[&entity]
+        .clone()
+        .iter()
+        .map(|child| {
+            try_component::<Visibility>(ecm, *child, "visibility") != Some(Visibility::Collapsed)
+        })
+        .collect()
+}
+
+/// Returns the index of each visible child between the visible children and the count of visible children.
+/// Collapsed children get no index, spacing is only applied between visible children.
+fn spacing_indices(visible: &[bool]) -> (Vec<Option<usize>>, usize) {
+    let mut count = 0;
+
+    let indices = visible
+        .iter()
+        .map(|visible| {
+            if !*visible {
+                return None;
+            }
+
+            count += 1;
+            Some(count - 1)
+        })
+        .collect();
+
+    (indices, count)
+}
+
 /// Applies spacing to widgets in a stack, depending upon their position, and the orientation.
 fn apply_spacing(
     margins: &mut Thickness,
@@ -326,7 +369,7 @@ mod tests {
     use orbtk_utils::{Orientation, Thickness};
     use std::iter;
 
-    use super::apply_spacing;
+    use super::{apply_spacing, spacing_indices};
 
     const NUM_WIDGETS: usize = 5;
 
@@ -384,6 +427,15 @@ mod tests {
         spacing(Orientation::Horizontal, 8.0, expected);
     }
 
+    #[test]
+    fn spacing_collapsed() {
+        assert_eq!(
+            spacing_indices(&[true, false, true, true, false]),
+            (vec![Some(0), None, Some(1), Some(2), None], 3)
+        );
+        assert_eq!(spacing_indices(&[false, false]), (vec![None, None], 0));
+    }
+
     fn spacing(orientation: Orientation, spacing: f64, expected: impl Iterator<Item = Thickness>) {
         let inputs = (0..NUM_WIDGETS).map(|id| (id, Thickness::default()));
 
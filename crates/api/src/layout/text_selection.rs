@@ -7,12 +7,13 @@ use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, try_component, Layout};
+use super::{collapse, component, component_try_mut, is_collapsed, try_component, Layout};
 
 /// The text selection layout is used to measure and arrange a text selection cursor.
 #[derive(Default)]
 pub struct TextSelectionLayout {
     desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
     old_text_selection: Cell<TextSelection>,
 }
 
@@ -37,9 +38,10 @@ impl Layout for TextSelectionLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return *self.desired_size.borrow();
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
         }
 
         let constraint: Constraint = component(ecm, entity, "constraint");
@@ -101,9 +103,8 @@ impl Layout for TextSelectionLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64) {
-        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
-            self.desired_size.borrow_mut().set_size(0.0, 0.0);
-            return (0.0, 0.0);
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
         }
 
         let mut pos = 0.0;
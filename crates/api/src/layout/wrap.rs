@@ -0,0 +1,319 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
+
+use super::{collapse, component, component_or_default, component_try_mut, is_collapsed, Layout};
+
+/// Orders its children in lines. A horizontal wrap layout places the children from left to right and starts a new
+/// line if the available width is exceeded. A vertical wrap layout places the children from top to bottom and
+/// starts a new column if the available height is exceeded.
+#[derive(Default)]
+pub struct WrapLayout {
+    desired_size: RefCell<DirtySize>,
+    collapsed: Cell<bool>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+}
+
+impl WrapLayout {
+    pub fn new() -> Self {
+        WrapLayout::default()
+    }
+
+    pub fn set_dirty(&self, dirty: bool) {
+        self.desired_size.borrow_mut().set_dirty(dirty);
+    }
+}
+
+impl Layout for WrapLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if is_collapsed(ecm, entity, &self.desired_size, &self.collapsed) {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            return *desired;
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.set_dirty(true);
+        }
+
+        let orientation: Orientation = component(ecm, entity, "orientation");
+        let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let bounds: Rectangle = component(ecm, entity, "bounds");
+        let mut dirty = false;
+        let mut sizes = vec![];
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                sizes.push(outer_size(
+                    (child_desired_size.width(), child_desired_size.height()),
+                    ecm,
+                    child,
+                ));
+
+                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
+                    dirty = true;
+                }
+            }
+        }
+
+        // the children are measured in one line, the size of the wrapped lines is taken from the last arrangement
+        let (_, line_size) = wrap(&sizes, f64::MAX, spacing, orientation);
+        let (_, wrapped_size) = match orientation {
+            Orientation::Horizontal if bounds.width() > 0.0 => {
+                wrap(&sizes, bounds.width(), spacing, orientation)
+            }
+            Orientation::Vertical if bounds.height() > 0.0 => {
+                wrap(&sizes, bounds.height(), spacing, orientation)
+            }
+            _ => (vec![], line_size),
+        };
+
+        self.set_dirty(dirty);
+
+        let mut desired = self.desired_size.borrow_mut();
+
+        match orientation {
+            Orientation::Horizontal => desired.set_size(line_size.0, wrapped_size.1),
+            Orientation::Vertical => desired.set_size(wrapped_size.0, line_size.1),
+        }
+
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if self.collapsed.get() {
+            return collapse(ecm, entity, &self.desired_size);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let orientation: Orientation = component(ecm, entity, "orientation");
+        let spacing: f64 = component_or_default(ecm, entity, "spacing");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        // a line could not be longer than the available space of the parent
+        let limit = match orientation {
+            Orientation::Horizontal => size.0.min(parent_size.0 - margin.left() - margin.right()),
+            Orientation::Vertical => size.1.min(parent_size.1 - margin.top() - margin.bottom()),
+        };
+
+        let available_size = size;
+        let nchildren = ecm.entity_store().children[&entity].len();
+        let mut children = vec![];
+        let mut sizes = vec![];
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            // children are placed at the start of their line
+            if let Some(halign) = component_try_mut::<Alignment>(ecm, child, "h_align") {
+                *halign = Alignment::Start;
+            }
+
+            if let Some(valign) = component_try_mut::<Alignment>(ecm, child, "v_align") {
+                *valign = Alignment::Start;
+            }
+
+            let mut child_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_size = child_layout.arrange(
+                    render_context_2_d,
+                    available_size,
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            children.push(child);
+            sizes.push(outer_size(child_size, ecm, child));
+        }
+
+        let (positions, wrapped_size) = wrap(&sizes, limit, spacing, orientation);
+
+        for (child, position) in children.iter().zip(positions) {
+            let child_margin: Thickness = component(ecm, *child, "margin");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, *child, "bounds") {
+                child_bounds.set_x(position.0 + child_margin.left());
+                child_bounds.set_y(position.1 + child_margin.top());
+            }
+
+            mark_as_dirty("bounds", *child, ecm);
+        }
+
+        self.set_dirty(false);
+
+        // the size along the lines is limited, the size across the lines depends on the wrapped children
+        let size = match orientation {
+            Orientation::Horizontal => constraint.perform((limit, wrapped_size.1)),
+            Orientation::Vertical => constraint.perform((wrapped_size.0, limit)),
+        };
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        size
+    }
+}
+
+impl From<WrapLayout> for Box<dyn Layout> {
+    fn from(layout: WrapLayout) -> Self {
+        Box::new(layout)
+    }
+}
+
+// Returns the size of the child including its margin. Children without a size are ignored by the layout.
+fn outer_size(
+    size: (f64, f64),
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    child: Entity,
+) -> (f64, f64) {
+    if size.0 <= 0.0 || size.1 <= 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let margin: Thickness = component(ecm, child, "margin");
+
+    (
+        size.0 + margin.left() + margin.right(),
+        size.1 + margin.top() + margin.bottom(),
+    )
+}
+
+/// Calculates the positions of the given sizes, if they are wrapped in lines of the given length. Returns the
+/// positions and the size of all lines.
+fn wrap(
+    sizes: &[(f64, f64)],
+    limit: f64,
+    spacing: f64,
+    orientation: Orientation,
+) -> (Vec<(f64, f64)>, (f64, f64)) {
+    // the wrapping is calculated horizontal, vertical sizes are swapped before and after
+    let swap = |(a, b): (f64, f64)| match orientation {
+        Orientation::Horizontal => (a, b),
+        Orientation::Vertical => (b, a),
+    };
+
+    let mut positions = Vec::with_capacity(sizes.len());
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut line_height: f64 = 0.0;
+    let mut width: f64 = 0.0;
+
+    for size in sizes {
+        let size = swap(*size);
+
+        if size.0 <= 0.0 && size.1 <= 0.0 {
+            positions.push(swap((x, y)));
+            continue;
+        }
+
+        if x > 0.0 && x + size.0 > limit {
+            x = 0.0;
+            y += line_height + spacing;
+            line_height = 0.0;
+        }
+
+        positions.push(swap((x, y)));
+
+        width = width.max(x + size.0);
+        line_height = line_height.max(size.1);
+        x += size.0 + spacing;
+    }
+
+    (positions, swap((width, y + line_height)))
+}
+
+#[cfg(test)]
+mod tests {
+    use orbtk_utils::Orientation;
+
+    use super::wrap;
+
+    #[test]
+    fn test_wrap_horizontal() {
+        let sizes = [(40.0, 20.0), (40.0, 30.0), (40.0, 20.0), (0.0, 0.0)];
+        let (positions, size) = wrap(&sizes, 100.0, 10.0, Orientation::Horizontal);
+
+        assert_eq!(
+            positions,
+            vec![(0.0, 0.0), (50.0, 0.0), (0.0, 40.0), (50.0, 40.0)]
+        );
+        assert_eq!(size, (90.0, 60.0));
+    }
+
+    #[test]
+    fn test_wrap_vertical() {
+        let sizes = [(20.0, 40.0), (30.0, 40.0), (20.0, 40.0)];
+        let (positions, size) = wrap(&sizes, 100.0, 10.0, Orientation::Vertical);
+
+        assert_eq!(positions, vec![(0.0, 0.0), (0.0, 50.0), (40.0, 0.0)]);
+        assert_eq!(size, (60.0, 90.0));
+    }
+
+    #[test]
+    fn test_wrap_large_child() {
+        let sizes = [(140.0, 20.0), (40.0, 20.0)];
+        let (positions, size) = wrap(&sizes, 100.0, 0.0, Orientation::Horizontal);
+
+        assert_eq!(positions, vec![(0.0, 0.0), (0.0, 20.0)]);
+        assert_eq!(size, (140.0, 40.0));
+    }
+}
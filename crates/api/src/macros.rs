@@ -98,6 +98,8 @@ macro_rules! widget {
             name: Option<String>,
             style: Option<String>,
             id: Option<String>,
+            tooltip: Option<String>,
+            cursor: Option<CursorIcon>,
             #[property(Alignment)]
             h_align: Alignment,
             #[property(Alignment)]
@@ -156,6 +158,24 @@ macro_rules! widget {
                 self
             }
 
+            /// Sets the tool tip text that is shown after the mouse hovers the widget for a while.
+            pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+                if !self.tooltip.is_none() {
+                    return self;
+                }
+                self.tooltip = Some(tooltip.into());
+                self
+            }
+
+            /// Sets the mouse cursor that is shown while the mouse is over the widget.
+            pub fn cursor(mut self, cursor: CursorIcon) -> Self {
+                if !self.cursor.is_none() {
+                    return self;
+                }
+                self.cursor = Some(cursor);
+                self
+            }
+
             /// Sets the style selector (replaces the old selector property).
             pub fn style(mut self, style: impl Into<String>) -> Self {
                 if !self.style.is_none() {
@@ -227,6 +247,35 @@ macro_rules! widget {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the render transform of the widget and its children, e.g. `"rotate(45deg) scale(2)"`. It
+            /// changes only the drawing and the hit testing, not the layout.
+            pub fn transform(self, transform: impl IntoPropertySource<Transform>) -> Self {
+                self.set_property("transform", transform)
+            }
+
+            /// Sets or shares the origin of the transform relative to the size of the widget, `(0.5, 0.5)` is the center
+            /// and the default.
+            pub fn transform_origin(self, transform_origin: impl IntoPropertySource<Point>) -> Self {
+                self.set_property("transform_origin", transform_origin)
+            }
+
+            /// Sets or shares the stacking order of the widget among its siblings. Widgets with a greater `z_index` are
+            /// drawn above their overlapping siblings and receive the mouse events first, the default is `0`.
+            pub fn z_index(self, z_index: impl IntoPropertySource<i32>) -> Self {
+                self.set_property("z_index", z_index)
+            }
+
+            /// Sets or shares the flow direction of the widget and its children. Right to left mirrors their layout.
+            pub fn flow_direction(self, flow_direction: impl IntoPropertySource<FlowDirection>) -> Self {
+                self.set_property("flow_direction", flow_direction)
+            }
+
+            /// Sets or shares the theme resources that override the resources of the theme for the widget and its
+            /// children, e.g. the colors of a palette.
+            pub fn resources(self, resources: impl IntoPropertySource<Resources>) -> Self {
+                self.set_property("resources", resources)
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: impl Into<f64>) -> Self {
                 if !self.width.is_none() {
@@ -454,6 +503,14 @@ macro_rules! widget {
                     ctx.register_property("id", entity, id);
                 }
 
+                if let Some(tooltip) = this.tooltip {
+                    ctx.register_property("tooltip", entity, tooltip);
+                }
+
+                if let Some(cursor) = this.cursor {
+                    ctx.register_property("cursor", entity, cursor);
+                }
+
                 if let Some(style) = this.style {
                     ctx.register_property("selector", entity, Selector::new(style));
                 } else {
@@ -6,7 +6,7 @@ use dces::prelude::{Component, Entity, StringComponentStore};
 
 pub use self::layout::*;
 pub use self::widget::*;
-use crate::{into_property_source, render, theming, utils};
+use crate::{into_property_source, render, shell, theming, utils};
 
 mod layout;
 mod widget;
@@ -89,7 +89,21 @@ into_property_source!(i64);
 
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
+into_property_source!(
+    utils::BorderRadius: f64,
+    i32,
+    (f64, f64, f64, f64),
+    (i32, i32, i32, i32),
+    &str,
+    String,
+    utils::Value
+);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
+into_property_source!(utils::BoxShadow: &str, String, utils::Value);
+into_property_source!(utils::CheckCycle: &str);
+into_property_source!(utils::ClockFormat: &str);
+into_property_source!(utils::Date: &str, (i32, u32, u32));
+into_property_source!(utils::FlowDirection: &str);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(utils::Size: f64, i32, (i32, i32), (f64, f64));
@@ -107,18 +121,69 @@ into_property_source!(
     (f64, f64, f64, f64),
     utils::Value
 );
+into_property_source!(utils::ChartData: Vec<f64>, Vec<utils::DataSeries>, Vec<(&str, f64)>);
+into_property_source!(utils::RichText: &str, String, Vec<utils::TextSpan>);
+into_property_source!(utils::Stretch: &str);
 into_property_source!(utils::String16: &str, String);
+into_property_source!(utils::Time: &str, (u32, u32));
+into_property_source!(
+    utils::Transform: &str,
+    String,
+    (f64, f64, f64, f64, f64, f64),
+    utils::Value
+);
 into_property_source!(utils::SelectionMode: &str);
 into_property_source!(utils::Visibility: &str);
 into_property_source!(Vec<String>);
+into_property_source!(Vec<usize>);
+into_property_source!(Vec<f64>);
+into_property_source!(Vec<utils::SyntaxToken>);
+into_property_source!(Vec<utils::GaugeZone>);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
 
+impl<T: Component + Debug> IntoPropertySource<utils::ItemsSource<T>> for utils::ItemsSource<T> {
+    fn into_source(self) -> PropertySource<utils::ItemsSource<T>> {
+        PropertySource::Value(self)
+    }
+}
+
+impl<T: Component + Debug> IntoPropertySource<utils::ItemsSource<T>> for Vec<T> {
+    fn into_source(self) -> PropertySource<utils::ItemsSource<T>> {
+        PropertySource::Value(self.into())
+    }
+}
+
+impl<T: Component + Debug> IntoPropertySource<utils::ItemsSource<T>> for Entity {
+    fn into_source(self) -> PropertySource<utils::ItemsSource<T>> {
+        PropertySource::Source(self)
+    }
+}
+
+impl<T: Component + Debug> IntoPropertySource<utils::ItemsSource<T>> for (String, Entity) {
+    fn into_source(self) -> PropertySource<utils::ItemsSource<T>> {
+        PropertySource::KeySource(self.0, self.1)
+    }
+}
+
+impl<T: Component + Debug> IntoPropertySource<utils::ItemsSource<T>> for (&str, Entity) {
+    fn into_source(self) -> PropertySource<utils::ItemsSource<T>> {
+        PropertySource::KeySource(self.0.to_string(), self.1)
+    }
+}
+
 // Implementation of css types
+into_property_source!(theming::Resources);
 into_property_source!(theming::Selector: &str, String);
 into_property_source!(theming::Theme);
 
 // Implementation of render property types
+into_property_source!(render::AnimatedImage: &str, String, Vec<render::ImageFrame>);
 into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
+into_property_source!(render::VectorImage: &str, String);
+
+// Implementation of shell property types
+into_property_source!(shell::ColorScheme: &str);
+into_property_source!(shell::FileDialogMode);
 
 // Implementation of custom property types
 into_property_source!(Columns: ColumnsBuilder);
@@ -0,0 +1,51 @@
+use crate::{
+    render_object::*,
+    utils::{BorderRadius, Brush, Point, Rectangle},
+};
+
+/// Used to render a card. It draws a soft drop shadow for the `elevation` of the widget and the rectangle with
+/// background, border and border radius on top of it. While `hover` is `true` the `hover_elevation` is used.
+pub struct CardRenderObject;
+
+impl Into<Box<dyn RenderObject>> for CardRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for CardRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, border_radius, elevation, shadow_brush) = {
+            let widget = ctx.widget();
+            let elevation = if widget.clone_or_default::<bool>("hover") {
+                widget.clone_or_default::<f64>("hover_elevation")
+            } else {
+                widget.clone_or_default::<f64>("elevation")
+            };
+
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone_or_default::<BorderRadius>("border_radius"),
+                elevation,
+                widget.clone_or_default::<Brush>("shadow_brush"),
+            )
+        };
+
+        // the higher the card the further and softer is its shadow
+        if let Brush::SolidColor(color) = shadow_brush {
+            if elevation > 0.0 && bounds.width() > 0.0 && bounds.height() > 0.0 {
+                ctx.render_context_2_d().fill_shadow(
+                    global_position.x() + bounds.x(),
+                    global_position.y() + bounds.y() + elevation / 2.0,
+                    bounds.width(),
+                    bounds.height(),
+                    border_radius.fit(bounds.width(), bounds.height()).max(),
+                    elevation * 1.5,
+                    color,
+                );
+            }
+        }
+
+        RectangleRenderObject.render_self(ctx, global_position);
+    }
+}
@@ -0,0 +1,748 @@
+use std::f64::consts::PI;
+
+use crate::{
+    render::{snap_line, RenderContext2D},
+    render_object::*,
+    utils::{Brush, ChartData, ChartScale, Point, Rectangle, Thickness},
+};
+
+/// The maximal number of ticks of the value axis of a chart.
+pub const CHART_MAX_TICKS: usize = 6;
+
+/// The size of the color boxes of a chart legend.
+const LEGEND_BOX_SIZE: f64 = 10.0;
+
+/// The space between the labels, the legend and the plot of a chart.
+const CHART_SPACING: f64 = 8.0;
+
+/// The radius of the data points of a line chart.
+const POINT_RADIUS: f64 = 3.0;
+
+/// Describes the kind of a chart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ChartKind {
+    /// The values are connected by lines.
+    Line,
+
+    /// The values are drawn as bars that are grouped by category.
+    Bar,
+
+    /// The values of the first series are drawn as slices of a circle.
+    Pie,
+}
+
+impl Default for ChartKind {
+    fn default() -> Self {
+        ChartKind::Line
+    }
+}
+
+/// Describes the positions of the parts of a chart relative to its bounds.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChartLayout {
+    /// The area of the data.
+    pub plot: Rectangle,
+
+    /// The scale of the value axis.
+    pub scale: ChartScale,
+
+    /// The height of a line of text.
+    pub line_height: f64,
+
+    /// The y position of the legend, the legend is only drawn if it has entries.
+    pub legend_y: f64,
+}
+
+/// The data point of a chart that is hit by the mouse.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChartHit {
+    /// The index of the series.
+    pub series: usize,
+
+    /// The index of the category.
+    pub index: usize,
+
+    /// The position of the data point relative to the bounds.
+    pub position: Point,
+}
+
+/// Calculates the `ChartLayout` of a chart with the given data, size, padding and font.
+pub fn chart_layout(
+    render_context_2_d: &mut RenderContext2D,
+    kind: ChartKind,
+    data: &ChartData,
+    size: (f64, f64),
+    padding: Thickness,
+    font: &str,
+    font_size: f64,
+) -> ChartLayout {
+    let (width, height) = size;
+
+    let line_height = render_context_2_d
+        .measure("Ag", font_size, font)
+        .height
+        .max(font_size);
+
+    let mut top = padding.top();
+    let legend_y = top;
+
+    if !legend(data, kind).is_empty() {
+        top += line_height + CHART_SPACING;
+    }
+
+    let (min, max) = data.value_range().unwrap_or((0.0, 1.0));
+
+    if kind == ChartKind::Pie {
+        return ChartLayout {
+            plot: Rectangle::new(
+                (padding.left(), top),
+                (
+                    (width - padding.left() - padding.right()).max(0.0),
+                    (height - top - padding.bottom()).max(0.0),
+                ),
+            ),
+            scale: ChartScale::default(),
+            line_height,
+            legend_y,
+        };
+    }
+
+    // bars start at zero
+    let scale = if kind == ChartKind::Bar {
+        ChartScale::auto(min.min(0.0), max.max(0.0), CHART_MAX_TICKS)
+    } else {
+        ChartScale::auto(min, max, CHART_MAX_TICKS)
+    };
+
+    let label_width = scale
+        .ticks()
+        .iter()
+        .map(|tick| {
+            render_context_2_d
+                .measure(&scale.format(*tick), font_size, font)
+                .width
+        })
+        .fold(0.0, f64::max);
+
+    let left = padding.left() + label_width + CHART_SPACING;
+    let top = top + line_height / 2.0;
+    let bottom = height - padding.bottom() - line_height - CHART_SPACING / 2.0;
+
+    ChartLayout {
+        plot: Rectangle::new(
+            (left, top),
+            (
+                (width - padding.right() - left).max(0.0),
+                (bottom - top).max(0.0),
+            ),
+        ),
+        scale,
+        line_height,
+        legend_y,
+    }
+}
+
+/// Gets the x position of the category with the given index relative to the bounds.
+pub fn category_x(kind: ChartKind, layout: &ChartLayout, count: usize, index: usize) -> f64 {
+    let plot = layout.plot;
+
+    match kind {
+        ChartKind::Line if count > 1 => plot.x() + index as f64 / (count - 1) as f64 * plot.width(),
+        _ => plot.x() + (index as f64 + 0.5) * plot.width() / count.max(1) as f64,
+    }
+}
+
+/// Gets the y position of the value relative to the bounds.
+pub fn value_y(layout: &ChartLayout, value: f64) -> f64 {
+    let position = layout.scale.position(value).clamp(0.0, 1.0);
+    layout.plot.y() + layout.plot.height() * (1.0 - position)
+}
+
+/// Gets the points of the finite values of each series of a line chart.
+pub fn line_chart_points(data: &ChartData, layout: &ChartLayout) -> Vec<Vec<(usize, Point)>> {
+    let count = data.len();
+
+    data.series
+        .iter()
+        .map(|series| {
+            series
+                .values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| value.is_finite())
+                .map(|(index, value)| {
+                    (
+                        index,
+                        Point::new(
+                            category_x(ChartKind::Line, layout, count, index),
+                            value_y(layout, *value),
+                        ),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Gets the bars of a bar chart with the index of their series and category.
+pub fn bar_chart_bars(data: &ChartData, layout: &ChartLayout) -> Vec<(usize, usize, Rectangle)> {
+    let plot = layout.plot;
+    let count = data.len();
+    let series_count = data.series.len();
+
+    if count == 0 || series_count == 0 {
+        return vec![];
+    }
+
+    // 20 percent of each category is the gap between the groups
+    let group_width = plot.width() / count as f64;
+    let bar_width = group_width * 0.8 / series_count as f64;
+    let zero_y = value_y(layout, 0.0);
+    let mut bars = vec![];
+
+    for (series_index, series) in data.series.iter().enumerate() {
+        for (index, value) in series.values.iter().enumerate() {
+            if !value.is_finite() {
+                continue;
+            }
+
+            let y = value_y(layout, *value);
+            bars.push((
+                series_index,
+                index,
+                Rectangle::new(
+                    (
+                        plot.x()
+                            + index as f64 * group_width
+                            + group_width * 0.1
+                            + series_index as f64 * bar_width,
+                        y.min(zero_y),
+                    ),
+                    (bar_width, (y - zero_y).abs()),
+                ),
+            ));
+        }
+    }
+
+    bars
+}
+
+/// Gets the center, the radius and the slices of a pie chart. The slices contain the index of their category and
+/// their start and end angle. Only positive values of the first series are drawn.
+pub fn pie_chart_slices(
+    data: &ChartData,
+    layout: &ChartLayout,
+) -> (Point, f64, Vec<(usize, f64, f64)>) {
+    let plot = layout.plot;
+    let center = Point::new(
+        plot.x() + plot.width() / 2.0,
+        plot.y() + plot.height() / 2.0,
+    );
+    let radius = plot.width().min(plot.height()) / 2.0;
+    let values: Vec<f64> = data
+        .series
+        .first()
+        .map(|series| series.values.clone())
+        .unwrap_or_default();
+    let total: f64 = values
+        .iter()
+        .filter(|value| value.is_finite() && **value > 0.0)
+        .sum();
+
+    if total <= 0.0 {
+        return (center, radius, vec![]);
+    }
+
+    // starts at the top and runs clockwise
+    let mut angle = -PI / 2.0;
+    let mut slices = vec![];
+
+    for (index, value) in values.iter().enumerate() {
+        if !value.is_finite() || *value <= 0.0 {
+            continue;
+        }
+
+        let end = angle + value / total * 2.0 * PI;
+        slices.push((index, angle, end));
+        angle = end;
+    }
+
+    (center, radius, slices)
+}
+
+/// Gets the data point of the chart at the given position relative to the bounds.
+pub fn chart_hit(
+    kind: ChartKind,
+    data: &ChartData,
+    layout: &ChartLayout,
+    position: Point,
+) -> Option<ChartHit> {
+    match kind {
+        ChartKind::Line => {
+            // the nearest point inside of a small radius
+            let mut hit = None;
+            let mut distance = (POINT_RADIUS * 3.0).powi(2);
+
+            for (series, points) in line_chart_points(data, layout).iter().enumerate() {
+                for (index, point) in points {
+                    let d = (point.x() - position.x()).powi(2) + (point.y() - position.y()).powi(2);
+
+                    if d <= distance {
+                        distance = d;
+                        hit = Some(ChartHit {
+                            series,
+                            index: *index,
+                            position: *point,
+                        });
+                    }
+                }
+            }
+
+            hit
+        }
+        ChartKind::Bar => bar_chart_bars(data, layout)
+            .into_iter()
+            .find(|(_, _, rect)| rect.contains(position))
+            .map(|(series, index, rect)| ChartHit {
+                series,
+                index,
+                position: Point::new(rect.x() + rect.width() / 2.0, rect.y()),
+            }),
+        ChartKind::Pie => {
+            let (center, radius, slices) = pie_chart_slices(data, layout);
+            let dx = position.x() - center.x();
+            let dy = position.y() - center.y();
+
+            if dx * dx + dy * dy > radius * radius {
+                return None;
+            }
+
+            // the angle in the range of the slices
+            let mut angle = dy.atan2(dx);
+
+            if angle < -PI / 2.0 {
+                angle += 2.0 * PI;
+            }
+
+            slices
+                .iter()
+                .find(|(_, start, end)| angle >= *start && angle < *end)
+                .map(|(index, _, _)| ChartHit {
+                    series: 0,
+                    index: *index,
+                    position,
+                })
+        }
+    }
+}
+
+/// Gets the tool tip text of a data point, e.g. `Income, Feb: 18.5`.
+pub fn chart_hit_text(data: &ChartData, hit: &ChartHit) -> String {
+    let value = data
+        .series
+        .get(hit.series)
+        .and_then(|series| series.values.get(hit.index))
+        .copied()
+        .unwrap_or_default();
+    let name = data
+        .series
+        .get(hit.series)
+        .map(|series| series.name.as_str())
+        .unwrap_or_default();
+
+    if name.is_empty() {
+        format!("{}: {}", data.label(hit.index), value)
+    } else {
+        format!("{}, {}: {}", name, data.label(hit.index), value)
+    }
+}
+
+/// Used to render a line chart.
+pub struct LineChartRenderObject;
+
+impl Into<Box<dyn RenderObject>> for LineChartRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for LineChartRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        render_chart(ctx, global_position, ChartKind::Line);
+    }
+}
+
+/// Used to render a bar chart.
+pub struct BarChartRenderObject;
+
+impl Into<Box<dyn RenderObject>> for BarChartRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for BarChartRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        render_chart(ctx, global_position, ChartKind::Bar);
+    }
+}
+
+/// Used to render a pie chart.
+pub struct PieChartRenderObject;
+
+impl Into<Box<dyn RenderObject>> for PieChartRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for PieChartRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        render_chart(ctx, global_position, ChartKind::Pie);
+    }
+}
+
+// --- Helpers --
+
+// The brushes and the font of a chart.
+struct ChartStyle {
+    foreground: Brush,
+    background: Brush,
+    border_brush: Brush,
+    tooltip_background: Brush,
+    font: String,
+    font_size: f64,
+}
+
+// Gets the entries of the legend, the series of line and bar charts or the categories of pie charts.
+fn legend(data: &ChartData, kind: ChartKind) -> Vec<(String, Brush)> {
+    if kind == ChartKind::Pie {
+        return (0..data.len())
+            .filter(|index| data.labels.get(*index).is_some())
+            .map(|index| (data.label(index), data.category_brush(index)))
+            .collect();
+    }
+
+    data.series
+        .iter()
+        .enumerate()
+        .filter(|(_, series)| !series.name.is_empty())
+        .map(|(index, series)| (series.name.clone(), data.series_brush(index)))
+        .collect()
+}
+
+fn render_chart(ctx: &mut Context, global_position: &Point, kind: ChartKind) {
+    let (bounds, padding, data, style, hover_text, hover_position) = {
+        let widget = ctx.widget();
+        (
+            *widget.get::<Rectangle>("bounds"),
+            widget.clone_or_default::<Thickness>("padding"),
+            widget.clone_or_default::<ChartData>("data"),
+            ChartStyle {
+                foreground: widget.clone_or_default::<Brush>("foreground"),
+                background: widget.clone_or_default::<Brush>("background"),
+                border_brush: widget.clone_or_default::<Brush>("border_brush"),
+                tooltip_background: widget.clone_or_default::<Brush>("tooltip_background"),
+                font: widget.clone_or_default::<String>("font"),
+                font_size: widget.clone_or_default::<f64>("font_size"),
+            },
+            widget.clone_or_default::<String>("hover_text"),
+            widget.clone_or_default::<Point>("hover_position"),
+        )
+    };
+
+    if bounds.width() == 0.0 || bounds.height() == 0.0 {
+        return;
+    }
+
+    let render_context_2_d = ctx.render_context_2_d();
+    let layout = chart_layout(
+        render_context_2_d,
+        kind,
+        &data,
+        (bounds.width(), bounds.height()),
+        padding,
+        style.font.as_str(),
+        style.font_size,
+    );
+    let origin = Point::new(
+        global_position.x() + bounds.x(),
+        global_position.y() + bounds.y(),
+    );
+
+    if !style.background.is_transparent() {
+        render_context_2_d.set_fill_style(style.background.clone());
+        render_context_2_d.fill_rect(origin.x(), origin.y(), bounds.width(), bounds.height());
+    }
+
+    render_context_2_d.set_font_family(style.font.as_str());
+    render_context_2_d.set_font_size(style.font_size);
+
+    render_legend(
+        render_context_2_d,
+        origin,
+        &layout,
+        &style,
+        &legend(&data, kind),
+    );
+
+    match kind {
+        ChartKind::Line => {
+            render_axes(render_context_2_d, origin, &layout, &style, &data, kind);
+
+            for (series, points) in line_chart_points(&data, &layout).iter().enumerate() {
+                let brush = data.series_brush(series);
+
+                render_context_2_d.begin_path();
+
+                for (i, (_, point)) in points.iter().enumerate() {
+                    if i == 0 {
+                        render_context_2_d.move_to(origin.x() + point.x(), origin.y() + point.y());
+                    } else {
+                        render_context_2_d.line_to(origin.x() + point.x(), origin.y() + point.y());
+                    }
+                }
+
+                render_context_2_d.set_line_width(2.0);
+                render_context_2_d.set_stroke_style(brush.clone());
+                render_context_2_d.stroke();
+                render_context_2_d.close_path();
+
+                render_context_2_d.set_fill_style(brush);
+
+                for (_, point) in points {
+                    render_context_2_d.begin_path();
+                    render_context_2_d.arc(
+                        origin.x() + point.x(),
+                        origin.y() + point.y(),
+                        POINT_RADIUS,
+                        0.0,
+                        2.0 * PI,
+                    );
+                    render_context_2_d.fill();
+                    render_context_2_d.close_path();
+                }
+            }
+        }
+        ChartKind::Bar => {
+            render_axes(render_context_2_d, origin, &layout, &style, &data, kind);
+
+            for (series, _, rect) in bar_chart_bars(&data, &layout) {
+                render_context_2_d.set_fill_style(data.series_brush(series));
+                render_context_2_d.fill_rect(
+                    origin.x() + rect.x(),
+                    origin.y() + rect.y(),
+                    rect.width(),
+                    rect.height(),
+                );
+            }
+        }
+        ChartKind::Pie => {
+            let (center, radius, slices) = pie_chart_slices(&data, &layout);
+
+            for (index, start, end) in slices {
+                render_context_2_d.begin_path();
+                render_context_2_d.move_to(origin.x() + center.x(), origin.y() + center.y());
+                render_context_2_d.arc(
+                    origin.x() + center.x(),
+                    origin.y() + center.y(),
+                    radius,
+                    start,
+                    end,
+                );
+                render_context_2_d.close_path();
+                render_context_2_d.set_fill_style(data.category_brush(index));
+                render_context_2_d.fill();
+            }
+        }
+    }
+
+    if hover_text.is_empty() {
+        return;
+    }
+
+    // the tool tip of the hovered data point
+    let text_width = render_context_2_d
+        .measure(&hover_text, style.font_size, style.font.as_str())
+        .width;
+    let width = text_width + 2.0 * CHART_SPACING;
+    let height = layout.line_height + CHART_SPACING;
+    let x = (hover_position.x() + CHART_SPACING)
+        .min(bounds.width() - width)
+        .max(0.0);
+    let y = (hover_position.y() - height - CHART_SPACING).max(0.0);
+
+    render_context_2_d.set_fill_style(style.tooltip_background);
+    render_context_2_d.fill_rect(origin.x() + x, origin.y() + y, width, height);
+    render_context_2_d.begin_path();
+    render_context_2_d.set_fill_style(style.foreground);
+    render_context_2_d.fill_text(
+        &hover_text,
+        origin.x() + x + CHART_SPACING,
+        origin.y() + y + CHART_SPACING / 2.0,
+    );
+    render_context_2_d.close_path();
+}
+
+// Draws the color boxes and the names of the legend in one row.
+fn render_legend(
+    render_context_2_d: &mut RenderContext2D,
+    origin: Point,
+    layout: &ChartLayout,
+    style: &ChartStyle,
+    legend: &[(String, Brush)],
+) {
+    let mut x = origin.x() + layout.plot.x().min(CHART_SPACING);
+    let y = origin.y() + layout.legend_y;
+
+    for (name, brush) in legend {
+        render_context_2_d.set_fill_style(brush.clone());
+        render_context_2_d.fill_rect(
+            x,
+            y + (layout.line_height - LEGEND_BOX_SIZE) / 2.0,
+            LEGEND_BOX_SIZE,
+            LEGEND_BOX_SIZE,
+        );
+        x += LEGEND_BOX_SIZE + CHART_SPACING / 2.0;
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_fill_style(style.foreground.clone());
+        render_context_2_d.fill_text(name, x, y);
+        render_context_2_d.close_path();
+        x += render_context_2_d
+            .measure(name, style.font_size, style.font.as_str())
+            .width
+            + 2.0 * CHART_SPACING;
+    }
+}
+
+// Draws the grid lines with the labels of the value axis and the labels of the categories.
+fn render_axes(
+    render_context_2_d: &mut RenderContext2D,
+    origin: Point,
+    layout: &ChartLayout,
+    style: &ChartStyle,
+    data: &ChartData,
+    kind: ChartKind,
+) {
+    let plot = layout.plot;
+    let scale_factor = render_context_2_d.scale_factor();
+
+    render_context_2_d.begin_path();
+
+    for tick in layout.scale.ticks() {
+        let y = snap_line(origin.y() + value_y(layout, tick), 1.0, scale_factor);
+        render_context_2_d.move_to(origin.x() + plot.x(), y);
+        render_context_2_d.line_to(origin.x() + plot.x() + plot.width(), y);
+    }
+
+    render_context_2_d.set_line_width(1.0);
+    render_context_2_d.set_stroke_style(style.border_brush.clone());
+    render_context_2_d.stroke();
+    render_context_2_d.close_path();
+
+    render_context_2_d.set_fill_style(style.foreground.clone());
+
+    for tick in layout.scale.ticks() {
+        let label = layout.scale.format(tick);
+        let width = render_context_2_d
+            .measure(&label, style.font_size, style.font.as_str())
+            .width;
+        render_context_2_d.begin_path();
+        render_context_2_d.fill_text(
+            &label,
+            origin.x() + plot.x() - CHART_SPACING - width,
+            origin.y() + value_y(layout, tick) - layout.line_height / 2.0,
+        );
+        render_context_2_d.close_path();
+    }
+
+    let count = data.len();
+
+    if count == 0 {
+        return;
+    }
+
+    // labels that do not fit are skipped
+    let label_width = (0..count)
+        .map(|index| {
+            render_context_2_d
+                .measure(&data.label(index), style.font_size, style.font.as_str())
+                .width
+        })
+        .fold(0.0, f64::max)
+        + CHART_SPACING;
+    let skip = ((label_width * count as f64 / plot.width().max(1.0)).ceil() as usize).max(1);
+
+    for index in (0..count).step_by(skip) {
+        let label = data.label(index);
+        let width = render_context_2_d
+            .measure(&label, style.font_size, style.font.as_str())
+            .width;
+        render_context_2_d.begin_path();
+        render_context_2_d.fill_text(
+            &label,
+            origin.x() + category_x(kind, layout, count, index) - width / 2.0,
+            origin.y() + plot.y() + plot.height() + CHART_SPACING / 2.0,
+        );
+        render_context_2_d.close_path();
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> ChartLayout {
+        ChartLayout {
+            plot: Rectangle::new((0.0, 0.0), (100.0, 100.0)),
+            scale: ChartScale {
+                min: -10.0,
+                max: 10.0,
+                step: 5.0,
+            },
+            line_height: 12.0,
+            legend_y: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_bar_chart_bars() {
+        let data = ChartData::from(vec![5.0, -10.0]);
+        let bars = bar_chart_bars(&data, &layout());
+
+        assert_eq!(
+            bars,
+            vec![
+                (0, 0, Rectangle::new((5.0, 25.0), (40.0, 25.0))),
+                (0, 1, Rectangle::new((55.0, 50.0), (40.0, 50.0))),
+            ]
+        );
+        assert_eq!(
+            chart_hit(ChartKind::Bar, &data, &layout(), Point::new(60.0, 70.0))
+                .map(|hit| hit.index),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_pie_chart_hit() {
+        let data = ChartData::from(vec![("a", 1.0), ("b", 3.0), ("c", -1.0)]);
+        let (center, radius, slices) = pie_chart_slices(&data, &layout());
+
+        assert_eq!(center, Point::new(50.0, 50.0));
+        assert_eq!(radius, 50.0);
+        assert_eq!(slices.len(), 2);
+
+        // the first slice is the top right quarter
+        let hit = |x, y| {
+            chart_hit(ChartKind::Pie, &data, &layout(), Point::new(x, y)).map(|hit| hit.index)
+        };
+        assert_eq!(hit(70.0, 30.0), Some(0));
+        assert_eq!(hit(30.0, 30.0), Some(1));
+        assert_eq!(hit(30.0, 70.0), Some(1));
+        assert_eq!(hit(99.0, 99.0), None);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{
+    properties::TextSelection,
+    render::RenderContext2D,
+    render_object::*,
+    utils::{Brush, Point, Rectangle, String16, SyntaxToken, Thickness},
+    widget_base::WidgetContainer,
+};
+
+/// The space between the line numbers and the borders of the gutter.
+pub const CODE_GUTTER_PADDING: f64 = 8.0;
+
+/// Describes the positions of the lines of a code editor relative to its bounds.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CodeLayout {
+    /// The height of a line.
+    pub line_height: f64,
+
+    /// The width of the gutter with the line numbers.
+    pub gutter_width: f64,
+
+    /// The x position of the text, moved by the horizontal scroll offset.
+    pub text_x: f64,
+
+    /// The y position of the first line, moved by the vertical scroll offset.
+    pub text_y: f64,
+}
+
+impl CodeLayout {
+    /// Gets the index of the line at the given y position relative to the bounds.
+    pub fn line_at(&self, y: f64, line_count: usize) -> usize {
+        if self.line_height <= 0.0 || y < self.text_y {
+            return 0;
+        }
+
+        (((y - self.text_y) / self.line_height) as usize).min(line_count.saturating_sub(1))
+    }
+
+    /// Gets the y position of the line with the given index relative to the bounds.
+    pub fn line_y(&self, line: usize) -> f64 {
+        self.text_y + line as f64 * self.line_height
+    }
+}
+
+/// The properties of a code editor that define the positions of its lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CodeStyle {
+    /// The font family of the text.
+    pub font: String,
+
+    /// The font size of the text.
+    pub font_size: f64,
+
+    /// The space around the text.
+    pub padding: Thickness,
+
+    /// If `true` the line numbers are drawn in a gutter.
+    pub show_line_numbers: bool,
+
+    /// The scroll offset of the text.
+    pub scroll_offset: Point,
+}
+
+impl CodeStyle {
+    /// Calculates the `CodeLayout` of a text with the given number of lines.
+    pub fn layout(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        line_count: usize,
+    ) -> CodeLayout {
+        let line_height = render_context_2_d
+            .measure("Ag", self.font_size, self.font.as_str())
+            .height
+            .max(self.font_size);
+
+        let gutter_width = if self.show_line_numbers {
+            // the gutter is as wide as the widest line number
+            let digits = "0".repeat(line_count.max(1).to_string().len());
+            render_context_2_d
+                .measure(&digits, self.font_size, self.font.as_str())
+                .width
+                + 2.0 * CODE_GUTTER_PADDING
+        } else {
+            0.0
+        };
+
+        CodeLayout {
+            line_height,
+            gutter_width,
+            text_x: gutter_width + self.padding.left() - self.scroll_offset.x(),
+            text_y: self.padding.top() - self.scroll_offset.y(),
+        }
+    }
+
+    /// Measures the width of the given text.
+    pub fn measure(&self, render_context_2_d: &mut RenderContext2D, text: &str) -> f64 {
+        render_context_2_d
+            .measure(text, self.font_size, self.font.as_str())
+            .width
+    }
+}
+
+/// Used to render a code editor. It draws the `text` line by line with the brushes of the `syntax_tokens`, the
+/// line numbers in a gutter, the `text_selection` and the caret.
+pub struct CodeRenderObject;
+
+impl Into<Box<dyn RenderObject>> for CodeRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for CodeRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, text, tokens, style, selection, caret, focused) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.get::<String16>("text").as_string(),
+                widget.clone_or_default::<Vec<SyntaxToken>>("syntax_tokens"),
+                code_style(&widget),
+                widget.clone_or_default::<TextSelection>("text_selection"),
+                widget.clone_or_default::<usize>("caret"),
+                widget.clone_or_default::<bool>("focused"),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 || style.font_size == 0.0 {
+            return;
+        }
+
+        let mut brush = |key: &str| ctx.widget().try_clone::<Brush>(key).unwrap_or_default();
+        let background = brush("background");
+        let gutter_background = brush("gutter_background");
+        let line_number_foreground = brush("line_number_foreground");
+        let selection_brush = brush("selection_brush");
+        let foreground = brush("foreground");
+        let token_brushes: Vec<Brush> = tokens
+            .iter()
+            .map(|token| {
+                ctx.widget()
+                    .try_clone::<Brush>(token.kind.brush_key())
+                    .unwrap_or_else(|| foreground.clone())
+            })
+            .collect();
+
+        let lines = code_lines(&text);
+        let layout = style.layout(ctx.render_context_2_d(), lines.len());
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+        let render_context_2_d = ctx.render_context_2_d();
+
+        render_context_2_d.set_fill_style(background);
+        render_context_2_d.fill_rect(x, y, bounds.width(), bounds.height());
+
+        let first_line = layout.line_at(0.0, lines.len());
+        let last_line = layout.line_at(bounds.height(), lines.len());
+        render_context_2_d.set_font_family(style.font.as_str());
+        render_context_2_d.set_font_size(style.font_size);
+
+        // text area
+        render_context_2_d.save();
+        render_context_2_d.rect(
+            x + layout.gutter_width,
+            y,
+            (bounds.width() - layout.gutter_width).max(0.0),
+            bounds.height(),
+        );
+        render_context_2_d.clip();
+
+        let mut measure = |text: &str| style.measure(render_context_2_d, text);
+        let space_width = measure(" ");
+        let mut selection_rects = vec![];
+        let mut caret_rect = None;
+        let mut texts = vec![];
+        let selection_end = selection.start_index + selection.length;
+        let mut token = tokens
+            .iter()
+            .position(|token| token.end > lines[first_line].0)
+            .unwrap_or(tokens.len());
+
+        for (line, (start, line_text)) in lines
+            .iter()
+            .enumerate()
+            .take(last_line + 1)
+            .skip(first_line)
+        {
+            let line_y = y + layout.line_y(line);
+            let line_x = x + layout.text_x;
+            let chars: Vec<char> = line_text.chars().collect();
+            let end = start + chars.len();
+            let mut column_x = |column: usize| {
+                let prefix: String = chars[..column.min(chars.len())].iter().collect();
+                measure(&prefix)
+            };
+
+            if selection.length > 0 && selection.start_index <= end && selection_end > *start {
+                let from = column_x(selection.start_index.saturating_sub(*start));
+                let mut to = column_x(selection_end - start);
+
+                // the selected line break
+                if selection_end > end {
+                    to += space_width;
+                }
+
+                selection_rects.push(Rectangle::new(
+                    (line_x + from, line_y),
+                    (to - from, layout.line_height),
+                ));
+            }
+
+            if focused && caret >= *start && caret <= end {
+                caret_rect = Some(Rectangle::new(
+                    (line_x + column_x(caret - start), line_y),
+                    (1.0, layout.line_height),
+                ));
+            }
+
+            // splits the line into plain text and tokens
+            let mut column = 0;
+
+            while column < chars.len() {
+                let index = start + column;
+
+                while token < tokens.len() && tokens[token].end <= index {
+                    token += 1;
+                }
+
+                let (to, brush) = match tokens.get(token) {
+                    Some(t) if t.start <= index => (t.end - start, token_brushes[token].clone()),
+                    Some(t) => (t.start - start, foreground.clone()),
+                    None => (chars.len(), foreground.clone()),
+                };
+                let to = to.min(chars.len());
+                let segment: String = chars[column..to].iter().collect();
+
+                texts.push((segment, line_x + column_x(column), line_y, brush));
+                column = to;
+            }
+        }
+
+        render_context_2_d.set_fill_style(selection_brush);
+
+        for rect in selection_rects {
+            render_context_2_d.fill_rect(rect.x(), rect.y(), rect.width(), rect.height());
+        }
+
+        for (segment, text_x, text_y, brush) in texts {
+            if segment.trim().is_empty() || brush.is_transparent() {
+                continue;
+            }
+
+            render_context_2_d.begin_path();
+            render_context_2_d.set_fill_style(brush);
+            render_context_2_d.fill_text(&segment, text_x, text_y);
+            render_context_2_d.close_path();
+        }
+
+        if let Some(rect) = caret_rect {
+            render_context_2_d.set_fill_style(foreground);
+            render_context_2_d.fill_rect(rect.x(), rect.y(), rect.width(), rect.height());
+        }
+
+        render_context_2_d.restore();
+
+        if layout.gutter_width == 0.0 {
+            return;
+        }
+
+        // gutter with right aligned line numbers
+        render_context_2_d.set_fill_style(gutter_background);
+        render_context_2_d.fill_rect(x, y, layout.gutter_width, bounds.height());
+        render_context_2_d.save();
+        render_context_2_d.rect(x, y, layout.gutter_width, bounds.height());
+        render_context_2_d.clip();
+        render_context_2_d.set_fill_style(line_number_foreground);
+
+        for line in first_line..=last_line.min(lines.len() - 1) {
+            let number = (line + 1).to_string();
+            let width = style.measure(render_context_2_d, &number);
+            render_context_2_d.begin_path();
+            render_context_2_d.fill_text(
+                &number,
+                x + layout.gutter_width - CODE_GUTTER_PADDING - width,
+                y + layout.line_y(line),
+            );
+            render_context_2_d.close_path();
+        }
+
+        render_context_2_d.restore();
+    }
+}
+
+/// Reads the `CodeStyle` from the `font`, `font_size`, `padding`, `show_line_numbers` and `scroll_offset`
+/// properties of the widget.
+pub fn code_style(widget: &WidgetContainer<'_>) -> CodeStyle {
+    CodeStyle {
+        font: widget.clone_or_default::<String>("font"),
+        font_size: widget.clone_or_default::<f64>("font_size"),
+        padding: widget.clone_or_default::<Thickness>("padding"),
+        show_line_numbers: widget.clone_or_default::<bool>("show_line_numbers"),
+        scroll_offset: widget.clone_or_default::<Point>("scroll_offset"),
+    }
+}
+
+/// Splits a text into its lines. Returns the index of the first character and the text of each line.
+pub fn code_lines(text: &str) -> Vec<(usize, &str)> {
+    let mut lines = vec![];
+    let mut start = 0;
+
+    for line in text.split('\n') {
+        lines.push((start, line));
+        start += line.chars().count() + 1;
+    }
+
+    lines
+}
@@ -0,0 +1,214 @@
+use crate::{
+    render::RenderContext2D,
+    render_object::*,
+    utils::{Brush, GaugeZone, Point, Rectangle, Thickness},
+};
+
+/// The space between the track and the color zones of a gauge.
+const ZONE_SPACING: f64 = 2.0;
+
+/// Gets the angle in radians of the value on a gauge that starts at `start_angle` and sweeps clockwise by
+/// `sweep_angle`, both in degrees. Values outside of `min` and `max` are clamped.
+pub fn gauge_angle(val: f64, min: f64, max: f64, start_angle: f64, sweep_angle: f64) -> f64 {
+    let progress = if max > min {
+        ((val - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    (start_angle + sweep_angle * progress).to_radians()
+}
+
+/// Used to render a gauge. It draws the track arc from `min` to `max`, the color `zones` and the `val` either as
+/// filled arc or as needle.
+pub struct GaugeRenderObject;
+
+impl Into<Box<dyn RenderObject>> for GaugeRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for GaugeRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, padding, val, min, max, start_angle, sweep_angle, thickness) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.clone_or_default::<Thickness>("padding"),
+                widget.clone_or_default::<f64>("val"),
+                widget.clone_or_default::<f64>("min"),
+                widget.clone_or_default::<f64>("max"),
+                widget.clone_or_default::<f64>("start_angle"),
+                widget.clone_or_default::<f64>("sweep_angle"),
+                widget.clone_or_default::<f64>("thickness"),
+            )
+        };
+        let (needle, show_value, decimals, zones, font, font_size) = {
+            let widget = ctx.widget();
+            (
+                widget.clone_or_default::<bool>("needle"),
+                widget.clone_or_default::<bool>("show_value"),
+                widget.clone_or_default::<usize>("decimals"),
+                widget.clone_or_default::<Vec<GaugeZone>>("zones"),
+                widget.clone_or_default::<String>("font"),
+                widget.clone_or_default::<f64>("font_size"),
+            )
+        };
+        let (foreground, track_brush, indicator_brush) = {
+            let widget = ctx.widget();
+            (
+                widget.clone_or_default::<Brush>("foreground"),
+                widget.clone_or_default::<Brush>("track_brush"),
+                widget.clone_or_default::<Brush>("indicator_brush"),
+            )
+        };
+
+        let width = bounds.width() - padding.left() - padding.right();
+        let height = bounds.height() - padding.top() - padding.bottom();
+        let radius = (width.min(height) - thickness) / 2.0;
+
+        if radius <= 0.0 {
+            return;
+        }
+
+        let center = Point::new(
+            global_position.x() + bounds.x() + padding.left() + width / 2.0,
+            global_position.y() + bounds.y() + padding.top() + height / 2.0,
+        );
+        let angle = |value: f64| gauge_angle(value, min, max, start_angle, sweep_angle);
+        let render_context_2_d = ctx.render_context_2_d();
+
+        stroke_arc(
+            render_context_2_d,
+            center,
+            radius,
+            (angle(min), angle(max)),
+            thickness,
+            track_brush,
+        );
+
+        // the zones are drawn as thin band inside of the track
+        let zone_width = (thickness / 3.0).max(2.0);
+        let zone_radius = radius - thickness / 2.0 - ZONE_SPACING - zone_width / 2.0;
+
+        if zone_radius > 0.0 {
+            for zone in &zones {
+                stroke_arc(
+                    render_context_2_d,
+                    center,
+                    zone_radius,
+                    (angle(zone.from), angle(zone.to)),
+                    zone_width,
+                    zone.brush.clone(),
+                );
+            }
+        }
+
+        // the indicator takes the brush of the zone of the value
+        let indicator_brush = zones
+            .iter()
+            .rev()
+            .find(|zone| zone.contains(val))
+            .map_or(indicator_brush, |zone| zone.brush.clone());
+
+        if needle {
+            let length = radius - thickness / 2.0;
+            render_context_2_d.begin_path();
+            render_context_2_d.move_to(center.x(), center.y());
+            render_context_2_d.line_to(
+                center.x() + length * angle(val).cos(),
+                center.y() + length * angle(val).sin(),
+            );
+            render_context_2_d.set_line_width((thickness / 4.0).max(2.0));
+            render_context_2_d.set_stroke_style(indicator_brush.clone());
+            render_context_2_d.stroke();
+            render_context_2_d.close_path();
+
+            render_context_2_d.begin_path();
+            render_context_2_d.arc(
+                center.x(),
+                center.y(),
+                (thickness / 2.0).max(4.0),
+                0.0,
+                2.0 * std::f64::consts::PI,
+            );
+            render_context_2_d.set_fill_style(indicator_brush);
+            render_context_2_d.fill();
+            render_context_2_d.close_path();
+        } else {
+            stroke_arc(
+                render_context_2_d,
+                center,
+                radius,
+                (angle(min), angle(val)),
+                thickness,
+                indicator_brush,
+            );
+        }
+
+        if !show_value || font_size == 0.0 {
+            return;
+        }
+
+        let text = format!("{:.*}", decimals, val);
+        let metrics = render_context_2_d.measure(&text, font_size, font.as_str());
+
+        // below the needle hub or in the center of the arc
+        let y = if needle {
+            center.y() + radius / 2.0 - metrics.height / 2.0
+        } else {
+            center.y() - metrics.height / 2.0
+        };
+
+        render_context_2_d.begin_path();
+        render_context_2_d.set_font_family(font.as_str());
+        render_context_2_d.set_font_size(font_size);
+        render_context_2_d.set_fill_style(foreground);
+        render_context_2_d.fill_text(&text, center.x() - metrics.width / 2.0, y);
+        render_context_2_d.close_path();
+    }
+}
+
+// --- Helpers --
+
+// Strokes the arc between both angles, the arc always runs clockwise from the smaller angle.
+fn stroke_arc(
+    render_context_2_d: &mut RenderContext2D,
+    center: Point,
+    radius: f64,
+    angles: (f64, f64),
+    width: f64,
+    brush: Brush,
+) {
+    let (start, end) = (angles.0.min(angles.1), angles.0.max(angles.1));
+
+    if end - start <= 0.0 || brush.is_transparent() {
+        return;
+    }
+
+    render_context_2_d.begin_path();
+    render_context_2_d.arc(center.x(), center.y(), radius, start, end);
+    render_context_2_d.set_line_width(width);
+    render_context_2_d.set_stroke_style(brush);
+    render_context_2_d.stroke();
+    render_context_2_d.close_path();
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_angle() {
+        let angle = |val| gauge_angle(val, 0.0, 100.0, 135.0, 270.0).to_degrees();
+
+        assert!((angle(0.0) - 135.0).abs() < 1e-9);
+        assert!((angle(50.0) - 270.0).abs() < 1e-9);
+        assert!((angle(150.0) - 405.0).abs() < 1e-9);
+        assert!((angle(-10.0) - 135.0).abs() < 1e-9);
+        assert!((gauge_angle(5.0, 1.0, 1.0, 90.0, 180.0).to_degrees() - 90.0).abs() < 1e-9);
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{render::Image, render_object::*};
+use crate::{
+    render::{Image, VectorImage},
+    render_object::*,
+};
 
-/// Used to render an image.
+/// Used to render an image. The image is resized by the `stretch` property and aligned inside of the bounds by the
+/// `image_h_align` and `image_v_align` properties.
+///
+/// A non empty `vector_image` is drawn instead of the `image`, it is scaled without losing quality.
 pub struct ImageRenderObject;
 
 impl Into<Box<dyn RenderObject>> for ImageRenderObject {
@@ -11,19 +17,64 @@ impl Into<Box<dyn RenderObject>> for ImageRenderObject {
 
 impl RenderObject for ImageRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, mut image) = {
+        let (bounds, mut image, vector_image, stretch, h_align, v_align) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.try_clone::<Image>("image"),
+                widget
+                    .try_clone::<VectorImage>("vector_image")
+                    .filter(|vector_image| !vector_image.is_empty()),
+                widget.try_clone::<Stretch>("stretch").unwrap_or_default(),
+                widget
+                    .try_clone::<Alignment>("image_h_align")
+                    .unwrap_or(Alignment::Start),
+                widget
+                    .try_clone::<Alignment>("image_v_align")
+                    .unwrap_or(Alignment::Start),
             )
         };
 
+        let x = bounds.x() + global_position.x();
+        let y = bounds.y() + global_position.y();
+
+        if let Some(vector_image) = vector_image {
+            let (width, height) = stretch.size(
+                (vector_image.width(), vector_image.height()),
+                (bounds.width(), bounds.height()),
+            );
+
+            vector_image.draw(
+                ctx.render_context_2_d(),
+                x + h_align.align_position(bounds.width(), width, 0.0, 0.0),
+                y + v_align.align_position(bounds.height(), height, 0.0, 0.0),
+                width,
+                height,
+            );
+            return;
+        }
+
         if let Some(image) = &mut image {
-            ctx.render_context_2_d().draw_image(
+            if stretch == Stretch::None {
+                ctx.render_context_2_d().draw_image(
+                    image,
+                    x + h_align.align_position(bounds.width(), image.width(), 0.0, 0.0),
+                    y + v_align.align_position(bounds.height(), image.height(), 0.0, 0.0),
+                );
+                return;
+            }
+
+            let (width, height) = stretch.size(
+                (image.width(), image.height()),
+                (bounds.width(), bounds.height()),
+            );
+
+            ctx.render_context_2_d().draw_image_with_size(
                 image,
-                bounds.x() + global_position.x(),
-                bounds.y() + global_position.y(),
+                x + h_align.align_position(bounds.width(), width, 0.0, 0.0),
+                y + v_align.align_position(bounds.height(), height, 0.0, 0.0),
+                width,
+                height,
             );
         }
     }
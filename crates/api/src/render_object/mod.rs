@@ -9,19 +9,31 @@ use crate::{
     utils::*,
 };
 
+pub use self::card::*;
+pub use self::chart::*;
+pub use self::code::*;
 pub use self::default::*;
 pub use self::font_icon::*;
+pub use self::gauge::*;
 pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
+pub use self::rich_text::*;
 pub use self::text::*;
+pub use self::tick_bar::*;
 
+mod card;
+mod chart;
+mod code;
 mod default;
 mod font_icon;
+mod gauge;
 mod image;
 mod pipeline;
 mod rectangle;
+mod rich_text;
 mod text;
+mod tick_bar;
 
 pub trait RenderObject: Any {
     fn render(
@@ -40,6 +52,21 @@ pub trait RenderObject: Any {
             if let Some(offset) = offsets.get(&parent) {
                 global_position = Point::new(offset.0, offset.1);
             }
+
+            // the widget is drawn at its mirrored position inside of a right to left parent
+            if flow_direction(ecm, parent) == FlowDirection::RightToLeft {
+                if let (Ok(bounds), Ok(parent_bounds)) = (
+                    ecm.component_store().get::<Rectangle>("bounds", entity),
+                    ecm.component_store().get::<Rectangle>("bounds", parent),
+                ) {
+                    let x = FlowDirection::RightToLeft.mirror_x(
+                        bounds.x(),
+                        bounds.width(),
+                        parent_bounds.width(),
+                    );
+                    global_position.set_x(global_position.x() + x - bounds.x());
+                }
+            }
         }
 
         if let Ok(visibility) = ecm
@@ -53,6 +80,18 @@ pub trait RenderObject: Any {
             return;
         }
 
+        // the transform of the widget is applied on top of the transforms of its parents
+        let position = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .map_or(global_position, |b| global_position + b.position());
+        let parent_transform = widget_transform(ecm, entity, position).map(|transform| {
+            let parent_transform = ecm.entity_store().parent[&entity]
+                .map_or_else(Transform::default, |parent| global_transform(ecm, parent));
+            set_transform(render_context, parent_transform.multiply(transform));
+            parent_transform
+        });
+
         render_context.begin_path();
         render_context.set_alpha(
             *ecm.component_store()
@@ -75,10 +114,21 @@ pub trait RenderObject: Any {
             }
         }
 
-        self.render_self(
-            &mut Context::new((entity, ecm), &theme, context_provider, render_context),
-            &global_position,
-        );
+        // widgets outside of the redrawn region keep their pixels of the last frame
+        let redraw = context_provider.dirty_region.get().map_or(true, |region| {
+            context_provider
+                .screen_bounds
+                .borrow()
+                .get(&entity)
+                .map_or(true, |bounds| bounds.intersects(&region))
+        });
+
+        if redraw {
+            self.render_self(
+                &mut Context::new((entity, ecm), &theme, context_provider, render_context),
+                &global_position,
+            );
+        }
 
         let mut global_pos = (0.0, 0.0);
 
@@ -128,6 +178,10 @@ pub trait RenderObject: Any {
                 render_context.close_path();
             }
         }
+
+        if let Some(parent_transform) = parent_transform {
+            set_transform(render_context, parent_transform);
+        }
     }
 
     fn render_self(&self, _: &mut Context, _: &Point) {}
@@ -142,9 +196,7 @@ pub trait RenderObject: Any {
         offsets: &mut BTreeMap<Entity, (f64, f64)>,
         debug: bool,
     ) {
-        for index in 0..ecm.entity_store().children[&entity].len() {
-            let child = ecm.entity_store().children[&entity][index];
-
+        for child in render_order(ecm, entity) {
             if let Some(render_object) = context_provider.render_objects.borrow().get(&child) {
                 render_object.render(
                     render_context,
@@ -159,3 +211,312 @@ pub trait RenderObject: Any {
         }
     }
 }
+
+/// Returns the flow direction of the given widget. It is inherited from the nearest parent with a flow direction.
+pub fn flow_direction(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> FlowDirection {
+    let mut current = Some(entity);
+
+    while let Some(entity) = current {
+        if let Ok(flow_direction) = ecm
+            .component_store()
+            .get::<FlowDirection>("flow_direction", entity)
+        {
+            return *flow_direction;
+        }
+
+        current = ecm.entity_store().parent[&entity];
+    }
+
+    FlowDirection::default()
+}
+
+/// Returns the combined render transform of the given widget and its parents. It maps the window coordinates of the
+/// untransformed widget to the coordinates on the screen.
+pub fn global_transform(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Transform {
+    let mut transform = Transform::default();
+    let mut current = Some(entity);
+
+    while let Some(entity) = current {
+        let position = ecm
+            .component_store()
+            .get::<Point>("position", entity)
+            .map_or_else(|_| Point::default(), |p| *p);
+
+        if let Some(widget_transform) = widget_transform(ecm, entity, position) {
+            transform = widget_transform.multiply(transform);
+        }
+
+        current = ecm.entity_store().parent[&entity];
+    }
+
+    transform
+}
+
+/// Checks if the given point on the screen is outside of the bounds of a parent of the widget that clips its
+/// children.
+pub fn is_clipped(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    point: Point,
+) -> bool {
+    let mut current = ecm.entity_store().parent[&entity];
+
+    while let Some(parent) = current {
+        let store = ecm.component_store();
+
+        if store.get::<bool>("clip", parent).map_or(false, |c| *c) {
+            if let (Ok(bounds), Ok(position)) = (
+                store.get::<Rectangle>("bounds", parent),
+                store.get::<Point>("position", parent),
+            ) {
+                let inside = global_transform(ecm, parent).invert().map_or(false, |t| {
+                    Rectangle::new(*position, bounds.size()).contains(t.apply(point))
+                });
+
+                if !inside {
+                    return true;
+                }
+            }
+        }
+
+        current = ecm.entity_store().parent[&parent];
+    }
+
+    false
+}
+
+/// Calculates the bounds on the screen of the given widget and all of its visible children, including their
+/// transforms and shadows. The bounds of the last frame are used to redraw only the changed regions of the window.
+pub fn screen_bounds(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> BTreeMap<Entity, Rectangle> {
+    let mut screen_bounds = BTreeMap::new();
+    collect_screen_bounds(
+        ecm,
+        entity,
+        Point::default(),
+        Transform::default(),
+        &mut screen_bounds,
+    );
+    screen_bounds
+}
+
+// Calculates the position of the widget like `RenderObject::render` and collects the bounds of the widget and its
+// children. The offset is the position of the parent in the window.
+fn collect_screen_bounds(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    offset: Point,
+    transform: Transform,
+    screen_bounds: &mut BTreeMap<Entity, Rectangle>,
+) {
+    let store = ecm.component_store();
+
+    if store
+        .get::<Visibility>("visibility", entity)
+        .map_or(true, |visibility| *visibility != Visibility::Visible)
+    {
+        return;
+    }
+
+    let bounds = match store.get::<Rectangle>("bounds", entity) {
+        Ok(bounds) => *bounds,
+        Err(_) => return,
+    };
+
+    let mut x = bounds.x();
+
+    if let Some(parent) = ecm.entity_store().parent[&entity] {
+        if flow_direction(ecm, parent) == FlowDirection::RightToLeft {
+            if let Ok(parent_bounds) = store.get::<Rectangle>("bounds", parent) {
+                x = FlowDirection::RightToLeft.mirror_x(
+                    bounds.x(),
+                    bounds.width(),
+                    parent_bounds.width(),
+                );
+            }
+        }
+    }
+
+    let position = Point::new(offset.x() + x, offset.y() + bounds.y());
+    let transform = widget_transform(ecm, entity, position).map_or(transform, |widget_transform| {
+        transform.multiply(widget_transform)
+    });
+
+    let mut rect = Rectangle::new(position, bounds.size());
+
+    if let Ok(box_shadow) = store.get::<BoxShadow>("box_shadow", entity) {
+        if !box_shadow.is_none() {
+            let shadow = box_shadow.rect(rect);
+            rect = rect.union(&Rectangle::new(
+                (shadow.x() - box_shadow.blur, shadow.y() - box_shadow.blur),
+                (
+                    shadow.width() + 2.0 * box_shadow.blur,
+                    shadow.height() + 2.0 * box_shadow.blur,
+                ),
+            ));
+        }
+    }
+
+    screen_bounds.insert(entity, transform_rect(transform, rect));
+
+    for child in ecm.entity_store().children[&entity].iter() {
+        collect_screen_bounds(ecm, *child, position, transform, screen_bounds);
+    }
+}
+
+// Returns the bounding box of the transformed rect.
+fn transform_rect(transform: Transform, rect: Rectangle) -> Rectangle {
+    if transform.is_identity() {
+        return rect;
+    }
+
+    let corners = [
+        transform.apply((rect.x(), rect.y())),
+        transform.apply((rect.x() + rect.width(), rect.y())),
+        transform.apply((rect.x(), rect.y() + rect.height())),
+        transform.apply((rect.x() + rect.width(), rect.y() + rect.height())),
+    ];
+
+    let left = corners.iter().map(|c| c.x()).fold(f64::INFINITY, f64::min);
+    let top = corners.iter().map(|c| c.y()).fold(f64::INFINITY, f64::min);
+    let right = corners
+        .iter()
+        .map(|c| c.x())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let bottom = corners
+        .iter()
+        .map(|c| c.y())
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    Rectangle::new((left, top), (right - left, bottom - top))
+}
+
+/// Returns the children of the given widget in the order they are drawn. Children with a greater `z_index` are drawn
+/// above children with a lower one, children with the same `z_index` keep the order of the tree.
+pub fn render_order(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Vec<Entity> {
+    let children = &ecm.entity_store().children[&entity];
+    let z_indices: Vec<i32> = children.iter().map(|child| z_index(ecm, *child)).collect();
+
+    z_order(&z_indices)
+        .into_iter()
+        .map(|index| children[index])
+        .collect()
+}
+
+/// Sorts the given widgets in the order they are drawn, the widget on top is the last one.
+pub fn sort_by_render_order(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    widgets: &mut Vec<Entity>,
+) {
+    widgets.sort_by_cached_key(|widget| render_path(ecm, *widget));
+}
+
+// Returns the z index and the index among its siblings of each widget on the path from the root to the given widget.
+// Comparing the paths gives the drawing order, a parent is drawn before its children.
+fn render_path(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> Vec<(i32, usize)> {
+    let mut path = vec![];
+    let mut current = entity;
+
+    while let Some(parent) = ecm.entity_store().parent[&current] {
+        let index = ecm.entity_store().children[&parent]
+            .iter()
+            .position(|child| *child == current)
+            .unwrap_or_default();
+
+        path.push((z_index(ecm, current), index));
+        current = parent;
+    }
+
+    path.reverse();
+    path
+}
+
+fn z_index(ecm: &EntityComponentManager<Tree, StringComponentStore>, entity: Entity) -> i32 {
+    ecm.component_store()
+        .get::<i32>("z_index", entity)
+        .map_or(0, |z| *z)
+}
+
+// Returns the indices of the children sorted by the given z indices. Children with the same z index keep their
+// order.
+fn z_order(z_indices: &[i32]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..z_indices.len()).collect();
+    order.sort_by_key(|index| z_indices[*index]);
+    order
+}
+
+// Returns the transform of the widget around its transform origin in window coordinates or `None` if the widget
+// is not transformed. The position is the untransformed window position of the widget.
+fn widget_transform(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    position: Point,
+) -> Option<Transform> {
+    let store = ecm.component_store();
+    let transform = store.get::<Transform>("transform", entity).ok()?;
+
+    if transform.is_identity() {
+        return None;
+    }
+
+    let size = store
+        .get::<Rectangle>("bounds", entity)
+        .map_or((0.0, 0.0), |b| (b.width(), b.height()));
+    let origin = store
+        .get::<Point>("transform_origin", entity)
+        .map_or_else(|_| Point::new(0.5, 0.5), |o| *o);
+
+    Some(transform.around((
+        position.x() + size.0 * origin.x(),
+        position.y() + size.1 * origin.y(),
+    )))
+}
+
+fn set_transform(render_context: &mut RenderContext2D, transform: Transform) {
+    render_context.set_transform(
+        transform.a,
+        transform.b,
+        transform.c,
+        transform.d,
+        transform.e,
+        transform.f,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transform_rect, z_order};
+    use crate::utils::*;
+
+    #[test]
+    fn test_z_order() {
+        assert_eq!(z_order(&[]), Vec::<usize>::new());
+        assert_eq!(z_order(&[0, 0, 0]), vec![0, 1, 2]);
+        assert_eq!(z_order(&[2, 0, 1]), vec![1, 2, 0]);
+        assert_eq!(z_order(&[1, -1, 1, 0]), vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_transform_rect() {
+        let rect = Rectangle::new((10.0, 10.0), (20.0, 10.0));
+        assert_eq!(transform_rect(Transform::default(), rect), rect);
+
+        let bounds = transform_rect(Transform::rotate(90.0).around((20.0, 15.0)), rect);
+        assert!((bounds.x() - 15.0).abs() < 1e-6 && (bounds.y() - 5.0).abs() < 1e-6);
+        assert!((bounds.width() - 10.0).abs() < 1e-6 && (bounds.height() - 20.0).abs() < 1e-6);
+    }
+}
@@ -1,15 +1,76 @@
 use std::f64::consts::PI;
 
 use crate::{
-    render::RenderContext2D,
+    render::{Image, RenderContext2D},
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{BorderRadius, BoxShadow, Brush, Point, Rectangle, Thickness},
 };
 
+/// Renders a rectangle with background, border and border radius, each corner can have its own radius. If the widget has a `border_image` it is drawn
+/// as nine-slice image instead, the `border_image_slice` defines the fixed corners of the image. The `box_shadow` of
+/// the widget is drawn below the rectangle.
 pub struct RectangleRenderObject;
 
 impl RectangleRenderObject {
+    // Draws the soft shadow of the box, the shadow is rounded by the greatest corner radius.
+    fn render_box_shadow(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, border_radius, box_shadow) = {
+            let widget = ctx.widget();
+
+            match widget.try_clone::<BoxShadow>("box_shadow") {
+                Some(box_shadow) if !box_shadow.is_none() => (
+                    widget.clone::<Rectangle>("bounds"),
+                    widget.clone_or_default::<BorderRadius>("border_radius"),
+                    box_shadow,
+                ),
+                _ => return,
+            }
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 {
+            return;
+        }
+
+        let rect = box_shadow.rect(Rectangle::new(
+            *global_position + bounds.position(),
+            bounds.size(),
+        ));
+
+        ctx.render_context_2_d().fill_shadow(
+            rect.x(),
+            rect.y(),
+            rect.width(),
+            rect.height(),
+            border_radius
+                .grow(box_shadow.spread)
+                .fit(rect.width(), rect.height())
+                .max(),
+            box_shadow.blur,
+            box_shadow.color,
+        );
+    }
+
+    // Draws the image in nine slices, the corners keep their size and the edges and the center are stretched.
+    fn render_border_image(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        rect: Rectangle,
+        image: &mut Image,
+        slice: Thickness,
+    ) {
+        for (source, target) in nine_slices((image.width(), image.height()), slice, rect) {
+            render_context_2_d.draw_image_with_clip_and_size(
+                image,
+                source,
+                target.x(),
+                target.y(),
+                target.width(),
+                target.height(),
+            );
+        }
+    }
+
     // Renders rectangle with border and without radius.
     fn render_bordered_rect_path(
         &self,
@@ -33,7 +94,7 @@ impl RectangleRenderObject {
         }
     }
 
-    // Builds rectangle path with radius and without border.
+    // Builds rectangle path with the radius of each corner and without border.
     fn render_rounded_rect_path(
         &self,
         render_context_2_d: &mut RenderContext2D,
@@ -41,19 +102,20 @@ impl RectangleRenderObject {
         y: f64,
         width: f64,
         height: f64,
-        radius: f64,
+        radius: BorderRadius,
     ) {
+        let radius = radius.fit(width, height);
         let r = x + width;
         let b = y + height;
-        render_context_2_d.move_to(x + radius, y);
-        render_context_2_d.line_to(r - radius, y);
-        render_context_2_d.quadratic_curve_to(r, y, r, y + radius);
-        render_context_2_d.line_to(r, y + height - radius);
-        render_context_2_d.quadratic_curve_to(r, b, r - radius, b);
-        render_context_2_d.line_to(x + radius, b);
-        render_context_2_d.quadratic_curve_to(x, b, x, b - radius);
-        render_context_2_d.line_to(x, y + radius);
-        render_context_2_d.quadratic_curve_to(x, y, x + radius, y);
+        render_context_2_d.move_to(x + radius.top_left, y);
+        render_context_2_d.line_to(r - radius.top_right, y);
+        render_context_2_d.quadratic_curve_to(r, y, r, y + radius.top_right);
+        render_context_2_d.line_to(r, b - radius.bottom_right);
+        render_context_2_d.quadratic_curve_to(r, b, r - radius.bottom_right, b);
+        render_context_2_d.line_to(x + radius.bottom_left, b);
+        render_context_2_d.quadratic_curve_to(x, b, x, b - radius.bottom_left);
+        render_context_2_d.line_to(x, y + radius.top_left);
+        render_context_2_d.quadratic_curve_to(x, y, x + radius.top_left, y);
         render_context_2_d.close_path();
     }
 
@@ -101,7 +163,7 @@ impl RectangleRenderObject {
         &self,
         render_context_2_d: &mut RenderContext2D,
         rect: Rectangle,
-        radius: f64,
+        radius: BorderRadius,
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
@@ -136,12 +198,36 @@ impl Into<Box<dyn RenderObject>> for RectangleRenderObject {
 
 impl RenderObject for RectangleRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        self.render_box_shadow(ctx, global_position);
+
+        let border_image = {
+            let widget = ctx.widget();
+            match widget.try_get::<Image>("border_image") {
+                Some(image) if image.width() > 0.0 && image.height() > 0.0 => Some((
+                    image.clone(),
+                    widget.clone_or_default::<Thickness>("border_image_slice"),
+                )),
+                _ => None,
+            }
+        };
+
+        if let Some((mut image, slice)) = border_image {
+            let bounds = ctx.widget().clone::<Rectangle>("bounds");
+            self.render_border_image(
+                ctx.render_context_2_d(),
+                Rectangle::new(*global_position + bounds.position(), bounds.size()),
+                &mut image,
+                slice,
+            );
+            return;
+        }
+
         let (bounds, background, border_radius, border_thickness, border_brush) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.get::<Brush>("background").clone(),
-                widget.clone_or_default::<f64>("border_radius"),
+                widget.clone_or_default::<BorderRadius>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
             )
@@ -166,7 +252,8 @@ impl RenderObject for RectangleRenderObject {
         ctx.render_context_2_d().begin_path();
 
         if (bounds.width() - bounds.height()).abs() < f64::EPSILON
-            && border_radius >= bounds.width() / 2.0
+            && border_radius.is_uniform()
+            && border_radius.top_left >= bounds.width() / 2.0
         {
             if !has_thickness {
                 self.render_circle(
@@ -175,7 +262,7 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left,
                 );
                 ctx.render_context_2_d().set_fill_style(background);
                 ctx.render_context_2_d().fill();
@@ -186,13 +273,13 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left,
                     background,
                     border_brush,
                     border_thickness,
                 );
             }
-        } else if border_radius > 0. && has_thickness {
+        } else if !border_radius.is_zero() && has_thickness {
             self.render_rounded_bordered_rect_path(
                 ctx.render_context_2_d(),
                 Rectangle::new(*global_position + bounds.position(), bounds.size()),
@@ -201,7 +288,7 @@ impl RenderObject for RectangleRenderObject {
                 border_brush,
                 border_thickness,
             );
-        } else if border_radius > 0. {
+        } else if !border_radius.is_zero() {
             self.render_rounded_rect_path(
                 ctx.render_context_2_d(),
                 global_position.x() + bounds.x(),
@@ -233,3 +320,149 @@ impl RenderObject for RectangleRenderObject {
         }
     }
 }
+
+// --- Helpers --
+
+// Splits the image into nine slices by the given slice thickness and returns the source rectangle inside of the image
+// and the target rectangle inside of the given rectangle of each slice. If the corners are greater than the target
+// they are scaled down.
+fn nine_slices(
+    image_size: (f64, f64),
+    slice: Thickness,
+    rect: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let left = slice.left().max(0.0).min(image_size.0);
+    let right = slice.right().max(0.0).min(image_size.0 - left);
+    let top = slice.top().max(0.0).min(image_size.1);
+    let bottom = slice.bottom().max(0.0).min(image_size.1 - top);
+
+    let scale_x = if left + right > rect.width() {
+        rect.width() / (left + right)
+    } else {
+        1.0
+    };
+    let scale_y = if top + bottom > rect.height() {
+        rect.height() / (top + bottom)
+    } else {
+        1.0
+    };
+
+    let source_columns = [
+        (0.0, left),
+        (left, image_size.0 - right),
+        (image_size.0 - right, image_size.0),
+    ];
+    let source_rows = [
+        (0.0, top),
+        (top, image_size.1 - bottom),
+        (image_size.1 - bottom, image_size.1),
+    ];
+    let target_columns = [
+        (rect.x(), rect.x() + left * scale_x),
+        (
+            rect.x() + left * scale_x,
+            rect.x() + rect.width() - right * scale_x,
+        ),
+        (
+            rect.x() + rect.width() - right * scale_x,
+            rect.x() + rect.width(),
+        ),
+    ];
+    let target_rows = [
+        (rect.y(), rect.y() + top * scale_y),
+        (
+            rect.y() + top * scale_y,
+            rect.y() + rect.height() - bottom * scale_y,
+        ),
+        (
+            rect.y() + rect.height() - bottom * scale_y,
+            rect.y() + rect.height(),
+        ),
+    ];
+
+    let mut slices = vec![];
+
+    for (source_row, target_row) in source_rows.iter().zip(target_rows.iter()) {
+        for (source_column, target_column) in source_columns.iter().zip(target_columns.iter()) {
+            let source = Rectangle::new(
+                (source_column.0, source_row.0),
+                (
+                    source_column.1 - source_column.0,
+                    source_row.1 - source_row.0,
+                ),
+            );
+            let target = Rectangle::new(
+                (target_column.0, target_row.0),
+                (
+                    target_column.1 - target_column.0,
+                    target_row.1 - target_row.0,
+                ),
+            );
+
+            if source.width() > 0.0
+                && source.height() > 0.0
+                && target.width() > 0.0
+                && target.height() > 0.0
+            {
+                slices.push((source, target));
+            }
+        }
+    }
+
+    slices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nine_slices() {
+        let slices = nine_slices(
+            (30.0, 30.0),
+            Thickness::from(10.0),
+            Rectangle::new((5.0, 5.0), (100.0, 50.0)),
+        );
+
+        assert_eq!(slices.len(), 9);
+
+        // top left corner keeps its size
+        assert_eq!(slices[0].0, Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+        assert_eq!(slices[0].1, Rectangle::new((5.0, 5.0), (10.0, 10.0)));
+
+        // center is stretched
+        assert_eq!(slices[4].0, Rectangle::new((10.0, 10.0), (10.0, 10.0)));
+        assert_eq!(slices[4].1, Rectangle::new((15.0, 15.0), (80.0, 30.0)));
+
+        // bottom right corner keeps its size
+        assert_eq!(slices[8].0, Rectangle::new((20.0, 20.0), (10.0, 10.0)));
+        assert_eq!(slices[8].1, Rectangle::new((95.0, 45.0), (10.0, 10.0)));
+    }
+
+    #[test]
+    fn test_nine_slices_scaled_corners() {
+        let slices = nine_slices(
+            (30.0, 30.0),
+            Thickness::from(10.0),
+            Rectangle::new((0.0, 0.0), (10.0, 40.0)),
+        );
+
+        // no space for the center column
+        assert_eq!(slices.len(), 6);
+        assert_eq!(slices[0].1, Rectangle::new((0.0, 0.0), (5.0, 10.0)));
+        assert_eq!(slices[1].1, Rectangle::new((5.0, 0.0), (5.0, 10.0)));
+    }
+
+    #[test]
+    fn test_nine_slices_without_slice() {
+        let slices = nine_slices(
+            (30.0, 20.0),
+            Thickness::from(0.0),
+            Rectangle::new((0.0, 0.0), (60.0, 40.0)),
+        );
+
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].0, Rectangle::new((0.0, 0.0), (30.0, 20.0)));
+        assert_eq!(slices[0].1, Rectangle::new((0.0, 0.0), (60.0, 40.0)));
+    }
+}
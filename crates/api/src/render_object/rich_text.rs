@@ -0,0 +1,125 @@
+use crate::{
+    properties::TextSelection,
+    render::RenderContext2D,
+    render_object::*,
+    utils::{Brush, Point, Rectangle, RichText, RichTextStyle},
+    widget_base::WidgetContainer,
+};
+
+/// Used to render a rich text. The spans are wrapped at the width of the bounds, links are underlined and the
+/// `text_selection` is drawn with the `selection_brush`.
+pub struct RichTextRenderObject;
+
+impl Into<Box<dyn RenderObject>> for RichTextRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for RichTextRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, rich_text, style, selection, selection_brush) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.clone::<RichText>("rich_text"),
+                rich_text_style(&widget),
+                widget
+                    .try_clone::<TextSelection>("text_selection")
+                    .unwrap_or_default(),
+                widget.try_clone::<Brush>("selection_brush"),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 || rich_text.is_empty() {
+            return;
+        }
+
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+        let render_context_2_d = ctx.render_context_2_d();
+        let (fragments, _) = rich_text.layout(
+            &style,
+            bounds.width(),
+            rich_text_measure(render_context_2_d),
+        );
+
+        if let Some(selection_brush) = selection_brush {
+            if selection.length > 0 {
+                let selection_bounds = rich_text.range_bounds(
+                    &style,
+                    &fragments,
+                    selection.start_index,
+                    selection.start_index + selection.length,
+                    rich_text_measure(render_context_2_d),
+                );
+
+                render_context_2_d.set_fill_style(selection_brush);
+
+                for rect in selection_bounds {
+                    render_context_2_d.fill_rect(
+                        x + rect.x(),
+                        y + rect.y(),
+                        rect.width(),
+                        rect.height(),
+                    );
+                }
+            }
+        }
+
+        for fragment in &fragments {
+            let span = &rich_text.spans()[fragment.span];
+            let foreground = style.foreground(span);
+
+            if foreground.is_transparent() {
+                continue;
+            }
+
+            render_context_2_d.begin_path();
+            render_context_2_d.set_font_family(style.font(span));
+            render_context_2_d.set_font_size(style.font_size(span));
+            render_context_2_d.set_fill_style(foreground);
+            render_context_2_d.fill_text(&fragment.text, x + fragment.x, y + fragment.y);
+            render_context_2_d.close_path();
+
+            if span.link.is_some() {
+                // the trailing space of a link is not underlined
+                let width = render_context_2_d
+                    .measure(
+                        fragment.text.trim_end(),
+                        style.font_size(span),
+                        style.font(span),
+                    )
+                    .width;
+                render_context_2_d.fill_rect(
+                    x + fragment.x,
+                    y + fragment.y + fragment.height - 1.0,
+                    width,
+                    1.0,
+                );
+            }
+        }
+    }
+}
+
+/// Reads the `RichTextStyle` from the `font`, `bold_font`, `font_size`, `foreground` and `link_foreground`
+/// properties of the widget.
+pub fn rich_text_style(widget: &WidgetContainer<'_>) -> RichTextStyle {
+    RichTextStyle {
+        font: widget.clone_or_default::<String>("font"),
+        bold_font: widget.clone_or_default::<String>("bold_font"),
+        font_size: widget.clone_or_default::<f64>("font_size"),
+        foreground: widget.clone_or_default::<Brush>("foreground"),
+        link_foreground: widget.clone_or_default::<Brush>("link_foreground"),
+    }
+}
+
+/// Returns a function that measures texts of a rich text with the render context.
+pub fn rich_text_measure(
+    render_context_2_d: &mut RenderContext2D,
+) -> impl FnMut(&str, f64, &str) -> (f64, f64) + '_ {
+    move |text, font_size, font| {
+        let text_metrics = render_context_2_d.measure(text, font_size, font);
+        (text_metrics.width, text_metrics.height)
+    }
+}
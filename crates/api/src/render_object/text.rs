@@ -1,9 +1,13 @@
 use crate::{
+    application::Global,
     render_object::*,
-    utils::{Brush, Point, Rectangle, String16},
+    utils::{Brush, Mnemonic, Point, Rectangle, String16, TextSelection},
+    widget_base::WidgetContainer,
 };
 
-/// Used to render a text.
+/// Used to render a text. If the `mnemonic` property of the widget is set, the mnemonic markers of the text are
+/// removed and the mnemonic character is underlined while Alt is pressed. The `composition` of an input method editor
+/// is drawn underlined at the start of the `text_selection`.
 pub struct TextRenderObject;
 
 impl Into<Box<dyn RenderObject>> for TextRenderObject {
@@ -14,9 +18,23 @@ impl Into<Box<dyn RenderObject>> for TextRenderObject {
 
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, text, foreground, font, font_size) = {
+        let (bounds, text, foreground, font, font_size, mnemonic, composition) = {
             let widget = ctx.widget();
-            let text = widget.clone::<String16>("text");
+            let mut text = widget.clone::<String16>("text");
+            let composition = widget.clone_or_default::<String16>("composition");
+
+            // the composition is not committed yet, it is inserted at the caret
+            let composition = if composition.is_empty() {
+                None
+            } else {
+                let index = widget
+                    .clone_or_default::<TextSelection>("text_selection")
+                    .start_index
+                    .min(text.len());
+                let before = text.get_string(0, index).unwrap_or_default();
+                text.insert_str(index, composition.as_string().as_str());
+                Some((before, composition.as_string()))
+            };
 
             let txt = {
                 if !text.is_empty() {
@@ -31,6 +49,8 @@ impl RenderObject for TextRenderObject {
                 widget.get::<Brush>("foreground").clone(),
                 widget.get::<String>("font").clone(),
                 *widget.get::<f64>("font_size"),
+                *widget.try_get::<bool>("mnemonic").unwrap_or(&false),
+                composition,
             )
         };
 
@@ -43,18 +63,80 @@ impl RenderObject for TextRenderObject {
             return;
         }
 
+        let mnemonic = if mnemonic {
+            Some(Mnemonic::parse(&text))
+        } else {
+            None
+        };
+
+        let show_mnemonic = mnemonic.is_some()
+            && ctx
+                .window()
+                .get::<Global>("global")
+                .keyboard_state
+                .is_alt_down();
+
+        let text = mnemonic.as_ref().map_or(text.as_str(), |m| m.text());
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+
         if !text.is_empty() {
             ctx.render_context_2_d().begin_path();
-            ctx.render_context_2_d().set_font_family(font);
+            ctx.render_context_2_d().set_font_family(font.as_str());
             ctx.render_context_2_d().set_font_size(font_size);
             ctx.render_context_2_d().set_fill_style(foreground);
 
-            ctx.render_context_2_d().fill_text(
-                &text,
-                global_position.x() + bounds.x(),
-                global_position.y() + bounds.y(),
-            );
+            ctx.render_context_2_d().fill_text(text, x, y);
             ctx.render_context_2_d().close_path();
         }
+
+        // underlines the mnemonic character
+        if let Some((before, character)) = mnemonic
+            .as_ref()
+            .filter(|_| show_mnemonic)
+            .and_then(|m| m.split())
+        {
+            let offset = ctx
+                .render_context_2_d()
+                .measure(before, font_size, font.as_str())
+                .width;
+            let metrics = ctx
+                .render_context_2_d()
+                .measure(character, font_size, font.as_str());
+
+            ctx.render_context_2_d().fill_rect(
+                x + offset,
+                y + metrics.height - 1.0,
+                metrics.width,
+                1.0,
+            );
+        }
+
+        // underlines the composition
+        if let Some((before, composition)) = composition {
+            let offset = ctx
+                .render_context_2_d()
+                .measure(before.as_str(), font_size, font.as_str())
+                .width;
+            let metrics =
+                ctx.render_context_2_d()
+                    .measure(composition.as_str(), font_size, font.as_str());
+
+            ctx.render_context_2_d().fill_rect(
+                x + offset,
+                y + metrics.height - 1.0,
+                metrics.width,
+                1.0,
+            );
+        }
     }
 }
+
+/// Returns the displayed text of a widget. If its `mnemonic` property is set, the mnemonic markers are removed.
+pub fn display_text(widget: &WidgetContainer<'_>, text: &String16) -> String {
+    if *widget.try_get::<bool>("mnemonic").unwrap_or(&false) {
+        return Mnemonic::parse(&text.to_string()).text().to_string();
+    }
+
+    text.to_string()
+}
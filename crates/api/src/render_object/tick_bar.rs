@@ -0,0 +1,92 @@
+use crate::{
+    render::snap_line,
+    render_object::*,
+    utils::{Brush, Point, Rectangle},
+};
+
+/// Renders vertical tick marks between the `min` and `max` property of the widget. The distance
+/// of the ticks is defined by the `tick_frequency` property.
+pub struct TickBarRenderObject;
+
+impl Into<Box<dyn RenderObject>> for TickBarRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for TickBarRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, foreground, min, max, tick_frequency) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.get::<Brush>("foreground").clone(),
+                widget.clone_or_default::<f64>("min"),
+                widget.clone_or_default::<f64>("max"),
+                widget.clone_or_default::<f64>("tick_frequency"),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 || foreground.is_transparent() {
+            return;
+        }
+
+        let ticks = calculate_ticks(min, max, tick_frequency, bounds.width());
+
+        if ticks.is_empty() {
+            return;
+        }
+
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+        let scale_factor = ctx.scale_factor();
+
+        ctx.render_context_2_d().begin_path();
+
+        for tick in ticks {
+            // the ticks are aligned to the physical pixels to draw them sharp
+            let tick_x = snap_line(x + tick, 1.0, scale_factor);
+            ctx.render_context_2_d().move_to(tick_x, y);
+            ctx.render_context_2_d()
+                .line_to(tick_x, y + bounds.height());
+        }
+
+        ctx.render_context_2_d().set_line_width(1.0);
+        ctx.render_context_2_d().set_stroke_style(foreground);
+        ctx.render_context_2_d().stroke();
+        ctx.render_context_2_d().close_path();
+    }
+}
+
+// Calculates the x positions of the ticks relative to the given width.
+fn calculate_ticks(min: f64, max: f64, tick_frequency: f64, width: f64) -> Vec<f64> {
+    let mut ticks = vec![];
+
+    if tick_frequency <= 0.0 || max <= min {
+        return ticks;
+    }
+
+    let count = ((max - min) / tick_frequency).floor() as usize;
+
+    for i in 0..=count {
+        ticks.push(i as f64 * tick_frequency / (max - min) * width);
+    }
+
+    ticks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_ticks() {
+        assert!(calculate_ticks(0.0, 100.0, 0.0, 100.0).is_empty());
+        assert!(calculate_ticks(100.0, 0.0, 10.0, 100.0).is_empty());
+        assert_eq!(
+            calculate_ticks(0.0, 100.0, 25.0, 200.0),
+            vec![0.0, 50.0, 100.0, 150.0, 200.0]
+        );
+        assert_eq!(calculate_ticks(-10.0, 10.0, 15.0, 100.0), vec![0.0, 75.0]);
+    }
+}
@@ -0,0 +1,125 @@
+use dces::prelude::Entity;
+
+use crate::{event::*, utils::Point};
+
+/// The `DragDropService` tracks the active drag-and-drop operation of a window. It is registered with the key
+/// `drag_drop` in the registry. A drag is started by a `DragStartEvent` and moved, dropped or canceled by the event
+/// pipeline, the service describes the current state e.g. for the drag ghost of the window.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let dragging = registry
+///     .get::<DragDropService>("drag_drop")
+///     .is_dragging();
+/// ```
+#[derive(Default)]
+pub struct DragDropService {
+    drag: Option<Drag>,
+    target: Option<Entity>,
+    host: Option<Entity>,
+}
+
+impl DragDropService {
+    /// Creates a new drag-and-drop service.
+    pub fn new() -> Self {
+        DragDropService::default()
+    }
+
+    /// Checks if a drag is active.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Gets the active drag with its source, the current mouse position and the data.
+    pub fn drag(&self) -> Option<&Drag> {
+        self.drag.as_ref()
+    }
+
+    /// Gets the widget that has accepted the drag on the last mouse move.
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Checks if the drag is accepted by the widget under the mouse.
+    pub fn accepted(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Gets the widget that shows the drag, it is updated on each change of the drag.
+    pub fn host(&self) -> Option<Entity> {
+        self.host
+    }
+
+    /// Sets the widget that shows the drag.
+    pub fn set_host(&mut self, host: Entity) {
+        self.host = Some(host);
+    }
+
+    /// Starts a new drag. An active drag is replaced.
+    pub fn start(&mut self, drag: Drag) {
+        self.drag = Some(drag);
+        self.target = None;
+    }
+
+    /// Moves the active drag to the given position.
+    pub fn move_to(&mut self, position: Point) {
+        if let Some(drag) = &mut self.drag {
+            drag.position = position;
+        }
+    }
+
+    /// Sets the widget that has accepted the drag. Returns the previous target if it has changed.
+    pub fn set_target(&mut self, target: Option<Entity>) -> Option<Option<Entity>> {
+        if target == self.target {
+            return None;
+        }
+
+        Some(std::mem::replace(&mut self.target, target))
+    }
+
+    /// Ends the active drag and returns it with the widget that has accepted it.
+    pub fn finish(&mut self) -> Option<(Drag, Option<Entity>)> {
+        let drag = self.drag.take()?;
+        Some((drag, self.target.take()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drag() -> Drag {
+        Drag {
+            source: Entity(1),
+            position: Point::new(0.0, 0.0),
+            data: DragData::new(42_u32).text("Task"),
+        }
+    }
+
+    #[test]
+    fn test_drag() {
+        let mut service = DragDropService::new();
+        assert!(!service.is_dragging());
+        assert!(service.finish().is_none());
+
+        service.start(drag());
+        service.move_to(Point::new(10.0, 20.0));
+        assert!(service.is_dragging());
+        assert!(!service.accepted());
+        assert_eq!(service.drag().unwrap().position, Point::new(10.0, 20.0));
+        assert_eq!(service.drag().unwrap().data.get::<u32>(), Some(&42));
+
+        // the previous target is only returned if the target has changed
+        assert_eq!(service.set_target(Some(Entity(2))), Some(None));
+        assert_eq!(service.set_target(Some(Entity(2))), None);
+        assert_eq!(service.set_target(Some(Entity(3))), Some(Some(Entity(2))));
+        assert!(service.accepted());
+
+        let (drag, target) = service.finish().unwrap();
+        assert_eq!(drag.source, Entity(1));
+        assert_eq!(target, Some(Entity(3)));
+        assert!(!service.is_dragging());
+        assert_eq!(service.target(), None);
+    }
+}
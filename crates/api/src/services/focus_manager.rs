@@ -0,0 +1,174 @@
+use dces::prelude::Entity;
+
+use crate::{application::Global, theming::Selector, widget_base::Context};
+
+/// The `FocusManager` tracks the widget with the keyboard focus of a window. It is registered with the key
+/// `focus_manager` in the registry. Key events are routed to the focused widget first. Tab moves the focus to the
+/// next focusable widget in tree order, Shift+Tab to the previous one.
+///
+/// A widget takes part in the Tab navigation if its `focusable` property is `true`. Widgets with a positive
+/// `tab_index` are visited first in ascending order, followed by the widgets with a `tab_index` of `0` in tree
+/// order. Widgets with a negative `tab_index` are skipped. The focused widget gets the `focused` selector state,
+/// that is used by the theme to draw the focus indicator.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let focused = registry
+///     .get::<FocusManager>("focus_manager")
+///     .focused_widget();
+/// ```
+#[derive(Default, Debug)]
+pub struct FocusManager {
+    focused_widget: Option<Entity>,
+}
+
+impl FocusManager {
+    /// Creates a new focus manager.
+    pub fn new() -> Self {
+        FocusManager::default()
+    }
+
+    /// Gets the widget with the keyboard focus.
+    pub fn focused_widget(&self) -> Option<Entity> {
+        self.focused_widget
+    }
+
+    /// Checks if the given widget has the keyboard focus.
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.focused_widget == Some(entity)
+    }
+
+    /// Moves the keyboard focus to the given widget. The `focused` property and the `focused` selector state of the
+    /// old and the new focused widget are updated. A disabled widget doesn't get the focus.
+    pub fn request_focus(&mut self, entity: Entity, ctx: &mut Context) {
+        if self.is_focused(entity) || !*ctx.get_widget(entity).get::<bool>("enabled") {
+            return;
+        }
+
+        if let Some(old_focused_widget) = self.focused_widget {
+            set_focused(old_focused_widget, false, ctx);
+        }
+
+        self.set_focused_widget(Some(entity), ctx);
+
+        if ctx.get_widget(entity).has::<bool>("focused") {
+            set_focused(entity, true, ctx);
+        }
+    }
+
+    /// Removes the keyboard focus from the given widget if it is focused.
+    pub fn remove_focus(&mut self, entity: Entity, ctx: &mut Context) {
+        if !self.is_focused(entity) {
+            return;
+        }
+
+        set_focused(entity, false, ctx);
+        self.set_focused_widget(None, ctx);
+    }
+
+    /// Returns the widget that gets the focus by Tab or by Shift+Tab if `reverse` is set. The given widgets are the
+    /// focusable widgets of the window in tree order with their tab index.
+    pub fn next_focus(&self, widgets: &[(Entity, i32)], reverse: bool) -> Option<Entity> {
+        next_in_tab_order(&tab_order(widgets), self.focused_widget, reverse)
+    }
+
+    // the focused widget is also stored in the global property of the window
+    fn set_focused_widget(&mut self, entity: Option<Entity>, ctx: &mut Context) {
+        self.focused_widget = entity;
+        ctx.window().get_mut::<Global>("global").focused_widget = entity;
+    }
+}
+
+// --- Helpers --
+
+fn set_focused(entity: Entity, focused: bool, ctx: &mut Context) {
+    let mut widget = ctx.get_widget(entity);
+    widget.set("focused", focused);
+    widget
+        .get_mut::<Selector>("selector")
+        .set_pseudo_class("focused", focused);
+    widget.update(false);
+}
+
+// returns the widgets in the order they are visited by Tab. Widgets with a positive tab index come first.
+fn tab_order(widgets: &[(Entity, i32)]) -> Vec<Entity> {
+    let mut order: Vec<(Entity, i32)> = widgets
+        .iter()
+        .copied()
+        .filter(|(_, tab_index)| *tab_index >= 0)
+        .collect();
+
+    // the sort is stable, widgets with the same tab index keep the tree order
+    order.sort_by_key(|(_, tab_index)| (*tab_index == 0, *tab_index));
+    order.into_iter().map(|(entity, _)| entity).collect()
+}
+
+// returns the widget after (or before if reverse) the current one. The order wraps around at its end.
+fn next_in_tab_order(order: &[Entity], current: Option<Entity>, reverse: bool) -> Option<Entity> {
+    if order.is_empty() {
+        return None;
+    }
+
+    let position = current.and_then(|current| order.iter().position(|e| *e == current));
+
+    let index = match (position, reverse) {
+        (Some(position), false) => (position + 1) % order.len(),
+        (Some(position), true) => (position + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+
+    Some(order[index])
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entities(entities: &[u32]) -> Vec<Entity> {
+        entities.iter().map(|e| Entity(*e)).collect()
+    }
+
+    #[test]
+    fn test_tab_order() {
+        let widgets = vec![
+            (Entity(1), 0),
+            (Entity(2), 2),
+            (Entity(3), -1),
+            (Entity(4), 0),
+            (Entity(5), 1),
+            (Entity(6), 2),
+        ];
+
+        assert_eq!(tab_order(&widgets), entities(&[5, 2, 6, 1, 4]));
+        assert_eq!(tab_order(&[]), entities(&[]));
+    }
+
+    #[test]
+    fn test_next_in_tab_order() {
+        let order = entities(&[1, 2, 3]);
+
+        assert_eq!(next_in_tab_order(&order, None, false), Some(Entity(1)));
+        assert_eq!(next_in_tab_order(&order, None, true), Some(Entity(3)));
+        assert_eq!(
+            next_in_tab_order(&order, Some(Entity(1)), false),
+            Some(Entity(2))
+        );
+        assert_eq!(
+            next_in_tab_order(&order, Some(Entity(3)), false),
+            Some(Entity(1))
+        );
+        assert_eq!(
+            next_in_tab_order(&order, Some(Entity(1)), true),
+            Some(Entity(3))
+        );
+        assert_eq!(
+            next_in_tab_order(&order, Some(Entity(7)), false),
+            Some(Entity(1))
+        );
+        assert_eq!(next_in_tab_order(&[], Some(Entity(1)), false), None);
+    }
+}
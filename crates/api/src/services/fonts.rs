@@ -0,0 +1,157 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt, fs,
+    path::Path,
+    rc::Rc,
+};
+
+use crate::render::RenderContext2D;
+
+/// The `Fonts` service holds the font families that could be used by the `font` property of text widgets and by
+/// the themes. It is registered with the key `fonts` in the registry. The Roboto fonts and the Material icons font
+/// of the default theme are registered by default.
+///
+/// Fonts that are registered at runtime are passed to the render context of each window before the next layout
+/// pass. A family that is not registered falls back to the registered fonts.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// registry
+///     .get::<Fonts>("fonts")
+///     .register("Inter", include_bytes!("../assets/Inter-Regular.ttf"));
+/// ```
+#[derive(Clone)]
+pub struct Fonts {
+    families: Rc<RefCell<BTreeMap<String, &'static [u8]>>>,
+
+    // is increased on each registration, it's shared between all windows
+    revision: Rc<Cell<usize>>,
+
+    // the revision of the fonts that are passed to the render context of the window
+    registered_revision: Cell<usize>,
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        let fonts = Fonts {
+            families: Rc::new(RefCell::new(BTreeMap::new())),
+            revision: Rc::new(Cell::new(0)),
+            registered_revision: Cell::new(0),
+        };
+
+        fonts.register("Roboto-Regular", crate::theme::fonts::ROBOTO_REGULAR_FONT);
+        fonts.register("Roboto-Medium", crate::theme::fonts::ROBOTO_MEDIUM_FONT);
+        fonts.register(
+            "MaterialIcons-Regular",
+            crate::theme::fonts::MATERIAL_ICONS_FONT,
+        );
+
+        fonts
+    }
+}
+
+impl fmt::Debug for Fonts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fonts")
+            .field("families", &self.families())
+            .finish()
+    }
+}
+
+impl Fonts {
+    /// Creates a new fonts service with the default fonts.
+    pub fn new() -> Self {
+        Fonts::default()
+    }
+
+    /// Registers the font data (ttf or otf) with the given family name. An existing family with the same name is
+    /// replaced.
+    pub fn register(&self, family: impl Into<String>, font_file: &'static [u8]) {
+        self.families.borrow_mut().insert(family.into(), font_file);
+        self.revision.set(self.revision.get() + 1);
+    }
+
+    /// Loads the font file from the given path and registers it with the given family name.
+    pub fn register_file(
+        &self,
+        family: impl Into<String>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), String> {
+        let path = path.as_ref();
+        let font_file = fs::read(path)
+            .map_err(|e| format!("Fonts: could not read font file {:?}: {}", path, e))?;
+
+        // the render backends keep the fonts for the lifetime of the application
+        self.register(family, Box::leak(font_file.into_boxed_slice()));
+
+        Ok(())
+    }
+
+    /// Checks if a font with the given family name is registered.
+    pub fn contains(&self, family: &str) -> bool {
+        self.families.borrow().contains_key(family)
+    }
+
+    /// Returns the names of all registered font families in alphabetical order.
+    pub fn families(&self) -> Vec<String> {
+        self.families.borrow().keys().cloned().collect()
+    }
+
+    /// Returns all registered fonts by family name.
+    pub fn to_map(&self) -> BTreeMap<String, &'static [u8]> {
+        self.families.borrow().clone()
+    }
+
+    /// Passes the fonts that are registered since the last call to the given render context. Returns `true` if
+    /// fonts were passed.
+    pub(crate) fn register_pending(&self, render_context: &mut RenderContext2D) -> bool {
+        if self.registered_revision.get() == self.revision.get() {
+            return false;
+        }
+
+        for (family, font_file) in self.families.borrow().iter() {
+            render_context.register_font(family, font_file);
+        }
+
+        self.registered_revision.set(self.revision.get());
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let fonts = Fonts::new();
+        assert!(fonts.contains("Roboto-Regular"));
+        assert!(!fonts.contains("Inter"));
+
+        let clone = fonts.clone();
+        clone.register("Inter", crate::theme::fonts::ROBOTO_REGULAR_FONT);
+
+        assert!(fonts.contains("Inter"));
+        assert_eq!(
+            fonts.families(),
+            vec![
+                "Inter".to_string(),
+                "MaterialIcons-Regular".to_string(),
+                "Roboto-Medium".to_string(),
+                "Roboto-Regular".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_register_file() {
+        let fonts = Fonts::new();
+        assert!(fonts
+            .register_file("Missing", "does/not/exist.ttf")
+            .is_err());
+        assert!(!fonts.contains("Missing"));
+    }
+}
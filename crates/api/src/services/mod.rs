@@ -1,5 +1,21 @@
 //! This module contains global services.
 //!
+pub use self::drag_drop::*;
+pub use self::focus_manager::*;
+pub use self::fonts::*;
+pub use self::notification::*;
+pub use self::notifications::*;
 pub use self::settings::*;
+pub use self::shortcuts::*;
+pub use self::theme_watcher::*;
+pub use self::windows::*;
 
+mod drag_drop;
+mod focus_manager;
+mod fonts;
+mod notification;
+mod notifications;
 mod settings;
+mod shortcuts;
+mod theme_watcher;
+mod windows;
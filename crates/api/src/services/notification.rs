@@ -0,0 +1,360 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt,
+    rc::Rc,
+    sync::mpsc,
+};
+
+use dces::prelude::Entity;
+
+use crate::{
+    shell::{now, WindowRequest},
+    widget_base::StatesContext,
+};
+
+/// The default time in milliseconds until a notification is dismissed.
+pub const DEFAULT_NOTIFICATION_TIMEOUT: f64 = 4000.0;
+
+/// The default number of notifications that are shown at the same time.
+pub const DEFAULT_MAX_NOTIFICATIONS: usize = 3;
+
+/// Describes the kind of a notification. It defines the style of the banner.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NotificationKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Describes the corner of the window where the notifications are shown.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NotificationCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Default for NotificationCorner {
+    fn default() -> Self {
+        NotificationCorner::BottomRight
+    }
+}
+
+/// The button of a notification with its label and the handler that is called on click.
+#[derive(Clone)]
+pub struct NotificationAction {
+    /// The label of the button.
+    pub label: String,
+
+    /// Is called if the button is clicked, afterwards the notification is dismissed.
+    pub handler: Rc<dyn Fn(&mut StatesContext)>,
+}
+
+impl fmt::Debug for NotificationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotificationAction")
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+/// A transient message that is shown by the `NotificationService`.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// The kind of the notification.
+    pub kind: NotificationKind,
+
+    /// The message text.
+    pub message: String,
+
+    /// The time in milliseconds until the notification is dismissed. If it is `0` the notification stays until
+    /// it is dismissed by the user.
+    pub timeout: f64,
+
+    /// The optional action button.
+    pub action: Option<NotificationAction>,
+}
+
+impl Notification {
+    /// Creates a new notification with the given kind and message and the default timeout.
+    pub fn new(kind: NotificationKind, message: impl Into<String>) -> Self {
+        Notification {
+            kind,
+            message: message.into(),
+            timeout: DEFAULT_NOTIFICATION_TIMEOUT,
+            action: None,
+        }
+    }
+
+    /// Builder method that sets the timeout in milliseconds, `0` disables the timeout.
+    pub fn timeout(mut self, timeout: f64) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Builder method that adds an action button with the given label and click handler.
+    pub fn action<H: Fn(&mut StatesContext) + 'static>(
+        mut self,
+        label: impl Into<String>,
+        handler: H,
+    ) -> Self {
+        self.action = Some(NotificationAction {
+            label: label.into(),
+            handler: Rc::new(handler),
+        });
+        self
+    }
+}
+
+/// The `NotificationService` queues transient notifications of a window. It is registered with the key
+/// `notifications` in the registry. The notifications are shown by the `ToastPanel` of the window, not more than
+/// `max_visible` at the same time, the others wait in the queue until a visible notification is dismissed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// registry
+///     .get_mut::<NotificationService>("notifications")
+///     .show(Toast::info("Saved"));
+/// ```
+pub struct NotificationService {
+    next_id: u64,
+    queue: VecDeque<(u64, Notification)>,
+    visible: Vec<(u64, Notification, f64)>,
+    max_visible: usize,
+    corner: NotificationCorner,
+    inbox: NotificationSender,
+}
+
+/// The `NotificationSender` queues notifications in the `NotificationService` it is created by, e.g. to show
+/// notifications from another service. The queued notifications are shown on the next update of the `ToastPanel`.
+#[derive(Clone)]
+pub struct NotificationSender {
+    notifications: Rc<RefCell<Vec<Notification>>>,
+    host: Rc<Cell<Option<Entity>>>,
+    update_requests: Rc<RefCell<Vec<Entity>>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl NotificationSender {
+    /// Queues the notification.
+    pub fn send(&self, notification: Notification) {
+        self.notifications.borrow_mut().push(notification);
+        self.request_update();
+    }
+
+    // Requests an update of the host on the next frame.
+    fn request_update(&self) {
+        if let Some(host) = self.host.get() {
+            self.update_requests.borrow_mut().push(host);
+            let _ = self.window_sender.send(WindowRequest::Redraw);
+        }
+    }
+}
+
+impl NotificationService {
+    /// Creates a new notification service. The update requests and the window sender are used to update the
+    /// `ToastPanel` if the notifications are changed.
+    pub fn new(
+        update_requests: Rc<RefCell<Vec<Entity>>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) -> Self {
+        NotificationService {
+            next_id: 0,
+            queue: VecDeque::new(),
+            visible: vec![],
+            max_visible: DEFAULT_MAX_NOTIFICATIONS,
+            corner: NotificationCorner::default(),
+            inbox: NotificationSender {
+                notifications: Rc::new(RefCell::new(vec![])),
+                host: Rc::new(Cell::new(None)),
+                update_requests,
+                window_sender,
+            },
+        }
+    }
+
+    /// Queues the notification and returns its id.
+    pub fn show(&mut self, notification: Notification) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue.push_back((id, notification));
+        self.request_update();
+
+        id
+    }
+
+    /// Removes the notification with the given id.
+    pub fn dismiss(&mut self, id: u64) {
+        self.queue.retain(|(i, _)| *i != id);
+        self.visible.retain(|(i, _, _)| *i != id);
+        self.request_update();
+    }
+
+    /// Removes all notifications.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.visible.clear();
+        self.request_update();
+    }
+
+    /// Gets the visible notifications with their ids, the oldest first.
+    pub fn visible(&self) -> Vec<(u64, &Notification)> {
+        self.visible
+            .iter()
+            .map(|(id, notification, _)| (*id, notification))
+            .collect()
+    }
+
+    /// Gets the time in milliseconds of `now()` when the next visible notification is dismissed by its timeout.
+    pub fn next_timeout(&self) -> Option<f64> {
+        self.visible
+            .iter()
+            .map(|(_, _, dismiss_at)| *dismiss_at)
+            .filter(|dismiss_at| dismiss_at.is_finite())
+            .fold(None, |next, dismiss_at| {
+                Some(next.map_or(dismiss_at, |next: f64| next.min(dismiss_at)))
+            })
+    }
+
+    /// Gets the number of notifications that are shown at the same time.
+    pub fn max_visible(&self) -> usize {
+        self.max_visible
+    }
+
+    /// Sets the number of notifications that are shown at the same time.
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible.max(1);
+        self.request_update();
+    }
+
+    /// Gets the corner of the window where the notifications are shown.
+    pub fn corner(&self) -> NotificationCorner {
+        self.corner
+    }
+
+    /// Sets the corner of the window where the notifications are shown.
+    pub fn set_corner(&mut self, corner: NotificationCorner) {
+        self.corner = corner;
+        self.request_update();
+    }
+
+    /// Sets the widget that shows the notifications. It is updated if the notifications are changed.
+    pub fn set_host(&mut self, host: Entity) {
+        self.inbox.host.set(Some(host));
+    }
+
+    /// Creates a sender that queues notifications in this service.
+    pub fn sender(&self) -> NotificationSender {
+        self.inbox.clone()
+    }
+
+    /// Dismisses the notifications with an elapsed timeout and shows queued notifications. Returns `true` if the
+    /// visible notifications are changed.
+    pub fn update(&mut self) -> bool {
+        self.update_at(now())
+    }
+
+    // Requests an update of the host on the next frame.
+    fn request_update(&self) {
+        self.inbox.request_update();
+    }
+
+    fn update_at(&mut self, now: f64) -> bool {
+        let notifications: Vec<Notification> =
+            self.inbox.notifications.borrow_mut().drain(..).collect();
+
+        for notification in notifications {
+            let id = self.next_id;
+            self.next_id += 1;
+            self.queue.push_back((id, notification));
+        }
+
+        let count = self.visible.len();
+        self.visible.retain(|(_, _, dismiss_at)| *dismiss_at > now);
+        let mut changed = count != self.visible.len();
+
+        while self.visible.len() < self.max_visible {
+            let (id, notification) = match self.queue.pop_front() {
+                Some(entry) => entry,
+                None => break,
+            };
+
+            let dismiss_at = if notification.timeout > 0.0 {
+                now + notification.timeout
+            } else {
+                f64::INFINITY
+            };
+
+            self.visible.push((id, notification, dismiss_at));
+            changed = true;
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> NotificationService {
+        let (sender, _) = mpsc::channel();
+        NotificationService::new(Rc::new(RefCell::new(vec![])), sender)
+    }
+
+    #[test]
+    fn test_queue() {
+        let mut service = service();
+        service.set_max_visible(2);
+
+        let first = service.show(Notification::new(NotificationKind::Info, "one"));
+        service.show(Notification::new(NotificationKind::Error, "two").timeout(0.0));
+        service.show(Notification::new(NotificationKind::Success, "three").timeout(500.0));
+        assert!(service.visible().is_empty());
+
+        assert!(service.update_at(1000.0));
+        assert_eq!(service.visible().len(), 2);
+        assert_eq!(service.visible()[0].0, first);
+        assert_eq!(service.next_timeout(), Some(5000.0));
+        assert!(!service.update_at(2000.0));
+
+        // the first one times out, the third one takes its place
+        assert!(service.update_at(5000.0));
+        let messages: Vec<&str> = service
+            .visible()
+            .iter()
+            .map(|(_, n)| n.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["two", "three"]);
+
+        assert!(service.update_at(5500.0));
+        assert_eq!(service.visible().len(), 1);
+        assert_eq!(service.next_timeout(), None);
+
+        // without timeout it stays until it is dismissed
+        let id = service.visible()[0].0;
+        service.dismiss(id);
+        assert!(service.visible().is_empty());
+    }
+
+    #[test]
+    fn test_sender() {
+        let mut service = service();
+        service.show(Notification::new(NotificationKind::Info, "one"));
+        service
+            .sender()
+            .send(Notification::new(NotificationKind::Info, "two"));
+
+        assert!(service.update_at(1000.0));
+        let messages: Vec<&str> = service
+            .visible()
+            .iter()
+            .map(|(_, n)| n.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["one", "two"]);
+    }
+}
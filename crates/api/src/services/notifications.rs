@@ -0,0 +1,244 @@
+use std::{
+    rc::Rc,
+    sync::mpsc::{self, TryRecvError},
+};
+
+use crate::{
+    services::{Notification, NotificationAction, NotificationKind, NotificationSender},
+    shell::{
+        desktop_notification_actions, show_desktop_notification, DesktopNotification,
+        DesktopNotificationResult, WindowRequest,
+    },
+    widget_base::StatesContext,
+};
+
+// A desktop notification that waits for its result.
+struct PendingNotification {
+    receiver: mpsc::Receiver<DesktopNotificationResult>,
+    notification: DesktopNotification,
+    action: Option<NotificationAction>,
+}
+
+/// The `Notifications` service posts native notifications to the notification center of the desktop, e.g. to
+/// inform the user while the window is minimized or hidden. It is registered with the key `desktop_notifications` in
+/// the registry.
+///
+/// If the platform has no supported notification center or the notification could not be shown, it is shown as
+/// toast by the `NotificationService` of the window instead. The handler of an action is called if the action of the
+/// desktop notification or of the toast is activated.
+///
+/// The supported notification centers are `notify-send` on Linux and BSD, `osascript` on macOS and the toasts of
+/// PowerShell on Windows. macOS and Windows report no activation, notifications with action are shown as toast on
+/// these platforms, see `desktop_notification_actions`. Web and Redox show all notifications as toast.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// registry
+///     .get_mut::<Notifications>("desktop_notifications")
+///     .show_with_action(
+///         DesktopNotification::new("Download finished", "orbtk.zip"),
+///         "Open",
+///         move |states| states.get_mut::<MainState>(id).open_download(),
+///     );
+/// ```
+pub struct Notifications {
+    app_name: String,
+    window_sender: mpsc::Sender<WindowRequest>,
+    toasts: NotificationSender,
+    pending: Vec<PendingNotification>,
+}
+
+impl Notifications {
+    /// Creates a new notifications service. The application name is used for notifications without an own
+    /// application name, the toasts are queued with the given notification sender.
+    pub fn new(
+        app_name: impl Into<String>,
+        window_sender: mpsc::Sender<WindowRequest>,
+        toasts: NotificationSender,
+    ) -> Self {
+        Notifications {
+            app_name: app_name.into(),
+            window_sender,
+            toasts,
+            pending: vec![],
+        }
+    }
+
+    /// Shows the notification without action.
+    pub fn show(&mut self, mut notification: DesktopNotification) {
+        notification.action = None;
+        self.post(notification, None);
+    }
+
+    /// Shows the notification with an action button with the given label. The handler is called if the action is
+    /// activated.
+    pub fn show_with_action<H: Fn(&mut StatesContext) + 'static>(
+        &mut self,
+        mut notification: DesktopNotification,
+        label: impl Into<String>,
+        handler: H,
+    ) {
+        let label = label.into();
+        notification.action = Some(label.clone());
+
+        self.post(
+            notification,
+            Some(NotificationAction {
+                label,
+                handler: Rc::new(handler),
+            }),
+        );
+    }
+
+    /// Gets the number of desktop notifications that wait for their result.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Collects the results of the shown desktop notifications. Returns the actions that are activated, notifications
+    /// that could not be shown are passed to the toasts.
+    pub fn poll(&mut self) -> Vec<NotificationAction> {
+        let mut activated = vec![];
+        let mut failed = vec![];
+
+        self.pending
+            .retain(|pending| match pending.receiver.try_recv() {
+                Ok(DesktopNotificationResult::Activated) => {
+                    if let Some(action) = &pending.action {
+                        activated.push(action.clone());
+                    }
+                    false
+                }
+                Ok(DesktopNotificationResult::Failed) => {
+                    failed.push((pending.notification.clone(), pending.action.clone()));
+                    false
+                }
+                Ok(DesktopNotificationResult::Dismissed) | Err(TryRecvError::Disconnected) => false,
+                Err(TryRecvError::Empty) => true,
+            });
+
+        for (notification, action) in failed {
+            self.show_toast(&notification, action);
+        }
+
+        activated
+    }
+
+    fn post(&mut self, mut notification: DesktopNotification, action: Option<NotificationAction>) {
+        if notification.app_name.is_empty() {
+            notification.app_name = self.app_name.clone();
+        }
+
+        // the action could not be activated in the notification center
+        if action.is_some() && !desktop_notification_actions() {
+            self.show_toast(&notification, action);
+            return;
+        }
+
+        match show_desktop_notification(&notification, self.window_sender.clone()) {
+            Ok(receiver) => self.pending.push(PendingNotification {
+                receiver,
+                notification,
+                action,
+            }),
+            Err(_) => self.show_toast(&notification, action),
+        }
+    }
+
+    // Shows the notification as toast in the window.
+    fn show_toast(&self, notification: &DesktopNotification, action: Option<NotificationAction>) {
+        let mut toast = Notification::new(NotificationKind::Info, toast_message(notification));
+        toast.action = action;
+        self.toasts.send(toast);
+    }
+}
+
+// -- Helpers --
+
+fn toast_message(notification: &DesktopNotification) -> String {
+    if notification.body.is_empty() {
+        notification.title.clone()
+    } else if notification.title.is_empty() {
+        notification.body.clone()
+    } else {
+        format!("{}: {}", notification.title, notification.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::services::NotificationService;
+
+    fn action() -> NotificationAction {
+        NotificationAction {
+            label: "Open".to_string(),
+            handler: Rc::new(|_| {}),
+        }
+    }
+
+    #[test]
+    fn test_poll() {
+        let (window_sender, _) = mpsc::channel();
+        let mut service =
+            NotificationService::new(Rc::new(RefCell::new(vec![])), window_sender.clone());
+        let mut notifications = Notifications::new("test", window_sender, service.sender());
+
+        let mut senders = vec![];
+
+        for _ in 0..3 {
+            let (sender, receiver) = mpsc::channel();
+            senders.push(sender);
+            notifications.pending.push(PendingNotification {
+                receiver,
+                notification: DesktopNotification::new("Download finished", "orbtk.zip"),
+                action: Some(action()),
+            });
+        }
+
+        assert!(notifications.poll().is_empty());
+        assert_eq!(notifications.pending(), 3);
+
+        senders[0]
+            .send(DesktopNotificationResult::Activated)
+            .unwrap();
+        senders[1].send(DesktopNotificationResult::Failed).unwrap();
+
+        let activated = notifications.poll();
+        assert_eq!(activated.len(), 1);
+        assert_eq!(activated[0].label, "Open");
+        assert_eq!(notifications.pending(), 1);
+
+        // the failed notification is shown as toast with its action
+        service.update();
+        assert_eq!(service.visible().len(), 1);
+        assert_eq!(
+            service.visible()[0].1.message,
+            "Download finished: orbtk.zip"
+        );
+        assert!(service.visible()[0].1.action.is_some());
+
+        senders.clear();
+        assert!(notifications.poll().is_empty());
+        assert_eq!(notifications.pending(), 0);
+    }
+
+    #[test]
+    fn test_toast_message() {
+        assert_eq!(
+            toast_message(&DesktopNotification::new("Saved", "")),
+            "Saved"
+        );
+        assert_eq!(
+            toast_message(&DesktopNotification::new("", "Saved")),
+            "Saved"
+        );
+        assert_eq!(
+            toast_message(&DesktopNotification::new("Saved", "file.txt")),
+            "Saved: file.txt"
+        );
+    }
+}
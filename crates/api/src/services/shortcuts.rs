@@ -0,0 +1,421 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    rc::Rc,
+    str::FromStr,
+};
+
+use dces::prelude::Entity;
+
+use crate::{application::KeyboardState, shell::Key, widget_base::StatesContext};
+
+/// Describes a key with its modifiers, e.g. `Ctrl+S`. A chord could be parsed from a string like `"Ctrl+Shift+Z"`.
+/// Letters are case insensitive, the shift state is described by the `shift` modifier.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let save: KeyChord = "Ctrl+S".parse().unwrap();
+/// assert_eq!(save, KeyChord::new(Key::S(false)).ctrl());
+/// assert_eq!(save.to_string(), "Ctrl+S");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyChord {
+    /// The key of the chord.
+    pub key: Key,
+
+    /// Control key must be pressed.
+    pub ctrl: bool,
+
+    /// Shift key must be pressed.
+    pub shift: bool,
+
+    /// Alt key must be pressed.
+    pub alt: bool,
+}
+
+impl KeyChord {
+    /// Creates a new chord of the given key without modifiers.
+    pub fn new(key: Key) -> Self {
+        KeyChord {
+            key: normalize_key(key),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Builder method that adds the control modifier.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Builder method that adds the shift modifier.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Builder method that adds the alt modifier.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Checks if the chord matches the given pressed key and the modifiers of the keyboard state.
+    pub fn matches(&self, key: Key, keyboard_state: &KeyboardState) -> bool {
+        self.key == normalize_key(key)
+            && self.ctrl == keyboard_state.is_ctrl_down()
+            && self.shift == keyboard_state.is_shift_down()
+            && self.alt == keyboard_state.is_alt_down()
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+
+        write!(f, "{}", key_name(self.key))
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+
+        // the plus key itself, e.g. "Ctrl++"
+        if s.ends_with("++") {
+            parts.truncate(parts.len() - 2);
+            parts.push("+");
+        }
+
+        let key = parts
+            .pop()
+            .and_then(parse_key)
+            .ok_or_else(|| format!("KeyChord: invalid key in {}.", s))?;
+
+        let mut chord = KeyChord::new(key);
+
+        for modifier in parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "shift" => chord.shift = true,
+                "alt" => chord.alt = true,
+                _ => return Err(format!("KeyChord: invalid modifier {} in {}.", modifier, s)),
+            }
+        }
+
+        Ok(chord)
+    }
+}
+
+impl TryFrom<&str> for KeyChord {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Callback of a shortcut. If it returns `true` the key event is handled and not routed to the widgets.
+pub type ShortcutCallback = dyn Fn(&mut StatesContext) -> bool + 'static;
+
+/// Describes what happens if a shortcut is pressed.
+#[derive(Clone)]
+pub enum ShortcutAction {
+    /// Calls the callback.
+    Callback(Rc<ShortcutCallback>),
+
+    /// Sends a `ShortcutEvent` with the name of the shortcut direct to the given widget.
+    Event(Entity),
+}
+
+impl fmt::Debug for ShortcutAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutAction::Callback(_) => write!(f, "ShortcutAction::Callback"),
+            ShortcutAction::Event(entity) => write!(f, "ShortcutAction::Event({:?})", entity),
+        }
+    }
+}
+
+/// A registered shortcut with its name, its chord and its action.
+#[derive(Clone, Debug)]
+pub struct Shortcut {
+    /// The name of the shortcut, e.g. `save`.
+    pub name: String,
+
+    /// The key chord that triggers the shortcut.
+    pub chord: KeyChord,
+
+    /// The action that is executed.
+    pub action: ShortcutAction,
+}
+
+/// The `Shortcuts` service maps key chords of a window to callbacks or events. It is registered with the key
+/// `shortcuts` in the registry. A key down event that matches a registered chord is dispatched before it is routed to
+/// the widgets, e.g. `Ctrl+S` triggers the save shortcut also if a text box has the focus.
+///
+/// Each chord could only be used by one shortcut, a conflicting registration fails. The display string of a shortcut
+/// could be used as `accelerator` of a `MenuItem`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// registry
+///     .get_mut::<Shortcuts>("shortcuts")
+///     .register_event("save", "Ctrl+S", id)
+///     .unwrap();
+///
+/// let accelerator = registry.get::<Shortcuts>("shortcuts").display_string("save");
+/// ```
+#[derive(Default, Debug)]
+pub struct Shortcuts {
+    shortcuts: Vec<Shortcut>,
+}
+
+impl Shortcuts {
+    /// Creates a new shortcuts service.
+    pub fn new() -> Self {
+        Shortcuts::default()
+    }
+
+    /// Registers a shortcut that calls the given callback. An existing shortcut with the same name is replaced. Returns
+    /// an error if the chord is invalid or already used by another shortcut.
+    pub fn register_callback<F: Fn(&mut StatesContext) -> bool + 'static, E: fmt::Display>(
+        &mut self,
+        name: impl Into<String>,
+        chord: impl TryInto<KeyChord, Error = E>,
+        callback: F,
+    ) -> Result<(), String> {
+        self.register(name, chord, ShortcutAction::Callback(Rc::new(callback)))
+    }
+
+    /// Registers a shortcut that sends a `ShortcutEvent` to the given widget. An existing shortcut with the same name is
+    /// replaced. Returns an error if the chord is invalid or already used by another shortcut.
+    pub fn register_event<E: fmt::Display>(
+        &mut self,
+        name: impl Into<String>,
+        chord: impl TryInto<KeyChord, Error = E>,
+        target: Entity,
+    ) -> Result<(), String> {
+        self.register(name, chord, ShortcutAction::Event(target))
+    }
+
+    /// Registers a shortcut with the given action. The chord is a `KeyChord` or a string like `"Ctrl+S"`. An existing
+    /// shortcut with the same name is replaced. Returns an error if the chord is invalid or already used by another
+    /// shortcut.
+    pub fn register<E: fmt::Display>(
+        &mut self,
+        name: impl Into<String>,
+        chord: impl TryInto<KeyChord, Error = E>,
+        action: ShortcutAction,
+    ) -> Result<(), String> {
+        let name = name.into();
+        let chord = chord.try_into().map_err(|e| e.to_string())?;
+
+        if let Some(conflict) = self.conflict(&name, chord) {
+            return Err(format!(
+                "Shortcuts: {} of {} is already used by {}.",
+                chord, name, conflict
+            ));
+        }
+
+        self.unregister(&name);
+        self.shortcuts.push(Shortcut {
+            name,
+            chord,
+            action,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the shortcut with the given name.
+    pub fn unregister(&mut self, name: &str) -> Option<Shortcut> {
+        let index = self.shortcuts.iter().position(|s| s.name == name)?;
+        Some(self.shortcuts.remove(index))
+    }
+
+    /// Returns the name of the shortcut that uses the given chord if it is not the shortcut with the given name.
+    pub fn conflict(&self, name: &str, chord: KeyChord) -> Option<&str> {
+        self.shortcuts
+            .iter()
+            .find(|s| s.chord == chord && s.name != name)
+            .map(|s| s.name.as_str())
+    }
+
+    /// Gets the shortcut with the given name.
+    pub fn get(&self, name: &str) -> Option<&Shortcut> {
+        self.shortcuts.iter().find(|s| s.name == name)
+    }
+
+    /// Gets the shortcut that matches the pressed key and the modifiers of the keyboard state.
+    pub fn find(&self, key: Key, keyboard_state: &KeyboardState) -> Option<&Shortcut> {
+        self.shortcuts
+            .iter()
+            .find(|s| s.chord.matches(key, keyboard_state))
+    }
+
+    /// Returns the display string of the shortcut with the given name, e.g. `Ctrl+S`. It could be used as accelerator
+    /// of a menu item.
+    pub fn display_string(&self, name: &str) -> Option<String> {
+        self.get(name).map(|s| s.chord.to_string())
+    }
+
+    /// Returns the number of registered shortcuts.
+    pub fn len(&self) -> usize {
+        self.shortcuts.len()
+    }
+
+    /// Checks if no shortcut is registered.
+    pub fn is_empty(&self) -> bool {
+        self.shortcuts.is_empty()
+    }
+}
+
+// --- Helpers --
+
+// names of the keys that are not displayed by their character
+static KEY_NAMES: [(Key, &str); 14] = [
+    (Key::Backspace, "Backspace"),
+    (Key::Delete, "Delete"),
+    (Key::Enter, "Enter"),
+    (Key::Tab, "Tab"),
+    (Key::Escape, "Escape"),
+    (Key::Space, "Space"),
+    (Key::Up, "Up"),
+    (Key::Down, "Down"),
+    (Key::Left, "Left"),
+    (Key::Right, "Right"),
+    (Key::Home, "Home"),
+    (Key::End, "End"),
+    (Key::PageUp, "PageUp"),
+    (Key::PageDown, "PageDown"),
+];
+
+// letters are stored in lower case, the shift state is part of the chord
+fn normalize_key(key: Key) -> Key {
+    let text: &str = key.into();
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Key::from(c.to_ascii_lowercase()),
+        _ => key,
+    }
+}
+
+fn key_name(key: Key) -> String {
+    if let Some((_, name)) = KEY_NAMES.iter().find(|(k, _)| *k == key) {
+        return name.to_string();
+    }
+
+    <&str>::from(key).to_uppercase()
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    if let Some((key, _)) = KEY_NAMES.iter().find(|(_, n)| n.eq_ignore_ascii_case(name)) {
+        return Some(*key);
+    }
+
+    match name.to_lowercase().as_str() {
+        "esc" => return Some(Key::Escape),
+        "del" => return Some(Key::Delete),
+        "return" => return Some(Key::Enter),
+        _ => {}
+    }
+
+    let mut chars = name.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => match Key::from(c.to_ascii_lowercase()) {
+            Key::Unknown => None,
+            key => Some(normalize_key(key)),
+        },
+        _ => None,
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "Ctrl+S".parse::<KeyChord>(),
+            Ok(KeyChord::new(Key::S(false)).ctrl())
+        );
+        assert_eq!(
+            "ctrl + shift + z".parse::<KeyChord>(),
+            Ok(KeyChord::new(Key::Z(false)).ctrl().shift())
+        );
+        assert_eq!(
+            "Alt+Enter".parse::<KeyChord>(),
+            Ok(KeyChord::new(Key::Enter).alt())
+        );
+        assert_eq!("Esc".parse::<KeyChord>(), Ok(KeyChord::new(Key::Escape)));
+        assert_eq!(
+            "Ctrl++".parse::<KeyChord>(),
+            Ok(KeyChord::new(Key::NumpadAdd).ctrl())
+        );
+        assert!("Ctrl+".parse::<KeyChord>().is_err());
+        assert!("Super+S".parse::<KeyChord>().is_err());
+        assert!("Ctrl+Foo".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(KeyChord::new(Key::S(true)).ctrl().to_string(), "Ctrl+S");
+        assert_eq!(
+            KeyChord::new(Key::Z(false)).ctrl().shift().to_string(),
+            "Ctrl+Shift+Z"
+        );
+        assert_eq!(KeyChord::new(Key::Delete).alt().to_string(), "Alt+Delete");
+        assert_eq!(KeyChord::new(Key::One).ctrl().to_string(), "Ctrl+1");
+    }
+
+    #[test]
+    fn test_register() {
+        let mut shortcuts = Shortcuts::new();
+
+        assert!(shortcuts
+            .register_event("save", "Ctrl+S", Entity(1))
+            .is_ok());
+        assert!(shortcuts
+            .register_event("undo", KeyChord::new(Key::Z(false)).ctrl(), Entity(1))
+            .is_ok());
+        assert_eq!(
+            shortcuts.register_event("copy", "Ctl+C", Entity(1)),
+            Err("KeyChord: invalid modifier Ctl in Ctl+C.".to_string())
+        );
+        assert!(shortcuts
+            .register_event("store", "ctrl+s", Entity(2))
+            .is_err());
+        assert_eq!(shortcuts.len(), 2);
+        assert_eq!(shortcuts.display_string("save"), Some("Ctrl+S".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(normalize_key(Key::S(true)), Key::S(false));
+        assert_eq!(normalize_key(Key::S(false)), Key::S(false));
+        assert_eq!(normalize_key(Key::Enter), Key::Enter);
+        assert_eq!(normalize_key(Key::One), Key::One);
+    }
+}
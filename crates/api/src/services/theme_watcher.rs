@@ -0,0 +1,127 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use crate::{
+    shell::{now, CONSOLE},
+    theming::{config::ThemeConfig, Theme},
+};
+
+/// The time in milliseconds between two checks of the watched theme files.
+pub const THEME_WATCH_INTERVAL: f64 = 500.0;
+
+/// The `ThemeWatcher` is used during development to iterate on the styling without restarting the application. It
+/// watches the given theme files (RON) and reloads the theme if one of them has changed. The files extend the
+/// given base theme in the given order. It is registered with the key `theme_watcher` in the registry if the
+/// application is started with `Application::watch_theme`.
+///
+/// A file that could not be parsed is reported on the console and the current theme is kept.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Application::new()
+///     .watch_theme(&["assets/my_theme.ron"])
+///     .window(|ctx| Window::new().build(ctx))
+///     .run();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThemeWatcher {
+    base: ThemeConfig,
+    files: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+    last_check: f64,
+}
+
+impl ThemeWatcher {
+    /// Creates a new watcher for the given files that extend the base theme.
+    pub fn new<P: Into<PathBuf>>(base: ThemeConfig, files: impl IntoIterator<Item = P>) -> Self {
+        let files: Vec<PathBuf> = files.into_iter().map(|f| f.into()).collect();
+        let modified = files.iter().map(modified).collect();
+
+        ThemeWatcher {
+            base,
+            files,
+            modified,
+            last_check: 0.0,
+        }
+    }
+
+    /// Gets the watched files.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Reads the base theme extended by all watched files.
+    pub fn theme(&self) -> Result<Theme, String> {
+        let mut config = self.base.clone();
+
+        for file in &self.files {
+            let content = fs::read_to_string(file)
+                .map_err(|e| format!("Could not read theme file {:?}: {}", file, e))?;
+            let file_config = ThemeConfig::parse(&content)
+                .map_err(|e| format!("Could not parse theme file {:?}: {}", file, e))?;
+
+            config = config.extend(file_config);
+        }
+
+        Ok(Theme::from_config(config))
+    }
+
+    /// Checks if one of the watched files has changed since the last call and returns the reloaded theme in this
+    /// case. The files are checked at most every `THEME_WATCH_INTERVAL` milliseconds.
+    pub fn poll(&mut self) -> Option<Theme> {
+        let time = now();
+
+        if time - self.last_check < THEME_WATCH_INTERVAL {
+            return None;
+        }
+
+        self.last_check = time;
+
+        let modified: Vec<Option<SystemTime>> = self.files.iter().map(modified).collect();
+
+        if modified == self.modified {
+            return None;
+        }
+
+        self.modified = modified;
+
+        match self.theme() {
+            Ok(theme) => Some(theme),
+            Err(e) => {
+                CONSOLE.log(e);
+                None
+            }
+        }
+    }
+}
+
+// -- Helpers --
+
+fn modified(file: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(file).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme() {
+        let path = std::env::temp_dir().join("orbtk_theme_watcher_test.ron");
+        fs::write(
+            &path,
+            "Theme ( styles: { \"button\": ( properties: { \"font_size\": 24 } ) } )",
+        )
+        .unwrap();
+
+        let watcher = ThemeWatcher::new(ThemeConfig::default(), vec![path.clone()]);
+        let theme = watcher.theme().unwrap();
+        assert!(theme.style("button").is_some());
+
+        fs::write(&path, "Theme ( styles: {").unwrap();
+        assert!(watcher.theme().is_err());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.theme().is_err());
+    }
+}
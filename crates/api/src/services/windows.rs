@@ -0,0 +1,196 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt,
+    rc::{Rc, Weak},
+    sync::mpsc,
+};
+
+use dces::prelude::Entity;
+
+use crate::{
+    event::{EventQueue, EventStrategy, MessageEvent},
+    shell::WindowRequest,
+};
+
+// The connection to an open window. The event queue is only referenced weakly, it's dropped with the window.
+struct WindowHandle {
+    root: Entity,
+    event_queue: Weak<RefCell<EventQueue>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl WindowHandle {
+    fn is_open(&self) -> bool {
+        self.event_queue.upgrade().is_some()
+    }
+}
+
+/// The `Windows` service gives access to the other open windows of the application. It is registered with the key
+/// `windows` in the registry of each window and shared between all windows of an application.
+///
+/// A window is identified by the `id` of its root `Window` widget, windows without id could not be addressed. If a
+/// window is opened with the id of an open window, the new window replaces the old one in the service.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// registry
+///     .get::<Windows>("windows")
+///     .send_message("main", Color::rgb(255, 0, 0));
+/// ```
+#[derive(Clone, Default)]
+pub struct Windows {
+    windows: Rc<RefCell<BTreeMap<String, WindowHandle>>>,
+
+    // the id of the window that owns this instance of the service
+    id: Option<String>,
+}
+
+impl Windows {
+    /// Creates a new windows service without windows.
+    pub fn new() -> Self {
+        Windows::default()
+    }
+
+    /// Gets the id of the window that owns the service, or `None` if its root `Window` widget has no id.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the ids of all open windows in alphabetical order.
+    pub fn ids(&self) -> Vec<String> {
+        self.remove_closed();
+        self.windows.borrow().keys().cloned().collect()
+    }
+
+    /// Checks if a window with the given id is open.
+    pub fn contains(&self, id: &str) -> bool {
+        self.windows
+            .borrow()
+            .get(id)
+            .map_or(false, |window| window.is_open())
+    }
+
+    /// Requests to close the window with the given id. Returns `false` if there is no open window with the id.
+    pub fn close(&self, id: &str) -> bool {
+        self.remove_closed();
+
+        self.windows.borrow().get(id).map_or(false, |window| {
+            window.window_sender.send(WindowRequest::Close).is_ok()
+        })
+    }
+
+    /// Sends a message to the window with the given id. It is passed as `MessageEvent` to the `on_message` handler of
+    /// the root `Window` widget of the receiving window on its next update. Returns `false` if there is no open window
+    /// with the id.
+    pub fn send_message<M: Any>(&self, id: &str, message: M) -> bool {
+        self.remove_closed();
+
+        let windows = self.windows.borrow();
+
+        let window = match windows.get(id) {
+            Some(window) => window,
+            None => return false,
+        };
+
+        let event_queue = match window.event_queue.upgrade() {
+            Some(event_queue) => event_queue,
+            None => return false,
+        };
+
+        event_queue.borrow_mut().register_event_with_strategy(
+            MessageEvent::new(self.id.clone(), message),
+            EventStrategy::Direct,
+            window.root,
+        );
+
+        // wakes up the receiving window
+        window.window_sender.send(WindowRequest::Redraw).is_ok()
+    }
+
+    /// Adds a window with the given id and returns the instance of the service that is owned by the window.
+    pub(crate) fn register(
+        &self,
+        id: Option<String>,
+        root: Entity,
+        event_queue: &Rc<RefCell<EventQueue>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) -> Windows {
+        self.remove_closed();
+
+        if let Some(id) = &id {
+            self.windows.borrow_mut().insert(
+                id.clone(),
+                WindowHandle {
+                    root,
+                    event_queue: Rc::downgrade(event_queue),
+                    window_sender,
+                },
+            );
+        }
+
+        Windows {
+            windows: self.windows.clone(),
+            id,
+        }
+    }
+
+    fn remove_closed(&self) {
+        self.windows
+            .borrow_mut()
+            .retain(|_, window| window.is_open());
+    }
+}
+
+impl fmt::Debug for Windows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows")
+            .field("id", &self.id)
+            .field("windows", &self.ids())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let windows = Windows::new();
+        let (sender, receiver) = mpsc::channel();
+        let event_queue = Rc::new(RefCell::new(EventQueue::new()));
+
+        let main = windows.register(Some("main".to_string()), Entity(0), &event_queue, sender);
+        assert_eq!(main.id(), Some("main"));
+        assert_eq!(windows.ids(), vec!["main".to_string()]);
+
+        let (palette_sender, _) = mpsc::channel();
+        let palette_queue = Rc::new(RefCell::new(EventQueue::new()));
+        let palette = windows.register(
+            Some("palette".to_string()),
+            Entity(1),
+            &palette_queue,
+            palette_sender,
+        );
+
+        assert!(palette.send_message("main", 42_u32));
+        assert!(!palette.send_message("missing", 42_u32));
+        assert_eq!(event_queue.borrow().len(), 1);
+        assert!(receiver
+            .try_iter()
+            .any(|r| matches!(r, WindowRequest::Redraw)));
+
+        assert!(palette.close("main"));
+        assert!(receiver
+            .try_iter()
+            .any(|r| matches!(r, WindowRequest::Close)));
+
+        // the window is closed if its event queue is dropped
+        drop(palette_queue);
+        assert!(!windows.contains("palette"));
+        assert_eq!(main.ids(), vec!["main".to_string()]);
+    }
+}
@@ -2,7 +2,15 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use crate::{
+    prelude::*,
+    render::RenderContext2D,
+    render_object, shell,
+    shell::{Gesture, Key, MouseButton},
+    theming::{Selector, Theme},
+    tree::Tree,
+    utils::*,
+};
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
 #[derive(Constructor)]
@@ -48,6 +56,25 @@ impl EventStateSystem {
             .handler_map
             .borrow_mut()
             .remove(&entity);
+        self.context_provider
+            .hovered_widgets
+            .borrow_mut()
+            .retain(|hovered| *hovered != entity);
+
+        let root = ecm.entity_store().root();
+        if let Ok(transitions) = ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("transitions", root)
+        {
+            transitions.remove_widget(entity);
+        }
+
+        if let Ok(animations) = ecm
+            .component_store_mut()
+            .get_mut::<Animations>("animations", root)
+        {
+            animations.remove_widget(entity);
+        }
     }
 
     fn process_direct(
@@ -88,7 +115,7 @@ impl EventStateSystem {
         mouse_position: Point,
         event: &EventBox,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-    ) -> bool {
+    ) -> (bool, Option<Entity>) {
         let mut matching_nodes = vec![];
         let mut update = false;
 
@@ -118,8 +145,15 @@ impl EventStateSystem {
             }
         }
 
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            if let Ok(global) = ecm.component_store_mut().get_mut::<Global>("global", root) {
+                global.mouse_position = event.position;
+            }
+        }
+
         let mut unknown_event = true;
         let mut clipped_parent = vec![];
+        let mut hovered_widgets = vec![];
 
         loop {
             if !disabled_parents.is_empty() {
@@ -213,6 +247,37 @@ impl EventStateSystem {
                     }
                     unknown_event = false;
                 }
+                // drag over, drop and gesture handling
+                if let Some(position) = drag_position(event).or_else(|| gesture_position(event)) {
+                    if check_mouse_condition(
+                        position,
+                        &WidgetContainer::new(
+                            current_node,
+                            ecm,
+                            &theme,
+                            Some(&self.context_provider.event_queue),
+                        ),
+                    ) {
+                        let mut add = true;
+                        if let Some(op) = clipped_parent.get(0) {
+                            if !check_mouse_condition(
+                                position,
+                                &WidgetContainer::new(
+                                    *op,
+                                    ecm,
+                                    &theme,
+                                    Some(&self.context_provider.event_queue),
+                                ),
+                            ) {
+                                add = false;
+                            }
+                        }
+                        if add && has_handler {
+                            matching_nodes.push(current_node);
+                        }
+                    }
+                    unknown_event = false;
+                }
                 // mouse down handling
                 if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
                     if check_mouse_condition(
@@ -275,6 +340,9 @@ impl EventStateSystem {
                         if add && has_handler {
                             matching_nodes.push(current_node);
                         }
+                        if add {
+                            hovered_widgets.push(current_node);
+                        }
                     }
                     unknown_event = false;
                 }
@@ -309,7 +377,59 @@ impl EventStateSystem {
             }
         }
 
+        // overlapping siblings are stacked by their z index, the widgets are sorted in the order they are drawn
+        render_object::sort_by_render_order(ecm, &mut matching_nodes);
+        render_object::sort_by_render_order(ecm, &mut hovered_widgets);
+
+        // the last hovered widget with a tool tip is the top most widget under the mouse
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            let tool_tip_target = hovered_widgets.iter().rev().find(|widget| {
+                ecm.component_store()
+                    .get::<String>("tooltip", **widget)
+                    .map(|tooltip| !tooltip.is_empty())
+                    .unwrap_or(false)
+            });
+
+            self.context_provider
+                .tool_tip
+                .borrow_mut()
+                .hover(tool_tip_target.map(|target| target.0), event.position);
+        }
+
+        // the widgets under the mouse get the hover state, the top most widget with a cursor defines the cursor
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            self.update_cursor(&hovered_widgets, ecm);
+            update = self.process_hover(hovered_widgets, event.position, &theme, ecm) || update;
+        }
+
+        // hides the tool tip on mouse down
+        if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+            self.context_provider
+                .tool_tip
+                .borrow_mut()
+                .hover(None, event.position);
+        }
+
+        // key and composition events are handled by the focused widget, its children and its parents first
+        if event.downcast_ref::<KeyDownEvent>().is_ok()
+            || event.downcast_ref::<KeyUpEvent>().is_ok()
+            || event.downcast_ref::<CompositionEvent>().is_ok()
+        {
+            if let Some(focused_widget) = self.focused_widget() {
+                let (focus_path, others): (Vec<Entity>, Vec<Entity>) =
+                    matching_nodes.into_iter().partition(|node| {
+                        is_ancestor(*node, focused_widget, ecm)
+                            || is_ancestor(focused_widget, *node, ecm)
+                    });
+
+                // the handlers are called from the end of the list
+                matching_nodes = others;
+                matching_nodes.extend(focus_path);
+            }
+        }
+
         let mut handled = false;
+        let mut handled_node = None;
 
         for node in matching_nodes.iter().rev() {
             if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
@@ -327,12 +447,576 @@ impl EventStateSystem {
             }
 
             if handled {
+                handled_node = Some(*node);
                 break;
             }
         }
 
+        (update, handled_node)
+    }
+
+    // Gets the widget with the keyboard focus.
+    fn focused_widget(&self) -> Option<Entity> {
+        self.registry
+            .borrow()
+            .try_get::<FocusManager>("focus_manager")
+            .and_then(|focus_manager| focus_manager.focused_widget())
+    }
+
+    // Moves the keyboard focus by Tab or by Shift+Tab if the key event is not handled by a widget.
+    fn process_tab_navigation(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        match event.downcast_ref::<KeyDownEvent>() {
+            Ok(event) if event.event.key == Key::Tab => {}
+            _ => return false,
+        }
+
+        let root = ecm.entity_store().root();
+        let reverse = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .map(|global| global.keyboard_state.is_shift_down())
+            .unwrap_or(false);
+        let widgets = focusable_widgets(ecm);
+
+        let next = self
+            .registry
+            .borrow()
+            .try_get::<FocusManager>("focus_manager")
+            .and_then(|focus_manager| focus_manager.next_focus(&widgets, reverse));
+
+        match next {
+            Some(next) => self.process_direct(
+                &EventBox::new(FocusEvent::RequestFocus(next), EventStrategy::Direct, root),
+                ecm,
+            ),
+            None => false,
+        }
+    }
+
+    // Dispatches a key down event that matches a registered shortcut. Returns true if the shortcut has handled the
+    // event, then it is not routed to the widgets.
+    fn process_shortcut(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let key = match event.downcast_ref::<KeyDownEvent>() {
+            Ok(event) => event.event.key,
+            _ => return false,
+        };
+
+        let root = ecm.entity_store().root();
+        let shortcut = match ecm.component_store().get::<Global>("global", root) {
+            Ok(global) => self
+                .registry
+                .borrow()
+                .try_get::<Shortcuts>("shortcuts")
+                .and_then(|shortcuts| shortcuts.find(key, &global.keyboard_state))
+                .map(|shortcut| (shortcut.name.clone(), shortcut.action.clone())),
+            Err(_) => None,
+        };
+
+        match shortcut {
+            Some((_, ShortcutAction::Callback(callback))) => callback(&mut StatesContext::new(
+                &mut *self.context_provider.states.borrow_mut(),
+                ecm,
+            )),
+            Some((name, ShortcutAction::Event(target))) => self.process_direct(
+                &EventBox::new(ShortcutEvent(name), EventStrategy::Direct, target),
+                ecm,
+            ),
+            None => false,
+        }
+    }
+
+    // Shows the mnemonics while Alt is pressed and activates the widget of a mnemonic by Alt and its key. Returns true
+    // if a widget is activated, then the key event is not routed to the widgets.
+    fn process_mnemonic(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        // redraws the underline of the mnemonics
+        if event
+            .downcast_ref::<KeyDownEvent>()
+            .map_or(false, |event| event.event.key == Key::Alt)
+            || event
+                .downcast_ref::<KeyUpEvent>()
+                .map_or(false, |event| event.event.key == Key::Alt)
+        {
+            for entity in mnemonic_widgets(ecm) {
+                mark_as_dirty(entity, ecm);
+            }
+
+            return false;
+        }
+
+        let key = match event.downcast_ref::<KeyDownEvent>() {
+            Ok(event) => event.event.key,
+            _ => return false,
+        };
+
+        let root = ecm.entity_store().root();
+        let alt_down = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .map(|global| {
+                global.keyboard_state.is_alt_down() && !global.keyboard_state.is_ctrl_down()
+            })
+            .unwrap_or(false);
+
+        let character = match mnemonic_key(key) {
+            Some(character) if alt_down => character,
+            _ => return false,
+        };
+
+        // the first widget in tree order with a click handler is activated
+        for entity in mnemonic_widgets(ecm) {
+            let matches = ecm
+                .component_store()
+                .get::<String16>("text", entity)
+                .map_or(false, |text| {
+                    Mnemonic::parse(&text.to_string()).key() == Some(character)
+                });
+
+            if matches
+                && self.process_direct(
+                    &EventBox::new(
+                        ClickEvent {
+                            position: Point::default(),
+                        },
+                        EventStrategy::Direct,
+                        entity,
+                    ),
+                    ecm,
+                )
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Shows the cursor of the top most widget under the mouse. While the left mouse button is pressed the cursor is
+    // kept, e.g. a splitter keeps its resize cursor while it is dragged.
+    fn update_cursor(
+        &self,
+        hovered_widgets: &[Entity],
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        if self.context_provider.gestures.borrow().is_pressed() {
+            return;
+        }
+
+        let cursor = hovered_widgets
+            .iter()
+            .rev()
+            .find_map(|entity| {
+                ecm.component_store()
+                    .get::<CursorIcon>("cursor", *entity)
+                    .ok()
+                    .copied()
+            })
+            .unwrap_or_default();
+
+        if cursor == self.context_provider.cursor.get() {
+            return;
+        }
+
+        self.context_provider.cursor.set(cursor);
+        let _ = self
+            .context_provider
+            .window_sender
+            .send(shell::WindowRequest::ChangeCursor(cursor));
+    }
+
+    // Sets the hover selector state of the widgets the mouse has entered and clears it on the widgets the mouse has
+    // left. The widgets get also the mouse enter and mouse leave events.
+    fn process_hover(
+        &self,
+        hovered_widgets: Vec<Entity>,
+        position: Point,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let previous = self
+            .context_provider
+            .hovered_widgets
+            .replace(hovered_widgets.clone());
+        let mut update = false;
+
+        // children are left before their parents
+        for entity in previous
+            .iter()
+            .rev()
+            .filter(|entity| !hovered_widgets.contains(entity))
+        {
+            set_hover(
+                &mut WidgetContainer::new(
+                    *entity,
+                    ecm,
+                    theme,
+                    Some(&self.context_provider.event_queue),
+                ),
+                false,
+            );
+            self.process_direct(
+                &EventBox::new(MouseLeaveEvent { position }, EventStrategy::Direct, *entity),
+                ecm,
+            );
+            update = true;
+        }
+
+        for entity in hovered_widgets
+            .iter()
+            .filter(|entity| !previous.contains(entity))
+        {
+            set_hover(
+                &mut WidgetContainer::new(
+                    *entity,
+                    ecm,
+                    theme,
+                    Some(&self.context_provider.event_queue),
+                ),
+                true,
+            );
+            self.process_direct(
+                &EventBox::new(MouseEnterEvent { position }, EventStrategy::Direct, *entity),
+                ecm,
+            );
+            update = true;
+        }
+
         update
     }
+
+    // Feeds the left mouse button and touch events to the gesture recognizer and dispatches the recognized gestures.
+    fn process_gestures(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let recognized: Vec<Gesture> = {
+            let mut gestures = self.context_provider.gestures.borrow_mut();
+
+            if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+                match event.button {
+                    MouseButton::Left => gestures.mouse_down(event.position).into_iter().collect(),
+                    _ => vec![],
+                }
+            } else if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+                match event.button {
+                    MouseButton::Left => gestures.mouse_up(event.position).into_iter().collect(),
+                    _ => vec![],
+                }
+            } else if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+                gestures.mouse_move(event.position);
+                vec![]
+            } else if let Ok(event) = event.downcast_ref::<TouchEvent>() {
+                gestures.touch(event.id, event.phase, event.position)
+            } else {
+                vec![]
+            }
+        };
+
+        recognized.into_iter().fold(false, |update, gesture| {
+            self.dispatch_gesture(gesture, ecm) || update
+        })
+    }
+
+    // Dispatches a long press if the mouse is pressed long enough. While a long press is pending the window wakes up
+    // when the long press time is elapsed.
+    fn poll_gestures(&self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) -> bool {
+        let (gesture, long_press_time) = {
+            let mut gestures = self.context_provider.gestures.borrow_mut();
+            (gestures.poll(), gestures.long_press_time())
+        };
+
+        if let Some(time) = long_press_time {
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(shell::WindowRequest::RedrawAt(time));
+        }
+
+        match gesture {
+            Some(gesture) => self.dispatch_gesture(gesture, ecm),
+            None => false,
+        }
+    }
+
+    // Calls the handlers of the activated actions of desktop notifications.
+    fn poll_desktop_notifications(
+        &self,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let actions = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<Notifications>("desktop_notifications")
+            .map(|notifications| notifications.poll())
+            .unwrap_or_default();
+
+        for action in actions {
+            (action.handler)(&mut StatesContext::new(
+                &mut *self.context_provider.states.borrow_mut(),
+                ecm,
+            ));
+        }
+    }
+
+    // Sends the event of a gesture to the top most widget under its position that handles it.
+    fn dispatch_gesture(
+        &self,
+        gesture: Gesture,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let root = ecm.entity_store().root();
+
+        let event = match gesture {
+            Gesture::DoubleClick(position) => {
+                EventBox::new(DoubleClickEvent { position }, EventStrategy::BottomUp, root)
+            }
+            Gesture::LongPress(position) => {
+                EventBox::new(LongPressEvent { position }, EventStrategy::BottomUp, root)
+            }
+            Gesture::Swipe(position, direction, velocity) => EventBox::new(
+                SwipeEvent {
+                    position,
+                    direction,
+                    velocity,
+                },
+                EventStrategy::BottomUp,
+                root,
+            ),
+            Gesture::Pinch(position, scale) => EventBox::new(
+                PinchEvent { position, scale },
+                EventStrategy::BottomUp,
+                root,
+            ),
+            Gesture::Rotate(position, angle) => EventBox::new(
+                RotateEvent { position, angle },
+                EventStrategy::BottomUp,
+                root,
+            ),
+        };
+
+        self.process_bottom_up_event(self.context_provider.mouse_position.get(), &event, ecm)
+            .0
+    }
+
+    // Starts, moves, drops and cancels the drag-and-drop operation of the window.
+    fn process_drag(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragStartEvent>() {
+            self.drag_drop(|service| {
+                service.start(Drag {
+                    source: event.source,
+                    position: event.position,
+                    data: event.data.clone(),
+                })
+            });
+            self.update_drag_host(ecm);
+            return true;
+        }
+
+        let dragging = self
+            .drag_drop(|service| service.is_dragging())
+            .unwrap_or(false);
+
+        if let Ok(event) = event.downcast_ref::<ExternalDragEvent>() {
+            match &event.event {
+                shell::ExternalDragEvent::Enter(data) => {
+                    self.start_external_drag(event.position, data.clone(), ecm);
+                }
+                shell::ExternalDragEvent::Drop(data) => {
+                    // not all shells report the drag before the drop
+                    if dragging {
+                        self.drag_over(event.position, ecm);
+                    } else {
+                        self.start_external_drag(event.position, data.clone(), ecm);
+                    }
+
+                    self.drop_data(event.position, ecm);
+                }
+                shell::ExternalDragEvent::Leave => self.cancel_drag(ecm),
+            }
+
+            self.update_drag_host(ecm);
+            return true;
+        }
+
+        if !dragging {
+            return false;
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            self.drag_over(event.position, ecm);
+        } else if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+            self.drop_data(event.position, ecm);
+        } else if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
+            if event.event.key != Key::Escape {
+                return false;
+            }
+
+            self.cancel_drag(ecm);
+        } else {
+            return false;
+        }
+
+        self.update_drag_host(ecm);
+        true
+    }
+
+    // Calls the given function with the drag-and-drop service of the window.
+    fn drag_drop<R>(&self, f: impl FnOnce(&mut DragDropService) -> R) -> Option<R> {
+        self.registry
+            .borrow_mut()
+            .try_get_mut::<DragDropService>("drag_drop")
+            .map(f)
+    }
+
+    // Sends the drag over event to the widgets under the mouse, the widget that accepts it becomes the target.
+    fn drag_over(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let drag = match self
+            .drag_drop(|service| {
+                service.move_to(position);
+                service.drag().cloned()
+            })
+            .flatten()
+        {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        let root = ecm.entity_store().root();
+        let (_, target) = self.process_bottom_up_event(
+            position,
+            &EventBox::new(DragOverEvent(drag.clone()), EventStrategy::BottomUp, root),
+            ecm,
+        );
+
+        let previous = match self
+            .drag_drop(|service| service.set_target(target))
+            .flatten()
+        {
+            Some(previous) => previous,
+            None => return,
+        };
+
+        if let Some(previous) = previous {
+            self.process_direct(
+                &EventBox::new(
+                    DragLeaveEvent(drag.clone()),
+                    EventStrategy::Direct,
+                    previous,
+                ),
+                ecm,
+            );
+        }
+
+        if let Some(target) = target {
+            self.process_direct(
+                &EventBox::new(DragEnterEvent(drag), EventStrategy::Direct, target),
+                ecm,
+            );
+        }
+    }
+
+    // Starts a drag of another application, the window is the source of the drag.
+    fn start_external_drag(
+        &self,
+        position: Point,
+        data: shell::DropData,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let root = ecm.entity_store().root();
+        self.drag_drop(|service| {
+            service.start(Drag {
+                source: root,
+                position,
+                data: DragData::from(data),
+            })
+        });
+        self.drag_over(position, ecm);
+    }
+
+    // Drops the data on the widgets under the mouse if the drag is accepted and finishes the drag.
+    fn drop_data(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let (mut drag, target) = match self.drag_drop(|service| service.finish()).flatten() {
+            Some(finished) => finished,
+            None => return,
+        };
+
+        drag.position = position;
+        let mut dropped = false;
+
+        if target.is_some() {
+            let root = ecm.entity_store().root();
+            let (_, node) = self.process_bottom_up_event(
+                position,
+                &EventBox::new(DropEvent(drag.clone()), EventStrategy::BottomUp, root),
+                ecm,
+            );
+            dropped = node.is_some();
+        }
+
+        self.end_drag(drag, dropped, ecm);
+    }
+
+    // Cancels the drag without a drop.
+    fn cancel_drag(&self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+        let (drag, target) = match self.drag_drop(|service| service.finish()).flatten() {
+            Some(finished) => finished,
+            None => return,
+        };
+
+        if let Some(target) = target {
+            self.process_direct(
+                &EventBox::new(DragLeaveEvent(drag.clone()), EventStrategy::Direct, target),
+                ecm,
+            );
+        }
+
+        self.end_drag(drag, false, ecm);
+    }
+
+    // Notifies the source widget that the drag is finished.
+    fn end_drag(
+        &self,
+        drag: Drag,
+        dropped: bool,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let source = drag.source;
+        self.process_direct(
+            &EventBox::new(DragEndEvent(drag, dropped), EventStrategy::Direct, source),
+            ecm,
+        );
+    }
+
+    // Updates the widget that shows the drag, e.g. the drag ghost of the window.
+    fn update_drag_host(&self, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+        if let Some(host) = self.drag_drop(|service| service.host()).flatten() {
+            mark_as_dirty(host, ecm);
+        }
+    }
 }
 
 impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
@@ -343,6 +1027,79 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
     ) {
         let mut update = false;
 
+        // mark all widgets as dirty that have requested an update on this frame
+        for entity in self.context_provider.update_requests.borrow_mut().drain(..) {
+            mark_as_dirty(entity, ecm);
+        }
+
+        // mark all widgets as dirty whose requested update time is elapsed
+        let now = shell::now();
+        let scheduled_updates: Vec<Entity> = {
+            let mut scheduled_updates = self.context_provider.scheduled_updates.borrow_mut();
+            let due = scheduled_updates
+                .iter()
+                .filter(|(time, _)| *time <= now)
+                .map(|(_, entity)| *entity)
+                .collect();
+            scheduled_updates.retain(|(time, _)| *time > now);
+            due
+        };
+
+        for entity in scheduled_updates {
+            mark_as_dirty(entity, ecm);
+        }
+
+        update = self.poll_gestures(ecm) || update;
+        self.poll_desktop_notifications(ecm);
+
+        // the theme transitions are advanced on each frame until they are finished
+        if Transitions::run(ecm) {
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(shell::WindowRequest::Redraw);
+        }
+
+        // the animations are driven by the frame clock, the handlers of finished animations are called afterwards
+        let frame = Animations::run(ecm);
+
+        for (entity, handler) in frame.finished {
+            handler(
+                &mut StatesContext::new(&mut *self.context_provider.states.borrow_mut(), ecm),
+                entity,
+            );
+        }
+
+        if frame.running {
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(shell::WindowRequest::Redraw);
+        }
+
+        // development mode: restyles the whole tree if a watched theme file has changed
+        let theme = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<ThemeWatcher>("theme_watcher")
+            .and_then(|theme_watcher| {
+                // the files are checked again after the interval even if the window is idle
+                let _ = self
+                    .context_provider
+                    .window_sender
+                    .send(shell::WindowRequest::RedrawAt(
+                        shell::now() + THEME_WATCH_INTERVAL,
+                    ));
+
+                theme_watcher.poll()
+            });
+
+        if let Some(theme) = theme {
+            let root = ecm.entity_store().root();
+            Context::new((root, ecm), &theme, &self.context_provider, render_context)
+                .switch_theme(theme.clone());
+        }
+
         loop {
             {
                 let mouse_position = self.context_provider.mouse_position.get();
@@ -366,11 +1123,26 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         //     self.process_top_down_event(&event, ecm);
                         // }
                         EventStrategy::BottomUp => {
-                            let should_update =
+                            // shortcuts and mnemonics of the window are dispatched before the key routing
+                            if self.process_shortcut(&event, ecm)
+                                || self.process_mnemonic(&event, ecm)
+                            {
+                                update = true;
+                                continue;
+                            }
+
+                            let (should_update, handled_node) =
                                 self.process_bottom_up_event(mouse_position, &event, ecm);
                             update = update || should_update;
+
+                            if handled_node.is_none() {
+                                update = self.process_tab_navigation(&event, ecm) || update;
+                            }
                         }
                     }
+
+                    update = self.process_drag(&event, ecm) || update;
+                    update = self.process_gestures(&event, ecm) || update;
                 }
             }
 
@@ -380,7 +1152,7 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
 
             let root = ecm.entity_store().root();
 
-            let theme = ecm
+            let mut theme = ecm
                 .component_store()
                 .get::<Global>("global", root)
                 .unwrap()
@@ -434,6 +1206,11 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                             state.update(registry, &mut ctx);
                         }
 
+                        // the following states are updated with the switched theme
+                        if let Some(switched_theme) = ctx.switched_theme() {
+                            theme = switched_theme.clone();
+                        }
+
                         keys.append(&mut ctx.new_states_keys());
 
                         remove_widget_list.append(ctx.remove_widget_list());
@@ -481,3 +1258,170 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
         }
     }
 }
+
+// --- Helpers --
+
+// Marks the given widget as dirty, its state is updated on this frame.
+fn mark_as_dirty(entity: Entity, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+    let root = ecm.entity_store().root();
+
+    if let Ok(dirty) = ecm.component_store_mut().get_mut::<bool>("dirty", entity) {
+        *dirty = true;
+    } else {
+        return;
+    }
+
+    if let Ok(dirty_widgets) = ecm
+        .component_store_mut()
+        .get_mut::<Vec<Entity>>("dirty_widgets", root)
+    {
+        if !dirty_widgets.contains(&entity) {
+            dirty_widgets.push(entity);
+        }
+    }
+}
+
+// Checks if the given ancestor is the entity itself or one of its parents.
+fn is_ancestor(
+    ancestor: Entity,
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> bool {
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        if node == ancestor {
+            return true;
+        }
+
+        current = ecm.entity_store().parent.get(&node).copied().flatten();
+    }
+
+    false
+}
+
+// Checks if the widget and all of its parents are enabled and visible.
+fn is_reachable(entity: Entity, ecm: &EntityComponentManager<Tree, StringComponentStore>) -> bool {
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        let store = ecm.component_store();
+
+        if !store.get::<bool>("enabled", node).map_or(true, |e| *e)
+            || store
+                .get::<Visibility>("visibility", node)
+                .map_or(false, |v| *v != Visibility::Visible)
+        {
+            return false;
+        }
+
+        current = ecm.entity_store().parent.get(&node).copied().flatten();
+    }
+
+    true
+}
+
+// Returns the focusable widgets of the window in tree order with their tab index.
+fn focusable_widgets(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Vec<(Entity, i32)> {
+    let root = ecm.entity_store().root();
+
+    ecm.entity_store()
+        .start_node(root)
+        .into_iter()
+        .filter(|entity| {
+            ecm.component_store()
+                .get::<bool>("focusable", *entity)
+                .map_or(false, |f| *f)
+                && is_reachable(*entity, ecm)
+        })
+        .map(|entity| {
+            let tab_index = ecm
+                .component_store()
+                .get::<i32>("tab_index", entity)
+                .map_or(0, |t| *t);
+            (entity, tab_index)
+        })
+        .collect()
+}
+
+// Returns the widgets with mnemonics that are enabled and visible in tree order.
+fn mnemonic_widgets(ecm: &EntityComponentManager<Tree, StringComponentStore>) -> Vec<Entity> {
+    let root = ecm.entity_store().root();
+
+    ecm.entity_store()
+        .start_node(root)
+        .into_iter()
+        .filter(|entity| {
+            ecm.component_store()
+                .get::<bool>("mnemonic", *entity)
+                .map_or(false, |m| *m)
+                && is_reachable(*entity, ecm)
+        })
+        .collect()
+}
+
+// Returns the lower case character of a key that could be a mnemonic.
+fn mnemonic_key(key: Key) -> Option<char> {
+    let text: &str = key.into();
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_alphanumeric() => Some(c.to_lowercase().next().unwrap_or(c)),
+        _ => None,
+    }
+}
+
+// Sets or clears the hover pseudo-class of a widget. Other pseudo-classes like pressed or focused are kept.
+fn set_hover(widget: &mut WidgetContainer, hover: bool) {
+    let changed = widget
+        .try_get_mut::<Selector>("selector")
+        .map_or(false, |selector| selector.set_pseudo_class("hover", hover));
+
+    if changed {
+        widget.update(false);
+    }
+}
+
+// Returns the mouse or touch position of a gesture event.
+fn gesture_position(event: &EventBox) -> Option<Point> {
+    if let Ok(event) = event.downcast_ref::<DoubleClickEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<PinchEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<RotateEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<LongPressEvent>() {
+        return Some(event.position);
+    }
+
+    event
+        .downcast_ref::<SwipeEvent>()
+        .ok()
+        .map(|event| event.position)
+}
+
+// Returns the mouse position of a drag over or drop event.
+fn drag_position(event: &EventBox) -> Option<Point> {
+    if let Ok(event) = event.downcast_ref::<DragOverEvent>() {
+        return Some(event.0.position);
+    }
+
+    event
+        .downcast_ref::<DropEvent>()
+        .ok()
+        .map(|event| event.0.position)
+}
+
+// --- Helpers --
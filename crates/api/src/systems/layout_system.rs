@@ -16,6 +16,14 @@ impl System<Tree, StringComponentStore, RenderContext2D> for LayoutSystem {
     ) {
         let root = ecm.entity_store().root();
 
+        // fonts that are registered at runtime are measured with this layout pass
+        if self.context_provider.fonts.register_pending(render_context) {
+            ecm.component_store_mut()
+                .get_mut::<Vec<Entity>>("dirty_widgets", root)
+                .unwrap()
+                .push(root);
+        }
+
         if ecm
             .component_store()
             .get::<Vec<Entity>>("dirty_widgets", root)
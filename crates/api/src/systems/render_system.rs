@@ -2,9 +2,10 @@ use std::collections::BTreeMap;
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, tree::Tree};
+use crate::{prelude::*, render::RenderContext2D, render_object, tree::Tree, utils::*};
 
-/// The `RenderSystem` iterates over all visual widgets and used its render objects to draw them on the screen.
+/// The `RenderSystem` iterates over all visual widgets and used its render objects to draw them on the screen. Only
+/// the region of the window that contains the changed widgets is drawn again.
 #[derive(Constructor)]
 pub struct RenderSystem {
     context_provider: ContextProvider,
@@ -29,8 +30,8 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
         }
 
         // reset the dirty flag of all dirty widgets to `false`
-        for widget in dirty_widgets {
-            if let Ok(dirty) = ecm.component_store_mut().get_mut::<bool>("dirty", widget) {
+        for widget in dirty_widgets.iter() {
+            if let Ok(dirty) = ecm.component_store_mut().get_mut::<bool>("dirty", *widget) {
                 *dirty = false;
             }
         }
@@ -40,6 +41,25 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
             .unwrap()
             .clear();
 
+        let screen_bounds = render_object::screen_bounds(ecm, root);
+        let changed_region = dirty_region(
+            &dirty_widgets,
+            &self.context_provider.screen_bounds.borrow(),
+            &screen_bounds,
+        );
+        self.context_provider.screen_bounds.replace(screen_bounds);
+
+        let region = if self.context_provider.first_run.get() {
+            None
+        } else {
+            match changed_region {
+                Some(region) => Some(region),
+
+                // nothing visible has changed
+                None => return,
+            }
+        };
+
         #[cfg(feature = "debug")]
         let debug = true;
         #[cfg(not(feature = "debug"))]
@@ -59,7 +79,13 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
 
         // CONSOLE.time("render");
 
-        render_context.start();
+        match region {
+            Some(region) => render_context.start_region(region),
+            None => render_context.start(),
+        }
+
+        self.context_provider.dirty_region.set(region);
+
         render_context.begin_path();
         self.context_provider.render_objects.borrow()[&root].render(
             render_context,
@@ -72,8 +98,100 @@ impl System<Tree, StringComponentStore, RenderContext2D> for RenderSystem {
         );
         render_context.finish();
 
+        self.context_provider.dirty_region.set(None);
+
         if self.context_provider.first_run.get() {
             self.context_provider.first_run.set(false);
         }
     }
 }
+
+// Calculates the region of the window that has to be drawn again. It contains the old and the new bounds of the dirty
+// widgets and of all widgets that have been moved, resized, shown or hidden since the last frame. The region is
+// rounded to whole pixels and grown by one pixel for the anti-aliasing. Returns `None` if nothing visible has
+// changed.
+fn dirty_region(
+    dirty_widgets: &[Entity],
+    previous_bounds: &BTreeMap<Entity, Rectangle>,
+    screen_bounds: &BTreeMap<Entity, Rectangle>,
+) -> Option<Rectangle> {
+    let mut region: Option<Rectangle> = None;
+
+    let mut add = |rect: Option<&Rectangle>| {
+        if let Some(rect) = rect.filter(|r| r.width() > 0.0 && r.height() > 0.0) {
+            region = Some(region.map_or(*rect, |region| region.union(rect)));
+        }
+    };
+
+    for widget in dirty_widgets {
+        add(previous_bounds.get(widget));
+        add(screen_bounds.get(widget));
+    }
+
+    for (entity, bounds) in screen_bounds {
+        let previous = previous_bounds.get(entity);
+
+        if previous != Some(bounds) {
+            add(previous);
+            add(Some(bounds));
+        }
+    }
+
+    for (entity, bounds) in previous_bounds {
+        if !screen_bounds.contains_key(entity) {
+            add(Some(bounds));
+        }
+    }
+
+    region.map(|region| {
+        let x = region.x().floor() - 1.0;
+        let y = region.y().floor() - 1.0;
+
+        Rectangle::new(
+            (x, y),
+            (
+                (region.x() + region.width()).ceil() + 1.0 - x,
+                (region.y() + region.height()).ceil() + 1.0 - y,
+            ),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dirty_region() {
+        let mut previous_bounds = BTreeMap::new();
+        previous_bounds.insert(Entity(0), Rectangle::new((0.0, 0.0), (100.0, 100.0)));
+        previous_bounds.insert(Entity(1), Rectangle::new((10.0, 10.0), (20.0, 10.0)));
+        previous_bounds.insert(Entity(2), Rectangle::new((50.0, 50.0), (10.0, 10.0)));
+
+        // nothing has changed
+        let screen_bounds = previous_bounds.clone();
+        assert_eq!(dirty_region(&[], &previous_bounds, &screen_bounds), None);
+
+        // a widget has changed its content
+        assert_eq!(
+            dirty_region(&[Entity(1)], &previous_bounds, &screen_bounds),
+            Some(Rectangle::new((9.0, 9.0), (22.0, 12.0)))
+        );
+
+        // a widget has been moved, the old and the new bounds are drawn again
+        let mut screen_bounds = previous_bounds.clone();
+        screen_bounds.insert(Entity(2), Rectangle::new((60.5, 50.0), (10.0, 10.0)));
+        assert_eq!(
+            dirty_region(&[], &previous_bounds, &screen_bounds),
+            Some(Rectangle::new((49.0, 49.0), (23.0, 12.0)))
+        );
+
+        // a widget has been hidden
+        let mut screen_bounds = previous_bounds.clone();
+        screen_bounds.remove(&Entity(1));
+        assert_eq!(
+            dirty_region(&[], &previous_bounds, &screen_bounds),
+            Some(Rectangle::new((9.0, 9.0), (22.0, 12.0)))
+        );
+    }
+}
@@ -1,18 +1,26 @@
-use std::{collections::BTreeMap, sync::mpsc};
+use std::{cell::RefMut, collections::BTreeMap, sync::mpsc};
 
 use dces::prelude::*;
 
 use crate::{
-    application::{create_window, ContextProvider},
+    application::{create_window, window_icon, ContextProvider, OwnedWindow},
     prelude::*,
-    render::RenderContext2D,
-    shell::{ShellRequest, WindowRequest},
+    render::{Image, RenderContext2D},
+    shell::{
+        place_owned_window, place_window, window_monitor, GestureRecognizer, Monitor, ShellRequest,
+        ToolTipService, TrayIcon, WindowMode, WindowRequest,
+    },
     theming::prelude::*,
     tree::Tree,
+    utils::ItemsChanges,
 };
 
 use super::WidgetContainer;
 
+/// Reads the changes of a bound `ItemsSource` since the given version. Used by items widgets.
+pub type ItemsSourceContext =
+    Option<Box<dyn Fn(&mut Context, usize) -> Option<ItemsChanges> + 'static>>;
+
 /// The `Context` is provides access for the states to objects they could work with.
 pub struct Context<'a> {
     ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
@@ -22,6 +30,7 @@ pub struct Context<'a> {
     new_states: BTreeMap<Entity, Box<dyn State>>,
     remove_widget_list: Vec<Entity>,
     render_context: &'a mut RenderContext2D,
+    theme_switched: bool,
 }
 
 impl<'a> Drop for Context<'a> {
@@ -52,6 +61,7 @@ impl<'a> Context<'a> {
             new_states: BTreeMap::new(),
             remove_widget_list: vec![],
             render_context,
+            theme_switched: false,
         }
     }
 
@@ -272,6 +282,17 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Moves a child of the given parent to the given index. If the given entity is not a child
+    /// of the given parent nothing will happen.
+    pub fn move_child_to_index(&mut self, child: Entity, parent: Entity, index: usize) {
+        if let Some(children) = self.ecm.entity_store().children.get_mut(&parent) {
+            if let Some(old_index) = children.iter().position(|&c| c == child) {
+                let child = children.remove(old_index);
+                children.insert(index.min(children.len()), child);
+            }
+        }
+    }
+
     /// Returns a mutable reference of the children that should be removed.
     pub fn remove_widget_list(&mut self) -> &mut Vec<Entity> {
         &mut self.remove_widget_list
@@ -384,6 +405,29 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Returns all children and children of children of the given parent, that have a property with the given key
+    /// and value.
+    pub fn entities_by_property<P: Component + PartialEq>(
+        &mut self,
+        parent: Entity,
+        key: &str,
+        value: &P,
+    ) -> Vec<Entity> {
+        let mut children = vec![];
+        get_all_children(&mut children, parent, self.ecm.entity_store());
+
+        children
+            .into_iter()
+            .filter(|child| {
+                self.ecm
+                    .component_store()
+                    .get::<P>(key, *child)
+                    .map(|property| property == value)
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
     /// Pushes an event to the event queue with the given `strategy`.
     pub fn push_event_strategy<E: Event>(&mut self, event: E, strategy: EventStrategy) {
         self.provider
@@ -429,11 +473,39 @@ impl<'a> Context<'a> {
             .register_event_with_strategy(event, strategy, entity);
     }
 
-    /// Creates and show a new window.
+    /// Starts a drag-and-drop operation with the current widget as source and the given data at the current mouse
+    /// position. The drag ends on the next mouse up.
+    pub fn start_drag(&mut self, data: DragData) {
+        let position = self.provider.mouse_position.get();
+        self.push_event_strategy_by_entity(
+            DragStartEvent {
+                source: self.entity,
+                position,
+                data,
+            },
+            self.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    /// Creates and show a new window with the theme of the current window.
     pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&mut self, create_fn: F) {
+        let theme = self.theme.clone();
+        self.show_window_with_theme(theme, create_fn);
+    }
+
+    /// Creates and show a new window with its own theme, e.g. a tool palette with a dark theme.
+    pub fn show_window_with_theme<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        theme: Theme,
+        create_fn: F,
+    ) {
         let (adapter, settings, receiver) = create_window(
             self.provider.application_name.clone(),
-            self.theme.clone(),
+            theme,
+            self.provider.fonts.clone(),
+            self.provider.windows.clone(),
+            None,
             self.provider.shell_sender.clone(),
             create_fn,
         );
@@ -443,6 +515,218 @@ impl<'a> Context<'a> {
             .expect("Context.show_window: Could not send shell request.");
     }
 
+    /// Creates and show a new window that is owned by the current window, e.g. a tool palette. It is kept on top and
+    /// closed together with its owner. If the `center_on_owner` property of its `Window` widget is set, it is
+    /// placed in the center of the owner and kept inside of the work area of the monitor the owner is shown on.
+    ///
+    /// The platform windows are not linked, the owned window is kept on top of all windows like with
+    /// `always_on_top`.
+    pub fn show_owned_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) {
+        self.show_owned(create_fn, false);
+    }
+
+    /// Creates and show a new modal window that is owned by the current window, e.g. a settings dialog or a
+    /// confirmation. Like `show_owned_window`, but the input of the current window is blocked until the modal window
+    /// is closed.
+    pub fn show_modal_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) {
+        self.show_owned(create_fn, true);
+    }
+
+    fn show_owned<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+        modal: bool,
+    ) {
+        let (mut adapter, mut settings, receiver) = create_window(
+            self.provider.application_name.clone(),
+            self.theme.clone(),
+            self.provider.fonts.clone(),
+            self.provider.windows.clone(),
+            None,
+            self.provider.shell_sender.clone(),
+            create_fn,
+        );
+
+        settings.always_on_top = true;
+
+        let center_on_owner = {
+            let ecm = adapter.entity_component_manager();
+            let root = ecm.entity_store().root();
+            ecm.component_store()
+                .get::<bool>("center_on_owner", root)
+                .map_or(false, |center| *center)
+        };
+
+        // the window is opened on the same monitor as its owner
+        if center_on_owner {
+            let owner_bounds = self.window_bounds();
+            let bounds = place_owned_window(
+                &self.provider.monitors.borrow(),
+                owner_bounds,
+                settings.size,
+            );
+
+            settings.position = (bounds.x(), bounds.y());
+            settings.size = (bounds.width(), bounds.height());
+        }
+
+        self.provider.owned_windows.borrow_mut().push(OwnedWindow {
+            event_queue: Rc::downgrade(&adapter.context_provider().event_queue),
+            window_sender: adapter.context_provider().window_sender.clone(),
+            modal,
+        });
+
+        self.provider
+            .shell_sender
+            .send(ShellRequest::CreateWindow(adapter, settings, receiver))
+            .expect("Context.show_owned_window: Could not send shell request.");
+    }
+
+    // -- Window control --
+
+    /// Changes the title of the window by setting the `title` property of its `Window` widget.
+    pub fn set_window_title(&mut self, title: impl Into<String>) {
+        self.window().set::<String>("title", title.into());
+    }
+
+    /// Requests to resize the inner size of the window. The `Window` widget is resized if the platform window is
+    /// resized.
+    pub fn resize_window(&mut self, width: f64, height: f64) {
+        self.send_window_request(WindowRequest::Resize(width, height));
+    }
+
+    /// Requests to move the top left corner of the window to the given position on the screen.
+    pub fn move_window(&mut self, x: f64, y: f64) {
+        self.provider.window_position.set(Point::new(x, y));
+        self.send_window_request(WindowRequest::Move(x, y));
+    }
+
+    /// Requests to minimize the window.
+    pub fn minimize_window(&mut self) {
+        self.set_window_mode(WindowMode::Minimized);
+    }
+
+    /// Requests to maximize the window.
+    pub fn maximize_window(&mut self) {
+        self.set_window_mode(WindowMode::Maximized);
+    }
+
+    /// Requests to restore a minimized, maximized or fullscreen window.
+    pub fn restore_window(&mut self) {
+        self.set_window_mode(WindowMode::Normal);
+    }
+
+    /// Requests to show the window fullscreen or to leave fullscreen.
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.set_window_mode(if fullscreen {
+            WindowMode::Fullscreen
+        } else {
+            WindowMode::Normal
+        });
+    }
+
+    /// Requests to minimize, maximize, restore the window or to show it fullscreen.
+    pub fn set_window_mode(&mut self, mode: WindowMode) {
+        self.provider.window_mode.set(mode);
+        self.send_window_request(WindowRequest::ChangeMode(mode));
+    }
+
+    /// Sets the minimum inner size of the window, `None` removes the constraint.
+    pub fn set_window_min_size(&mut self, size: Option<(f64, f64)>) {
+        self.send_window_request(WindowRequest::SetMinSize(size));
+    }
+
+    /// Sets the maximum inner size of the window, `None` removes the constraint.
+    pub fn set_window_max_size(&mut self, size: Option<(f64, f64)>) {
+        self.send_window_request(WindowRequest::SetMaxSize(size));
+    }
+
+    /// Changes the icon of the window, an empty image resets the default icon of the platform. The icon is shown by
+    /// the glutin shell and as favicon of the page on the web.
+    pub fn set_window_icon(&mut self, icon: impl Into<Image>) {
+        self.send_window_request(WindowRequest::SetIcon(window_icon(&icon.into())));
+    }
+
+    /// Shows the progress between `0.0` and `1.0` on the task bar entry of the window, e.g. of a download. `None`
+    /// hides the progress. The progress is only shown by the glutin shell on Windows.
+    pub fn set_taskbar_progress(&mut self, progress: Option<f64>) {
+        self.send_window_request(WindowRequest::SetProgress(
+            progress.map(|progress| progress.max(0.0).min(1.0)),
+        ));
+    }
+
+    /// Shows or hides the window. A hidden window keeps running, e.g. to show it again on a click on its tray icon.
+    pub fn set_window_visible(&mut self, visible: bool) {
+        self.send_window_request(WindowRequest::SetVisible(visible));
+    }
+
+    /// Shows the icon of the application in the system tray, `None` removes it. Its events are passed to the
+    /// `on_tray_event` handler of the `Window` widget. The tray icon is only shown by the glutin shell with the `tray`
+    /// feature, see `TrayIcon`.
+    pub fn set_tray_icon(&mut self, tray_icon: Option<TrayIcon>) {
+        self.send_window_request(WindowRequest::SetTrayIcon(tray_icon));
+    }
+
+    /// Gets the mode of the window, it's the mode that is requested last or that is reported by the shell. The glutin
+    /// shell reports if the platform shows the window fullscreen or minimizes it, but it could not detect if the user
+    /// maximizes or restores the window by its decorations. The other shells report no changes of the platform.
+    pub fn window_mode(&self) -> WindowMode {
+        self.provider.window_mode.get()
+    }
+
+    /// Gets the position of the top left corner of the window on the screen.
+    pub fn window_position(&self) -> Point {
+        self.provider.window_position.get()
+    }
+
+    /// Gets the current inner size of the window.
+    pub fn window_size(&mut self) -> (f64, f64) {
+        let bounds = *self.window().get::<Rectangle>("bounds");
+        (bounds.width(), bounds.height())
+    }
+
+    /// Gets the monitors of the platform. The list is empty if the shell could not enumerate the monitors.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.provider.monitors.borrow().clone()
+    }
+
+    /// Gets the monitor that contains the largest part of the window, `None` if the monitors are unknown.
+    pub fn current_monitor(&mut self) -> Option<Monitor> {
+        let bounds = self.window_bounds();
+        let monitors = self.provider.monitors.borrow();
+
+        window_monitor(&monitors, bounds).map(|index| monitors[index].clone())
+    }
+
+    /// Requests to move and resize the window to the given bounds, e.g. to restore a saved window position. The
+    /// window is kept inside of the work area of a connected monitor, if the saved monitor is disconnected it is
+    /// moved to the nearest one.
+    pub fn place_window(&mut self, bounds: impl Into<Rectangle>) {
+        let bounds = place_window(&self.provider.monitors.borrow(), bounds.into());
+
+        self.move_window(bounds.x(), bounds.y());
+        self.resize_window(bounds.width(), bounds.height());
+    }
+
+    // the position and the inner size of the window on the screen
+    fn window_bounds(&mut self) -> Rectangle {
+        let (width, height) = self.window_size();
+        Rectangle::new(self.window_position(), (width, height))
+    }
+
+    /// Gets the scale factor of the monitor the window is shown on, the number of physical pixels of one logical
+    /// pixel. Layouts and render objects work with logical pixels, render objects could use it to align lines to
+    /// physical pixels, e.g. with `snap_line`.
+    pub fn scale_factor(&self) -> f64 {
+        self.render_context.scale_factor()
+    }
+
     /// Returns a mutable reference of the 2d render ctx.
     pub fn render_context_2_d(&mut self) -> &mut RenderContext2D {
         self.render_context
@@ -461,14 +745,106 @@ impl<'a> Context<'a> {
         self.provider.window_sender.clone()
     }
 
+    /// Requests an update of the current widget on the next frame. Could be used to drive animations.
+    pub fn request_next_frame(&mut self) {
+        self.provider.update_requests.borrow_mut().push(self.entity);
+        self.send_window_request(WindowRequest::Redraw);
+    }
+
+    /// Requests an update of the current widget at the given time in milliseconds of `now()`, e.g. if a timer elapses.
+    /// The window sleeps until then if nothing else changes.
+    pub fn request_update_at(&mut self, time: f64) {
+        self.provider
+            .scheduled_updates
+            .borrow_mut()
+            .push((time, self.entity));
+        self.send_window_request(WindowRequest::RedrawAt(time));
+    }
+
+    /// Returns the tool tip service of the window. It handles the hover delay and the position of tool tips.
+    pub fn tool_tip_service(&self) -> RefMut<'_, ToolTipService> {
+        self.provider.tool_tip.borrow_mut()
+    }
+
+    /// Returns the gesture recognizer of the window. It could be used to change the thresholds of the gestures.
+    pub fn gesture_recognizer(&self) -> RefMut<'_, GestureRecognizer> {
+        self.provider.gestures.borrow_mut()
+    }
+
+    /// Scrolls the nearest parent `ScrollViewer` of the given widget, so the widget is visible inside of its view
+    /// port. The widget positions of the last rendered frame are used. Returns `false` if the widget is not placed
+    /// inside of a scroll viewer.
+    pub fn scroll_into_view(&mut self, entity: Entity) -> bool {
+        let mut scroll_viewer = None;
+        let mut current = entity;
+
+        while let Some(parent) = self.ecm.entity_store().parent[&current] {
+            if self
+                .ecm
+                .component_store()
+                .get::<Point>("scroll_offset", parent)
+                .is_ok()
+            {
+                scroll_viewer = Some(parent);
+                break;
+            }
+
+            current = parent;
+        }
+
+        let scroll_viewer = match scroll_viewer {
+            Some(scroll_viewer) => scroll_viewer,
+            None => return false,
+        };
+
+        let position = *self.get_widget(entity).get::<Point>("position");
+        let size = self.get_widget(entity).get::<Rectangle>("bounds").size();
+
+        let mut scroll_viewer = self.get_widget(scroll_viewer);
+        let view_port_position = *scroll_viewer.get::<Point>("position");
+        let view_port_size = scroll_viewer.get::<Rectangle>("bounds").size();
+        let offset = *scroll_viewer.get::<Point>("scroll_offset");
+
+        let offset = Point::new(
+            scroll_into_view_offset(
+                offset.x(),
+                view_port_size.width(),
+                position.x() - view_port_position.x() + offset.x(),
+                size.width(),
+            ),
+            scroll_into_view_offset(
+                offset.y(),
+                view_port_size.height(),
+                position.y() - view_port_position.y() + offset.y(),
+                size.height(),
+            ),
+        );
+
+        scroll_viewer.set("scroll_offset", offset);
+
+        true
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
     }
 
-    /// Switch the current theme.
+    /// Returns the new theme if it was switched with this context.
+    pub(crate) fn switched_theme(&self) -> Option<&Theme> {
+        if self.theme_switched {
+            return Some(&self.theme);
+        }
+
+        None
+    }
+
+    /// Switches the theme of the window at runtime, e.g. to toggle between a light and a dark theme. All properties
+    /// that are derived from the selectors are resolved against the new theme and the window is redrawn. Widgets
+    /// that are created afterwards are styled with the new theme.
     pub fn switch_theme(&mut self, theme: Theme) {
         self.theme = theme.clone();
+        self.theme_switched = true;
 
         self.window().get_mut::<Global>("global").theme = theme;
 
@@ -503,4 +879,39 @@ pub fn get_all_children(children: &mut Vec<Entity>, parent: Entity, tree: &Tree)
     }
 }
 
+/// Calculates the scroll offset that is needed to show the item with the given start and size inside of the view
+/// port. The start of the item is relative to the scrolled content. If the item is larger than the view port its
+/// start is shown.
+pub fn scroll_into_view_offset(offset: f64, view_port_size: f64, start: f64, size: f64) -> f64 {
+    if start < offset || size > view_port_size {
+        return start;
+    }
+
+    if start + size > offset + view_port_size {
+        return start + size - view_port_size;
+    }
+
+    offset
+}
+
 // -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_into_view_offset() {
+        // visible
+        assert!((scroll_into_view_offset(10., 100., 20., 20.) - 10.).abs() < f64::EPSILON);
+
+        // above
+        assert!((scroll_into_view_offset(50., 100., 20., 20.) - 20.).abs() < f64::EPSILON);
+
+        // below
+        assert!((scroll_into_view_offset(0., 100., 120., 20.) - 40.).abs() < f64::EPSILON);
+
+        // larger than view port
+        assert!((scroll_into_view_offset(0., 100., 120., 200.) - 120.).abs() < f64::EPSILON);
+    }
+}
@@ -20,7 +20,8 @@ mod states_context;
 mod template;
 mod widget_container;
 
-/// Toggles the selector state`.
+/// Activates or deactivates the pseudo-class of the selector with the name of the given flag property, e.g.
+/// `pressed` or `selected`, depending on its value.
 pub fn toggle_flag(flag: &str, widget: &mut WidgetContainer) {
     if !widget.has::<bool>(flag) {
         return;
@@ -29,11 +30,7 @@ pub fn toggle_flag(flag: &str, widget: &mut WidgetContainer) {
     let value = *widget.get::<bool>(flag);
 
     if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
-        if value {
-            selector.set_state(flag);
-        } else {
-            selector.clear_state();
-        }
+        selector.set_pseudo_class(flag, value);
     }
 }
 
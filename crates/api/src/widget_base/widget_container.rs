@@ -1,9 +1,21 @@
-use std::{any::type_name, cell::RefCell, rc::Rc};
+use std::{
+    any::{type_name, Any},
+    cell::RefCell,
+    rc::Rc,
+};
 
 use dces::prelude::*;
 
 use crate::{
-    event::ChangedEvent, event::*, properties::Constraint, theming::*, tree::*, utils::prelude::*,
+    animation::{Animation, AnimationId, Animations, Transitions},
+    event::ChangedEvent,
+    event::*,
+    properties::Constraint,
+    render::Image,
+    render_object, shell,
+    theming::*,
+    tree::*,
+    utils::prelude::*,
 };
 
 /// Mark the widget and shared widgets as dirty.
@@ -31,6 +43,16 @@ pub fn mark_as_dirty(
     }
 }
 
+// The theme context of a widget that is given by its parents.
+#[derive(Default)]
+struct ThemeScope {
+    // the resources of the parents from the outermost to the innermost
+    resources: Vec<Resources>,
+
+    // the selectors of the parents from the outermost to the innermost
+    ancestors: Vec<Selector>,
+}
+
 /// The `WidgetContainer` wraps the entity of a widget and provides access to its properties, its children properties and its parent properties.
 pub struct WidgetContainer<'a> {
     ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
@@ -241,7 +263,18 @@ impl<'a> WidgetContainer<'a> {
             }
         }
 
+        // the disabled pseudo-class follows the enabled flag
+        let enabled = (&value as &dyn Any).downcast_ref::<bool>().copied();
+
         self.set_non_dirty(key, value);
+
+        if let (Some(enabled), "enabled") = (enabled, key) {
+            if let Some(selector) = self.try_get_mut::<Selector>("selector") {
+                if selector.set_pseudo_class("disabled", !enabled) {
+                    self.update(false);
+                }
+            }
+        }
     }
 
     /// Sets the property of type `P` without setting the widget dirty.
@@ -303,6 +336,70 @@ impl<'a> WidgetContainer<'a> {
             .ok()
     }
 
+    /// Gets the combined render transform of the widget and its parents. Its inverse maps a point on the screen to
+    /// the untransformed window coordinates of the widget.
+    pub fn global_transform(&self) -> Transform {
+        render_object::global_transform(self.ecm, self.current_node)
+    }
+
+    /// Checks if the given point on the screen is clipped away by a parent of the widget.
+    pub fn is_clipped(&self, point: Point) -> bool {
+        render_object::is_clipped(self.ecm, self.current_node, point)
+    }
+
+    /// Starts the animation of a property of the widget, a running animation or theme transition of the property is
+    /// replaced. Returns the id of the animation, e.g. to cancel it. The animation runs from the current value of the
+    /// property if it has no start value.
+    pub fn animate<T>(&mut self, animation: Animation<T>) -> AnimationId
+    where
+        T: Component + Clone + Interpolate,
+    {
+        let entity = self.current_node;
+        let root = self.ecm.entity_store().root();
+        let from = animation
+            .start_value()
+            .cloned()
+            .or_else(|| self.try_clone::<T>(animation.key()))
+            .unwrap_or_else(|| animation.end_value().clone());
+
+        if let Ok(transitions) = self
+            .ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("transitions", root)
+        {
+            transitions.cancel(entity, animation.key());
+        }
+
+        self.register_animations();
+
+        self.ecm
+            .component_store_mut()
+            .get_mut::<Animations>("animations", root)
+            .unwrap()
+            .start(entity, animation, from)
+    }
+
+    /// Cancels the animation with the given id, the property keeps its current value. Returns `false` if the
+    /// animation is already finished.
+    pub fn cancel_animation(&mut self, id: AnimationId) -> bool {
+        let root = self.ecm.entity_store().root();
+
+        self.ecm
+            .component_store_mut()
+            .get_mut::<Animations>("animations", root)
+            .map_or(false, |animations| animations.cancel(id))
+    }
+
+    /// Checks if the animation with the given id is still running.
+    pub fn is_animation_running(&self, id: AnimationId) -> bool {
+        let root = self.ecm.entity_store().root();
+
+        self.ecm
+            .component_store()
+            .get::<Animations>("animations", root)
+            .map_or(false, |animations| animations.is_running(id))
+    }
+
     /// Checks if the given value is equal to the given property.
     pub fn eq<P: Component + PartialEq>(&self, key: &str, other: &P) -> bool {
         if let Some(value) = self.try_get::<P>(key) {
@@ -368,6 +465,57 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // Sets the value of the theme. If the style has a transition for the property, the value runs from the current to
+    // the new value instead of snapping. The first style of a widget is applied without transition.
+    fn update_transitioned_value<T>(&mut self, key: &str, value: Value, transitions: &[Transition])
+    where
+        T: Component + Clone + PartialEq + Interpolate,
+        Value: Into<T>,
+    {
+        if !self.has::<T>(key) {
+            return;
+        }
+
+        let to: T = value.into();
+        let entity = self.current_node;
+        let root = self.ecm.entity_store().root();
+        let transition = transitions.iter().rev().find(|t| t.matches(key)).cloned();
+        let from = self.clone::<T>(key);
+
+        if let Ok(running) = self
+            .ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("transitions", root)
+        {
+            match transition {
+                Some(transition) if running.is_styled(entity) && from != to => {
+                    running.start(entity, key, from, to, transition);
+                    return;
+                }
+                _ => running.cancel(entity, key),
+            }
+        }
+
+        self.update_value::<T, T>(key, to);
+    }
+
+    // Loads the border image from the path that is defined in the theme.
+    fn update_border_image(&mut self, key: &str, value: Value) {
+        if !self.has::<Image>(key) {
+            return;
+        }
+
+        let path: String = value.into();
+
+        match Image::from_path(path.as_str()) {
+            Ok(image) => self.update_value::<Image, Image>(key, image),
+            Err(e) => shell::CONSOLE.log(format!(
+                "WidgetContainer: could not load border image {}: {}",
+                path, e
+            )),
+        }
+    }
+
     /// Update all properties from theme for the current widget.
     pub fn update(&mut self, force: bool) {
         self.update_widget(self.current_node, force, false);
@@ -378,13 +526,95 @@ impl<'a> WidgetContainer<'a> {
         self.update_widget(self.current_node, force, true);
     }
 
+    /// Overrides the theme resource with the given key for the current widget and its children and updates their
+    /// properties from theme.
+    pub fn set_resource(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let entity = self.current_node;
+        let resources = self
+            .try_clone::<Resources>("resources")
+            .unwrap_or_default()
+            .with(key, value);
+
+        self.ecm
+            .component_store_mut()
+            .register("resources", entity, resources);
+        self.update_widget(entity, true, true);
+    }
+
     /// Update all properties from theme for the given widget.
     pub fn update_widget(&mut self, entity: Entity, force: bool, should_mark_as_dirty: bool) {
+        // the selectors of the parents are matched by the combinators of the theme and their resources override the
+        // resources of the theme
+        let mut scope = ThemeScope::default();
+        let mut parent = self
+            .ecm
+            .entity_store()
+            .parent
+            .get(&entity)
+            .cloned()
+            .flatten();
+
+        while let Some(p) = parent {
+            if let Ok(parent_resources) =
+                self.ecm.component_store().get::<Resources>("resources", p)
+            {
+                scope.resources.insert(0, parent_resources.clone());
+            }
+
+            if let Ok(parent_selector) = self.ecm.component_store().get::<Selector>("selector", p) {
+                scope.ancestors.insert(0, parent_selector.clone());
+            }
+
+            parent = self.ecm.entity_store().parent.get(&p).cloned().flatten();
+        }
+
+        self.update_widget_in_scope(entity, force, should_mark_as_dirty, &mut scope);
+    }
+
+    fn update_widget_in_scope(
+        &mut self,
+        entity: Entity,
+        force: bool,
+        should_mark_as_dirty: bool,
+        scope: &mut ThemeScope,
+    ) {
+        let own_resources = self
+            .ecm
+            .component_store()
+            .get::<Resources>("resources", entity)
+            .ok()
+            .cloned();
+        let has_own_resources = own_resources.is_some();
+        scope.resources.extend(own_resources);
+
+        self.update_widget_by_theme(entity, force, should_mark_as_dirty, scope);
+
+        if has_own_resources {
+            scope.resources.pop();
+        }
+    }
+
+    fn update_widget_by_theme(
+        &mut self,
+        entity: Entity,
+        force: bool,
+        should_mark_as_dirty: bool,
+        scope: &mut ThemeScope,
+    ) {
         self.current_node = entity;
         if !self.has::<Selector>("selector") {
             return;
         }
 
+        if let Some(enabled) = self.try_clone::<bool>("enabled") {
+            // direct access to prevent initial setting of dirty flag on widget
+            self.ecm
+                .component_store_mut()
+                .get_mut::<Selector>("selector", self.current_node)
+                .unwrap()
+                .set_pseudo_class("disabled", !enabled);
+        }
+
         if force {
             // direct access to prevent initial setting of dirty flag on widget
             self.ecm
@@ -400,40 +630,133 @@ impl<'a> WidgetContainer<'a> {
             return;
         }
 
-        if let Some(props) = self.theme.properties(&selector) {
-            for (key, value) in props {
+        let theme = self.theme;
+
+        if let Some(props) = theme.properties_in(&selector, &scope.ancestors) {
+            let transitions: Vec<Transition> = props
+                .get("transition")
+                .and_then(|value| theme.resolve(value, &scope.resources))
+                .map(|value| Value(value.clone()).into())
+                .unwrap_or_default();
+
+            if !transitions.is_empty() {
+                self.register_transitions();
+            }
+
+            for (key, value) in props.iter() {
+                let value = match theme.resolve(value, &scope.resources) {
+                    Some(value) => value,
+                    None => continue,
+                };
+
                 match key.as_str() {
-                    "foreground" | "background" | "icon_brush" | "border_brush" => {
-                        self.update_value::<Brush, Value>(key, Value(value.clone()));
+                    "foreground"
+                    | "background"
+                    | "icon_brush"
+                    | "border_brush"
+                    | "link_foreground"
+                    | "selection_brush"
+                    | "gutter_background"
+                    | "line_number_foreground"
+                    | "keyword_brush"
+                    | "type_brush"
+                    | "string_brush"
+                    | "number_brush"
+                    | "comment_brush"
+                    | "operator_brush"
+                    | "tooltip_background"
+                    | "track_brush"
+                    | "indicator_brush"
+                    | "shadow_brush" => {
+                        self.update_transitioned_value::<Brush>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
                     }
-                    "font_size" | "icon_size" | "spacing" | "border_radius" => {
-                        self.update_value::<f64, Value>(key, Value(value.clone()));
+                    "font_size" | "icon_size" | "spacing" | "elevation" | "hover_elevation" => {
+                        self.update_transitioned_value::<f64>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
                     }
-                    "padding" | "border_width" => {
-                        self.update_value::<Thickness, Value>(key, Value(value.clone()));
+                    "padding" | "border_width" | "border_image_slice" => {
+                        self.update_transitioned_value::<Thickness>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
                     }
                     "padding_left" | "padding_top" | "padding_right" | "padding_bottom" => {
                         self.update_padding(key, Value(value.clone()));
                     }
+                    "clip" => self.update_value::<bool, bool>(key, Value(value.clone()).get()),
+                    "overflow" => {
+                        let overflow = Value(value.clone()).get::<String>();
+                        self.update_value::<bool, bool>("clip", overflow == "hidden");
+                    }
                     "font_family" | "icon_family" => {
                         self.update_value::<String, Value>(key, Value(value.clone()));
                     }
                     "opacity" => {
-                        self.update_value::<f32, Value>(key, Value(value.clone()));
+                        self.update_transitioned_value::<f32>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
+                    }
+                    "border_radius" => {
+                        self.update_transitioned_value::<BorderRadius>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
+                    }
+                    "transform" => {
+                        self.update_transitioned_value::<Transform>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
+                    }
+                    "box_shadow" => {
+                        self.update_transitioned_value::<BoxShadow>(
+                            key,
+                            Value(value.clone()),
+                            &transitions,
+                        );
                     }
+                    "border_image" => self.update_border_image(key, Value(value.clone())),
                     "width" | "height" | "min_width" | "min_height" | "max_width"
                     | "max_height" => self.update_constraint(key, Value(value.clone())),
                     _ => {}
                 }
             }
+
+            if !transitions.is_empty() {
+                let root = self.ecm.entity_store().root();
+
+                if let Ok(running) = self
+                    .ecm
+                    .component_store_mut()
+                    .get_mut::<Transitions>("transitions", root)
+                {
+                    running.set_styled(entity);
+                }
+            }
         }
 
         let force = selector.dirty() || force;
 
+        scope.ancestors.push(selector);
+
         for child in &(self.ecm.entity_store().children.clone())[&entity] {
-            self.update_widget(*child, force, should_mark_as_dirty);
+            self.update_widget_in_scope(*child, force, should_mark_as_dirty, scope);
         }
 
+        scope.ancestors.pop();
+
         self.current_node = entity;
 
         // direct access to prevent initial setting of dirty flag on widget
@@ -448,6 +771,38 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // Registers the store of the running transitions on the root if it doesn't exist yet.
+    fn register_transitions(&mut self) {
+        let root = self.ecm.entity_store().root();
+
+        if self
+            .ecm
+            .component_store()
+            .get::<Transitions>("transitions", root)
+            .is_err()
+        {
+            self.ecm
+                .component_store_mut()
+                .register("transitions", root, Transitions::default());
+        }
+    }
+
+    // Registers the store of the running animations on the root if it doesn't exist yet.
+    fn register_animations(&mut self) {
+        let root = self.ecm.entity_store().root();
+
+        if self
+            .ecm
+            .component_store()
+            .get::<Animations>("animations", root)
+            .is_err()
+        {
+            self.ecm
+                .component_store_mut()
+                .register("animations", root, Animations::default());
+        }
+    }
+
     fn get_name(&self) -> String {
         if self.has::<String>("name") {
             self.ecm
@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use unicode_bidi::BidiInfo;
+
+/// Reorders a text from its logical order into the visual order of the unicode bidirectional algorithm, e.g. the
+/// characters of an Arabic or Hebrew text are reversed. Render backends that draw glyphs from left to right use it
+/// before drawing. A text without right-to-left characters is returned unchanged.
+pub fn visual_order(text: &str) -> Cow<'_, str> {
+    let bidi_info = BidiInfo::new(text, None);
+
+    if !bidi_info.has_rtl() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut visual = String::with_capacity(text.len());
+
+    for paragraph in &bidi_info.paragraphs {
+        visual.push_str(&bidi_info.reorder_line(paragraph, paragraph.range.clone()));
+    }
+
+    Cow::Owned(visual)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visual_order() {
+        assert_eq!(visual_order("hello"), "hello");
+        assert_eq!(visual_order("שלום"), "םולש");
+        assert_eq!(visual_order("abc שלום"), "abc םולש");
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     thread,
 };
 
-use crate::{platform, utils::*, PipelineTrait, RenderTarget, TextMetrics};
+use crate::{platform, utils::*, PipelineTrait, RenderTarget, TextMetrics, DEFAULT_SCALE_FACTOR};
 use platform::Image;
 
 #[derive(Clone)]
@@ -20,11 +20,15 @@ impl PartialEq for PipelineWrapper {
 enum RenderTask {
     // Single tasks
     Start(),
+    StartRegion(Rectangle),
     SetBackground(Color),
     Resize {
         width: f64,
         height: f64,
     },
+    SetScaleFactor {
+        scale_factor: f64,
+    },
     RegisterFont {
         family: String,
         font_file: &'static [u8],
@@ -43,6 +47,15 @@ enum RenderTask {
         width: f64,
         height: f64,
     },
+    FillShadow {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    },
     FillText {
         text: String,
         x: f64,
@@ -97,12 +110,27 @@ enum RenderTask {
         x: f64,
         y: f64,
     },
+    DrawImageWithSize {
+        image: Image,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
     DrawImageWithClip {
         image: Image,
         clip: Rectangle,
         x: f64,
         y: f64,
     },
+    DrawImageWithClipAndSize {
+        image: Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
     DrawPipeline {
         x: f64,
         y: f64,
@@ -159,12 +187,16 @@ struct RenderWorker {
 fn is_single_tasks(task: &RenderTask) -> bool {
     match task {
         RenderTask::Start() => true,
+        RenderTask::StartRegion(_) => true,
         RenderTask::SetBackground(_) => true,
         RenderTask::Resize { .. } => true,
+        RenderTask::SetScaleFactor { .. } => true,
         RenderTask::RegisterFont { .. } => true,
         RenderTask::DrawRenderTarget { .. } => true,
         RenderTask::DrawImage { .. } => true,
+        RenderTask::DrawImageWithSize { .. } => true,
         RenderTask::DrawImageWithClip { .. } => true,
+        RenderTask::DrawImageWithClipAndSize { .. } => true,
         RenderTask::DrawPipeline { .. } => true,
         RenderTask::SetTransform { .. } => true,
         RenderTask::Terminate { .. } => true,
@@ -196,6 +228,11 @@ impl RenderWorker {
                             render_context_2_d.start();
                             continue;
                         }
+                        RenderTask::StartRegion(region) => {
+                            tasks_collection.clear();
+                            render_context_2_d.start_region(region);
+                            continue;
+                        }
                         RenderTask::SetBackground(background) => {
                             render_context_2_d.set_background(background);
                             continue;
@@ -204,6 +241,10 @@ impl RenderWorker {
                             render_context_2_d.resize(width, height);
                             continue;
                         }
+                        RenderTask::SetScaleFactor { scale_factor } => {
+                            render_context_2_d.set_scale_factor(scale_factor);
+                            continue;
+                        }
                         RenderTask::RegisterFont { family, font_file } => {
                             render_context_2_d.register_font(family.as_str(), font_file);
                             continue;
@@ -218,9 +259,29 @@ impl RenderWorker {
                         RenderTask::DrawImage { image, x, y } => {
                             render_context_2_d.draw_image(&image, x, y);
                         }
+                        RenderTask::DrawImageWithSize {
+                            image,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d.draw_image_with_size(&image, x, y, width, height);
+                        }
                         RenderTask::DrawImageWithClip { image, clip, x, y } => {
                             render_context_2_d.draw_image_with_clip(&image, clip, x, y);
                         }
+                        RenderTask::DrawImageWithClipAndSize {
+                            image,
+                            clip,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d
+                                .draw_image_with_clip_and_size(&image, clip, x, y, width, height);
+                        }
                         RenderTask::DrawPipeline {
                             x,
                             y,
@@ -270,6 +331,18 @@ impl RenderWorker {
                             } => {
                                 render_context_2_d.stroke_rect(x, y, width, height);
                             }
+                            RenderTask::FillShadow {
+                                x,
+                                y,
+                                width,
+                                height,
+                                radius,
+                                blur,
+                                color,
+                            } => {
+                                render_context_2_d
+                                    .fill_shadow(x, y, width, height, radius, blur, color);
+                            }
                             RenderTask::FillText { text, x, y } => {
                                 render_context_2_d.fill_text(text.as_str(), x, y);
                             }
@@ -385,6 +458,10 @@ pub struct RenderContext2D {
     finish_receiver: mpsc::Receiver<bool>,
     tasks: Vec<RenderTask>,
     measure_context: platform::RenderContext2D,
+    scale_factor: f64,
+
+    // the number of finished frames whose result was not received yet
+    pending_frames: usize,
 }
 
 impl Drop for RenderContext2D {
@@ -419,6 +496,8 @@ impl RenderContext2D {
             finish_receiver,
             tasks: vec![],
             measure_context: platform::RenderContext2D::new(width, height),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            pending_frames: 0,
         }
     }
 
@@ -449,19 +528,45 @@ impl RenderContext2D {
             .expect("Could not send start to render thread.");
     }
 
+    /// Starts a new render pipeline that redraws only the given region of the last frame.
+    pub fn start_region(&mut self, region: Rectangle) {
+        self.sender
+            .send(vec![RenderTask::StartRegion(region)])
+            .expect("Could not send start to render thread.");
+    }
+
     /// Finishes the current render pipeline.
     pub fn finish(&mut self) {
         self.tasks.push(RenderTask::Finish());
         self.send_tasks();
+        self.pending_frames += 1;
     }
 
-    /// Resizes the render ctx.
+    /// Resizes the render ctx to the given size in logical pixels.
     pub fn resize(&mut self, width: f64, height: f64) {
         self.sender
             .send(vec![RenderTask::Resize { width, height }])
             .expect("Could not send resize to render thread.");
     }
 
+    /// Gets the scale factor of the monitor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the scale factor of the monitor. The frames are drawn in physical pixels, the size in logical pixels is
+    /// kept.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor <= 0.0 || (self.scale_factor - scale_factor).abs() < f64::EPSILON {
+            return;
+        }
+
+        self.scale_factor = scale_factor;
+        self.sender
+            .send(vec![RenderTask::SetScaleFactor { scale_factor }])
+            .expect("Could not send set scale factor to render thread.");
+    }
+
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
         self.measure_context.register_font(family, font_file);
@@ -496,6 +601,29 @@ impl RenderContext2D {
         });
     }
 
+    /// Draws the soft shadow of a rectangle with rounded corners, call it before the rectangle is drawn. The shadow
+    /// fades out over the blur radius around the rectangle.
+    pub fn fill_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    ) {
+        self.tasks.push(RenderTask::FillShadow {
+            x,
+            y,
+            width,
+            height,
+            radius,
+            blur,
+            color,
+        });
+    }
+
     // Text
 
     /// Draws (fills) a given text at the given (x, y) position.
@@ -623,6 +751,26 @@ impl RenderContext2D {
             .expect("Could not send image to render thread.");
     }
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(
+        &mut self,
+        image: &mut Image,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawImageWithSize {
+                image: image.clone(),
+                x,
+                y,
+                width,
+                height,
+            }])
+            .expect("Could not send scaled image to render thread.");
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &mut Image, clip: Rectangle, x: f64, y: f64) {
         self.sender
@@ -635,6 +783,28 @@ impl RenderContext2D {
             .expect("Could not send clipped image to render thread.");
     }
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &mut Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawImageWithClipAndSize {
+                image: image.clone(),
+                clip,
+                x,
+                y,
+                width,
+                height,
+            }])
+            .expect("Could not send clipped and scaled image to render thread.");
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -738,12 +908,35 @@ impl RenderContext2D {
     pub fn data(&mut self) -> Option<&[u32]> {
         if let Ok(RenderResult::Finish { data }) = self.result_receiver.try_recv() {
             self.output = data;
+            self.pending_frames = self.pending_frames.saturating_sub(1);
             Some(&self.output)
         } else {
             None
         }
     }
 
+    /// Waits until the render thread has drawn all finished frames and returns the last one. Returns `None` if no
+    /// frame was finished since the last call.
+    pub fn wait_for_frame(&mut self) -> Option<&[u32]> {
+        if self.pending_frames == 0 {
+            return None;
+        }
+
+        while self.pending_frames > 0 {
+            match self.result_receiver.recv() {
+                Ok(RenderResult::Finish { data }) => self.output = data,
+                Err(_) => break,
+            }
+
+            self.pending_frames -= 1;
+        }
+
+        // the finish notifications are not needed if the frames are waited for
+        while self.finish_receiver.try_recv().is_ok() {}
+
+        Some(&self.output)
+    }
+
     pub fn data_mut(&mut self) -> &mut [u32] {
         &mut self.output
     }
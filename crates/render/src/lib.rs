@@ -10,13 +10,18 @@ pub use orbtk_utils::prelude as utils;
 #[path = "pathfinder/mod.rs"]
 pub mod platform;
 
-#[cfg(any(target_arch = "wasm32", feature = "pfinder"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "gpu", not(feature = "pfinder")))]
+#[path = "wgpu/mod.rs"]
+pub mod platform;
+
+#[cfg(any(target_arch = "wasm32", feature = "pfinder", feature = "gpu"))]
 pub use self::platform::*;
 
 #[cfg(all(
     not(target_arch = "wasm32"),
     feature = "default",
-    not(feature = "pfinder")
+    not(feature = "pfinder"),
+    not(feature = "gpu")
 ))]
 #[path = "raqote/mod.rs"]
 pub mod platform;
@@ -24,14 +29,16 @@ pub mod platform;
 #[cfg(all(
     not(target_arch = "wasm32"),
     feature = "default",
-    not(feature = "pfinder")
+    not(feature = "pfinder"),
+    not(feature = "gpu")
 ))]
 pub mod concurrent;
 
 #[cfg(all(
     not(target_arch = "wasm32"),
     feature = "default",
-    not(feature = "pfinder")
+    not(feature = "pfinder"),
+    not(feature = "gpu")
 ))]
 pub use self::concurrent::*;
 
@@ -42,9 +49,17 @@ pub mod platform;
 #[cfg(target_arch = "wasm32")]
 pub use platform::RenderContext2D;
 
+pub use self::bidi::*;
 pub use self::render_target::*;
+pub use self::scale_factor::*;
+pub use self::shadow::*;
+pub use self::vector_image::*;
 
+mod bidi;
 mod render_target;
+mod scale_factor;
+mod shadow;
+mod vector_image;
 
 /// Defines the current configuration of the render ctx.
 #[derive(Debug, Clone)]
@@ -1,4 +1,6 @@
-use crate::{utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    shadow_layers, utils::*, visual_order, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+};
 
 use font_kit::handle::Handle;
 use pathfinder_canvas::{
@@ -52,7 +54,7 @@ pub struct RenderContext2D {
     _origin_size: (f64, f64),
     config: RenderConfig,
     device_pixel_ratio: f32,
-    saved_config: Option<RenderConfig>,
+    saved_configs: Vec<RenderConfig>,
 }
 
 impl RenderContext2D {
@@ -68,7 +70,7 @@ impl RenderContext2D {
             _origin_size: (width, height),
             device_pixel_ratio: 1.0,
             config: RenderConfig::default(),
-            saved_config: None,
+            saved_configs: vec![],
         }
     }
 
@@ -118,7 +120,7 @@ impl RenderContext2D {
             _origin_size: origin_size,
             device_pixel_ratio,
             config: RenderConfig::default(),
-            saved_config: None,
+            saved_configs: vec![],
         }
     }
 
@@ -139,6 +141,18 @@ impl RenderContext2D {
         // }
     }
 
+    /// Gets the scale factor of the monitor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.device_pixel_ratio as f64
+    }
+
+    /// Sets the scale factor of the monitor, it's used as device pixel ratio.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor > 0.0 {
+            self.device_pixel_ratio = scale_factor as f32;
+        }
+    }
+
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {}
 
@@ -153,6 +167,65 @@ impl RenderContext2D {
         ));
     }
 
+    /// Draws the soft shadow of a rectangle with rounded corners, call it before the rectangle is drawn. The shadow
+    /// fades out over the blur radius around the rectangle.
+    pub fn fill_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    ) {
+        let device_pixel_ratio = self.device_pixel_ratio();
+        let layers = shadow_layers(
+            Rectangle::new((x, y), (width, height)),
+            radius,
+            blur,
+            color.a(),
+        );
+
+        self.canvas().save();
+
+        for layer in layers {
+            let radius = layer
+                .radius
+                .min(layer.rect.width() / 2.0)
+                .min(layer.rect.height() / 2.0) as f32;
+            let (x, y) = (layer.rect.x() as f32, layer.rect.y() as f32);
+            let (r, b) = (
+                x + layer.rect.width() as f32,
+                y + layer.rect.height() as f32,
+            );
+
+            let point = |x: f32, y: f32| vec2f(x, y) * device_pixel_ratio;
+
+            let mut path = Path2D::new();
+            path.move_to(point(x + radius, y));
+            path.line_to(point(r - radius, y));
+            path.quadratic_curve_to(point(r, y), point(r, y + radius));
+            path.line_to(point(r, b - radius));
+            path.quadratic_curve_to(point(r, b), point(r - radius, b));
+            path.line_to(point(x + radius, b));
+            path.quadratic_curve_to(point(x, b), point(x, b - radius));
+            path.line_to(point(x, y + radius));
+            path.quadratic_curve_to(point(x, y), point(x + radius, y));
+            path.close_path();
+
+            self.canvas().set_fill_style(FillStyle::Color(ColorU::new(
+                color.r(),
+                color.g(),
+                color.b(),
+                layer.alpha,
+            )));
+            self.canvas().fill_path(path, FillRule::Winding);
+        }
+
+        self.canvas().restore();
+    }
+
     pub fn device_pixel_ratio(&self) -> f32 {
         self.device_pixel_ratio
     }
@@ -172,10 +245,12 @@ impl RenderContext2D {
     pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
         let device_pixel_ratio = self.device_pixel_ratio();
 
-        let t_m = self.canvas().measure_text(text);
+        // the glyphs are drawn from left to right
+        let text = visual_order(text);
+        let t_m = self.canvas().measure_text(&text);
 
         self.canvas().fill_text(
-            text,
+            &text,
             vec2f(x as f32, y as f32 + t_m.actual_bounding_box_ascent) * device_pixel_ratio,
         );
     }
@@ -293,9 +368,32 @@ impl RenderContext2D {
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {}
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(
+        &mut self,
+        _image: &Image,
+        _x: f64,
+        _y: f64,
+        _width: f64,
+        _height: f64,
+    ) {
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, _image: &Image, _clip: Rectangle, _x: f64, _y: f64) {}
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        _image: &Image,
+        _clip: Rectangle,
+        _x: f64,
+        _y: f64,
+        _width: f64,
+        _height: f64,
+    ) {
+    }
+
     pub fn draw_pipeline(
         &mut self,
         _x: f64,
@@ -354,7 +452,8 @@ impl RenderContext2D {
                         color.a(),
                     )))
             }
-            Brush::LinearGradient { start, end, stops } => {}
+            // gradients are not supported by the pathfinder backend yet
+            Brush::LinearGradient { .. } | Brush::Gradient(_) => {}
         }
     }
 
@@ -372,7 +471,8 @@ impl RenderContext2D {
                         color.a(),
                     )))
             }
-            Brush::LinearGradient { start, end, stops } => {}
+            // gradients are not supported by the pathfinder backend yet
+            Brush::LinearGradient { .. } | Brush::Gradient(_) => {}
         }
     }
 
@@ -394,16 +494,14 @@ impl RenderContext2D {
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
     pub fn save(&mut self) {
-        self.saved_config = Some(self.config.clone());
+        self.saved_configs.push(self.config.clone());
     }
 
     /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack. If there is no saved state, this method does nothing.
     pub fn restore(&mut self) {
-        if let Some(config) = &self.saved_config {
-            self.config = config.clone();
+        if let Some(config) = self.saved_configs.pop() {
+            self.config = config;
         }
-
-        self.saved_config = None;
     }
 
     pub fn clear(&mut self, brush: &Brush) {
@@ -434,6 +532,11 @@ impl RenderContext2D {
         }
     }
 
+    /// Starts a new frame. Pathfinder renders the whole scene on the GPU for each frame, so the region is ignored.
+    pub fn start_region(&mut self, _region: Rectangle) {
+        self.start();
+    }
+
     pub fn finish(&mut self) {
         let canvas = self.canvas.pop().unwrap();
 
@@ -1,4 +1,4 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub use crate::platform::Font;
-pub use crate::platform::Image;
+pub use crate::platform::{AnimatedImage, Image, ImageFrame};
 pub use crate::*;
@@ -1,35 +1,71 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use unicode_bidi::BidiInfo;
+
 use crate::utils::{Color, Rectangle};
 
 #[derive(Debug, Clone)]
 pub struct Font {
-    inner: rusttype::Font<'static>,
+    // bitmap fonts like color emoji fonts have no outlines that could be read by rusttype
+    inner: Option<rusttype::Font<'static>>,
+    data: &'static [u8],
+
+    // the decoded color glyphs by glyph id and font size
+    color_glyphs: RefCell<HashMap<(u16, u32), Option<ColorGlyph>>>,
 }
 
 impl Font {
     pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, rusttype::Error> {
-        rusttype::Font::from_bytes(bytes).map(|font| Font { inner: font })
+        let inner = match rusttype::Font::from_bytes(bytes) {
+            Ok(font) => Some(font),
+            Err(_) if ttf_parser::Face::from_slice(bytes, 0).is_ok() => None,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Font {
+            inner,
+            data: bytes,
+            color_glyphs: RefCell::new(HashMap::new()),
+        })
     }
 
-    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
-        let scale = rusttype::Scale::uniform(size as f32);
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+    /// Gets the font file data.
+    pub fn data(&self) -> &'static [u8] {
+        self.data
+    }
+
+    /// Checks if the font contains a glyph for the given character.
+    pub fn has_glyph(&self, c: char) -> bool {
+        ttf_parser::Face::from_slice(self.data, 0)
+            .ok()
+            .and_then(|face| face.glyph_index(c))
+            .map_or(false, |id| id.0 != 0)
+    }
 
-        let pixel_height = size.ceil();
+    // Returns the distance between the baseline and the highest edge of the glyphs.
+    fn ascent(&self, size: f64) -> f32 {
+        if let Some(inner) = &self.inner {
+            return inner
+                .v_metrics(rusttype::Scale::uniform(size as f32))
+                .ascent;
+        }
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+        ttf_parser::Face::from_slice(self.data, 0).map_or(size as f32, |face| {
+            face.ascender() as f32 * size as f32 / face.units_per_em().unwrap_or(1000) as f32
+        })
+    }
 
-        let width = glyphs
-            .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
-            .ceil() as f64;
+    // Returns the color bitmap of a glyph scaled to the font size, e.g. of an emoji. Outline glyphs have none.
+    fn color_glyph(&self, id: u16, size: f64) -> Option<ColorGlyph> {
+        self.color_glyphs
+            .borrow_mut()
+            .entry((id, size.round() as u32))
+            .or_insert_with(|| ColorGlyph::new(self.data, id, size))
+            .clone()
+    }
 
-        (width, pixel_height)
+    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
+        measure_text(&[self], text, size)
     }
 
     pub fn render_text(
@@ -41,14 +77,7 @@ impl Font {
         config: (f64, Color, f32),
         position: (f64, f64),
     ) {
-        self.render_text_clipped(
-            text,
-            data,
-            width,
-            config,
-            position,
-            Rectangle::new((0.0, 0.0), (width, std::f64::MAX)),
-        );
+        render_text(&[self], text, data, width, config, position);
     }
 
     pub fn render_text_clipped(
@@ -61,70 +90,489 @@ impl Font {
         position: (f64, f64),
         clip: Rectangle,
     ) {
-        let scale = rusttype::Scale::uniform(config.0 as f32);
+        render_text_clipped(&[self], text, data, width, config, position, clip);
+    }
+}
+
+// The color bitmap of a glyph. The offset is measured from the pen position on the baseline to the top left corner.
+#[derive(Debug, Clone)]
+struct ColorGlyph {
+    image: image::RgbaImage,
+    offset: (f32, f32),
+}
+
+impl ColorGlyph {
+    // Decodes the PNG image of the glyph from the bitmap tables of the font (CBDT or sbix) and scales it.
+    fn new(data: &[u8], id: u16, size: f64) -> Option<Self> {
+        let face = ttf_parser::Face::from_slice(data, 0).ok()?;
+        let raster = face.glyph_raster_image(ttf_parser::GlyphId(id), size.ceil() as u16)?;
+
+        if raster.format != ttf_parser::RasterImageFormat::PNG {
+            return None;
+        }
+
+        let image = image::load_from_memory_with_format(raster.data, image::ImageFormat::Png)
+            .ok()?
+            .to_rgba();
 
-        // The origin of a line of text is at the baseline (roughly where non-descending letters sit).
-        // We don't want to clip the text, so we shift it down with an offset when laying it out.
-        // v_metrics.ascent is the distance between the baseline and the highest edge of any glyph in
-        // the font. That's enough to guarantee that there's no clipping.
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+        let scale = size as f32 / raster.pixels_per_em as f32;
+        let width = ((raster.width as f32 * scale).round() as u32).max(1);
+        let height = ((raster.height as f32 * scale).round() as u32).max(1);
+
+        Some(ColorGlyph {
+            image: image::imageops::resize(
+                &image,
+                width,
+                height,
+                image::imageops::FilterType::Triangle,
+            ),
+            offset: (
+                raster.x as f32 * scale,
+                -(raster.y as f32 + raster.height as f32) * scale,
+            ),
+        })
+    }
+}
+
+/// Describes a glyph of a shaped text. The glyph is drawn with the font of the fallback chain at `font`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapedGlyph {
+    pub font: usize,
+    pub id: u16,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Shapes the text with the first font of the given fallback chain. Characters that are missing in a font are taken
+/// from the next font of the chain that contains them. Shaping applies kerning, ligatures and the joining of complex
+/// scripts, right to left parts of the text are placed in their visual order. Returns the glyphs and the width of the
+/// text.
+pub fn shape_text(fonts: &[&Font], text: &str, size: f64) -> (Vec<ShapedGlyph>, f32) {
+    let mut glyphs = vec![];
+    let mut pen_x = 0.0;
+
+    if fonts.is_empty() {
+        return (glyphs, pen_x);
+    }
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+    let bidi_info = BidiInfo::new(text, None);
 
-        let pixel_width = glyphs
+    for paragraph in &bidi_info.paragraphs {
+        let (levels, runs) = bidi_info.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+            let mut font_runs = font_runs(fonts, &text[run]);
+
+            // the parts of a right to left run are placed from its end
+            if rtl {
+                font_runs.reverse();
+            }
+
+            for (font, part) in font_runs {
+                pen_x = shape_run(fonts, font, part, rtl, size, pen_x, &mut glyphs);
+            }
+        }
+    }
+
+    (glyphs, pen_x)
+}
+
+/// Returns the font of the given family followed by all other registered fonts. Glyphs that are missing in the font
+/// are taken from the others.
+pub fn font_chain<'a>(
+    fonts: &'a HashMap<String, Font>,
+    families: &[String],
+    family: &str,
+) -> Vec<&'a Font> {
+    // a family that is not registered falls back to the registered fonts
+    let mut chain: Vec<&Font> = fonts.get(family).into_iter().collect();
+    chain.extend(
+        families
             .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
-            .ceil() as i32;
-
-        let pixel_height = config.0.ceil() as i32;
-
-        for g in glyphs.iter() {
-            if let Some(bb) = g.pixel_bounding_box() {
-                g.draw(|off_x, off_y, v| {
-                    let off_x = off_x as i32 + bb.min.x;
-                    let off_y = off_y as i32 + bb.min.y;
-
-                    if off_x >= 0
-                        && off_x < pixel_width
-                        && off_y >= 0
-                        && off_y < pixel_height
-                        && position.0 + off_x as f64 >= clip.x()
-                        && position.0 + off_x as f64 <= clip.x() + clip.width()
-                        && position.1 + off_y as f64 >= clip.y()
-                        && position.1 + off_y as f64 <= clip.y() + clip.height()
-                    {
-                        // Alpha blending from orbclient
-                        let alpha = (config.2 * v * 255.0) as u32;
-                        let new = (alpha << 24) | (config.1.data & 0x00FF_FFFF);
-
-                        let index = ((position.1 as i32 + off_y) * width as i32
-                            + position.0 as i32
-                            + off_x) as usize;
-                        if index >= data.len() {
-                            return;
-                        }
-                        let old = &mut data[index];
-                        if alpha >= 255 {
-                            *old = new;
-                        } else if alpha > 0 {
-                            let n_alpha = 255 - alpha;
-                            let rb = ((n_alpha * (*old & 0x00FF_00FF))
-                                + (alpha * (new & 0x00FF_00FF)))
-                                >> 8;
-                            let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
-                                + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
-
-                            *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
-                        }
+            .filter(|f| f.as_str() != family)
+            .filter_map(|f| fonts.get(f)),
+    );
+
+    chain
+}
+
+/// Measures the width and the height of the shaped text.
+pub fn measure_text(fonts: &[&Font], text: &str, size: f64) -> (f64, f64) {
+    let (_, width) = shape_text(fonts, text, size);
+
+    (width.ceil() as f64, size.ceil())
+}
+
+/// Draws the shaped text with the given font fallback chain.
+pub fn render_text(
+    fonts: &[&Font],
+    text: &str,
+    data: &mut [u32],
+    width: f64,
+    // size, color, alpha
+    config: (f64, Color, f32),
+    position: (f64, f64),
+) {
+    render_text_clipped(
+        fonts,
+        text,
+        data,
+        width,
+        config,
+        position,
+        Rectangle::new((0.0, 0.0), (width, std::f64::MAX)),
+    );
+}
+
+/// Draws the shaped text with the given font fallback chain inside of the clip rectangle.
+pub fn render_text_clipped(
+    fonts: &[&Font],
+    text: &str,
+    data: &mut [u32],
+    width: f64,
+    // size, color, alpha
+    config: (f64, Color, f32),
+    position: (f64, f64),
+    clip: Rectangle,
+) {
+    let scale = rusttype::Scale::uniform(config.0 as f32);
+
+    let (shaped_glyphs, text_width) = shape_text(fonts, text, config.0);
+
+    // The origin of a line of text is at the baseline (roughly where non-descending letters sit).
+    // We don't want to clip the text, so we shift it down with an offset when laying it out.
+    // v_metrics.ascent is the distance between the baseline and the highest edge of any glyph in
+    // the font. That's enough to guarantee that there's no clipping.
+    let ascent = fonts[0].ascent(config.0);
+
+    let pixel_width = text_width.ceil() as i32;
+    let pixel_height = config.0.ceil() as i32;
+
+    for shaped_glyph in shaped_glyphs {
+        let font = fonts[shaped_glyph.font];
+
+        // color glyphs like emojis are drawn with their own colors
+        if let Some(color_glyph) = font.color_glyph(shaped_glyph.id, config.0) {
+            draw_color_glyph(
+                &color_glyph,
+                data,
+                width,
+                config.2,
+                (
+                    position.0 + (shaped_glyph.x + color_glyph.offset.0) as f64,
+                    position.1 + (ascent + shaped_glyph.y + color_glyph.offset.1) as f64,
+                ),
+                clip,
+            );
+            continue;
+        }
+
+        let inner = match &font.inner {
+            Some(inner) => inner,
+            None => continue,
+        };
+
+        let g = inner
+            .glyph(rusttype::GlyphId(shaped_glyph.id))
+            .scaled(scale)
+            .positioned(rusttype::point(shaped_glyph.x, ascent + shaped_glyph.y));
+
+        if let Some(bb) = g.pixel_bounding_box() {
+            g.draw(|off_x, off_y, v| {
+                let off_x = off_x as i32 + bb.min.x;
+                let off_y = off_y as i32 + bb.min.y;
+
+                if off_x >= 0
+                    && off_x < pixel_width
+                    && off_y >= 0
+                    && off_y < pixel_height
+                    && position.0 + off_x as f64 >= clip.x()
+                    && position.0 + off_x as f64 <= clip.x() + clip.width()
+                    && position.1 + off_y as f64 >= clip.y()
+                    && position.1 + off_y as f64 <= clip.y() + clip.height()
+                {
+                    // Alpha blending from orbclient
+                    let alpha = (config.2 * v * 255.0) as u32;
+                    let new = (alpha << 24) | (config.1.data & 0x00FF_FFFF);
+
+                    let index = ((position.1 as i32 + off_y) * width as i32
+                        + position.0 as i32
+                        + off_x) as usize;
+                    if index >= data.len() {
+                        return;
+                    }
+                    let old = &mut data[index];
+                    if alpha >= 255 {
+                        *old = new;
+                    } else if alpha > 0 {
+                        let n_alpha = 255 - alpha;
+                        let rb =
+                            ((n_alpha * (*old & 0x00FF_00FF)) + (alpha * (new & 0x00FF_00FF))) >> 8;
+                        let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
+                            + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
+
+                        *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
                     }
-                });
+                }
+            });
+        }
+    }
+}
+
+// -- Helpers --
+
+// Draws the bitmap of a color glyph with the given position of its top left corner.
+fn draw_color_glyph(
+    color_glyph: &ColorGlyph,
+    data: &mut [u32],
+    width: f64,
+    alpha: f32,
+    position: (f64, f64),
+    clip: Rectangle,
+) {
+    for (x, y, pixel) in color_glyph.image.enumerate_pixels() {
+        let x = (position.0 + x as f64).round();
+        let y = (position.1 + y as f64).round();
+
+        if x < 0.0
+            || x >= width
+            || y < 0.0
+            || x < clip.x()
+            || x > clip.x() + clip.width()
+            || y < clip.y()
+            || y > clip.y() + clip.height()
+        {
+            continue;
+        }
+
+        let index = (y as i32 * width as i32 + x as i32) as usize;
+        if index >= data.len() {
+            continue;
+        }
+
+        let [r, g, b, a] = pixel.0;
+        let a = (a as f32 * alpha) as u32;
+
+        if a == 0 {
+            continue;
+        }
+
+        let old = data[index];
+        let blend = |new: u32, shift: u32| (new * a + ((old >> shift) & 0xFF) * (255 - a)) / 255;
+
+        data[index] = 0xFF00_0000
+            | (blend(r as u32, 16) << 16)
+            | (blend(g as u32, 8) << 8)
+            | blend(b as u32, 0);
+    }
+}
+
+// Splits the text into parts that are drawn with the same font of the fallback chain. Characters that are not
+// contained by any font are drawn with the first font.
+fn font_runs<'a>(fonts: &[&Font], text: &'a str) -> Vec<(usize, &'a str)> {
+    let mut runs: Vec<(usize, &'a str)> = vec![];
+    let mut start = 0;
+    let mut current = None;
+
+    for (index, c) in text.char_indices() {
+        // a character stays with the font of the previous one if it contains the character, e.g. combining marks
+        let font = match current {
+            Some(font) if fonts[font].has_glyph(c) || c.is_whitespace() => font,
+            _ => fonts.iter().position(|font| font.has_glyph(c)).unwrap_or(0),
+        };
+
+        if let Some(current) = current {
+            if current != font {
+                runs.push((current, &text[start..index]));
+                start = index;
             }
         }
+
+        current = Some(font);
+    }
+
+    if let Some(current) = current {
+        runs.push((current, &text[start..]));
+    }
+
+    runs
+}
+
+// Shapes one part of the text with one font, adds its glyphs and returns the new pen position.
+fn shape_run(
+    fonts: &[&Font],
+    font: usize,
+    text: &str,
+    rtl: bool,
+    size: f64,
+    pen_x: f32,
+    glyphs: &mut Vec<ShapedGlyph>,
+) -> f32 {
+    let face = match rustybuzz::Face::from_slice(fonts[font].data, 0) {
+        Some(face) => face,
+        None => return pen_x,
+    };
+
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_direction(if rtl {
+        rustybuzz::Direction::RightToLeft
+    } else {
+        rustybuzz::Direction::LeftToRight
+    });
+
+    let glyph_buffer = rustybuzz::shape(&face, &[], buffer);
+    let units = size as f32 / face.units_per_em() as f32;
+    let mut pen_x = pen_x;
+
+    for (info, position) in glyph_buffer
+        .glyph_infos()
+        .iter()
+        .zip(glyph_buffer.glyph_positions())
+    {
+        glyphs.push(ShapedGlyph {
+            font,
+            id: info.glyph_id as u16,
+            x: pen_x + position.x_offset as f32 * units,
+            y: -position.y_offset as f32 * units,
+        });
+
+        pen_x += position.x_advance as f32 * units;
+    }
+
+    pen_x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTO_FONT: &[u8] = include_bytes!("../../../theme/src/fonts/Roboto-Regular.ttf");
+    const ICON_FONT: &[u8] = include_bytes!("../../../theme/src/fonts/MaterialIcons.ttf");
+
+    // a character of the icon font that is missing in Roboto
+    const ICON: char = '\u{e137}';
+
+    fn fonts() -> (Font, Font) {
+        (
+            Font::from_bytes(ROBOTO_FONT).unwrap(),
+            Font::from_bytes(ICON_FONT).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_font_runs() {
+        let (roboto, icons) = fonts();
+        let fonts = [&roboto, &icons];
+
+        // the whitespace stays with the icon font, the missing character with the first font
+        let text = format!("a{} b\u{4f60}", ICON);
+        assert_eq!(
+            font_runs(&fonts, &text),
+            vec![
+                (0, "a"),
+                (1, format!("{} ", ICON).as_str()),
+                (0, "b\u{4f60}")
+            ]
+        );
+        assert!(font_runs(&fonts, "").is_empty());
+    }
+
+    #[test]
+    fn test_shape_text_with_fallback() {
+        let (roboto, icons) = fonts();
+        let text = format!("a{}", ICON);
+
+        let (glyphs, width) = shape_text(&[&roboto, &icons], &text, 16.0);
+        let (_, a_width) = shape_text(&[&roboto], "a", 16.0);
+        let (_, icon_width) = shape_text(&[&icons], &ICON.to_string(), 16.0);
+
+        assert_eq!(glyphs.len(), 2);
+        assert_eq!((glyphs[0].font, glyphs[1].font), (0, 1));
+        assert!(glyphs.iter().all(|glyph| glyph.id != 0));
+        assert_eq!(glyphs[1].x, a_width);
+        assert_eq!(width, a_width + icon_width);
+        assert_eq!(
+            measure_text(&[&roboto, &icons], &text, 16.0),
+            (width.ceil() as f64, 16.0)
+        );
+
+        // without fallback the icon is drawn as missing glyph of the first font
+        let (glyphs, _) = shape_text(&[&roboto], &text, 16.0);
+        assert_eq!(glyphs[1].id, 0);
+
+        assert_eq!(shape_text(&[], &text, 16.0), (vec![], 0.0));
+    }
+
+    #[test]
+    fn test_font_chain() {
+        let (roboto, icons) = fonts();
+        let mut fonts = HashMap::new();
+        fonts.insert("Roboto".to_string(), roboto);
+        fonts.insert("Icons".to_string(), icons);
+        let families = vec!["Roboto".to_string(), "Icons".to_string()];
+
+        let chain = |family| {
+            font_chain(&fonts, &families, family)
+                .iter()
+                .map(|font| font.data().as_ptr())
+                .collect::<Vec<_>>()
+        };
+
+        let roboto = fonts["Roboto"].data().as_ptr();
+        let icons = fonts["Icons"].data().as_ptr();
+
+        assert_eq!(chain("Icons"), vec![icons, roboto]);
+        assert_eq!(chain("Roboto"), vec![roboto, icons]);
+        assert_eq!(chain("Missing"), vec![roboto, icons]);
+    }
+
+    #[test]
+    fn test_color_glyph() {
+        let (roboto, _) = fonts();
+        let (glyphs, _) = shape_text(&[&roboto], "a", 16.0);
+
+        // outline glyphs are drawn with the color of the text
+        assert!(roboto.color_glyph(glyphs[0].id, 16.0).is_none());
+    }
+
+    #[test]
+    fn test_draw_color_glyph() {
+        let color_glyph = ColorGlyph {
+            image: image::RgbaImage::from_raw(2, 1, vec![255, 0, 0, 255, 0, 255, 0, 128]).unwrap(),
+            offset: (0.0, 0.0),
+        };
+        let black = 0xFF00_0000;
+        let clip = Rectangle::new((0.0, 0.0), (4.0, 2.0));
+
+        let mut data = vec![black; 8];
+        draw_color_glyph(&color_glyph, &mut data, 4.0, 1.0, (1.0, 1.0), clip);
+        assert_eq!(
+            data,
+            vec![
+                black,
+                black,
+                black,
+                black,
+                black,
+                0xFFFF_0000,
+                0xFF00_8000,
+                black
+            ]
+        );
+
+        // the glyph is drawn with the alpha of the text and clipped
+        let mut data = vec![black; 8];
+        draw_color_glyph(
+            &color_glyph,
+            &mut data,
+            4.0,
+            0.5,
+            (0.0, 0.0),
+            Rectangle::new((0.0, 0.0), (0.5, 2.0)),
+        );
+        assert_eq!(data[0], 0xFF7F_0000);
+        assert_eq!(data[1..], vec![black; 7][..]);
     }
 }
@@ -1,4 +1,6 @@
-use std::{fmt, path::Path};
+use std::{fmt, fs::File, io::BufReader, path::Path};
+
+use image::{gif::GifDecoder, png::PngDecoder, AnimationDecoder, Frames, ImageFormat};
 
 use crate::RenderTarget;
 
@@ -81,6 +83,114 @@ impl Image {
     }
 }
 
+/// A frame of an `AnimatedImage`.
+#[derive(Clone, Default, Debug)]
+pub struct ImageFrame {
+    /// The image of the frame.
+    pub image: Image,
+
+    /// The time in milliseconds the frame is shown.
+    pub delay: f64,
+}
+
+/// An image with a sequence of frames, e.g. loaded from an animated GIF or PNG (APNG).
+#[derive(Clone, Default, Debug)]
+pub struct AnimatedImage {
+    frames: Vec<ImageFrame>,
+    source: String,
+}
+
+impl std::cmp::PartialEq for AnimatedImage {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.frames.len() == other.frames.len()
+    }
+}
+
+impl AnimatedImage {
+    /// Creates a new animated image from the given frames.
+    pub fn new(frames: Vec<ImageFrame>) -> Self {
+        AnimatedImage {
+            frames,
+            source: String::default(),
+        }
+    }
+
+    /// Loads all frames of an animated GIF or PNG (APNG). Other images are loaded as animation with one frame.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let source = path.as_ref().to_string_lossy().to_string();
+
+        let frames = match ImageFormat::from_path(&path) {
+            Ok(ImageFormat::Gif) => {
+                let decoder = GifDecoder::new(open_file(&path)?).map_err(|e| e.to_string())?;
+                frames_from_decoder(decoder.into_frames())?
+            }
+            Ok(ImageFormat::Png) => {
+                let decoder = PngDecoder::new(open_file(&path)?).map_err(|e| e.to_string())?;
+
+                if decoder.is_apng() {
+                    frames_from_decoder(decoder.apng().into_frames())?
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        };
+
+        // still images are loaded as one frame
+        let frames = if frames.is_empty() {
+            vec![ImageFrame {
+                image: Image::from_path(&path)?,
+                delay: 0.0,
+            }]
+        } else {
+            frames
+        };
+
+        Ok(AnimatedImage { frames, source })
+    }
+
+    /// Gets the frames of the animation.
+    pub fn frames(&self) -> &[ImageFrame] {
+        &self.frames
+    }
+
+    /// Gets the count of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the animation has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+fn open_file<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| e.to_string())
+}
+
+fn frames_from_decoder(frames: Frames<'_>) -> Result<Vec<ImageFrame>, String> {
+    let mut image_frames = vec![];
+
+    for frame in frames {
+        let frame = frame.map_err(|e| e.to_string())?;
+        let (numerator, denominator) = frame.delay().numer_denom_ms();
+
+        image_frames.push(ImageFrame {
+            delay: if denominator > 0 {
+                f64::from(numerator) / f64::from(denominator)
+            } else {
+                0.0
+            },
+            image: Image::from_rgba_image(frame.into_buffer())?,
+        });
+    }
+
+    Ok(image_frames)
+}
+
 impl From<(u32, u32, Vec<u32>)> for Image {
     fn from(image: (u32, u32, Vec<u32>)) -> Self {
         Image::from_data(image.0, image.1, image.2).unwrap()
@@ -101,4 +211,22 @@ impl From<String> for Image {
     }
 }
 
+impl From<&str> for AnimatedImage {
+    fn from(s: &str) -> AnimatedImage {
+        AnimatedImage::from_path(s).unwrap()
+    }
+}
+
+impl From<String> for AnimatedImage {
+    fn from(s: String) -> AnimatedImage {
+        AnimatedImage::from_path(s).unwrap()
+    }
+}
+
+impl From<Vec<ImageFrame>> for AnimatedImage {
+    fn from(frames: Vec<ImageFrame>) -> AnimatedImage {
+        AnimatedImage::new(frames)
+    }
+}
+
 // --- Conversions ---
@@ -1,27 +1,61 @@
 use std::{cmp, collections::HashMap};
 
-use crate::{utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    physical_size, shadow_layers, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+    DEFAULT_SCALE_FACTOR,
+};
 
 pub use self::font::*;
-pub use self::image::Image;
+pub use self::image::{AnimatedImage, Image, ImageFrame};
 
 mod font;
 mod image;
 
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
+///
+/// The coordinates and sizes are given in logical pixels, the draw target has the size in physical pixels of the
+/// monitor. Shapes and images are scaled by the scale factor, text is rasterized in physical pixels.
 pub struct RenderContext2D {
     draw_target: raqote::DrawTarget,
+
+    // the size in logical pixels
+    size: (f64, f64),
+    scale_factor: f64,
+
+    // the transform that is set by `set_transform`, it's scaled by the scale factor
+    transform: Transform,
+
     path: raqote::Path,
     config: RenderConfig,
-    saved_config: Option<RenderConfig>,
+    saved_states: Vec<SavedState>,
     fonts: HashMap<String, Font>,
 
+    // the registered font families in the order of their registration, used as font fallback chain
+    font_families: Vec<String>,
+
     // hack / work around for faster text clipping
     clip: bool,
     last_rect: Rectangle,
     clip_rect: Option<Rectangle>,
 
+    // the number of clips pushed since the last save
+    clips: usize,
+
     background: Color,
+
+    // the next frame has to redraw the whole draw target, e.g. after a resize
+    redraw: bool,
+
+    // the current frame redraws only a region of the last frame
+    region: bool,
+}
+
+// The state of the canvas that is restored by `restore`.
+struct SavedState {
+    config: RenderConfig,
+    clip: bool,
+    clip_rect: Option<Rectangle>,
+    clips: usize,
 }
 
 impl RenderContext2D {
@@ -29,37 +63,94 @@ impl RenderContext2D {
     pub fn new(width: f64, height: f64) -> Self {
         RenderContext2D {
             draw_target: raqote::DrawTarget::new(width as i32, height as i32),
+            size: (width, height),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            transform: Transform::default(),
             path: raqote::Path {
                 ops: Vec::new(),
                 winding: raqote::Winding::NonZero,
             },
             config: RenderConfig::default(),
-            saved_config: None,
+            saved_states: vec![],
             fonts: HashMap::new(),
+            font_families: vec![],
             clip: false,
             last_rect: Rectangle::new((0.0, 0.0), (width, height)),
             clip_rect: None,
+            clips: 0,
             background: Color::default(),
+            redraw: true,
+            region: false,
         }
     }
 
     /// Set the background of the render context.
     pub fn set_background(&mut self, background: Color) {
+        if self.background != background {
+            self.redraw = true;
+        }
+
         self.background = background;
     }
 
+    /// Resizes the render context to the given size in logical pixels.
     pub fn resize(&mut self, width: f64, height: f64) {
+        self.size = (width, height);
+        self.create_draw_target();
+    }
+
+    /// Gets the scale factor of the monitor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the scale factor of the monitor. The draw target is resized to keep its size in logical pixels.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor <= 0.0 || (self.scale_factor - scale_factor).abs() < f64::EPSILON {
+            return;
+        }
+
+        self.scale_factor = scale_factor;
+        self.create_draw_target();
+    }
+
+    // Creates the draw target with the size in physical pixels, the whole frame is redrawn.
+    fn create_draw_target(&mut self) {
+        let (width, height) = physical_size(self.size.0, self.size.1, self.scale_factor);
         self.draw_target = raqote::DrawTarget::new(width as i32, height as i32);
+        self.apply_transform();
+        self.redraw = true;
+    }
+
+    // Sets the transform scaled by the scale factor to the draw target.
+    fn apply_transform(&mut self) {
+        let transform = self
+            .transform
+            .then(Transform::scale(self.scale_factor, self.scale_factor));
+
+        self.draw_target
+            .set_transform(&raqote::Transform::row_major(
+                transform.a as f32,
+                transform.b as f32,
+                transform.c as f32,
+                transform.d as f32,
+                transform.e as f32,
+                transform.f as f32,
+            ));
     }
 
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
-        if self.fonts.contains_key(family) {
-            return;
+        if let Some(font) = self.fonts.get(family) {
+            if std::ptr::eq(font.data(), font_file) {
+                return;
+            }
         }
 
         if let Ok(font) = Font::from_bytes(font_file) {
-            self.fonts.insert(family.to_string(), font);
+            if self.fonts.insert(family.to_string(), font).is_none() {
+                self.font_families.push(family.to_string());
+            }
         }
     }
 
@@ -72,7 +163,10 @@ impl RenderContext2D {
             y as f32,
             width as f32,
             height as f32,
-            &brush_to_source(&self.config.fill_style),
+            &brush_to_source(
+                &self.config.fill_style,
+                Rectangle::new((x, y), (width, height)),
+            ),
             &raqote::DrawOptions {
                 alpha: self.config.alpha,
                 ..Default::default()
@@ -86,6 +180,37 @@ impl RenderContext2D {
         self.stroke();
     }
 
+    /// Draws the soft shadow of a rectangle with rounded corners, call it before the rectangle is drawn. The shadow
+    /// fades out over the blur radius around the rectangle.
+    pub fn fill_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    ) {
+        let rect = Rectangle::new((x, y), (width, height));
+
+        for layer in shadow_layers(rect, radius, blur, color.a()) {
+            self.draw_target.fill(
+                &rounded_rect_path(layer.rect, layer.radius),
+                &raqote::Source::Solid(raqote::SolidSource {
+                    r: premultiply(color.r(), layer.alpha),
+                    g: premultiply(color.g(), layer.alpha),
+                    b: premultiply(color.b(), layer.alpha),
+                    a: layer.alpha,
+                }),
+                &raqote::DrawOptions {
+                    alpha: self.config.alpha,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
     // Text
 
     /// Draws (fills) a given text at the given (x, y) position.
@@ -94,46 +219,54 @@ impl RenderContext2D {
             return;
         }
 
-        let color = match self.config.fill_style {
-            Brush::SolidColor(color) => color,
-            _ => Color::from("#000000"),
-        };
+        // text is filled with the first color of a gradient
+        let color = Color::from(self.config.fill_style.clone());
 
         if color.a() == 0 || self.config.alpha == 0.0 {
             return;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let width = self.draw_target.width() as f64;
-
-            if self.clip {
-                if let Some(rect) = self.clip_rect {
-                    font.render_text_clipped(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                        rect,
-                    );
-                } else {
-                    font.render_text(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                    );
-                }
-            } else {
-                font.render_text(
-                    text,
-                    self.draw_target.get_data_mut(),
-                    width,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
-                );
-            }
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_families,
+            &self.config.font_config.family,
+        );
+
+        if fonts.is_empty() {
+            return;
+        }
+
+        // the text is rasterized in physical pixels to keep it sharp on HiDPI monitors
+        let scale_factor = self.scale_factor;
+        let width = self.draw_target.width() as f64;
+        let config = (
+            self.config.font_config.font_size * scale_factor,
+            color,
+            self.config.alpha,
+        );
+        let position = (x * scale_factor, y * scale_factor);
+
+        match self.clip_rect.filter(|_| self.clip) {
+            Some(rect) => render_text_clipped(
+                &fonts,
+                text,
+                self.draw_target.get_data_mut(),
+                width,
+                config,
+                position,
+                Rectangle::new(
+                    (rect.x() * scale_factor, rect.y() * scale_factor),
+                    (rect.width() * scale_factor, rect.height() * scale_factor),
+                ),
+            ),
+            None => render_text(
+                &fonts,
+                text,
+                self.draw_target.get_data_mut(),
+                width,
+                config,
+                position,
+            ),
         }
     }
 
@@ -145,8 +278,14 @@ impl RenderContext2D {
             return text_metrics;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_families,
+            &self.config.font_config.family,
+        );
+
+        if !fonts.is_empty() {
+            let (width, height) = measure_text(&fonts, text, self.config.font_config.font_size);
 
             text_metrics.width = width;
             text_metrics.height = height;
@@ -159,7 +298,7 @@ impl RenderContext2D {
     pub fn fill(&mut self) {
         self.draw_target.fill(
             &self.path,
-            &brush_to_source(&self.config.fill_style),
+            &brush_to_source(&self.config.fill_style, path_bounds(&self.path)),
             &raqote::DrawOptions {
                 alpha: self.config.alpha,
                 ..Default::default()
@@ -171,7 +310,7 @@ impl RenderContext2D {
     pub fn stroke(&mut self) {
         self.draw_target.stroke(
             &self.path,
-            &brush_to_source(&self.config.stroke_style),
+            &brush_to_source(&self.config.stroke_style, path_bounds(&self.path)),
             &raqote::StrokeStyle {
                 width: self.config.line_width as f32,
                 ..Default::default()
@@ -290,6 +429,25 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(&mut self, image: &Image, x: f64, y: f64, width: f64, height: f64) {
+        self.draw_target.draw_image_with_size_at(
+            width as f32,
+            height as f32,
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                data: &image.data(),
+                width: image.width() as i32,
+                height: image.height() as i32,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
         let mut y = y as i32;
@@ -320,6 +478,53 @@ impl RenderContext2D {
         }
     }
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        let stride = image.width() as usize;
+        let clip_x = clip.x().max(0.0) as usize;
+        let clip_y = clip.y().max(0.0) as usize;
+        let clip_width = cmp::min(clip.width() as usize, stride.saturating_sub(clip_x));
+        let clip_height = cmp::min(
+            clip.height() as usize,
+            (image.height() as usize).saturating_sub(clip_y),
+        );
+
+        if clip_width == 0 || clip_height == 0 {
+            return;
+        }
+
+        let mut data = Vec::with_capacity(clip_width * clip_height);
+
+        for row in clip_y..clip_y + clip_height {
+            let offset = row * stride + clip_x;
+            data.extend_from_slice(&image.data()[offset..offset + clip_width]);
+        }
+
+        self.draw_target.draw_image_with_size_at(
+            width as f32,
+            height as f32,
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                data: &data,
+                width: clip_width as i32,
+                height: clip_height as i32,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -328,15 +533,37 @@ impl RenderContext2D {
         height: f64,
         pipeline: Box<dyn PipelineTrait>,
     ) {
-        let mut render_target = RenderTarget::new(width as u32, height as u32);
+        // the pipeline draws in physical pixels
+        let (physical_width, physical_height) = physical_size(width, height, self.scale_factor);
+        let mut render_target = RenderTarget::new(physical_width, physical_height);
         pipeline.draw_pipeline(&mut render_target);
-        self.draw_render_target(&render_target, x, y);
+
+        self.draw_target.draw_image_with_size_at(
+            width as f32,
+            height as f32,
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                data: &render_target.data(),
+                width: physical_width as i32,
+                height: physical_height as i32,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
     }
 
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
+    /// The clipping path is intersected with the clipping paths that are already active.
     pub fn clip(&mut self) {
-        self.clip_rect = Some(self.last_rect);
+        self.clip_rect = Some(match self.clip_rect.filter(|_| self.clip) {
+            Some(clip_rect) => clip_rect.intersection(&self.last_rect),
+            None => self.last_rect,
+        });
         self.clip = true;
+        self.clips += 1;
         self.draw_target.push_clip(&self.path);
     }
 
@@ -386,35 +613,45 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
-        self.draw_target
-            .set_transform(&raqote::Transform::row_major(
-                h_scaling as f32,
-                h_skewing as f32,
-                v_skewing as f32,
-                v_scaling as f32,
-                h_moving as f32,
-                v_moving as f32,
-            ));
+        self.transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        );
+        self.apply_transform();
     }
 
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
     pub fn save(&mut self) {
-        self.saved_config = Some(self.config.clone());
+        self.saved_states.push(SavedState {
+            config: self.config.clone(),
+            clip: self.clip,
+            clip_rect: self.clip_rect,
+            clips: self.clips,
+        });
+        self.clips = 0;
     }
 
-    /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack.
-    /// If there is no saved state, this method does nothing.
+    /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack. The clips
+    /// since the save are removed. If there is no saved state, only the clips are removed.
     pub fn restore(&mut self) {
-        self.clip = false;
-        self.clip_rect = None;
-        self.draw_target.pop_clip();
-        if let Some(config) = &self.saved_config {
-            self.config = config.clone();
+        for _ in 0..self.clips {
+            self.draw_target.pop_clip();
         }
 
-        self.saved_config = None;
+        match self.saved_states.pop() {
+            Some(state) => {
+                self.config = state.config;
+                self.clip = state.clip;
+                self.clip_rect = state.clip_rect;
+                self.clips = state.clips;
+            }
+            None => {
+                self.clip = false;
+                self.clip_rect = None;
+                self.clips = 0;
+            }
+        }
     }
 
     pub fn clear(&mut self, brush: &Brush) {
@@ -451,11 +688,52 @@ impl RenderContext2D {
 
     pub fn start(&mut self) {
         self.clear(&Brush::from(self.background));
+        self.redraw = false;
+    }
+
+    /// Starts a new frame that redraws only the given region of the last frame. The region is cleared and all drawing
+    /// is clipped to it until `finish`. If the size or the background has changed since the last frame the whole
+    /// frame is redrawn.
+    pub fn start_region(&mut self, region: Rectangle) {
+        if self.redraw {
+            self.start();
+            return;
+        }
+
+        self.save();
+        self.begin_path();
+        self.rect(region.x(), region.y(), region.width(), region.height());
+        self.clip();
+        self.region = true;
+
+        self.draw_target.fill_rect(
+            region.x() as f32,
+            region.y() as f32,
+            region.width() as f32,
+            region.height() as f32,
+            &raqote::Source::Solid(raqote::SolidSource {
+                r: self.background.r(),
+                g: self.background.g(),
+                b: self.background.b(),
+                a: self.background.a(),
+            }),
+            &raqote::DrawOptions {
+                blend_mode: raqote::BlendMode::Src,
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn finish(&mut self) {
+        if self.region {
+            self.region = false;
+            self.restore();
+        }
     }
-    pub fn finish(&mut self) {}
 }
 
-fn brush_to_source<'a>(brush: &Brush) -> raqote::Source<'a> {
+// Converts the brush to a raqote source, gradients that adapt to the shape are placed inside of the given bounds.
+fn brush_to_source<'a>(brush: &Brush, bounds: Rectangle) -> raqote::Source<'a> {
     match brush {
         Brush::SolidColor(color) => raqote::Source::Solid(raqote::SolidSource {
             r: color.r(),
@@ -463,26 +741,104 @@ fn brush_to_source<'a>(brush: &Brush) -> raqote::Source<'a> {
             b: color.b(),
             a: color.a(),
         }),
-        Brush::LinearGradient { start, end, stops } => {
-            let g_stops = stops
-                .iter()
-                .map(|stop| raqote::GradientStop {
-                    position: stop.position as f32,
-                    color: raqote::Color::new(
-                        stop.color.a(),
-                        stop.color.r(),
-                        stop.color.g(),
-                        stop.color.b(),
-                    ),
-                })
-                .collect();
-
-            raqote::Source::new_linear_gradient(
-                raqote::Gradient { stops: g_stops },
-                raqote::Point::new(start.x() as f32, start.y() as f32),
-                raqote::Point::new(end.x() as f32, start.y() as f32),
-                raqote::Spread::Pad,
-            )
-        }
+        Brush::LinearGradient { start, end, stops } => raqote::Source::new_linear_gradient(
+            gradient_stops(stops),
+            raqote::Point::new(start.x() as f32, start.y() as f32),
+            raqote::Point::new(end.x() as f32, end.y() as f32),
+            raqote::Spread::Pad,
+        ),
+        Brush::Gradient(gradient) => match gradient.kind {
+            GradientKind::Linear(angle) => {
+                let (start, end) = Gradient::linear_coords(angle, bounds);
+
+                raqote::Source::new_linear_gradient(
+                    gradient_stops(&gradient.stops),
+                    raqote::Point::new(start.x() as f32, start.y() as f32),
+                    raqote::Point::new(end.x() as f32, end.y() as f32),
+                    raqote::Spread::Pad,
+                )
+            }
+            GradientKind::Radial(center) => {
+                let (center, radius) = Gradient::radial_coords(center, bounds);
+
+                raqote::Source::new_radial_gradient(
+                    gradient_stops(&gradient.stops),
+                    raqote::Point::new(center.x() as f32, center.y() as f32),
+                    radius as f32,
+                    raqote::Spread::Pad,
+                )
+            }
+        },
+    }
+}
+
+fn gradient_stops(stops: &[GradientStop]) -> raqote::Gradient {
+    raqote::Gradient {
+        stops: stops
+            .iter()
+            .map(|stop| raqote::GradientStop {
+                position: stop.position as f32,
+                color: raqote::Color::new(
+                    stop.color.a(),
+                    stop.color.r(),
+                    stop.color.g(),
+                    stop.color.b(),
+                ),
+            })
+            .collect(),
+    }
+}
+
+// Calculates the bounds of the path including the control points of the curves.
+fn path_bounds(path: &raqote::Path) -> Rectangle {
+    let points = path.ops.iter().flat_map(|op| match *op {
+        raqote::PathOp::MoveTo(p) | raqote::PathOp::LineTo(p) => vec![p],
+        raqote::PathOp::QuadTo(c, p) => vec![c, p],
+        raqote::PathOp::CubicTo(c1, c2, p) => vec![c1, c2, p],
+        raqote::PathOp::Close => vec![],
+    });
+
+    let (mut left, mut top) = (f32::MAX, f32::MAX);
+    let (mut right, mut bottom) = (f32::MIN, f32::MIN);
+
+    for point in points {
+        left = left.min(point.x);
+        top = top.min(point.y);
+        right = right.max(point.x);
+        bottom = bottom.max(point.y);
     }
+
+    if left > right || top > bottom {
+        return Rectangle::default();
+    }
+
+    Rectangle::new(
+        (left as f64, top as f64),
+        ((right - left) as f64, (bottom - top) as f64),
+    )
+}
+
+// Builds the path of a rectangle with rounded corners.
+fn rounded_rect_path(rect: Rectangle, radius: f64) -> raqote::Path {
+    let radius = radius.min(rect.width() / 2.0).min(rect.height() / 2.0) as f32;
+    let (x, y) = (rect.x() as f32, rect.y() as f32);
+    let (r, b) = (x + rect.width() as f32, y + rect.height() as f32);
+
+    let mut path_builder = raqote::PathBuilder::new();
+    path_builder.move_to(x + radius, y);
+    path_builder.line_to(r - radius, y);
+    path_builder.quad_to(r, y, r, y + radius);
+    path_builder.line_to(r, b - radius);
+    path_builder.quad_to(r, b, r - radius, b);
+    path_builder.line_to(x + radius, b);
+    path_builder.quad_to(x, b, x, b - radius);
+    path_builder.line_to(x, y + radius);
+    path_builder.quad_to(x, y, x + radius, y);
+    path_builder.close();
+    path_builder.finish()
+}
+
+// Multiplies the color channel with the alpha value, raqote expects premultiplied colors.
+fn premultiply(channel: u8, alpha: u8) -> u8 {
+    (channel as u16 * alpha as u16 / 255) as u8
 }
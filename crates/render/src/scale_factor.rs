@@ -0,0 +1,56 @@
+/// The scale factor of a monitor without HiDPI support, one logical pixel is drawn with one physical pixel.
+pub const DEFAULT_SCALE_FACTOR: f64 = 1.0;
+
+/// Converts a logical size, that is used by layouts and render objects, to the size in physical pixels of a monitor
+/// with the given scale factor.
+pub fn physical_size(width: f64, height: f64, scale_factor: f64) -> (u32, u32) {
+    (
+        (width * scale_factor).round().max(0.0) as u32,
+        (height * scale_factor).round().max(0.0) as u32,
+    )
+}
+
+/// Aligns the logical position of a line with the given width to the physical pixels of a monitor with the given
+/// scale factor, the line is drawn sharp instead of blurred over two pixel rows. A line with an odd width in physical
+/// pixels is placed in the center of a pixel, other lines on the border between two pixels.
+pub fn snap_line(position: f64, line_width: f64, scale_factor: f64) -> f64 {
+    if scale_factor <= 0.0 {
+        return position;
+    }
+
+    let physical = position * scale_factor;
+    let physical_width = (line_width * scale_factor).round().max(1.0) as u64;
+
+    let snapped = if physical_width % 2 == 1 {
+        physical.floor() + 0.5
+    } else {
+        physical.round()
+    };
+
+    snapped / scale_factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_physical_size() {
+        assert_eq!(physical_size(100.0, 50.0, 1.0), (100, 50));
+        assert_eq!(physical_size(100.0, 50.0, 2.0), (200, 100));
+        assert_eq!(physical_size(101.0, 33.0, 1.5), (152, 50));
+    }
+
+    #[test]
+    fn test_snap_line() {
+        assert!((snap_line(10.3, 1.0, 1.0) - 10.5).abs() < f64::EPSILON);
+        assert!((snap_line(10.3, 2.0, 1.0) - 10.0).abs() < f64::EPSILON);
+
+        // a line of one logical pixel is two physical pixels wide
+        assert!((snap_line(10.3, 1.0, 2.0) - 10.5).abs() < f64::EPSILON);
+        assert!((snap_line(10.1, 1.0, 2.0) - 10.0).abs() < f64::EPSILON);
+
+        // a line of one logical pixel is three physical pixels wide
+        assert!((snap_line(10.0, 1.0, 3.0) - 30.5 / 3.0).abs() < f64::EPSILON);
+    }
+}
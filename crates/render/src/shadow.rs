@@ -0,0 +1,80 @@
+use crate::utils::Rectangle;
+
+/// The maximum number of layers of a shadow that is drawn by `shadow_layers`.
+pub const MAX_SHADOW_LAYERS: usize = 12;
+
+/// Describes one layer of a soft shadow, a rounded rectangle that is filled with the alpha value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShadowLayer {
+    /// The rectangle of the layer.
+    pub rect: Rectangle,
+
+    /// The corner radius of the layer.
+    pub radius: f64,
+
+    /// The alpha value of the layer.
+    pub alpha: u8,
+}
+
+/// Splits the soft shadow of a rounded rectangle into layers for render backends without a native blur. Each
+/// layer grows the rectangle by a part of the blur radius, the layers add up to the given alpha inside of the
+/// rectangle and fade out to its outer edge.
+pub fn shadow_layers(rect: Rectangle, radius: f64, blur: f64, alpha: u8) -> Vec<ShadowLayer> {
+    if alpha == 0 || rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return vec![];
+    }
+
+    let count = (blur.max(0.0).ceil() as usize).clamp(1, MAX_SHADOW_LAYERS);
+
+    // the alpha of one layer, so that all layers together cover with the given alpha
+    let total = alpha as f64 / 255.0;
+    let layer_alpha = (1.0 - (1.0 - total).powf(1.0 / count as f64)) * 255.0;
+    let layer_alpha = layer_alpha.round().clamp(1.0, 255.0) as u8;
+
+    (1..=count)
+        .map(|i| {
+            let spread = blur.max(0.0) * i as f64 / count as f64;
+
+            ShadowLayer {
+                rect: Rectangle::new(
+                    (rect.x() - spread, rect.y() - spread),
+                    (rect.width() + 2.0 * spread, rect.height() + 2.0 * spread),
+                ),
+                radius: radius.max(0.0) + spread,
+                alpha: layer_alpha,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shadow_layers() {
+        let layers = shadow_layers(Rectangle::new((10.0, 10.0), (100.0, 50.0)), 4.0, 8.0, 128);
+
+        assert_eq!(layers.len(), 8);
+        assert_eq!(layers[0].rect, Rectangle::new((9.0, 9.0), (102.0, 52.0)));
+        assert_eq!(layers[7].rect, Rectangle::new((2.0, 2.0), (116.0, 66.0)));
+        assert!((layers[7].radius - 12.0).abs() < f64::EPSILON);
+
+        // all layers together cover the rectangle with nearly the given alpha
+        let covered = 1.0
+            - layers
+                .iter()
+                .fold(1.0, |rest, layer| rest * (1.0 - layer.alpha as f64 / 255.0));
+        assert!((covered * 255.0 - 128.0).abs() < 8.0);
+
+        assert_eq!(
+            shadow_layers(Rectangle::new((0.0, 0.0), (10.0, 10.0)), 0.0, 100.0, 255).len(),
+            MAX_SHADOW_LAYERS
+        );
+        assert_eq!(
+            shadow_layers(Rectangle::new((0.0, 0.0), (10.0, 10.0)), 0.0, 0.0, 64).len(),
+            1
+        );
+        assert!(shadow_layers(Rectangle::new((0.0, 0.0), (10.0, 10.0)), 0.0, 8.0, 0).is_empty());
+    }
+}
@@ -0,0 +1,1221 @@
+use std::{f64::consts::PI, fs, path::Path};
+
+use crate::{
+    utils::{Brush, Color, Point, Rectangle},
+    RenderContext2D,
+};
+
+/// Describes a drawing command of a `VectorPath`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathCommand {
+    /// Starts a new sub path at the point.
+    MoveTo(Point),
+
+    /// Draws a line to the point.
+    LineTo(Point),
+
+    /// Draws a quadratic curve with the control point to the point.
+    QuadraticCurveTo(Point, Point),
+
+    /// Draws a cubic curve with the two control points to the point.
+    BezierCurveTo(Point, Point, Point),
+
+    /// Closes the current sub path.
+    ClosePath,
+}
+
+/// A path of a `VectorImage` with its fill and stroke. The points are in the coordinates of the view box of the
+/// image.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VectorPath {
+    /// The drawing commands of the path.
+    pub commands: Vec<PathCommand>,
+
+    /// The fill color, `None` if the path is not filled.
+    pub fill: Option<Color>,
+
+    /// The stroke color, `None` if the path is not stroked.
+    pub stroke: Option<Color>,
+
+    /// The width of the stroke.
+    pub stroke_width: f64,
+}
+
+/// A vector image that is parsed from SVG. It could be drawn in any size without losing quality.
+///
+/// Supported are the elements `svg`, `g`, `path`, `rect`, `circle`, `ellipse`, `line`, `polyline` and `polygon`
+/// with the attributes (or style properties) `fill`, `stroke`, `stroke-width`, `opacity`, `fill-opacity`,
+/// `stroke-opacity` and `transform`. Gradients, text, masks and clip paths are not supported.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VectorImage {
+    width: f64,
+    height: f64,
+    view_box: Rectangle,
+    paths: Vec<VectorPath>,
+}
+
+impl VectorImage {
+    /// Parses a vector image from a SVG document.
+    pub fn from_svg(svg: &str) -> Result<Self, String> {
+        SvgParser::default().parse(svg)
+    }
+
+    /// Loads a vector image from a SVG file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let svg = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        VectorImage::from_svg(&svg)
+    }
+
+    /// Gets the width of the image.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Gets the height of the image.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Gets the view box, the part of the image coordinates that is drawn.
+    pub fn view_box(&self) -> Rectangle {
+        self.view_box
+    }
+
+    /// Gets the paths of the image.
+    pub fn paths(&self) -> &[VectorPath] {
+        &self.paths
+    }
+
+    /// Returns `true` if the image has nothing to draw.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Draws the image scaled to the given rectangle.
+    pub fn draw(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        let view_box = self.view_box;
+
+        if view_box.width() <= 0.0 || view_box.height() <= 0.0 {
+            return;
+        }
+
+        let scale_x = width / view_box.width();
+        let scale_y = height / view_box.height();
+        let map = |point: Point| {
+            (
+                x + (point.x() - view_box.x()) * scale_x,
+                y + (point.y() - view_box.y()) * scale_y,
+            )
+        };
+
+        for path in &self.paths {
+            render_context_2_d.begin_path();
+
+            for command in &path.commands {
+                match *command {
+                    PathCommand::MoveTo(point) => {
+                        let (x, y) = map(point);
+                        render_context_2_d.move_to(x, y);
+                    }
+                    PathCommand::LineTo(point) => {
+                        let (x, y) = map(point);
+                        render_context_2_d.line_to(x, y);
+                    }
+                    PathCommand::QuadraticCurveTo(control, point) => {
+                        let (cx, cy) = map(control);
+                        let (x, y) = map(point);
+                        render_context_2_d.quadratic_curve_to(cx, cy, x, y);
+                    }
+                    PathCommand::BezierCurveTo(control_1, control_2, point) => {
+                        let (c1x, c1y) = map(control_1);
+                        let (c2x, c2y) = map(control_2);
+                        let (x, y) = map(point);
+                        render_context_2_d.bezier_curve_to(c1x, c1y, c2x, c2y, x, y);
+                    }
+                    PathCommand::ClosePath => render_context_2_d.close_path(),
+                }
+            }
+
+            if let Some(fill) = path.fill {
+                render_context_2_d.set_fill_style(Brush::from(fill));
+                render_context_2_d.fill();
+            }
+
+            if let Some(stroke) = path.stroke {
+                render_context_2_d.set_line_width(path.stroke_width * (scale_x + scale_y) / 2.0);
+                render_context_2_d.set_stroke_style(Brush::from(stroke));
+                render_context_2_d.stroke();
+            }
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for VectorImage {
+    /// Parses the text as SVG document if it starts with `<`, otherwise it is loaded as file.
+    fn from(s: &str) -> VectorImage {
+        if s.trim_start().starts_with('<') {
+            VectorImage::from_svg(s).unwrap()
+        } else {
+            VectorImage::from_path(s).unwrap()
+        }
+    }
+}
+
+impl From<String> for VectorImage {
+    fn from(s: String) -> VectorImage {
+        VectorImage::from(s.as_str())
+    }
+}
+
+// --- Conversions ---
+
+// --- SVG parser ---
+
+// Elements whose content is not drawn.
+static SKIPPED_ELEMENTS: [&str; 12] = [
+    "defs", "clipPath", "mask", "pattern", "symbol", "marker", "style", "script", "title", "desc",
+    "metadata", "text",
+];
+
+// Affine transformation matrix (a, b, c, d, e, f).
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Transform([f64; 6]);
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform([1.0, 0.0, 0.0, 1.0, 0.0, 0.0])
+    }
+}
+
+impl Transform {
+    // Returns the transform that applies the other transform first and this transform after it.
+    fn multiply(self, other: Transform) -> Transform {
+        let [a, b, c, d, e, f] = self.0;
+        let [oa, ob, oc, od, oe, of] = other.0;
+
+        Transform([
+            a * oa + c * ob,
+            b * oa + d * ob,
+            a * oc + c * od,
+            b * oc + d * od,
+            a * oe + c * of + e,
+            b * oe + d * of + f,
+        ])
+    }
+
+    fn apply(self, x: f64, y: f64) -> Point {
+        let [a, b, c, d, e, f] = self.0;
+        Point::new(a * x + c * y + e, b * x + d * y + f)
+    }
+
+    // The average scale, used for stroke widths.
+    fn scale(self) -> f64 {
+        let [a, b, c, d, _, _] = self.0;
+        (a * d - b * c).abs().sqrt()
+    }
+
+    // Parses a transform list like `translate(10, 20) scale(2)`.
+    fn parse(text: &str) -> Transform {
+        let mut transform = Transform::default();
+
+        for part in text.split(')') {
+            let mut parts = part.splitn(2, '(');
+            let name = parts
+                .next()
+                .unwrap_or_default()
+                .trim_matches(|c: char| c.is_whitespace() || c == ',');
+            let values = Numbers::new(parts.next().unwrap_or_default()).collect::<Vec<f64>>();
+            let value = |i: usize| values.get(i).copied().unwrap_or_default();
+
+            let next = match (name, values.len()) {
+                ("matrix", 6) => {
+                    Transform([value(0), value(1), value(2), value(3), value(4), value(5)])
+                }
+                ("translate", 1) => Transform([1.0, 0.0, 0.0, 1.0, value(0), 0.0]),
+                ("translate", 2) => Transform([1.0, 0.0, 0.0, 1.0, value(0), value(1)]),
+                ("scale", 1) => Transform([value(0), 0.0, 0.0, value(0), 0.0, 0.0]),
+                ("scale", 2) => Transform([value(0), 0.0, 0.0, value(1), 0.0, 0.0]),
+                ("rotate", 1) | ("rotate", 3) => {
+                    let (sin, cos) = value(0).to_radians().sin_cos();
+                    let rotate = Transform([cos, sin, -sin, cos, 0.0, 0.0]);
+
+                    if values.len() == 3 {
+                        Transform([1.0, 0.0, 0.0, 1.0, value(1), value(2)])
+                            .multiply(rotate)
+                            .multiply(Transform([1.0, 0.0, 0.0, 1.0, -value(1), -value(2)]))
+                    } else {
+                        rotate
+                    }
+                }
+                ("skewX", 1) => Transform([1.0, 0.0, value(0).to_radians().tan(), 1.0, 0.0, 0.0]),
+                ("skewY", 1) => Transform([1.0, value(0).to_radians().tan(), 0.0, 1.0, 0.0, 0.0]),
+                _ => continue,
+            };
+
+            transform = transform.multiply(next);
+        }
+
+        transform
+    }
+}
+
+// The inherited presentation attributes of an element.
+#[derive(Copy, Clone, Debug)]
+struct SvgStyle {
+    fill: Option<Color>,
+    stroke: Option<Color>,
+    stroke_width: f64,
+    opacity: f64,
+    fill_opacity: f64,
+    stroke_opacity: f64,
+    transform: Transform,
+}
+
+impl Default for SvgStyle {
+    fn default() -> Self {
+        SvgStyle {
+            fill: Some(Color::rgb(0, 0, 0)),
+            stroke: None,
+            stroke_width: 1.0,
+            opacity: 1.0,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
+            transform: Transform::default(),
+        }
+    }
+}
+
+impl SvgStyle {
+    // Returns the style of a child element with the given attributes.
+    fn child(&self, attributes: &[(&str, String)]) -> SvgStyle {
+        let mut style = *self;
+        // opacity is not inherited, it is applied to the colors of the element
+        style.opacity = 1.0;
+
+        let mut properties: Vec<(&str, &str)> = attributes
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect();
+
+        // the style attribute overrides the presentation attributes
+        if let Some((_, css)) = attributes.iter().find(|(key, _)| *key == "style") {
+            for declaration in css.split(';') {
+                let mut parts = declaration.splitn(2, ':');
+
+                if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+                    properties.push((key.trim(), value.trim()));
+                }
+            }
+        }
+
+        for (key, value) in properties {
+            match key {
+                "fill" => style.fill = parse_color(value),
+                "stroke" => style.stroke = parse_color(value),
+                "stroke-width" => {
+                    style.stroke_width = parse_length(value).unwrap_or(style.stroke_width)
+                }
+                "opacity" => style.opacity = parse_length(value).unwrap_or(1.0),
+                "fill-opacity" => style.fill_opacity = parse_length(value).unwrap_or(1.0),
+                "stroke-opacity" => style.stroke_opacity = parse_length(value).unwrap_or(1.0),
+                "transform" => style.transform = self.transform.multiply(Transform::parse(value)),
+                _ => {}
+            }
+        }
+
+        style.opacity *= self.opacity;
+        style
+    }
+
+    fn vector_path(&self, commands: Vec<PathCommand>) -> VectorPath {
+        VectorPath {
+            commands,
+            fill: self
+                .fill
+                .map(|color| with_opacity(color, self.opacity * self.fill_opacity)),
+            stroke: self
+                .stroke
+                .map(|color| with_opacity(color, self.opacity * self.stroke_opacity)),
+            stroke_width: self.stroke_width * self.transform.scale(),
+        }
+    }
+}
+
+// A start tag or an end tag of a xml document.
+#[derive(Clone, Debug, PartialEq)]
+enum Tag<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        empty: bool,
+    },
+    End(&'a str),
+}
+
+// Splits a xml document into tags. Text, comments, processing instructions and declarations are skipped.
+fn parse_tags(xml: &str) -> Result<Vec<Tag<'_>>, String> {
+    let mut tags = vec![];
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+
+        let skip_to = if rest.starts_with("<!--") {
+            Some("-->")
+        } else if rest.starts_with("<![CDATA[") {
+            Some("]]>")
+        } else if rest.starts_with("<?") {
+            Some("?>")
+        } else if rest.starts_with("<!") {
+            Some(">")
+        } else {
+            None
+        };
+
+        if let Some(end) = skip_to {
+            let index = rest
+                .find(end)
+                .ok_or_else(|| String::from("SVG: unclosed declaration"))?;
+            rest = &rest[index + end.len()..];
+            continue;
+        }
+
+        let end = rest
+            .find('>')
+            .ok_or_else(|| String::from("SVG: unclosed tag"))?;
+        let content = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = content.strip_prefix('/') {
+            tags.push(Tag::End(name.trim()));
+            continue;
+        }
+
+        let empty = content.ends_with('/');
+        let content = content.trim_end_matches('/');
+        let name_end = content
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(content.len());
+
+        tags.push(Tag::Start {
+            name: &content[..name_end],
+            attributes: parse_attributes(&content[name_end..])?,
+            empty,
+        });
+    }
+
+    Ok(tags)
+}
+
+fn parse_attributes(text: &str) -> Result<Vec<(&str, String)>, String> {
+    let mut attributes = vec![];
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() {
+        let equal = rest
+            .find('=')
+            .ok_or_else(|| format!("SVG: attribute without value: {}", rest))?;
+        let key = rest[..equal].trim();
+        rest = rest[equal + 1..].trim_start();
+
+        let quote = rest
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+            .ok_or_else(|| format!("SVG: attribute {} without quotes", key))?;
+        let end = rest[1..]
+            .find(quote)
+            .ok_or_else(|| format!("SVG: unclosed attribute {}", key))?;
+
+        attributes.push((key, unescape(&rest[1..end + 1])));
+        rest = rest[end + 2..].trim_start();
+    }
+
+    Ok(attributes)
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[derive(Default)]
+struct SvgParser {
+    image: VectorImage,
+    styles: Vec<SvgStyle>,
+    skip_depth: usize,
+}
+
+impl SvgParser {
+    fn parse(mut self, svg: &str) -> Result<VectorImage, String> {
+        let mut has_root = false;
+
+        for tag in parse_tags(svg)? {
+            match tag {
+                Tag::Start {
+                    name,
+                    attributes,
+                    empty,
+                } => {
+                    if self.skip_depth > 0 || SKIPPED_ELEMENTS.contains(&name) {
+                        if !empty {
+                            self.skip_depth += 1;
+                        }
+                        continue;
+                    }
+
+                    if name == "svg" && !has_root {
+                        has_root = true;
+                        self.read_size(&attributes);
+                    }
+
+                    let style = self
+                        .styles
+                        .last()
+                        .copied()
+                        .unwrap_or_default()
+                        .child(&attributes);
+
+                    if let Some(commands) = shape_commands(name, &attributes, style.transform) {
+                        if !commands.is_empty() {
+                            self.image.paths.push(style.vector_path(commands));
+                        }
+                    }
+
+                    if !empty {
+                        self.styles.push(style);
+                    }
+                }
+                Tag::End(_) => {
+                    if self.skip_depth > 0 {
+                        self.skip_depth -= 1;
+                    } else {
+                        self.styles.pop();
+                    }
+                }
+            }
+        }
+
+        if !has_root {
+            return Err(String::from("SVG: svg element not found"));
+        }
+
+        Ok(self.image)
+    }
+
+    // Reads the size and the view box of the root element.
+    fn read_size(&mut self, attributes: &[(&str, String)]) {
+        let width = attribute(attributes, "width").and_then(parse_length);
+        let height = attribute(attributes, "height").and_then(parse_length);
+        let view_box = attribute(attributes, "viewBox")
+            .map(|view_box| Numbers::new(view_box).collect::<Vec<f64>>())
+            .filter(|values| values.len() == 4 && values[2] > 0.0 && values[3] > 0.0);
+
+        let (width, height) = match (width, height, &view_box) {
+            (Some(width), Some(height), _) => (width, height),
+            (Some(width), None, Some(values)) => (width, width * values[3] / values[2]),
+            (None, Some(height), Some(values)) => (height * values[2] / values[3], height),
+            (_, _, Some(values)) => (values[2], values[3]),
+            (width, height, None) => (width.unwrap_or(100.0), height.unwrap_or(100.0)),
+        };
+
+        self.image.width = width;
+        self.image.height = height;
+        self.image.view_box = match view_box {
+            Some(values) => Rectangle::new((values[0], values[1]), (values[2], values[3])),
+            None => Rectangle::new((0.0, 0.0), (width, height)),
+        };
+    }
+}
+
+fn attribute<'a>(attributes: &'a [(&str, String)], key: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, value)| value.as_str())
+}
+
+// Parses a number with an optional `px` unit. Other units and percentages are not supported.
+fn parse_length(text: &str) -> Option<f64> {
+    text.trim()
+        .trim_end_matches("px")
+        .trim()
+        .parse::<f64>()
+        .ok()
+}
+
+fn with_opacity(color: Color, opacity: f64) -> Color {
+    let alpha = (f64::from(color.a()) * opacity.clamp(0.0, 1.0)).round() as u8;
+    Color::rgba(color.r(), color.g(), color.b(), alpha)
+}
+
+// Parses a SVG color, `None` for `none`.
+fn parse_color(text: &str) -> Option<Color> {
+    let text = text.trim();
+
+    if let Some(hex) = text.strip_prefix('#') {
+        let hex = if hex.len() == 3 {
+            hex.chars().flat_map(|c| vec![c, c]).collect::<String>()
+        } else {
+            hex.to_string()
+        };
+
+        return u32::from_str_radix(&hex, 16)
+            .ok()
+            .filter(|_| hex.len() == 6)
+            .map(|rgb| Color::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+    }
+
+    if let Some(values) = text
+        .strip_prefix("rgb(")
+        .and_then(|text| text.strip_suffix(')'))
+    {
+        let values: Vec<u8> = values
+            .split(',')
+            .map(|value| {
+                let value = value.trim();
+                match value.strip_suffix('%') {
+                    Some(percent) => percent.parse::<f64>().unwrap_or_default() * 2.55,
+                    None => value.parse::<f64>().unwrap_or_default(),
+                }
+                .round()
+                .clamp(0.0, 255.0) as u8
+            })
+            .collect();
+
+        if values.len() == 3 {
+            return Some(Color::rgb(values[0], values[1], values[2]));
+        }
+
+        return None;
+    }
+
+    match text {
+        "none" | "transparent" => None,
+        "white" => Some(Color::rgb(255, 255, 255)),
+        "red" => Some(Color::rgb(255, 0, 0)),
+        "green" => Some(Color::rgb(0, 128, 0)),
+        "lime" => Some(Color::rgb(0, 255, 0)),
+        "blue" => Some(Color::rgb(0, 0, 255)),
+        "yellow" => Some(Color::rgb(255, 255, 0)),
+        "orange" => Some(Color::rgb(255, 165, 0)),
+        "gray" | "grey" => Some(Color::rgb(128, 128, 128)),
+        "silver" => Some(Color::rgb(192, 192, 192)),
+        // black, currentColor and unknown colors
+        _ => Some(Color::rgb(0, 0, 0)),
+    }
+}
+
+// Reads the numbers of a SVG number list or path data, e.g. `10-5.5.5e2`.
+struct Numbers<'a> {
+    text: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Numbers<'a> {
+    fn new(text: &'a str) -> Self {
+        Numbers {
+            text: text.as_bytes(),
+            index: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.index < self.text.len()
+            && (self.text[self.index].is_ascii_whitespace() || self.text[self.index] == b',')
+        {
+            self.index += 1;
+        }
+    }
+
+    // Returns the next byte after the separators without consuming it.
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.text.get(self.index).copied()
+    }
+
+    // Reads an arc flag, that could be written without separator (`a1 1 0 011 1`).
+    fn flag(&mut self) -> Option<bool> {
+        match self.peek()? {
+            b'0' => {
+                self.index += 1;
+                Some(false)
+            }
+            b'1' => {
+                self.index += 1;
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Iterator for Numbers<'a> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        self.skip_separators();
+
+        let start = self.index;
+        let mut end = start;
+        let mut has_point = false;
+        let mut has_exponent = false;
+
+        while end < self.text.len() {
+            match self.text[end] {
+                b'+' | b'-'
+                    if end == start
+                        || (has_exponent && matches!(self.text[end - 1], b'e' | b'E')) => {}
+                b'0'..=b'9' => {}
+                b'.' if !has_point && !has_exponent => has_point = true,
+                b'e' | b'E' if !has_exponent && end > start => has_exponent = true,
+                _ => break,
+            }
+            end += 1;
+        }
+
+        let number = std::str::from_utf8(&self.text[start..end])
+            .ok()?
+            .parse::<f64>()
+            .ok()?;
+        self.index = end;
+
+        Some(number)
+    }
+}
+
+// Creates the path commands of a shape element, `None` if the element is not a shape.
+fn shape_commands(
+    name: &str,
+    attributes: &[(&str, String)],
+    transform: Transform,
+) -> Option<Vec<PathCommand>> {
+    let number = |key: &str| {
+        attribute(attributes, key)
+            .and_then(parse_length)
+            .unwrap_or_default()
+    };
+
+    let mut builder = PathBuilder::new(transform);
+
+    match name {
+        "path" => builder.path_data(attribute(attributes, "d").unwrap_or_default()),
+        "rect" => {
+            let (x, y, width, height) =
+                (number("x"), number("y"), number("width"), number("height"));
+
+            if width > 0.0 && height > 0.0 {
+                let rx = attribute(attributes, "rx").and_then(parse_length);
+                let ry = attribute(attributes, "ry").and_then(parse_length);
+                let rx = rx.or(ry).unwrap_or_default().min(width / 2.0);
+                let ry = ry.or(Some(rx)).unwrap_or_default().min(height / 2.0);
+                builder.rect(x, y, width, height, rx, ry);
+            }
+        }
+        "circle" => {
+            let r = number("r");
+            builder.ellipse(number("cx"), number("cy"), r, r);
+        }
+        "ellipse" => builder.ellipse(number("cx"), number("cy"), number("rx"), number("ry")),
+        "line" => {
+            builder.move_to(number("x1"), number("y1"));
+            builder.line_to(number("x2"), number("y2"));
+        }
+        "polyline" | "polygon" => {
+            let points = Numbers::new(attribute(attributes, "points").unwrap_or_default())
+                .collect::<Vec<f64>>();
+
+            for (i, point) in points.chunks_exact(2).enumerate() {
+                if i == 0 {
+                    builder.move_to(point[0], point[1]);
+                } else {
+                    builder.line_to(point[0], point[1]);
+                }
+            }
+
+            if name == "polygon" && points.len() >= 4 {
+                builder.close();
+            }
+        }
+        _ => return None,
+    }
+
+    Some(builder.commands)
+}
+
+// Builds transformed path commands from SVG coordinates.
+struct PathBuilder {
+    transform: Transform,
+    commands: Vec<PathCommand>,
+    current: (f64, f64),
+    start: (f64, f64),
+}
+
+impl PathBuilder {
+    fn new(transform: Transform) -> Self {
+        PathBuilder {
+            transform,
+            commands: vec![],
+            current: (0.0, 0.0),
+            start: (0.0, 0.0),
+        }
+    }
+
+    fn point(&self, point: (f64, f64)) -> Point {
+        self.transform.apply(point.0, point.1)
+    }
+
+    fn move_to(&mut self, x: f64, y: f64) {
+        self.current = (x, y);
+        self.start = (x, y);
+        self.commands.push(PathCommand::MoveTo(self.point((x, y))));
+    }
+
+    fn line_to(&mut self, x: f64, y: f64) {
+        self.current = (x, y);
+        self.commands.push(PathCommand::LineTo(self.point((x, y))));
+    }
+
+    fn quadratic_curve_to(&mut self, control: (f64, f64), x: f64, y: f64) {
+        self.current = (x, y);
+        self.commands.push(PathCommand::QuadraticCurveTo(
+            self.point(control),
+            self.point((x, y)),
+        ));
+    }
+
+    fn bezier_curve_to(&mut self, control_1: (f64, f64), control_2: (f64, f64), x: f64, y: f64) {
+        self.current = (x, y);
+        self.commands.push(PathCommand::BezierCurveTo(
+            self.point(control_1),
+            self.point(control_2),
+            self.point((x, y)),
+        ));
+    }
+
+    fn close(&mut self) {
+        self.current = self.start;
+        self.commands.push(PathCommand::ClosePath);
+    }
+
+    // Draws an elliptical arc from the current point to the given point with cubic curves.
+    #[allow(clippy::too_many_arguments)]
+    fn arc_to(
+        &mut self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) {
+        let (x1, y1) = self.current;
+
+        if (x1 - x).abs() < f64::EPSILON && (y1 - y).abs() < f64::EPSILON {
+            return;
+        }
+
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+
+        if rx < f64::EPSILON || ry < f64::EPSILON {
+            self.line_to(x, y);
+            return;
+        }
+
+        // conversion from endpoint to center parameterization (SVG spec F.6.5)
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let dx = (x1 - x) / 2.0;
+        let dy = (y1 - y) / 2.0;
+        let x1p = cos * dx + sin * dy;
+        let y1p = -sin * dx + cos * dy;
+
+        let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+        let denominator = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+        let mut factor = (numerator / denominator).max(0.0).sqrt();
+
+        if large_arc == sweep {
+            factor = -factor;
+        }
+
+        let cxp = factor * rx * y1p / ry;
+        let cyp = -factor * ry * x1p / rx;
+        let cx = cos * cxp - sin * cyp + (x1 + x) / 2.0;
+        let cy = sin * cxp + cos * cyp + (y1 + y) / 2.0;
+
+        let angle = |ux: f64, uy: f64, vx: f64, vy: f64| {
+            let sign = if ux * vy - uy * vx < 0.0 { -1.0 } else { 1.0 };
+            let dot =
+                (ux * vx + uy * vy) / ((ux * ux + uy * uy).sqrt() * (vx * vx + vy * vy).sqrt());
+            sign * dot.clamp(-1.0, 1.0).acos()
+        };
+
+        let start_angle = angle(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+        let mut sweep_angle = angle(
+            (x1p - cxp) / rx,
+            (y1p - cyp) / ry,
+            (-x1p - cxp) / rx,
+            (-y1p - cyp) / ry,
+        );
+
+        if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * PI;
+        } else if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * PI;
+        }
+
+        // each segment covers at most a quarter of the ellipse
+        let segments = (sweep_angle.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let delta = sweep_angle / segments as f64;
+        let k = 4.0 / 3.0 * (delta / 4.0).tan();
+
+        let ellipse_point = |theta: f64| {
+            let (sin_t, cos_t) = theta.sin_cos();
+            (
+                cx + rx * cos_t * cos - ry * sin_t * sin,
+                cy + rx * cos_t * sin + ry * sin_t * cos,
+            )
+        };
+        let derivative = |theta: f64| {
+            let (sin_t, cos_t) = theta.sin_cos();
+            (
+                -rx * sin_t * cos - ry * cos_t * sin,
+                -rx * sin_t * sin + ry * cos_t * cos,
+            )
+        };
+
+        let mut theta = start_angle;
+
+        for i in 0..segments {
+            let next_theta = theta + delta;
+            let from = ellipse_point(theta);
+            let d1 = derivative(theta);
+            let d2 = derivative(next_theta);
+            let to = if i + 1 == segments {
+                (x, y)
+            } else {
+                ellipse_point(next_theta)
+            };
+
+            self.bezier_curve_to(
+                (from.0 + k * d1.0, from.1 + k * d1.1),
+                (to.0 - k * d2.0, to.1 - k * d2.1),
+                to.0,
+                to.1,
+            );
+
+            theta = next_theta;
+        }
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64) {
+        if rx <= 0.0 || ry <= 0.0 {
+            return;
+        }
+
+        self.move_to(cx + rx, cy);
+        self.arc_to(rx, ry, 0.0, false, true, cx, cy + ry);
+        self.arc_to(rx, ry, 0.0, false, true, cx - rx, cy);
+        self.arc_to(rx, ry, 0.0, false, true, cx, cy - ry);
+        self.arc_to(rx, ry, 0.0, false, true, cx + rx, cy);
+        self.close();
+    }
+
+    fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, rx: f64, ry: f64) {
+        if rx <= 0.0 || ry <= 0.0 {
+            self.move_to(x, y);
+            self.line_to(x + width, y);
+            self.line_to(x + width, y + height);
+            self.line_to(x, y + height);
+            self.close();
+            return;
+        }
+
+        self.move_to(x + rx, y);
+        self.line_to(x + width - rx, y);
+        self.arc_to(rx, ry, 0.0, false, true, x + width, y + ry);
+        self.line_to(x + width, y + height - ry);
+        self.arc_to(rx, ry, 0.0, false, true, x + width - rx, y + height);
+        self.line_to(x + rx, y + height);
+        self.arc_to(rx, ry, 0.0, false, true, x, y + height - ry);
+        self.line_to(x, y + ry);
+        self.arc_to(rx, ry, 0.0, false, true, x + rx, y);
+        self.close();
+    }
+
+    // Parses SVG path data, e.g. `M10 10 h 20 v 20 z`.
+    fn path_data(&mut self, data: &str) {
+        let mut numbers = Numbers::new(data);
+        let mut command = b'M';
+        // the last control point for the smooth curve commands
+        let mut last_control: Option<(u8, (f64, f64))> = None;
+
+        while let Some(next) = numbers.peek() {
+            if next.is_ascii_alphabetic() {
+                command = next;
+                numbers.index += 1;
+            }
+
+            let relative = command.is_ascii_lowercase();
+            let (cx, cy) = self.current;
+            let offset = |x: f64, y: f64| {
+                if relative {
+                    (cx + x, cy + y)
+                } else {
+                    (x, y)
+                }
+            };
+
+            let mut control = None;
+
+            let ok = match command.to_ascii_uppercase() {
+                b'Z' => {
+                    self.close();
+                    // a close path is not repeated
+                    command = b' ';
+                    Some(())
+                }
+                b'M' => (|| {
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.move_to(x, y);
+                    // following coordinates are line commands
+                    command = if relative { b'l' } else { b'L' };
+                    Some(())
+                })(),
+                b'L' => (|| {
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.line_to(x, y);
+                    Some(())
+                })(),
+                b'H' => (|| {
+                    let x = numbers.next()?;
+                    self.line_to(if relative { cx + x } else { x }, cy);
+                    Some(())
+                })(),
+                b'V' => (|| {
+                    let y = numbers.next()?;
+                    self.line_to(cx, if relative { cy + y } else { y });
+                    Some(())
+                })(),
+                b'C' => (|| {
+                    let control_1 = offset(numbers.next()?, numbers.next()?);
+                    let control_2 = offset(numbers.next()?, numbers.next()?);
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.bezier_curve_to(control_1, control_2, x, y);
+                    control = Some((b'C', control_2));
+                    Some(())
+                })(),
+                b'S' => (|| {
+                    let control_1 = reflect(last_control, b'C', (cx, cy));
+                    let control_2 = offset(numbers.next()?, numbers.next()?);
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.bezier_curve_to(control_1, control_2, x, y);
+                    control = Some((b'C', control_2));
+                    Some(())
+                })(),
+                b'Q' => (|| {
+                    let control_1 = offset(numbers.next()?, numbers.next()?);
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.quadratic_curve_to(control_1, x, y);
+                    control = Some((b'Q', control_1));
+                    Some(())
+                })(),
+                b'T' => (|| {
+                    let control_1 = reflect(last_control, b'Q', (cx, cy));
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.quadratic_curve_to(control_1, x, y);
+                    control = Some((b'Q', control_1));
+                    Some(())
+                })(),
+                b'A' => (|| {
+                    let rx = numbers.next()?;
+                    let ry = numbers.next()?;
+                    let rotation = numbers.next()?;
+                    let large_arc = numbers.flag()?;
+                    let sweep = numbers.flag()?;
+                    let (x, y) = offset(numbers.next()?, numbers.next()?);
+                    self.arc_to(rx, ry, rotation, large_arc, sweep, x, y);
+                    Some(())
+                })(),
+                _ => None,
+            };
+
+            // stops at invalid path data like browsers do
+            if ok.is_none() {
+                break;
+            }
+
+            last_control = control;
+        }
+    }
+}
+
+// Returns the reflection of the last control point of the same curve type, otherwise the current point.
+fn reflect(last_control: Option<(u8, (f64, f64))>, kind: u8, current: (f64, f64)) -> (f64, f64) {
+    match last_control {
+        Some((last_kind, control)) if last_kind == kind => {
+            (2.0 * current.0 - control.0, 2.0 * current.1 - control.1)
+        }
+        _ => current,
+    }
+}
+
+// --- SVG parser ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Point, b: (f64, f64)) -> bool {
+        (a.x() - b.0).abs() < 0.0001 && (a.y() - b.1).abs() < 0.0001
+    }
+
+    #[test]
+    fn test_numbers() {
+        let numbers = Numbers::new("10-5.5.5e2,1E-1 -.5").collect::<Vec<f64>>();
+        assert_eq!(numbers, vec![10.0, -5.5, 50.0, 0.1, -0.5]);
+
+        let mut numbers = Numbers::new("011 1");
+        assert_eq!(numbers.flag(), Some(false));
+        assert_eq!(numbers.flag(), Some(true));
+        assert_eq!(numbers.next(), Some(1.0));
+        assert_eq!(numbers.next(), Some(1.0));
+        assert_eq!(numbers.next(), None);
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#ff0000"), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(parse_color("#0f0"), Some(Color::rgb(0, 255, 0)));
+        assert_eq!(parse_color("rgb(0, 0, 255)"), Some(Color::rgb(0, 0, 255)));
+        assert_eq!(parse_color("white"), Some(Color::rgb(255, 255, 255)));
+        assert_eq!(parse_color("none"), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let transform = Transform::parse("translate(10, 20) scale(2)");
+        assert!(close(transform.apply(1.0, 1.0), (12.0, 22.0)));
+
+        let transform = Transform::parse("rotate(90)");
+        assert!(close(transform.apply(1.0, 0.0), (0.0, 1.0)));
+
+        let transform = Transform::parse("rotate(180 5 5)");
+        assert!(close(transform.apply(0.0, 0.0), (10.0, 10.0)));
+
+        let transform = Transform::parse("matrix(1 0 0 1 3 4)");
+        assert!(close(transform.apply(0.0, 0.0), (3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_path_data() {
+        let mut builder = PathBuilder::new(Transform::default());
+        builder.path_data("M10 10 h 20 v 20 H 10 z m 5 5 l 1 1 2 2");
+
+        assert_eq!(
+            builder.commands,
+            vec![
+                PathCommand::MoveTo(Point::new(10.0, 10.0)),
+                PathCommand::LineTo(Point::new(30.0, 10.0)),
+                PathCommand::LineTo(Point::new(30.0, 30.0)),
+                PathCommand::LineTo(Point::new(10.0, 30.0)),
+                PathCommand::ClosePath,
+                PathCommand::MoveTo(Point::new(15.0, 15.0)),
+                PathCommand::LineTo(Point::new(16.0, 16.0)),
+                PathCommand::LineTo(Point::new(18.0, 18.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_data_curves() {
+        let mut builder = PathBuilder::new(Transform::default());
+        builder.path_data("M0 0 C 0 10 10 10 10 0 S 20 -10 20 0 Q 25 5 30 0 T 40 0");
+
+        assert_eq!(
+            builder.commands[2],
+            PathCommand::BezierCurveTo(
+                Point::new(10.0, -10.0),
+                Point::new(20.0, -10.0),
+                Point::new(20.0, 0.0)
+            )
+        );
+        assert_eq!(
+            builder.commands[4],
+            PathCommand::QuadraticCurveTo(Point::new(35.0, -5.0), Point::new(40.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_arc() {
+        let mut builder = PathBuilder::new(Transform::default());
+        builder.path_data("M 0 10 A 10 10 0 0 1 10 0");
+
+        // a quarter circle is drawn with one curve that ends at the end point
+        assert_eq!(builder.commands.len(), 2);
+        match builder.commands[1] {
+            PathCommand::BezierCurveTo(_, _, point) => assert!(close(point, (10.0, 0.0))),
+            _ => panic!("arc is not converted to a curve"),
+        }
+
+        let mut builder = PathBuilder::new(Transform::default());
+        builder.path_data("M 0 0 a 5 5 0 1 0 10 0");
+        assert_eq!(builder.commands.len(), 3);
+    }
+
+    #[test]
+    fn test_from_svg() {
+        let image = VectorImage::from_svg(
+            r##"<?xml version="1.0"?>
+            <!-- icon -->
+            <svg xmlns="http://www.w3.org/2000/svg" width="24" viewBox="0 0 48 48">
+                <defs><path d="M0 0 L 1 1"/></defs>
+                <g fill="#ff0000" transform="translate(10 0)">
+                    <rect x="0" y="0" width="10" height="10" style="stroke: blue; stroke-width: 2"/>
+                    <circle cx="5" cy="5" r="5" fill="none" stroke="#000"/>
+                </g>
+                <path d="M 0 0 L 10 10" opacity="0.5"/>
+            </svg>"##,
+        )
+        .unwrap();
+
+        assert!((image.width() - 24.0).abs() < f64::EPSILON);
+        assert!((image.height() - 24.0).abs() < f64::EPSILON);
+        assert_eq!(image.view_box(), Rectangle::new((0.0, 0.0), (48.0, 48.0)));
+        assert_eq!(image.paths().len(), 3);
+
+        let rect = &image.paths()[0];
+        assert_eq!(rect.fill, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(rect.stroke, Some(Color::rgb(0, 0, 255)));
+        assert!((rect.stroke_width - 2.0).abs() < f64::EPSILON);
+        assert_eq!(rect.commands[0], PathCommand::MoveTo(Point::new(10.0, 0.0)));
+
+        let circle = &image.paths()[1];
+        assert_eq!(circle.fill, None);
+        assert_eq!(circle.stroke, Some(Color::rgb(0, 0, 0)));
+
+        let path = &image.paths()[2];
+        assert_eq!(path.fill.map(|fill| fill.a()), Some(128));
+
+        assert!(VectorImage::from_svg("<html></html>").is_err());
+    }
+}
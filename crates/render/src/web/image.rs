@@ -104,3 +104,69 @@ impl From<(u32, u32, Vec<u32>)> for Image {
         Image::new(image.0.into(), image.1.into())
     }
 }
+
+/// A frame of an `AnimatedImage`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ImageFrame {
+    /// The image of the frame.
+    pub image: Image,
+
+    /// The time in milliseconds the frame is shown.
+    pub delay: f64,
+}
+
+/// An image with a sequence of frames. On web the browser draws the first frame of an animated image.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct AnimatedImage {
+    frames: Vec<ImageFrame>,
+}
+
+impl AnimatedImage {
+    /// Creates a new animated image from the given frames.
+    pub fn new(frames: Vec<ImageFrame>) -> Self {
+        AnimatedImage { frames }
+    }
+
+    /// Loads the image as animation with one frame.
+    pub fn from_path<P: std::string::ToString + AsRef<Path>>(path: P) -> Result<Self, String> {
+        Ok(AnimatedImage {
+            frames: vec![ImageFrame {
+                image: Image::from_path(path)?,
+                delay: 0.0,
+            }],
+        })
+    }
+
+    /// Gets the frames of the animation.
+    pub fn frames(&self) -> &[ImageFrame] {
+        &self.frames
+    }
+
+    /// Gets the count of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the animation has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl From<&str> for AnimatedImage {
+    fn from(s: &str) -> AnimatedImage {
+        AnimatedImage::from_path(s).unwrap()
+    }
+}
+
+impl From<String> for AnimatedImage {
+    fn from(s: String) -> AnimatedImage {
+        AnimatedImage::from_path(s).unwrap()
+    }
+}
+
+impl From<Vec<ImageFrame>> for AnimatedImage {
+    fn from(frames: Vec<ImageFrame>) -> AnimatedImage {
+        AnimatedImage::new(frames)
+    }
+}
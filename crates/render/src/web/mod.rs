@@ -1,11 +1,19 @@
+use std::collections::HashSet;
+
 use stdweb::{
     js,
     unstable::TryInto,
-    web::{document, html_element::CanvasElement, CanvasRenderingContext2d, FillRule},
+    web::{
+        document, html_element::CanvasElement, CanvasGradient, CanvasRenderingContext2d, FillRule,
+        TypedArray,
+    },
 };
 
 // pub use crate::image::Image as InnerImage;
-use crate::{utils::*, FontConfig, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    utils::*, FontConfig, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+    DEFAULT_SCALE_FACTOR,
+};
 
 pub use self::image::*;
 
@@ -16,9 +24,30 @@ pub struct RenderContext2D {
     canvas_render_context_2_d: CanvasRenderingContext2d,
     font_config: FontConfig,
     config: RenderConfig,
-    saved_config: Option<RenderConfig>,
+    saved_configs: Vec<RenderConfig>,
     export_data: Vec<u32>,
     background: Color,
+
+    // the bounds of the current path, gradients that adapt to the shape are placed inside of them
+    path_bounds: Option<Rectangle>,
+
+    // the size of the canvas in the last frame, resizing the canvas clears it
+    size: (u32, u32),
+
+    // the device pixel ratio the canvas is scaled with by the shell
+    scale_factor: f64,
+
+    // the next frame has to redraw the whole canvas, e.g. after the background has changed
+    redraw: bool,
+
+    // the current frame redraws only a region of the last frame
+    region: bool,
+
+    // the font families that are added to the document
+    fonts: HashSet<String>,
+
+    // registered fonts are loaded by the browser
+    fonts_loading: bool,
 }
 
 impl RenderContext2D {
@@ -39,16 +68,40 @@ impl RenderContext2D {
         ctx.set_text_baseline(stdweb::web::TextBaseline::Middle);
         RenderContext2D {
             config: RenderConfig::default(),
-            saved_config: None,
+            saved_configs: vec![],
             canvas_render_context_2_d: ctx,
             font_config: FontConfig::default(),
             export_data,
             background: Color::default(),
+            path_bounds: None,
+            size: (0, 0),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            redraw: true,
+            region: false,
+            fonts: HashSet::new(),
+            fonts_loading: false,
+        }
+    }
+
+    /// Gets the scale factor of the monitor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the scale factor of the monitor, the device pixel ratio the canvas is scaled with.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
+            self.redraw = true;
         }
     }
 
     /// Set the background of the render context.
     pub fn set_background(&mut self, background: Color) {
+        if self.background != background {
+            self.redraw = true;
+        }
+
         self.background = background;
     }
 
@@ -63,36 +116,139 @@ impl RenderContext2D {
         canvas_render_context_2_d.set_text_baseline(stdweb::web::TextBaseline::Middle);
         RenderContext2D {
             config: RenderConfig::default(),
-            saved_config: None,
+            saved_configs: vec![],
             canvas_render_context_2_d,
             font_config: FontConfig::default(),
             export_data,
             background: Color::default(),
+            path_bounds: None,
+            size: (0, 0),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            redraw: true,
+            region: false,
+            fonts: HashSet::new(),
+            fonts_loading: false,
         }
     }
 
+    /// Registers a new font file. The browser loads the font in the background, text is drawn with a fallback font
+    /// until it is loaded.
+    pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
+        if !self.fonts.insert(family.to_string()) {
+            return;
+        }
+
+        let data = TypedArray::<u8>::from(font_file);
+
+        js! {
+            var font = new FontFace(@{family}, @{data});
+            document.fonts.add(font);
+            font.load();
+        }
+
+        self.fonts_loading = true;
+    }
+
+    /// Checks if the registered fonts were loaded since the last call. The whole canvas is redrawn in the next frame
+    /// then.
+    pub fn fonts_loaded(&mut self) -> bool {
+        if !self.fonts_loading {
+            return false;
+        }
+
+        let loading: bool = js! {
+            return document.fonts.status === "loading";
+        }
+        .try_into()
+        .unwrap_or(false);
+
+        if loading {
+            return false;
+        }
+
+        self.fonts_loading = false;
+        self.redraw = true;
+
+        true
+    }
+
     // Rectangles
 
     /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the
     /// specified width and height and whose style is determined by the fillStyle attribute.
     pub fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        self.fill_style(&self.config.fill_style);
+        self.fill_style(
+            &self.config.fill_style,
+            Rectangle::new((x, y), (width, height)),
+        );
         self.canvas_render_context_2_d
             .fill_rect(x, y, width, height);
     }
 
     /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
     pub fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        self.stroke_style(&self.config.fill_style);
+        self.stroke_style(
+            &self.config.fill_style,
+            Rectangle::new((x, y), (width, height)),
+        );
         self.canvas_render_context_2_d
             .stroke_rect(x, y, width, height);
     }
 
+    /// Draws the soft shadow of a rectangle with rounded corners, call it before the rectangle is drawn. The shadow
+    /// fades out over the blur radius around the rectangle.
+    pub fn fill_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    ) {
+        let color = format!(
+            "rgba({}, {}, {}, {})",
+            color.r(),
+            color.g(),
+            color.b(),
+            color.a() as f64 / 255.0
+        );
+        let radius = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+        let (r, b) = (x + width, y + height);
+
+        self.canvas_render_context_2_d.save();
+        self.canvas_render_context_2_d.set_shadow_color(&color);
+        self.canvas_render_context_2_d.set_shadow_blur(blur);
+        self.canvas_render_context_2_d.set_fill_style_color(&color);
+        self.canvas_render_context_2_d.begin_path();
+        self.canvas_render_context_2_d.move_to(x + radius, y);
+        self.canvas_render_context_2_d.line_to(r - radius, y);
+        self.canvas_render_context_2_d
+            .quadratic_curve_to(r, y, r, y + radius);
+        self.canvas_render_context_2_d.line_to(r, b - radius);
+        self.canvas_render_context_2_d
+            .quadratic_curve_to(r, b, r - radius, b);
+        self.canvas_render_context_2_d.line_to(x + radius, b);
+        self.canvas_render_context_2_d
+            .quadratic_curve_to(x, b, x, b - radius);
+        self.canvas_render_context_2_d.line_to(x, y + radius);
+        self.canvas_render_context_2_d
+            .quadratic_curve_to(x, y, x + radius, y);
+        self.canvas_render_context_2_d.close_path();
+        self.canvas_render_context_2_d.fill(FillRule::default());
+        self.canvas_render_context_2_d.restore();
+    }
+
     // Text
 
     /// Draws (fills) a given text at the given (x, y) position.
     pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
-        self.fill_style(&self.config.fill_style);
+        let width = self.measure_text(text).width;
+        self.fill_style(
+            &self.config.fill_style,
+            Rectangle::new((x, y), (width, self.font_config.font_size)),
+        );
         self.canvas_render_context_2_d
             .set_text_baseline(stdweb::web::TextBaseline::Middle);
         self.canvas_render_context_2_d.fill_text(
@@ -128,18 +284,25 @@ impl RenderContext2D {
 
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
-        self.fill_style(&self.config.fill_style);
+        self.fill_style(
+            &self.config.fill_style,
+            self.path_bounds.unwrap_or_default(),
+        );
         self.canvas_render_context_2_d.fill(FillRule::default());
     }
 
     /// Strokes {outlines} the current or given path with the current stroke style.
     pub fn stroke(&mut self) {
-        self.stroke_style(&self.config.stroke_style);
+        self.stroke_style(
+            &self.config.stroke_style,
+            self.path_bounds.unwrap_or_default(),
+        );
         self.canvas_render_context_2_d.stroke();
     }
 
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
     pub fn begin_path(&mut self) {
+        self.path_bounds = None;
         self.canvas_render_context_2_d.begin_path();
     }
 
@@ -151,11 +314,15 @@ impl RenderContext2D {
 
     /// Adds a rectangle to the current path.
     pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.extend_path_bounds(x, y);
+        self.extend_path_bounds(x + width, y + height);
         self.canvas_render_context_2_d.rect(x, y, width, height);
     }
 
     /// Creates a circular arc centered at (x, y) with a radius of radius. The path starts at startAngle and ends at endAngle.
     pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.extend_path_bounds(x - radius, y - radius);
+        self.extend_path_bounds(x + radius, y + radius);
         self.canvas_render_context_2_d
             .arc(x, y, radius, start_angle, end_angle, false);
     }
@@ -163,16 +330,20 @@ impl RenderContext2D {
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
+        self.extend_path_bounds(x, y);
         self.canvas_render_context_2_d.move_to(x, y);
     }
 
     /// Adds a straight line to the current sub-path by connecting the sub-path's last point to the specified {x, y} coordinates.
     pub fn line_to(&mut self, x: f64, y: f64) {
+        self.extend_path_bounds(x, y);
         self.canvas_render_context_2_d.line_to(x, y);
     }
 
     /// Adds a quadratic Bézier curve to the current sub-path.
     pub fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        self.extend_path_bounds(cpx, cpy);
+        self.extend_path_bounds(x, y);
         self.canvas_render_context_2_d
             .quadratic_curve_to(cpx, cpy, x, y);
     }
@@ -181,6 +352,9 @@ impl RenderContext2D {
     /// It requires three points: the first two are control points and the third one is the end point.
     /// The starting point is the latest point in the current path, which can be changed using MoveTo{} before creating the Bézier curve.
     pub fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        self.extend_path_bounds(cp1x, cp1y);
+        self.extend_path_bounds(cp2x, cp2y);
+        self.extend_path_bounds(x, y);
         self.canvas_render_context_2_d
             .bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
     }
@@ -210,6 +384,24 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(&mut self, image: &Image, x: f64, y: f64, width: f64, height: f64) {
+        js!(
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                img = document.image_store.load_image(@{&image.source});
+                img.then(
+                    function(i) {
+                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&x}, @{&y}, @{&width}, @{&height});
+                    }
+                )
+            } else {
+                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y}, @{&width}, @{&height});
+            }
+        );
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
         js!(
@@ -228,6 +420,32 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        js!(
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                img = document.image_store.load_image(@{&image.source});
+                img.then(
+                    function(i) {
+                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&width}, @{&height});
+                    }
+                )
+            } else {
+                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&width}, @{&height});
+            }
+        );
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -343,8 +561,16 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        // the transform replaces the scaling of the canvas by the device pixel ratio
+        let scale_factor = self.scale_factor;
+
         self.canvas_render_context_2_d.set_transform(
-            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+            h_scaling * scale_factor,
+            h_skewing * scale_factor,
+            v_skewing * scale_factor,
+            v_scaling * scale_factor,
+            h_moving * scale_factor,
+            v_moving * scale_factor,
         );
     }
 
@@ -352,7 +578,7 @@ impl RenderContext2D {
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
     pub fn save(&mut self) {
-        self.saved_config = Some(self.config.clone());
+        self.saved_configs.push(self.config.clone());
         self.canvas_render_context_2_d.save();
     }
 
@@ -360,11 +586,9 @@ impl RenderContext2D {
     /// If there is no saved state, this method does nothing.
     pub fn restore(&mut self) {
         self.canvas_render_context_2_d.restore();
-        if let Some(config) = &self.saved_config {
-            self.config = config.clone();
+        if let Some(config) = self.saved_configs.pop() {
+            self.config = config;
         }
-
-        self.saved_config = None;
     }
 
     pub fn clear(&mut self, brush: &Brush) {
@@ -391,6 +615,7 @@ impl RenderContext2D {
         canvas_render_context_2_d: CanvasRenderingContext2d,
     ) {
         self.canvas_render_context_2_d = canvas_render_context_2_d;
+        self.redraw = true;
     }
 
     pub fn data(&mut self) -> &[u32] {
@@ -451,60 +676,151 @@ impl RenderContext2D {
 
     pub fn start(&mut self) {
         let background = Brush::from(self.background);
-        self.clear(&background)
+        self.clear(&background);
+
+        let canvas = self.canvas_render_context_2_d.get_canvas();
+        self.size = (canvas.width(), canvas.height());
+        self.redraw = false;
     }
-    pub fn finish(&mut self) {}
 
-    fn fill_style<'a>(&self, brush: &Brush) {
+    /// Starts a new frame that redraws only the given region of the last frame. The region is cleared and all drawing
+    /// is clipped to it until `finish`. If the size or the background has changed since the last frame the whole
+    /// frame is redrawn.
+    pub fn start_region(&mut self, region: Rectangle) {
+        let canvas = self.canvas_render_context_2_d.get_canvas();
+
+        if self.redraw || self.size != (canvas.width(), canvas.height()) {
+            self.start();
+            return;
+        }
+
+        self.save();
+        self.begin_path();
+        self.rect(region.x(), region.y(), region.width(), region.height());
+        self.clip();
+        self.region = true;
+
+        self.canvas_render_context_2_d.clear_rect(
+            region.x(),
+            region.y(),
+            region.width(),
+            region.height(),
+        );
+        self.canvas_render_context_2_d
+            .set_fill_style_color(&self.background.to_string());
+        self.canvas_render_context_2_d.fill_rect(
+            region.x(),
+            region.y(),
+            region.width(),
+            region.height(),
+        );
+    }
+
+    pub fn finish(&mut self) {
+        if self.region {
+            self.region = false;
+            self.restore();
+        }
+    }
+
+    fn fill_style(&self, brush: &Brush, bounds: Rectangle) {
         match brush {
             Brush::SolidColor(color) => {
                 self.canvas_render_context_2_d
                     .set_fill_style_color(&color.to_string());
             }
-            Brush::LinearGradient { start, end, stops } => {
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                for stop in stops {
-                    web_gradient
-                        .add_color_stop(stop.position, stop.color.to_string().as_str())
-                        .unwrap();
+            _ => {
+                if let Some(gradient) = self.create_gradient(brush, bounds) {
+                    self.canvas_render_context_2_d
+                        .set_fill_style_gradient(&gradient);
                 }
-
-                self.canvas_render_context_2_d
-                    .set_fill_style_gradient(&web_gradient);
             }
         }
     }
 
-    fn stroke_style<'a>(&self, brush: &Brush) {
+    fn stroke_style(&self, brush: &Brush, bounds: Rectangle) {
         match brush {
             Brush::SolidColor(color) => {
                 self.canvas_render_context_2_d
                     .set_stroke_style_color(&color.to_string());
             }
-            Brush::LinearGradient { start, end, stops } => {
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
+            _ => {
+                if let Some(gradient) = self.create_gradient(brush, bounds) {
+                    self.canvas_render_context_2_d
+                        .set_stroke_style_gradient(&gradient);
+                }
+            }
+        }
+    }
+
+    // Creates the canvas gradient of the brush, gradients that adapt to the shape are placed inside of the bounds.
+    fn create_gradient(&self, brush: &Brush, bounds: Rectangle) -> Option<CanvasGradient> {
+        let (web_gradient, stops) = match brush {
+            Brush::SolidColor(_) => return None,
+            Brush::LinearGradient { start, end, stops } => (
+                self.canvas_render_context_2_d.create_linear_gradient(
                     start.x(),
                     start.y(),
                     end.x(),
                     end.y(),
-                );
-
-                for stop in stops {
-                    web_gradient
-                        .add_color_stop(stop.position, stop.color.to_string().as_str())
-                        .unwrap();
+                ),
+                stops,
+            ),
+            Brush::Gradient(gradient) => match gradient.kind {
+                GradientKind::Linear(angle) => {
+                    let (start, end) = Gradient::linear_coords(angle, bounds);
+
+                    (
+                        self.canvas_render_context_2_d.create_linear_gradient(
+                            start.x(),
+                            start.y(),
+                            end.x(),
+                            end.y(),
+                        ),
+                        &gradient.stops,
+                    )
+                }
+                GradientKind::Radial(center) => {
+                    let (center, radius) = Gradient::radial_coords(center, bounds);
+
+                    (
+                        self.canvas_render_context_2_d
+                            .create_radial_gradient(
+                                center.x(),
+                                center.y(),
+                                0.0,
+                                center.x(),
+                                center.y(),
+                                radius,
+                            )
+                            .ok()?,
+                        &gradient.stops,
+                    )
                 }
+            },
+        };
 
-                self.canvas_render_context_2_d
-                    .set_stroke_style_gradient(&web_gradient);
-            }
+        for stop in stops {
+            web_gradient
+                .add_color_stop(stop.position, stop.color.to_string().as_str())
+                .unwrap();
         }
+
+        Some(web_gradient)
+    }
+
+    fn extend_path_bounds(&mut self, x: f64, y: f64) {
+        self.path_bounds = Some(match self.path_bounds {
+            Some(bounds) => {
+                let left = bounds.x().min(x);
+                let top = bounds.y().min(y);
+                let right = (bounds.x() + bounds.width()).max(x);
+                let bottom = (bounds.y() + bounds.height()).max(y);
+
+                Rectangle::new((left, top), (right - left, bottom - top))
+            }
+            None => Rectangle::new((x, y), (0.0, 0.0)),
+        });
     }
 }
 
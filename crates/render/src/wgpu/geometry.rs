@@ -0,0 +1,410 @@
+use std::f64::consts::PI;
+
+use crate::utils::{Point, Rectangle, Transform};
+
+// The maximum distance in pixels between a curve and its flattened lines.
+const TOLERANCE: f64 = 0.25;
+
+// Strokes with a sharper corner than the miter limit are joined with a bevel.
+const MITER_LIMIT: f64 = 10.0;
+
+/// Describes one operation of a path like the path of a 2D canvas.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathOp {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadTo(Point, Point),
+    CubicTo(Point, Point, Point),
+    Close,
+}
+
+/// Describes a path that is flattened to lines.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+    pub closed: bool,
+}
+
+/// Triangles of a fill or stroke in pixels of the window.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Mesh {
+    pub points: Vec<Point>,
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    fn triangle(&mut self, a: Point, b: Point, c: Point) {
+        let index = self.points.len() as u32;
+        self.points.extend_from_slice(&[a, b, c]);
+        self.indices
+            .extend_from_slice(&[index, index + 1, index + 2]);
+    }
+
+    fn quad(&mut self, a: Point, b: Point, c: Point, d: Point) {
+        let index = self.points.len() as u32;
+        self.points.extend_from_slice(&[a, b, c, d]);
+        self.indices
+            .extend_from_slice(&[index, index + 1, index + 2, index, index + 2, index + 3]);
+    }
+
+    /// Gets the bounds of all triangles.
+    pub fn bounds(&self) -> Option<Rectangle> {
+        points_bounds(&self.points)
+    }
+}
+
+/// Appends the sub path of a circular arc to the path. The arc is connected with a line to the last point of the
+/// path. The angles are in radians and the arc runs clockwise on screen.
+pub fn arc(path: &mut Vec<PathOp>, center: Point, radius: f64, start_angle: f64, end_angle: f64) {
+    let point = |angle: f64| {
+        Point::new(
+            center.x() + radius * angle.cos(),
+            center.y() + radius * angle.sin(),
+        )
+    };
+
+    let start = point(start_angle);
+
+    match path.last() {
+        None | Some(PathOp::Close) => path.push(PathOp::MoveTo(start)),
+        _ => path.push(PathOp::LineTo(start)),
+    }
+
+    let sweep = (end_angle - start_angle).max(-2.0 * PI).min(2.0 * PI);
+    let segments = (sweep.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = sweep / segments as f64;
+
+    // the length of the control points of a cubic curve that approximates the segment
+    let k = 4.0 / 3.0 * (step / 4.0).tan() * radius;
+
+    for i in 0..segments {
+        let from = start_angle + step * i as f64;
+        let to = from + step;
+
+        path.push(PathOp::CubicTo(
+            Point::new(
+                center.x() + radius * from.cos() - k * from.sin(),
+                center.y() + radius * from.sin() + k * from.cos(),
+            ),
+            Point::new(
+                center.x() + radius * to.cos() + k * to.sin(),
+                center.y() + radius * to.sin() - k * to.cos(),
+            ),
+            point(to),
+        ));
+    }
+}
+
+/// Appends a closed rectangle to the path.
+pub fn rect(path: &mut Vec<PathOp>, rect: Rectangle) {
+    let (x, y) = (rect.x(), rect.y());
+    let (right, bottom) = (x + rect.width(), y + rect.height());
+
+    path.push(PathOp::MoveTo(Point::new(x, y)));
+    path.push(PathOp::LineTo(Point::new(right, y)));
+    path.push(PathOp::LineTo(Point::new(right, bottom)));
+    path.push(PathOp::LineTo(Point::new(x, bottom)));
+    path.push(PathOp::Close);
+}
+
+/// Appends a closed rectangle with rounded corners to the path.
+pub fn rounded_rect(path: &mut Vec<PathOp>, rect: Rectangle, radius: f64) {
+    let radius = radius.min(rect.width() / 2.0).min(rect.height() / 2.0);
+    let (x, y) = (rect.x(), rect.y());
+    let (r, b) = (x + rect.width(), y + rect.height());
+
+    path.push(PathOp::MoveTo(Point::new(x + radius, y)));
+    path.push(PathOp::LineTo(Point::new(r - radius, y)));
+    path.push(PathOp::QuadTo(Point::new(r, y), Point::new(r, y + radius)));
+    path.push(PathOp::LineTo(Point::new(r, b - radius)));
+    path.push(PathOp::QuadTo(Point::new(r, b), Point::new(r - radius, b)));
+    path.push(PathOp::LineTo(Point::new(x + radius, b)));
+    path.push(PathOp::QuadTo(Point::new(x, b), Point::new(x, b - radius)));
+    path.push(PathOp::LineTo(Point::new(x, y + radius)));
+    path.push(PathOp::QuadTo(Point::new(x, y), Point::new(x + radius, y)));
+    path.push(PathOp::Close);
+}
+
+/// Calculates the bounds of the path including the control points of the curves.
+pub fn path_bounds(path: &[PathOp]) -> Rectangle {
+    let points: Vec<Point> = path
+        .iter()
+        .flat_map(|op| match *op {
+            PathOp::MoveTo(p) | PathOp::LineTo(p) => vec![p],
+            PathOp::QuadTo(c, p) => vec![c, p],
+            PathOp::CubicTo(c1, c2, p) => vec![c1, c2, p],
+            PathOp::Close => vec![],
+        })
+        .collect();
+
+    points_bounds(&points).unwrap_or_default()
+}
+
+/// Transforms the path and flattens its curves to lines.
+pub fn flatten(path: &[PathOp], transform: Transform) -> Vec<Polyline> {
+    let mut polylines: Vec<Polyline> = vec![];
+    let mut current = Polyline::default();
+
+    let mut finish = |current: &mut Polyline, closed: bool| {
+        let start = current.points.first().copied();
+        let mut polyline = std::mem::take(current);
+        polyline.closed = closed;
+
+        if polyline.points.len() > 1 {
+            polylines.push(polyline);
+        }
+
+        // a path that continues after a close starts again at the start of the closed sub path
+        if closed {
+            current.points.extend(start);
+        }
+    };
+
+    for op in path {
+        let last = current.points.last().copied();
+
+        match *op {
+            PathOp::MoveTo(p) => {
+                finish(&mut current, false);
+                current.points = vec![transform.apply(p)];
+            }
+            PathOp::LineTo(p) => current.points.push(transform.apply(p)),
+            PathOp::QuadTo(c, p) => {
+                let (c, p) = (transform.apply(c), transform.apply(p));
+                let from = last.unwrap_or(c);
+                let segments = segments(&[from, c, p]);
+
+                for i in 1..=segments {
+                    let t = i as f64 / segments as f64;
+                    let mt = 1.0 - t;
+
+                    current.points.push(Point::new(
+                        mt * mt * from.x() + 2.0 * mt * t * c.x() + t * t * p.x(),
+                        mt * mt * from.y() + 2.0 * mt * t * c.y() + t * t * p.y(),
+                    ));
+                }
+            }
+            PathOp::CubicTo(c1, c2, p) => {
+                let (c1, c2, p) = (transform.apply(c1), transform.apply(c2), transform.apply(p));
+                let from = last.unwrap_or(c1);
+                let segments = segments(&[from, c1, c2, p]);
+
+                for i in 1..=segments {
+                    let t = i as f64 / segments as f64;
+                    let mt = 1.0 - t;
+
+                    current.points.push(Point::new(
+                        mt * mt * mt * from.x()
+                            + 3.0 * mt * mt * t * c1.x()
+                            + 3.0 * mt * t * t * c2.x()
+                            + t * t * t * p.x(),
+                        mt * mt * mt * from.y()
+                            + 3.0 * mt * mt * t * c1.y()
+                            + 3.0 * mt * t * t * c2.y()
+                            + t * t * t * p.y(),
+                    ));
+                }
+            }
+            PathOp::Close => finish(&mut current, true),
+        }
+    }
+
+    finish(&mut current, false);
+
+    polylines
+}
+
+/// Builds the triangles of the filled polylines. The triangles overlap, they are drawn into the stencil buffer
+/// with the nonzero winding rule before the shape is covered.
+pub fn fill(polylines: &[Polyline]) -> Mesh {
+    let mut mesh = Mesh::default();
+
+    for polyline in polylines {
+        let first = polyline.points[0];
+
+        for pair in polyline.points[1..].windows(2) {
+            mesh.triangle(first, pair[0], pair[1]);
+        }
+    }
+
+    mesh
+}
+
+/// Builds the triangles of the stroked polylines with the given line width in pixels. The lines are joined with
+/// miters and have butt caps like in a 2D canvas.
+pub fn stroke(polylines: &[Polyline], line_width: f64) -> Mesh {
+    let mut mesh = Mesh::default();
+    let half = line_width / 2.0;
+
+    if half <= 0.0 {
+        return mesh;
+    }
+
+    for polyline in polylines {
+        let mut points = polyline.points.clone();
+        points.dedup();
+
+        if polyline.closed && points.len() > 2 && points.first() == points.last() {
+            points.pop();
+        }
+
+        if points.len() < 2 {
+            continue;
+        }
+
+        let mut segments: Vec<(Point, Point)> = points.windows(2).map(|p| (p[0], p[1])).collect();
+
+        if polyline.closed {
+            segments.push((points[points.len() - 1], points[0]));
+        }
+
+        for &(from, to) in &segments {
+            let normal = scale(normal(from, to), half);
+            mesh.quad(from + normal, to + normal, to - normal, from - normal);
+        }
+
+        let joins = if polyline.closed {
+            segments.len()
+        } else {
+            segments.len() - 1
+        };
+
+        for i in 0..joins {
+            let (from, point) = segments[i];
+            let (_, to) = segments[(i + 1) % segments.len()];
+            join(&mut mesh, from, point, to, half);
+        }
+    }
+
+    mesh
+}
+
+// -- Helpers --
+
+// Adds the bevel and the miter between two segments on both sides of the joint.
+fn join(mesh: &mut Mesh, from: Point, point: Point, to: Point, half: f64) {
+    let n0 = normal(from, point);
+    let n1 = normal(point, to);
+    let sum = n0 + n1;
+    let length = sum.x().hypot(sum.y());
+
+    for &side in &[1.0, -1.0] {
+        let a = point + scale(n0, half * side);
+        let b = point + scale(n1, half * side);
+        mesh.triangle(point, a, b);
+
+        // the segments continue in the opposite direction, there is no miter
+        if length < f64::EPSILON {
+            continue;
+        }
+
+        let miter = scale(sum, 1.0 / length);
+        let cos = miter.x() * n0.x() + miter.y() * n0.y();
+
+        if cos > 1.0 / MITER_LIMIT {
+            mesh.triangle(a, point + scale(miter, half / cos * side), b);
+        }
+    }
+}
+
+// The unit normal of the segment or zero if the segment has no length.
+fn normal(from: Point, to: Point) -> Point {
+    let (dx, dy) = (to.x() - from.x(), to.y() - from.y());
+    let length = dx.hypot(dy);
+
+    if length < f64::EPSILON {
+        return Point::default();
+    }
+
+    Point::new(-dy / length, dx / length)
+}
+
+fn scale(point: Point, factor: f64) -> Point {
+    Point::new(point.x() * factor, point.y() * factor)
+}
+
+// The number of lines a curve with the given control polygon is flattened to.
+fn segments(points: &[Point]) -> usize {
+    let length: f64 = points.windows(2).map(|p| p[0].distance(p[1])).sum();
+
+    ((length / (8.0 * TOLERANCE)).sqrt().ceil() as usize).clamp(1, 64)
+}
+
+fn points_bounds(points: &[Point]) -> Option<Rectangle> {
+    let (mut left, mut top) = (f64::MAX, f64::MAX);
+    let (mut right, mut bottom) = (f64::MIN, f64::MIN);
+
+    for point in points {
+        left = left.min(point.x());
+        top = top.min(point.y());
+        right = right.max(point.x());
+        bottom = bottom.max(point.y());
+    }
+
+    if left > right || top > bottom {
+        return None;
+    }
+
+    Some(Rectangle::new((left, top), (right - left, bottom - top)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten() {
+        let mut path = vec![];
+        rect(&mut path, Rectangle::new((0.0, 0.0), (10.0, 20.0)));
+
+        let polylines = flatten(&path, Transform::translate(5.0, 5.0));
+        assert_eq!(polylines.len(), 1);
+        assert!(polylines[0].closed);
+        assert_eq!(polylines[0].points[2], Point::new(15.0, 25.0));
+
+        let mut path = vec![];
+        arc(&mut path, Point::new(0.0, 0.0), 10.0, 0.0, PI);
+        let polylines = flatten(&path, Transform::default());
+        let last = *polylines[0].points.last().unwrap();
+        assert!((last.x() + 10.0).abs() < 1e-6 && last.y().abs() < 1e-6);
+
+        // the control points of a circle stay on the circle
+        for point in &polylines[0].points {
+            assert!((point.distance(Point::new(0.0, 0.0)) - 10.0).abs() < TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut path = vec![];
+        rect(&mut path, Rectangle::new((0.0, 0.0), (10.0, 20.0)));
+
+        let mesh = fill(&flatten(&path, Transform::default()));
+        assert_eq!(mesh.indices.len(), 6);
+        assert_eq!(
+            mesh.bounds(),
+            Some(Rectangle::new((0.0, 0.0), (10.0, 20.0)))
+        );
+    }
+
+    #[test]
+    fn test_stroke() {
+        let path = vec![
+            PathOp::MoveTo(Point::new(0.0, 0.0)),
+            PathOp::LineTo(Point::new(10.0, 0.0)),
+        ];
+
+        let mesh = stroke(&flatten(&path, Transform::default()), 2.0);
+        assert_eq!(
+            mesh.bounds(),
+            Some(Rectangle::new((0.0, -1.0), (10.0, 2.0)))
+        );
+
+        // the miters of the corners reach the outer corner of the border
+        let mut path = vec![];
+        rect(&mut path, Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+        let mesh = stroke(&flatten(&path, Transform::default()), 2.0);
+        let bounds = mesh.bounds().unwrap();
+        assert!((bounds.x() + 1.0).abs() < 1e-6 && (bounds.width() - 12.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,742 @@
+use std::{borrow::Cow, mem, ops::Range};
+
+use bytemuck::{Pod, Zeroable};
+use raw_window_handle::HasRawWindowHandle;
+use wgpu::util::DeviceExt;
+
+use crate::utils::{Color, Rectangle};
+
+// The number of samples per pixel that smooth the edges of the shapes.
+const SAMPLE_COUNT: u32 = 4;
+
+// The pixels are stored like the buffers of the software renderer, premultiplied ARGB in a u32.
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8Unorm;
+
+const STENCIL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24PlusStencil8;
+
+/// A vertex of the 2D pipeline. The position is in pixels of the window, the color is premultiplied and multiplied
+/// with the texture at `uv`. Radial gradients sample their color ramp at the length of `uv`.
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 2],
+    pub uv: [f32; 2],
+    pub color: [f32; 4],
+    pub radial: f32,
+}
+
+/// Describes how a draw is drawn on the frame.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawKind {
+    /// The cover replaces the pixels, used to clear a region.
+    Clear,
+
+    /// The cover is blended over the pixels, used for text and images.
+    Blend,
+
+    /// The given triangles are counted in the stencil with the nonzero winding rule, the cover is blended where
+    /// the count is not zero.
+    Fill(Range<u32>),
+
+    /// The given triangles are marked in the stencil, the cover is blended where they are marked.
+    Stroke(Range<u32>),
+}
+
+/// Describes one draw call of a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Draw {
+    pub kind: DrawKind,
+
+    /// The indices of the triangles that are painted.
+    pub cover: Range<u32>,
+
+    /// The index of the texture of the frame or `None` to paint with the vertex color only.
+    pub texture: Option<usize>,
+
+    /// Nothing is drawn outside of the scissor.
+    pub scissor: Rectangle,
+}
+
+/// An image that is uploaded to the GPU for one frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextureData {
+    pub width: u32,
+    pub height: u32,
+
+    // premultiplied ARGB
+    pub data: Vec<u32>,
+}
+
+/// All draws of a frame, recorded by the render context and drawn at its end.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Frame {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+    pub draws: Vec<Draw>,
+    pub textures: Vec<TextureData>,
+
+    /// The whole frame is cleared with the color before it is drawn. If it is `None` the last frame is kept and only
+    /// the drawn parts change.
+    pub clear: Option<Color>,
+}
+
+impl Frame {
+    /// Adds the vertices and the indices relative to the vertices and returns the range of the indices.
+    pub fn push(
+        &mut self,
+        vertices: impl IntoIterator<Item = Vertex>,
+        indices: &[u32],
+    ) -> Range<u32> {
+        let offset = self.vertices.len() as u32;
+        let start = self.indices.len() as u32;
+
+        self.vertices.extend(vertices);
+        self.indices.extend(indices.iter().map(|i| i + offset));
+
+        start..self.indices.len() as u32
+    }
+
+    /// Adds a texture and returns its index.
+    pub fn push_texture(&mut self, width: u32, height: u32, data: Vec<u32>) -> usize {
+        self.textures.push(TextureData {
+            width,
+            height,
+            data,
+        });
+
+        self.textures.len() - 1
+    }
+}
+
+/// Draws the frames with wgpu on the surface of a window. The frame is drawn into a multisampled texture that is kept
+/// between the frames, so a frame could redraw only a part of the last one, and copied to the window afterwards.
+pub struct Gpu {
+    surface: wgpu::Surface,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    swap_chain: wgpu::SwapChain,
+    size: (u32, u32),
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    pipelines: Pipelines,
+    targets: Targets,
+    white: wgpu::BindGroup,
+    screen: wgpu::Buffer,
+}
+
+struct Pipelines {
+    clear: wgpu::RenderPipeline,
+    blend: wgpu::RenderPipeline,
+    fill_stencil: wgpu::RenderPipeline,
+    stroke_stencil: wgpu::RenderPipeline,
+    cover: wgpu::RenderPipeline,
+    present: wgpu::RenderPipeline,
+}
+
+// The textures the frame is drawn into.
+struct Targets {
+    multisampled: wgpu::TextureView,
+    frame: wgpu::TextureView,
+    stencil: wgpu::TextureView,
+    frame_bind_group: wgpu::BindGroup,
+}
+
+impl Gpu {
+    /// Creates the GPU context for the given window. Returns `None` if no graphics adapter could be found.
+    pub fn new<W: HasRawWindowHandle>(window: &W, width: u32, height: u32) -> Option<Self> {
+        let instance = wgpu::Instance::new(wgpu::BackendBit::PRIMARY);
+        let surface = unsafe { instance.create_surface(window) };
+
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            compatible_surface: Some(&surface),
+        }))?;
+
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits::default(),
+            },
+            None,
+        ))
+        .ok()?;
+
+        let size = (width.max(1), height.max(1));
+        let swap_chain = create_swap_chain(&device, &surface, size);
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("orbtk texture"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        filtering: true,
+                        comparison: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("orbtk sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let pipelines = Pipelines::new(&device, &bind_group_layout);
+        let targets = Targets::new(&device, &bind_group_layout, &sampler, size);
+
+        let white = texture_bind_group(
+            &device,
+            &queue,
+            &bind_group_layout,
+            &sampler,
+            &TextureData {
+                width: 1,
+                height: 1,
+                data: vec![0xFFFF_FFFF],
+            },
+        );
+
+        // two triangles that cover the window, used to copy the frame to the window
+        let screen = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("orbtk screen"),
+            contents: bytemuck::cast_slice(&[
+                screen_vertex([-1.0, 1.0], [0.0, 0.0]),
+                screen_vertex([1.0, 1.0], [1.0, 0.0]),
+                screen_vertex([1.0, -1.0], [1.0, 1.0]),
+                screen_vertex([-1.0, 1.0], [0.0, 0.0]),
+                screen_vertex([1.0, -1.0], [1.0, 1.0]),
+                screen_vertex([-1.0, -1.0], [0.0, 1.0]),
+            ]),
+            usage: wgpu::BufferUsage::VERTEX,
+        });
+
+        Some(Gpu {
+            surface,
+            device,
+            queue,
+            swap_chain,
+            size,
+            bind_group_layout,
+            sampler,
+            pipelines,
+            targets,
+            white,
+            screen,
+        })
+    }
+
+    /// Draws the frame and shows it in the window. The vertices of the frame are in pixels of the given size.
+    pub fn render(&mut self, frame: &Frame, width: f64, height: f64) {
+        let size = ((width as u32).max(1), (height as u32).max(1));
+
+        // the content of the targets is lost, the frame has to be cleared
+        if size != self.size {
+            self.size = size;
+            self.swap_chain = create_swap_chain(&self.device, &self.surface, size);
+            self.targets = Targets::new(&self.device, &self.bind_group_layout, &self.sampler, size);
+        }
+
+        let bind_groups: Vec<wgpu::BindGroup> = frame
+            .textures
+            .iter()
+            .map(|texture| {
+                texture_bind_group(
+                    &self.device,
+                    &self.queue,
+                    &self.bind_group_layout,
+                    &self.sampler,
+                    texture,
+                )
+            })
+            .collect();
+
+        let buffers = if frame.vertices.is_empty() || frame.indices.is_empty() {
+            None
+        } else {
+            let (width, height) = (width.max(1.0) as f32, height.max(1.0) as f32);
+
+            // pixels to normalized device coordinates
+            let vertices: Vec<Vertex> = frame
+                .vertices
+                .iter()
+                .map(|vertex| Vertex {
+                    position: [
+                        vertex.position[0] / width * 2.0 - 1.0,
+                        1.0 - vertex.position[1] / height * 2.0,
+                    ],
+                    ..*vertex
+                })
+                .collect();
+
+            Some((
+                self.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("orbtk vertices"),
+                        contents: bytemuck::cast_slice(&vertices),
+                        usage: wgpu::BufferUsage::VERTEX,
+                    }),
+                self.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("orbtk indices"),
+                        contents: bytemuck::cast_slice(&frame.indices),
+                        usage: wgpu::BufferUsage::INDEX,
+                    }),
+            ))
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("orbtk frame"),
+            });
+
+        {
+            let load = match frame.clear {
+                Some(color) => wgpu::LoadOp::Clear(wgpu_color(color)),
+                None => wgpu::LoadOp::Load,
+            };
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("orbtk frame"),
+                color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                    attachment: &self.targets.multisampled,
+                    resolve_target: Some(&self.targets.frame),
+                    ops: wgpu::Operations { load, store: true },
+                }],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachmentDescriptor {
+                    attachment: &self.targets.stencil,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: false,
+                    }),
+                    stencil_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0),
+                        store: false,
+                    }),
+                }),
+            });
+
+            if let Some((vertices, indices)) = &buffers {
+                pass.set_vertex_buffer(0, vertices.slice(..));
+                pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
+
+                for draw in &frame.draws {
+                    let (x, y, width, height) = match scissor(draw.scissor, self.size) {
+                        Some(scissor) => scissor,
+                        None => continue,
+                    };
+
+                    pass.set_scissor_rect(x, y, width, height);
+
+                    match &draw.kind {
+                        DrawKind::Clear => pass.set_pipeline(&self.pipelines.clear),
+                        DrawKind::Blend => pass.set_pipeline(&self.pipelines.blend),
+                        DrawKind::Fill(stencil) | DrawKind::Stroke(stencil) => {
+                            pass.set_pipeline(match draw.kind {
+                                DrawKind::Fill(_) => &self.pipelines.fill_stencil,
+                                _ => &self.pipelines.stroke_stencil,
+                            });
+                            pass.set_bind_group(0, &self.white, &[]);
+                            pass.set_stencil_reference(1);
+                            pass.draw_indexed(stencil.clone(), 0, 0..1);
+
+                            // the cover resets the stencil to zero for the next shape
+                            pass.set_pipeline(&self.pipelines.cover);
+                            pass.set_stencil_reference(0);
+                        }
+                    }
+
+                    let bind_group = draw
+                        .texture
+                        .and_then(|texture| bind_groups.get(texture))
+                        .unwrap_or(&self.white);
+
+                    pass.set_bind_group(0, bind_group, &[]);
+                    pass.draw_indexed(draw.cover.clone(), 0, 0..1);
+                }
+            }
+        }
+
+        let output = self.swap_chain.get_current_frame().ok();
+
+        match &output {
+            Some(output) => {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("orbtk present"),
+                    color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: &output.output.view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: true,
+                        },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+
+                pass.set_pipeline(&self.pipelines.present);
+                pass.set_bind_group(0, &self.targets.frame_bind_group, &[]);
+                pass.set_vertex_buffer(0, self.screen.slice(..));
+                pass.draw(0..6, 0..1);
+            }
+
+            // the swap chain is outdated, e.g. after the window was moved to another monitor
+            None => self.swap_chain = create_swap_chain(&self.device, &self.surface, self.size),
+        }
+
+        self.queue.submit(Some(encoder.finish()));
+
+        // the frame is shown in the window when the output is dropped
+        mem::drop(output);
+    }
+}
+
+impl Pipelines {
+    fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> Self {
+        let shader = device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+            label: Some("orbtk shader"),
+            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("shader.wgsl"))),
+            flags: wgpu::ShaderFlags::all(),
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("orbtk pipeline"),
+            bind_group_layouts: &[bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |blend: wgpu::BlendState,
+                        write_mask: wgpu::ColorWrite,
+                        front: wgpu::StencilFaceState,
+                        back: wgpu::StencilFaceState| {
+            create_pipeline(
+                device,
+                &layout,
+                &shader,
+                (blend, write_mask),
+                Some(wgpu::StencilState {
+                    front,
+                    back,
+                    read_mask: 0xFF,
+                    write_mask: 0xFF,
+                }),
+                SAMPLE_COUNT,
+            )
+        };
+
+        let stencil = |compare: wgpu::CompareFunction, pass_op: wgpu::StencilOperation| {
+            wgpu::StencilFaceState {
+                compare,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op,
+            }
+        };
+
+        let ignore = stencil(wgpu::CompareFunction::Always, wgpu::StencilOperation::Keep);
+
+        let over = wgpu::BlendState {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let cover = stencil(
+            wgpu::CompareFunction::NotEqual,
+            wgpu::StencilOperation::Zero,
+        );
+        let replace = stencil(
+            wgpu::CompareFunction::Always,
+            wgpu::StencilOperation::Replace,
+        );
+
+        Pipelines {
+            clear: pipeline(
+                wgpu::BlendState::REPLACE,
+                wgpu::ColorWrite::ALL,
+                ignore.clone(),
+                ignore.clone(),
+            ),
+            blend: pipeline(over.clone(), wgpu::ColorWrite::ALL, ignore.clone(), ignore),
+            fill_stencil: pipeline(
+                wgpu::BlendState::REPLACE,
+                wgpu::ColorWrite::empty(),
+                stencil(
+                    wgpu::CompareFunction::Always,
+                    wgpu::StencilOperation::IncrementWrap,
+                ),
+                stencil(
+                    wgpu::CompareFunction::Always,
+                    wgpu::StencilOperation::DecrementWrap,
+                ),
+            ),
+            stroke_stencil: pipeline(
+                wgpu::BlendState::REPLACE,
+                wgpu::ColorWrite::empty(),
+                replace.clone(),
+                replace,
+            ),
+            cover: pipeline(over, wgpu::ColorWrite::ALL, cover.clone(), cover),
+            present: create_pipeline(
+                device,
+                &layout,
+                &shader,
+                (wgpu::BlendState::REPLACE, wgpu::ColorWrite::ALL),
+                None,
+                1,
+            ),
+        }
+    }
+}
+
+impl Targets {
+    fn new(
+        device: &wgpu::Device,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        size: (u32, u32),
+    ) -> Self {
+        let texture = |label: &str,
+                       format: wgpu::TextureFormat,
+                       sample_count: u32,
+                       usage: wgpu::TextureUsage| {
+            device
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size: wgpu::Extent3d {
+                        width: size.0,
+                        height: size.1,
+                        depth: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count,
+                    dimension: wgpu::TextureDimension::D2,
+                    format,
+                    usage,
+                })
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+
+        let frame = texture(
+            "orbtk frame",
+            FORMAT,
+            1,
+            wgpu::TextureUsage::RENDER_ATTACHMENT | wgpu::TextureUsage::SAMPLED,
+        );
+
+        let frame_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("orbtk frame"),
+            layout: bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&frame),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        });
+
+        Targets {
+            multisampled: texture(
+                "orbtk multisampled frame",
+                FORMAT,
+                SAMPLE_COUNT,
+                wgpu::TextureUsage::RENDER_ATTACHMENT,
+            ),
+            frame,
+            stencil: texture(
+                "orbtk stencil",
+                STENCIL_FORMAT,
+                SAMPLE_COUNT,
+                wgpu::TextureUsage::RENDER_ATTACHMENT,
+            ),
+            frame_bind_group,
+        }
+    }
+}
+
+// -- Helpers --
+
+fn create_swap_chain(
+    device: &wgpu::Device,
+    surface: &wgpu::Surface,
+    size: (u32, u32),
+) -> wgpu::SwapChain {
+    device.create_swap_chain(
+        surface,
+        &wgpu::SwapChainDescriptor {
+            usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
+            format: FORMAT,
+            width: size.0,
+            height: size.1,
+            present_mode: wgpu::PresentMode::Fifo,
+        },
+    )
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    (blend, write_mask): (wgpu::BlendState, wgpu::ColorWrite),
+    stencil: Option<wgpu::StencilState>,
+    sample_count: u32,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("orbtk pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: "vs_main",
+            buffers: &[wgpu::VertexBufferLayout {
+                array_stride: mem::size_of::<Vertex>() as wgpu::BufferAddress,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &wgpu::vertex_attr_array![0 => Float2, 1 => Float2, 2 => Float4, 3 => Float],
+            }],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: "fs_main",
+            targets: &[wgpu::ColorTargetState {
+                format: FORMAT,
+                alpha_blend: blend.clone(),
+                color_blend: blend,
+                write_mask,
+            }],
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: stencil.map(|stencil| wgpu::DepthStencilState {
+            format: STENCIL_FORMAT,
+            depth_write_enabled: false,
+            depth_compare: wgpu::CompareFunction::Always,
+            stencil,
+            bias: wgpu::DepthBiasState::default(),
+            clamp_depth: false,
+        }),
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+    })
+}
+
+fn texture_bind_group(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    layout: &wgpu::BindGroupLayout,
+    sampler: &wgpu::Sampler,
+    texture: &TextureData,
+) -> wgpu::BindGroup {
+    let size = wgpu::Extent3d {
+        width: texture.width.max(1),
+        height: texture.height.max(1),
+        depth: 1,
+    };
+
+    let gpu_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("orbtk texture"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST,
+    });
+
+    if texture.data.len() as u32 >= size.width * size.height {
+        queue.write_texture(
+            wgpu::TextureCopyView {
+                texture: &gpu_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+            },
+            bytemuck::cast_slice(&texture.data[..(size.width * size.height) as usize]),
+            wgpu::TextureDataLayout {
+                offset: 0,
+                bytes_per_row: 4 * size.width,
+                rows_per_image: size.height,
+            },
+            size,
+        );
+    }
+
+    let view = gpu_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("orbtk texture"),
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+        ],
+    })
+}
+
+fn screen_vertex(position: [f32; 2], uv: [f32; 2]) -> Vertex {
+    Vertex {
+        position,
+        uv,
+        color: [1.0; 4],
+        radial: 0.0,
+    }
+}
+
+fn wgpu_color(color: Color) -> wgpu::Color {
+    let alpha = color.a() as f64 / 255.0;
+
+    wgpu::Color {
+        r: color.r() as f64 / 255.0 * alpha,
+        g: color.g() as f64 / 255.0 * alpha,
+        b: color.b() as f64 / 255.0 * alpha,
+        a: alpha,
+    }
+}
+
+// Converts the rectangle to a scissor rectangle inside of the window. Returns `None` if nothing is inside.
+fn scissor(rect: Rectangle, size: (u32, u32)) -> Option<(u32, u32, u32, u32)> {
+    let left = rect.x().floor().max(0.0);
+    let top = rect.y().floor().max(0.0);
+    let right = (rect.x() + rect.width()).ceil().min(size.0 as f64);
+    let bottom = (rect.y() + rect.height()).ceil().min(size.1 as f64);
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    Some((
+        left as u32,
+        top as u32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    ))
+}
@@ -0,0 +1,926 @@
+use std::{collections::HashMap, mem, ops::Range};
+
+use raw_window_handle::HasRawWindowHandle;
+
+use crate::{
+    physical_size, shadow_layers, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+    DEFAULT_SCALE_FACTOR,
+};
+
+pub use self::font::*;
+pub use self::image::{AnimatedImage, Image, ImageFrame};
+
+use self::{
+    geometry::PathOp,
+    gpu::{Draw, DrawKind, Frame, Gpu, Vertex},
+};
+
+#[path = "../raqote/font.rs"]
+mod font;
+mod geometry;
+mod gpu;
+#[path = "../raqote/image.rs"]
+mod image;
+
+// The number of pixels of the color ramp of a gradient.
+const GRADIENT_RAMP_SIZE: usize = 256;
+
+/// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
+///
+/// The wgpu render context records the drawing of a frame and draws it on the GPU at `finish`. Paths are flattened
+/// to triangles that are drawn into the stencil buffer and covered with the brush afterwards, text and images are
+/// uploaded as textures. Clipping is done by the bounds of the clipping path.
+///
+/// The coordinates and sizes are given in logical pixels, the frame is drawn in physical pixels of the monitor.
+pub struct RenderContext2D {
+    gpu: Option<Gpu>,
+
+    // the size in logical pixels
+    size: (f64, f64),
+    scale_factor: f64,
+    frame: Frame,
+    path: Vec<PathOp>,
+    config: RenderConfig,
+
+    // the transform that is set by `set_transform` scaled by the scale factor
+    transform: Transform,
+    saved_states: Vec<SavedState>,
+    fonts: HashMap<String, Font>,
+
+    // the registered font families in the order of their registration, used as font fallback chain
+    font_families: Vec<String>,
+
+    // the bounds of the clipping paths in pixels of the window
+    clip_rect: Option<Rectangle>,
+
+    background: Color,
+
+    // the next frame has to redraw the whole window, e.g. after a resize
+    redraw: bool,
+
+    // the current frame redraws only a region of the last frame
+    region: bool,
+}
+
+// The state of the canvas that is restored by `restore`.
+struct SavedState {
+    config: RenderConfig,
+    clip_rect: Option<Rectangle>,
+}
+
+// Describes how the cover of a shape is painted.
+enum Paint {
+    Solid(Color),
+    Linear {
+        texture: usize,
+        start: Point,
+        end: Point,
+    },
+    Radial {
+        texture: usize,
+        center: Point,
+        radius: f64,
+    },
+}
+
+impl RenderContext2D {
+    /// Creates a new render ctx 2d without a window. It could be used to measure text, the drawings are discarded.
+    pub fn new(width: f64, height: f64) -> Self {
+        RenderContext2D {
+            gpu: None,
+            size: (width, height),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            frame: Frame::default(),
+            path: vec![],
+            config: RenderConfig::default(),
+            transform: Transform::default(),
+            saved_states: vec![],
+            fonts: HashMap::new(),
+            font_families: vec![],
+            clip_rect: None,
+            background: Color::default(),
+            redraw: true,
+            region: false,
+        }
+    }
+
+    /// Creates a new render ctx 2d that draws on the given window with the GPU.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no graphics adapter that supports wgpu could be found.
+    pub fn new_ex<W: HasRawWindowHandle>(size: (f64, f64), window: &W) -> Self {
+        let gpu = Gpu::new(window, size.0 as u32, size.1 as u32)
+            .expect("RenderContext2D.new_ex: no graphics adapter found.");
+
+        RenderContext2D {
+            gpu: Some(gpu),
+            ..RenderContext2D::new(size.0, size.1)
+        }
+    }
+
+    /// Set the background of the render context.
+    pub fn set_background(&mut self, background: Color) {
+        if self.background != background {
+            self.redraw = true;
+        }
+
+        self.background = background;
+    }
+
+    /// Resizes the render context to the given size in logical pixels.
+    pub fn resize(&mut self, width: f64, height: f64) {
+        self.size = (width, height);
+        self.redraw = true;
+    }
+
+    /// Gets the scale factor of the monitor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the scale factor of the monitor. The size in logical pixels is kept.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor <= 0.0 || (self.scale_factor - scale_factor).abs() < f64::EPSILON {
+            return;
+        }
+
+        let factor = scale_factor / self.scale_factor;
+        self.transform = self.transform.then(Transform::scale(factor, factor));
+        self.scale_factor = scale_factor;
+        self.redraw = true;
+    }
+
+    /// Registers a new font file.
+    pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
+        if let Some(font) = self.fonts.get(family) {
+            if std::ptr::eq(font.data(), font_file) {
+                return;
+            }
+        }
+
+        if let Ok(font) = Font::from_bytes(font_file) {
+            if self.fonts.insert(family.to_string(), font).is_none() {
+                self.font_families.push(family.to_string());
+            }
+        }
+    }
+
+    // Rectangles
+
+    /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the specified width and height and whose style is determined by the fillStyle attribute.
+    pub fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let mut path = vec![];
+        geometry::rect(&mut path, Rectangle::new((x, y), (width, height)));
+        self.draw_path(&path, self.config.fill_style.clone(), None);
+    }
+
+    /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
+    pub fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.rect(x, y, width, height);
+        self.stroke();
+    }
+
+    /// Draws the soft shadow of a rectangle with rounded corners, call it before the rectangle is drawn. The shadow
+    /// fades out over the blur radius around the rectangle.
+    pub fn fill_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+        blur: f64,
+        color: Color,
+    ) {
+        let rect = Rectangle::new((x, y), (width, height));
+
+        for layer in shadow_layers(rect, radius, blur, color.a()) {
+            let mut path = vec![];
+            geometry::rounded_rect(&mut path, layer.rect, layer.radius);
+            self.draw_path(
+                &path,
+                Brush::from(Color::rgba(color.r(), color.g(), color.b(), layer.alpha)),
+                None,
+            );
+        }
+    }
+
+    // Text
+
+    /// Draws (fills) a given text at the given (x, y) position.
+    pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
+        if text.is_empty() {
+            return;
+        }
+
+        // text is filled with the first color of a gradient
+        let color = Color::from(self.config.fill_style.clone());
+
+        if color.a() == 0 || self.config.alpha == 0.0 {
+            return;
+        }
+
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_families,
+            &self.config.font_config.family,
+        );
+
+        if fonts.is_empty() {
+            return;
+        }
+
+        // the text is rasterized in physical pixels to keep it sharp on HiDPI monitors
+        let scale_factor = self.scale_factor;
+        let size = self.config.font_config.font_size * scale_factor;
+        let (width, height) = measure_text(&fonts, text, size);
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        // the text is drawn on the CPU and uploaded as texture
+        let mut data = vec![0; width as usize * height as usize];
+        render_text(
+            &fonts,
+            text,
+            &mut data,
+            width,
+            (size, color, self.config.alpha),
+            (0.0, 0.0),
+        );
+
+        let texture = self.frame.push_texture(width as u32, height as u32, data);
+
+        self.draw_texture(
+            texture,
+            Rectangle::new(
+                (
+                    (x * scale_factor).floor() / scale_factor,
+                    (y * scale_factor).floor() / scale_factor,
+                ),
+                (width / scale_factor, height / scale_factor),
+            ),
+            Rectangle::new((0.0, 0.0), (1.0, 1.0)),
+            1.0,
+        );
+    }
+
+    /// Returns a TextMetrics object.
+    pub fn measure_text(&mut self, text: &str) -> TextMetrics {
+        let mut text_metrics = TextMetrics::default();
+
+        if text.is_empty() {
+            return text_metrics;
+        }
+
+        let fonts = font_chain(
+            &self.fonts,
+            &self.font_families,
+            &self.config.font_config.family,
+        );
+
+        if !fonts.is_empty() {
+            let (width, height) = measure_text(&fonts, text, self.config.font_config.font_size);
+
+            text_metrics.width = width;
+            text_metrics.height = height;
+        }
+
+        text_metrics
+    }
+
+    /// Measures the given text with the given font size and family.
+    pub fn measure(
+        &mut self,
+        text: &str,
+        font_size: f64,
+        family: impl Into<String>,
+    ) -> TextMetrics {
+        self.set_font_family(family);
+        self.set_font_size(font_size);
+        self.measure_text(text)
+    }
+
+    /// Fills the current or given path with the current file style.
+    pub fn fill(&mut self) {
+        let path = mem::take(&mut self.path);
+        self.draw_path(&path, self.config.fill_style.clone(), None);
+        self.path = path;
+    }
+
+    /// Strokes {outlines} the current or given path with the current stroke style.
+    pub fn stroke(&mut self) {
+        let path = mem::take(&mut self.path);
+        self.draw_path(
+            &path,
+            self.config.stroke_style.clone(),
+            Some(self.config.line_width),
+        );
+        self.path = path;
+    }
+
+    /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
+    pub fn begin_path(&mut self) {
+        self.path.clear();
+    }
+
+    /// Attempts to add a straight line from the current point to the start of the current sub-path. If the shape has already been closed or has only one point, this function does nothing.
+    pub fn close_path(&mut self) {
+        self.path.push(PathOp::Close);
+    }
+
+    /// Adds a rectangle to the current path.
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        geometry::rect(&mut self.path, Rectangle::new((x, y), (width, height)));
+    }
+
+    /// Creates a circular arc centered at (x, y) with a radius of radius. The path starts at startAngle and ends at endAngle.
+    pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        geometry::arc(
+            &mut self.path,
+            Point::new(x, y),
+            radius,
+            start_angle,
+            end_angle,
+        );
+    }
+
+    /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.path.push(PathOp::MoveTo(Point::new(x, y)));
+    }
+
+    /// Adds a straight line to the current sub-path by connecting the sub-path's last point to the specified {x, y} coordinates.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.path.push(PathOp::LineTo(Point::new(x, y)));
+    }
+
+    /// Adds a quadratic Bézier curve to the current sub-path.
+    pub fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        self.path
+            .push(PathOp::QuadTo(Point::new(cpx, cpy), Point::new(x, y)));
+    }
+
+    /// Adds a cubic Bézier curve to the current sub-path.
+    /// It requires three points: the first two are control points and the third one is the end point.
+    /// The starting point is the latest point in the current path, which can be changed using MoveTo{} before creating the Bézier curve.
+    pub fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        self.path.push(PathOp::CubicTo(
+            Point::new(cp1x, cp1y),
+            Point::new(cp2x, cp2y),
+            Point::new(x, y),
+        ));
+    }
+
+    /// Draws a render target.
+    pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
+        let (width, height) = (render_target.width(), render_target.height());
+
+        self.draw_image_data(
+            render_target.data(),
+            (width, height),
+            Rectangle::new((0.0, 0.0), (width, height)),
+            Rectangle::new((x, y), (width, height)),
+        );
+    }
+
+    /// Draws the image.
+    pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        self.draw_image_with_size(image, x, y, image.width(), image.height());
+    }
+
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(&mut self, image: &Image, x: f64, y: f64, width: f64, height: f64) {
+        self.draw_image_with_clip_and_size(
+            image,
+            Rectangle::new((0.0, 0.0), (image.width(), image.height())),
+            x,
+            y,
+            width,
+            height,
+        );
+    }
+
+    /// Draws the given part of the image.
+    pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
+        self.draw_image_with_clip_and_size(image, clip, x, y, clip.width(), clip.height());
+    }
+
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.draw_image_data(
+            image.data(),
+            (image.width(), image.height()),
+            clip,
+            Rectangle::new((x, y), (width, height)),
+        );
+    }
+
+    pub fn draw_pipeline(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        pipeline: Box<dyn PipelineTrait>,
+    ) {
+        // the pipeline draws in physical pixels
+        let (physical_width, physical_height) = physical_size(width, height, self.scale_factor);
+        let mut render_target = RenderTarget::new(physical_width, physical_height);
+        pipeline.draw_pipeline(&mut render_target);
+
+        let target_size = (render_target.width(), render_target.height());
+
+        self.draw_image_data(
+            render_target.data(),
+            target_size,
+            Rectangle::new((0.0, 0.0), target_size),
+            Rectangle::new((x, y), (width, height)),
+        );
+    }
+
+    /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
+    /// The clipping path is intersected with the clipping paths that are already active. The bounds of the path are
+    /// used for clipping.
+    pub fn clip(&mut self) {
+        let bounds = geometry::flatten(&self.path, self.transform)
+            .iter()
+            .flat_map(|polyline| polyline.points.iter().copied())
+            .fold(None, |bounds: Option<Rectangle>, point| {
+                let rect = Rectangle::new(point, (0.0, 0.0));
+                Some(bounds.map_or(rect, |bounds| bounds.union(&rect)))
+            })
+            .unwrap_or_default();
+
+        self.clip_rect = Some(match self.clip_rect {
+            Some(clip_rect) => clip_rect.intersection(&bounds),
+            None => bounds,
+        });
+    }
+
+    // Line styles
+
+    /// Sets the thickness of lines.
+    pub fn set_line_width(&mut self, line_width: f64) {
+        self.config.line_width = line_width;
+    }
+
+    /// Sets the alpha value,
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.config.alpha = alpha;
+    }
+
+    /// Specifies the font family.
+    pub fn set_font_family(&mut self, family: impl Into<String>) {
+        self.config.font_config.family = family.into();
+    }
+
+    /// Specifies the font size.
+    pub fn set_font_size(&mut self, size: f64) {
+        self.config.font_config.font_size = size + 4.0;
+    }
+
+    // Fill and stroke style
+
+    /// Specifies the fill color to use inside shapes.
+    pub fn set_fill_style(&mut self, fill_style: Brush) {
+        self.config.fill_style = fill_style;
+    }
+
+    /// Specifies the fill stroke to use inside shapes.
+    pub fn set_stroke_style(&mut self, stroke_style: Brush) {
+        self.config.stroke_style = stroke_style;
+    }
+
+    // Transformations
+
+    /// Sets the transformation.
+    pub fn set_transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        self.transform = Transform::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        )
+        .then(Transform::scale(self.scale_factor, self.scale_factor));
+    }
+
+    // Canvas states
+
+    /// Saves the entire state of the canvas by pushing the current state onto a stack.
+    pub fn save(&mut self) {
+        self.saved_states.push(SavedState {
+            config: self.config.clone(),
+            clip_rect: self.clip_rect,
+        });
+    }
+
+    /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack. The clips
+    /// since the save are removed. If there is no saved state, only the clips are removed.
+    pub fn restore(&mut self) {
+        match self.saved_states.pop() {
+            Some(state) => {
+                self.config = state.config;
+                self.clip_rect = state.clip_rect;
+            }
+            None => self.clip_rect = None,
+        }
+    }
+
+    pub fn clear(&mut self, brush: &Brush) {
+        let color = match *brush {
+            Brush::SolidColor(color) => color,
+            _ => Color::rgba(0x0, 0x0, 0x80, 0x80),
+        };
+
+        let cover = self.push_cover(self.bounds(), &Paint::Solid(color), 1.0);
+
+        self.frame.draws.push(Draw {
+            kind: DrawKind::Clear,
+            cover,
+            texture: None,
+            scissor: self.bounds(),
+        });
+    }
+
+    pub fn start(&mut self) {
+        self.frame = Frame {
+            clear: Some(self.background),
+            ..Frame::default()
+        };
+        self.redraw = false;
+    }
+
+    /// Starts a new frame that redraws only the given region of the last frame. The region is cleared and all drawing
+    /// is clipped to it until `finish`. If the size or the background has changed since the last frame the whole
+    /// frame is redrawn.
+    pub fn start_region(&mut self, region: Rectangle) {
+        if self.redraw {
+            self.start();
+            return;
+        }
+
+        self.frame = Frame::default();
+
+        // the clipping and the cover are given in physical pixels
+        let region = Rectangle::new(
+            (
+                region.x() * self.scale_factor,
+                region.y() * self.scale_factor,
+            ),
+            (
+                region.width() * self.scale_factor,
+                region.height() * self.scale_factor,
+            ),
+        );
+
+        self.save();
+        self.clip_rect = Some(match self.clip_rect {
+            Some(clip_rect) => clip_rect.intersection(&region),
+            None => region,
+        });
+        self.region = true;
+
+        let cover = self.push_cover(region, &Paint::Solid(self.background), 1.0);
+
+        self.frame.draws.push(Draw {
+            kind: DrawKind::Clear,
+            cover,
+            texture: None,
+            scissor: region,
+        });
+    }
+
+    /// Draws the recorded frame on the GPU.
+    pub fn finish(&mut self) {
+        if self.region {
+            self.region = false;
+            self.restore();
+        }
+
+        let frame = mem::take(&mut self.frame);
+
+        if let Some(gpu) = &mut self.gpu {
+            let (width, height) = physical_size(self.size.0, self.size.1, self.scale_factor);
+            gpu.render(&frame, width as f64, height as f64);
+        }
+    }
+
+    // -- Helpers --
+
+    // The bounds of the window in physical pixels.
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(
+            (0.0, 0.0),
+            (
+                self.size.0 * self.scale_factor,
+                self.size.1 * self.scale_factor,
+            ),
+        )
+    }
+
+    // Nothing is drawn outside of the scissor.
+    fn scissor(&self) -> Rectangle {
+        self.clip_rect.unwrap_or_else(|| self.bounds())
+    }
+
+    // Fills the path or strokes it with the given line width.
+    fn draw_path(&mut self, path: &[PathOp], brush: Brush, line_width: Option<f64>) {
+        if brush.is_transparent() || self.config.alpha <= 0.0 {
+            return;
+        }
+
+        let polylines = geometry::flatten(path, self.transform);
+
+        let mesh = match line_width {
+            // the line width is scaled with the transform
+            Some(line_width) => geometry::stroke(&polylines, line_width * self.transform_scale()),
+            None => geometry::fill(&polylines),
+        };
+
+        let bounds = match mesh.bounds() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let stencil = self.frame.push(
+            mesh.points.iter().map(|point| Vertex {
+                position: [point.x() as f32, point.y() as f32],
+                ..Vertex::default()
+            }),
+            &mesh.indices,
+        );
+
+        // gradients adapt to the bounds of the path before it is transformed
+        let paint = self.paint(&brush, geometry::path_bounds(path));
+        let cover = self.push_cover(bounds, &paint, self.config.alpha);
+
+        self.frame.draws.push(Draw {
+            kind: match line_width {
+                Some(_) => DrawKind::Stroke(stencil),
+                None => DrawKind::Fill(stencil),
+            },
+            cover,
+            texture: paint.texture(),
+            scissor: self.scissor(),
+        });
+    }
+
+    // Draws the given part of the image data into the given rectangle.
+    fn draw_image_data(
+        &mut self,
+        data: &[u32],
+        (width, height): (f64, f64),
+        clip: Rectangle,
+        rect: Rectangle,
+    ) {
+        if width <= 0.0 || height <= 0.0 || self.config.alpha <= 0.0 {
+            return;
+        }
+
+        let texture = self.frame.push_texture(
+            width as u32,
+            height as u32,
+            data.iter().map(|pixel| premultiply(*pixel)).collect(),
+        );
+
+        self.draw_texture(
+            texture,
+            rect,
+            Rectangle::new(
+                (clip.x() / width, clip.y() / height),
+                (clip.width() / width, clip.height() / height),
+            ),
+            self.config.alpha,
+        );
+    }
+
+    // Draws the texture into the rectangle, the texture coordinates of the rectangle are given by `uv`.
+    fn draw_texture(&mut self, texture: usize, rect: Rectangle, uv: Rectangle, alpha: f32) {
+        let corners = [
+            (0.0, 0.0),
+            (rect.width(), 0.0),
+            (rect.width(), rect.height()),
+            (0.0, rect.height()),
+        ];
+
+        let vertices: Vec<Vertex> = corners
+            .iter()
+            .map(|(x, y)| {
+                let position = self.transform.apply(Point::new(rect.x() + x, rect.y() + y));
+
+                Vertex {
+                    position: [position.x() as f32, position.y() as f32],
+                    uv: [
+                        (uv.x() + uv.width() * x / rect.width()) as f32,
+                        (uv.y() + uv.height() * y / rect.height()) as f32,
+                    ],
+                    color: [alpha; 4],
+                    radial: 0.0,
+                }
+            })
+            .collect();
+
+        let cover = self.frame.push(vertices, &[0, 1, 2, 0, 2, 3]);
+
+        self.frame.draws.push(Draw {
+            kind: DrawKind::Blend,
+            cover,
+            texture: Some(texture),
+            scissor: self.scissor(),
+        });
+    }
+
+    // Adds the rectangle in pixels of the window that is painted with the paint.
+    fn push_cover(&mut self, rect: Rectangle, paint: &Paint, alpha: f32) -> Range<u32> {
+        let inverse = self.transform.invert().unwrap_or_default();
+
+        let vertices: Vec<Vertex> = [
+            Point::new(rect.x(), rect.y()),
+            Point::new(rect.x() + rect.width(), rect.y()),
+            Point::new(rect.x() + rect.width(), rect.y() + rect.height()),
+            Point::new(rect.x(), rect.y() + rect.height()),
+        ]
+        .iter()
+        .map(|point| paint.vertex(*point, inverse.apply(*point), alpha))
+        .collect();
+
+        self.frame.push(vertices, &[0, 1, 2, 0, 2, 3])
+    }
+
+    // Converts the brush to a paint, gradients that adapt to the shape are placed inside of the given bounds.
+    fn paint(&mut self, brush: &Brush, bounds: Rectangle) -> Paint {
+        match brush {
+            Brush::SolidColor(color) => Paint::Solid(*color),
+            Brush::LinearGradient { start, end, stops } => Paint::Linear {
+                texture: self.push_gradient(stops),
+                start: *start,
+                end: *end,
+            },
+            Brush::Gradient(gradient) => match gradient.kind {
+                GradientKind::Linear(angle) => {
+                    let (start, end) = Gradient::linear_coords(angle, bounds);
+
+                    Paint::Linear {
+                        texture: self.push_gradient(&gradient.stops),
+                        start,
+                        end,
+                    }
+                }
+                GradientKind::Radial(center) => {
+                    let (center, radius) = Gradient::radial_coords(center, bounds);
+
+                    Paint::Radial {
+                        texture: self.push_gradient(&gradient.stops),
+                        center,
+                        radius,
+                    }
+                }
+            },
+        }
+    }
+
+    // Adds the color ramp of the gradient as texture.
+    fn push_gradient(&mut self, stops: &[GradientStop]) -> usize {
+        let ramp = (0..GRADIENT_RAMP_SIZE)
+            .map(|i| {
+                let color = gradient_color(stops, i as f64 / (GRADIENT_RAMP_SIZE - 1) as f64);
+                premultiply(color.data)
+            })
+            .collect();
+
+        self.frame.push_texture(GRADIENT_RAMP_SIZE as u32, 1, ramp)
+    }
+
+    // The factor the transform scales the lengths with.
+    fn transform_scale(&self) -> f64 {
+        let transform = self.transform;
+        (transform.a * transform.d - transform.b * transform.c)
+            .abs()
+            .sqrt()
+    }
+}
+
+impl Paint {
+    fn texture(&self) -> Option<usize> {
+        match self {
+            Paint::Solid(_) => None,
+            Paint::Linear { texture, .. } | Paint::Radial { texture, .. } => Some(*texture),
+        }
+    }
+
+    // Creates the vertex at the given position in pixels of the window, `point` is the position before the
+    // transform.
+    fn vertex(&self, position: Point, point: Point, alpha: f32) -> Vertex {
+        let (uv, color, radial) = match *self {
+            Paint::Solid(color) => {
+                let a = color.a() as f32 / 255.0 * alpha;
+
+                (
+                    [0.5, 0.5],
+                    [
+                        color.r() as f32 / 255.0 * a,
+                        color.g() as f32 / 255.0 * a,
+                        color.b() as f32 / 255.0 * a,
+                        a,
+                    ],
+                    0.0,
+                )
+            }
+            Paint::Linear { start, end, .. } => {
+                let (dx, dy) = (end.x() - start.x(), end.y() - start.y());
+                let length = dx * dx + dy * dy;
+
+                // the position on the gradient line, 0.0 at the start and 1.0 at the end
+                let t = if length > 0.0 {
+                    ((point.x() - start.x()) * dx + (point.y() - start.y()) * dy) / length
+                } else {
+                    0.0
+                };
+
+                ([t as f32, 0.5], [alpha; 4], 0.0)
+            }
+            Paint::Radial { center, radius, .. } => {
+                let radius = radius.max(f64::EPSILON);
+
+                (
+                    [
+                        ((point.x() - center.x()) / radius) as f32,
+                        ((point.y() - center.y()) / radius) as f32,
+                    ],
+                    [alpha; 4],
+                    1.0,
+                )
+            }
+        };
+
+        Vertex {
+            position: [position.x() as f32, position.y() as f32],
+            uv,
+            color,
+            radial,
+        }
+    }
+}
+
+// -- Helpers --
+
+// The color of the gradient at the given position (0.0 - 1.0).
+fn gradient_color(stops: &[GradientStop], position: f64) -> Color {
+    let first = match stops.first() {
+        Some(first) => first,
+        None => return Color::rgba(0, 0, 0, 0),
+    };
+
+    if position <= first.position {
+        return first.color;
+    }
+
+    for pair in stops.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+
+        if position <= end.position {
+            let length = end.position - start.position;
+
+            if length <= 0.0 {
+                return end.color;
+            }
+
+            return Color::interpolate(
+                start.color,
+                end.color,
+                (position - start.position) / length,
+            );
+        }
+    }
+
+    stops[stops.len() - 1].color
+}
+
+// Multiplies the color channels of an ARGB pixel with its alpha value.
+fn premultiply(pixel: u32) -> u32 {
+    let alpha = pixel >> 24;
+
+    if alpha == 255 {
+        return pixel;
+    }
+
+    let channel = |shift: u32| ((pixel >> shift & 0xFF) * alpha / 255) << shift;
+
+    (alpha << 24) | channel(16) | channel(8) | channel(0)
+}
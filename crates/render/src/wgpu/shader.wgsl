@@ -0,0 +1,39 @@
+struct VertexOutput {
+    [[builtin(position)]] position: vec4<f32>;
+    [[location(0)]] uv: vec2<f32>;
+    [[location(1)]] color: vec4<f32>;
+    [[location(2)]] radial: f32;
+};
+
+[[stage(vertex)]]
+fn vs_main(
+    [[location(0)]] position: vec2<f32>,
+    [[location(1)]] uv: vec2<f32>,
+    [[location(2)]] color: vec4<f32>,
+    [[location(3)]] radial: f32
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.uv = uv;
+    out.color = color;
+    out.radial = radial;
+    return out;
+}
+
+[[group(0), binding(0)]]
+var t_color: texture_2d<f32>;
+
+[[group(0), binding(1)]]
+var s_color: sampler;
+
+// Textures and colors are premultiplied. Radial gradients sample their color ramp at the distance to the center.
+[[stage(fragment)]]
+fn fs_main(in: VertexOutput) -> [[location(0)]] vec4<f32> {
+    var uv: vec2<f32> = in.uv;
+
+    if (in.radial > 0.5) {
+        uv = vec2<f32>(length(in.uv), 0.5);
+    }
+
+    return textureSample(t_color, s_color, uv) * in.color;
+}
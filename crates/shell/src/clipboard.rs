@@ -0,0 +1,113 @@
+//! This module contains the clipboard service used to copy and paste text.
+
+use std::{cell::RefCell, rc::Rc};
+
+/// The `Clipboard` service gives access to the text clipboard of the platform. On platforms without clipboard
+/// access (web and Redox) the text is only stored inside of the application.
+///
+/// The service is registered in the `Registry` with the key `clipboard`:
+///
+/// ```rust,ignore
+/// registry.get::<Clipboard>("clipboard").set("Hello");
+/// let text = registry.get::<Clipboard>("clipboard").get();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Clipboard {
+    value: RefCell<Option<String>>,
+
+    // the context is kept as long as the service lives, on X11 the copied text is lost if its context is dropped
+    platform: Rc<RefCell<PlatformClipboard>>,
+}
+
+impl Clipboard {
+    /// Creates a new clipboard service.
+    pub fn new() -> Self {
+        Clipboard::default()
+    }
+
+    /// Gets the current text of the clipboard.
+    pub fn get(&self) -> Option<String> {
+        if let Some(value) = self.platform.borrow_mut().get() {
+            return Some(value);
+        }
+
+        self.value.borrow().clone()
+    }
+
+    /// Sets the text of the clipboard.
+    pub fn set(&self, value: impl Into<String>) {
+        let value = value.into();
+        self.platform.borrow_mut().set(&value);
+        *self.value.borrow_mut() = Some(value);
+    }
+}
+
+// The clipboard of the platform, its context is created on first use.
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+#[derive(Default)]
+struct PlatformClipboard {
+    context: Option<copypasta::ClipboardContext>,
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+impl PlatformClipboard {
+    fn context(&mut self) -> Option<&mut copypasta::ClipboardContext> {
+        if self.context.is_none() {
+            self.context = copypasta::ClipboardContext::new().ok();
+        }
+
+        self.context.as_mut()
+    }
+
+    fn get(&mut self) -> Option<String> {
+        use copypasta::ClipboardProvider;
+
+        self.context()
+            .and_then(|context| context.get_contents().ok())
+    }
+
+    fn set(&mut self, value: &str) {
+        use copypasta::ClipboardProvider;
+
+        if let Some(context) = self.context() {
+            let _ = context.set_contents(value.to_string());
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+impl std::fmt::Debug for PlatformClipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlatformClipboard")
+            .field("connected", &self.context.is_some())
+            .finish()
+    }
+}
+
+#[cfg(any(target_arch = "wasm32", target_os = "redox"))]
+#[derive(Debug, Default)]
+struct PlatformClipboard;
+
+#[cfg(any(target_arch = "wasm32", target_os = "redox"))]
+impl PlatformClipboard {
+    fn get(&mut self) -> Option<String> {
+        None
+    }
+
+    fn set(&mut self, _: &str) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let clipboard = Clipboard::new();
+        clipboard.set("Hello");
+        assert_eq!(clipboard.get(), Some("Hello".to_string()));
+
+        // clones of the service share the context of the platform clipboard
+        assert!(Rc::ptr_eq(&clipboard.clone().platform, &clipboard.platform));
+    }
+}
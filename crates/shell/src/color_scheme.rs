@@ -0,0 +1,204 @@
+//! This module contains the detection of the light or dark preference of the platform.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{process::Command, sync::mpsc, thread, time::Duration};
+
+/// The time in milliseconds between two checks of the color scheme of the platform.
+pub const COLOR_SCHEME_POLL_INTERVAL: u64 = 2000;
+
+/// Describes the light or dark preference of the platform.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme::Light
+    }
+}
+
+impl From<&str> for ColorScheme {
+    fn from(s: &str) -> Self {
+        match s {
+            "Dark" | "dark" => ColorScheme::Dark,
+            _ => ColorScheme::Light,
+        }
+    }
+}
+
+/// Reads the color scheme of the platform. If the platform doesn't provide a preference `ColorScheme::Light` is
+/// returned.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn system_color_scheme() -> ColorScheme {
+    color_scheme_command()
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| parse_color_scheme(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default()
+}
+
+/// Reads the color scheme of the platform. On web the `prefers-color-scheme` media query of the browser is used.
+#[cfg(target_arch = "wasm32")]
+pub fn system_color_scheme() -> ColorScheme {
+    use stdweb::{js, unstable::TryInto};
+
+    let dark: bool = js! {
+        return window.matchMedia && window.matchMedia("(prefers-color-scheme: dark)").matches;
+    }
+    .try_into()
+    .unwrap_or(false);
+
+    if dark {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    }
+}
+
+/// The `ColorSchemeMonitor` reports the changes of the color scheme of the platform. The first poll reports the
+/// current color scheme. On desktop the platform is checked on a background thread every
+/// `COLOR_SCHEME_POLL_INTERVAL` milliseconds.
+pub struct ColorSchemeMonitor {
+    #[cfg(not(target_arch = "wasm32"))]
+    receiver: mpsc::Receiver<ColorScheme>,
+
+    #[cfg(target_arch = "wasm32")]
+    color_scheme: Option<ColorScheme>,
+}
+
+impl Default for ColorSchemeMonitor {
+    fn default() -> Self {
+        ColorSchemeMonitor::new()
+    }
+}
+
+impl ColorSchemeMonitor {
+    /// Creates a new monitor and starts the watching.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut color_scheme = None;
+
+            loop {
+                let current = system_color_scheme();
+
+                if color_scheme != Some(current) {
+                    color_scheme = Some(current);
+
+                    // the monitor is dropped with its window
+                    if sender.send(current).is_err() {
+                        return;
+                    }
+                }
+
+                thread::sleep(Duration::from_millis(COLOR_SCHEME_POLL_INTERVAL));
+            }
+        });
+
+        ColorSchemeMonitor { receiver }
+    }
+
+    /// Creates a new monitor and starts the watching.
+    #[cfg(target_arch = "wasm32")]
+    pub fn new() -> Self {
+        ColorSchemeMonitor { color_scheme: None }
+    }
+
+    /// Returns the new color scheme if it has changed since the last poll.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn poll(&mut self) -> Option<ColorScheme> {
+        self.receiver.try_iter().last()
+    }
+
+    /// Returns the new color scheme if it has changed since the last poll.
+    #[cfg(target_arch = "wasm32")]
+    pub fn poll(&mut self) -> Option<ColorScheme> {
+        let current = system_color_scheme();
+
+        if self.color_scheme == Some(current) {
+            return None;
+        }
+
+        self.color_scheme = Some(current);
+        Some(current)
+    }
+}
+
+// -- Helpers --
+
+#[cfg(target_os = "windows")]
+fn color_scheme_command() -> Command {
+    let mut command = Command::new("reg");
+    command.args(&[
+        "query",
+        "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+        "/v",
+        "AppsUseLightTheme",
+    ]);
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn color_scheme_command() -> Command {
+    let mut command = Command::new("defaults");
+    command.args(&["read", "-g", "AppleInterfaceStyle"]);
+    command
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(target_os = "windows"),
+    not(target_os = "macos")
+))]
+fn color_scheme_command() -> Command {
+    let mut command = Command::new("gsettings");
+    command.args(&["get", "org.gnome.desktop.interface", "color-scheme"]);
+    command
+}
+
+// Parses the output of the color scheme command of the platform.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_color_scheme(output: &str) -> ColorScheme {
+    let output = output.trim().to_lowercase();
+
+    // windows: AppsUseLightTheme    REG_DWORD    0x0
+    if output.contains("appsuselighttheme") {
+        if output.ends_with("0x0") {
+            return ColorScheme::Dark;
+        }
+
+        return ColorScheme::Light;
+    }
+
+    if output.contains("dark") {
+        return ColorScheme::Dark;
+    }
+
+    ColorScheme::Light
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_scheme() {
+        assert_eq!(parse_color_scheme("Dark\n"), ColorScheme::Dark);
+        assert_eq!(parse_color_scheme("'prefer-dark'\n"), ColorScheme::Dark);
+        assert_eq!(parse_color_scheme("'default'\n"), ColorScheme::Light);
+        assert_eq!(parse_color_scheme(""), ColorScheme::Light);
+        assert_eq!(
+            parse_color_scheme("    AppsUseLightTheme    REG_DWORD    0x0\r\n"),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            parse_color_scheme("    AppsUseLightTheme    REG_DWORD    0x1\r\n"),
+            ColorScheme::Light
+        );
+    }
+}
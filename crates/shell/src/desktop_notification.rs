@@ -0,0 +1,290 @@
+//! This module contains the native desktop notifications of the platform.
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+use std::{
+    io::Read,
+    process::{Command, Stdio},
+    thread,
+};
+
+use std::sync::mpsc;
+
+use crate::WindowRequest;
+
+/// A notification that is shown by the notification center of the desktop, outside of the windows of the
+/// application.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DesktopNotification {
+    /// The name of the application that sends the notification.
+    pub app_name: String,
+
+    /// The title.
+    pub title: String,
+
+    /// The body text.
+    pub body: String,
+
+    /// The name of an icon of the icon theme or the path of an image file. If it is empty the icon of the
+    /// application is shown.
+    pub icon: String,
+
+    /// The label of the action button, `None` shows no button. The button is only shown if
+    /// `desktop_notification_actions` returns `true`.
+    pub action: Option<String>,
+}
+
+impl DesktopNotification {
+    /// Creates a notification with the given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        DesktopNotification {
+            title: title.into(),
+            body: body.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of the application.
+    pub fn app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    /// Sets the name or the path of the icon.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Sets the label of the action button.
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+}
+
+/// Describes how a desktop notification is finished.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DesktopNotificationResult {
+    /// The user has clicked the action button of the notification.
+    Activated,
+
+    /// The notification is closed or expired without activation.
+    Dismissed,
+
+    /// The notification could not be shown by the notification center.
+    Failed,
+}
+
+/// Shows the notification with the notification center of the desktop. It waits on a separate thread until the
+/// notification is finished and sends the result to the returned receiver. Afterwards a redraw is requested with the
+/// given window sender to wake up the waiting window.
+///
+/// On Linux and BSD the notification is shown with `notify-send`, on macOS with `osascript` and on Windows as toast of
+/// PowerShell. Only `notify-send` reports the activation of the action button. The notification centers of macOS and
+/// Windows show no action button, their result is always `Dismissed`.
+///
+/// Returns an error if the notification center could not be started or the platform has no supported notification
+/// center, e.g. Redox and web.
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+pub fn show_desktop_notification(
+    notification: &DesktopNotification,
+    window_sender: mpsc::Sender<WindowRequest>,
+) -> Result<mpsc::Receiver<DesktopNotificationResult>, String> {
+    let mut child = notification_command(notification)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            format!(
+                "show_desktop_notification: could not show {}: {}",
+                notification.title, e
+            )
+        })?;
+
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut output = String::new();
+
+        if let Some(stdout) = child.stdout.as_mut() {
+            let _ = stdout.read_to_string(&mut output);
+        }
+
+        let result = match child.wait() {
+            Ok(status) if status.success() => notification_result(&output),
+            _ => DesktopNotificationResult::Failed,
+        };
+
+        let _ = sender.send(result);
+        let _ = window_sender.send(WindowRequest::Redraw);
+    });
+
+    Ok(receiver)
+}
+
+/// Shows the notification with the notification center of the desktop.
+///
+/// Returns an error because the platform has no supported notification center.
+#[cfg(any(target_arch = "wasm32", target_os = "redox"))]
+pub fn show_desktop_notification(
+    notification: &DesktopNotification,
+    _window_sender: mpsc::Sender<WindowRequest>,
+) -> Result<mpsc::Receiver<DesktopNotificationResult>, String> {
+    Err(format!(
+        "show_desktop_notification: could not show {}: the platform has no supported notification center",
+        notification.title
+    ))
+}
+
+/// Checks if the notification center of the platform shows the action button and reports its activation. This is
+/// only supported by `notify-send` on Linux and BSD, macOS and Windows show notifications without action button.
+pub fn desktop_notification_actions() -> bool {
+    cfg!(all(
+        not(target_arch = "wasm32"),
+        not(target_os = "windows"),
+        not(target_os = "macos"),
+        not(target_os = "redox")
+    ))
+}
+
+// the action that is printed by notify-send if the action button is clicked
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+const ACTION_ID: &str = "activate";
+
+#[cfg(target_os = "windows")]
+fn notification_command(notification: &DesktopNotification) -> Command {
+    use std::os::windows::process::CommandExt;
+
+    // the process is started without console window
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let mut command = Command::new("powershell");
+    command
+        .creation_flags(CREATE_NO_WINDOW)
+        .arg("-NoProfile")
+        .arg("-NonInteractive")
+        .arg("-Command")
+        .arg(toast_script(notification));
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn notification_command(notification: &DesktopNotification) -> Command {
+    let mut command = Command::new("osascript");
+    command.arg("-e").arg(format!(
+        "display notification {} with title {}",
+        apple_script_string(&notification.body),
+        apple_script_string(&notification.title)
+    ));
+    command
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(target_os = "windows"),
+    not(target_os = "macos"),
+    not(target_os = "redox")
+))]
+fn notification_command(notification: &DesktopNotification) -> Command {
+    let mut command = Command::new("notify-send");
+
+    if !notification.app_name.is_empty() {
+        command.arg(format!("--app-name={}", notification.app_name));
+    }
+
+    if !notification.icon.is_empty() {
+        command.arg(format!("--icon={}", notification.icon));
+    }
+
+    // waits until the notification is closed to report the activation of the action
+    if let Some(action) = &notification.action {
+        command
+            .arg("--wait")
+            .arg(format!("--action={}={}", ACTION_ID, action));
+    }
+
+    // a title that starts with `-` is not parsed as option
+    command
+        .arg("--")
+        .arg(&notification.title)
+        .arg(&notification.body);
+    command
+}
+
+// -- Helpers --
+
+// Reads the result from the output of the notification command.
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+fn notification_result(output: &str) -> DesktopNotificationResult {
+    if output.lines().any(|line| line.trim() == ACTION_ID) {
+        DesktopNotificationResult::Activated
+    } else {
+        DesktopNotificationResult::Dismissed
+    }
+}
+
+// Creates the PowerShell script that shows the notification as toast. Toasts of not installed applications are only
+// shown with the id of a registered application, the id of PowerShell is used.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn toast_script(notification: &DesktopNotification) -> String {
+    format!(
+        "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+         $template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+         $text = $template.GetElementsByTagName('text'); \
+         $text.Item(0).AppendChild($template.CreateTextNode({})) > $null; \
+         $text.Item(1).AppendChild($template.CreateTextNode({})) > $null; \
+         $toast = [Windows.UI.Notifications.ToastNotification]::new($template); \
+         [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier({}).Show($toast)",
+        power_shell_string(&notification.title),
+        power_shell_string(&notification.body),
+        power_shell_string(POWER_SHELL_APP_ID)
+    )
+}
+
+// the application id of PowerShell that is used to show toasts
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+const POWER_SHELL_APP_ID: &str =
+    "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe";
+
+// Quotes the text as PowerShell string literal without variable expansion.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn power_shell_string(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+// Quotes the text as AppleScript string literal.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn apple_script_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_shell_string() {
+        assert_eq!(power_shell_string("Saved"), "'Saved'");
+        assert_eq!(power_shell_string("It's $HOME"), "'It''s $HOME'");
+        assert!(toast_script(&DesktopNotification::new("Saved", "file.txt"))
+            .contains("CreateTextNode('file.txt')"));
+    }
+
+    #[test]
+    fn test_apple_script_string() {
+        assert_eq!(apple_script_string("Saved"), "\"Saved\"");
+        assert_eq!(apple_script_string("a \"b\" \\c"), "\"a \\\"b\\\" \\\\c\"");
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+    #[test]
+    fn test_notification_result() {
+        assert_eq!(
+            notification_result("activate\n"),
+            DesktopNotificationResult::Activated
+        );
+        assert_eq!(
+            notification_result(""),
+            DesktopNotificationResult::Dismissed
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! This module contains elements to work with window events.
 
-use std::char;
+use std::{char, path::PathBuf};
 
 use orbtk_utils::Point;
 
@@ -16,12 +16,16 @@ pub enum Key {
     Right,
     Space,
     Enter,
+    Tab,
     Control,
     ShiftL,
     ShiftR,
     Alt,
     Escape,
     Home,
+    End,
+    PageUp,
+    PageDown,
     CapsLock,
     A(bool),
     B(bool),
@@ -349,6 +353,7 @@ impl From<char> for Key {
             '8' => Key::Eight,
             '9' => Key::Nine,
             ' ' => Key::Space,
+            '\t' => Key::Tab,
             '.' => Key::Dot,
             '?' => Key::QuestionMark,
             '!' => Key::ExclamationMark,
@@ -398,6 +403,38 @@ pub struct MouseEvent {
     pub state: ButtonState,
 }
 
+/// The height of a line in pixels that is used to convert between line and pixel scroll deltas.
+pub const SCROLL_LINE_HEIGHT: f64 = 16.0;
+
+/// Represents the delta of a mouse wheel or trackpad scroll in lines and in pixels. Positive values scroll to the
+/// top and to the left.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct ScrollDelta {
+    /// The delta in lines, e.g. the notches of a mouse wheel.
+    pub lines: Point,
+
+    /// The delta in pixels, e.g. the movement on a trackpad.
+    pub pixels: Point,
+}
+
+impl ScrollDelta {
+    /// Creates a delta from a line based scroll, e.g. of a mouse wheel.
+    pub fn from_lines(x: f64, y: f64) -> Self {
+        ScrollDelta {
+            lines: Point::new(x, y),
+            pixels: Point::new(x * SCROLL_LINE_HEIGHT, y * SCROLL_LINE_HEIGHT),
+        }
+    }
+
+    /// Creates a delta from a pixel based scroll, e.g. of a trackpad.
+    pub fn from_pixels(x: f64, y: f64) -> Self {
+        ScrollDelta {
+            lines: Point::new(x / SCROLL_LINE_HEIGHT, y / SCROLL_LINE_HEIGHT),
+            pixels: Point::new(x, y),
+        }
+    }
+}
+
 /// Represents a keyboard key event.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct KeyEvent {
@@ -407,3 +444,90 @@ pub struct KeyEvent {
 
     pub text: String,
 }
+
+/// Describes the data that is dragged from another application onto the window.
+#[derive(PartialEq, Clone, Debug)]
+pub enum DropData {
+    /// The paths of dragged files, e.g. from a file manager. On the web only the file names are known.
+    Files(Vec<PathBuf>),
+
+    /// A dragged text, e.g. a selection of a text editor.
+    Text(String),
+}
+
+/// Represents a drag of another application over the window.
+#[derive(PartialEq, Clone, Debug)]
+pub enum ExternalDragEvent {
+    /// The data is dragged into the window.
+    Enter(DropData),
+
+    /// The data is dropped on the window.
+    Drop(DropData),
+
+    /// The drag has left the window without a drop.
+    Leave,
+}
+
+/// Describes the phase of a touch point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TouchPhase {
+    /// The finger has touched the screen.
+    Started,
+
+    /// The finger is moved.
+    Moved,
+
+    /// The finger is lifted.
+    Ended,
+
+    /// The touch is canceled by the system.
+    Cancelled,
+}
+
+/// Represents a change of one touch point. Each finger on the screen has its own id while it touches the screen.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TouchEvent {
+    /// The id of the touch point.
+    pub id: u64,
+
+    /// The phase of the touch point.
+    pub phase: TouchPhase,
+
+    /// The position of the touch point on the window.
+    pub position: Point,
+}
+
+/// Describes a step of the composition of an input method editor (IME), that is used e.g. to type CJK characters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImeEvent {
+    /// A new composition is started.
+    Start,
+
+    /// The composition string is changed. The string is not committed yet and shown at the caret.
+    Update(String),
+
+    /// The composition is finished and the given string is committed. It is empty if the composition is canceled.
+    Commit(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_delta() {
+        let delta = ScrollDelta::from_lines(-1.0, 2.0);
+        assert_eq!(delta.lines, Point::new(-1.0, 2.0));
+        assert_eq!(
+            delta.pixels,
+            Point::new(-SCROLL_LINE_HEIGHT, 2.0 * SCROLL_LINE_HEIGHT)
+        );
+
+        let delta = ScrollDelta::from_pixels(SCROLL_LINE_HEIGHT / 2.0, -SCROLL_LINE_HEIGHT);
+        assert_eq!(delta.lines, Point::new(0.5, -1.0));
+        assert_eq!(
+            delta.pixels,
+            Point::new(SCROLL_LINE_HEIGHT / 2.0, -SCROLL_LINE_HEIGHT)
+        );
+    }
+}
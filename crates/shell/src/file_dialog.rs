@@ -0,0 +1,59 @@
+//! This module contains the native file dialogs of the platform.
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+use std::{path::PathBuf, sync::mpsc, thread};
+
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+use crate::WindowRequest;
+
+/// Describes if a file dialog is used to open or to save a file.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FileDialogMode {
+    /// Chooses an existing file to open.
+    Open,
+
+    /// Chooses a file path to save to.
+    Save,
+}
+
+/// Shows the native file dialog of the platform on a separate thread. The chosen path is sent to the returned
+/// receiver, `None` if the dialog is canceled. After the dialog is closed a redraw is requested with the given
+/// window sender to wake up the waiting window.
+///
+/// The filters are a list of patterns like `*.txt`.
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+pub fn show_file_dialog(
+    mode: FileDialogMode,
+    title: impl Into<String>,
+    filters: &[&str],
+    window_sender: mpsc::Sender<WindowRequest>,
+) -> mpsc::Receiver<Option<PathBuf>> {
+    let (sender, receiver) = mpsc::channel();
+    let title = title.into();
+    let filters: Vec<String> = filters.iter().map(|f| f.to_string()).collect();
+
+    thread::spawn(move || {
+        let filters: Vec<&str> = filters.iter().map(|f| f.as_str()).collect();
+        let description = filters.join(", ");
+
+        let path = match mode {
+            FileDialogMode::Open if filters.is_empty() => {
+                tinyfiledialogs::open_file_dialog(&title, "", None)
+            }
+            FileDialogMode::Open => {
+                tinyfiledialogs::open_file_dialog(&title, "", Some((&filters, &description)))
+            }
+            FileDialogMode::Save if filters.is_empty() => {
+                tinyfiledialogs::save_file_dialog(&title, "")
+            }
+            FileDialogMode::Save => {
+                tinyfiledialogs::save_file_dialog_with_filter(&title, "", &filters, &description)
+            }
+        };
+
+        let _ = sender.send(path.map(PathBuf::from));
+        let _ = window_sender.send(WindowRequest::Redraw);
+    });
+
+    receiver
+}
@@ -0,0 +1,475 @@
+//! This module contains the gesture recognizer that synthesizes double clicks, long presses, swipes, pinches and
+//! rotations from the raw mouse and touch events of a window.
+
+use std::f64::consts::PI;
+
+use orbtk_utils::Point;
+
+use crate::{event::TouchPhase, time::now};
+
+/// Describes the thresholds of the `GestureRecognizer`. Times are in milliseconds, distances in pixels and velocities
+/// in pixels per millisecond.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GestureSettings {
+    /// The maximum time between two clicks of a double click.
+    pub double_click_time: f64,
+
+    /// The maximum distance between two clicks of a double click.
+    pub double_click_distance: f64,
+
+    /// The time the mouse must be pressed until a long press is recognized.
+    pub long_press_time: f64,
+
+    /// The maximum distance the mouse could be moved during a click or a long press.
+    pub tap_distance: f64,
+
+    /// The minimum distance of a swipe.
+    pub swipe_distance: f64,
+
+    /// The minimum velocity of a swipe.
+    pub swipe_velocity: f64,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        GestureSettings {
+            double_click_time: 500.0,
+            double_click_distance: 4.0,
+            long_press_time: 600.0,
+            tap_distance: 8.0,
+            swipe_distance: 50.0,
+            swipe_velocity: 0.3,
+        }
+    }
+}
+
+/// Describes the direction of a swipe.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Describes a gesture that is recognized by the `GestureRecognizer`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Gesture {
+    /// Two clicks in a short time on the same position.
+    DoubleClick(Point),
+
+    /// The mouse is pressed without moving until the long press time is elapsed.
+    LongPress(Point),
+
+    /// A fast move with pressed mouse. Contains the start position, the direction and the velocity.
+    Swipe(Point, SwipeDirection, f64),
+
+    /// Two touch points are moved apart or together. Contains the center between the points and the scale factor
+    /// since the last move.
+    Pinch(Point, f64),
+
+    /// Two touch points are rotated around each other. Contains the center between the points and the angle in
+    /// radians since the last move, positive angles are clockwise.
+    Rotate(Point, f64),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Press {
+    position: Point,
+    time: f64,
+    moved: bool,
+    long_pressed: bool,
+}
+
+/// The `GestureRecognizer` tracks the left mouse button of a window and recognizes double clicks, long presses and
+/// swipes. A simple click (tap) is reported as `ClickEvent` by the widgets. It tracks also the touch points of the
+/// window and recognizes pinches and rotations of the first two points.
+#[derive(Clone, Debug, Default)]
+pub struct GestureRecognizer {
+    settings: GestureSettings,
+    press: Option<Press>,
+    last_click: Option<(Point, f64)>,
+    touches: Vec<(u64, Point)>,
+}
+
+impl GestureRecognizer {
+    /// Creates a new gesture recognizer with the given thresholds.
+    pub fn new(settings: GestureSettings) -> Self {
+        GestureRecognizer {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Gets the thresholds of the recognizer.
+    pub fn settings(&self) -> &GestureSettings {
+        &self.settings
+    }
+
+    /// Sets the thresholds of the recognizer.
+    pub fn set_settings(&mut self, settings: GestureSettings) {
+        self.settings = settings;
+    }
+
+    /// Handles a press of the left mouse button. Returns a double click if the press completes one.
+    pub fn mouse_down(&mut self, position: Point) -> Option<Gesture> {
+        self.mouse_down_at(position, now())
+    }
+
+    /// Handles a move of the mouse.
+    pub fn mouse_move(&mut self, position: Point) {
+        self.mouse_move_at(position);
+    }
+
+    /// Handles a release of the left mouse button. Returns a swipe if the mouse is moved fast enough.
+    pub fn mouse_up(&mut self, position: Point) -> Option<Gesture> {
+        self.mouse_up_at(position, now())
+    }
+
+    /// Checks the elapsed time of the current press. Returns a long press once if the long press time is elapsed.
+    pub fn poll(&mut self) -> Option<Gesture> {
+        self.poll_at(now())
+    }
+
+    /// Checks if a long press could be recognized by a later `poll`.
+    pub fn is_pending(&self) -> bool {
+        self.press
+            .map_or(false, |press| !press.moved && !press.long_pressed)
+    }
+
+    /// Gets the time in milliseconds of `time::now` when the current press becomes a long press. Returns `None` if
+    /// no long press is pending.
+    pub fn long_press_time(&self) -> Option<f64> {
+        self.press
+            .filter(|press| !press.moved && !press.long_pressed)
+            .map(|press| press.time + self.settings.long_press_time)
+    }
+
+    /// Checks if the left mouse button is pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.press.is_some()
+    }
+
+    /// Gets the current touch points with their ids.
+    pub fn touches(&self) -> &[(u64, Point)] {
+        &self.touches
+    }
+
+    /// Handles a touch event. Returns a pinch and a rotation if one of the first two touch points is moved.
+    pub fn touch(&mut self, id: u64, phase: TouchPhase, position: Point) -> Vec<Gesture> {
+        let index = self.touches.iter().position(|(touch, _)| *touch == id);
+
+        match (phase, index) {
+            (TouchPhase::Started, Some(index)) => self.touches[index].1 = position,
+            (TouchPhase::Started, None) => self.touches.push((id, position)),
+            (TouchPhase::Moved, Some(index)) => {
+                let previous = self.pair();
+                self.touches[index].1 = position;
+
+                if index < 2 {
+                    if let (Some(previous), Some(current)) = (previous, self.pair()) {
+                        return pinch_and_rotate(previous, current);
+                    }
+                }
+            }
+            (TouchPhase::Ended, Some(index)) | (TouchPhase::Cancelled, Some(index)) => {
+                self.touches.remove(index);
+            }
+            _ => {}
+        }
+
+        vec![]
+    }
+
+    fn pair(&self) -> Option<(Point, Point)> {
+        if self.touches.len() < 2 {
+            return None;
+        }
+
+        Some((self.touches[0].1, self.touches[1].1))
+    }
+
+    fn mouse_down_at(&mut self, position: Point, now: f64) -> Option<Gesture> {
+        self.press = Some(Press {
+            position,
+            time: now,
+            moved: false,
+            long_pressed: false,
+        });
+
+        match self.last_click.take() {
+            Some((last_position, last_time))
+                if now - last_time <= self.settings.double_click_time
+                    && distance(last_position, position) <= self.settings.double_click_distance =>
+            {
+                Some(Gesture::DoubleClick(position))
+            }
+            _ => None,
+        }
+    }
+
+    fn mouse_move_at(&mut self, position: Point) {
+        let tap_distance = self.settings.tap_distance;
+
+        if let Some(press) = &mut self.press {
+            if distance(press.position, position) > tap_distance {
+                press.moved = true;
+            }
+        }
+    }
+
+    fn mouse_up_at(&mut self, position: Point, now: f64) -> Option<Gesture> {
+        let press = self.press.take()?;
+
+        if press.long_pressed {
+            return None;
+        }
+
+        if !press.moved && distance(press.position, position) <= self.settings.tap_distance {
+            self.last_click = Some((position, now));
+            return None;
+        }
+
+        let delta = Point::new(
+            position.x() - press.position.x(),
+            position.y() - press.position.y(),
+        );
+        let length = distance(press.position, position);
+        let velocity = length / (now - press.time).max(1.0);
+
+        if length < self.settings.swipe_distance || velocity < self.settings.swipe_velocity {
+            return None;
+        }
+
+        let direction = if delta.x().abs() >= delta.y().abs() {
+            if delta.x() < 0.0 {
+                SwipeDirection::Left
+            } else {
+                SwipeDirection::Right
+            }
+        } else if delta.y() < 0.0 {
+            SwipeDirection::Up
+        } else {
+            SwipeDirection::Down
+        };
+
+        Some(Gesture::Swipe(press.position, direction, velocity))
+    }
+
+    fn poll_at(&mut self, now: f64) -> Option<Gesture> {
+        let long_press_time = self.settings.long_press_time;
+        let press = self.press.as_mut()?;
+
+        if press.moved || press.long_pressed || now - press.time < long_press_time {
+            return None;
+        }
+
+        press.long_pressed = true;
+        Some(Gesture::LongPress(press.position))
+    }
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    ((b.x() - a.x()).powi(2) + (b.y() - a.y()).powi(2)).sqrt()
+}
+
+fn angle(a: Point, b: Point) -> f64 {
+    (b.y() - a.y()).atan2(b.x() - a.x())
+}
+
+// Compares two positions of a touch point pair.
+fn pinch_and_rotate(previous: (Point, Point), current: (Point, Point)) -> Vec<Gesture> {
+    let mut gestures = vec![];
+    let center = Point::new(
+        (current.0.x() + current.1.x()) / 2.0,
+        (current.0.y() + current.1.y()) / 2.0,
+    );
+
+    let previous_distance = distance(previous.0, previous.1);
+    let current_distance = distance(current.0, current.1);
+
+    if previous_distance > 0.0 && current_distance > 0.0 && previous_distance != current_distance {
+        gestures.push(Gesture::Pinch(center, current_distance / previous_distance));
+    }
+
+    let mut rotation = angle(current.0, current.1) - angle(previous.0, previous.1);
+
+    if rotation > PI {
+        rotation -= 2.0 * PI;
+    } else if rotation < -PI {
+        rotation += 2.0 * PI;
+    }
+
+    if rotation != 0.0 {
+        gestures.push(Gesture::Rotate(center, rotation));
+    }
+
+    gestures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_click() {
+        let mut recognizer = GestureRecognizer::default();
+
+        assert_eq!(recognizer.mouse_down_at(Point::new(10.0, 10.0), 0.0), None);
+        assert_eq!(recognizer.mouse_up_at(Point::new(10.0, 10.0), 100.0), None);
+        assert_eq!(
+            recognizer.mouse_down_at(Point::new(11.0, 10.0), 300.0),
+            Some(Gesture::DoubleClick(Point::new(11.0, 10.0)))
+        );
+        assert_eq!(recognizer.mouse_up_at(Point::new(11.0, 10.0), 350.0), None);
+
+        // too slow
+        assert_eq!(
+            recognizer.mouse_down_at(Point::new(10.0, 10.0), 2000.0),
+            None
+        );
+        assert_eq!(recognizer.mouse_up_at(Point::new(10.0, 10.0), 2100.0), None);
+        assert_eq!(
+            recognizer.mouse_down_at(Point::new(10.0, 10.0), 2700.0),
+            None
+        );
+        assert_eq!(recognizer.mouse_up_at(Point::new(10.0, 10.0), 2750.0), None);
+
+        // too far away
+        assert_eq!(
+            recognizer.mouse_down_at(Point::new(40.0, 40.0), 2800.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_long_press() {
+        let mut recognizer = GestureRecognizer::default();
+
+        recognizer.mouse_down_at(Point::new(10.0, 10.0), 0.0);
+        assert!(recognizer.is_pending());
+        assert_eq!(
+            recognizer.long_press_time(),
+            Some(recognizer.settings().long_press_time)
+        );
+        assert_eq!(recognizer.poll_at(300.0), None);
+        assert_eq!(
+            recognizer.poll_at(600.0),
+            Some(Gesture::LongPress(Point::new(10.0, 10.0)))
+        );
+        assert_eq!(recognizer.poll_at(700.0), None);
+        assert!(!recognizer.is_pending());
+        assert_eq!(recognizer.long_press_time(), None);
+
+        // a long press is not counted as click of a double click
+        assert_eq!(recognizer.mouse_up_at(Point::new(10.0, 10.0), 800.0), None);
+        assert_eq!(
+            recognizer.mouse_down_at(Point::new(10.0, 10.0), 900.0),
+            None
+        );
+
+        // moving cancels the long press
+        recognizer.mouse_move_at(Point::new(30.0, 10.0));
+        assert!(!recognizer.is_pending());
+        assert_eq!(recognizer.poll_at(2000.0), None);
+    }
+
+    #[test]
+    fn test_swipe() {
+        let mut recognizer = GestureRecognizer::default();
+
+        recognizer.mouse_down_at(Point::new(100.0, 100.0), 0.0);
+        recognizer.mouse_move_at(Point::new(60.0, 105.0));
+        assert_eq!(
+            recognizer.mouse_up_at(Point::new(20.0, 110.0), 100.0),
+            Some(Gesture::Swipe(
+                Point::new(100.0, 100.0),
+                SwipeDirection::Left,
+                (80.0_f64.powi(2) + 10.0_f64.powi(2)).sqrt() / 100.0
+            ))
+        );
+
+        recognizer.mouse_down_at(Point::new(100.0, 100.0), 1000.0);
+        recognizer.mouse_move_at(Point::new(100.0, 200.0));
+        assert!(matches!(
+            recognizer.mouse_up_at(Point::new(100.0, 200.0), 1100.0),
+            Some(Gesture::Swipe(_, SwipeDirection::Down, _))
+        ));
+
+        // too slow
+        recognizer.mouse_down_at(Point::new(100.0, 100.0), 2000.0);
+        recognizer.mouse_move_at(Point::new(200.0, 100.0));
+        assert_eq!(
+            recognizer.mouse_up_at(Point::new(200.0, 100.0), 3000.0),
+            None
+        );
+
+        // too short
+        recognizer.mouse_down_at(Point::new(100.0, 100.0), 4000.0);
+        recognizer.mouse_move_at(Point::new(120.0, 100.0));
+        assert_eq!(
+            recognizer.mouse_up_at(Point::new(120.0, 100.0), 4010.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pinch() {
+        let mut recognizer = GestureRecognizer::default();
+
+        assert!(recognizer
+            .touch(1, TouchPhase::Started, Point::new(100.0, 100.0))
+            .is_empty());
+        assert!(recognizer
+            .touch(1, TouchPhase::Moved, Point::new(90.0, 100.0))
+            .is_empty());
+        assert!(recognizer
+            .touch(2, TouchPhase::Started, Point::new(200.0, 100.0))
+            .is_empty());
+        assert_eq!(recognizer.touches().len(), 2);
+
+        assert_eq!(
+            recognizer.touch(2, TouchPhase::Moved, Point::new(310.0, 100.0)),
+            vec![Gesture::Pinch(Point::new(200.0, 100.0), 2.0)]
+        );
+
+        // a third point is ignored
+        recognizer.touch(3, TouchPhase::Started, Point::new(0.0, 0.0));
+        assert!(recognizer
+            .touch(3, TouchPhase::Moved, Point::new(10.0, 10.0))
+            .is_empty());
+
+        recognizer.touch(1, TouchPhase::Ended, Point::new(90.0, 100.0));
+        recognizer.touch(3, TouchPhase::Cancelled, Point::new(10.0, 10.0));
+        assert_eq!(recognizer.touches(), &[(2, Point::new(310.0, 100.0))]);
+        assert!(recognizer
+            .touch(2, TouchPhase::Moved, Point::new(320.0, 100.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut recognizer = GestureRecognizer::default();
+
+        recognizer.touch(1, TouchPhase::Started, Point::new(100.0, 100.0));
+        recognizer.touch(2, TouchPhase::Started, Point::new(200.0, 100.0));
+
+        let gestures = recognizer.touch(2, TouchPhase::Moved, Point::new(100.0, 200.0));
+        assert_eq!(gestures.len(), 1);
+
+        match gestures[0] {
+            Gesture::Rotate(center, angle) => {
+                assert_eq!(center, Point::new(100.0, 150.0));
+                assert!((angle - PI / 2.0).abs() < 1e-9);
+            }
+            _ => panic!("rotation expected"),
+        }
+
+        // rotation over the half circle
+        recognizer.touch(2, TouchPhase::Moved, Point::new(0.0, 101.0));
+        match recognizer.touch(2, TouchPhase::Moved, Point::new(0.0, 99.0))[0] {
+            Gesture::Rotate(_, angle) => assert!(angle > 0.0 && angle < 0.1),
+            _ => panic!("rotation expected"),
+        }
+    }
+}
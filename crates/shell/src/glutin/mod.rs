@@ -1,6 +1,9 @@
 //! self module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
 pub use super::native::*;
 
@@ -11,7 +14,10 @@ use crate::prelude::*;
 pub use self::window::*;
 pub use self::window_builder::*;
 
+#[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+mod presenter;
 mod states;
+mod tray;
 mod window;
 mod window_builder;
 
@@ -104,6 +110,26 @@ where
                         break;
                     }
                 }
+
+                // the loop sleeps until the next event or the next requested update of a window
+                if *control_flow != ControlFlow::Exit {
+                    let next_update = self
+                        .window_shells
+                        .iter()
+                        .map(|window_shell| window_shell.next_update())
+                        .fold(f64::INFINITY, f64::min);
+
+                    *control_flow = if next_update.is_finite() {
+                        ControlFlow::WaitUntil(
+                            Instant::now()
+                                + Duration::from_secs_f64(
+                                    ((next_update - now()) / 1000.0).max(0.0),
+                                ),
+                        )
+                    } else {
+                        ControlFlow::Wait
+                    };
+                }
             });
     }
 }
@@ -0,0 +1,207 @@
+use glow::HasContext;
+
+type Program = <glow::Context as HasContext>::Program;
+type Texture = <glow::Context as HasContext>::Texture;
+type VertexArray = <glow::Context as HasContext>::VertexArray;
+
+// Draws a quad over the whole window, the corners are derived from the vertex id.
+const VERTEX_SHADER: &str = r#"#version 330 core
+out vec2 uv;
+
+void main() {
+    vec2 position = vec2(float(gl_VertexID & 1), float(gl_VertexID >> 1));
+    uv = vec2(position.x, 1.0 - position.y);
+    gl_Position = vec4(position * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 330 core
+in vec2 uv;
+out vec4 color;
+
+uniform sampler2D frame;
+
+void main() {
+    color = vec4(texture(frame, uv).rgb, 1.0);
+}
+"#;
+
+/// Shows the frames of the CPU render context on an OpenGL window. Each frame is uploaded as texture and drawn over
+/// the whole window.
+pub struct GlPresenter {
+    gl: glow::Context,
+    program: Program,
+    vertex_array: VertexArray,
+    texture: Texture,
+}
+
+impl GlPresenter {
+    /// Creates the presenter for the current OpenGL context, `loader` returns the address of an OpenGL function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shaders could not be compiled, e.g. if OpenGL 3.3 is not supported.
+    pub fn new(loader: impl FnMut(&str) -> *const std::os::raw::c_void) -> Self {
+        unsafe {
+            let gl = glow::Context::from_loader_function(loader);
+
+            let program = gl
+                .create_program()
+                .expect("GlPresenter.new: could not create program.");
+
+            let shaders: Vec<_> = [
+                (glow::VERTEX_SHADER, VERTEX_SHADER),
+                (glow::FRAGMENT_SHADER, FRAGMENT_SHADER),
+            ]
+            .iter()
+            .map(|(shader_type, source)| {
+                let shader = gl
+                    .create_shader(*shader_type)
+                    .expect("GlPresenter.new: could not create shader.");
+                gl.shader_source(shader, source);
+                gl.compile_shader(shader);
+
+                if !gl.get_shader_compile_status(shader) {
+                    panic!(
+                        "GlPresenter.new: could not compile shader: {}",
+                        gl.get_shader_info_log(shader)
+                    );
+                }
+
+                gl.attach_shader(program, shader);
+                shader
+            })
+            .collect();
+
+            gl.link_program(program);
+
+            if !gl.get_program_link_status(program) {
+                panic!(
+                    "GlPresenter.new: could not link program: {}",
+                    gl.get_program_info_log(program)
+                );
+            }
+
+            for shader in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+
+            let vertex_array = gl
+                .create_vertex_array()
+                .expect("GlPresenter.new: could not create vertex array.");
+
+            let texture = gl
+                .create_texture()
+                .expect("GlPresenter.new: could not create texture.");
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+
+            GlPresenter {
+                gl,
+                program,
+                vertex_array,
+                texture,
+            }
+        }
+    }
+
+    /// Draws the ARGB pixels of a frame with the given size over the window. Frames that don't match the size are
+    /// skipped, e.g. the last frame before a resize.
+    pub fn present(&self, data: &[u32], width: u32, height: u32) {
+        let bytes = match frame_bytes(data, width, height) {
+            Some(bytes) => bytes,
+            None => return,
+        };
+
+        unsafe {
+            let gl = &self.gl;
+
+            gl.viewport(0, 0, width as i32, height as i32);
+
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+            gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 4);
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                glow::BGRA,
+                glow::UNSIGNED_BYTE,
+                Some(bytes),
+            );
+
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+        }
+    }
+}
+
+impl Drop for GlPresenter {
+    fn drop(&mut self) {
+        unsafe {
+            self.gl.delete_texture(self.texture);
+            self.gl.delete_vertex_array(self.vertex_array);
+            self.gl.delete_program(self.program);
+        }
+    }
+}
+
+// -- Helpers --
+
+// Returns the bytes of the ARGB pixels of a frame with the given size, `None` if the frame doesn't match the size.
+fn frame_bytes(data: &[u32], width: u32, height: u32) -> Option<&[u8]> {
+    if data.len() != width as usize * height as usize {
+        return None;
+    }
+
+    // the ARGB pixels are stored as BGRA bytes on little endian machines
+    Some(unsafe {
+        std::slice::from_raw_parts(
+            data.as_ptr() as *const u8,
+            data.len() * std::mem::size_of::<u32>(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_bytes() {
+        let data = [0xFF11_2233, 0x8044_5566];
+
+        assert_eq!(frame_bytes(&data, 2, 2), None);
+        assert_eq!(frame_bytes(&data, 2, 1).map(|bytes| bytes.len()), Some(8));
+
+        if cfg!(target_endian = "little") {
+            assert_eq!(
+                frame_bytes(&data, 1, 2),
+                Some(&[0x33, 0x22, 0x11, 0xFF, 0x66, 0x55, 0x44, 0x80][..])
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! This module shows the `TrayIcon` of a window in the system tray of the platform. The system tray is supported on
+//! Windows, macOS and Linux if the shell is built with the `tray` feature, Linux needs gtk and libappindicator.
+
+use crate::tray::{TrayEvent, TrayIcon};
+
+#[cfg(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos", target_os = "linux")
+))]
+pub use self::platform::*;
+
+#[cfg(not(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos", target_os = "linux")
+)))]
+pub use self::unsupported::*;
+
+/// The interval in milliseconds in which the events of a shown tray icon are checked.
+pub const TRAY_POLL_INTERVAL: f64 = 100.0;
+
+#[cfg(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos", target_os = "linux")
+))]
+mod platform {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use tray_icon::{menu, ClickEvent, TrayIconBuilder};
+
+    use super::*;
+    use crate::{event::MouseButton, tray::TrayMenuItem, WindowIcon};
+
+    lazy_static! {
+        // the events of all tray icons that are not yet taken by their windows
+        static ref PENDING_EVENTS: Mutex<(Vec<tray_icon::TrayEvent>, Vec<menu::MenuEvent>)> =
+            Mutex::new((vec![], vec![]));
+    }
+
+    /// Shows a `TrayIcon` in the system tray and reads its events.
+    pub struct PlatformTray {
+        tray_icon: tray_icon::TrayIcon,
+
+        // the ids of the items of the context menu by the ids of the platform menu items
+        items: HashMap<u32, String>,
+    }
+
+    impl PlatformTray {
+        /// Shows the given tray icon in the system tray. Returns an error if the system tray is not available.
+        pub fn new(tray_icon: &TrayIcon) -> Result<Self, String> {
+            // the tray icon is shown by gtk on Linux, glutin runs no gtk event loop
+            #[cfg(target_os = "linux")]
+            gtk::init()
+                .map_err(|e| format!("PlatformTray::new: could not initialize gtk: {}", e))?;
+
+            let mut items = HashMap::new();
+            let menu = menu::Menu::new();
+
+            for item in &tray_icon.menu {
+                menu.append(platform_menu_item(item, &mut items).as_ref());
+            }
+
+            let mut builder = TrayIconBuilder::new().with_menu(Box::new(menu));
+
+            if !tray_icon.tool_tip.is_empty() {
+                builder = builder.with_tooltip(&tray_icon.tool_tip);
+            }
+
+            if let Some(icon) = tray_icon.icon.as_ref().and_then(platform_icon) {
+                builder = builder.with_icon(icon);
+            }
+
+            let tray_icon = builder
+                .build()
+                .map_err(|e| format!("PlatformTray::new: could not show tray icon: {}", e))?;
+
+            Ok(PlatformTray { tray_icon, items })
+        }
+
+        /// Takes the events of the tray icon since the last call.
+        pub fn events(&self) -> Vec<TrayEvent> {
+            #[cfg(target_os = "linux")]
+            while gtk::events_pending() {
+                gtk::main_iteration_do(false);
+            }
+
+            let mut pending = PENDING_EVENTS.lock().unwrap();
+            let (tray_events, menu_events) = &mut *pending;
+            tray_events.extend(tray_icon::TrayEvent::receiver().try_iter());
+            menu_events.extend(menu::MenuEvent::receiver().try_iter());
+
+            let mut events = vec![];
+
+            // the events of the tray icons of other windows are kept
+            tray_events.retain(|event| {
+                if event.id != self.tray_icon.id() {
+                    return true;
+                }
+
+                events.push(match event.event {
+                    ClickEvent::Left => TrayEvent::Click(MouseButton::Left),
+                    ClickEvent::Right => TrayEvent::Click(MouseButton::Right),
+                    ClickEvent::Double => TrayEvent::DoubleClick,
+                });
+                false
+            });
+
+            menu_events.retain(|event| match self.items.get(&event.id) {
+                Some(id) => {
+                    events.push(TrayEvent::MenuItem(id.clone()));
+                    false
+                }
+                None => true,
+            });
+
+            events
+        }
+    }
+
+    // -- Helpers --
+
+    // Converts the entry of the context menu and stores the ids of its items.
+    fn platform_menu_item(
+        item: &TrayMenuItem,
+        items: &mut HashMap<u32, String>,
+    ) -> Box<dyn menu::MenuItemExt> {
+        match item {
+            TrayMenuItem::Item {
+                id,
+                text,
+                checkable,
+                checked,
+                enabled,
+            } => {
+                let (platform_id, item): (u32, Box<dyn menu::MenuItemExt>) = if *checkable {
+                    let item = menu::CheckMenuItem::new(text, *enabled, *checked, None);
+                    (item.id(), Box::new(item))
+                } else {
+                    let item = menu::MenuItem::new(text, *enabled, None);
+                    (item.id(), Box::new(item))
+                };
+
+                items.insert(platform_id, id.clone());
+                item
+            }
+            TrayMenuItem::Separator => Box::new(menu::PredefinedMenuItem::separator()),
+            TrayMenuItem::Submenu {
+                text,
+                items: submenu_items,
+            } => {
+                let submenu = menu::Submenu::new(text, true);
+
+                for item in submenu_items {
+                    submenu.append(platform_menu_item(item, items).as_ref());
+                }
+
+                Box::new(submenu)
+            }
+        }
+    }
+
+    // Converts the icon to the icon of the tray, invalid icons are ignored.
+    fn platform_icon(icon: &WindowIcon) -> Option<tray_icon::icon::Icon> {
+        tray_icon::icon::Icon::from_rgba(icon.to_rgba(), icon.width, icon.height).ok()
+    }
+}
+
+#[cfg(not(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos", target_os = "linux")
+)))]
+mod unsupported {
+    use super::*;
+
+    /// Placeholder of the system tray if the shell is built without the `tray` feature.
+    pub struct PlatformTray;
+
+    impl PlatformTray {
+        /// Returns an error because the system tray is not supported.
+        pub fn new(_tray_icon: &TrayIcon) -> Result<Self, String> {
+            Err("PlatformTray::new: the shell is built without system tray support, enable the tray feature"
+                .to_string())
+        }
+
+        /// Returns no events.
+        pub fn events(&self) -> Vec<TrayEvent> {
+            vec![]
+        }
+    }
+}
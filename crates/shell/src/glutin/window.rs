@@ -1,14 +1,33 @@
-use std::sync::mpsc;
+use std::{mem, path::PathBuf, sync::mpsc};
 
-use glutin::{event, event_loop::ControlFlow, window, ContextWrapper, PossiblyCurrent};
+use glutin::{event, event_loop::ControlFlow, window};
+
+#[cfg(any(feature = "pfinder", not(feature = "gpu")))]
+use glutin::{ContextWrapper, PossiblyCurrent};
+
+#[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+use super::presenter::GlPresenter;
+
+use super::{
+    tray::{PlatformTray, TRAY_POLL_INTERVAL},
+    CONSOLE,
+};
 
 use derive_more::Constructor;
+use raw_window_handle::HasRawWindowHandle;
 
 use crate::{
-    event::{ButtonState, MouseButton, MouseEvent},
+    color_scheme::{ColorSchemeMonitor, COLOR_SCHEME_POLL_INTERVAL},
+    event::{
+        ButtonState, DropData, ExternalDragEvent, Key, KeyEvent, MouseButton, MouseEvent,
+        ScrollDelta, TouchEvent, TouchPhase,
+    },
+    monitor::Monitor,
     render::RenderContext2D,
+    taskbar::TaskbarProgress,
+    time::{now, UpdateTimer, FRAME_INTERVAL},
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowIcon, WindowMode, WindowRequest,
 };
 
 /// Represents a wrapper for a glutin window. It handles events, propagate them to
@@ -18,7 +37,12 @@ pub struct Window<A>
 where
     A: WindowAdapter,
 {
+    #[cfg(any(feature = "pfinder", not(feature = "gpu")))]
     gl_context: ContextWrapper<PossiblyCurrent, window::Window>,
+    #[cfg(all(feature = "gpu", not(feature = "pfinder")))]
+    window: window::Window,
+    #[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+    presenter: GlPresenter,
     adapter: A,
     render_context: RenderContext2D,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
@@ -27,6 +51,14 @@ where
     close: bool,
     mouse_pos: (f64, f64),
     scale_factor: f64,
+    monitors: Vec<Monitor>,
+    hovered_files: Vec<PathBuf>,
+    dropped_files: Vec<PathBuf>,
+    color_scheme_monitor: ColorSchemeMonitor,
+    update_timer: UpdateTimer,
+    tray: Option<PlatformTray>,
+    taskbar_progress: TaskbarProgress,
+    mode: WindowMode,
 }
 
 impl<A> Window<A>
@@ -35,7 +67,17 @@ where
 {
     /// Returns an glutin specific window id.
     pub fn id(&self) -> window::WindowId {
-        self.gl_context.window().id()
+        self.window().id()
+    }
+
+    #[cfg(any(feature = "pfinder", not(feature = "gpu")))]
+    fn window(&self) -> &window::Window {
+        self.gl_context.window()
+    }
+
+    #[cfg(all(feature = "gpu", not(feature = "pfinder")))]
+    fn window(&self) -> &window::Window {
+        &self.window
     }
 
     /// Check if the window is open.
@@ -43,6 +85,62 @@ where
         true
     }
 
+    // Resizes the render context and the adapter to the given physical size, they get the size in logical pixels.
+    fn resize(&mut self, size: glutin::dpi::PhysicalSize<u32>) {
+        #[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+        self.gl_context.resize(size);
+
+        let size = size.to_logical::<f64>(self.scale_factor);
+        self.adapter.resize(size.width, size.height);
+        self.render_context.resize(size.width, size.height);
+        self.update = true;
+    }
+
+    // Passes the mode of the window to the adapter if it's changed by the platform. glutin reports only if the window
+    // is shown fullscreen, a minimized window is resized to zero. A maximized window is not distinguished from a
+    // normal window, the requested mode is kept.
+    fn update_mode(&mut self, size: glutin::dpi::PhysicalSize<u32>) {
+        let mode = platform_mode(
+            self.mode,
+            self.window().fullscreen().is_some(),
+            size.width == 0 || size.height == 0,
+        );
+
+        if mode != self.mode {
+            self.mode = mode;
+            self.adapter.mode_changed(mode);
+        }
+    }
+
+    // Passes the monitors to the adapter if they are changed since the last check. glutin sends no event if a monitor
+    // is connected, they are checked if the window is moved or its scale factor is changed.
+    fn update_monitors(&mut self) {
+        let monitors = platform_monitors(self.window());
+
+        if monitors != self.monitors {
+            self.monitors = monitors.clone();
+            self.adapter.monitors_changed(monitors);
+        }
+    }
+
+    // Sends the files that are hovered or dropped since the last update as one drag to the adapter. glutin sends
+    // a separate event for each file.
+    fn push_external_drag_events(&mut self) {
+        if !self.hovered_files.is_empty() {
+            let files = mem::take(&mut self.hovered_files);
+            self.adapter
+                .external_drag_event(ExternalDragEvent::Enter(DropData::Files(files)));
+            self.update = true;
+        }
+
+        if !self.dropped_files.is_empty() {
+            let files = mem::take(&mut self.dropped_files);
+            self.adapter
+                .external_drag_event(ExternalDragEvent::Drop(DropData::Files(files)));
+            self.update = true;
+        }
+    }
+
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self, control_flow: &mut ControlFlow, event: &event::Event<()>) {
         match event {
@@ -53,9 +151,39 @@ where
                 if !window_id.eq(&self.id()) {
                     return;
                 }
-                self.adapter.resize(s.width as f64, s.height as f64);
-                self.render_context.resize(s.width as f64, s.height as f64);
-                self.update = true;
+                self.resize(*s);
+                self.update_mode(*s);
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event:
+                    event::WindowEvent::ScaleFactorChanged {
+                        scale_factor,
+                        new_inner_size,
+                    },
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // the window is moved to a monitor with another DPI
+                self.scale_factor = *scale_factor;
+                self.render_context.set_scale_factor(*scale_factor);
+                self.adapter.scale_factor_changed(*scale_factor);
+                self.resize(**new_inner_size);
+                self.update_monitors();
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Moved(position),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let position = position.to_logical::<f64>(self.scale_factor);
+                self.adapter.moved(position.x, position.y);
+                self.update_monitors();
                 *control_flow = ControlFlow::Wait;
             }
             event::Event::WindowEvent {
@@ -70,9 +198,42 @@ where
             }
             event::Event::WindowEvent {
                 event: event::WindowEvent::KeyboardInput { input, .. },
-                // todo: implement
-                ..
-            } => *control_flow = ControlFlow::Wait,
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                if let Some(key) = input.virtual_keycode.and_then(key) {
+                    self.adapter.key_event(KeyEvent {
+                        key,
+                        state: match input.state {
+                            event::ElementState::Pressed => ButtonState::Down,
+                            event::ElementState::Released => ButtonState::Up,
+                        },
+                        text: String::default(),
+                    });
+                    self.update = true;
+                }
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::ReceivedCharacter(character),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // control characters like backspace or enter are sent as keys
+                if !character.is_control() {
+                    self.adapter.key_event(KeyEvent {
+                        key: Key::from(*character),
+                        state: ButtonState::Down,
+                        text: character.to_string(),
+                    });
+                    self.update = true;
+                }
+                *control_flow = ControlFlow::Wait;
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::MouseInput { state, button, .. },
                 ..
@@ -111,12 +272,13 @@ where
                 if !window_id.eq(&self.id()) {
                     return;
                 }
-                match delta {
-                    event::MouseScrollDelta::LineDelta(_, _) => {}
-                    event::MouseScrollDelta::PixelDelta(p) => {
-                        self.adapter.scroll(p.x, p.y);
+                let delta = match delta {
+                    event::MouseScrollDelta::LineDelta(x, y) => {
+                        ScrollDelta::from_lines(x as f64, y as f64)
                     }
-                }
+                    event::MouseScrollDelta::PixelDelta(p) => ScrollDelta::from_pixels(p.x, p.y),
+                };
+                self.adapter.scroll(delta);
                 self.redraw = true;
                 self.update = true;
                 *control_flow = ControlFlow::Wait;
@@ -135,6 +297,61 @@ where
                 self.redraw = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Touch(touch),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let position = touch.location.to_logical::<f64>(self.scale_factor);
+                let phase = match touch.phase {
+                    event::TouchPhase::Started => TouchPhase::Started,
+                    event::TouchPhase::Moved => TouchPhase::Moved,
+                    event::TouchPhase::Ended => TouchPhase::Ended,
+                    event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                };
+                self.adapter.touch_event(TouchEvent {
+                    id: touch.id,
+                    phase,
+                    position: (position.x, position.y).into(),
+                });
+                self.update = true;
+                self.redraw = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFile(path),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.hovered_files.push(path.clone());
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::DroppedFile(path),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.dropped_files.push(path.clone());
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFileCancelled,
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.hovered_files.clear();
+                self.adapter.external_drag_event(ExternalDragEvent::Leave);
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
             _ => *control_flow = ControlFlow::Wait,
         }
     }
@@ -148,15 +365,97 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::RedrawAt(time) => {
+                        self.update_timer.request(time);
+                    }
                     WindowRequest::ChangeTitle(title) => {
-                        // todo fix
-                        // self.window.set_title(&title);
+                        self.window().set_title(&title);
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeCursor(cursor) => {
+                        self.window().set_cursor_visible(cursor != CursorIcon::None);
+                        self.window().set_cursor_icon(match cursor {
+                            CursorIcon::Default | CursorIcon::None => window::CursorIcon::Default,
+                            CursorIcon::ResizeHorizontal => window::CursorIcon::EwResize,
+                            CursorIcon::ResizeVertical => window::CursorIcon::NsResize,
+                            CursorIcon::Pointer => window::CursorIcon::Hand,
+                            CursorIcon::Text => window::CursorIcon::Text,
+                            CursorIcon::ResizeDiagonal => window::CursorIcon::NwseResize,
+                            CursorIcon::ResizeAntiDiagonal => window::CursorIcon::NeswResize,
+                            CursorIcon::Move => window::CursorIcon::Move,
+                            CursorIcon::Wait => window::CursorIcon::Wait,
+                            CursorIcon::Crosshair => window::CursorIcon::Crosshair,
+                        });
+                    }
+                    WindowRequest::SetImePosition(position) => {
+                        self.window()
+                            .set_ime_position(glutin::dpi::LogicalPosition::new(
+                                position.x(),
+                                position.y(),
+                            ));
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::Resize(width, height) => {
+                        self.window()
+                            .set_inner_size(glutin::dpi::LogicalSize::new(width, height));
+                    }
+                    WindowRequest::Move(x, y) => {
+                        self.window()
+                            .set_outer_position(glutin::dpi::LogicalPosition::new(x, y));
+                    }
+                    WindowRequest::ChangeMode(mode) => {
+                        self.mode = mode;
+                        let window = self.window();
+                        window.set_fullscreen(if mode == WindowMode::Fullscreen {
+                            Some(window::Fullscreen::Borderless(window.current_monitor()))
+                        } else {
+                            None
+                        });
+                        window.set_maximized(mode == WindowMode::Maximized);
+                        window.set_minimized(mode == WindowMode::Minimized);
+                    }
+                    WindowRequest::SetMinSize(size) => {
+                        self.window().set_min_inner_size(
+                            size.map(|(width, height)| {
+                                glutin::dpi::LogicalSize::new(width, height)
+                            }),
+                        );
+                    }
+                    WindowRequest::SetMaxSize(size) => {
+                        self.window().set_max_inner_size(
+                            size.map(|(width, height)| {
+                                glutin::dpi::LogicalSize::new(width, height)
+                            }),
+                        );
+                    }
+                    WindowRequest::SetIcon(icon) => {
+                        self.window()
+                            .set_window_icon(icon.as_ref().and_then(platform_icon));
+                    }
+                    WindowRequest::SetVisible(visible) => {
+                        self.window().set_visible(visible);
+                    }
+                    WindowRequest::SetTrayIcon(tray_icon) => {
+                        // the tray icon is replaced, a new icon could not be shown next to the old one
+                        self.tray = None;
+
+                        if let Some(tray_icon) = tray_icon {
+                            match PlatformTray::new(&tray_icon) {
+                                Ok(tray) => self.tray = Some(tray),
+                                Err(error) => CONSOLE.log(error),
+                            }
+                        }
+                    }
+                    WindowRequest::SetProgress(progress) => {
+                        let window = self.window().raw_window_handle();
+
+                        if let Err(error) = self.taskbar_progress.set_progress(window, progress) {
+                            CONSOLE.log(error);
+                        }
+                    }
                 }
             }
         }
@@ -164,6 +463,24 @@ where
 
     /// Runs update on the adapter.
     pub fn update(&mut self) {
+        self.push_external_drag_events();
+
+        if let Some(color_scheme) = self.color_scheme_monitor.poll() {
+            self.adapter.color_scheme_changed(color_scheme);
+            self.update = true;
+        }
+
+        if let Some(tray) = &self.tray {
+            for event in tray.events() {
+                self.adapter.tray_event(event);
+                self.update = true;
+            }
+        }
+
+        if self.update_timer.elapsed() {
+            self.update = true;
+        }
+
         if !self.update {
             return;
         }
@@ -172,11 +489,165 @@ where
         self.redraw = true;
     }
 
-    /// Swaps the current frame buffer.
+    /// Gets the time in milliseconds of `time::now` of the next update. A pending update or a frame that is still
+    /// drawn by the render context runs on the next frame, otherwise the window sleeps until its update timer elapses
+    /// or the color scheme and the events of its tray icon are checked again.
+    pub fn next_update(&self) -> f64 {
+        if self.update || self.redraw {
+            return now() + FRAME_INTERVAL;
+        }
+
+        let next_check = if self.tray.is_some() {
+            now() + TRAY_POLL_INTERVAL.min(COLOR_SCHEME_POLL_INTERVAL as f64)
+        } else {
+            now() + COLOR_SCHEME_POLL_INTERVAL as f64
+        };
+
+        self.update_timer
+            .time()
+            .map_or(next_check, |time| time.min(next_check))
+    }
+
+    /// Swaps the current frame buffer. The wgpu render context presents its frame itself.
+    #[cfg(any(feature = "pfinder", feature = "gpu"))]
     pub fn render(&mut self) {
         if self.redraw {
+            #[cfg(feature = "pfinder")]
             self.gl_context.swap_buffers().unwrap();
             self.redraw = false;
         }
     }
+
+    /// Draws the last finished frame of the render context and swaps the current frame buffer.
+    #[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+    pub fn render(&mut self) {
+        if self.redraw {
+            let size = self.gl_context.window().inner_size();
+
+            if let Some(data) = self.render_context.data() {
+                self.presenter.present(data, size.width, size.height);
+                self.gl_context.swap_buffers().unwrap();
+                self.redraw = false;
+            }
+        }
+    }
+}
+
+// -- Helpers --
+
+// Maps the glutin keys that are not sent as characters.
+fn key(virtual_keycode: event::VirtualKeyCode) -> Option<Key> {
+    use event::VirtualKeyCode;
+
+    Some(match virtual_keycode {
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::Left => Key::Left,
+        VirtualKeyCode::Right => Key::Right,
+        VirtualKeyCode::Up => Key::Up,
+        VirtualKeyCode::Down => Key::Down,
+        VirtualKeyCode::Delete => Key::Delete,
+        VirtualKeyCode::Return => Key::Enter,
+        VirtualKeyCode::Tab => Key::Tab,
+        VirtualKeyCode::LControl | VirtualKeyCode::RControl => Key::Control,
+        VirtualKeyCode::LShift => Key::ShiftL,
+        VirtualKeyCode::RShift => Key::ShiftR,
+        VirtualKeyCode::LAlt | VirtualKeyCode::RAlt => Key::Alt,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::Home => Key::Home,
+        VirtualKeyCode::End => Key::End,
+        VirtualKeyCode::PageUp => Key::PageUp,
+        VirtualKeyCode::PageDown => Key::PageDown,
+        VirtualKeyCode::Numpad0 => Key::Numpad0,
+        VirtualKeyCode::Numpad1 => Key::Numpad1,
+        VirtualKeyCode::Numpad2 => Key::Numpad2,
+        VirtualKeyCode::Numpad3 => Key::Numpad3,
+        VirtualKeyCode::Numpad4 => Key::Numpad4,
+        VirtualKeyCode::Numpad5 => Key::Numpad5,
+        VirtualKeyCode::Numpad6 => Key::Numpad6,
+        VirtualKeyCode::Numpad7 => Key::Numpad7,
+        VirtualKeyCode::Numpad8 => Key::Numpad8,
+        VirtualKeyCode::Numpad9 => Key::Numpad9,
+        VirtualKeyCode::Divide => Key::NumpadDivide,
+        VirtualKeyCode::Multiply => Key::NumpadMultiply,
+        VirtualKeyCode::Subtract => Key::NumpadSubtract,
+        VirtualKeyCode::Add => Key::NumpadAdd,
+        VirtualKeyCode::NumpadEnter => Key::NumpadEnter,
+        VirtualKeyCode::Decimal => Key::NumpadDot,
+        // shortcuts like select all, copy, paste and cut
+        VirtualKeyCode::A => Key::A(false),
+        VirtualKeyCode::C => Key::C(false),
+        VirtualKeyCode::V => Key::V(false),
+        VirtualKeyCode::X => Key::X(false),
+        _ => return None,
+    })
+}
+
+// Returns the mode of the window after it's resized by the platform.
+fn platform_mode(mode: WindowMode, fullscreen: bool, minimized: bool) -> WindowMode {
+    if fullscreen {
+        WindowMode::Fullscreen
+    } else if minimized {
+        WindowMode::Minimized
+    } else if mode == WindowMode::Maximized {
+        WindowMode::Maximized
+    } else {
+        WindowMode::Normal
+    }
+}
+
+// Reads the monitors of the platform. winit provides no work areas, they cover the whole monitors.
+pub(crate) fn platform_monitors(window: &window::Window) -> Vec<Monitor> {
+    let primary = window.primary_monitor();
+
+    window
+        .available_monitors()
+        .map(|monitor| {
+            let scale_factor = monitor.scale_factor();
+            let position = monitor.position().to_logical::<f64>(scale_factor);
+            let size = monitor.size().to_logical::<f64>(scale_factor);
+
+            Monitor::new(
+                monitor.name().unwrap_or_default(),
+                (position.x, position.y, size.width, size.height),
+            )
+            .scale_factor(scale_factor)
+            .primary(monitor == primary)
+        })
+        .collect()
+}
+
+// Converts the icon to the icon of the glutin window, invalid icons are ignored.
+pub(crate) fn platform_icon(icon: &WindowIcon) -> Option<window::Icon> {
+    window::Icon::from_rgba(icon.to_rgba(), icon.width, icon.height).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_mode() {
+        assert_eq!(
+            platform_mode(WindowMode::Normal, true, false),
+            WindowMode::Fullscreen
+        );
+        assert_eq!(
+            platform_mode(WindowMode::Maximized, false, true),
+            WindowMode::Minimized
+        );
+
+        // the platform has left the fullscreen or restored the minimized window
+        assert_eq!(
+            platform_mode(WindowMode::Fullscreen, false, false),
+            WindowMode::Normal
+        );
+        assert_eq!(
+            platform_mode(WindowMode::Minimized, false, false),
+            WindowMode::Normal
+        );
+        assert_eq!(
+            platform_mode(WindowMode::Maximized, false, false),
+            WindowMode::Maximized
+        );
+    }
 }
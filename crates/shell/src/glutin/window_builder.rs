@@ -1,24 +1,45 @@
-use std::{collections::HashMap, sync::mpsc, sync::Arc};
+use std::{collections::HashMap, sync::mpsc};
 
+use glutin::{dpi::LogicalSize, window};
+
+#[cfg(feature = "pfinder")]
+use std::sync::Arc;
+
+#[cfg(feature = "pfinder")]
+use glutin::dpi::PhysicalSize;
+
+#[cfg(feature = "pfinder")]
 use font_kit::handle::Handle;
-use glutin::{
-    dpi::{LogicalSize, PhysicalSize},
-    window, ContextBuilder, GlProfile, GlRequest,
-};
+#[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+use glutin::Api;
+#[cfg(any(feature = "pfinder", not(feature = "gpu")))]
+use glutin::{ContextBuilder, GlProfile, GlRequest};
+#[cfg(feature = "pfinder")]
 use pathfinder_color::ColorF;
+#[cfg(feature = "pfinder")]
 use pathfinder_geometry::vector::{vec2f, vec2i};
+#[cfg(feature = "pfinder")]
 use pathfinder_gl::{GLDevice, GLVersion};
+#[cfg(feature = "pfinder")]
 use pathfinder_renderer::gpu::{
     options::{DestFramebuffer, RendererOptions},
     renderer::Renderer,
 };
+#[cfg(feature = "pfinder")]
 use pathfinder_resources::embedded::EmbeddedResourceLoader;
 
-use super::{Shell, Window};
+use super::{
+    window::{platform_icon, platform_monitors},
+    Shell, Window,
+};
+
+#[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+use super::presenter::GlPresenter;
 
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    color_scheme::ColorSchemeMonitor, render::RenderContext2D, taskbar::TaskbarProgress,
+    time::UpdateTimer, utils::Rectangle, window_adapter::WindowAdapter, WindowIcon, WindowMode,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -58,7 +79,8 @@ where
             .with_decorations(!settings.borderless)
             .with_resizable(settings.resizeable)
             .with_always_on_top(settings.always_on_top)
-            .with_inner_size(logical_size);
+            .with_inner_size(logical_size)
+            .with_window_icon(settings.icon.as_ref().and_then(platform_icon));
 
         WindowBuilder {
             shell,
@@ -97,13 +119,18 @@ where
         self
     }
 
-    /// Sets the bounds.
+    /// Sets the icon.
+    pub fn icon(mut self, icon: WindowIcon) -> Self {
+        self.window_builder = self.window_builder.with_window_icon(platform_icon(&icon));
+        self
+    }
+
+    /// Sets the bounds in logical pixels.
     pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
         self.bounds = bounds.into();
-        let window_size = (self.bounds.width(), self.bounds.height());
-        let physical_size = PhysicalSize::new(window_size.0, window_size.1);
+        let logical_size = LogicalSize::new(self.bounds.width(), self.bounds.height());
 
-        self.window_builder = self.window_builder.with_inner_size(physical_size);
+        self.window_builder = self.window_builder.with_inner_size(logical_size);
         self
     }
 
@@ -120,7 +147,8 @@ where
     }
 
     /// Builds the window shell and add it to the application `Shell`.
-    pub fn build(self) {
+    #[cfg(feature = "pfinder")]
+    pub fn build(mut self) {
         // Create an OpenGL 3.x context for Pathfinder to use.
         let gl_context = ContextBuilder::new()
             .with_gl(GlRequest::Latest)
@@ -171,8 +199,116 @@ where
             font_handles,
         );
 
+        self.adapter.scale_factor_changed(scale_factor);
+
+        let monitors = platform_monitors(gl_context.window());
+        self.adapter.monitors_changed(monitors.clone());
+
+        self.shell.window_shells.push(Window::new(
+            gl_context,
+            self.adapter,
+            render_context,
+            self.request_receiver,
+            true,
+            true,
+            false,
+            (0.0, 0.0),
+            scale_factor,
+            monitors,
+            vec![],
+            vec![],
+            ColorSchemeMonitor::new(),
+            UpdateTimer::default(),
+            None,
+            TaskbarProgress::default(),
+            WindowMode::Normal,
+        ))
+    }
+
+    /// Builds the window shell and add it to the application `Shell`.
+    #[cfg(all(feature = "gpu", not(feature = "pfinder")))]
+    pub fn build(mut self) {
+        let window = self
+            .window_builder
+            .build(self.shell.event_loop())
+            .expect("WindowBuilder.build: could not create window.");
+
+        let scale_factor = window.current_monitor().scale_factor();
+        let size = window.inner_size().to_logical::<f64>(scale_factor);
+
+        // the render context is drawn in physical pixels of the monitor
+        let mut render_context = RenderContext2D::new_ex((size.width, size.height), &window);
+        render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
+
+        let monitors = platform_monitors(&window);
+        self.adapter.monitors_changed(monitors.clone());
+
+        for (family, font) in self.fonts {
+            render_context.register_font(&family, font);
+        }
+
+        self.shell.window_shells.push(Window::new(
+            window,
+            self.adapter,
+            render_context,
+            self.request_receiver,
+            true,
+            true,
+            false,
+            (0.0, 0.0),
+            scale_factor,
+            monitors,
+            vec![],
+            vec![],
+            ColorSchemeMonitor::new(),
+            UpdateTimer::default(),
+            None,
+            TaskbarProgress::default(),
+            WindowMode::Normal,
+        ))
+    }
+
+    /// Builds the window shell and add it to the application `Shell`.
+    #[cfg(not(any(feature = "pfinder", feature = "gpu")))]
+    pub fn build(mut self) {
+        // Create an OpenGL 3.3 context to show the frames of the render context.
+        let gl_context = ContextBuilder::new()
+            .with_gl(GlRequest::Specific(Api::OpenGl, (3, 3)))
+            .with_gl_profile(GlProfile::Core)
+            .with_vsync(true)
+            .build_windowed(self.window_builder, self.shell.event_loop())
+            .expect("WindowBuilder.build: could not create OpenGL context.");
+
+        let gl_context = unsafe {
+            gl_context
+                .make_current()
+                .expect("WindowBuilder.build: could not make OpenGL context current.")
+        };
+
+        let presenter = GlPresenter::new(|name| gl_context.get_proc_address(name) as *const _);
+
+        let scale_factor = gl_context.window().current_monitor().scale_factor();
+        let size = gl_context
+            .window()
+            .inner_size()
+            .to_logical::<f64>(scale_factor);
+
+        // the render context is drawn in physical pixels of the monitor
+        let mut render_context = RenderContext2D::new(size.width, size.height);
+        render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
+
+        let monitors = platform_monitors(gl_context.window());
+        self.adapter.monitors_changed(monitors.clone());
+
+        for (family, font) in self.fonts {
+            render_context.register_font(&family, font);
+        }
+
         self.shell.window_shells.push(Window::new(
             gl_context,
+            presenter,
             self.adapter,
             render_context,
             self.request_receiver,
@@ -181,6 +317,14 @@ where
             false,
             (0.0, 0.0),
             scale_factor,
+            monitors,
+            vec![],
+            vec![],
+            ColorSchemeMonitor::new(),
+            UpdateTimer::default(),
+            None,
+            TaskbarProgress::default(),
+            WindowMode::Normal,
         ))
     }
 }
@@ -0,0 +1,342 @@
+//! This module contains a headless implementation of the window shell. It opens no platform windows, the windows are
+//! drawn into in-memory framebuffers, e.g. to test widgets and their rendering in CI.
+//!
+//! The module is available on all native platforms without the pathfinder and wgpu backends, with the `headless`
+//! feature it is used as the platform of the shell.
+
+use std::sync::mpsc;
+
+pub use super::native::*;
+
+use crate::{window_adapter::WindowAdapter, ShellRequest, WindowSettings};
+
+pub use self::window::*;
+pub use self::window_builder::*;
+
+mod window;
+mod window_builder;
+
+/// Does nothing. This function is only use by the web backend.
+pub fn initialize() {}
+
+/// Represents an application shell that could handle multiple windows.
+pub struct Shell<A: 'static>
+where
+    A: WindowAdapter,
+{
+    window_shells: Vec<Window<A>>,
+    requests: mpsc::Receiver<ShellRequest<A>>,
+}
+
+impl<A> Shell<A>
+where
+    A: WindowAdapter,
+{
+    /// Creates a new application shell.
+    pub fn new(requests: mpsc::Receiver<ShellRequest<A>>) -> Self {
+        Shell {
+            window_shells: vec![],
+            requests,
+        }
+    }
+
+    /// Creates a window builder, that could be used to create a window and add it to the application shell.
+    pub fn create_window(&mut self, adapter: A) -> WindowBuilder<A> {
+        WindowBuilder::new(self, adapter)
+    }
+
+    /// Creates a window builder from a settings object.
+    pub fn create_window_from_settings(
+        &mut self,
+        settings: WindowSettings,
+        adapter: A,
+    ) -> WindowBuilder<A> {
+        WindowBuilder::from_settings(settings, self, adapter)
+    }
+
+    /// Gets the windows of the shell.
+    pub fn windows(&self) -> &[Window<A>] {
+        &self.window_shells
+    }
+
+    /// Gets the windows of the shell as mutable slice, e.g. to pass input events to them.
+    pub fn windows_mut(&mut self) -> &mut [Window<A>] {
+        &mut self.window_shells
+    }
+
+    /// Receives window request from the application and handles them.
+    pub fn receive_requests(&mut self) {
+        let mut requests = vec![];
+        for request in self.requests.try_iter() {
+            requests.push(request);
+        }
+
+        for request in requests {
+            if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
+                self.create_window_from_settings(settings, adapter)
+                    .request_receiver(window_requests)
+                    .build();
+            }
+        }
+    }
+
+    /// Runs one frame: the windows handle their requests, are updated and drawn. Closed windows are removed.
+    pub fn step(&mut self) {
+        self.receive_requests();
+
+        for window_shell in &mut self.window_shells {
+            window_shell.receive_requests();
+            window_shell.update();
+            window_shell.render();
+        }
+
+        self.window_shells
+            .retain(|window_shell| window_shell.is_open());
+    }
+
+    /// Runs (starts) the application shell and its windows until all windows are idle. Update timers that elapse
+    /// later, e.g. of tool tips, are not waited for.
+    pub fn run(&mut self) {
+        loop {
+            self.step();
+
+            if self
+                .window_shells
+                .iter()
+                .all(|window_shell| window_shell.is_idle())
+            {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        monitor::Monitor,
+        render::RenderContext2D,
+        tray::{TrayEvent, TrayIcon, TrayMenuItem},
+        utils::*,
+        WindowIcon, WindowMode, WindowRequest,
+    };
+
+    #[derive(Default)]
+    struct TestAdapter {
+        runs: usize,
+        size: (f64, f64),
+        scale_factor: f64,
+        monitors: Vec<Monitor>,
+        tray_events: Vec<TrayEvent>,
+    }
+
+    impl WindowAdapter for TestAdapter {
+        fn resize(&mut self, width: f64, height: f64) {
+            self.size = (width, height);
+        }
+
+        fn scale_factor_changed(&mut self, scale_factor: f64) {
+            self.scale_factor = scale_factor;
+        }
+
+        fn monitors_changed(&mut self, monitors: Vec<Monitor>) {
+            self.monitors = monitors;
+        }
+
+        fn mouse_position(&self) -> Point {
+            Point::default()
+        }
+
+        fn tray_event(&mut self, event: TrayEvent) {
+            self.tray_events.push(event);
+        }
+
+        fn active(&mut self, _active: bool) {}
+
+        fn run(&mut self, render_context: &mut RenderContext2D) {
+            self.runs += 1;
+
+            render_context.start();
+            render_context.set_fill_style(Brush::from(Color::rgb(255, 0, 0)));
+            render_context.fill_rect(0.0, 0.0, 10.0, 10.0);
+            render_context.finish();
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let (_sender, receiver) = mpsc::channel();
+        let mut shell = Shell::new(receiver);
+
+        shell
+            .create_window(TestAdapter::default())
+            .bounds(Rectangle::new((0.0, 0.0), (20.0, 20.0)))
+            .build();
+
+        shell.run();
+
+        let window = &shell.windows()[0];
+        assert_eq!(window.adapter().runs, 1);
+        assert_eq!(window.adapter().size, (20.0, 20.0));
+        assert_eq!(window.pixel(5, 5), Some(Color::rgb(255, 0, 0).data));
+        assert_ne!(window.pixel(15, 15), Some(Color::rgb(255, 0, 0).data));
+        assert_eq!(window.pixel(20, 0), None);
+
+        // idle windows are not updated again
+        shell.step();
+        assert_eq!(shell.windows()[0].adapter().runs, 1);
+
+        shell.windows_mut()[0].mouse(5.0, 5.0);
+        shell.step();
+        assert_eq!(shell.windows()[0].adapter().runs, 2);
+    }
+
+    #[test]
+    fn test_scale_factor() {
+        let (_sender, receiver) = mpsc::channel();
+        let mut shell = Shell::new(receiver);
+
+        shell
+            .create_window(TestAdapter::default())
+            .bounds(Rectangle::new((0.0, 0.0), (20.0, 20.0)))
+            .scale_factor(2.0)
+            .build();
+
+        shell.run();
+
+        // the adapter works with logical pixels, the frame is drawn in physical pixels
+        let window = &shell.windows()[0];
+        assert_eq!(window.adapter().scale_factor, 2.0);
+        assert_eq!(window.adapter().size, (20.0, 20.0));
+        assert_eq!(window.physical_size(), (40, 40));
+        assert_eq!(window.framebuffer().len(), 1600);
+        assert_eq!(window.pixel(15, 15), Some(Color::rgb(255, 0, 0).data));
+        assert_ne!(window.pixel(25, 25), Some(Color::rgb(255, 0, 0).data));
+
+        // the window is moved to a monitor without HiDPI
+        shell.windows_mut()[0].scale_factor_changed(1.0);
+        shell.run();
+
+        let window = &shell.windows()[0];
+        assert_eq!(window.adapter().scale_factor, 1.0);
+        assert_eq!(window.size(), (20.0, 20.0));
+        assert_eq!(window.framebuffer().len(), 400);
+        assert_ne!(window.pixel(15, 15), Some(Color::rgb(255, 0, 0).data));
+    }
+
+    #[test]
+    fn test_monitors() {
+        let (_sender, receiver) = mpsc::channel();
+        let mut shell = Shell::new(receiver);
+
+        shell
+            .create_window(TestAdapter::default())
+            .monitors(vec![
+                Monitor::new("left", (0.0, 0.0, 1920.0, 1080.0)).primary(true),
+                Monitor::new("right", (1920.0, 0.0, 1280.0, 1024.0)),
+            ])
+            .build();
+
+        shell.run();
+        assert_eq!(shell.windows()[0].adapter().monitors.len(), 2);
+
+        // the right monitor is disconnected
+        shell.windows_mut()[0].monitors_changed(vec![Monitor::new(
+            "left",
+            (0.0, 0.0, 1920.0, 1080.0),
+        )
+        .primary(true)]);
+        shell.run();
+
+        let window = &shell.windows()[0];
+        assert_eq!(window.adapter().runs, 2);
+        assert_eq!(window.adapter().monitors.len(), 1);
+        assert!(window.adapter().monitors[0].primary);
+    }
+
+    #[test]
+    fn test_window_requests() {
+        let (_sender, receiver) = mpsc::channel();
+        let mut shell = Shell::new(receiver);
+        let (window_sender, window_receiver) = mpsc::channel();
+
+        shell
+            .create_window(TestAdapter::default())
+            .bounds(Rectangle::new((0.0, 0.0), (20.0, 20.0)))
+            .request_receiver(window_receiver)
+            .build();
+
+        window_sender
+            .send(WindowRequest::SetMaxSize(Some((30.0, 30.0))))
+            .unwrap();
+        window_sender
+            .send(WindowRequest::Resize(40.0, 10.0))
+            .unwrap();
+        window_sender.send(WindowRequest::Move(5.0, 6.0)).unwrap();
+        window_sender
+            .send(WindowRequest::ChangeMode(WindowMode::Maximized))
+            .unwrap();
+        window_sender
+            .send(WindowRequest::SetIcon(Some(WindowIcon::new(
+                1,
+                1,
+                vec![0xff_00_00_00],
+            ))))
+            .unwrap();
+        window_sender
+            .send(WindowRequest::SetProgress(Some(0.5)))
+            .unwrap();
+
+        shell.run();
+
+        let window = &shell.windows()[0];
+        assert_eq!(window.size(), (30.0, 10.0));
+        assert_eq!(window.adapter().size, (30.0, 10.0));
+        assert_eq!(window.framebuffer().len(), 300);
+        assert_eq!(window.position(), (5.0, 6.0));
+        assert_eq!(window.mode(), WindowMode::Maximized);
+        assert_eq!(window.icon().map(|icon| icon.width), Some(1));
+        assert_eq!(window.progress(), Some(0.5));
+    }
+
+    #[test]
+    fn test_tray_icon() {
+        let (_sender, receiver) = mpsc::channel();
+        let mut shell = Shell::new(receiver);
+        let (window_sender, window_receiver) = mpsc::channel();
+
+        shell
+            .create_window(TestAdapter::default())
+            .request_receiver(window_receiver)
+            .build();
+
+        // events are ignored without tray icon
+        shell.windows_mut()[0].tray_event(TrayEvent::DoubleClick);
+
+        window_sender
+            .send(WindowRequest::SetTrayIcon(Some(
+                TrayIcon::new()
+                    .tool_tip("Test")
+                    .menu(vec![TrayMenuItem::item("show", "Show")]),
+            )))
+            .unwrap();
+        window_sender
+            .send(WindowRequest::SetVisible(false))
+            .unwrap();
+        shell.run();
+
+        let window = &mut shell.windows_mut()[0];
+        assert!(!window.is_visible());
+        assert_eq!(
+            window.tray_icon().map(|t| t.tool_tip.as_str()),
+            Some("Test")
+        );
+
+        window.tray_event(TrayEvent::MenuItem("show".to_string()));
+        assert_eq!(
+            window.adapter().tray_events,
+            vec![TrayEvent::MenuItem("show".to_string())]
+        );
+    }
+}
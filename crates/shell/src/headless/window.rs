@@ -0,0 +1,409 @@
+use std::sync::mpsc;
+
+use crate::{
+    color_scheme::ColorScheme,
+    event::{ExternalDragEvent, ImeEvent, KeyEvent, MouseEvent, ScrollDelta, TouchEvent},
+    monitor::Monitor,
+    render::{physical_size, RenderContext2D},
+    time::UpdateTimer,
+    tray::{TrayEvent, TrayIcon},
+    utils::Point,
+    window_adapter::WindowAdapter,
+    CursorIcon, WindowIcon, WindowMode, WindowRequest,
+};
+
+/// Represents a window without a platform window. It runs the update and render pipeline of its adapter into an
+/// in-memory framebuffer. Input is passed to the window by calling its event methods, e.g. in automated tests.
+///
+/// The size and the positions are given in logical pixels, the framebuffer has the size in physical pixels of a
+/// monitor with the scale factor of the window.
+pub struct Window<A>
+where
+    A: WindowAdapter,
+{
+    adapter: A,
+    render_context: RenderContext2D,
+    request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    size: (f64, f64),
+    scale_factor: f64,
+    position: (f64, f64),
+    min_size: Option<(f64, f64)>,
+    max_size: Option<(f64, f64)>,
+    mode: WindowMode,
+    icon: Option<WindowIcon>,
+    progress: Option<f64>,
+    visible: bool,
+    tray_icon: Option<TrayIcon>,
+    framebuffer: Vec<u32>,
+    title: String,
+    cursor: CursorIcon,
+    ime_position: Option<Point>,
+    update: bool,
+    redraw: bool,
+    close: bool,
+    update_timer: UpdateTimer,
+}
+
+impl<A> Window<A>
+where
+    A: WindowAdapter,
+{
+    pub fn new(
+        adapter: A,
+        render_context: RenderContext2D,
+        request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        size: (f64, f64),
+        scale_factor: f64,
+        title: String,
+        icon: Option<WindowIcon>,
+    ) -> Self {
+        let (width, height) = physical_size(size.0, size.1, scale_factor);
+
+        Window {
+            adapter,
+            render_context,
+            request_receiver,
+            size,
+            scale_factor,
+            position: (0.0, 0.0),
+            min_size: None,
+            max_size: None,
+            mode: WindowMode::Normal,
+            icon,
+            progress: None,
+            visible: true,
+            tray_icon: None,
+            framebuffer: vec![0; width as usize * height as usize],
+            title,
+            cursor: CursorIcon::Default,
+            ime_position: None,
+            update: true,
+            redraw: true,
+            close: false,
+            update_timer: UpdateTimer::default(),
+        }
+    }
+
+    /// Check if the window is open.
+    pub fn is_open(&self) -> bool {
+        !self.close
+    }
+
+    /// Checks if the window has neither a pending update nor a frame that is not drawn yet.
+    pub fn is_idle(&self) -> bool {
+        !self.update && !self.redraw
+    }
+
+    /// Gets the window adapter.
+    pub fn adapter(&self) -> &A {
+        &self.adapter
+    }
+
+    /// Gets the window adapter as mutable reference.
+    pub fn adapter_mut(&mut self) -> &mut A {
+        &mut self.adapter
+    }
+
+    /// Gets the size in logical pixels.
+    pub fn size(&self) -> (f64, f64) {
+        self.size
+    }
+
+    /// Gets the scale factor, the number of physical pixels of one logical pixel.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Gets the size of the framebuffer in physical pixels.
+    pub fn physical_size(&self) -> (u32, u32) {
+        physical_size(self.size.0, self.size.1, self.scale_factor)
+    }
+
+    /// Gets the position that is requested by the application or passed with `moved`.
+    pub fn position(&self) -> (f64, f64) {
+        self.position
+    }
+
+    /// Gets the mode that is requested by the application or passed with `mode_changed`.
+    pub fn mode(&self) -> WindowMode {
+        self.mode
+    }
+
+    /// Gets the icon of the window.
+    pub fn icon(&self) -> Option<&WindowIcon> {
+        self.icon.as_ref()
+    }
+
+    /// Gets the task bar progress that is requested by the application.
+    pub fn progress(&self) -> Option<f64> {
+        self.progress
+    }
+
+    /// Checks if the window is visible, it is hidden by the application with `WindowRequest::SetVisible`.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Gets the tray icon that is requested by the application.
+    pub fn tray_icon(&self) -> Option<&TrayIcon> {
+        self.tray_icon.as_ref()
+    }
+
+    /// Gets the pixels of the last drawn frame as premultiplied ARGB values, row by row from the top left corner.
+    pub fn framebuffer(&self) -> &[u32] {
+        &self.framebuffer
+    }
+
+    /// Gets the pixel of the last drawn frame at the given position in physical pixels.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<u32> {
+        let width = self.physical_size().0 as usize;
+
+        if x >= width {
+            return None;
+        }
+
+        self.framebuffer.get(y * width + x).copied()
+    }
+
+    /// Gets the title that is requested by the application.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Gets the cursor that is requested by the application.
+    pub fn cursor(&self) -> CursorIcon {
+        self.cursor
+    }
+
+    /// Gets the position of the candidate window of the input method editor that is requested by the application.
+    pub fn ime_position(&self) -> Option<Point> {
+        self.ime_position
+    }
+
+    // -- Events --
+
+    /// Resizes the window to the given size in logical pixels.
+    pub fn resize(&mut self, width: f64, height: f64) {
+        self.size = (width, height);
+        self.resize_framebuffer();
+        self.render_context.resize(width, height);
+        self.adapter.resize(width, height);
+        self.update = true;
+    }
+
+    /// Changes the scale factor, e.g. to simulate that the window is moved to a monitor with another DPI. The size
+    /// in logical pixels is kept.
+    pub fn scale_factor_changed(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.resize_framebuffer();
+        self.render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
+        self.update = true;
+    }
+
+    /// Changes the color scheme of the platform, e.g. to simulate that the user has switched to a dark mode.
+    pub fn color_scheme_changed(&mut self, color_scheme: ColorScheme) {
+        self.adapter.color_scheme_changed(color_scheme);
+        self.update = true;
+    }
+
+    /// Changes the simulated monitors, e.g. if a monitor is connected or disconnected.
+    pub fn monitors_changed(&mut self, monitors: Vec<Monitor>) {
+        self.adapter.monitors_changed(monitors);
+        self.update = true;
+    }
+
+    /// Moves the window to the given position on the screen.
+    pub fn moved(&mut self, x: f64, y: f64) {
+        self.position = (x, y);
+        self.adapter.moved(x, y);
+    }
+
+    /// Changes the mode of the window like the platform, e.g. if the user maximizes the window by its decorations.
+    pub fn mode_changed(&mut self, mode: WindowMode) {
+        self.mode = mode;
+        self.adapter.mode_changed(mode);
+        self.update = true;
+    }
+
+    /// Moves the mouse to the given position.
+    pub fn mouse(&mut self, x: f64, y: f64) {
+        self.adapter.mouse(x, y);
+        self.update = true;
+    }
+
+    /// Presses or releases a mouse button.
+    pub fn mouse_event(&mut self, event: MouseEvent) {
+        self.adapter.mouse_event(event);
+        self.update = true;
+    }
+
+    /// Scrolls by the given delta.
+    pub fn scroll(&mut self, delta: ScrollDelta) {
+        self.adapter.scroll(delta);
+        self.update = true;
+    }
+
+    /// Presses or releases a key.
+    pub fn key_event(&mut self, event: KeyEvent) {
+        self.adapter.key_event(event);
+        self.update = true;
+    }
+
+    /// Changes a touch point.
+    pub fn touch_event(&mut self, event: TouchEvent) {
+        self.adapter.touch_event(event);
+        self.update = true;
+    }
+
+    /// Changes the composition of the input method editor.
+    pub fn ime_event(&mut self, event: ImeEvent) {
+        self.adapter.ime_event(event);
+        self.update = true;
+    }
+
+    /// Drags data of another application over the window or drops it.
+    pub fn external_drag_event(&mut self, event: ExternalDragEvent) {
+        self.adapter.external_drag_event(event);
+        self.update = true;
+    }
+
+    /// Clicks on the tray icon or activates an item of its context menu. It is ignored if the window has no tray icon.
+    pub fn tray_event(&mut self, event: TrayEvent) {
+        if self.tray_icon.is_none() {
+            return;
+        }
+
+        self.adapter.tray_event(event);
+        self.update = true;
+    }
+
+    /// Activates or deactivates the window.
+    pub fn active(&mut self, active: bool) {
+        self.adapter.active(active);
+        self.update = true;
+    }
+
+    /// Receives window request from the application and handles them.
+    pub fn receive_requests(&mut self) {
+        if let Some(request_receiver) = &self.request_receiver {
+            for request in request_receiver.try_iter() {
+                match request {
+                    WindowRequest::Redraw => {
+                        self.update = true;
+                        self.redraw = true;
+                    }
+                    WindowRequest::RedrawAt(time) => {
+                        self.update_timer.request(time);
+                    }
+                    WindowRequest::ChangeTitle(title) => {
+                        self.title = title;
+                        self.update = true;
+                        self.redraw = true;
+                    }
+                    WindowRequest::ChangeCursor(cursor) => {
+                        self.cursor = cursor;
+                    }
+                    WindowRequest::SetImePosition(position) => {
+                        self.ime_position = Some(position);
+                    }
+                    WindowRequest::Close => {
+                        self.close = true;
+                    }
+                    WindowRequest::Resize(width, height) => {
+                        let (width, height) = self.constrain((width, height));
+                        self.resize(width, height);
+                    }
+                    WindowRequest::Move(x, y) => {
+                        self.moved(x, y);
+                    }
+                    WindowRequest::ChangeMode(mode) => {
+                        self.mode = mode;
+                    }
+                    WindowRequest::SetMinSize(size) => {
+                        self.min_size = size;
+                        self.resize_to_constraints();
+                    }
+                    WindowRequest::SetMaxSize(size) => {
+                        self.max_size = size;
+                        self.resize_to_constraints();
+                    }
+                    WindowRequest::SetIcon(icon) => {
+                        self.icon = icon;
+                    }
+                    WindowRequest::SetProgress(progress) => {
+                        self.progress = progress;
+                    }
+                    WindowRequest::SetVisible(visible) => {
+                        self.visible = visible;
+                    }
+                    WindowRequest::SetTrayIcon(tray_icon) => {
+                        self.tray_icon = tray_icon;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs update on the adapter.
+    pub fn update(&mut self) {
+        if self.update_timer.elapsed() {
+            self.update = true;
+        }
+
+        if !self.update {
+            return;
+        }
+        self.adapter.run(&mut self.render_context);
+        self.update = false;
+        self.redraw = true;
+    }
+
+    /// Waits until the render context has drawn the last frame and copies it to the framebuffer.
+    pub fn render(&mut self) {
+        if !self.redraw {
+            return;
+        }
+
+        if let Some(data) = self.render_context.wait_for_frame() {
+            if data.len() == self.framebuffer.len() {
+                self.framebuffer.copy_from_slice(data);
+            }
+        }
+
+        self.redraw = false;
+    }
+
+    fn resize_framebuffer(&mut self) {
+        let (width, height) = self.physical_size();
+        self.framebuffer = vec![0; width as usize * height as usize];
+    }
+
+    // clamps the size between the minimum and the maximum size
+    fn constrain(&self, (mut width, mut height): (f64, f64)) -> (f64, f64) {
+        if let Some((max_width, max_height)) = self.max_size {
+            width = width.min(max_width);
+            height = height.min(max_height);
+        }
+
+        if let Some((min_width, min_height)) = self.min_size {
+            width = width.max(min_width);
+            height = height.max(min_height);
+        }
+
+        (width, height)
+    }
+
+    fn resize_to_constraints(&mut self) {
+        let size = self.constrain(self.size);
+
+        if size != self.size {
+            self.resize(size.0, size.1);
+        }
+    }
+
+    /// Gets the time in milliseconds of `time::now` of the requested update timer.
+    pub fn next_update(&self) -> Option<f64> {
+        self.update_timer.time()
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashMap, sync::mpsc};
+
+use super::{Shell, Window};
+use crate::{
+    monitor::Monitor,
+    render::{RenderContext2D, DEFAULT_SCALE_FACTOR},
+    utils::Rectangle,
+    window_adapter::WindowAdapter,
+    WindowIcon, WindowRequest, WindowSettings,
+};
+
+/// The `WindowBuilder` is used to construct a window shell for the headless backend.
+pub struct WindowBuilder<'a, A: 'static>
+where
+    A: WindowAdapter,
+{
+    shell: &'a mut Shell<A>,
+    adapter: A,
+    title: String,
+    fonts: HashMap<String, &'static [u8]>,
+    bounds: Rectangle,
+    scale_factor: f64,
+    monitors: Vec<Monitor>,
+    icon: Option<WindowIcon>,
+    request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+}
+
+impl<'a, A> WindowBuilder<'a, A>
+where
+    A: WindowAdapter,
+{
+    /// Creates a new window builder.
+    pub fn new(shell: &'a mut Shell<A>, adapter: A) -> Self {
+        WindowBuilder {
+            shell,
+            adapter,
+            title: String::default(),
+            fonts: HashMap::new(),
+            bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            monitors: vec![],
+            icon: None,
+            request_receiver: None,
+        }
+    }
+
+    /// Creates the window builder from a settings object.
+    pub fn from_settings(settings: WindowSettings, shell: &'a mut Shell<A>, adapter: A) -> Self {
+        WindowBuilder {
+            shell,
+            adapter,
+            title: settings.title,
+            fonts: settings.fonts,
+            bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: DEFAULT_SCALE_FACTOR,
+            monitors: vec![],
+            icon: settings.icon,
+            request_receiver: None,
+        }
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets borderless. It has no effect without a platform window.
+    pub fn borderless(self, _borderless: bool) -> Self {
+        self
+    }
+
+    /// Sets resizeable. It has no effect without a platform window.
+    pub fn resizeable(self, _resizeable: bool) -> Self {
+        self
+    }
+
+    /// Sets always_on_top. It has no effect without a platform window.
+    pub fn always_on_top(self, _always_on_top: bool) -> Self {
+        self
+    }
+
+    /// Sets the icon.
+    pub fn icon(mut self, icon: WindowIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the bounds in logical pixels.
+    pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
+        self.bounds = bounds.into();
+        self
+    }
+
+    /// Sets the scale factor of the simulated monitor, the framebuffer has the size of the bounds multiplied with it.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Sets the simulated monitors, they are passed to the adapter on startup. Without monitors the adapter is not
+    /// informed like on shells that could not enumerate the monitors.
+    pub fn monitors(mut self, monitors: Vec<Monitor>) -> Self {
+        self.monitors = monitors;
+        self
+    }
+
+    /// Registers a new font with family key.
+    pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
+        self.fonts.insert(family.into(), font_file);
+        self
+    }
+
+    /// Register a window request receiver to communicate with the window shell from outside.
+    pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
+        self.request_receiver = Some(request_receiver);
+        self
+    }
+
+    /// Builds the window shell and add it to the application `Shell`.
+    pub fn build(mut self) {
+        let size = (self.bounds.width(), self.bounds.height());
+
+        let mut render_context = RenderContext2D::new(size.0, size.1);
+        render_context.set_scale_factor(self.scale_factor);
+
+        for (family, font) in self.fonts {
+            render_context.register_font(&family, font);
+        }
+
+        self.adapter.scale_factor_changed(self.scale_factor);
+        self.adapter.resize(size.0, size.1);
+
+        if !self.monitors.is_empty() {
+            self.adapter.monitors_changed(self.monitors);
+        }
+
+        self.shell.window_shells.push(Window::new(
+            self.adapter,
+            render_context,
+            self.request_receiver,
+            size,
+            self.scale_factor,
+            self.title,
+            self.icon,
+        ));
+    }
+}
@@ -27,20 +27,48 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod clipboard;
+pub mod color_scheme;
+pub mod desktop_notification;
 pub mod event;
+pub mod file_dialog;
+pub mod gesture;
+pub mod monitor;
 pub mod prelude;
+pub mod taskbar;
+pub mod time;
+pub mod tool_tip;
+pub mod tray;
+pub mod url;
 pub mod window_adapter;
 
 pub use orbtk_utils::prelude as utils;
 
-#[cfg(all(not(target_arch = "wasm32"), feature = "pfinder"))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "pfinder"),
+    not(feature = "gpu")
+))]
+pub mod headless;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+pub use self::headless as platform;
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "headless"),
+    any(feature = "pfinder", feature = "gpu", feature = "opengl")
+))]
 #[path = "glutin/mod.rs"]
 pub mod platform;
 
 #[cfg(all(
     not(target_arch = "wasm32"),
     feature = "default",
-    not(feature = "pfinder")
+    not(feature = "headless"),
+    not(feature = "pfinder"),
+    not(feature = "gpu"),
+    not(feature = "opengl")
 ))]
 #[path = "minifb/mod.rs"]
 pub mod platform;
@@ -56,17 +84,170 @@ pub use orbtk_render::prelude as render;
 
 use std::{collections::HashMap, sync::mpsc};
 
+use orbtk_utils::Point;
+
 /// Used to send a request to the window.
 #[derive(Clone, Debug)]
 pub enum WindowRequest {
     /// Request redraw of the `Windows`s content.
     Redraw,
 
+    /// Request a redraw of the `Windows`s content at the given time in milliseconds of `time::now`, e.g. if a timer
+    /// elapses. Until then the window is idle if nothing else changes.
+    RedrawAt(f64),
+
     /// Request to close the `Windows`.
     Close,
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to change the mouse cursor of the `Windows`.
+    ChangeCursor(CursorIcon),
+
+    /// Request to move the candidate window of the input method editor below the given position, e.g. the caret.
+    SetImePosition(Point),
+
+    /// Request to resize the inner size of the `Windows`s to the given width and height.
+    /// It is handled by the glutin shell, the minifb and web shells ignore it.
+    Resize(f64, f64),
+
+    /// Request to move the top left corner of the `Windows`s to the given position on the screen.
+    Move(f64, f64),
+
+    /// Request to minimize, maximize, restore the `Windows`s or to show it fullscreen.
+    /// It is handled by the glutin shell, the minifb and web shells ignore it.
+    ChangeMode(WindowMode),
+
+    /// Request to change the minimum inner size of the `Windows`, `None` removes the constraint.
+    /// It is handled by the glutin shell, the minifb and web shells ignore it.
+    SetMinSize(Option<(f64, f64)>),
+
+    /// Request to change the maximum inner size of the `Windows`, `None` removes the constraint.
+    /// It is handled by the glutin shell, the minifb and web shells ignore it.
+    SetMaxSize(Option<(f64, f64)>),
+
+    /// Request to change the icon of the `Windows`, `None` resets the default icon of the platform. It is shown by
+    /// the glutin shell and as favicon of the page by the web shell, the minifb shell ignores it.
+    SetIcon(Option<WindowIcon>),
+
+    /// Request to show the progress between `0.0` and `1.0` on the task bar entry of the `Windows`, `None` hides the
+    /// progress. It is shown by the glutin shell on Windows, other platforms and shells have no task bar progress and
+    /// ignore it.
+    SetProgress(Option<f64>),
+
+    /// Request to show or hide the `Windows`. A hidden window keeps running, e.g. to handle the events of its
+    /// `TrayIcon`.
+    SetVisible(bool),
+
+    /// Request to show the icon of the application in the system tray, `None` removes it. It is only shown by the
+    /// glutin shell with the `tray` feature, other shells ignore it.
+    SetTrayIcon(Option<tray::TrayIcon>),
+}
+
+/// Describes if a window is shown normal, minimized, maximized or fullscreen.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WindowMode {
+    /// The window is shown with its own size.
+    Normal,
+
+    /// The window is minimized to the task bar.
+    Minimized,
+
+    /// The window fills the work area of the screen.
+    Maximized,
+
+    /// The window covers the whole screen without decorations.
+    Fullscreen,
+}
+
+impl Default for WindowMode {
+    fn default() -> Self {
+        WindowMode::Normal
+    }
+}
+
+/// The icon of a window that is shown in its title bar and on the task bar. The pixels are stored like the data of an
+/// `Image` as ARGB colors row by row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WindowIcon {
+    /// The width of the icon in pixels.
+    pub width: u32,
+
+    /// The height of the icon in pixels.
+    pub height: u32,
+
+    /// The ARGB colors of the pixels.
+    pub data: Vec<u32>,
+}
+
+impl WindowIcon {
+    /// Creates a new window icon from ARGB colors.
+    pub fn new(width: u32, height: u32, data: Vec<u32>) -> Self {
+        WindowIcon {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Returns the pixels of the icon as RGBA bytes, the format that is expected by most platforms.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.data
+            .iter()
+            .flat_map(|pixel| {
+                vec![
+                    (pixel >> 16) as u8,
+                    (pixel >> 8) as u8,
+                    *pixel as u8,
+                    (pixel >> 24) as u8,
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Describes the mouse cursor that is shown over a window.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CursorIcon {
+    /// The default arrow cursor.
+    Default,
+
+    /// Cursor that indicates a horizontal resize.
+    ResizeHorizontal,
+
+    /// Cursor that indicates a vertical resize.
+    ResizeVertical,
+
+    /// Hand cursor that indicates a link.
+    Pointer,
+
+    /// I-beam cursor that indicates editable text.
+    Text,
+
+    /// Cursor that indicates a diagonal resize from the top left to the bottom right corner.
+    ResizeDiagonal,
+
+    /// Cursor that indicates a diagonal resize from the top right to the bottom left corner.
+    ResizeAntiDiagonal,
+
+    /// Cursor that indicates a move in all directions.
+    Move,
+
+    /// Cursor that indicates that the application is busy.
+    Wait,
+
+    /// Crosshair cursor that indicates a precise selection.
+    Crosshair,
+
+    /// Hides the cursor. It is not supported by the minifb shell, the default cursor is shown instead.
+    None,
+}
+
+impl Default for CursorIcon {
+    fn default() -> Self {
+        CursorIcon::Default
+    }
 }
 
 /// Used to send a request to the application shell.
@@ -112,4 +293,21 @@ pub struct WindowSettings {
 
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
+
+    /// The icon of the window, `None` uses the default icon of the platform.
+    pub icon: Option<WindowIcon>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_icon_to_rgba() {
+        let icon = WindowIcon::new(2, 1, vec![0xff_11_22_33, 0x80_00_00_ff]);
+        assert_eq!(
+            icon.to_rgba(),
+            vec![0x11, 0x22, 0x33, 0xff, 0x00, 0x00, 0xff, 0x80]
+        );
+    }
 }
@@ -1,6 +1,10 @@
 //! This module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
 
 pub use super::native::*;
 
@@ -75,6 +79,8 @@ where
                 return;
             }
 
+            let frame_start = Instant::now();
+
             for i in 0..self.window_shells.len() {
                 let mut remove = false;
                 if let Some(window_shell) = self.window_shells.get_mut(i) {
@@ -96,6 +102,14 @@ where
             }
 
             self.receive_requests();
+
+            // minifb could not wait for events, the events are polled once per frame. Idle windows are neither
+            // updated nor drawn.
+            if let Some(remaining) =
+                Duration::from_secs_f64(FRAME_INTERVAL / 1000.0).checked_sub(frame_start.elapsed())
+            {
+                thread::sleep(remaining);
+            }
         }
     }
 }
@@ -1,17 +1,22 @@
 use std::{cell::RefCell, rc::Rc, sync::mpsc};
 
-use super::{KeyState, MouseState, WindowState};
+use super::{KeyState, MouseState, WindowState, CONSOLE};
 use crate::{
-    event::{ButtonState, KeyEvent, MouseButton, MouseEvent},
+    color_scheme::ColorSchemeMonitor,
+    event::{ButtonState, KeyEvent, MouseButton, MouseEvent, ScrollDelta},
     render::RenderContext2D,
+    time::UpdateTimer,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowMode, WindowRequest,
 };
 
 use orbtk_utils::Point;
 
 /// Represents a wrapper for a minifb window. It handles events, propagate them to
 /// the window adapter and handles the update and render pipeline.
+///
+/// minifb could only move windows and change their title and cursor. Requests to resize or hide the window, to
+/// change its mode, size constraints or icon and to show a task bar progress are ignored and logged.
 pub struct Window<A>
 where
     A: WindowAdapter,
@@ -27,6 +32,8 @@ where
     close: bool,
     key_states: Vec<KeyState>,
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
+    color_scheme_monitor: ColorSchemeMonitor,
+    update_timer: UpdateTimer,
 }
 
 impl<A> Window<A>
@@ -53,6 +60,8 @@ where
             close: false,
             key_states,
             key_events,
+            color_scheme_monitor: ColorSchemeMonitor::new(),
+            update_timer: UpdateTimer::default(),
         }
     }
 }
@@ -81,6 +90,8 @@ where
             | minifb::Key::Right
             | minifb::Key::Up
             | minifb::Key::Down
+            | minifb::Key::PageUp
+            | minifb::Key::PageDown
             | minifb::Key::Backspace
             | minifb::Key::Delete => minifb::KeyRepeat::Yes,
             _ => minifb::KeyRepeat::No,
@@ -170,7 +181,8 @@ where
 
         // scroll
         if let Some(delta) = self.window.get_scroll_wheel() {
-            self.adapter.scroll(delta.0 as f64, delta.1 as f64);
+            self.adapter
+                .scroll(ScrollDelta::from_pixels(delta.0 as f64, delta.1 as f64));
             self.update = true;
         }
 
@@ -193,6 +205,11 @@ where
             self.window_state.active = self.window.is_active();
         }
 
+        if let Some(color_scheme) = self.color_scheme_monitor.poll() {
+            self.adapter.color_scheme_changed(color_scheme);
+            self.update = true;
+        }
+
         // keys
         while let Some(event) = self.key_events.borrow_mut().pop() {
             self.adapter.key_event(event);
@@ -219,14 +236,74 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::RedrawAt(time) => {
+                        self.update_timer.request(time);
+                    }
                     WindowRequest::ChangeTitle(title) => {
                         self.window.set_title(&title);
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeCursor(cursor) => {
+                        self.window.set_cursor_style(match cursor {
+                            CursorIcon::Default | CursorIcon::Wait | CursorIcon::None => {
+                                minifb::CursorStyle::Arrow
+                            }
+                            CursorIcon::ResizeHorizontal => minifb::CursorStyle::ResizeLeftRight,
+                            CursorIcon::ResizeVertical => minifb::CursorStyle::ResizeUpDown,
+                            CursorIcon::Pointer => minifb::CursorStyle::OpenHand,
+                            CursorIcon::Text => minifb::CursorStyle::Ibeam,
+                            CursorIcon::ResizeDiagonal
+                            | CursorIcon::ResizeAntiDiagonal
+                            | CursorIcon::Move => minifb::CursorStyle::ResizeAll,
+                            CursorIcon::Crosshair => minifb::CursorStyle::Crosshair,
+                        });
+                    }
+                    // minifb does not support input method editors
+                    WindowRequest::SetImePosition(_) => {}
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::Move(x, y) => {
+                        self.window.set_position(x as isize, y as isize);
+                    }
+                    // requests that already match the window are skipped, the others are not supported by minifb
+                    WindowRequest::Resize(_, _) => log_ignored("resize"),
+                    WindowRequest::ChangeMode(mode) => {
+                        if mode != WindowMode::Normal {
+                            log_ignored("window mode");
+                        }
+                    }
+                    WindowRequest::SetMinSize(size) => {
+                        if size.is_some() {
+                            log_ignored("minimum size");
+                        }
+                    }
+                    WindowRequest::SetMaxSize(size) => {
+                        if size.is_some() {
+                            log_ignored("maximum size");
+                        }
+                    }
+                    WindowRequest::SetIcon(icon) => {
+                        if icon.is_some() {
+                            log_ignored("icon");
+                        }
+                    }
+                    WindowRequest::SetProgress(progress) => {
+                        if progress.is_some() {
+                            log_ignored("task bar progress");
+                        }
+                    }
+                    WindowRequest::SetVisible(visible) => {
+                        if !visible {
+                            log_ignored("hiding");
+                        }
+                    }
+                    WindowRequest::SetTrayIcon(tray_icon) => {
+                        if tray_icon.is_some() {
+                            CONSOLE.log("Window: the tray icon is ignored, the system tray is only supported by the glutin shell with the tray feature");
+                        }
+                    }
                 }
             }
         }
@@ -235,6 +312,10 @@ where
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         //super::CONSOLE.time("complete");
+        if self.update_timer.elapsed() {
+            self.update = true;
+        }
+
         if !self.update {
             return;
         }
@@ -259,3 +340,13 @@ where
         }
     }
 }
+
+// -- Helpers --
+
+// Logs a window request that is not supported by minifb.
+fn log_ignored(request: &str) {
+    CONSOLE.log(format!(
+        "Window: the {} request is ignored, it is not supported by the minifb shell",
+        request
+    ));
+}
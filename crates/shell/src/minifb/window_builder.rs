@@ -1,4 +1,4 @@
-use std::{cell::RefCell, char, collections::HashMap, rc::Rc, sync::mpsc, time::Duration};
+use std::{cell::RefCell, char, collections::HashMap, rc::Rc, sync::mpsc};
 
 use super::{KeyState, Shell, Window};
 use crate::{
@@ -122,8 +122,8 @@ where
             panic!("{}", e);
         });
 
-        // Limit to max ~60 fps update rate
-        window.limit_update_rate(Some(Duration::from_micros(16600)));
+        // the shell paces its loop itself
+        window.limit_update_rate(None);
 
         let key_events = Rc::new(RefCell::new(vec![]));
 
@@ -152,6 +152,7 @@ where
                 KeyState::new(minifb::Key::Down, Key::Down),
                 KeyState::new(minifb::Key::Delete, Key::Delete),
                 KeyState::new(minifb::Key::Enter, Key::Enter),
+                KeyState::new(minifb::Key::Tab, Key::Tab),
                 KeyState::new(minifb::Key::LeftCtrl, Key::Control),
                 KeyState::new(minifb::Key::RightCtrl, Key::Control),
                 KeyState::new(minifb::Key::LeftShift, Key::ShiftL),
@@ -160,6 +161,9 @@ where
                 KeyState::new(minifb::Key::RightAlt, Key::Alt),
                 KeyState::new(minifb::Key::Escape, Key::Escape),
                 KeyState::new(minifb::Key::Home, Key::Home),
+                KeyState::new(minifb::Key::End, Key::End),
+                KeyState::new(minifb::Key::PageUp, Key::PageUp),
+                KeyState::new(minifb::Key::PageDown, Key::PageDown),
                 KeyState::new(minifb::Key::NumPad0, Key::Numpad0),
                 KeyState::new(minifb::Key::NumPad1, Key::Numpad1),
                 KeyState::new(minifb::Key::NumPad2, Key::Numpad2),
@@ -212,6 +216,7 @@ impl KeyInputCallBack {
             || key == Key::Home
             || key == Key::Escape
             || key == Key::Delete
+            || key == Key::Tab
         {
             return;
         }
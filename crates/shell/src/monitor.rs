@@ -0,0 +1,240 @@
+//! This module contains the description of the monitors of the platform and helpers to place windows on them.
+
+use std::cmp::Ordering;
+
+use crate::utils::{Point, Rectangle};
+
+/// Describes a monitor that is connected to the platform. The bounds are given in logical pixels of the scale factor
+/// of the monitor, the top left corner of the primary monitor is usually at `(0.0, 0.0)`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, it is empty if the platform provides no name.
+    pub name: String,
+
+    /// The position and the resolution of the monitor.
+    pub bounds: Rectangle,
+
+    /// The area of the monitor that is not covered by task bars and docks. Platforms that don't provide the work area
+    /// use the bounds of the monitor.
+    pub work_area: Rectangle,
+
+    /// The number of physical pixels of one logical pixel.
+    pub scale_factor: f64,
+
+    /// Is it the primary monitor of the platform?
+    pub primary: bool,
+}
+
+impl Monitor {
+    /// Creates a monitor with the given name and bounds, the work area covers the whole monitor.
+    pub fn new(name: impl Into<String>, bounds: impl Into<Rectangle>) -> Self {
+        let bounds = bounds.into();
+
+        Monitor {
+            name: name.into(),
+            bounds,
+            work_area: bounds,
+            scale_factor: 1.0,
+            primary: false,
+        }
+    }
+
+    /// Sets the work area.
+    pub fn work_area(mut self, work_area: impl Into<Rectangle>) -> Self {
+        self.work_area = work_area.into();
+        self
+    }
+
+    /// Sets the scale factor.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Marks the monitor as primary monitor.
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
+    }
+}
+
+/// Gets the index of the monitor the window with the given bounds is shown on, that is the monitor that contains the
+/// largest part of the window. If the window is outside of all monitors the nearest monitor is returned. Returns
+/// `None` if the list of monitors is empty.
+pub fn window_monitor(monitors: &[Monitor], window_bounds: Rectangle) -> Option<usize> {
+    let center = Point::new(
+        window_bounds.x() + window_bounds.width() / 2.0,
+        window_bounds.y() + window_bounds.height() / 2.0,
+    );
+
+    monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| {
+            (
+                index,
+                intersection_area(monitor.bounds, window_bounds),
+                distance(monitor.bounds, center),
+            )
+        })
+        // the largest part of the window, otherwise the shortest distance
+        .max_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap_or(Ordering::Equal)
+                .then(b.2.partial_cmp(&a.2).unwrap_or(Ordering::Equal))
+        })
+        .map(|(index, _, _)| index)
+}
+
+/// Places the window with the given bounds inside of the work area of the monitor it is shown on, e.g. to restore a
+/// saved window position after a monitor was disconnected. The window is moved into the work area and shrunk if it
+/// is larger than the work area. The bounds are returned unchanged if the list of monitors is empty.
+pub fn place_window(monitors: &[Monitor], window_bounds: Rectangle) -> Rectangle {
+    match window_monitor(monitors, window_bounds) {
+        Some(index) => fit_into(window_bounds, monitors[index].work_area),
+        None => window_bounds,
+    }
+}
+
+/// Centers a window with the given size over the bounds of its owner and keeps it inside of the work area of the
+/// monitor the owner is shown on, the window is opened on the same screen as its owner.
+pub fn place_owned_window(
+    monitors: &[Monitor],
+    owner_bounds: Rectangle,
+    size: (f64, f64),
+) -> Rectangle {
+    let bounds = Rectangle::new(
+        (
+            owner_bounds.x() + (owner_bounds.width() - size.0) / 2.0,
+            owner_bounds.y() + (owner_bounds.height() - size.1) / 2.0,
+        ),
+        size,
+    );
+
+    match window_monitor(monitors, owner_bounds) {
+        Some(index) => fit_into(bounds, monitors[index].work_area),
+        None => bounds,
+    }
+}
+
+// -- Helpers --
+
+fn fit_into(bounds: Rectangle, area: Rectangle) -> Rectangle {
+    let width = bounds.width().min(area.width());
+    let height = bounds.height().min(area.height());
+
+    let x = bounds
+        .x()
+        .min(area.x() + area.width() - width)
+        .max(area.x());
+    let y = bounds
+        .y()
+        .min(area.y() + area.height() - height)
+        .max(area.y());
+
+    Rectangle::new((x, y), (width, height))
+}
+
+fn intersection_area(a: Rectangle, b: Rectangle) -> f64 {
+    let width = (a.x() + a.width()).min(b.x() + b.width()) - a.x().max(b.x());
+    let height = (a.y() + a.height()).min(b.y() + b.height()) - a.y().max(b.y());
+
+    width.max(0.0) * height.max(0.0)
+}
+
+// the distance of the point to the nearest point of the rectangle
+fn distance(rectangle: Rectangle, point: Point) -> f64 {
+    let dx = (rectangle.x() - point.x())
+        .max(point.x() - rectangle.x() - rectangle.width())
+        .max(0.0);
+    let dy = (rectangle.y() - point.y())
+        .max(point.y() - rectangle.y() - rectangle.height())
+        .max(0.0);
+
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitors() -> Vec<Monitor> {
+        vec![
+            Monitor::new("left", (0.0, 0.0, 1920.0, 1080.0))
+                .work_area((0.0, 0.0, 1920.0, 1040.0))
+                .primary(true),
+            Monitor::new("right", (1920.0, 0.0, 1280.0, 1024.0)).scale_factor(2.0),
+        ]
+    }
+
+    #[test]
+    fn test_window_monitor() {
+        let monitors = monitors();
+
+        assert_eq!(
+            window_monitor(&[], Rectangle::new((0.0, 0.0), (100.0, 100.0))),
+            None
+        );
+        assert_eq!(
+            window_monitor(&monitors, Rectangle::new((100.0, 100.0), (400.0, 300.0))),
+            Some(0)
+        );
+
+        // the larger part of the window is on the right monitor
+        assert_eq!(
+            window_monitor(&monitors, Rectangle::new((1820.0, 100.0), (400.0, 300.0))),
+            Some(1)
+        );
+
+        // the window is outside of all monitors
+        assert_eq!(
+            window_monitor(&monitors, Rectangle::new((4000.0, 100.0), (400.0, 300.0))),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_place_window() {
+        let monitors = monitors();
+
+        let bounds = Rectangle::new((100.0, 100.0), (400.0, 300.0));
+        assert_eq!(place_window(&monitors, bounds), bounds);
+        assert_eq!(place_window(&[], bounds), bounds);
+
+        // the window is moved above the task bar
+        assert_eq!(
+            place_window(&monitors, Rectangle::new((100.0, 900.0), (400.0, 300.0))),
+            Rectangle::new((100.0, 740.0), (400.0, 300.0))
+        );
+
+        // the monitor of the saved position is disconnected
+        assert_eq!(
+            place_window(&monitors, Rectangle::new((5000.0, -200.0), (2000.0, 300.0))),
+            Rectangle::new((1920.0, 0.0), (1280.0, 300.0))
+        );
+    }
+
+    #[test]
+    fn test_place_owned_window() {
+        let monitors = monitors();
+
+        assert_eq!(
+            place_owned_window(
+                &monitors,
+                Rectangle::new((2000.0, 100.0), (600.0, 400.0)),
+                (200.0, 100.0)
+            ),
+            Rectangle::new((2200.0, 250.0), (200.0, 100.0))
+        );
+
+        // the owner is at the right edge of its monitor
+        assert_eq!(
+            place_owned_window(
+                &monitors,
+                Rectangle::new((2900.0, 100.0), (300.0, 400.0)),
+                (400.0, 100.0)
+            ),
+            Rectangle::new((2800.0, 250.0), (400.0, 100.0))
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    clipboard::*, color_scheme::*, desktop_notification::*, event::*, file_dialog::*, gesture::*,
+    monitor::*, platform::*, time::*, tool_tip::*, tray::*, url::*, window_adapter::*, CursorIcon,
+    ShellRequest, WindowIcon, WindowMode, WindowRequest, WindowSettings,
 };
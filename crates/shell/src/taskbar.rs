@@ -0,0 +1,141 @@
+//! This module contains the progress on the task bar entry of a window. It is shown on Windows, the task bars of
+//! other platforms have no common progress integration.
+
+use raw_window_handle::RawWindowHandle;
+
+/// Shows the progress of a window on its task bar entry. The task bar of the platform is connected on the first
+/// progress.
+#[derive(Default)]
+pub struct TaskbarProgress {
+    #[cfg(target_os = "windows")]
+    taskbar: Option<platform::Taskbar>,
+}
+
+impl TaskbarProgress {
+    /// Shows the progress between `0.0` and `1.0` on the task bar entry of the window with the given handle, `None`
+    /// hides the progress.
+    ///
+    /// Returns an error if the task bar is not available or the platform is not Windows.
+    #[cfg(target_os = "windows")]
+    pub fn set_progress(
+        &mut self,
+        window: RawWindowHandle,
+        progress: Option<f64>,
+    ) -> Result<(), String> {
+        let hwnd = match window {
+            RawWindowHandle::Windows(handle) => handle.hwnd,
+            _ => return Err("TaskbarProgress::set_progress: the window has no hwnd".to_string()),
+        };
+
+        if self.taskbar.is_none() {
+            self.taskbar = Some(platform::Taskbar::new()?);
+        }
+
+        if let Some(taskbar) = &self.taskbar {
+            taskbar.set_progress(hwnd, progress.map(|p| p.clamp(0.0, 1.0)));
+        }
+
+        Ok(())
+    }
+
+    /// Shows the progress between `0.0` and `1.0` on the task bar entry of the window with the given handle, `None`
+    /// hides the progress.
+    ///
+    /// Returns an error if the task bar is not available or the platform is not Windows.
+    #[cfg(not(target_os = "windows"))]
+    pub fn set_progress(
+        &mut self,
+        _window: RawWindowHandle,
+        _progress: Option<f64>,
+    ) -> Result<(), String> {
+        Err(
+            "TaskbarProgress::set_progress: the task bar progress is only supported on Windows"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::{ffi::c_void, ptr};
+
+    use winapi::{
+        shared::{windef::HWND, winerror::SUCCEEDED, wtypesbase::CLSCTX_INPROC_SERVER},
+        um::{
+            combaseapi::{CoCreateInstance, CoInitializeEx},
+            objbase::COINIT_APARTMENTTHREADED,
+            shobjidl_core::{CLSID_TaskbarList, ITaskbarList3, TBPF_NOPROGRESS, TBPF_NORMAL},
+        },
+        Interface,
+    };
+
+    // the number of steps of the progress that is passed to the task bar
+    const PROGRESS_STEPS: u64 = 1000;
+
+    // The task bar list of the Windows shell.
+    pub struct Taskbar {
+        list: *mut ITaskbarList3,
+    }
+
+    impl Taskbar {
+        pub fn new() -> Result<Self, String> {
+            let mut list: *mut ITaskbarList3 = ptr::null_mut();
+
+            unsafe {
+                // COM is usually initialized by the window library, then the call has no effect
+                CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+                let result = CoCreateInstance(
+                    &CLSID_TaskbarList,
+                    ptr::null_mut(),
+                    CLSCTX_INPROC_SERVER,
+                    &ITaskbarList3::uuidof(),
+                    &mut list as *mut *mut ITaskbarList3 as *mut *mut winapi::ctypes::c_void,
+                );
+
+                if !SUCCEEDED(result) || list.is_null() {
+                    return Err(
+                        "Taskbar::new: could not connect to the task bar of Windows".to_string()
+                    );
+                }
+
+                if !SUCCEEDED((*list).HrInit()) {
+                    (*list).Release();
+                    return Err(
+                        "Taskbar::new: could not initialize the task bar of Windows".to_string()
+                    );
+                }
+            }
+
+            Ok(Taskbar { list })
+        }
+
+        pub fn set_progress(&self, hwnd: *mut c_void, progress: Option<f64>) {
+            let hwnd = hwnd as HWND;
+
+            unsafe {
+                match progress {
+                    Some(progress) => {
+                        (*self.list).SetProgressState(hwnd, TBPF_NORMAL);
+                        (*self.list).SetProgressValue(
+                            hwnd,
+                            (progress * PROGRESS_STEPS as f64).round() as u64,
+                            PROGRESS_STEPS,
+                        );
+                    }
+                    None => {
+                        (*self.list).SetProgressState(hwnd, TBPF_NOPROGRESS);
+                    }
+                }
+            }
+        }
+    }
+
+    impl Drop for Taskbar {
+        fn drop(&mut self) {
+            unsafe {
+                (*self.list).Release();
+            }
+        }
+    }
+}
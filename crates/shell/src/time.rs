@@ -0,0 +1,76 @@
+//! This module contains a platform independent clock that could be used to drive timers and animations.
+
+/// The time in milliseconds between two frames while a window is updated continuously, e.g. by an animation.
+pub const FRAME_INTERVAL: f64 = 1000.0 / 60.0;
+
+/// Returns the current time in milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64() * 1000.0)
+        .unwrap_or_default()
+}
+
+/// Returns the current time in milliseconds.
+#[cfg(target_arch = "wasm32")]
+pub fn now() -> f64 {
+    stdweb::web::Date::now()
+}
+
+/// The `UpdateTimer` keeps the earliest requested update time of a window. An idle window sleeps until the time is
+/// elapsed or an event arrives instead of checking its timers on each frame.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct UpdateTimer {
+    time: Option<f64>,
+}
+
+impl UpdateTimer {
+    /// Requests an update at the given time in milliseconds of `now`. The earliest requested time wins.
+    pub fn request(&mut self, time: f64) {
+        self.time = Some(self.time.map_or(time, |current| current.min(time)));
+    }
+
+    /// Gets the requested update time.
+    pub fn time(&self) -> Option<f64> {
+        self.time
+    }
+
+    /// Returns `true` once if the requested update time is elapsed.
+    pub fn elapsed(&mut self) -> bool {
+        self.elapsed_at(now())
+    }
+
+    fn elapsed_at(&mut self, now: f64) -> bool {
+        match self.time {
+            Some(time) if time <= now => {
+                self.time = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_timer() {
+        let mut timer = UpdateTimer::default();
+        assert!(!timer.elapsed_at(100.0));
+
+        timer.request(500.0);
+        timer.request(300.0);
+        timer.request(400.0);
+        assert_eq!(timer.time(), Some(300.0));
+
+        assert!(!timer.elapsed_at(200.0));
+        assert!(timer.elapsed_at(300.0));
+        assert!(!timer.elapsed_at(400.0));
+        assert_eq!(timer.time(), None);
+    }
+}
@@ -0,0 +1,170 @@
+//! This module contains the global tool tip service that handles the timing and positioning of tool tips.
+
+use orbtk_utils::{Point, Size};
+
+use crate::time::now;
+
+/// The default delay in milliseconds until a tool tip is shown.
+pub const DEFAULT_TOOL_TIP_DELAY: f64 = 500.0;
+
+/// The vertical distance between the mouse pointer and the tool tip.
+pub const TOOL_TIP_OFFSET: f64 = 20.0;
+
+/// Describes the current status of the `ToolTipService`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ToolTipStatus {
+    /// No target with a tool tip is hovered.
+    Hidden,
+
+    /// A target is hovered, but the delay is not elapsed yet.
+    Pending,
+
+    /// The tool tip of the given target should be shown at the given mouse position.
+    Visible(u32, Point),
+}
+
+/// The `ToolTipService` tracks the hovered tool tip target of a window. It decides when a tool tip should be shown
+/// and where it should be placed.
+#[derive(Clone, Debug)]
+pub struct ToolTipService {
+    delay: f64,
+    target: Option<u32>,
+    position: Point,
+    hover_start: f64,
+}
+
+impl Default for ToolTipService {
+    fn default() -> Self {
+        ToolTipService::new(DEFAULT_TOOL_TIP_DELAY)
+    }
+}
+
+impl ToolTipService {
+    /// Creates a new tool tip service with the given delay in milliseconds.
+    pub fn new(delay: f64) -> Self {
+        ToolTipService {
+            delay,
+            target: None,
+            position: Point::default(),
+            hover_start: 0.0,
+        }
+    }
+
+    /// Gets the delay in milliseconds until a tool tip is shown.
+    pub fn delay(&self) -> f64 {
+        self.delay
+    }
+
+    /// Sets the delay in milliseconds until a tool tip is shown.
+    pub fn set_delay(&mut self, delay: f64) {
+        self.delay = delay;
+    }
+
+    /// Updates the hovered target and the mouse position. Use `None` if the pointer leaves all tool tip targets.
+    pub fn hover(&mut self, target: Option<u32>, position: Point) {
+        self.hover_at(target, position, now());
+    }
+
+    /// Gets the current status of the tool tip.
+    pub fn status(&self) -> ToolTipStatus {
+        self.status_at(now())
+    }
+
+    /// Gets the time in milliseconds of `time::now` when the tool tip of the hovered target is shown. Returns `None`
+    /// if no target is hovered.
+    pub fn show_time(&self) -> Option<f64> {
+        self.target.map(|_| self.hover_start + self.delay)
+    }
+
+    /// Calculates the position of a tool tip with the given size. The tool tip is placed below the mouse pointer
+    /// and kept inside of the window.
+    pub fn position(&self, size: Size, window_size: Size) -> Point {
+        let mut x = self.position.x();
+        let mut y = self.position.y() + TOOL_TIP_OFFSET;
+
+        if x + size.width() > window_size.width() {
+            x = window_size.width() - size.width();
+        }
+
+        if y + size.height() > window_size.height() {
+            y = self.position.y() - size.height();
+        }
+
+        Point::new(x.max(0.0), y.max(0.0))
+    }
+
+    fn hover_at(&mut self, target: Option<u32>, position: Point, now: f64) {
+        if target != self.target {
+            self.target = target;
+            self.hover_start = now;
+            self.position = position;
+            return;
+        }
+
+        // the tool tip keeps its position after it is shown
+        if self.status_at(now) == ToolTipStatus::Pending {
+            self.position = position;
+        }
+    }
+
+    fn status_at(&self, now: f64) -> ToolTipStatus {
+        match self.target {
+            Some(target) if now - self.hover_start >= self.delay => {
+                ToolTipStatus::Visible(target, self.position)
+            }
+            Some(_) => ToolTipStatus::Pending,
+            None => ToolTipStatus::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status() {
+        let mut service = ToolTipService::new(500.0);
+        assert_eq!(service.status_at(0.0), ToolTipStatus::Hidden);
+
+        service.hover_at(Some(1), Point::new(10.0, 10.0), 100.0);
+        assert_eq!(service.status_at(200.0), ToolTipStatus::Pending);
+        assert_eq!(service.show_time(), Some(600.0));
+        assert_eq!(
+            service.status_at(600.0),
+            ToolTipStatus::Visible(1, Point::new(10.0, 10.0))
+        );
+
+        // moving inside of the shown target keeps the position
+        service.hover_at(Some(1), Point::new(20.0, 20.0), 700.0);
+        assert_eq!(
+            service.status_at(700.0),
+            ToolTipStatus::Visible(1, Point::new(10.0, 10.0))
+        );
+
+        // a new target restarts the delay
+        service.hover_at(Some(2), Point::new(30.0, 30.0), 800.0);
+        assert_eq!(service.status_at(900.0), ToolTipStatus::Pending);
+
+        service.hover_at(None, Point::new(40.0, 40.0), 1000.0);
+        assert_eq!(service.status_at(2000.0), ToolTipStatus::Hidden);
+    }
+
+    #[test]
+    fn test_position() {
+        let mut service = ToolTipService::new(0.0);
+        let window_size = Size::new(200.0, 100.0);
+
+        service.hover_at(Some(1), Point::new(10.0, 10.0), 0.0);
+        assert_eq!(
+            service.position(Size::new(50.0, 20.0), window_size),
+            Point::new(10.0, 30.0)
+        );
+
+        service.hover_at(Some(2), Point::new(180.0, 90.0), 0.0);
+        assert_eq!(
+            service.position(Size::new(50.0, 20.0), window_size),
+            Point::new(150.0, 70.0)
+        );
+    }
+}
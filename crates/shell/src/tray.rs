@@ -0,0 +1,225 @@
+//! This module contains the icon of an application in the system tray (notification area) with its tool tip and
+//! context menu.
+
+use crate::{event::MouseButton, WindowIcon};
+
+/// Describes an entry of the context menu of a `TrayIcon`. The entries are usually built from the `MenuItem`, `Menu`
+/// and `MenuSeparator` widgets of a `ContextMenu` with `tray_menu`, an activated item is passed as
+/// `TrayEvent::MenuItem` with its id.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrayMenuItem {
+    /// An item that could be activated.
+    Item {
+        /// The id that is passed with the activation.
+        id: String,
+
+        /// The displayed text.
+        text: String,
+
+        /// Is the item checkable?
+        checkable: bool,
+
+        /// Is the item checked?
+        checked: bool,
+
+        /// Could the item be activated?
+        enabled: bool,
+    },
+
+    /// A line between two groups of items.
+    Separator,
+
+    /// A sub menu with its own items.
+    Submenu {
+        /// The displayed text.
+        text: String,
+
+        /// The items of the sub menu.
+        items: Vec<TrayMenuItem>,
+    },
+}
+
+impl TrayMenuItem {
+    /// Creates an enabled item.
+    pub fn item(id: impl Into<String>, text: impl Into<String>) -> Self {
+        TrayMenuItem::Item {
+            id: id.into(),
+            text: text.into(),
+            checkable: false,
+            checked: false,
+            enabled: true,
+        }
+    }
+
+    /// Creates an enabled item that could be checked.
+    pub fn checkable(id: impl Into<String>, text: impl Into<String>, checked: bool) -> Self {
+        TrayMenuItem::Item {
+            id: id.into(),
+            text: text.into(),
+            checkable: true,
+            checked,
+            enabled: true,
+        }
+    }
+
+    /// Creates a separator.
+    pub fn separator() -> Self {
+        TrayMenuItem::Separator
+    }
+
+    /// Creates a sub menu with the given items.
+    pub fn submenu(text: impl Into<String>, items: Vec<TrayMenuItem>) -> Self {
+        TrayMenuItem::Submenu {
+            text: text.into(),
+            items,
+        }
+    }
+
+    /// Enables or disables the item, it has no effect on separators and sub menus.
+    pub fn enabled(mut self, value: bool) -> Self {
+        if let TrayMenuItem::Item { enabled, .. } = &mut self {
+            *enabled = value;
+        }
+
+        self
+    }
+}
+
+/// Describes an interaction of the user with a `TrayIcon`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrayEvent {
+    /// The icon is clicked with the given mouse button.
+    Click(MouseButton),
+
+    /// The icon is double clicked with the left mouse button.
+    DoubleClick,
+
+    /// The item of the context menu with the given id is activated.
+    MenuItem(String),
+}
+
+/// The `TrayIcon` describes the icon of an application in the system tray with its tool tip and context menu. It is
+/// shown with `WindowRequest::SetTrayIcon`, its events are passed to `WindowAdapter::tray_event` of the window that
+/// requested it. The tray icon keeps the application running if the window is hidden.
+///
+/// The system tray is supported by the glutin shell with the `tray` feature on Windows, macOS and Linux, on Linux it
+/// needs gtk and libappindicator. The tool tip is not shown on Linux. Other shells ignore the tray icon.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let tray_icon = TrayIcon::new()
+///     .tool_tip("OrbTk")
+///     .menu(vec![
+///         TrayMenuItem::item("show", "Show window"),
+///         TrayMenuItem::separator(),
+///         TrayMenuItem::item("quit", "Quit"),
+///     ]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrayIcon {
+    /// The icon, `None` uses the default icon of the platform.
+    pub icon: Option<WindowIcon>,
+
+    /// The tool tip that is shown over the icon.
+    pub tool_tip: String,
+
+    /// The items of the context menu.
+    pub menu: Vec<TrayMenuItem>,
+}
+
+impl TrayIcon {
+    /// Creates a tray icon without icon, tool tip and context menu.
+    pub fn new() -> Self {
+        TrayIcon::default()
+    }
+
+    /// Sets the icon.
+    pub fn icon(mut self, icon: WindowIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the tool tip.
+    pub fn tool_tip(mut self, tool_tip: impl Into<String>) -> Self {
+        self.tool_tip = tool_tip.into();
+        self
+    }
+
+    /// Sets the items of the context menu.
+    pub fn menu(mut self, menu: Vec<TrayMenuItem>) -> Self {
+        self.menu = menu;
+        self
+    }
+
+    /// Checks or unchecks the checkable item with the given id in the context menu and its sub menus. Returns `false`
+    /// if there is no checkable item with the id.
+    pub fn set_checked(&mut self, id: &str, value: bool) -> bool {
+        set_checked(&mut self.menu, id, value)
+    }
+}
+
+// -- Helpers --
+
+fn set_checked(items: &mut [TrayMenuItem], id: &str, value: bool) -> bool {
+    items.iter_mut().any(|item| match item {
+        TrayMenuItem::Item {
+            id: item_id,
+            checkable: true,
+            checked,
+            ..
+        } if item_id == id => {
+            *checked = value;
+            true
+        }
+        TrayMenuItem::Submenu { items, .. } => set_checked(items, id, value),
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_checked() {
+        let mut tray_icon = TrayIcon::new().menu(vec![
+            TrayMenuItem::item("show", "Show"),
+            TrayMenuItem::separator(),
+            TrayMenuItem::submenu(
+                "Options",
+                vec![TrayMenuItem::checkable("autostart", "Autostart", false)],
+            ),
+        ]);
+
+        assert!(tray_icon.set_checked("autostart", true));
+        assert!(!tray_icon.set_checked("show", true));
+        assert!(!tray_icon.set_checked("missing", true));
+
+        assert_eq!(
+            tray_icon.menu[2],
+            TrayMenuItem::submenu(
+                "Options",
+                vec![TrayMenuItem::checkable("autostart", "Autostart", true)],
+            )
+        );
+    }
+
+    #[test]
+    fn test_enabled() {
+        assert_eq!(
+            TrayMenuItem::item("quit", "Quit").enabled(false),
+            TrayMenuItem::Item {
+                id: "quit".to_string(),
+                text: "Quit".to_string(),
+                checkable: false,
+                checked: false,
+                enabled: false,
+            }
+        );
+        assert_eq!(
+            TrayMenuItem::separator().enabled(false),
+            TrayMenuItem::Separator
+        );
+    }
+}
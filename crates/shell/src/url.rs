@@ -0,0 +1,77 @@
+//! This module contains the access to the browser of the platform.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::process::Command;
+
+/// Opens the given url in the default browser of the platform. On web the url is opened in a new browser tab.
+///
+/// Returns an error if the browser could not be started.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open_url(url: &str) -> Result<(), String> {
+    browser_command(url)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("open_url: could not open {}: {}", url, e))
+}
+
+/// Opens the given url in the default browser of the platform. On web the url is opened in a new browser tab.
+///
+/// Returns an error if the browser could not be started.
+#[cfg(target_arch = "wasm32")]
+pub fn open_url(url: &str) -> Result<(), String> {
+    use stdweb::js;
+
+    js! {
+        window.open(@{url}, "_blank");
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn browser_command(url: &str) -> Command {
+    let mut command = Command::new("rundll32");
+    command.args(&["url.dll,FileProtocolHandler", url]);
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn browser_command(url: &str) -> Command {
+    let mut command = Command::new("open");
+    command.arg(url);
+    command
+}
+
+#[cfg(target_os = "redox")]
+fn browser_command(url: &str) -> Command {
+    let mut command = Command::new("launcher");
+    command.arg(url);
+    command
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(target_os = "windows"),
+    not(target_os = "macos"),
+    not(target_os = "redox")
+))]
+fn browser_command(url: &str) -> Command {
+    let mut command = Command::new("xdg-open");
+    command.arg(url);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_browser_command() {
+        let url = "https://example.com/?a=1&b=2";
+        let command = browser_command(url);
+
+        // the url is passed as one argument without a shell
+        assert_eq!(command.get_args().last(), Some(std::ffi::OsStr::new(url)));
+    }
+}
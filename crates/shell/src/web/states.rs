@@ -2,6 +2,11 @@ use std::{cell::RefCell, rc::Rc};
 
 use stdweb::web::event;
 
+use crate::{
+    event::{DropData, ImeEvent},
+    utils::Point,
+};
+
 /// Used to store and read web events.
 pub struct EventState {
     pub mouse_move_events: Rc<RefCell<Vec<event::MouseMoveEvent>>>,
@@ -9,9 +14,15 @@ pub struct EventState {
     pub touch_start_events: Rc<RefCell<Vec<event::TouchStart>>>,
     pub touch_end_events: Rc<RefCell<Vec<event::TouchEnd>>>,
     pub touch_move_events: Rc<RefCell<Vec<event::TouchMove>>>,
+    pub touch_cancel_events: Rc<RefCell<Vec<event::TouchCancel>>>,
     pub mouse_down_events: Rc<RefCell<Vec<event::MouseDownEvent>>>,
     pub scroll_events: Rc<RefCell<Vec<event::MouseWheelEvent>>>,
     pub key_up_events: Rc<RefCell<Vec<event::KeyUpEvent>>>,
     pub key_down_events: Rc<RefCell<Vec<event::KeyDownEvent>>>,
     pub resize_events: Rc<RefCell<Vec<event::ResizeEvent>>>,
+    pub drag_over_events: Rc<RefCell<Vec<event::DragOverEvent>>>,
+    pub drag_leave_events: Rc<RefCell<Vec<event::DragLeaveEvent>>>,
+    pub drop_events: Rc<RefCell<Vec<(Point, DropData)>>>,
+    pub ime_events: Rc<RefCell<Vec<ImeEvent>>>,
+    pub active_events: Rc<RefCell<Vec<bool>>>,
 }
@@ -1,20 +1,26 @@
-use std::sync::mpsc;
+use std::{cell::RefCell, mem, rc::Rc, sync::mpsc};
 
 use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d, Touch},
 };
 
 use derive_more::Constructor;
 
-use super::EventState;
+use super::{EventState, CONSOLE};
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    color_scheme::ColorSchemeMonitor,
+    event::{
+        ButtonState, ExternalDragEvent, Key, KeyEvent, MouseButton, MouseEvent, ScrollDelta,
+        TouchEvent, TouchPhase,
+    },
+    monitor::Monitor,
     render::RenderContext2D,
+    time::UpdateTimer,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowIcon, WindowRequest,
 };
 
 use orbtk_utils::Point;
@@ -35,6 +41,8 @@ where
     update: bool,
     redraw: bool,
     close: bool,
+    color_scheme_monitor: ColorSchemeMonitor,
+    update_timer: UpdateTimer,
 }
 
 impl<A> Window<A>
@@ -48,13 +56,13 @@ where
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
-        while let Some(event) = self.event_state.mouse_move_events.borrow_mut().pop() {
+        for event in take(&self.event_state.mouse_move_events) {
             self.adapter
                 .mouse(event.client_x() as f64, event.client_y() as f64);
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.mouse_down_events.borrow_mut().pop() {
+        for event in take(&self.event_state.mouse_down_events) {
             self.adapter.mouse_event(MouseEvent {
                 position: Point::new(event.client_x() as f64, event.client_y() as f64),
                 button: get_mouse_button(event.button()),
@@ -63,7 +71,7 @@ where
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.mouse_up_events.borrow_mut().pop() {
+        for event in take(&self.event_state.mouse_up_events) {
             self.adapter.mouse_event(MouseEvent {
                 position: Point::new(event.client_x() as f64, event.client_y() as f64),
                 button: get_mouse_button(event.button()),
@@ -72,13 +80,13 @@ where
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.scroll_events.borrow_mut().pop() {
-            self.adapter.scroll(event.delta_x(), event.delta_y());
+        for event in take(&self.event_state.scroll_events) {
+            self.adapter.scroll(scroll_delta(&event));
             self.update = true;
         }
 
         // todo tmp solution to map touch events to mouse vent
-        while let Some(event) = self.event_state.touch_start_events.borrow_mut().pop() {
+        for event in take(&self.event_state.touch_start_events) {
             self.adapter.mouse_event(MouseEvent {
                 position: Point::new(
                     event.changed_touches()[0].client_x() as f64,
@@ -87,10 +95,15 @@ where
                 button: MouseButton::Left,
                 state: ButtonState::Down,
             });
+
+            for touch in touch_events(event.changed_touches(), TouchPhase::Started) {
+                self.adapter.touch_event(touch);
+            }
+
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.touch_end_events.borrow_mut().pop() {
+        for event in take(&self.event_state.touch_end_events) {
             self.adapter.mouse_event(MouseEvent {
                 position: Point::new(
                     event.changed_touches()[0].client_x() as f64,
@@ -100,18 +113,35 @@ where
                 state: ButtonState::Up,
             });
 
+            for touch in touch_events(event.changed_touches(), TouchPhase::Ended) {
+                self.adapter.touch_event(touch);
+            }
+
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.touch_move_events.borrow_mut().pop() {
+        for event in take(&self.event_state.touch_move_events) {
             self.adapter.mouse(
                 event.changed_touches()[0].client_x() as f64,
                 event.changed_touches()[0].client_y() as f64,
             );
+
+            for touch in touch_events(event.changed_touches(), TouchPhase::Moved) {
+                self.adapter.touch_event(touch);
+            }
+
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.key_down_events.borrow_mut().pop() {
+        for event in take(&self.event_state.touch_cancel_events) {
+            for touch in touch_events(event.changed_touches(), TouchPhase::Cancelled) {
+                self.adapter.touch_event(touch);
+            }
+
+            self.update = true;
+        }
+
+        for event in take(&self.event_state.key_down_events) {
             let key = get_key(event.code().as_str(), event.key());
 
             self.adapter.key_event(KeyEvent {
@@ -122,7 +152,7 @@ where
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.key_up_events.borrow_mut().pop() {
+        for event in take(&self.event_state.key_up_events) {
             let key = get_key(event.code().as_str(), event.key());
 
             self.adapter.key_event(KeyEvent {
@@ -133,7 +163,40 @@ where
             self.update = true;
         }
 
-        while let Some(_) = self.event_state.resize_events.borrow_mut().pop() {
+        for event in take(&self.event_state.drag_over_events) {
+            self.adapter
+                .mouse(event.client_x() as f64, event.client_y() as f64);
+            self.update = true;
+        }
+
+        for (position, data) in take(&self.event_state.drop_events) {
+            self.adapter.mouse(position.x(), position.y());
+            self.adapter
+                .external_drag_event(ExternalDragEvent::Drop(data));
+            self.update = true;
+        }
+
+        if !take(&self.event_state.drag_leave_events).is_empty() {
+            self.adapter.external_drag_event(ExternalDragEvent::Leave);
+            self.update = true;
+        }
+
+        for active in take(&self.event_state.active_events) {
+            self.adapter.active(active);
+            self.update = true;
+        }
+
+        for event in take(&self.event_state.ime_events) {
+            self.adapter.ime_event(event);
+            self.update = true;
+        }
+
+        if let Some(color_scheme) = self.color_scheme_monitor.poll() {
+            self.adapter.color_scheme_changed(color_scheme);
+            self.update = true;
+        }
+
+        if !take(&self.event_state.resize_events).is_empty() {
             let window_size = (
                 window().inner_width() as f64,
                 window().inner_height() as f64,
@@ -188,6 +251,14 @@ where
             }
 
             self.render_context.set_canvas_render_context_2d(ctx);
+
+            // the page is moved to a monitor with another device pixel ratio
+            if (self.render_context.scale_factor() - ratio).abs() > f64::EPSILON {
+                self.render_context.set_scale_factor(ratio);
+                self.adapter.scale_factor_changed(ratio);
+                self.adapter.monitors_changed(vec![screen_monitor(ratio)]);
+            }
+
             self.adapter.resize(window_size.0, window_size.1);
             self.old_canvas = Some(self.canvas.clone());
             self.canvas = canvas;
@@ -204,14 +275,56 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::RedrawAt(time) => {
+                        self.update_timer.request(time);
+                    }
                     WindowRequest::ChangeTitle(title) => {
                         document().set_title(title.as_str());
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeCursor(cursor) => {
+                        let cursor = match cursor {
+                            CursorIcon::Default => "default",
+                            CursorIcon::ResizeHorizontal => "ew-resize",
+                            CursorIcon::ResizeVertical => "ns-resize",
+                            CursorIcon::Pointer => "pointer",
+                            CursorIcon::Text => "text",
+                            CursorIcon::ResizeDiagonal => "nwse-resize",
+                            CursorIcon::ResizeAntiDiagonal => "nesw-resize",
+                            CursorIcon::Move => "move",
+                            CursorIcon::Wait => "wait",
+                            CursorIcon::Crosshair => "crosshair",
+                            CursorIcon::None => "none",
+                        };
+
+                        js! {
+                            @{&self.canvas}.style.cursor = @{cursor};
+                        }
+                    }
+                    // the browser places the candidate window itself
+                    WindowRequest::SetImePosition(_) => {}
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // the size and the position of the browser window are controlled by the user
+                    WindowRequest::Resize(_, _)
+                    | WindowRequest::Move(_, _)
+                    | WindowRequest::ChangeMode(_)
+                    | WindowRequest::SetMinSize(_)
+                    | WindowRequest::SetMaxSize(_) => {}
+                    WindowRequest::SetIcon(icon) => {
+                        set_favicon(icon.as_ref());
+                    }
+                    // the browser has no task bar integration and could not hide its tab
+                    WindowRequest::SetProgress(_) | WindowRequest::SetVisible(_) => {}
+                    WindowRequest::SetTrayIcon(tray_icon) => {
+                        if tray_icon.is_some() {
+                            CONSOLE.log(
+                                "Window: the tray icon is ignored, the browser has no system tray",
+                            );
+                        }
+                    }
                 }
             }
         }
@@ -219,6 +332,15 @@ where
 
     /// Runs update on the adapter.
     pub fn update(&mut self) {
+        if self.update_timer.elapsed() {
+            self.update = true;
+        }
+
+        // text that was drawn before its font was loaded is drawn again
+        if self.render_context.fonts_loaded() {
+            self.update = true;
+        }
+
         if !self.update {
             return;
         }
@@ -254,6 +376,28 @@ fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     }
 }
 
+// Converts the delta of a web wheel event. The web scrolls down on positive values, so the delta is inverted.
+fn scroll_delta(event: &event::MouseWheelEvent) -> ScrollDelta {
+    match event.delta_mode() {
+        event::MouseWheelDeltaMode::Pixel => {
+            ScrollDelta::from_pixels(-event.delta_x(), -event.delta_y())
+        }
+        _ => ScrollDelta::from_lines(-event.delta_x(), -event.delta_y()),
+    }
+}
+
+// Converts the changed touch points of a web touch event.
+fn touch_events(touches: Vec<Touch>, phase: TouchPhase) -> Vec<TouchEvent> {
+    touches
+        .iter()
+        .map(|touch| TouchEvent {
+            id: touch.identifier() as u64,
+            phase,
+            position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+        })
+        .collect()
+}
+
 fn get_key(code: &str, key: String) -> (Key, String) {
     let mut text = String::from("");
 
@@ -271,11 +415,39 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "ArrowDown" => Key::Down,
         "Escape" => Key::Escape,
         "Enter" => Key::Enter,
-        "OSLeft" | "OSRight" => Key::Home,
+        "Tab" => Key::Tab,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
         "CapsLock" => Key::CapsLock,
+        "Numpad0" => Key::Numpad0,
+        "Numpad1" => Key::Numpad1,
+        "Numpad2" => Key::Numpad2,
+        "Numpad3" => Key::Numpad3,
+        "Numpad4" => Key::Numpad4,
+        "Numpad5" => Key::Numpad5,
+        "Numpad6" => Key::Numpad6,
+        "Numpad7" => Key::Numpad7,
+        "Numpad8" => Key::Numpad8,
+        "Numpad9" => Key::Numpad9,
+        "NumpadDivide" => Key::NumpadDivide,
+        "NumpadMultiply" => Key::NumpadMultiply,
+        "NumpadSubtract" => Key::NumpadSubtract,
+        "NumpadAdd" => Key::NumpadAdd,
+        "NumpadEnter" => Key::NumpadEnter,
+        "NumpadDecimal" => Key::NumpadDot,
         _ => {
-            text = key.clone();
-            Key::from(key.chars().next().unwrap())
+            let mut chars = key.chars();
+
+            // named keys like `F1` don't insert text
+            match (chars.next(), chars.next()) {
+                (Some(character), None) => {
+                    text = key.clone();
+                    Key::from(character)
+                }
+                _ => Key::Unknown,
+            }
         }
     };
 
@@ -283,3 +455,118 @@ fn get_key(code: &str, key: String) -> (Key, String) {
 }
 
 // -- Helpers --
+
+// Takes the events that are queued since the last frame in the order of their occurrence.
+fn take<T>(events: &Rc<RefCell<Vec<T>>>) -> Vec<T> {
+    mem::take(&mut *events.borrow_mut())
+}
+
+// Shows the icon as favicon of the page, `None` removes the favicon that is set by the window.
+pub(crate) fn set_favicon(icon: Option<&WindowIcon>) {
+    match icon {
+        Some(icon) => {
+            let width = icon.width;
+            let height = icon.height;
+            let data = icon.to_rgba();
+
+            js! {
+                var canvas = document.createElement("canvas");
+                canvas.width = @{width};
+                canvas.height = @{height};
+
+                var context = canvas.getContext("2d");
+                var image = context.createImageData(@{width}, @{height});
+                image.data.set(@{data});
+                context.putImageData(image, 0, 0);
+
+                var link = document.getElementById("orbtk-favicon");
+
+                if (!link) {
+                    link = document.createElement("link");
+                    link.id = "orbtk-favicon";
+                    link.rel = "icon";
+                    document.head.appendChild(link);
+                }
+
+                link.href = canvas.toDataURL("image/png");
+            }
+        }
+        None => {
+            js! {
+                var link = document.getElementById("orbtk-favicon");
+
+                if (link) {
+                    link.parentNode.removeChild(link);
+                }
+            }
+        }
+    }
+}
+
+// Reads the screen the page is shown on, the browser provides no other monitors. The work area is the part of the
+// screen that is available for windows.
+pub(crate) fn screen_monitor(scale_factor: f64) -> Monitor {
+    let screen_value = |property: &str| -> f64 {
+        js! {
+            return screen[@{property}] || 0;
+        }
+        .try_into()
+        .unwrap_or(0.0)
+    };
+
+    Monitor::new(
+        "",
+        (0.0, 0.0, screen_value("width"), screen_value("height")),
+    )
+    .work_area((
+        screen_value("availLeft"),
+        screen_value("availTop"),
+        screen_value("availWidth"),
+        screen_value("availHeight"),
+    ))
+    .scale_factor(scale_factor)
+    .primary(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_key() {
+        assert_eq!(
+            get_key("KeyA", "a".to_string()),
+            (Key::A(false), "a".to_string())
+        );
+        assert_eq!(
+            get_key("Home", "Home".to_string()),
+            (Key::Home, String::new())
+        );
+        assert_eq!(
+            get_key("NumpadDecimal", ",".to_string()),
+            (Key::NumpadDot, String::new())
+        );
+
+        // named keys don't insert their name as text
+        assert_eq!(
+            get_key("F1", "F1".to_string()),
+            (Key::Unknown, String::new())
+        );
+        assert_eq!(
+            get_key("MetaLeft", "Meta".to_string()),
+            (Key::Unknown, String::new())
+        );
+    }
+
+    #[test]
+    fn test_take() {
+        let events = Rc::new(RefCell::new(vec![1, 2, 3]));
+
+        // the events are taken in the order of their occurrence
+        assert_eq!(take(&events), vec![1, 2, 3]);
+        assert!(events.borrow().is_empty());
+
+        events.borrow_mut().push(4);
+        assert_eq!(take(&events), vec![4]);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     cell::{Cell, RefCell},
     collections::HashMap,
+    path::PathBuf,
     rc::Rc,
     sync::mpsc,
     // time::Duration,
@@ -13,10 +14,18 @@ use stdweb::{
     web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
 };
 
-use super::{EventState, Shell, Window};
+use super::{
+    window::{screen_monitor, set_favicon},
+    EventState, Shell, Window,
+};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    color_scheme::ColorSchemeMonitor,
+    event::{DropData, ImeEvent},
+    render::RenderContext2D,
+    time::UpdateTimer,
+    utils::{Point, Rectangle},
+    window_adapter::WindowAdapter,
+    WindowIcon, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the web backend.
@@ -32,6 +41,7 @@ where
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
+    icon: Option<WindowIcon>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -50,6 +60,7 @@ where
             borderless: false,
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            icon: None,
             request_receiver: None,
         }
     }
@@ -65,6 +76,7 @@ where
             borderless: settings.borderless,
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            icon: settings.icon,
             request_receiver: None,
         }
     }
@@ -75,6 +87,12 @@ where
         self
     }
 
+    /// Sets the icon, it is shown as favicon of the page.
+    pub fn icon(mut self, icon: WindowIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     /// Sets borderless.
     pub fn borderless(mut self, borderless: bool) -> Self {
         self.borderless = borderless;
@@ -143,11 +161,17 @@ where
         let touch_start = Rc::new(RefCell::new(vec![]));
         let touch_end = Rc::new(RefCell::new(vec![]));
         let touch_move = Rc::new(RefCell::new(vec![]));
+        let touch_cancel = Rc::new(RefCell::new(vec![]));
         let mouse_down = Rc::new(RefCell::new(vec![]));
         let scroll = Rc::new(RefCell::new(vec![]));
         let key_down = Rc::new(RefCell::new(vec![]));
         let key_up = Rc::new(RefCell::new(vec![]));
         let resize = Rc::new(RefCell::new(vec![]));
+        let drag_over = Rc::new(RefCell::new(vec![]));
+        let drag_leave = Rc::new(RefCell::new(vec![]));
+        let drop = Rc::new(RefCell::new(vec![]));
+        let ime = Rc::new(RefCell::new(vec![]));
+        let active = Rc::new(RefCell::new(vec![]));
         let mouse_blocked = Rc::new(Cell::new(false));
 
         let mouse_down_c = mouse_down.clone();
@@ -199,6 +223,14 @@ where
                 touch_move_c.borrow_mut().push(e);
             });
 
+        let touch_cancel_c = touch_cancel.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::TouchCancel| {
+                touch_cancel_c.borrow_mut().push(e);
+            });
+
         let mouse_move_c = mouse_move.clone();
         document()
             .body()
@@ -236,6 +268,76 @@ where
             resize_c.borrow_mut().push(e);
         });
 
+        // the browser window or tab gets or loses the focus
+        let active_c = active.clone();
+        window().add_event_listener(move |_: event::FocusEvent| {
+            active_c.borrow_mut().push(true);
+        });
+
+        let active_c = active.clone();
+        window().add_event_listener(move |_: event::BlurEvent| {
+            active_c.borrow_mut().push(false);
+        });
+
+        // the default of drag over has to be prevented, otherwise the browser does not allow a drop
+        let drag_over_c = drag_over.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::DragOverEvent| {
+                e.prevent_default();
+                drag_over_c.borrow_mut().push(e);
+            });
+
+        // only a drag that leaves the document has no related target
+        let drag_leave_c = drag_leave.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::DragLeaveEvent| {
+                if e.related_target().is_none() {
+                    drag_leave_c.borrow_mut().push(e);
+                }
+            });
+
+        // the data of a drop is only readable inside of the listener
+        let drop_c = drop.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::DragDropEvent| {
+                e.prevent_default();
+                if let Some(data) = get_drop_data(&e) {
+                    drop_c
+                        .borrow_mut()
+                        .push((Point::new(e.client_x() as f64, e.client_y() as f64), data));
+                }
+            });
+
+        // stdweb does not provide composition events, they are forwarded by javascript
+        let ime_c = ime.clone();
+        let push_ime_event = move |kind: String, data: String| {
+            ime_c.borrow_mut().push(match kind.as_str() {
+                "compositionstart" => ImeEvent::Start,
+                "compositionupdate" => ImeEvent::Update(data),
+                _ => ImeEvent::Commit(data),
+            });
+        };
+
+        js! {
+            var push_ime_event = @{push_ime_event};
+            ["compositionstart", "compositionupdate", "compositionend"].forEach(function(kind) {
+                document.addEventListener(kind, function(e) {
+                    push_ime_event(kind, e.data || "");
+                });
+            });
+        }
+
+        // right click is handled by the widgets, e.g. to open a context menu.
+        document().add_event_listener(move |e: event::ContextMenuEvent| {
+            e.prevent_default();
+        });
+
         document().body().unwrap().append_child(&canvas);
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
 
@@ -270,10 +372,21 @@ where
             ctx.scale(ratio, ratio);
         }
 
-        let render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        let mut render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        render_context.set_scale_factor(ratio);
+        self.adapter.scale_factor_changed(ratio);
+        self.adapter.monitors_changed(vec![screen_monitor(ratio)]);
+
+        for (family, font) in self.fonts {
+            render_context.register_font(&family, font);
+        }
 
         document().set_title(self.title.as_str());
 
+        if self.icon.is_some() {
+            set_favicon(self.icon.as_ref());
+        }
+
         stdweb::event_loop();
 
         self.shell.window_shells.push(Window::new(
@@ -286,17 +399,48 @@ where
                 touch_start_events: touch_start,
                 touch_end_events: touch_end,
                 touch_move_events: touch_move,
+                touch_cancel_events: touch_cancel,
                 mouse_down_events: mouse_down,
                 scroll_events: scroll,
                 key_down_events: key_down,
                 key_up_events: key_up,
                 resize_events: resize,
+                drag_over_events: drag_over,
+                drag_leave_events: drag_leave,
+                drop_events: drop,
+                ime_events: ime,
+                active_events: active,
             },
             canvas,
             None,
             true,
             true,
             false,
+            ColorSchemeMonitor::new(),
+            UpdateTimer::default(),
         ));
     }
 }
+
+// Reads the dropped files or the dropped text of a drop event. On the web only the names of the files are known.
+fn get_drop_data(event: &event::DragDropEvent) -> Option<DropData> {
+    let data_transfer = event.data_transfer()?;
+
+    let files: Vec<PathBuf> = data_transfer
+        .files()
+        .iter()
+        .map(|file| PathBuf::from(file.name()))
+        .collect();
+
+    if !files.is_empty() {
+        return Some(DropData::Files(files));
+    }
+
+    let text = data_transfer.get_data("text");
+
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(DropData::Text(text))
+}
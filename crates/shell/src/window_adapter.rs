@@ -1,7 +1,10 @@
 //! This module contains traits to inject custom logic into the window shell.
 
 use crate::render::RenderContext2D;
-use crate::{event::*, utils::Point};
+use crate::{
+    color_scheme::ColorScheme, event::*, monitor::Monitor, tray::TrayEvent, utils::Point,
+    WindowMode,
+};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
@@ -9,18 +12,48 @@ pub trait WindowAdapter {
     /// Is called after the window is resized.
     fn resize(&mut self, _width: f64, _height: f64) {}
 
+    /// Is called after the window is moved on the screen, the position is the top left corner of the window.
+    fn moved(&mut self, _x: f64, _y: f64) {}
+
+    /// Is called after the platform has changed the mode of the window, e.g. if the user minimizes the window by its
+    /// decorations. It's not called for modes that are requested by the application.
+    fn mode_changed(&mut self, _mode: WindowMode) {}
+
+    /// Is called after the scale factor of the window is changed, e.g. if the window is moved to a monitor with
+    /// another DPI, and once on startup. The sizes and positions passed to the adapter are in logical pixels.
+    fn scale_factor_changed(&mut self, _scale_factor: f64) {}
+
+    /// Is called on startup with the monitors of the platform and after they are changed, e.g. if a monitor is
+    /// connected or its resolution is changed. Shells that could not enumerate the monitors never call it.
+    fn monitors_changed(&mut self, _monitors: Vec<Monitor>) {}
+
     /// Is called after the mouse was moved.
     fn mouse(&mut self, _x: f64, _y: f64) {}
 
     /// Is called after the state of a mouse button is changed.
     fn mouse_event(&mut self, _event: MouseEvent) {}
 
-    /// Is called if mouse wheel or trackpad detect scroll event.
-    fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
+    /// Is called if mouse wheel or trackpad detect scroll event. The delta includes horizontal scrolling.
+    fn scroll(&mut self, _delta: ScrollDelta) {}
 
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
+    /// Is called if data of another application is dragged over the window or dropped on it.
+    fn external_drag_event(&mut self, _event: ExternalDragEvent) {}
+
+    /// Is called after the composition of an input method editor is changed.
+    fn ime_event(&mut self, _event: ImeEvent) {}
+
+    /// Is called after the light or dark preference of the platform has changed and once on startup.
+    fn color_scheme_changed(&mut self, _color_scheme: ColorScheme) {}
+
+    /// Is called after a touch point is changed.
+    fn touch_event(&mut self, _event: TouchEvent) {}
+
+    /// Is called if the user interacts with the `TrayIcon` of the window.
+    fn tray_event(&mut self, _event: TrayEvent) {}
+
     /// Is called after the quit event of the window is called.
     fn quit_event(&mut self) {}
 
@@ -0,0 +1,29 @@
+/*!
+
+Test utils of OrbTk. A widget tree is run in a window of the headless shell, its frames are drawn into an in-memory
+framebuffer and could be compared with golden images to catch visual regressions of widgets and themes.
+
+# Example
+
+```rust,ignore
+use orbtk::prelude::*;
+use orbtk_test::prelude::*;
+
+let mut window = TestWindow::new(|ctx| {
+    Window::new()
+        .size(120.0, 40.0)
+        .child(Button::new().text("OK").build(ctx))
+        .build(ctx)
+});
+
+window
+    .frames(2)
+    .assert_snapshot("tests/snapshots/button.png", Tolerance::default());
+```
+
+ */
+
+pub mod prelude;
+pub mod snapshot;
+pub mod test_runner;
+pub mod test_window;
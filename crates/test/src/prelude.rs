@@ -0,0 +1 @@
+pub use crate::{snapshot::*, test_runner::*, test_window::*};
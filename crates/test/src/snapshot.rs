@@ -0,0 +1,245 @@
+//! This module contains helpers to compare rendered frames with golden images.
+
+use std::{env, error, fmt, fs, path::Path};
+
+use image::RgbaImage;
+
+/// If this environment variable is set, `assert_snapshot` overwrites the golden images instead of comparing them.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "ORBTK_UPDATE_SNAPSHOTS";
+
+/// Describes how much a frame could differ from its golden image, e.g. because of a different anti-aliasing of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerance {
+    /// The maximum difference of a color channel (0 - 255) for a pixel that is treated as equal.
+    pub channel: u8,
+
+    /// The maximum number of pixels that could differ more than `channel`.
+    pub pixels: usize,
+}
+
+impl Tolerance {
+    /// Creates a new tolerance.
+    pub fn new(channel: u8, pixels: usize) -> Self {
+        Tolerance { channel, pixels }
+    }
+
+    /// Creates a tolerance that accepts only identical images.
+    pub fn exact() -> Self {
+        Tolerance::new(0, 0)
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::new(2, 0)
+    }
+}
+
+/// Describes why a frame doesn't match its golden image.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnapshotError {
+    /// The frame and the golden image have different sizes.
+    Size {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+
+    /// More pixels differ than accepted by the tolerance.
+    Pixels {
+        /// Number of pixels that differ more than the channel tolerance.
+        count: usize,
+
+        /// Maximum difference of a color channel over all pixels.
+        max_difference: u8,
+    },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Size { expected, actual } => write!(
+                f,
+                "the size {}x{} differs from the size {}x{} of the golden image",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            SnapshotError::Pixels {
+                count,
+                max_difference,
+            } => write!(
+                f,
+                "{} pixels differ from the golden image, the maximum channel difference is {}",
+                count, max_difference
+            ),
+        }
+    }
+}
+
+impl error::Error for SnapshotError {}
+
+/// Converts premultiplied ARGB pixels, like the framebuffer of a headless window, to an image with straight RGBA
+/// pixels that could be stored as PNG.
+pub fn to_image(data: &[u32], width: u32, height: u32) -> RgbaImage {
+    let mut bytes = Vec::with_capacity(data.len() * 4);
+
+    for pixel in data {
+        let alpha = (pixel >> 24) as u8;
+
+        for shift in &[16, 8, 0] {
+            let channel = ((pixel >> shift) & 0xff) as u16;
+
+            bytes.push(match alpha {
+                0 => 0,
+                255 => channel as u8,
+                _ => ((channel * 255 + alpha as u16 / 2) / alpha as u16).min(255) as u8,
+            });
+        }
+
+        bytes.push(alpha);
+    }
+
+    RgbaImage::from_raw(width, height, bytes)
+        .expect("snapshot::to_image: the size doesn't match the pixels.")
+}
+
+/// Compares an image with its golden image. The images match if at most `tolerance.pixels` pixels have a color
+/// channel that differs more than `tolerance.channel`.
+pub fn compare(
+    actual: &RgbaImage,
+    expected: &RgbaImage,
+    tolerance: Tolerance,
+) -> Result<(), SnapshotError> {
+    if actual.dimensions() != expected.dimensions() {
+        return Err(SnapshotError::Size {
+            expected: expected.dimensions(),
+            actual: actual.dimensions(),
+        });
+    }
+
+    let mut count = 0;
+    let mut max_difference = 0;
+
+    for (a, e) in actual.pixels().zip(expected.pixels()) {
+        let difference =
+            a.0.iter()
+                .zip(e.0.iter())
+                .map(|(a, e)| (*a as i16 - *e as i16).abs() as u8)
+                .max()
+                .unwrap_or(0);
+
+        if difference > tolerance.channel {
+            count += 1;
+        }
+
+        max_difference = max_difference.max(difference);
+    }
+
+    if count > tolerance.pixels {
+        return Err(SnapshotError::Pixels {
+            count,
+            max_difference,
+        });
+    }
+
+    Ok(())
+}
+
+/// Compares an image with the golden PNG image at `path`.
+///
+/// If the golden image doesn't exist or the `ORBTK_UPDATE_SNAPSHOTS` environment variable is set, the image is written
+/// to `path`. A missing golden image fails the test, it should be checked and committed. If the image doesn't match,
+/// it is written next to the golden image with the `.actual.png` extension.
+///
+/// # Panics
+///
+/// Panics if the image doesn't match the golden image, or if the golden image could not be read or written.
+pub fn assert_snapshot(image: &RgbaImage, path: impl AsRef<Path>, tolerance: Tolerance) {
+    let path = path.as_ref();
+
+    if env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        save(image, path);
+        return;
+    }
+
+    if !path.exists() {
+        save(image, path);
+        panic!(
+            "snapshot::assert_snapshot: the golden image {} did not exist and was written, check it and run the test again.",
+            path.display()
+        );
+    }
+
+    let expected = image::open(path)
+        .unwrap_or_else(|e| {
+            panic!(
+                "snapshot::assert_snapshot: could not read {}: {}",
+                path.display(),
+                e
+            )
+        })
+        .to_rgba8();
+
+    if let Err(error) = compare(image, &expected, tolerance) {
+        let actual_path = path.with_extension("actual.png");
+        save(image, &actual_path);
+        panic!(
+            "snapshot::assert_snapshot: {} doesn't match: {}. The frame is written to {}.",
+            path.display(),
+            error,
+            actual_path.display()
+        );
+    }
+}
+
+fn save(image: &RgbaImage, path: &Path) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    image.save(path).unwrap_or_else(|e| {
+        panic!(
+            "snapshot::assert_snapshot: could not write {}: {}",
+            path.display(),
+            e
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_image() {
+        let image = to_image(&[0xff_ff_00_00, 0x80_40_00_80, 0x00_00_00_00], 3, 1);
+
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(1, 0).0, [128, 0, 255, 128]);
+        assert_eq!(image.get_pixel(2, 0).0, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = RgbaImage::from_raw(2, 1, vec![10, 10, 10, 255, 0, 0, 0, 255]).unwrap();
+        let actual = RgbaImage::from_raw(2, 1, vec![12, 10, 10, 255, 0, 0, 20, 255]).unwrap();
+
+        assert_eq!(compare(&expected, &expected, Tolerance::exact()), Ok(()));
+        assert_eq!(
+            compare(&actual, &expected, Tolerance::default()),
+            Err(SnapshotError::Pixels {
+                count: 1,
+                max_difference: 20
+            })
+        );
+        assert_eq!(compare(&actual, &expected, Tolerance::new(2, 1)), Ok(()));
+        assert_eq!(compare(&actual, &expected, Tolerance::new(20, 0)), Ok(()));
+
+        let small = RgbaImage::from_raw(1, 1, vec![0, 0, 0, 255]).unwrap();
+        assert_eq!(
+            compare(&small, &expected, Tolerance::default()),
+            Err(SnapshotError::Size {
+                expected: (2, 1),
+                actual: (1, 1)
+            })
+        );
+    }
+}
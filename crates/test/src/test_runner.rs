@@ -0,0 +1,339 @@
+use dces::prelude::{Component, Entity};
+use orbtk_api::prelude::{global_transform, mark_as_dirty, BuildContext};
+use orbtk_shell::event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent};
+use orbtk_theming::Theme;
+use orbtk_utils::prelude::{Point, Rectangle};
+
+use crate::test_window::TestWindow;
+
+/// Simulates the input of a user on a widget tree that runs in a headless window, e.g. to test the interaction logic
+/// of widgets end-to-end. After each input the window is run until it is idle, the resulting properties could be
+/// read with `get`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut runner = TestRunner::new(|ctx| {
+///     Window::new()
+///         .size(200.0, 40.0)
+///         .child(Slider::new().id("slider").build(ctx))
+///         .build(ctx)
+/// });
+///
+/// let slider = runner.entity_of_child("slider").unwrap();
+/// runner.click(slider).press_key(Key::End);
+///
+/// assert_eq!(runner.get::<f64>(slider, "val"), 100.0);
+/// ```
+pub struct TestRunner {
+    window: TestWindow,
+}
+
+impl TestRunner {
+    /// Creates a test runner with the default theme. The widget tree is built by `create_fn` and run until the window
+    /// is idle.
+    pub fn new<F: Fn(&mut BuildContext) -> Entity + 'static>(create_fn: F) -> Self {
+        Self::from_window(TestWindow::new(create_fn))
+    }
+
+    /// Creates a test runner with the given theme.
+    pub fn with_theme<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        theme: Theme,
+        create_fn: F,
+    ) -> Self {
+        Self::from_window(TestWindow::with_theme(theme, create_fn))
+    }
+
+    fn from_window(mut window: TestWindow) -> Self {
+        window.run();
+        TestRunner { window }
+    }
+
+    /// Gets the test window, e.g. to compare the current frame with a golden image.
+    pub fn window(&self) -> &TestWindow {
+        &self.window
+    }
+
+    /// Gets the test window as mutable reference.
+    pub fn window_mut(&mut self) -> &mut TestWindow {
+        &mut self.window
+    }
+
+    // -- Widgets --
+
+    /// Gets the root entity of the widget tree.
+    pub fn root(&mut self) -> Entity {
+        self.window
+            .window_mut()
+            .adapter_mut()
+            .entity_component_manager()
+            .entity_store()
+            .root
+            .expect("TestRunner.root: the widget tree is empty.")
+    }
+
+    /// Returns the entity of the first widget of the tree with the given id.
+    pub fn entity_of_child(&mut self, id: &str) -> Option<Entity> {
+        let root = self.root();
+        let ecm = self
+            .window
+            .window_mut()
+            .adapter_mut()
+            .entity_component_manager();
+
+        let mut it = ecm.entity_store().start_node(root).into_iter();
+
+        it.find(|entity| {
+            ecm.component_store()
+                .get::<String>("id", *entity)
+                .map_or(false, |entity_id| entity_id == id)
+        })
+    }
+
+    /// Returns the value of a property of a widget if it exists.
+    pub fn try_get<P: Component + Clone>(&mut self, entity: Entity, key: &str) -> Option<P> {
+        self.window
+            .window_mut()
+            .adapter_mut()
+            .entity_component_manager()
+            .component_store()
+            .get::<P>(key, entity)
+            .ok()
+            .cloned()
+    }
+
+    /// Gets the value of a property of a widget.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget has no property with the given key and type.
+    pub fn get<P: Component + Clone>(&mut self, entity: Entity, key: &str) -> P {
+        self.try_get(entity, key).unwrap_or_else(|| {
+            panic!(
+                "TestRunner.get: entity {:?} has no property {} of the requested type.",
+                entity, key
+            )
+        })
+    }
+
+    /// Sets the value of a property of a widget and runs the window, e.g. to simulate a changed model.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget has no property with the given key and type.
+    pub fn set<P: Component + Clone>(&mut self, entity: Entity, key: &str, value: P) -> &mut Self {
+        let ecm = self
+            .window
+            .window_mut()
+            .adapter_mut()
+            .entity_component_manager();
+
+        match ecm.component_store_mut().get_mut::<P>(key, entity) {
+            Ok(property) => *property = value,
+            Err(_) => panic!(
+                "TestRunner.set: entity {:?} has no property {} of the requested type.",
+                entity, key
+            ),
+        }
+
+        mark_as_dirty(key, entity, ecm);
+        self.run()
+    }
+
+    /// Gets the center of a widget in window coordinates, the render transforms of the widget and its parents are
+    /// applied.
+    pub fn center(&mut self, entity: Entity) -> Point {
+        let ecm = self
+            .window
+            .window_mut()
+            .adapter_mut()
+            .entity_component_manager();
+
+        let bounds = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .map_or_else(|_| Rectangle::default(), |b| *b);
+        let position = ecm
+            .component_store()
+            .get::<Point>("position", entity)
+            .map_or_else(|_| Point::default(), |p| *p);
+
+        global_transform(ecm, entity).apply(Point::new(
+            position.x() + bounds.width() / 2.0,
+            position.y() + bounds.height() / 2.0,
+        ))
+    }
+
+    // -- Input --
+
+    /// Moves the mouse to the given position.
+    pub fn mouse_move(&mut self, position: impl Into<Point>) -> &mut Self {
+        let position = position.into();
+        self.window.window_mut().mouse(position.x(), position.y());
+        self.run()
+    }
+
+    /// Moves the mouse to the given position and presses the button.
+    pub fn mouse_down(&mut self, position: impl Into<Point>, button: MouseButton) -> &mut Self {
+        self.mouse_button(position.into(), button, ButtonState::Down)
+    }
+
+    /// Moves the mouse to the given position and releases the button.
+    pub fn mouse_up(&mut self, position: impl Into<Point>, button: MouseButton) -> &mut Self {
+        self.mouse_button(position.into(), button, ButtonState::Up)
+    }
+
+    /// Clicks with the left mouse button on the given position.
+    pub fn click_at(&mut self, position: impl Into<Point>) -> &mut Self {
+        let position = position.into();
+        self.mouse_down(position, MouseButton::Left)
+            .mouse_up(position, MouseButton::Left)
+    }
+
+    /// Clicks with the left mouse button on the center of the widget.
+    pub fn click(&mut self, entity: Entity) -> &mut Self {
+        let center = self.center(entity);
+        self.click_at(center)
+    }
+
+    /// Presses the left mouse button on the center of the widget, moves the mouse to the given position and
+    /// releases the button there.
+    pub fn drag(&mut self, entity: Entity, to: impl Into<Point>) -> &mut Self {
+        let from = self.center(entity);
+        let to = to.into();
+        self.mouse_down(from, MouseButton::Left)
+            .mouse_move(to)
+            .mouse_up(to, MouseButton::Left)
+    }
+
+    /// Presses and releases a key, e.g. `Key::Tab` to move the keyboard focus.
+    pub fn press_key(&mut self, key: Key) -> &mut Self {
+        self.key(key, ButtonState::Down, String::default());
+        self.key(key, ButtonState::Up, String::default());
+        self.run()
+    }
+
+    /// Types the given text into the focused widget, each character is sent as key with its text like by a platform
+    /// window.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for character in text.chars() {
+            self.key(
+                Key::from(character),
+                ButtonState::Down,
+                character.to_string(),
+            );
+        }
+
+        self.run()
+    }
+
+    fn mouse_button(
+        &mut self,
+        position: Point,
+        button: MouseButton,
+        state: ButtonState,
+    ) -> &mut Self {
+        let window = self.window.window_mut();
+        window.mouse(position.x(), position.y());
+        window.mouse_event(MouseEvent {
+            position,
+            button,
+            state,
+        });
+        self.run()
+    }
+
+    fn key(&mut self, key: Key, state: ButtonState, text: String) {
+        self.window
+            .window_mut()
+            .key_event(KeyEvent { key, state, text });
+    }
+
+    fn run(&mut self) -> &mut Self {
+        self.window.run();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use orbtk_api::prelude::*;
+    use orbtk_widgets::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_click() {
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+
+        let mut runner = TestRunner::new(move |ctx| {
+            let counter = counter.clone();
+
+            Window::new()
+                .size(100.0, 40.0)
+                .child(
+                    Button::new()
+                        .id("button")
+                        .text("OK")
+                        .on_click(move |_, _| {
+                            counter.set(counter.get() + 1);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        let button = runner.entity_of_child("button").unwrap();
+        runner.click(button);
+
+        assert_eq!(clicks.get(), 1);
+        assert_eq!(runner.entity_of_child("missing"), None);
+    }
+
+    #[test]
+    fn test_slider() {
+        let mut runner = TestRunner::new(|ctx| {
+            Window::new()
+                .size(200.0, 40.0)
+                .child(Slider::new().id("slider").build(ctx))
+                .build(ctx)
+        });
+
+        let slider = runner.entity_of_child("slider").unwrap();
+
+        runner.click(slider);
+        assert!(runner.get::<bool>(slider, "focused"));
+
+        runner.press_key(Key::End);
+        assert_eq!(runner.get::<f64>(slider, "val"), 100.0);
+
+        runner.press_key(Key::Left);
+        assert_eq!(runner.get::<f64>(slider, "val"), 99.0);
+
+        runner.press_key(Key::Home);
+        assert_eq!(runner.get::<f64>(slider, "val"), 0.0);
+
+        let thumb = runner.entity_of_child("id_thumb").unwrap();
+        runner.drag(thumb, (200.0, 20.0));
+        assert_eq!(runner.get::<f64>(slider, "val"), 100.0);
+    }
+
+    #[test]
+    fn test_type_text() {
+        let mut runner = TestRunner::new(|ctx| {
+            Window::new()
+                .size(200.0, 40.0)
+                .child(TextBox::new().id("text_box").build(ctx))
+                .build(ctx)
+        });
+
+        let text_box = runner.entity_of_child("text_box").unwrap();
+        runner.click(text_box).type_text("abc");
+
+        assert_eq!(runner.get::<String16>(text_box, "text").to_string(), "abc");
+    }
+}
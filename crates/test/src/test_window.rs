@@ -0,0 +1,95 @@
+use std::{path::Path, sync::mpsc};
+
+use dces::prelude::Entity;
+use image::RgbaImage;
+use orbtk_api::prelude::{create_window, BuildContext, Fonts, WindowAdapter, Windows};
+use orbtk_shell::headless::{Shell, Window};
+use orbtk_theming::Theme;
+
+use crate::snapshot::{assert_snapshot, to_image, Tolerance};
+
+/// Runs a widget tree in a window of the headless shell. The frames are drawn into an in-memory framebuffer, nothing
+/// is shown on the screen.
+///
+/// The size and the title of the window are read from the root `Window` widget, like for windows of an `Application`.
+pub struct TestWindow {
+    shell: Shell<WindowAdapter>,
+}
+
+impl TestWindow {
+    /// Creates a test window with the default theme and the default fonts. The widget tree is built by `create_fn`,
+    /// no frame is drawn yet.
+    pub fn new<F: Fn(&mut BuildContext) -> Entity + 'static>(create_fn: F) -> Self {
+        Self::with_theme(orbtk_theme::default_theme(), create_fn)
+    }
+
+    /// Creates a test window with the given theme, e.g. to check a widget with each theme of the application.
+    pub fn with_theme<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        theme: Theme,
+        create_fn: F,
+    ) -> Self {
+        let (request_sender, request_receiver) = mpsc::channel();
+        let mut shell = Shell::new(request_receiver);
+
+        let (adapter, settings, receiver) =
+            create_window("", theme, Fonts::default(), None, request_sender, create_fn);
+
+        shell
+            .create_window_from_settings(settings, adapter)
+            .request_receiver(receiver)
+            .build();
+
+        TestWindow { shell }
+    }
+
+    /// Pumps the given number of frames: the requests of the window are handled, the widget tree is updated and drawn.
+    pub fn frames(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.shell.step();
+        }
+
+        self
+    }
+
+    /// Pumps frames until the window is idle. Update timers that elapse later, e.g. of tool tips, are not waited for.
+    pub fn run(&mut self) -> &mut Self {
+        self.shell.run();
+        self
+    }
+
+    /// Gets the headless window, e.g. to read the pixels of the framebuffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window was closed by the widget tree.
+    pub fn window(&self) -> &Window<WindowAdapter> {
+        self.shell
+            .windows()
+            .first()
+            .expect("TestWindow.window: the window is closed.")
+    }
+
+    /// Gets the headless window as mutable reference, e.g. to pass input events to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window was closed by the widget tree.
+    pub fn window_mut(&mut self) -> &mut Window<WindowAdapter> {
+        self.shell
+            .windows_mut()
+            .first_mut()
+            .expect("TestWindow.window_mut: the window is closed.")
+    }
+
+    /// Converts the last drawn frame to an image with straight (not premultiplied) RGBA pixels.
+    pub fn image(&self) -> RgbaImage {
+        let window = self.window();
+        let (width, height) = window.size();
+        to_image(window.framebuffer(), width as u32, height as u32)
+    }
+
+    /// Compares the last drawn frame with the golden PNG image at `path`, see `snapshot::assert_snapshot`.
+    pub fn assert_snapshot(&self, path: impl AsRef<Path>, tolerance: Tolerance) {
+        assert_snapshot(&self.image(), path, tolerance);
+    }
+}
@@ -0,0 +1,49 @@
+use std::{cell::Cell, thread, time::Duration};
+
+use orbtk_api::prelude::*;
+use orbtk_shell::event::MouseButton;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_context_menu_long_press() {
+    let context_menu = Rc::new(Cell::new(Entity(0)));
+    let menu = context_menu.clone();
+
+    let mut runner = TestRunner::new(move |ctx| {
+        menu.set(
+            ContextMenu::new()
+                .item(MenuItem::new().text("Copy").build(ctx))
+                .build(ctx),
+        );
+
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                TextBlock::new()
+                    .id("text_block")
+                    .text("Press me")
+                    .context_menu(menu.get())
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let context_menu = context_menu.get();
+    let text_block = runner.entity_of_child("text_block").unwrap();
+    let position = runner.center(text_block);
+
+    runner.mouse_down(position, MouseButton::Left);
+    assert!(!runner.get::<bool>(context_menu, "open"));
+
+    // the long press is recognized on the first frame after the long press time
+    thread::sleep(Duration::from_millis(
+        GestureSettings::default().long_press_time as u64 + 50,
+    ));
+    runner.window_mut().run();
+    assert!(runner.get::<bool>(context_menu, "open"));
+
+    // the release of the long press keeps the menu open
+    runner.mouse_up(position, MouseButton::Left);
+    assert!(runner.get::<bool>(context_menu, "open"));
+}
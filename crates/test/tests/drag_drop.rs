@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use orbtk_api::prelude::*;
+use orbtk_shell::event::{DropData, ExternalDragEvent as ShellDragEvent, MouseButton};
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_drag_drop() {
+    let drops = Rc::new(RefCell::new(vec![]));
+    let ends = Rc::new(RefCell::new(vec![]));
+    let (dropped, ended) = (drops.clone(), ends.clone());
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let dropped = dropped.clone();
+        let ended = ended.clone();
+
+        Window::new()
+            .size(300.0, 200.0)
+            .child(
+                Stack::new()
+                    .child(
+                        DragSource::new()
+                            .id("drag_source")
+                            .data(DragData::new(42_u32).text("Task"))
+                            .child(Container::new().height(40.0).build(ctx))
+                            .on_drag_end(move |_, _, dropped| ended.borrow_mut().push(dropped))
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id("target")
+                            .height(40.0)
+                            .on_drag_over(|_, drag| drag.data.is::<u32>())
+                            .on_drop(move |_, drag| {
+                                dropped.borrow_mut().push(*drag.data.get::<u32>().unwrap());
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(Container::new().id("other").height(40.0).build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let drag_source = runner.entity_of_child("drag_source").unwrap();
+    let target = runner.entity_of_child("target").unwrap();
+    let other = runner.entity_of_child("other").unwrap();
+    let start = runner.center(drag_source);
+    let (target, other) = (runner.center(target), runner.center(other));
+
+    // the drag starts after the mouse is moved with pressed button
+    runner
+        .mouse_down(start, MouseButton::Left)
+        .mouse_move((start.x() + 10.0, start.y()));
+    assert!(runner.get::<bool>(drag_source, "dragging"));
+
+    runner
+        .mouse_move(target)
+        .mouse_up(target, MouseButton::Left);
+    assert_eq!(*drops.borrow(), vec![42]);
+    assert_eq!(*ends.borrow(), vec![true]);
+    assert!(!runner.get::<bool>(drag_source, "dragging"));
+
+    // a widget without drop handler doesn't accept the drag
+    runner
+        .mouse_down(start, MouseButton::Left)
+        .mouse_move((start.x() + 10.0, start.y()))
+        .mouse_move(other)
+        .mouse_up(other, MouseButton::Left);
+    assert_eq!(drops.borrow().len(), 1);
+    assert_eq!(*ends.borrow(), vec![true, false]);
+}
+
+#[test]
+fn test_external_drop() {
+    let events = Rc::new(RefCell::new(vec![]));
+    let drops = Rc::new(RefCell::new(vec![]));
+    let (entered, dropped) = (events.clone(), drops.clone());
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let (entered, left) = (entered.clone(), entered.clone());
+        let dropped = dropped.clone();
+
+        Window::new()
+            .size(300.0, 100.0)
+            .child(
+                Container::new()
+                    .id("target")
+                    .on_drag_over(|_, drag| drag.data.get_files().is_some())
+                    .on_drag_enter(move |_, _| entered.borrow_mut().push("enter"))
+                    .on_drag_leave(move |_, _| left.borrow_mut().push("leave"))
+                    .on_drop(move |_, drag| {
+                        dropped
+                            .borrow_mut()
+                            .extend(drag.data.get_files().unwrap().iter().cloned());
+                        true
+                    })
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let target = runner.entity_of_child("target").unwrap();
+    let position = runner.center(target);
+    runner.mouse_move(position);
+
+    let files = vec![PathBuf::from("notes.txt")];
+    let drag = |runner: &mut TestRunner, event| {
+        runner.window_mut().window_mut().external_drag_event(event);
+        runner.window_mut().run();
+    };
+
+    // the files are dropped on the widget under the mouse
+    drag(
+        &mut runner,
+        ShellDragEvent::Enter(DropData::Files(files.clone())),
+    );
+    drag(
+        &mut runner,
+        ShellDragEvent::Drop(DropData::Files(files.clone())),
+    );
+    assert_eq!(*events.borrow(), vec!["enter"]);
+    assert_eq!(*drops.borrow(), files);
+
+    // a drop without a preceding drag is also delivered
+    drag(
+        &mut runner,
+        ShellDragEvent::Drop(DropData::Files(files.clone())),
+    );
+    assert_eq!(drops.borrow().len(), 2);
+
+    // a text is not accepted by the widget, a canceled drag is not dropped
+    drag(
+        &mut runner,
+        ShellDragEvent::Drop(DropData::Text("OrbTk".to_string())),
+    );
+    drag(&mut runner, ShellDragEvent::Enter(DropData::Files(files)));
+    drag(&mut runner, ShellDragEvent::Leave);
+    assert_eq!(drops.borrow().len(), 2);
+    assert_eq!(*events.borrow(), vec!["enter", "enter", "enter", "leave"]);
+}
@@ -0,0 +1,53 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::event::Key;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_tab_navigation() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(300.0, 400.0)
+            .child(
+                Stack::new()
+                    .child(TextBox::new().id("text_box").build(ctx))
+                    .child(Button::new().id("button").text("OK").build(ctx))
+                    .child(CheckBox::new().id("check_box").tab_index(1).build(ctx))
+                    .child(Switch::new().id("switch").build(ctx))
+                    .child(Button::new().text("Disabled").enabled(false).build(ctx))
+                    .child(ComboBox::new().id("combo_box").build(ctx))
+                    .child(ToggleButton::new().id("toggle_button").build(ctx))
+                    .child(Slider::new().id("slider").build(ctx))
+                    .child(Hyperlink::new().text("Skipped").tab_index(-1).build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    // the check box comes first by its tab index, the others follow in tree order
+    let order: Vec<Entity> = [
+        "check_box",
+        "text_box",
+        "button",
+        "switch",
+        "combo_box",
+        "toggle_button",
+        "slider",
+        "check_box",
+    ]
+    .iter()
+    .map(|id| runner.entity_of_child(id).unwrap())
+    .collect();
+
+    let root = runner.root();
+
+    for entity in order {
+        runner.press_key(Key::Tab);
+
+        assert_eq!(
+            runner.get::<Global>(root, "global").focused_widget,
+            Some(entity)
+        );
+        assert!(runner.get::<bool>(entity, "focused"));
+    }
+}
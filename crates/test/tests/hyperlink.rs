@@ -0,0 +1,56 @@
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_hyperlink() {
+    let navigated = Rc::new(RefCell::new(vec![]));
+    let urls = navigated.clone();
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let urls = urls.clone();
+
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                Stack::new()
+                    .child(
+                        Hyperlink::new()
+                            .id("link")
+                            .text("OrbTk")
+                            .url("https://github.com/redox-os/orbtk")
+                            .open_in_browser(false)
+                            .on_navigate(move |_, _, url| urls.borrow_mut().push(url))
+                            .build(ctx),
+                    )
+                    .child(
+                        Hyperlink::new()
+                            .id("empty_link")
+                            .text("Empty")
+                            .open_in_browser(false)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let link = runner.entity_of_child("link").unwrap();
+    assert!(!runner.get::<bool>(link, "visited"));
+
+    runner.click(link);
+    assert_eq!(
+        *navigated.borrow(),
+        vec!["https://github.com/redox-os/orbtk".to_string()]
+    );
+    assert!(runner.get::<bool>(link, "visited"));
+    assert!(runner
+        .get::<Selector>(link, "selector")
+        .has_pseudo_class("visited"));
+
+    // a link without url doesn't navigate
+    let empty_link = runner.entity_of_child("empty_link").unwrap();
+    runner.click(empty_link);
+    assert_eq!(navigated.borrow().len(), 1);
+    assert!(!runner.get::<bool>(empty_link, "visited"));
+}
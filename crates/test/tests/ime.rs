@@ -0,0 +1,43 @@
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_ime_composition() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 40.0)
+            .child(TextBox::new().id("text_box").build(ctx))
+            .build(ctx)
+    });
+
+    let text_box = runner.entity_of_child("text_box").unwrap();
+    runner.click(text_box).type_text("ab");
+
+    let compose = |runner: &mut TestRunner, event| {
+        runner.window_mut().window_mut().ime_event(event);
+        runner.window_mut().run();
+    };
+    let text = |runner: &mut TestRunner, key| runner.get::<String16>(text_box, key).to_string();
+
+    // the composition is shown at the caret but not inserted into the text
+    compose(&mut runner, ImeEvent::Start);
+    compose(&mut runner, ImeEvent::Update("ni".to_string()));
+    assert_eq!(text(&mut runner, "composition"), "ni");
+    assert_eq!(text(&mut runner, "text"), "ab");
+    assert!(runner.window().window().ime_position().is_some());
+
+    compose(&mut runner, ImeEvent::Commit("\u{4f60}".to_string()));
+    assert_eq!(text(&mut runner, "composition"), "");
+    assert_eq!(text(&mut runner, "text"), "ab\u{4f60}");
+
+    // a canceled composition commits nothing
+    compose(&mut runner, ImeEvent::Start);
+    compose(&mut runner, ImeEvent::Update("hao".to_string()));
+    compose(&mut runner, ImeEvent::Commit(String::new()));
+    assert_eq!(text(&mut runner, "composition"), "");
+    assert_eq!(text(&mut runner, "text"), "ab\u{4f60}");
+
+    runner.type_text("c");
+    assert_eq!(text(&mut runner, "text"), "ab\u{4f60}c");
+}
@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use orbtk_api::prelude::*;
+use orbtk_shell::event::Key;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_list_view_keyboard_navigation() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                ListView::new()
+                    .id("list_view")
+                    .count(50)
+                    .item_height(24.0)
+                    .items_builder(|ctx, index| {
+                        TextBlock::new().text(format!("Item {}", index)).build(ctx)
+                    })
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let list_view = runner.entity_of_child("list_view").unwrap();
+    let scroll_viewer = runner.entity_of_child("scroll_viewer").unwrap();
+    let view_port = runner.get::<Rectangle>(scroll_viewer, "bounds").height();
+
+    let mut press = |key| {
+        runner.press_key(key);
+        (
+            runner
+                .get::<SelectedIndices>(list_view, "selected_indices")
+                .0,
+            runner.get::<Point>(scroll_viewer, "scroll_offset").y(),
+        )
+    };
+    let selected = |index: usize| [index].iter().copied().collect::<HashSet<usize>>();
+
+    // focuses the list
+    assert_eq!(press(Key::Tab), (HashSet::new(), 0.0));
+
+    // the first items are visible without scrolling
+    assert_eq!(press(Key::Down), (selected(0), 0.0));
+    assert_eq!(press(Key::Down), (selected(1), 0.0));
+
+    // navigating past the view port scrolls the selected item to its bottom
+    for _ in 0..8 {
+        press(Key::Down);
+    }
+    assert_eq!(press(Key::Down), (selected(10), 11.0 * 24.0 - view_port));
+
+    // the last item is not realized before it is selected
+    assert_eq!(press(Key::End), (selected(49), 50.0 * 24.0 - view_port));
+    assert_eq!(press(Key::Up), (selected(48), 50.0 * 24.0 - view_port));
+    assert_eq!(press(Key::Home), (selected(0), 0.0));
+}
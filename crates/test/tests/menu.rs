@@ -0,0 +1,67 @@
+use std::cell::Cell;
+
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_menu_bar() {
+    let activated = Rc::new(RefCell::new(vec![]));
+    let ids = activated.clone();
+    let items = Rc::new(Cell::new((Entity(0), Entity(0))));
+    let menu_items = items.clone();
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let ids = ids.clone();
+        let wrap = MenuItem::new()
+            .id("wrap")
+            .text("Wrap")
+            .checkable(true)
+            .build(ctx);
+        let open = MenuItem::new().id("open").text("Open").build(ctx);
+        menu_items.set((wrap, open));
+
+        Window::new()
+            .size(300.0, 200.0)
+            .child(
+                MenuBar::new()
+                    .v_align("start")
+                    .child(
+                        Menu::new()
+                            .id("file")
+                            .text("File")
+                            .item(wrap)
+                            .item(MenuSeparator::new().build(ctx))
+                            .item(open)
+                            .build(ctx),
+                    )
+                    .on_activated(move |_, _, id| ids.borrow_mut().push(id))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let (wrap, open) = items.get();
+    let file = runner.entity_of_child("file").unwrap();
+
+    runner.click(file);
+    assert!(runner.get::<bool>(file, "selected"));
+
+    // a checkable item is toggled and closes the menu on activation
+    runner.click(wrap);
+    assert_eq!(*activated.borrow(), vec!["wrap".to_string()]);
+    assert!(runner.get::<bool>(wrap, "checked"));
+    assert!(!runner.get::<bool>(file, "selected"));
+
+    runner.click(file).click(open);
+    assert_eq!(
+        *activated.borrow(),
+        vec!["wrap".to_string(), "open".to_string()]
+    );
+    assert!(!runner.get::<bool>(open, "checked"));
+
+    // a click outside of the menu closes it without activation
+    runner.click(file).click_at((280.0, 180.0));
+    assert!(!runner.get::<bool>(file, "selected"));
+    assert_eq!(activated.borrow().len(), 2);
+}
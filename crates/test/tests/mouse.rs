@@ -0,0 +1,108 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::event::MouseButton;
+use orbtk_test::prelude::*;
+use orbtk_utils::prelude::Point;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_mouse_enter_leave() {
+    let events = Rc::new(RefCell::new(vec![]));
+    let recorded = events.clone();
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let button = |id: &'static str, ctx: &mut BuildContext| {
+            let (entered, left) = (recorded.clone(), recorded.clone());
+
+            Button::new()
+                .id(id)
+                .text(id)
+                .on_mouse_enter(move |_, _| {
+                    entered.borrow_mut().push(format!("enter {}", id));
+                    false
+                })
+                .on_mouse_leave(move |_, _| {
+                    left.borrow_mut().push(format!("leave {}", id));
+                    false
+                })
+                .build(ctx)
+        };
+
+        Window::new()
+            .size(200.0, 200.0)
+            .child(
+                Stack::new()
+                    .child(button("first", ctx))
+                    .child(button("second", ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let first = runner.entity_of_child("first").unwrap();
+    let second = runner.entity_of_child("second").unwrap();
+    let is_hovered = |runner: &mut TestRunner, entity| {
+        runner
+            .get::<Selector>(entity, "selector")
+            .has_pseudo_class("hover")
+    };
+
+    let position = runner.center(first);
+    runner.mouse_move(position);
+    assert_eq!(*events.borrow(), vec!["enter first"]);
+    assert!(is_hovered(&mut runner, first));
+
+    // a move inside of the widget raises no new event
+    runner.mouse_move((position.x() + 1.0, position.y()));
+    assert_eq!(events.borrow().len(), 1);
+
+    let position = runner.center(second);
+    runner.mouse_move(position);
+    assert_eq!(
+        *events.borrow(),
+        vec!["enter first", "leave first", "enter second"]
+    );
+    assert!(!is_hovered(&mut runner, first));
+    assert!(is_hovered(&mut runner, second));
+
+    runner.mouse_move((100.0, 190.0));
+    assert_eq!(events.borrow().last().unwrap(), "leave second");
+    assert!(!is_hovered(&mut runner, second));
+}
+
+#[test]
+fn test_cursor() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 200.0)
+            .child(
+                Stack::new()
+                    .child(TextBox::new().id("text_box").build(ctx))
+                    .child(Hyperlink::new().id("link").text("OrbTk").build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let text_box = runner.entity_of_child("text_box").unwrap();
+    let link = runner.entity_of_child("link").unwrap();
+    let (text_box, link) = (runner.center(text_box), runner.center(link));
+    let empty = Point::new(100.0, 190.0);
+
+    runner.mouse_move(text_box);
+    assert_eq!(runner.window().window().cursor(), CursorIcon::Text);
+
+    runner.mouse_move(link);
+    assert_eq!(runner.window().window().cursor(), CursorIcon::Pointer);
+
+    runner.mouse_move(empty);
+    assert_eq!(runner.window().window().cursor(), CursorIcon::Default);
+
+    // the cursor is kept while the mouse button is pressed
+    runner
+        .mouse_down(text_box, MouseButton::Left)
+        .mouse_move(empty);
+    assert_eq!(runner.window().window().cursor(), CursorIcon::Text);
+
+    runner.mouse_up(empty, MouseButton::Left).mouse_move(empty);
+    assert_eq!(runner.window().window().cursor(), CursorIcon::Default);
+}
@@ -0,0 +1,41 @@
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_password_box_reveal() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 40.0)
+            .child(PasswordBox::new().id("password_box").build(ctx))
+            .build(ctx)
+    });
+
+    let password_box = runner.entity_of_child("password_box").unwrap();
+    runner.click(password_box).type_text("abc");
+
+    // the text keeps the password, only the mask is shown
+    assert_eq!(
+        runner.get::<String16>(password_box, "text").to_string(),
+        "abc"
+    );
+    assert_eq!(
+        runner.get::<String16>(password_box, "mask").to_string(),
+        "***"
+    );
+
+    let reveal = runner.entity_of_child("password_box_reveal").unwrap();
+    runner.click(reveal);
+    assert!(runner.get::<bool>(password_box, "revealed"));
+    assert_eq!(
+        runner.get::<String16>(password_box, "mask").to_string(),
+        "abc"
+    );
+
+    runner.click(reveal);
+    assert!(!runner.get::<bool>(password_box, "revealed"));
+    assert_eq!(
+        runner.get::<String16>(password_box, "mask").to_string(),
+        "***"
+    );
+}
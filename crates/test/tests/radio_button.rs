@@ -0,0 +1,93 @@
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_radio_button_group() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(300.0, 400.0)
+            .child(
+                Stack::new()
+                    .child(
+                        Stack::new()
+                            .child(
+                                RadioButton::new()
+                                    .id("small")
+                                    .text("Small")
+                                    .group("size")
+                                    .selected(true)
+                                    .build(ctx),
+                            )
+                            .child(
+                                RadioButton::new()
+                                    .id("large")
+                                    .text("Large")
+                                    .group("size")
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        RadioButton::new()
+                            .id("medium")
+                            .text("Medium")
+                            .group("size")
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::new()
+                            .child(RadioButton::new().id("red").text("Red").build(ctx))
+                            .child(RadioButton::new().id("blue").text("Blue").build(ctx))
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::new()
+                            .child(
+                                RadioButton::new()
+                                    .id("other")
+                                    .text("Other")
+                                    .selected(true)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let ids = ["small", "large", "medium", "red", "blue", "other"];
+    let entities: Vec<Entity> = ids
+        .iter()
+        .map(|id| runner.entity_of_child(id).unwrap())
+        .collect();
+
+    let assert_selected = |runner: &mut TestRunner, selected: &[&str]| {
+        for (id, entity) in ids.iter().zip(entities.iter()) {
+            assert_eq!(
+                runner.get::<bool>(*entity, "selected"),
+                selected.contains(id),
+                "selected of {}",
+                id
+            );
+        }
+    };
+
+    assert_selected(&mut runner, &["small", "other"]);
+
+    // the radio buttons of a group are exclusive across parents
+    let large = runner.entity_of_child("large").unwrap();
+    runner.click(large);
+    assert_selected(&mut runner, &["large", "other"]);
+
+    let medium = runner.entity_of_child("medium").unwrap();
+    runner.click(medium).click(medium);
+    assert_selected(&mut runner, &["medium", "other"]);
+
+    // radio buttons without group are grouped by their parent
+    let red = runner.entity_of_child("red").unwrap();
+    let blue = runner.entity_of_child("blue").unwrap();
+    runner.click(red).click(blue);
+    assert_selected(&mut runner, &["medium", "blue", "other"]);
+}
@@ -0,0 +1,52 @@
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_collapse_scroll_bars() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(100.0, 100.0)
+            .child(
+                ScrollIndicator::new()
+                    .id("scroll_indicator")
+                    .interactive(true)
+                    .content_bounds(Rectangle::new((0.0, 0.0), (400.0, 400.0)))
+                    .view_port_bounds(Rectangle::new((0.0, 0.0), (100.0, 100.0)))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let scroll_indicator = runner.entity_of_child("scroll_indicator").unwrap();
+    let children: Vec<Entity> = [
+        "scroll_track_vertical",
+        "scroll_bar_vertical",
+        "scroll_track_horizontal",
+        "scroll_bar_horizontal",
+    ]
+    .iter()
+    .map(|id| runner.entity_of_child(id).unwrap())
+    .collect();
+
+    for child in &children {
+        assert_eq!(
+            runner.get::<Visibility>(*child, "visibility"),
+            Visibility::Visible
+        );
+    }
+
+    // the content shrinks below the size of the view port
+    runner.set(
+        scroll_indicator,
+        "content_bounds",
+        Rectangle::new((0.0, 0.0), (50.0, 50.0)),
+    );
+
+    for child in &children {
+        assert_eq!(
+            runner.get::<Visibility>(*child, "visibility"),
+            Visibility::Collapsed
+        );
+    }
+}
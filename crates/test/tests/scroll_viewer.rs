@@ -0,0 +1,48 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::event::SCROLL_LINE_HEIGHT;
+use orbtk_test::prelude::*;
+use orbtk_utils::prelude::Point;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_scroll_viewer_scroll() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(100.0, 100.0)
+            .child(
+                ScrollViewer::new()
+                    .id("scroll_viewer")
+                    .child(Container::new().width(400.0).height(400.0).build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let scroll_viewer = runner.entity_of_child("scroll_viewer").unwrap();
+    let center = runner.center(scroll_viewer);
+    runner.mouse_move(center);
+
+    let scroll = |runner: &mut TestRunner, delta| {
+        runner.window_mut().window_mut().scroll(delta);
+        runner.window_mut().run();
+        runner.get::<Point>(scroll_viewer, "scroll_offset")
+    };
+
+    // the pixel delta of a mouse wheel is scrolled with the speed of the scroll viewer
+    assert_eq!(
+        scroll(&mut runner, ScrollDelta::from_lines(0.0, -2.0)),
+        Point::new(0.0, 4.0 * SCROLL_LINE_HEIGHT)
+    );
+
+    // horizontal scrolling of a trackpad
+    assert_eq!(
+        scroll(&mut runner, ScrollDelta::from_pixels(-10.0, 0.0)),
+        Point::new(20.0, 4.0 * SCROLL_LINE_HEIGHT)
+    );
+
+    // the offset stays inside of the child
+    assert_eq!(
+        scroll(&mut runner, ScrollDelta::from_lines(100.0, -100.0)),
+        Point::new(0.0, 300.0)
+    );
+}
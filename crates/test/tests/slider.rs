@@ -0,0 +1,31 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::event::Key;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_slider_keyboard_step() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 40.0)
+            .child(Slider::new().id("slider").val(12.0).step(5.0).build(ctx))
+            .build(ctx)
+    });
+
+    let slider = runner.entity_of_child("slider").unwrap();
+    let mut press = |key| {
+        runner.press_key(key);
+        runner.get::<f64>(slider, "val")
+    };
+
+    // focuses the slider
+    assert_eq!(press(Key::Tab), 12.0);
+
+    // the val is moved to the next multiple of the step
+    assert_eq!(press(Key::Right), 15.0);
+    assert_eq!(press(Key::Left), 10.0);
+    assert_eq!(press(Key::PageUp), 20.0);
+    assert_eq!(press(Key::PageDown), 10.0);
+    assert_eq!(press(Key::End), 100.0);
+    assert_eq!(press(Key::Right), 100.0);
+}
@@ -0,0 +1,84 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::event::{ButtonState, Key, KeyEvent};
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_replace_selected_text() {
+    let mut runner = text_box_runner();
+    let text_box = runner.entity_of_child("text_box").unwrap();
+    runner.click(text_box).type_text("hello world");
+
+    // selects "world"
+    shift(&mut runner, ButtonState::Down);
+    for _ in 0..5 {
+        runner.press_key(Key::Left);
+    }
+    shift(&mut runner, ButtonState::Up);
+    assert_eq!(
+        runner.get::<TextSelection>(text_box, "text_selection"),
+        TextSelection::from((6, 5))
+    );
+
+    runner.type_text("there");
+    assert_eq!(
+        runner.get::<String16>(text_box, "text").to_string(),
+        "hello there"
+    );
+    assert_eq!(
+        runner.get::<TextSelection>(text_box, "text_selection"),
+        TextSelection::from((11, 0))
+    );
+}
+
+#[test]
+fn test_collapse_selection() {
+    let mut runner = text_box_runner();
+    let text_box = runner.entity_of_child("text_box").unwrap();
+    runner.click(text_box).type_text("abcdef");
+    runner.press_key(Key::Left).press_key(Key::Left);
+
+    // selects "cd", right places the caret at the end of the selection
+    shift(&mut runner, ButtonState::Down);
+    runner.press_key(Key::Left).press_key(Key::Left);
+    shift(&mut runner, ButtonState::Up);
+    runner.press_key(Key::Right);
+    assert_eq!(
+        runner.get::<TextSelection>(text_box, "text_selection"),
+        TextSelection::from((4, 0))
+    );
+
+    // selects "e", left places the caret at the start of the selection
+    shift(&mut runner, ButtonState::Down);
+    runner.press_key(Key::Right);
+    shift(&mut runner, ButtonState::Up);
+    runner.press_key(Key::Left);
+    assert_eq!(
+        runner.get::<TextSelection>(text_box, "text_selection"),
+        TextSelection::from((4, 0))
+    );
+    assert_eq!(
+        runner.get::<String16>(text_box, "text").to_string(),
+        "abcdef"
+    );
+}
+
+// -- Helpers --
+
+fn text_box_runner() -> TestRunner {
+    TestRunner::new(|ctx| {
+        Window::new()
+            .size(200.0, 40.0)
+            .child(TextBox::new().id("text_box").build(ctx))
+            .build(ctx)
+    })
+}
+
+fn shift(runner: &mut TestRunner, state: ButtonState) {
+    runner.window_mut().window_mut().key_event(KeyEvent {
+        key: Key::ShiftL,
+        state,
+        text: String::new(),
+    });
+    runner.window_mut().run();
+}
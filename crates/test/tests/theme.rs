@@ -0,0 +1,40 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::color_scheme::ColorScheme;
+use orbtk_test::prelude::*;
+use orbtk_theme::{dark_theme, light_theme};
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_switch_theme() {
+    fn window(follow_system_theme: bool) -> impl Fn(&mut BuildContext) -> Entity {
+        move |ctx| {
+            Window::new()
+                .size(100.0, 40.0)
+                .follow_system_theme(follow_system_theme)
+                .child(Button::new().id("button").text("OK").build(ctx))
+                .build(ctx)
+        }
+    }
+
+    fn background(runner: &mut TestRunner) -> Brush {
+        let button = runner.entity_of_child("button").unwrap();
+        runner.get::<Brush>(button, "background")
+    }
+
+    let dark = background(&mut TestRunner::with_theme(dark_theme(), window(false)));
+    let light = background(&mut TestRunner::with_theme(light_theme(), window(false)));
+    assert_ne!(dark, light);
+
+    // the properties of all widgets are resolved against the switched theme
+    let mut runner = TestRunner::with_theme(light_theme(), window(true));
+
+    for (color_scheme, expected) in [(ColorScheme::Dark, dark), (ColorScheme::Light, light)] {
+        runner
+            .window_mut()
+            .window_mut()
+            .color_scheme_changed(color_scheme);
+        runner.window_mut().run();
+
+        assert_eq!(background(&mut runner), expected);
+    }
+}
@@ -0,0 +1,29 @@
+use orbtk_api::prelude::*;
+use orbtk_shell::WindowMode;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_window_mode_changed_by_platform() {
+    let mut runner = TestRunner::new(|ctx| {
+        Window::new()
+            .size(300.0, 100.0)
+            .child(WindowTitleBar::new().title("Title").build(ctx))
+            .build(ctx)
+    });
+
+    let maximize = runner.entity_of_child("window_title_bar_maximize").unwrap();
+
+    // the user maximizes the window by the decorations of the platform
+    runner
+        .window_mut()
+        .window_mut()
+        .mode_changed(WindowMode::Maximized);
+    runner.window_mut().run();
+
+    runner.click(maximize);
+    assert_eq!(runner.window().window().mode(), WindowMode::Normal);
+
+    runner.click(maximize);
+    assert_eq!(runner.window().window().mode(), WindowMode::Maximized);
+}
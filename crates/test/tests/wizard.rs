@@ -0,0 +1,83 @@
+use std::cell::Cell;
+
+use orbtk_api::prelude::*;
+use orbtk_test::prelude::*;
+use orbtk_widgets::prelude::*;
+
+#[test]
+fn test_wizard() {
+    let valid = Rc::new(Cell::new(false));
+    let validations = Rc::new(RefCell::new(vec![]));
+    let finished = Rc::new(Cell::new(0));
+    let (is_valid, validated, counter) = (valid.clone(), validations.clone(), finished.clone());
+
+    let mut runner = TestRunner::new(move |ctx| {
+        let is_valid = is_valid.clone();
+        let validated = validated.clone();
+        let counter = counter.clone();
+
+        Window::new()
+            .size(400.0, 300.0)
+            .child(
+                Wizard::new()
+                    .id("wizard")
+                    .step_with_validation(
+                        "Account",
+                        TextBlock::new().id("account").text("Account").build(ctx),
+                        move |_, index| {
+                            validated.borrow_mut().push(index);
+                            is_valid.get()
+                        },
+                    )
+                    .step(
+                        "Summary",
+                        TextBlock::new().id("summary").text("Summary").build(ctx),
+                    )
+                    .on_finish(move |_, _| counter.set(counter.get() + 1))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let wizard = runner.entity_of_child("wizard").unwrap();
+    let account = runner.entity_of_child("account").unwrap();
+    let summary = runner.entity_of_child("summary").unwrap();
+    let next = runner.entity_of_child("id_next").unwrap();
+
+    assert_eq!(runner.get::<usize>(wizard, "current_step"), 0);
+    assert_eq!(
+        runner.get::<Visibility>(summary, "visibility"),
+        Visibility::Collapsed
+    );
+
+    // the failed validation blocks advancing
+    runner.click(next);
+    assert_eq!(*validations.borrow(), vec![0]);
+    assert_eq!(runner.get::<usize>(wizard, "current_step"), 0);
+
+    valid.set(true);
+    runner.click(next);
+    assert_eq!(runner.get::<usize>(wizard, "current_step"), 1);
+    assert_eq!(
+        runner.get::<Visibility>(account, "visibility"),
+        Visibility::Collapsed
+    );
+    assert_eq!(
+        runner.get::<Visibility>(summary, "visibility"),
+        Visibility::Visible
+    );
+
+    // the last step shows the finish button instead of the next button
+    assert_eq!(
+        runner.get::<Visibility>(next, "visibility"),
+        Visibility::Collapsed
+    );
+    let finish = runner.entity_of_child("id_finish").unwrap();
+    runner.click(finish);
+    assert_eq!(finished.get(), 1);
+
+    let back = runner.entity_of_child("id_back").unwrap();
+    runner.click(back);
+    assert_eq!(runner.get::<usize>(wizard, "current_step"), 0);
+    assert!(!runner.get::<bool>(back, "enabled"));
+}
@@ -5,6 +5,9 @@ It provides fonts, icons and colors.
 
  */
 
+#[macro_use]
+extern crate lazy_static;
+
 use orbtk_theming::{config::ThemeConfig, prelude::*};
 
 /// provides `constants` to reference colors.
@@ -48,30 +51,57 @@ pub fn default_theme() -> Theme {
     redox_theme()
 }
 
+/// Creates the configuration of the default theme, it could be extended by a custom theme.
+#[cfg(all(not(feature = "light"), not(feature = "redox")))]
+pub fn default_theme_config() -> ThemeConfig {
+    dark_theme_config()
+}
+
+/// Creates the configuration of the default theme, it could be extended by a custom theme.
+#[cfg(feature = "light")]
+pub fn default_theme_config() -> ThemeConfig {
+    light_theme_config()
+}
+
+/// Creates the configuration of the default theme, it could be extended by a custom theme.
+#[cfg(feature = "redox")]
+pub fn default_theme_config() -> ThemeConfig {
+    redox_theme_config()
+}
+
 /// Creates OrbTks default dark theme.
 pub fn dark_theme() -> Theme {
-    Theme::from_config(
-        ThemeConfig::from(DARK_THEME_RON)
-            .extend(ThemeConfig::from(COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
-    )
+    Theme::from_config(dark_theme_config())
+}
+
+/// Creates the configuration of OrbTks default dark theme.
+pub fn dark_theme_config() -> ThemeConfig {
+    ThemeConfig::from(DARK_THEME_RON)
+        .extend(ThemeConfig::from(COLORS_RON))
+        .extend(ThemeConfig::from(FONTS_RON))
 }
 
 /// Creates OrbTks default light theme.
 pub fn light_theme() -> Theme {
-    Theme::from_config(
-        ThemeConfig::from(LIGHT_THEME_RON)
-            .extend(ThemeConfig::from(COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
-    )
+    Theme::from_config(light_theme_config())
+}
+
+/// Creates the configuration of OrbTks default light theme.
+pub fn light_theme_config() -> ThemeConfig {
+    ThemeConfig::from(LIGHT_THEME_RON)
+        .extend(ThemeConfig::from(COLORS_RON))
+        .extend(ThemeConfig::from(FONTS_RON))
 }
 
 /// Creates OrbTks redox theme.
 pub fn redox_theme() -> Theme {
-    Theme::from_config(
-        ThemeConfig::from(REDOX_THEME_RON)
-            .extend(ThemeConfig::from(COLORS_RON))
-            .extend(ThemeConfig::from(REDOX_COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
-    )
+    Theme::from_config(redox_theme_config())
+}
+
+/// Creates the configuration of OrbTks redox theme.
+pub fn redox_theme_config() -> ThemeConfig {
+    ThemeConfig::from(REDOX_THEME_RON)
+        .extend(ThemeConfig::from(COLORS_RON))
+        .extend(ThemeConfig::from(REDOX_COLORS_RON))
+        .extend(ThemeConfig::from(FONTS_RON))
 }
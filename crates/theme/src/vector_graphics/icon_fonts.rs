@@ -0,0 +1,199 @@
+use std::{collections::HashMap, fs, path::Path, sync::RwLock};
+
+/// The code points of the `MaterialIcons-Regular` font, registered by default.
+pub const MATERIAL_ICONS_CODEPOINTS: &str =
+    include_str!("../../assets/common/MaterialIcons-Regular.codepoints");
+
+lazy_static! {
+    static ref ICON_FONTS: RwLock<HashMap<String, IconFontManifest>> = {
+        let mut icon_fonts = HashMap::new();
+        icon_fonts.insert(
+            String::from("MaterialIcons-Regular"),
+            IconFontManifest::from_codepoints(MATERIAL_ICONS_CODEPOINTS),
+        );
+        RwLock::new(icon_fonts)
+    };
+}
+
+/// Maps the icon names of an icon font to their glyphs.
+///
+/// A manifest is read from a code points file like the `codepoints` file of the Material Icons. Each line contains
+/// the name of an icon and its hexadecimal code point, e.g. `arrow_drop_down e5c5`. Empty lines and lines that
+/// start with `#` are skipped.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IconFontManifest {
+    glyphs: HashMap<String, String>,
+}
+
+impl IconFontManifest {
+    /// Creates an empty manifest.
+    pub fn new() -> Self {
+        IconFontManifest::default()
+    }
+
+    /// Reads a manifest from the content of a code points file. Invalid lines are skipped.
+    pub fn from_codepoints(codepoints: &str) -> Self {
+        let mut manifest = IconFontManifest::new();
+
+        for line in codepoints.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+
+            if let (Some(name), Some(glyph)) =
+                (parts.next(), parts.next().and_then(parse_code_point))
+            {
+                manifest.glyphs.insert(normalize_name(name), glyph);
+            }
+        }
+
+        manifest
+    }
+
+    /// Loads a manifest from a code points file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map(|codepoints| IconFontManifest::from_codepoints(&codepoints))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Inserts or replaces the glyph of the icon with the given name.
+    pub fn insert(&mut self, name: impl Into<String>, glyph: impl Into<String>) {
+        self.glyphs
+            .insert(normalize_name(&name.into()), glyph.into());
+    }
+
+    /// Adds the icons of the other manifest. Icons with the same name are replaced.
+    pub fn extend(&mut self, other: IconFontManifest) {
+        self.glyphs.extend(other.glyphs);
+    }
+
+    /// Gets the glyph of the icon with the given name.
+    pub fn glyph(&self, name: &str) -> Option<&str> {
+        self.glyphs.get(&normalize_name(name)).map(String::as_str)
+    }
+
+    /// Gets the number of icons.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Returns `true` if the manifest has no icons.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for IconFontManifest {
+    fn from(s: &str) -> IconFontManifest {
+        IconFontManifest::from_codepoints(s)
+    }
+}
+
+impl From<String> for IconFontManifest {
+    fn from(s: String) -> IconFontManifest {
+        IconFontManifest::from_codepoints(&s)
+    }
+}
+
+// --- Conversions ---
+
+/// Registers the manifest of an icon font family. If the family is already registered the icons are added to the
+/// existing manifest.
+///
+/// The font itself has to be registered in the render context with the same family name.
+pub fn register_icon_font(family: impl Into<String>, manifest: impl Into<IconFontManifest>) {
+    let mut icon_fonts = ICON_FONTS.write().unwrap();
+    let family = family.into();
+    let manifest = manifest.into();
+
+    match icon_fonts.get_mut(&family) {
+        Some(registered) => registered.extend(manifest),
+        None => {
+            icon_fonts.insert(family, manifest);
+        }
+    }
+}
+
+/// Loads the manifest of an icon font family from a code points file and registers it.
+pub fn load_icon_font<P: AsRef<Path>>(family: impl Into<String>, path: P) -> Result<(), String> {
+    register_icon_font(family, IconFontManifest::from_path(path)?);
+    Ok(())
+}
+
+/// Gets the glyph of the icon with the given name from the manifest of the icon font family.
+pub fn icon_glyph(family: &str, name: &str) -> Option<String> {
+    ICON_FONTS
+        .read()
+        .unwrap()
+        .get(family)
+        .and_then(|manifest| manifest.glyph(name))
+        .map(String::from)
+}
+
+// --- Helpers --
+
+// Icon names are case insensitive and could be written with `-` or `_`, e.g. `Arrow-Drop-Down`.
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace('-', "_")
+}
+
+// Parses a hexadecimal code point like `e5c5`, `0xe5c5` or `U+E5C5`.
+fn parse_code_point(code_point: &str) -> Option<String> {
+    let hex = code_point
+        .trim_start_matches("0x")
+        .trim_start_matches("U+")
+        .trim_start_matches("u+");
+
+    u32::from_str_radix(hex, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+        .map(String::from)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_codepoints() {
+        let manifest = IconFontManifest::from_codepoints(
+            "# comment\n\narrow_drop_down e5c5\nclose 0xe5cd\ninvalid\nbroken zz\n",
+        );
+
+        assert_eq!(manifest.len(), 2);
+        assert_eq!(manifest.glyph("arrow_drop_down"), Some("\u{e5c5}"));
+        assert_eq!(manifest.glyph("Arrow-Drop-Down"), Some("\u{e5c5}"));
+        assert_eq!(manifest.glyph("close"), Some("\u{e5cd}"));
+        assert_eq!(manifest.glyph("invalid"), None);
+    }
+
+    #[test]
+    fn test_register_icon_font() {
+        assert_eq!(
+            icon_glyph("MaterialIcons-Regular", "close"),
+            Some(String::from(
+                crate::vector_graphics::material_icons_font::MD_CLOSE
+            ))
+        );
+        assert_eq!(icon_glyph("test-icons", "star"), None);
+
+        register_icon_font("test-icons", "star e838");
+        register_icon_font("test-icons", "home e88a");
+
+        assert_eq!(
+            icon_glyph("test-icons", "star"),
+            Some(String::from("\u{e838}"))
+        );
+        assert_eq!(
+            icon_glyph("test-icons", "home"),
+            Some(String::from("\u{e88a}"))
+        );
+    }
+}
@@ -1,5 +1,11 @@
+pub use self::icon_fonts::*;
 pub use self::material_icons_font::*;
 
+/// Icon fonts are registered with a manifest that maps icon names to
+/// their glyphs. The manifest of the `Material Icons Font` is registered
+/// by default.
+pub mod icon_fonts;
+
 /// Icons in the `Material Icons Font` family are accessible via
 /// their unique codepoints, representing the `glyph`. The codepoints
 /// are mapped to this given constants.
@@ -111,6 +111,13 @@ impl<'a> ThemeConfig {
     }
 }
 
+impl ThemeConfig {
+    /// Parses a theme from the given RON string. Returns an error message if the string is not a valid theme.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        from_str(s).map_err(|e| e.to_string())
+    }
+}
+
 impl From<&str> for ThemeConfig {
     fn from(s: &str) -> Self {
         from_str(s).unwrap()
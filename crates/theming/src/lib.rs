@@ -1,9 +1,11 @@
+pub use self::resources::*;
 pub use self::selector::*;
 pub use self::style::*;
 pub use self::theme::*;
 
 pub mod config;
 pub mod prelude;
+mod resources;
 mod selector;
 mod style;
 mod theme;
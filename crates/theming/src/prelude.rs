@@ -1 +1 @@
-pub use crate::{Selector, Style, Theme};
+pub use crate::{Resources, Selector, Style, Theme};
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use ron::Value;
+
+/// Describes a set of theme resources, e.g. the colors of a palette. Style properties reference a resource by its
+/// key with a leading `$`, e.g. `"$PRIMARY_COLOR"`. Resources set on a widget override the resources of the theme
+/// for the widget and its children.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Container::new()
+///     .resources(Resources::new().with("PRIMARY_COLOR", "#3f51b5"))
+///     .build(ctx)
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Resources {
+    values: HashMap<String, Value>,
+}
+
+impl Resources {
+    /// Creates an empty set of resources.
+    pub fn new() -> Self {
+        Resources::default()
+    }
+
+    /// Builder method that inserts a string resource like a color.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(key, Value::String(value.into()));
+        self
+    }
+
+    /// Inserts a resource. An existing resource with the same key is replaced.
+    pub fn insert(&mut self, key: impl Into<String>, value: Value) {
+        self.values.insert(key.into(), value);
+    }
+
+    /// Removes the resource with the given key.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.values.remove(key)
+    }
+
+    /// Gets the resource with the given key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    /// Returns `true` if there are no resources.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl From<HashMap<String, Value>> for Resources {
+    fn from(values: HashMap<String, Value>) -> Self {
+        Resources { values }
+    }
+}
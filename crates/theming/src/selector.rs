@@ -1,28 +1,147 @@
 use std::fmt;
 
+/// The pseudo-classes in the order of their priority. If more than one pseudo-class is active the state of the first
+/// one is used.
+pub static PSEUDO_CLASSES: [&str; 5] = ["disabled", "pressed", "selected", "focused", "hover"];
+
+/// Converts the name of a pseudo-class to the name of the state in the theme, e.g. `:active` to `pressed`.
+pub fn pseudo_class_state(pseudo_class: &str) -> &str {
+    match pseudo_class.trim_start_matches(':') {
+        "active" => "pressed",
+        "checked" => "selected",
+        "focus" => "focused",
+        state => state,
+    }
+}
+
 /// The selector is used to read a property value from the `Theme`.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Selector {
     /// Represents the key of a style.
     pub style: Option<String>,
 
-    /// Used to reference the state property list of the given style.
+    /// Used to reference the state property list of the given style. A state that is set manually takes precedence
+    /// over the pseudo-classes.
     pub state: Option<String>,
 
+    // The active pseudo-classes like `hover` or `pressed`.
+    pseudo_classes: Vec<String>,
+
+    // Additional classes that could be matched by the rules of the theme, e.g. `primary` in `button.primary`.
+    classes: Vec<String>,
+
     /// Check if the selector is dirty.
     dirty: bool,
 }
 
 impl Selector {
-    /// Creates a new selector with the given style key.
+    /// Creates a new selector with the given style key. Additional classes are separated by whitespace, e.g.
+    /// `button primary` creates a selector with the style `button` and the class `primary`.
     pub fn new(style: impl Into<String>) -> Self {
+        let style = style.into();
+        let mut words = style.split_whitespace();
+
+        let (style, classes) = match words.next() {
+            Some(first) => (first.to_string(), words.map(|c| c.to_string()).collect()),
+            None => (style, vec![]),
+        };
+
         Selector {
-            style: Some(style.into()),
+            style: Some(style),
             state: None,
+            pseudo_classes: vec![],
+            classes,
             dirty: true,
         }
     }
 
+    /// Gets the additional classes of the selector.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Adds the given class. Returns `true` if the class was not set before.
+    pub fn add_class(&mut self, class: impl Into<String>) -> bool {
+        let class = class.into();
+
+        if self.has_class(&class) {
+            return false;
+        }
+
+        self.classes.push(class);
+        self.dirty = true;
+        true
+    }
+
+    /// Removes the given class. Returns `true` if the class was set before.
+    pub fn remove_class(&mut self, class: &str) -> bool {
+        if !self.has_class(class) {
+            return false;
+        }
+
+        self.classes.retain(|c| c != class);
+        self.dirty = true;
+        true
+    }
+
+    /// Checks if the selector has the given class.
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+
+    /// Gets the states that are used to read the properties of the style in the order of their priority. The
+    /// manually set state comes first, followed by the active pseudo-classes.
+    pub fn states(&self) -> Vec<&str> {
+        let mut states: Vec<&str> = self.state.iter().map(|s| s.as_str()).collect();
+
+        states.extend(
+            PSEUDO_CLASSES
+                .iter()
+                .copied()
+                .filter(|pseudo_class| self.has_pseudo_class(pseudo_class)),
+        );
+
+        // custom pseudo-classes like `dragging`
+        states.extend(
+            self.pseudo_classes
+                .iter()
+                .map(|p| p.as_str())
+                .filter(|p| !PSEUDO_CLASSES.contains(p)),
+        );
+
+        states
+    }
+
+    /// Gets the state with the highest priority.
+    pub fn current_state(&self) -> Option<&str> {
+        self.states().first().copied()
+    }
+
+    /// Activates or deactivates the given pseudo-class, e.g. `hover`, `:active` or `:checked`. Returns `true` if
+    /// the pseudo-class has changed.
+    pub fn set_pseudo_class(&mut self, pseudo_class: &str, active: bool) -> bool {
+        let state = pseudo_class_state(pseudo_class);
+
+        if active == self.has_pseudo_class(state) {
+            return false;
+        }
+
+        if active {
+            self.pseudo_classes.push(state.to_string());
+        } else {
+            self.pseudo_classes.retain(|p| p != state);
+        }
+
+        self.dirty = true;
+        true
+    }
+
+    /// Checks if the given pseudo-class is active.
+    pub fn has_pseudo_class(&self, pseudo_class: &str) -> bool {
+        let state = pseudo_class_state(pseudo_class);
+        self.pseudo_classes.iter().any(|p| p == state)
+    }
+
     /// Set the current state of the selector.
     pub fn set_state(&mut self, state: impl Into<String>) {
         self.state = Some(state.into());
@@ -77,3 +196,49 @@ impl From<String> for Selector {
         Selector::new(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudo_classes() {
+        let mut selector = Selector::new("button");
+        assert_eq!(selector.current_state(), None);
+
+        assert!(selector.set_pseudo_class(":hover", true));
+        assert!(!selector.set_pseudo_class("hover", true));
+        assert_eq!(selector.current_state(), Some("hover"));
+
+        selector.set_pseudo_class(":active", true);
+        assert!(selector.has_pseudo_class("pressed"));
+        assert_eq!(selector.current_state(), Some("pressed"));
+
+        selector.set_pseudo_class(":disabled", true);
+        assert_eq!(selector.current_state(), Some("disabled"));
+
+        selector.set_pseudo_class("disabled", false);
+        selector.set_pseudo_class("pressed", false);
+        assert_eq!(selector.current_state(), Some("hover"));
+
+        selector.set_state("expanded");
+        assert_eq!(selector.current_state(), Some("expanded"));
+    }
+
+    #[test]
+    fn test_classes() {
+        let mut selector = Selector::new("button primary  large");
+        assert_eq!(selector.style, Some("button".to_string()));
+        assert!(selector.has_class("primary"));
+        assert!(selector.has_class("large"));
+
+        selector.set_dirty(false);
+        assert!(!selector.add_class("primary"));
+        assert!(!selector.dirty());
+
+        assert!(selector.remove_class("large"));
+        assert!(!selector.remove_class("large"));
+        assert_eq!(selector.classes(), &["primary".to_string()]);
+        assert!(selector.dirty());
+    }
+}
@@ -1,20 +1,151 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
 use ron::Value;
 
 use crate::{
     config::{ThemeConfig, RESOURCE_KEY},
-    Selector, Style,
+    pseudo_class_state, Resources, Selector, Style,
 };
 
+// Limits the depth of resources that reference other resources to prevent endless loops.
+const MAX_RESOURCE_DEPTH: usize = 16;
+
+// Describes how a compound of a rule is related to the compound on its left.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Combinator {
+    // `listview button` matches a button inside of a list view
+    Descendant,
+
+    // `tabheader > textblock` matches a text block that is a direct child of a tab header
+    Child,
+}
+
+// A part of a rule like `button.primary`. An empty style matches any widget.
+#[derive(Debug, Clone, PartialEq)]
+struct Compound {
+    style: String,
+    classes: Vec<String>,
+}
+
+impl Compound {
+    fn parse(compound: &str) -> Self {
+        let mut parts = compound.split('.');
+
+        Compound {
+            style: parts.next().unwrap_or_default().to_string(),
+            classes: parts
+                .filter(|c| !c.is_empty())
+                .map(|c| c.to_string())
+                .collect(),
+        }
+    }
+
+    fn matches(&self, selector: &Selector) -> bool {
+        (self.style.is_empty() || selector.style.as_deref() == Some(self.style.as_str()))
+            && self.classes.iter().all(|c| selector.has_class(c))
+    }
+}
+
+// A style with a key that contains combinators or classes, e.g. `listview button` or `button.primary`.
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    key: String,
+
+    // the compounds from left to right, each with the combinator to its left neighbour
+    compounds: Vec<(Combinator, Compound)>,
+
+    // the number of classes and of style names, rules with a higher specificity override the properties of rules with a
+    // lower one. Like in CSS one class is more specific than any number of style names.
+    specificity: (usize, usize),
+
+    style: Style,
+}
+
+impl Rule {
+    // Checks if the given key is a rule and not a simple style key.
+    fn is_rule(key: &str) -> bool {
+        key.contains(|c: char| c.is_whitespace() || c == '>' || c == '.')
+    }
+
+    fn parse(key: &str, style: Style) -> Self {
+        let mut compounds = vec![];
+        let mut combinator = Combinator::Descendant;
+
+        for token in key.replace('>', " > ").split_whitespace() {
+            if token == ">" {
+                combinator = Combinator::Child;
+                continue;
+            }
+
+            compounds.push((combinator, Compound::parse(token)));
+            combinator = Combinator::Descendant;
+        }
+
+        let specificity = compounds.iter().fold((0, 0), |(classes, styles), (_, c)| {
+            (
+                classes + c.classes.len(),
+                styles + (!c.style.is_empty()) as usize,
+            )
+        });
+
+        Rule {
+            key: key.to_string(),
+            compounds,
+            specificity,
+            style,
+        }
+    }
+
+    // The rule is matched from right to left, the last compound has to match the widget itself.
+    fn matches(&self, selector: &Selector, ancestors: &[Selector]) -> bool {
+        match self.compounds.split_last() {
+            Some(((combinator, compound), rest)) => {
+                compound.matches(selector) && Rule::matches_ancestors(rest, *combinator, ancestors)
+            }
+            None => false,
+        }
+    }
+
+    fn matches_ancestors(
+        compounds: &[(Combinator, Compound)],
+        combinator: Combinator,
+        ancestors: &[Selector],
+    ) -> bool {
+        let ((next_combinator, compound), rest) = match compounds.split_last() {
+            Some(last) => last,
+            None => return true,
+        };
+
+        match combinator {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, ancestors)) => {
+                    compound.matches(parent)
+                        && Rule::matches_ancestors(rest, *next_combinator, ancestors)
+                }
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                compound.matches(&ancestors[i])
+                    && Rule::matches_ancestors(rest, *next_combinator, &ancestors[..i])
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
     styles: HashMap<String, Style>,
+
+    // sorted by specificity
+    rules: Vec<Rule>,
+
+    resources: Resources,
 }
 
 impl Theme {
     pub fn from_config(theme: ThemeConfig) -> Self {
         let mut styles = HashMap::new();
+        let mut rules = vec![];
 
         for style_key in theme.styles.keys() {
             let mut properties = HashMap::new();
@@ -28,42 +159,130 @@ impl Theme {
                 for state_key in base.states.keys() {
                     let mut state = HashMap::new();
                     Theme::read_states(&base_key, state_key, &theme, &mut state);
-                    states.insert(state_key.clone(), state);
+                    states.insert(pseudo_class_state(state_key).to_string(), state);
                 }
             }
 
             for state_key in theme.styles.get(style_key).unwrap().states.keys() {
                 let mut state = HashMap::new();
                 Theme::read_states(style_key, state_key, &theme, &mut state);
-                states.insert(state_key.clone(), state);
+                states.insert(pseudo_class_state(state_key).to_string(), state);
             }
 
-            styles.insert(style_key.clone(), Style { properties, states });
+            let style = Style { properties, states };
+
+            if Rule::is_rule(style_key) {
+                rules.push(Rule::parse(style_key, style));
+            } else {
+                styles.insert(style_key.clone(), style);
+            }
         }
 
-        Theme { styles }
+        // rules with the same specificity are applied in the order of their keys
+        rules.sort_by(|a, b| {
+            a.specificity
+                .cmp(&b.specificity)
+                .then_with(|| a.key.cmp(&b.key))
+        });
+
+        Theme {
+            styles,
+            rules,
+            resources: Resources::from(theme.resources),
+        }
     }
 
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
     }
 
+    /// Gets the resource of the theme with the given key.
+    pub fn resource(&self, key: &str) -> Option<&Value> {
+        self.resources.get(key)
+    }
+
+    /// Resolves the resource references (`"$KEY"`) of the given property value. A resource could reference an other
+    /// resource. The given overrides are ordered from the outermost to the innermost and are checked before the
+    /// resources of the theme. Returns `None` if a referenced resource doesn't exist.
+    pub fn resolve<'a>(
+        &'a self,
+        value: &'a Value,
+        overrides: &'a [Resources],
+    ) -> Option<&'a Value> {
+        let mut value = value;
+
+        for _ in 0..MAX_RESOURCE_DEPTH {
+            let key = match value {
+                Value::String(s) if s.starts_with(RESOURCE_KEY) => &s[RESOURCE_KEY.len()..],
+                _ => return Some(value),
+            };
+
+            value = overrides
+                .iter()
+                .rev()
+                .find_map(|resources| resources.get(key))
+                .or_else(|| self.resources.get(key))?;
+        }
+
+        None
+    }
+
     pub fn properties<'a>(&'a self, selector: &Selector) -> Option<&'a HashMap<String, Value>> {
         if !selector.dirty() {
             return None;
         }
 
         if let Some(style) = &selector.style {
-            if let Some(state) = &selector.state {
-                return self.styles.get(style)?.states.get(state);
-            }
-
-            return Some(&self.styles.get(style)?.properties);
+            return Some(Theme::state_properties(self.styles.get(style)?, selector));
         }
 
         None
     }
 
+    /// Gets the properties of the given selector inside of the given ancestors, ordered from the outermost to the
+    /// innermost. The properties of the style are overridden by the rules with combinators or classes that match
+    /// the selector, e.g. `listview button`, `tabheader > textblock` or `button.primary`. Rules with a higher
+    /// specificity win.
+    pub fn properties_in<'a>(
+        &'a self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<Cow<'a, HashMap<String, Value>>> {
+        if !selector.dirty() {
+            return None;
+        }
+
+        let properties = self.properties(selector);
+        let mut rules = self
+            .rules
+            .iter()
+            .filter(|rule| rule.matches(selector, ancestors))
+            .peekable();
+
+        if rules.peek().is_none() {
+            return properties.map(Cow::Borrowed);
+        }
+
+        let mut properties = properties.cloned().unwrap_or_default();
+
+        for rule in rules {
+            for (key, value) in Theme::state_properties(&rule.style, selector) {
+                properties.insert(key.clone(), value.clone());
+            }
+        }
+
+        Some(Cow::Owned(properties))
+    }
+
+    // The first state of the selector that is defined by the style is used.
+    fn state_properties<'a>(style: &'a Style, selector: &Selector) -> &'a HashMap<String, Value> {
+        selector
+            .states()
+            .into_iter()
+            .find_map(|state| style.states.get(state))
+            .unwrap_or(&style.properties)
+    }
+
     fn read_properties(key: &str, theme: &ThemeConfig, properties: &mut HashMap<String, Value>) {
         if key.is_empty() {
             return;
@@ -73,7 +292,7 @@ impl Theme {
             Theme::read_properties(&style.base, theme, properties);
 
             for (key, value) in &style.properties {
-                Theme::read_property(key, value, theme, properties);
+                Theme::read_property(key, value, properties);
             }
         }
     }
@@ -90,33 +309,189 @@ impl Theme {
 
         if let Some(style) = theme.styles.get(style_key) {
             for (key, value) in &style.properties {
-                Theme::read_property(key, value, theme, states);
+                Theme::read_property(key, value, states);
             }
 
             if let Some(state) = style.states.get(state_key) {
                 for (key, value) in state {
-                    Theme::read_property(key, value, theme, states);
+                    Theme::read_property(key, value, states);
                 }
             }
         }
     }
 
-    fn read_property(
-        key: &str,
-        value: &Value,
-        theme: &ThemeConfig,
-        map: &mut HashMap<String, Value>,
-    ) {
-        if let Ok(value) = value.clone().into_rust::<String>() {
-            if value.starts_with(RESOURCE_KEY) {
-                if let Some(value) = theme.resources.get(&value.replace(RESOURCE_KEY, "")) {
-                    map.insert(key.to_string(), value.clone());
-                }
-            } else {
-                map.insert(key.to_string(), Value::String(value));
-            }
-        } else {
-            map.insert(key.to_string(), value.clone());
-        }
+    // Resource references are kept, they are resolved if the properties are applied to a widget.
+    fn read_property(key: &str, value: &Value, map: &mut HashMap<String, Value>) {
+        map.insert(key.to_string(), value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEME: &str = r##"Theme (
+        styles: {
+            "button": ( properties: { "background": "$PRIMARY", "font_size": 16 } ),
+        },
+        resources: {
+            "INDIGO": "#3f51b5",
+            "PRIMARY": "$INDIGO",
+            "LOOP": "$LOOP",
+        },
+    )"##;
+
+    #[test]
+    fn test_resolve() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+        let properties = &theme.style("button").unwrap().properties;
+
+        assert_eq!(
+            theme.resolve(&properties["background"], &[]),
+            Some(&Value::String("#3f51b5".to_string()))
+        );
+        assert_eq!(
+            theme.resolve(&properties["font_size"], &[]),
+            Some(&properties["font_size"])
+        );
+
+        let overrides = vec![
+            Resources::new().with("INDIGO", "#000000"),
+            Resources::new().with("PRIMARY", "#ffffff"),
+        ];
+        assert_eq!(
+            theme.resolve(&properties["background"], &overrides),
+            Some(&Value::String("#ffffff".to_string()))
+        );
+        assert_eq!(
+            theme.resolve(&properties["background"], &overrides[..1]),
+            Some(&Value::String("#000000".to_string()))
+        );
+
+        assert_eq!(
+            theme.resolve(&Value::String("$MISSING".to_string()), &[]),
+            None
+        );
+        assert_eq!(
+            theme.resolve(&Value::String("$LOOP".to_string()), &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pseudo_class_states() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+                styles: {
+                    "button": (
+                        properties: { "background": "#000000" },
+                        states: {
+                            ":hover": { "background": "#111111" },
+                            ":active": { "background": "#222222" },
+                        },
+                    ),
+                },
+            )"##,
+        ));
+
+        let mut selector = Selector::new("button");
+        assert_eq!(
+            theme.properties(&selector).unwrap()["background"],
+            Value::String("#000000".to_string())
+        );
+
+        selector.set_pseudo_class("hover", true);
+        assert_eq!(
+            theme.properties(&selector).unwrap()["background"],
+            Value::String("#111111".to_string())
+        );
+
+        selector.set_pseudo_class("pressed", true);
+        assert_eq!(
+            theme.properties(&selector).unwrap()["background"],
+            Value::String("#222222".to_string())
+        );
+
+        // the style has no disabled state, the next state is used
+        selector.set_pseudo_class(":disabled", true);
+        assert_eq!(
+            theme.properties(&selector).unwrap()["background"],
+            Value::String("#222222".to_string())
+        );
+    }
+
+    #[test]
+    fn test_combinators() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+                styles: {
+                    "button": ( properties: { "background": "#000000", "font_size": 16 } ),
+                    "listview button": ( properties: { "background": "#111111" } ),
+                    "tabheader > textblock": ( properties: { "foreground": "#222222" } ),
+                    "button.primary": (
+                        properties: { "background": "#333333" },
+                        states: { ":hover": { "background": "#444444" } },
+                    ),
+                    ".large": ( properties: { "font_size": 24 } ),
+                },
+            )"##,
+        ));
+
+        let property = |selector: &Selector, ancestors: &[Selector], key: &str| {
+            theme
+                .properties_in(selector, ancestors)
+                .and_then(|properties| properties.get(key).cloned())
+        };
+        let color = |c: &str| Some(Value::String(c.to_string()));
+
+        let button = Selector::new("button");
+        let list_view = Selector::new("listview");
+        let tab_header = Selector::new("tabheader");
+        let stack = Selector::new("stack");
+
+        assert_eq!(property(&button, &[], "background"), color("#000000"));
+        assert_eq!(
+            property(&button, &[list_view.clone(), stack.clone()], "background"),
+            color("#111111")
+        );
+        assert_eq!(
+            property(&button, &[list_view.clone()], "font_size"),
+            property(&button, &[], "font_size")
+        );
+
+        let text_block = Selector::new("textblock");
+        assert_eq!(
+            property(&text_block, &[tab_header.clone()], "foreground"),
+            color("#222222")
+        );
+        assert_eq!(
+            property(&text_block, &[tab_header, stack], "foreground"),
+            None
+        );
+
+        // the class is more specific than the descendant rule
+        let mut primary = Selector::new("button primary large");
+        assert_eq!(
+            property(&primary, &[list_view.clone()], "background"),
+            color("#333333")
+        );
+        assert_eq!(
+            property(&primary, &[], "font_size"),
+            theme
+                .rules
+                .iter()
+                .find(|rule| rule.key == ".large")
+                .map(|rule| rule.style.properties["font_size"].clone())
+        );
+        assert_ne!(
+            property(&primary, &[], "font_size"),
+            property(&button, &[], "font_size")
+        );
+
+        primary.set_pseudo_class("hover", true);
+        assert_eq!(
+            property(&primary, &[list_view], "background"),
+            color("#444444")
+        );
     }
 }
@@ -0,0 +1,241 @@
+use crate::prelude::*;
+
+/// Describes the radii of the four corners of a border like `border-radius` of CSS. A single value rounds all
+/// corners, e.g. `BorderRadius::from(4.0)`, independent radii round only some corners of tabs or split buttons.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// // rounds only the top corners
+/// let radius = BorderRadius::from("4 4 0 0");
+/// let radius = BorderRadius::new(4.0, 4.0, 0.0, 0.0);
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct BorderRadius {
+    /// Radius of the top left corner.
+    pub top_left: f64,
+
+    /// Radius of the top right corner.
+    pub top_right: f64,
+
+    /// Radius of the bottom right corner.
+    pub bottom_right: f64,
+
+    /// Radius of the bottom left corner.
+    pub bottom_left: f64,
+}
+
+impl BorderRadius {
+    /// Creates a new border radius with the corners in clockwise order starting at the top left corner.
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        BorderRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Gets the greatest radius of all corners.
+    pub fn max(&self) -> f64 {
+        self.top_left
+            .max(self.top_right)
+            .max(self.bottom_right)
+            .max(self.bottom_left)
+    }
+
+    /// Checks if all corners have the same radius.
+    pub fn is_uniform(&self) -> bool {
+        (self.top_left - self.top_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_left).abs() < f64::EPSILON
+    }
+
+    /// Checks if no corner is rounded.
+    pub fn is_zero(&self) -> bool {
+        self.max() <= 0.0
+    }
+
+    /// Returns the radius grown by the given delta on each rounded corner, e.g. by the spread of a shadow.
+    pub fn grow(&self, delta: f64) -> BorderRadius {
+        let grow = |radius: f64| {
+            if radius > 0.0 {
+                (radius + delta).max(0.0)
+            } else {
+                radius
+            }
+        };
+
+        BorderRadius::new(
+            grow(self.top_left),
+            grow(self.top_right),
+            grow(self.bottom_right),
+            grow(self.bottom_left),
+        )
+    }
+
+    /// Fits the radii into the given size. Negative radii are treated as zero and if the radii of two neighbour
+    /// corners are greater than their side all radii are scaled down like in CSS.
+    pub fn fit(&self, width: f64, height: f64) -> BorderRadius {
+        let radius = BorderRadius::new(
+            self.top_left.max(0.0),
+            self.top_right.max(0.0),
+            self.bottom_right.max(0.0),
+            self.bottom_left.max(0.0),
+        );
+
+        let scale = [
+            (width, radius.top_left + radius.top_right),
+            (height, radius.top_right + radius.bottom_right),
+            (width, radius.bottom_right + radius.bottom_left),
+            (height, radius.bottom_left + radius.top_left),
+        ]
+        .iter()
+        .filter(|(_, sum)| *sum > 0.0)
+        .map(|(side, sum)| side.max(0.0) / sum)
+        .fold(1.0, f64::min);
+
+        BorderRadius::new(
+            radius.top_left * scale,
+            radius.top_right * scale,
+            radius.bottom_right * scale,
+            radius.bottom_left * scale,
+        )
+    }
+}
+
+// --- Trait implementations ---
+
+impl From<f64> for BorderRadius {
+    fn from(r: f64) -> Self {
+        BorderRadius::new(r, r, r, r)
+    }
+}
+
+impl From<i32> for BorderRadius {
+    fn from(r: i32) -> Self {
+        BorderRadius::from(r as f64)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for BorderRadius {
+    fn from(r: (f64, f64, f64, f64)) -> Self {
+        BorderRadius::new(r.0, r.1, r.2, r.3)
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for BorderRadius {
+    fn from(r: (i32, i32, i32, i32)) -> Self {
+        BorderRadius::new(r.0 as f64, r.1 as f64, r.2 as f64, r.3 as f64)
+    }
+}
+
+/// Parses the shorthand of CSS with one to four values like `4`, `4px 0` or `4 4 0 0`. The values are assigned in
+/// the order top left, top right, bottom right and bottom left, missing values are copied from the opposite corner.
+impl From<&str> for BorderRadius {
+    fn from(s: &str) -> Self {
+        let values: Vec<f64> = s
+            .split_whitespace()
+            .filter_map(|v| v.trim_end_matches("px").parse().ok())
+            .collect();
+
+        match values.as_slice() {
+            [r] => BorderRadius::from(*r),
+            [a, b] => BorderRadius::new(*a, *b, *a, *b),
+            [a, b, c] => BorderRadius::new(*a, *b, *c, *b),
+            [a, b, c, d] => BorderRadius::new(*a, *b, *c, *d),
+            _ => BorderRadius::default(),
+        }
+    }
+}
+
+impl From<String> for BorderRadius {
+    fn from(s: String) -> Self {
+        BorderRadius::from(s.as_str())
+    }
+}
+
+impl From<Value> for BorderRadius {
+    fn from(v: Value) -> Self {
+        match v.0 {
+            ron::Value::Number(value) => BorderRadius::from(value.into_f64()),
+            ron::Value::String(value) => BorderRadius::from(value),
+            ron::Value::Map(map) => {
+                let mut radius = BorderRadius::default();
+
+                for (key, value) in map.iter() {
+                    if let Ok(key) = key.clone().into_rust::<String>() {
+                        let value = value.clone().into_rust::<f64>().unwrap_or_default();
+
+                        match key.as_str() {
+                            "top_left" => radius.top_left = value,
+                            "top_right" => radius.top_right = value,
+                            "bottom_right" => radius.bottom_right = value,
+                            "bottom_left" => radius.bottom_left = value,
+                            _ => {}
+                        }
+                    }
+                }
+
+                radius
+            }
+            _ => BorderRadius::default(),
+        }
+    }
+}
+
+impl Interpolate for BorderRadius {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        BorderRadius::new(
+            self.top_left.interpolate(&to.top_left, progress),
+            self.top_right.interpolate(&to.top_right, progress),
+            self.bottom_right.interpolate(&to.bottom_right, progress),
+            self.bottom_left.interpolate(&to.bottom_left, progress),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(BorderRadius::from("4"), BorderRadius::from(4.0));
+        assert_eq!(
+            BorderRadius::from("4px 0"),
+            BorderRadius::new(4.0, 0.0, 4.0, 0.0)
+        );
+        assert_eq!(
+            BorderRadius::from("1 2 3"),
+            BorderRadius::new(1.0, 2.0, 3.0, 2.0)
+        );
+        assert_eq!(
+            BorderRadius::from("4 4 0 0"),
+            BorderRadius::new(4.0, 4.0, 0.0, 0.0)
+        );
+        assert_eq!(BorderRadius::from("1 2 3 4 5"), BorderRadius::default());
+    }
+
+    #[test]
+    fn test_fit() {
+        let radius = BorderRadius::new(20.0, 20.0, 0.0, 0.0).fit(20.0, 40.0);
+        assert_eq!(radius, BorderRadius::new(10.0, 10.0, 0.0, 0.0));
+
+        let radius = BorderRadius::from(4.0).fit(20.0, 40.0);
+        assert_eq!(radius, BorderRadius::from(4.0));
+        assert!(radius.is_uniform());
+
+        assert!(BorderRadius::from(-2.0).fit(20.0, 20.0).is_zero());
+    }
+
+    #[test]
+    fn test_grow() {
+        assert_eq!(
+            BorderRadius::new(4.0, 0.0, 4.0, 0.0).grow(2.0),
+            BorderRadius::new(6.0, 0.0, 6.0, 0.0)
+        );
+        assert!(BorderRadius::from(2.0).grow(-4.0).is_zero());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::prelude::*;
+
+/// Describes the drop shadow of a box like `box-shadow` of CSS. The shadow is the box moved by the offset, grown by
+/// the spread and softened over the blur radius.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct BoxShadow {
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub blur: f64,
+    pub spread: f64,
+    pub color: Color,
+}
+
+impl BoxShadow {
+    /// Creates a new box shadow.
+    pub fn new(offset_x: f64, offset_y: f64, blur: f64, spread: f64, color: Color) -> Self {
+        BoxShadow {
+            offset_x,
+            offset_y,
+            blur,
+            spread,
+            color,
+        }
+    }
+
+    /// Checks if the shadow is not drawn.
+    pub fn is_none(&self) -> bool {
+        self.color.a() == 0
+    }
+
+    /// Calculates the rectangle of the shadow for the given bounds of the box.
+    pub fn rect(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle::new(
+            (
+                bounds.x() + self.offset_x - self.spread,
+                bounds.y() + self.offset_y - self.spread,
+            ),
+            (
+                (bounds.width() + 2.0 * self.spread).max(0.0),
+                (bounds.height() + 2.0 * self.spread).max(0.0),
+            ),
+        )
+    }
+}
+
+/// Parses a shadow like `0px 2px 8px 0px #66000000` (offset x, offset y, optional blur radius, optional spread and
+/// color) or `none`. Inset shadows are not supported and are parsed as `none`.
+impl From<&str> for BoxShadow {
+    fn from(s: &str) -> BoxShadow {
+        let mut lengths = vec![];
+        let mut color = None;
+
+        for word in s.split_whitespace() {
+            match word.trim_end_matches("px").parse::<f64>() {
+                Ok(length) => lengths.push(length),
+                Err(_) if word == "none" || word == "inset" => return BoxShadow::default(),
+                Err(_) => color = Some(Color::from(word)),
+            }
+        }
+
+        if lengths.len() < 2 || lengths.len() > 4 {
+            return BoxShadow::default();
+        }
+
+        BoxShadow {
+            offset_x: lengths[0],
+            offset_y: lengths[1],
+            blur: lengths.get(2).copied().unwrap_or_default().max(0.0),
+            spread: lengths.get(3).copied().unwrap_or_default(),
+            color: color.unwrap_or_else(|| Color::rgba(0, 0, 0, 255)),
+        }
+    }
+}
+
+impl From<String> for BoxShadow {
+    fn from(s: String) -> BoxShadow {
+        BoxShadow::from(s.as_str())
+    }
+}
+
+impl From<Value> for BoxShadow {
+    fn from(v: Value) -> Self {
+        BoxShadow::from(v.get::<String>())
+    }
+}
+
+impl Interpolate for BoxShadow {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        BoxShadow {
+            offset_x: self.offset_x.interpolate(&to.offset_x, progress),
+            offset_y: self.offset_y.interpolate(&to.offset_y, progress),
+            blur: self.blur.interpolate(&to.blur, progress),
+            spread: self.spread.interpolate(&to.spread, progress),
+            color: self.color.interpolate(&to.color, progress),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let shadow = BoxShadow::from("0px 2px 8px 1px #66000000");
+        assert_eq!(
+            shadow,
+            BoxShadow::new(0.0, 2.0, 8.0, 1.0, Color::rgba(0, 0, 0, 0x66))
+        );
+        assert_eq!(shadow.color.a(), 0x66);
+        assert_eq!(
+            shadow.rect(Rectangle::new((10.0, 10.0), (20.0, 20.0))),
+            Rectangle::new((9.0, 11.0), (22.0, 22.0))
+        );
+
+        assert_eq!(BoxShadow::from("2 4").blur, 0.0);
+        assert!(BoxShadow::from("none").is_none());
+        assert!(BoxShadow::from("inset 0 2px #000000").is_none());
+        assert!(BoxShadow::from("2px #000000").is_none());
+    }
+}
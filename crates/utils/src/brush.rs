@@ -1,11 +1,7 @@
 use crate::prelude::*;
 
 /// Describes a position on a colorful gradient.
-#[derive(Copy, Clone, PartialEq, Debug)]
-pub struct LinearGradientStop {
-    pub position: f64,
-    pub color: Color,
-}
+pub type LinearGradientStop = GradientStop;
 
 /// A `Brush`describes how a shape is filled or stroked.
 #[derive(Clone, PartialEq, Debug)]
@@ -19,6 +15,9 @@ pub enum Brush {
         end: Point,
         stops: Vec<LinearGradientStop>,
     },
+
+    /// Paints an area with a linear or radial gradient that adapts to the bounds of the shape.
+    Gradient(Gradient),
 }
 
 impl Brush {
@@ -34,6 +33,10 @@ impl From<Brush> for Color {
     fn from(b: Brush) -> Color {
         match b {
             Brush::SolidColor(color) => color,
+            Brush::Gradient(gradient) => gradient
+                .stops
+                .first()
+                .map_or(Color::rgb(0, 0, 0), |stop| stop.color),
             _ => Color::rgb(0, 0, 0),
         }
     }
@@ -51,15 +54,25 @@ impl From<Color> for Brush {
     }
 }
 
+impl From<Gradient> for Brush {
+    fn from(g: Gradient) -> Brush {
+        Brush::Gradient(g)
+    }
+}
+
+/// Parses a color like `#ff0000` or a CSS gradient like `linear-gradient(to right, #000000, #ffffff)`.
 impl From<&str> for Brush {
     fn from(s: &str) -> Brush {
-        Brush::SolidColor(Color::from(s))
+        match Gradient::parse(s) {
+            Some(gradient) => Brush::Gradient(gradient),
+            None => Brush::SolidColor(Color::from(s)),
+        }
     }
 }
 
 impl From<String> for Brush {
     fn from(s: String) -> Brush {
-        Brush::SolidColor(Color::from(s))
+        Brush::from(s.as_str())
     }
 }
 
@@ -78,6 +91,18 @@ impl From<Value> for Brush {
 
 #[cfg(test)]
 mod tests {
-    //  use crate::prelude::*;
-    // todo: tbd after brush struct is finished
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Brush::from("#ff0000"),
+            Brush::SolidColor(Color::rgb(255, 0, 0))
+        );
+
+        match Brush::from("linear-gradient(45deg, #ff0000, #0000ff)") {
+            Brush::Gradient(gradient) => assert_eq!(gradient.kind, GradientKind::Linear(45.0)),
+            _ => panic!("expected gradient"),
+        }
+    }
 }
@@ -0,0 +1,327 @@
+use crate::Brush;
+
+/// The default colors of data series without own brush.
+pub const CHART_PALETTE: [&str; 8] = [
+    "#5b9bd5", "#ed7d31", "#70ad47", "#ffc000", "#a05ec9", "#4bc4c4", "#e05f8a", "#9e9e9e",
+];
+
+/// A named series of values of a chart.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataSeries {
+    /// The name of the series.
+    pub name: String,
+
+    /// The values of the series.
+    pub values: Vec<f64>,
+
+    /// The brush of the series. If it is not set a color of the `CHART_PALETTE` is used.
+    pub brush: Option<Brush>,
+}
+
+impl DataSeries {
+    /// Creates a new series with the given name and values.
+    pub fn new(name: impl Into<String>, values: Vec<f64>) -> Self {
+        DataSeries {
+            name: name.into(),
+            values,
+            brush: None,
+        }
+    }
+
+    /// Builder method that sets the brush of the series.
+    pub fn brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.brush = Some(brush.into());
+        self
+    }
+}
+
+/// The data of a chart. It contains the labels of the categories, e.g. the x axis labels of a line chart or the
+/// slices of a pie chart, and the data series with one value per label.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// let data = ChartData::new()
+///     .labels(vec!["Jan", "Feb", "Mar"])
+///     .series(DataSeries::new("Income", vec![12.0, 18.5, 9.0]))
+///     .series(DataSeries::new("Costs", vec![8.0, 11.0, 10.5]).brush("#e05f8a"));
+///
+/// let data = ChartData::from(vec![("Rust", 60.0), ("C++", 25.0), ("Go", 15.0)]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChartData {
+    /// The labels of the categories.
+    pub labels: Vec<String>,
+
+    /// The data series.
+    pub series: Vec<DataSeries>,
+}
+
+impl ChartData {
+    /// Creates empty chart data.
+    pub fn new() -> Self {
+        ChartData::default()
+    }
+
+    /// Builder method that sets the labels of the categories.
+    pub fn labels<S: Into<String>>(mut self, labels: Vec<S>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builder method that adds a data series.
+    pub fn series(mut self, series: DataSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Gets the number of categories, that is the number of labels or the length of the longest series.
+    pub fn len(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .fold(self.labels.len(), usize::max)
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.series.iter().all(|series| series.values.is_empty())
+    }
+
+    /// Gets the label of the category with the given index, or its number if it has no label.
+    pub fn label(&self, index: usize) -> String {
+        self.labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| (index + 1).to_string())
+    }
+
+    /// Gets the smallest and the biggest finite value of all series.
+    pub fn value_range(&self) -> Option<(f64, f64)> {
+        self.series
+            .iter()
+            .flat_map(|series| series.values.iter())
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| match range {
+                Some((min, max)) => Some((value.min(min), value.max(max))),
+                None => Some((*value, *value)),
+            })
+    }
+
+    /// Gets the brush of the series with the given index.
+    pub fn series_brush(&self, index: usize) -> Brush {
+        self.series
+            .get(index)
+            .and_then(|series| series.brush.clone())
+            .unwrap_or_else(|| self.category_brush(index))
+    }
+
+    /// Gets the brush of the category with the given index, e.g. of a slice of a pie chart.
+    pub fn category_brush(&self, index: usize) -> Brush {
+        Brush::from(CHART_PALETTE[index % CHART_PALETTE.len()])
+    }
+}
+
+// --- Conversions ---
+
+impl From<Vec<f64>> for ChartData {
+    fn from(values: Vec<f64>) -> ChartData {
+        ChartData::new().series(DataSeries::new("", values))
+    }
+}
+
+impl From<Vec<DataSeries>> for ChartData {
+    fn from(series: Vec<DataSeries>) -> ChartData {
+        ChartData {
+            labels: vec![],
+            series,
+        }
+    }
+}
+
+impl From<Vec<(&str, f64)>> for ChartData {
+    fn from(values: Vec<(&str, f64)>) -> ChartData {
+        ChartData::new()
+            .labels(values.iter().map(|(label, _)| *label).collect())
+            .series(DataSeries::new(
+                "",
+                values.iter().map(|(_, value)| *value).collect(),
+            ))
+    }
+}
+
+// --- Conversions ---
+
+/// The scale of a chart axis with round values, e.g. from 0 to 50 with a step of 10.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChartScale {
+    /// The value at the start of the axis.
+    pub min: f64,
+
+    /// The value at the end of the axis.
+    pub max: f64,
+
+    /// The distance between two ticks.
+    pub step: f64,
+}
+
+impl Default for ChartScale {
+    fn default() -> Self {
+        ChartScale {
+            min: 0.0,
+            max: 1.0,
+            step: 0.2,
+        }
+    }
+}
+
+impl ChartScale {
+    /// Calculates a scale with round values that contains `min` and `max` and has not more than about
+    /// `max_ticks` ticks.
+    pub fn auto(min: f64, max: f64, max_ticks: usize) -> Self {
+        if !min.is_finite() || !max.is_finite() {
+            return ChartScale::default();
+        }
+
+        let (min, max) = if max > min {
+            (min, max)
+        } else if min == 0.0 {
+            (0.0, 1.0)
+        } else {
+            // a single value is shown in the middle of the scale
+            (min - min.abs() / 2.0, max + max.abs() / 2.0)
+        };
+
+        let range = nice_number(max - min, false);
+        let step = nice_number(range / (max_ticks.max(2) - 1) as f64, true);
+
+        ChartScale {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    /// Gets the values of the ticks.
+    pub fn ticks(&self) -> Vec<f64> {
+        if self.step <= 0.0 || self.max <= self.min {
+            return vec![self.min];
+        }
+
+        let count = ((self.max - self.min) / self.step + 0.5).floor() as usize;
+
+        (0..=count)
+            .map(|i| self.min + i as f64 * self.step)
+            .collect()
+    }
+
+    /// Gets the relative position of the value on the scale, `0.0` is the start and `1.0` the end.
+    pub fn position(&self, value: f64) -> f64 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+
+        (value - self.min) / (self.max - self.min)
+    }
+
+    /// Formats a value with the number of decimals of the step.
+    pub fn format(&self, value: f64) -> String {
+        let decimals = if self.step > 0.0 && self.step < 1.0 {
+            (-self.step.log10().floor()) as usize
+        } else {
+            0
+        };
+
+        // avoids -0
+        let value = if value.abs() < self.step / 1e6 {
+            0.0
+        } else {
+            value
+        };
+
+        format!("{:.*}", decimals, value)
+    }
+}
+
+// --- Helpers --
+
+// Rounds a number to 1, 2, 5 or 10 times a power of ten.
+fn nice_number(value: f64, round: bool) -> f64 {
+    let exponent = value.log10().floor();
+    let fraction = value / 10_f64.powf(exponent);
+
+    let nice_fraction = if round {
+        if fraction < 1.5 {
+            1.0
+        } else if fraction < 3.0 {
+            2.0
+        } else if fraction < 7.0 {
+            5.0
+        } else {
+            10.0
+        }
+    } else if fraction <= 1.0 {
+        1.0
+    } else if fraction <= 2.0 {
+        2.0
+    } else if fraction <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice_fraction * 10_f64.powf(exponent)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_scale() {
+        let scale = ChartScale::auto(3.0, 47.0, 6);
+        assert_eq!(
+            scale,
+            ChartScale {
+                min: 0.0,
+                max: 50.0,
+                step: 10.0
+            }
+        );
+        assert_eq!(scale.ticks(), vec![0.0, 10.0, 20.0, 30.0, 40.0, 50.0]);
+        assert_eq!(scale.position(25.0), 0.5);
+        assert_eq!(scale.format(20.0), "20");
+
+        let scale = ChartScale::auto(-0.3, 0.8, 6);
+        assert_eq!(scale.step, 0.5);
+        assert_eq!((scale.min, scale.max), (-0.5, 1.0));
+        assert_eq!(scale.format(0.5), "0.5");
+        assert_eq!(scale.format(-0.0), "0.0");
+
+        let scale = ChartScale::auto(5.0, 5.0, 6);
+        assert!(scale.min <= 5.0 && scale.max >= 5.0 && scale.max > scale.min);
+    }
+
+    #[test]
+    fn test_chart_data() {
+        let data = ChartData::new()
+            .labels(vec!["a", "b"])
+            .series(DataSeries::new("one", vec![1.0, -2.0, 3.0]))
+            .series(DataSeries::new("two", vec![f64::NAN, 8.0]).brush("#ff0000"));
+
+        assert_eq!(data.len(), 3);
+        assert_eq!(data.label(1), "b");
+        assert_eq!(data.label(2), "3");
+        assert_eq!(data.value_range(), Some((-2.0, 8.0)));
+        assert_eq!(data.series_brush(1), Brush::from("#ff0000"));
+        assert_eq!(data.series_brush(0), Brush::from(CHART_PALETTE[0]));
+        assert_eq!(ChartData::new().value_range(), None);
+
+        let data = ChartData::from(vec![("x", 1.0), ("y", 2.0)]);
+        assert_eq!(data.labels, vec![String::from("x"), String::from("y")]);
+        assert_eq!(data.series[0].values, vec![1.0, 2.0]);
+    }
+}
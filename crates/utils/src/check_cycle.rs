@@ -0,0 +1,82 @@
+/// Describes the order in which a click cycles the states of a check box.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CheckCycle {
+    /// A click switches between unchecked and checked. The indeterminate state could only be set from code, a click
+    /// on an indeterminate check box checks it.
+    TwoState,
+
+    /// A click cycles from unchecked to checked to indeterminate.
+    CheckedIndeterminate,
+
+    /// A click cycles from unchecked to indeterminate to checked.
+    IndeterminateChecked,
+}
+
+impl CheckCycle {
+    /// Returns the next `(selected, indeterminate)` state of a check box after a click.
+    pub fn next(self, selected: bool, indeterminate: bool) -> (bool, bool) {
+        match (self, selected, indeterminate) {
+            (CheckCycle::TwoState, _, true) => (true, false),
+            (CheckCycle::TwoState, selected, false) => (!selected, false),
+            (CheckCycle::CheckedIndeterminate, _, true) => (false, false),
+            (CheckCycle::CheckedIndeterminate, true, false) => (false, true),
+            (CheckCycle::CheckedIndeterminate, false, false) => (true, false),
+            (CheckCycle::IndeterminateChecked, _, true) => (true, false),
+            (CheckCycle::IndeterminateChecked, true, false) => (false, false),
+            (CheckCycle::IndeterminateChecked, false, false) => (false, true),
+        }
+    }
+}
+
+impl Default for CheckCycle {
+    fn default() -> Self {
+        CheckCycle::TwoState
+    }
+}
+
+impl From<&str> for CheckCycle {
+    fn from(t: &str) -> Self {
+        match t {
+            "CheckedIndeterminate" | "checked_indeterminate" => CheckCycle::CheckedIndeterminate,
+            "IndeterminateChecked" | "indeterminate_checked" => CheckCycle::IndeterminateChecked,
+            _ => CheckCycle::TwoState,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_into() {
+        let check_cycle: CheckCycle = "TwoState".into();
+        assert_eq!(check_cycle, CheckCycle::TwoState);
+
+        let check_cycle: CheckCycle = "checked_indeterminate".into();
+        assert_eq!(check_cycle, CheckCycle::CheckedIndeterminate);
+
+        let check_cycle: CheckCycle = "IndeterminateChecked".into();
+        assert_eq!(check_cycle, CheckCycle::IndeterminateChecked);
+
+        let check_cycle: CheckCycle = "other".into();
+        assert_eq!(check_cycle, CheckCycle::TwoState);
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(CheckCycle::TwoState.next(false, false), (true, false));
+        assert_eq!(CheckCycle::TwoState.next(true, false), (false, false));
+        assert_eq!(CheckCycle::TwoState.next(false, true), (true, false));
+
+        let cycle = CheckCycle::CheckedIndeterminate;
+        assert_eq!(cycle.next(false, false), (true, false));
+        assert_eq!(cycle.next(true, false), (false, true));
+        assert_eq!(cycle.next(false, true), (false, false));
+
+        let cycle = CheckCycle::IndeterminateChecked;
+        assert_eq!(cycle.next(false, false), (false, true));
+        assert_eq!(cycle.next(false, true), (true, false));
+        assert_eq!(cycle.next(true, false), (false, false));
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+
+/// Represents a date of the (proleptic) gregorian calendar.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new date. The month is limited to 1 - 12 and the day to the days of the month.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.max(1).min(12);
+
+        Date {
+            year,
+            month,
+            day: day.max(1).min(days_in_month(year, month)),
+        }
+    }
+
+    /// Creates a date from the count of days since 1970-01-01.
+    pub fn from_days(days: i64) -> Self {
+        // algorithm of Howard Hinnant, see http://howardhinnant.github.io/date_algorithms.html
+        let days = days + 719_468;
+        let era = (if days >= 0 { days } else { days - 146_096 }) / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// Parses a date in the format `YYYY-MM-DD`. Returns `None` if the text is not a valid date.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().splitn(3, '-');
+
+        let year = parts.next()?.trim().parse::<i32>().ok()?;
+        let month = parts.next()?.trim().parse::<u32>().ok()?;
+        let day = parts.next()?.trim().parse::<u32>().ok()?;
+
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Date { year, month, day })
+    }
+
+    /// Gets the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Gets the month (1 - 12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Gets the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the count of days since 1970-01-01.
+    pub fn days(&self) -> i64 {
+        let year = i64::from(self.year) - if self.month <= 2 { 1 } else { 0 };
+        let month = i64::from(self.month);
+        let era = (if year >= 0 { year } else { year - 399 }) / 400;
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the day of the week, 0 is monday and 6 is sunday.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a thursday
+        (self.days() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the date that is the given count of days later (or earlier if negative).
+    pub fn add_days(&self, days: i64) -> Self {
+        Date::from_days(self.days() + days)
+    }
+
+    /// Returns the date that is the given count of months later (or earlier if negative). The day is limited to
+    /// the days of the new month.
+    pub fn add_months(&self, months: i32) -> Self {
+        let months = self.year * 12 + self.month as i32 - 1 + months;
+
+        Date::new(
+            months.div_euclid(12),
+            months.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// Returns the first day of the month of the date.
+    pub fn first_of_month(&self) -> Self {
+        Date::new(self.year, self.month, 1)
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Date::new(1970, 1, 1)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl From<(i32, u32, u32)> for Date {
+    fn from(t: (i32, u32, u32)) -> Self {
+        Date::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<&str> for Date {
+    fn from(t: &str) -> Self {
+        Date::parse(t).unwrap_or_default()
+    }
+}
+
+/// Returns `true` if the given year is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the count of days of the given month (1 - 12).
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_new() {
+        let date = Date::new(2020, 2, 31);
+        assert_eq!((date.year(), date.month(), date.day()), (2020, 2, 29));
+
+        let date = Date::new(2021, 13, 0);
+        assert_eq!((date.year(), date.month(), date.day()), (2021, 12, 1));
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(Date::new(1970, 1, 1).days(), 0);
+        assert_eq!(Date::new(2000, 3, 1).days(), 11017);
+        assert_eq!(Date::new(1969, 12, 31).days(), -1);
+
+        for days in -1000..1000 {
+            assert_eq!(Date::from_days(days * 37).days(), days * 37);
+        }
+
+        assert_eq!(Date::from_days(11017), Date::new(2000, 3, 1));
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(Date::new(1970, 1, 1).weekday(), 3);
+        assert_eq!(Date::new(2020, 6, 1).weekday(), 0);
+        assert_eq!(Date::new(2020, 6, 7).weekday(), 6);
+        assert_eq!(Date::new(1969, 12, 29).weekday(), 0);
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(Date::new(2020, 1, 31).add_months(1), Date::new(2020, 2, 29));
+        assert_eq!(
+            Date::new(2020, 12, 15).add_months(1),
+            Date::new(2021, 1, 15)
+        );
+        assert_eq!(
+            Date::new(2020, 1, 15).add_months(-1),
+            Date::new(2019, 12, 15)
+        );
+        assert_eq!(
+            Date::new(2020, 6, 15).add_months(-18),
+            Date::new(2018, 12, 15)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Date::parse("2020-06-01"), Some(Date::new(2020, 6, 1)));
+        assert_eq!(Date::parse(" 2020-6-1 "), Some(Date::new(2020, 6, 1)));
+        assert_eq!(Date::parse("2019-02-29"), None);
+        assert_eq!(Date::parse("2020-13-01"), None);
+        assert_eq!(Date::parse("date"), None);
+        assert_eq!(Date::new(2020, 6, 1).to_string(), "2020-06-01");
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2020, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2021, 4), 30);
+        assert_eq!(days_in_month(2021, 12), 31);
+    }
+}
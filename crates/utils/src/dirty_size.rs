@@ -52,7 +52,7 @@ impl DirtySize {
 
     pub fn set_size(&mut self, width: f64, height: f64) {
         if (self.width - width).abs() > std::f64::EPSILON
-            && (self.height - height).abs() > std::f64::EPSILON
+            || (self.height - height).abs() > std::f64::EPSILON
         {
             self.dirty = true
         }
@@ -111,6 +111,20 @@ mod tests {
         assert!(dirty_size.dirty());
     }
 
+    #[test]
+    fn test_set_size_one_dimension() {
+        let mut dirty_size = DirtySize::default();
+        dirty_size.set_size(10.0, 20.0);
+        dirty_size.set_dirty(false);
+
+        dirty_size.set_size(10.0, 0.0);
+        assert!(dirty_size.dirty());
+
+        dirty_size.set_dirty(false);
+        dirty_size.set_size(10.0, 0.0);
+        assert!(!dirty_size.dirty());
+    }
+
     #[test]
     fn test_set_dirty() {
         let mut dirty_size = DirtySize::default();
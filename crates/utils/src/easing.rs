@@ -0,0 +1,204 @@
+/// Describes how the progress of a transition or an animation runs over time.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Easing {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+
+    /// A cubic bézier curve with the control points `(x1, y1)` and `(x2, y2)` like `cubic-bezier()` of CSS.
+    CubicBezier(f64, f64, f64, f64),
+
+    /// A damped spring with the given stiffness and damping ratio, with a damping ratio below `1.0` it overshoots the
+    /// end value. The progress jumps to the end value at the end of the duration, a stiffness of `100.0` or more
+    /// settles the spring before. The stiffness is at least `MIN_SPRING_STIFFNESS`.
+    Spring(f64, f64),
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Ease
+    }
+}
+
+impl Easing {
+    /// Converts the linear progress of the time (0.0 - 1.0) to the eased progress.
+    pub fn ease(self, progress: f64) -> f64 {
+        let progress = progress.max(0.0).min(1.0);
+
+        match self {
+            Easing::Linear => progress,
+            Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, progress),
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, progress),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, progress),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, progress),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, progress),
+            Easing::Spring(stiffness, damping) => spring(stiffness, damping, progress),
+        }
+    }
+}
+
+impl From<&str> for Easing {
+    fn from(s: &str) -> Self {
+        let s = s.trim();
+
+        match s {
+            "linear" => return Easing::Linear,
+            "ease-in" | "ease_in" => return Easing::EaseIn,
+            "ease-out" | "ease_out" => return Easing::EaseOut,
+            "ease-in-out" | "ease_in_out" => return Easing::EaseInOut,
+            "spring" => return Easing::Spring(DEFAULT_SPRING_STIFFNESS, DEFAULT_SPRING_DAMPING),
+            _ => {}
+        }
+
+        if let Some(points) = s
+            .strip_prefix("cubic-bezier(")
+            .and_then(|s| s.strip_suffix(')'))
+        {
+            let points: Vec<f64> = points
+                .split(',')
+                .filter_map(|p| p.trim().parse().ok())
+                .collect();
+
+            if points.len() == 4 {
+                return Easing::CubicBezier(
+                    points[0].max(0.0).min(1.0),
+                    points[1],
+                    points[2].max(0.0).min(1.0),
+                    points[3],
+                );
+            }
+        }
+
+        if let Some(values) = s.strip_prefix("spring(").and_then(|s| s.strip_suffix(')')) {
+            let values: Vec<f64> = values
+                .split(',')
+                .filter_map(|v| v.trim().parse().ok())
+                .collect();
+
+            if values.len() == 2 {
+                return Easing::Spring(values[0].max(MIN_SPRING_STIFFNESS), values[1].max(0.0));
+            }
+        }
+
+        Easing::Ease
+    }
+}
+
+impl From<String> for Easing {
+    fn from(s: String) -> Self {
+        Easing::from(s.as_str())
+    }
+}
+
+/// The smallest stiffness of a spring, a spring without stiffness would never move.
+pub const MIN_SPRING_STIFFNESS: f64 = 1.0;
+
+/// The stiffness of the `spring` easing.
+pub const DEFAULT_SPRING_STIFFNESS: f64 = 100.0;
+
+/// The damping ratio of the `spring` easing, it overshoots the end value slightly.
+pub const DEFAULT_SPRING_DAMPING: f64 = 0.5;
+
+// --- Helpers --
+
+// Solves the curve for the given x (time) with Newton's method and returns its y (progress).
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    let curve = |a: f64, b: f64, t: f64| {
+        3.0 * a * t * (1.0 - t) * (1.0 - t) + 3.0 * b * t * t * (1.0 - t) + t * t * t
+    };
+    let slope = |a: f64, b: f64, t: f64| {
+        3.0 * a * (1.0 - t) * (1.0 - t) + 6.0 * (b - a) * t * (1.0 - t) + 3.0 * (1.0 - b) * t * t
+    };
+
+    let mut t = x;
+
+    for _ in 0..8 {
+        let error = curve(x1, x2, t) - x;
+
+        if error.abs() < 1e-6 {
+            break;
+        }
+
+        let slope = slope(x1, x2, t);
+
+        if slope.abs() < 1e-6 {
+            break;
+        }
+
+        t = (t - error / slope).max(0.0).min(1.0);
+    }
+
+    curve(y1, y2, t)
+}
+
+// Calculates the position of a damped spring with the mass 1.0 at the given time, the time of the whole transition is
+// 1.0.
+fn spring(stiffness: f64, damping: f64, t: f64) -> f64 {
+    let omega = stiffness.max(MIN_SPRING_STIFFNESS).sqrt();
+
+    if damping < 1.0 {
+        let damped_omega = omega * (1.0 - damping * damping).sqrt();
+
+        1.0 - (-damping * omega * t).exp()
+            * ((damped_omega * t).cos() + damping * omega / damped_omega * (damped_omega * t).sin())
+    } else {
+        // critically damped or overdamped springs don't overshoot
+        1.0 - (-omega * t).exp() * (1.0 + omega * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ease() {
+        for easing in &[
+            Easing::Linear,
+            Easing::Ease,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(easing.ease(0.0).abs() < 1e-4);
+            assert!((easing.ease(1.0) - 1.0).abs() < 1e-4);
+        }
+
+        assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-4);
+        assert!(Easing::EaseIn.ease(0.25) < 0.25);
+        assert!(Easing::EaseOut.ease(0.25) > 0.25);
+
+        // the spring overshoots the end value before it is settled
+        let spring = Easing::Spring(DEFAULT_SPRING_STIFFNESS, DEFAULT_SPRING_DAMPING);
+        assert!(spring.ease(0.0).abs() < 1e-4);
+        assert!((spring.ease(1.0) - 1.0).abs() < 1e-2);
+        assert!((0..100).any(|i| spring.ease(i as f64 / 100.0) > 1.0));
+
+        let spring = Easing::Spring(DEFAULT_SPRING_STIFFNESS, 1.0);
+        assert!((0..=100).all(|i| spring.ease(i as f64 / 100.0) <= 1.0));
+
+        // a spring without stiffness moves slowly instead of producing NaN
+        for spring in &[Easing::Spring(0.0, 0.5), Easing::from("spring(0, 0.5)")] {
+            assert!((0..=100).all(|i| spring.ease(i as f64 / 100.0).is_finite()));
+            assert!(spring.ease(0.5) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Easing::from("linear"), Easing::Linear);
+        assert_eq!(Easing::from("ease-in-out"), Easing::EaseInOut);
+        assert_eq!(
+            Easing::from("cubic-bezier(0.1, 0.7, 1.0, 0.1)"),
+            Easing::CubicBezier(0.1, 0.7, 1.0, 0.1)
+        );
+        assert_eq!(Easing::from("spring(200, 0.3)"), Easing::Spring(200.0, 0.3));
+        assert_eq!(
+            Easing::from("spring(0, 0.5)"),
+            Easing::Spring(MIN_SPRING_STIFFNESS, 0.5)
+        );
+        assert_eq!(Easing::from("unknown"), Easing::Ease);
+    }
+}
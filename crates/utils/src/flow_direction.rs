@@ -0,0 +1,64 @@
+/// Describes the direction in which the content of a widget flows. Right to left is used for languages like Arabic
+/// and Hebrew, the layout of the widget and its children is mirrored.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FlowDirection {
+    /// The content flows from left to right.
+    LeftToRight,
+
+    /// The content flows from right to left.
+    RightToLeft,
+}
+
+impl FlowDirection {
+    /// Mirrors the horizontal position of a child with the given position and width inside of its parent with the
+    /// given width, if the flow direction is right to left.
+    pub fn mirror_x(self, x: f64, width: f64, parent_width: f64) -> f64 {
+        match self {
+            FlowDirection::LeftToRight => x,
+            FlowDirection::RightToLeft => parent_width - x - width,
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for FlowDirection {
+    fn from(t: &str) -> Self {
+        match t {
+            "RightToLeft" | "right_to_left" | "rtl" => FlowDirection::RightToLeft,
+            _ => FlowDirection::LeftToRight,
+        }
+    }
+}
+
+impl Default for FlowDirection {
+    fn default() -> FlowDirection {
+        FlowDirection::LeftToRight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let flow_direction: FlowDirection = "RightToLeft".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "right_to_left".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "rtl".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "other".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+    }
+
+    #[test]
+    fn test_mirror_x() {
+        assert_eq!(FlowDirection::LeftToRight.mirror_x(10.0, 20.0, 100.0), 10.0);
+        assert_eq!(FlowDirection::RightToLeft.mirror_x(10.0, 20.0, 100.0), 70.0);
+    }
+}
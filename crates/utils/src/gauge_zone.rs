@@ -0,0 +1,30 @@
+use crate::Brush;
+
+/// A range of values of a gauge that is marked with its own brush, e.g. a warning zone from 80 to 100.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GaugeZone {
+    /// The start of the zone.
+    pub from: f64,
+
+    /// The end of the zone.
+    pub to: f64,
+
+    /// The brush of the zone.
+    pub brush: Brush,
+}
+
+impl GaugeZone {
+    /// Creates a new zone with the given range and brush.
+    pub fn new(from: f64, to: f64, brush: impl Into<Brush>) -> Self {
+        GaugeZone {
+            from: from.min(to),
+            to: from.max(to),
+            brush: brush.into(),
+        }
+    }
+
+    /// Checks if the value is inside of the zone.
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.from && value <= self.to
+    }
+}
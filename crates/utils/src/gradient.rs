@@ -0,0 +1,318 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+/// Describes a position on a colorful gradient.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GradientStop {
+    pub position: f64,
+    pub color: Color,
+}
+
+impl GradientStop {
+    /// Creates a new stop at the given position (0.0 - 1.0).
+    pub fn new(position: f64, color: Color) -> Self {
+        GradientStop { position, color }
+    }
+}
+
+/// Describes the shape of a gradient. The coordinates are relative to the bounds of the filled or stroked shape.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GradientKind {
+    /// A linear gradient with the angle in degrees like in CSS, `0.0` runs from the bottom to the top and `90.0` from
+    /// the left to the right.
+    Linear(f64),
+
+    /// A radial gradient around the given center that reaches to the farthest corner, `(0.0, 0.0)` is the top left
+    /// and `(1.0, 1.0)` the bottom right corner of the shape.
+    Radial(Point),
+}
+
+/// A linear or radial gradient with multiple color stops that adapts to the size of the shape.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// let brush = Brush::from("linear-gradient(90deg, #2196f3, #e91e63 80%)");
+/// let brush = Brush::from("radial-gradient(at 25% 50%, #ffffff, transparent)");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    /// Creates a linear gradient with the given angle in degrees.
+    pub fn linear(angle: f64, stops: Vec<GradientStop>) -> Self {
+        Gradient {
+            kind: GradientKind::Linear(angle),
+            stops,
+        }
+    }
+
+    /// Creates a radial gradient around the given relative center.
+    pub fn radial(center: impl Into<Point>, stops: Vec<GradientStop>) -> Self {
+        Gradient {
+            kind: GradientKind::Radial(center.into()),
+            stops,
+        }
+    }
+
+    /// Calculates the start and the end point of a linear gradient for the given bounds. The gradient line runs
+    /// through the center and is long enough that the first and the last stop touch the corners like in CSS.
+    pub fn linear_coords(angle: f64, bounds: Rectangle) -> (Point, Point) {
+        let angle = angle * PI / 180.0;
+        let (sin, cos) = angle.sin_cos();
+        let length = (bounds.width() * sin).abs() + (bounds.height() * cos).abs();
+
+        let center_x = bounds.x() + bounds.width() / 2.0;
+        let center_y = bounds.y() + bounds.height() / 2.0;
+        let (dx, dy) = (sin * length / 2.0, -cos * length / 2.0);
+
+        (
+            Point::new(center_x - dx, center_y - dy),
+            Point::new(center_x + dx, center_y + dy),
+        )
+    }
+
+    /// Calculates the center point and the radius of a radial gradient for the given bounds.
+    pub fn radial_coords(center: Point, bounds: Rectangle) -> (Point, f64) {
+        let center = Point::new(
+            bounds.x() + bounds.width() * center.x(),
+            bounds.y() + bounds.height() * center.y(),
+        );
+
+        let radius = [
+            Point::new(bounds.x(), bounds.y()),
+            Point::new(bounds.x() + bounds.width(), bounds.y()),
+            Point::new(bounds.x(), bounds.y() + bounds.height()),
+            Point::new(bounds.x() + bounds.width(), bounds.y() + bounds.height()),
+        ]
+        .iter()
+        .map(|corner| center.distance(*corner))
+        .fold(0.0, f64::max);
+
+        (center, radius)
+    }
+
+    /// Parses a CSS gradient like `linear-gradient(to right, #000000, #ffffff 80%)`,
+    /// `linear-gradient(45deg, ...)` or `radial-gradient(at 25% 75%, ...)`. Returns `None` if the string is not a
+    /// gradient or has less than two color stops.
+    pub fn parse(s: &str) -> Option<Gradient> {
+        let s = s.trim();
+
+        let (linear, arguments) = if let Some(arguments) = s.strip_prefix("linear-gradient(") {
+            (true, arguments)
+        } else if let Some(arguments) = s.strip_prefix("radial-gradient(") {
+            (false, arguments)
+        } else {
+            return None;
+        };
+
+        let mut arguments: Vec<&str> = arguments
+            .strip_suffix(')')?
+            .split(',')
+            .map(str::trim)
+            .collect();
+
+        let kind = if linear {
+            match parse_angle(arguments[0]) {
+                Some(angle) => {
+                    arguments.remove(0);
+                    GradientKind::Linear(angle)
+                }
+                None => GradientKind::Linear(180.0),
+            }
+        } else {
+            match parse_center(arguments[0]) {
+                Some(center) => {
+                    arguments.remove(0);
+                    GradientKind::Radial(center)
+                }
+                None => GradientKind::Radial(Point::new(0.5, 0.5)),
+            }
+        };
+
+        let stops = parse_stops(&arguments)?;
+
+        Some(Gradient { kind, stops })
+    }
+}
+
+// --- Helpers --
+
+// Parses `45deg`, `0.5turn`, `1.2rad` or a direction like `to right`.
+fn parse_angle(s: &str) -> Option<f64> {
+    if let Some(direction) = s.strip_prefix("to ") {
+        let mut words: Vec<&str> = direction.split_whitespace().collect();
+        words.sort_unstable();
+
+        return match words.as_slice() {
+            ["top"] => Some(0.0),
+            ["right", "top"] => Some(45.0),
+            ["right"] => Some(90.0),
+            ["bottom", "right"] => Some(135.0),
+            ["bottom"] => Some(180.0),
+            ["bottom", "left"] => Some(225.0),
+            ["left"] => Some(270.0),
+            ["left", "top"] => Some(315.0),
+            _ => None,
+        };
+    }
+
+    if let Some(deg) = s.strip_suffix("deg") {
+        return deg.trim().parse().ok();
+    }
+
+    if let Some(turn) = s.strip_suffix("turn") {
+        return turn.trim().parse::<f64>().ok().map(|t| t * 360.0);
+    }
+
+    s.strip_suffix("rad")?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .map(|r| r * 180.0 / PI)
+}
+
+// Parses the shape and the center of a radial gradient like `at 25% 75%`, `circle at center` or `ellipse`.
+fn parse_center(s: &str) -> Option<Point> {
+    let mut words = s.split_whitespace();
+
+    match words.next()? {
+        "circle" | "ellipse" => match words.next() {
+            Some("at") => {}
+            None => return Some(Point::new(0.5, 0.5)),
+            _ => return None,
+        },
+        "at" => {}
+        _ => return None,
+    }
+
+    let x = words.next().map_or(Some(0.5), parse_position)?;
+    let y = words.next().map_or(Some(0.5), parse_position)?;
+
+    Some(Point::new(x, y))
+}
+
+fn parse_position(s: &str) -> Option<f64> {
+    match s {
+        "left" | "top" => Some(0.0),
+        "center" => Some(0.5),
+        "right" | "bottom" => Some(1.0),
+        _ => s
+            .strip_suffix('%')?
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|p| p / 100.0),
+    }
+}
+
+// Parses the color stops like `#ff0000 25%`. Stops without position are spread evenly between their neighbours.
+fn parse_stops(arguments: &[&str]) -> Option<Vec<GradientStop>> {
+    if arguments.len() < 2 {
+        return None;
+    }
+
+    let mut stops = vec![];
+    let mut positions = vec![];
+
+    for argument in arguments {
+        let mut words = argument.split_whitespace();
+        stops.push(Color::from(words.next()?));
+        positions.push(match words.next() {
+            Some(position) => Some(parse_position(position)?),
+            None => None,
+        });
+    }
+
+    let last = positions.len() - 1;
+    positions[0] = positions[0].or(Some(0.0));
+    positions[last] = positions[last].or(Some(1.0));
+
+    let mut start = 0;
+
+    for i in 1..positions.len() {
+        if let Some(end_position) = positions[i] {
+            let start_position = positions[start].unwrap_or_default();
+
+            for (step, position) in positions[start + 1..i].iter_mut().enumerate() {
+                *position = Some(
+                    start_position
+                        + (end_position - start_position) * (step + 1) as f64 / (i - start) as f64,
+                );
+            }
+
+            start = i;
+        }
+    }
+
+    Some(
+        stops
+            .into_iter()
+            .zip(positions)
+            .map(|(color, position)| GradientStop::new(position.unwrap_or_default(), color))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let gradient =
+            Gradient::parse("linear-gradient(to right, #000000, #ff0000, #ffffff 80%)").unwrap();
+        assert_eq!(gradient.kind, GradientKind::Linear(90.0));
+        assert_eq!(
+            gradient
+                .stops
+                .iter()
+                .map(|s| s.position)
+                .collect::<Vec<f64>>(),
+            vec![0.0, 0.4, 0.8]
+        );
+
+        assert_eq!(
+            Gradient::parse("linear-gradient(0.5turn, #000000, #ffffff)")
+                .unwrap()
+                .kind,
+            GradientKind::Linear(180.0)
+        );
+        assert_eq!(
+            Gradient::parse("radial-gradient(circle at 25% top, #000000, #ffffff)")
+                .unwrap()
+                .kind,
+            GradientKind::Radial(Point::new(0.25, 0.0))
+        );
+        assert_eq!(
+            Gradient::parse("radial-gradient(#000000, #ffffff)")
+                .unwrap()
+                .kind,
+            GradientKind::Radial(Point::new(0.5, 0.5))
+        );
+
+        assert!(Gradient::parse("linear-gradient(90deg, #000000)").is_none());
+        assert!(Gradient::parse("#000000").is_none());
+    }
+
+    #[test]
+    fn test_coords() {
+        let bounds = Rectangle::new((0.0, 0.0), (100.0, 50.0));
+
+        let (start, end) = Gradient::linear_coords(90.0, bounds);
+        assert!((start.x() - 0.0).abs() < 1e-6 && (start.y() - 25.0).abs() < 1e-6);
+        assert!((end.x() - 100.0).abs() < 1e-6 && (end.y() - 25.0).abs() < 1e-6);
+
+        let (start, end) = Gradient::linear_coords(180.0, bounds);
+        assert!((start.y() - 0.0).abs() < 1e-6 && (end.y() - 50.0).abs() < 1e-6);
+
+        let (center, radius) = Gradient::radial_coords(Point::new(0.0, 0.0), bounds);
+        assert_eq!(center, Point::new(0.0, 0.0));
+        assert!((radius - 100.0_f64.hypot(50.0)).abs() < 1e-6);
+    }
+}
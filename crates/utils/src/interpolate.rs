@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// Used to calculate the values between a start and an end value, e.g. by transitions and animations.
+pub trait Interpolate {
+    /// Returns the value at the given progress (0.0 - 1.0) between this value and the given end value.
+    fn interpolate(&self, to: &Self, progress: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress as f32
+    }
+}
+
+// whole numbers are rounded to the nearest value
+macro_rules! interpolate_integer {
+    ($($type:ty),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                    (*self as f64).interpolate(&(*to as f64), progress).round() as $type
+                }
+            }
+        )*
+    };
+}
+
+interpolate_integer!(i32, i64, u8, u32, u64, usize);
+
+impl Interpolate for Thickness {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Thickness::new(
+            self.left.interpolate(&to.left, progress),
+            self.top.interpolate(&to.top, progress),
+            self.right.interpolate(&to.right, progress),
+            self.bottom.interpolate(&to.bottom, progress),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Color::interpolate(*self, *to, progress)
+    }
+}
+
+impl Interpolate for Brush {
+    // Only solid colors are interpolated, other brushes switch at the end.
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        match (self, to) {
+            (Brush::SolidColor(from), Brush::SolidColor(to)) => {
+                Brush::SolidColor(from.interpolate(to, progress))
+            }
+            _ if progress < 1.0 => self.clone(),
+            _ => to.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(2.0_f64.interpolate(&4.0, 0.5), 3.0);
+        assert_eq!(10_usize.interpolate(&0, 0.26), 7);
+        assert_eq!(
+            Thickness::from(0.0).interpolate(&Thickness::from((2.0, 4.0)), 0.5),
+            Thickness::new(1.0, 2.0, 1.0, 2.0)
+        );
+        assert_eq!(
+            Brush::from("#000000").interpolate(&Brush::from("#FF0000"), 1.0),
+            Brush::from("#FF0000")
+        );
+    }
+}
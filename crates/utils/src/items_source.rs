@@ -0,0 +1,232 @@
+use std::ops::Index;
+
+/// The maximum number of changes an `ItemsSource` remembers.
+pub const ITEMS_SOURCE_MAX_CHANGES: usize = 128;
+
+/// Describes a single change of an `ItemsSource`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ItemsChange {
+    /// An item is inserted at the given index.
+    Insert(usize),
+
+    /// The item at the given index is removed.
+    Remove(usize),
+
+    /// The item at the given index is replaced.
+    Update(usize),
+
+    /// All items are replaced.
+    Reset,
+}
+
+/// The changes of an `ItemsSource` since a given version.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemsChanges {
+    /// The current number of items.
+    pub count: usize,
+
+    /// The current version of the source.
+    pub version: usize,
+
+    /// The list of changes. If it is `None` the changes are unknown and all items has to be rebuild.
+    pub changes: Option<Vec<ItemsChange>>,
+}
+
+/// `ItemsSource` is an observable list of items. It records each insert, remove and update operation so items
+/// widgets that are bound to the source could update their item widgets incrementally.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ItemsSource<T> {
+    items: Vec<T>,
+    changes: Vec<ItemsChange>,
+    version: usize,
+}
+
+impl<T> Default for ItemsSource<T> {
+    fn default() -> Self {
+        ItemsSource {
+            items: vec![],
+            changes: vec![],
+            version: 0,
+        }
+    }
+}
+
+impl<T> ItemsSource<T> {
+    /// Creates a new empty source.
+    pub fn new() -> Self {
+        ItemsSource::default()
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the source contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns a reference to the item at the given index.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Returns an iterator over all items.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Returns all items as slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the current version. It is increased by each change.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Appends an item to the end of the source.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.record(ItemsChange::Insert(self.items.len() - 1));
+    }
+
+    /// Inserts an item at the given index.
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        self.items.insert(index, item);
+        self.record(ItemsChange::Insert(index));
+    }
+
+    /// Removes and returns the item at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let item = self.items.remove(index);
+        self.record(ItemsChange::Remove(index));
+        item
+    }
+
+    /// Replaces the item at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn update(&mut self, index: usize, item: T) {
+        self.items[index] = item;
+        self.record(ItemsChange::Update(index));
+    }
+
+    /// Replaces all items of the source.
+    pub fn reset(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.record(ItemsChange::Reset);
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.reset(vec![]);
+    }
+
+    /// Returns all changes since the given version.
+    pub fn changes(&self, version: usize) -> ItemsChanges {
+        let changes = if version > self.version || self.version - version > self.changes.len() {
+            None
+        } else {
+            Some(self.changes[self.changes.len() - (self.version - version)..].to_vec())
+        };
+
+        ItemsChanges {
+            count: self.items.len(),
+            version: self.version,
+            changes,
+        }
+    }
+
+    fn record(&mut self, change: ItemsChange) {
+        self.version += 1;
+
+        if change == ItemsChange::Reset {
+            // no single change before a reset is of interest
+            self.changes.clear();
+        }
+
+        self.changes.push(change);
+
+        if self.changes.len() > ITEMS_SOURCE_MAX_CHANGES {
+            self.changes.remove(0);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for ItemsSource<T> {
+    fn from(items: Vec<T>) -> Self {
+        ItemsSource {
+            items,
+            changes: vec![],
+            version: 0,
+        }
+    }
+}
+
+impl<T> Index<usize> for ItemsSource<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_changes() {
+        let mut source = ItemsSource::from(vec!["a", "b"]);
+        assert_eq!(source.changes(0).changes, Some(vec![]));
+
+        source.push("c");
+        source.insert(0, "d");
+        source.remove(1);
+        source.update(0, "e");
+
+        assert_eq!(source.as_slice(), &["e", "b", "c"]);
+        assert_eq!(source.version(), 4);
+
+        let changes = source.changes(2);
+        assert_eq!(changes.count, 3);
+        assert_eq!(changes.version, 4);
+        assert_eq!(
+            changes.changes,
+            Some(vec![ItemsChange::Remove(1), ItemsChange::Update(0)])
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut source = ItemsSource::from(vec![1, 2]);
+        source.push(3);
+        source.clear();
+
+        assert!(source.is_empty());
+        assert_eq!(source.changes(0).changes, None);
+        assert_eq!(source.changes(1).changes, Some(vec![ItemsChange::Reset]));
+    }
+
+    #[test]
+    fn test_max_changes() {
+        let mut source = ItemsSource::new();
+
+        for i in 0..ITEMS_SOURCE_MAX_CHANGES + 1 {
+            source.push(i);
+        }
+
+        assert_eq!(source.changes(0).changes, None);
+        assert_eq!(
+            source.changes(1).changes.map(|c| c.len()),
+            Some(ITEMS_SOURCE_MAX_CHANGES)
+        );
+    }
+}
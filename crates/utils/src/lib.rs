@@ -1,37 +1,73 @@
 pub use self::alignment::*;
 pub use self::border::*;
+pub use self::border_radius::*;
+pub use self::box_shadow::*;
 pub use self::brush::*;
+pub use self::chart_data::*;
+pub use self::check_cycle::*;
 pub use self::color::*;
+pub use self::date::*;
 pub use self::dirty_size::*;
+pub use self::easing::*;
 pub use self::filter::*;
+pub use self::flow_direction::*;
+pub use self::gauge_zone::*;
+pub use self::gradient::*;
+pub use self::interpolate::*;
+pub use self::items_source::*;
+pub use self::mnemonic::*;
 pub use self::orientation::*;
 pub use self::point::*;
 pub use self::rectangle::*;
+pub use self::rich_text::*;
 pub use self::selection_mode::*;
 pub use self::size::*;
+pub use self::stretch::*;
 pub use self::string16::*;
+pub use self::syntax::*;
 pub use self::text_alignment::*;
 pub use self::text_baseline::*;
 pub use self::thickness::*;
+pub use self::time::*;
+pub use self::transform::*;
+pub use self::transition::*;
 pub use self::value::*;
 pub use self::visibility::*;
 
 mod alignment;
 mod border;
+mod border_radius;
+mod box_shadow;
 mod brush;
+mod chart_data;
+mod check_cycle;
 mod color;
+mod date;
 mod dirty_size;
+mod easing;
 mod filter;
+mod flow_direction;
+mod gauge_zone;
+mod gradient;
+mod interpolate;
+mod items_source;
+mod mnemonic;
 mod orientation;
 mod point;
 pub mod prelude;
 mod rectangle;
+mod rich_text;
 mod selection_mode;
 mod size;
 mod spacer;
+mod stretch;
 mod string16;
+mod syntax;
 mod text_alignment;
 mod text_baseline;
 mod thickness;
+mod time;
+mod transform;
+mod transition;
 mod value;
 mod visibility;
@@ -0,0 +1,112 @@
+/// Describes a text with a mnemonic (access key). The character after an underscore is the mnemonic, e.g. `"_File"`
+/// is displayed as `File` with the mnemonic `f`. Only the first marked character is used, two underscores are displayed
+/// as one underscore.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mnemonic {
+    text: String,
+    index: Option<usize>,
+}
+
+impl Mnemonic {
+    /// Parses the mnemonic of the given text.
+    pub fn parse(source: &str) -> Self {
+        let mut text = String::with_capacity(source.len());
+        let mut index = None;
+        let mut chars = source.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == '_' {
+                match chars.peek() {
+                    Some('_') => {
+                        chars.next();
+                    }
+                    Some(_) if index.is_none() => {
+                        index = Some(text.len());
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            text.push(c);
+        }
+
+        Mnemonic { text, index }
+    }
+
+    /// Gets the displayed text without the mnemonic markers.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the byte index of the mnemonic character in the displayed text.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Gets the mnemonic character in lower case.
+    pub fn key(&self) -> Option<char> {
+        self.split()
+            .and_then(|(_, mnemonic)| mnemonic.chars().next())
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+    }
+
+    /// Splits the displayed text in the text before the mnemonic and the mnemonic character, e.g. to measure the
+    /// position of its underline.
+    pub fn split(&self) -> Option<(&str, &str)> {
+        let index = self.index?;
+        let len = self.text[index..].chars().next()?.len_utf8();
+
+        Some((&self.text[..index], &self.text[index..index + len]))
+    }
+}
+
+impl From<&str> for Mnemonic {
+    fn from(s: &str) -> Self {
+        Mnemonic::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_parse() {
+        let mnemonic = Mnemonic::parse("_File");
+        assert_eq!(mnemonic.text(), "File");
+        assert_eq!(mnemonic.index(), Some(0));
+        assert_eq!(mnemonic.key(), Some('f'));
+        assert_eq!(mnemonic.split(), Some(("", "F")));
+
+        let mnemonic = Mnemonic::parse("Save _as");
+        assert_eq!(mnemonic.text(), "Save as");
+        assert_eq!(mnemonic.key(), Some('a'));
+        assert_eq!(mnemonic.split(), Some(("Save ", "a")));
+
+        let mnemonic = Mnemonic::parse("Ö_ffnen");
+        assert_eq!(mnemonic.text(), "Öffnen");
+        assert_eq!(mnemonic.split(), Some(("Ö", "f")));
+    }
+
+    #[test]
+    fn test_parse_without_mnemonic() {
+        let mnemonic = Mnemonic::parse("File");
+        assert_eq!(mnemonic.text(), "File");
+        assert_eq!(mnemonic.key(), None);
+        assert_eq!(mnemonic.split(), None);
+
+        assert_eq!(Mnemonic::parse("snake__case").text(), "snake_case");
+        assert_eq!(Mnemonic::parse("snake__case").key(), None);
+        assert_eq!(Mnemonic::parse("end_").text(), "end_");
+        assert_eq!(Mnemonic::parse("end_").key(), None);
+        assert_eq!(Mnemonic::parse("").key(), None);
+    }
+
+    #[test]
+    fn test_first_mnemonic() {
+        let mnemonic = Mnemonic::parse("_Open _file");
+        assert_eq!(mnemonic.text(), "Open _file");
+        assert_eq!(mnemonic.key(), Some('o'));
+    }
+}
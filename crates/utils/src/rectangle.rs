@@ -112,6 +112,27 @@ impl Rectangle {
             || rect.y() > (self.y() + self.height())
             || self.y() > (rect.y() + rect.height()))
     }
+
+    /// Returns the overlapping part of this rect and the given `rect`. If they don't overlap the returned rect is
+    /// empty.
+    pub fn intersection(&self, rect: &Rectangle) -> Rectangle {
+        let x = self.x().max(rect.x());
+        let y = self.y().max(rect.y());
+        let right = (self.x() + self.width()).min(rect.x() + rect.width());
+        let bottom = (self.y() + self.height()).min(rect.y() + rect.height());
+
+        Rectangle::new((x, y), ((right - x).max(0.0), (bottom - y).max(0.0)))
+    }
+
+    /// Returns the smallest rect that contains this rect and the given `rect`.
+    pub fn union(&self, rect: &Rectangle) -> Rectangle {
+        let x = self.x().min(rect.x());
+        let y = self.y().min(rect.y());
+        let right = (self.x() + self.width()).max(rect.x() + rect.width());
+        let bottom = (self.y() + self.height()).max(rect.y() + rect.height());
+
+        Rectangle::new((x, y), (right - x, bottom - y))
+    }
 }
 
 // --- Conversions ---
@@ -250,4 +271,32 @@ mod tests {
         let r = Rectangle::new((5.0, -30.0), (20.0, 30.0));
         assert!(!rect.intersects(&r), "{:?}", r);
     }
+
+    #[test]
+    fn test_intersection() {
+        let rect = Rectangle::new((5.0, 10.0), (20.0, 30.0));
+
+        let r = Rectangle::new((15.0, 0.0), (20.0, 20.0));
+        assert_eq!(
+            rect.intersection(&r),
+            Rectangle::new((15.0, 10.0), (10.0, 10.0))
+        );
+
+        let r = Rectangle::new((10.0, 20.0), (5.0, 5.0));
+        assert_eq!(rect.intersection(&r), r);
+
+        let r = Rectangle::new((50.0, 50.0), (5.0, 5.0));
+        assert_eq!(rect.intersection(&r).size(), Size::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_union() {
+        let rect = Rectangle::new((5.0, 10.0), (20.0, 30.0));
+
+        let r = Rectangle::new((15.0, 0.0), (20.0, 20.0));
+        assert_eq!(rect.union(&r), Rectangle::new((5.0, 0.0), (30.0, 40.0)));
+
+        let r = Rectangle::new((10.0, 20.0), (5.0, 5.0));
+        assert_eq!(rect.union(&r), rect);
+    }
 }
@@ -0,0 +1,736 @@
+use crate::{Brush, Point, Rectangle};
+
+/// A run of text with the same style inside of a `RichText`. Style properties that are not set are taken from the
+/// `RichTextStyle` of the widget that draws the text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpan {
+    /// The text of the span. A `\n` starts a new line.
+    pub text: String,
+
+    /// The font family of the span.
+    pub font: Option<String>,
+
+    /// The font size of the span.
+    pub font_size: Option<f64>,
+
+    /// The foreground of the span.
+    pub foreground: Option<Brush>,
+
+    /// If `true` the span is drawn with the bold font of the style.
+    pub bold: bool,
+
+    /// The url of an inline link.
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Creates a new span with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        TextSpan {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder method that sets the font family.
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Builder method that sets the font size.
+    pub fn font_size(mut self, font_size: f64) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Builder method that sets the foreground.
+    pub fn foreground(mut self, foreground: impl Into<Brush>) -> Self {
+        self.foreground = Some(foreground.into());
+        self
+    }
+
+    /// Builder method that draws the span with the bold font.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Builder method that makes the span a link to the given url.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(s: &str) -> TextSpan {
+        TextSpan::new(s)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(s: String) -> TextSpan {
+        TextSpan::new(s)
+    }
+}
+
+/// The default style of the spans of a `RichText`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RichTextStyle {
+    /// The font family of normal spans.
+    pub font: String,
+
+    /// The font family of bold spans.
+    pub bold_font: String,
+
+    /// The font size.
+    pub font_size: f64,
+
+    /// The foreground of the text.
+    pub foreground: Brush,
+
+    /// The foreground of links.
+    pub link_foreground: Brush,
+}
+
+impl RichTextStyle {
+    /// Gets the font family of the given span.
+    pub fn font<'a>(&'a self, span: &'a TextSpan) -> &'a str {
+        match &span.font {
+            Some(font) => font.as_str(),
+            None if span.bold => self.bold_font.as_str(),
+            None => self.font.as_str(),
+        }
+    }
+
+    /// Gets the font size of the given span.
+    pub fn font_size(&self, span: &TextSpan) -> f64 {
+        span.font_size.unwrap_or(self.font_size)
+    }
+
+    /// Gets the foreground of the given span.
+    pub fn foreground(&self, span: &TextSpan) -> Brush {
+        match &span.foreground {
+            Some(foreground) => foreground.clone(),
+            None if span.link.is_some() => self.link_foreground.clone(),
+            None => self.foreground.clone(),
+        }
+    }
+}
+
+/// A part of a span of a `RichText` that is drawn on one line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextFragment {
+    /// The index of the span.
+    pub span: usize,
+
+    /// The index of the line.
+    pub line: usize,
+
+    /// The text of the fragment.
+    pub text: String,
+
+    /// The index of the first character of the fragment in the text of the `RichText`.
+    pub start: usize,
+
+    /// The x position relative to the rich text.
+    pub x: f64,
+
+    /// The y position relative to the rich text. Fragments of a line are aligned to the bottom of the line.
+    pub y: f64,
+
+    /// The width of the fragment.
+    pub width: f64,
+
+    /// The height of the fragment.
+    pub height: f64,
+}
+
+impl TextFragment {
+    /// Gets the bounds of the fragment.
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle::new((self.x, self.y), (self.width, self.height))
+    }
+
+    /// Gets the number of characters of the fragment.
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Returns `true` if the fragment has no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+/// Formatted text made of spans with different fonts, weights, colors and inline links.
+///
+/// A rich text could be created from a subset of Markdown with `**bold**`, `__bold__`, `` `code` `` and
+/// `[links](url)`, line breaks are kept.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// let rich_text = RichText::new()
+///     .span("Read the ")
+///     .span(TextSpan::new("documentation").link("https://docs.rs/orbtk"))
+///     .span(TextSpan::new(" first!").bold());
+///
+/// let rich_text = RichText::from_markdown("Read the [documentation](https://docs.rs/orbtk) **first!**");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichText {
+    spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    /// Creates an empty rich text.
+    pub fn new() -> Self {
+        RichText::default()
+    }
+
+    /// Creates a rich text from a subset of Markdown.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let chars: Vec<char> = markdown.chars().collect();
+        let mut rich_text = RichText::new();
+        let mut text = String::new();
+        let mut bold = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            match c {
+                '\\' if matches!(next, Some(next) if next.is_ascii_punctuation()) => {
+                    text.push(next.unwrap());
+                    i += 2;
+                    continue;
+                }
+                '*' | '_' if next == Some(c) => {
+                    rich_text.push_text(&mut text, bold);
+                    bold = !bold;
+                    i += 2;
+                    continue;
+                }
+                // italic is not supported by the fonts, only the marker is removed
+                '*' => {
+                    i += 1;
+                    continue;
+                }
+                '`' => {
+                    if let Some(end) = find(&chars, i + 1, "`") {
+                        text.extend(&chars[i + 1..end]);
+                        i = end + 1;
+                        continue;
+                    }
+                }
+                '[' => {
+                    if let Some((label, url, end)) = parse_link(&chars, i) {
+                        rich_text.push_text(&mut text, bold);
+
+                        for mut span in RichText::from_markdown(&label).spans {
+                            span.bold |= bold;
+                            rich_text.spans.push(span.link(url.clone()));
+                        }
+
+                        i = end;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            text.push(c);
+            i += 1;
+        }
+
+        rich_text.push_text(&mut text, bold);
+        rich_text
+    }
+
+    // Adds the text as span and clears it.
+    fn push_text(&mut self, text: &mut String, bold: bool) {
+        if text.is_empty() {
+            return;
+        }
+
+        let mut span = TextSpan::new(text.clone());
+        span.bold = bold;
+        self.spans.push(span);
+        text.clear();
+    }
+
+    /// Builder method that adds a span.
+    pub fn span(mut self, span: impl Into<TextSpan>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Adds a span.
+    pub fn push(&mut self, span: impl Into<TextSpan>) {
+        self.spans.push(span.into());
+    }
+
+    /// Gets the spans.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Gets the text of all spans without formatting.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Gets the number of characters.
+    pub fn len(&self) -> usize {
+        self.spans
+            .iter()
+            .map(|span| span.text.chars().count())
+            .sum()
+    }
+
+    /// Returns `true` if the rich text has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+
+    /// Gets the text between the character indices `start` and `end`.
+    pub fn text_range(&self, start: usize, end: usize) -> String {
+        self.text()
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect()
+    }
+
+    /// Breaks the spans into lines that are not wider than `max_width`, a word that is wider than `max_width` gets
+    /// its own line. `measure` returns the size of a text with the given font size and font family.
+    ///
+    /// Returns the fragments of the lines and the size of the whole text.
+    pub fn layout<F>(
+        &self,
+        style: &RichTextStyle,
+        max_width: f64,
+        mut measure: F,
+    ) -> (Vec<TextFragment>, (f64, f64))
+    where
+        F: FnMut(&str, f64, &str) -> (f64, f64),
+    {
+        let mut fragments: Vec<TextFragment> = vec![];
+        let mut line_heights = vec![0.0_f64];
+        let mut x = 0.0;
+        let mut start = 0;
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let font = style.font(span);
+            let font_size = style.font_size(span);
+
+            for word in words(&span.text) {
+                let line = line_heights.len() - 1;
+
+                if word == "\n" {
+                    let height = measure(" ", font_size, font).1;
+                    line_heights[line] = line_heights[line].max(height);
+                    line_heights.push(0.0);
+                    x = 0.0;
+                    start += 1;
+                    continue;
+                }
+
+                let (width, height) = measure(word, font_size, font);
+                let visible_width = measure(word.trim_end(), font_size, font).0;
+                let mut line = line;
+
+                if x > 0.0 && x + visible_width > max_width {
+                    line_heights.push(0.0);
+                    line += 1;
+                    x = 0.0;
+                }
+
+                line_heights[line] = line_heights[line].max(height);
+
+                match fragments.last_mut() {
+                    Some(fragment) if fragment.span == index && fragment.line == line => {
+                        fragment.text.push_str(word);
+                        fragment.width += width;
+                        fragment.height = fragment.height.max(height);
+                    }
+                    _ => fragments.push(TextFragment {
+                        span: index,
+                        line,
+                        text: word.to_string(),
+                        start,
+                        x,
+                        y: 0.0,
+                        width,
+                        height,
+                    }),
+                }
+
+                x += width;
+                start += word.chars().count();
+            }
+        }
+
+        let mut line_tops = Vec::with_capacity(line_heights.len());
+        let mut height = 0.0;
+
+        for line_height in &line_heights {
+            line_tops.push(height);
+            height += line_height;
+        }
+
+        let mut width = 0.0_f64;
+
+        for fragment in &mut fragments {
+            fragment.y = line_tops[fragment.line] + line_heights[fragment.line] - fragment.height;
+            width = width.max(fragment.x + fragment.width);
+        }
+
+        (fragments, (width, height))
+    }
+
+    /// Gets the index of the character boundary that is next to the given position.
+    pub fn index_at<F>(
+        &self,
+        style: &RichTextStyle,
+        fragments: &[TextFragment],
+        position: Point,
+        mut measure: F,
+    ) -> usize
+    where
+        F: FnMut(&str, f64, &str) -> (f64, f64),
+    {
+        let last_line = match fragments.last() {
+            Some(fragment) => fragment.line,
+            None => return 0,
+        };
+
+        // the line below the position, or the last line
+        let line = (0..=last_line)
+            .find(|line| {
+                fragments
+                    .iter()
+                    .filter(|fragment| fragment.line == *line)
+                    .any(|fragment| position.y() < fragment.y + fragment.height)
+            })
+            .unwrap_or(last_line);
+
+        let line_fragments: Vec<&TextFragment> =
+            fragments.iter().filter(|f| f.line == line).collect();
+
+        let fragment = match line_fragments
+            .iter()
+            .find(|fragment| position.x() < fragment.x + fragment.width)
+        {
+            Some(fragment) => fragment,
+            None => {
+                // behind the end of the line, the trailing space of a wrapped line is not selected
+                let fragment = line_fragments[line_fragments.len() - 1];
+                let trimmed = fragment.text.trim_end().chars().count();
+                let is_last_line = line == last_line;
+                return fragment.start
+                    + if is_last_line {
+                        fragment.len()
+                    } else {
+                        trimmed
+                    };
+            }
+        };
+
+        let span = &self.spans[fragment.span];
+        let font = style.font(span);
+        let font_size = style.font_size(span);
+        let mut index = fragment.start;
+        let mut previous_x = fragment.x;
+
+        for (offset, (byte, _)) in fragment.text.char_indices().skip(1).enumerate() {
+            let x = fragment.x + measure(&fragment.text[..byte], font_size, font).0;
+
+            if position.x() < (previous_x + x) / 2.0 {
+                return index;
+            }
+
+            index = fragment.start + offset + 1;
+            previous_x = x;
+        }
+
+        if position.x() < (previous_x + fragment.x + fragment.width) / 2.0 {
+            index
+        } else {
+            fragment.start + fragment.len()
+        }
+    }
+
+    /// Gets the rectangles that cover the characters between the indices `start` and `end`.
+    pub fn range_bounds<F>(
+        &self,
+        style: &RichTextStyle,
+        fragments: &[TextFragment],
+        start: usize,
+        end: usize,
+        mut measure: F,
+    ) -> Vec<Rectangle>
+    where
+        F: FnMut(&str, f64, &str) -> (f64, f64),
+    {
+        let mut bounds = vec![];
+
+        for fragment in fragments {
+            let fragment_end = fragment.start + fragment.len();
+
+            if end <= fragment.start || start >= fragment_end {
+                continue;
+            }
+
+            let span = &self.spans[fragment.span];
+            let font = style.font(span);
+            let font_size = style.font_size(span);
+            let mut x_of = |index: usize| {
+                let chars = index.max(fragment.start).min(fragment_end) - fragment.start;
+                let text: String = fragment.text.chars().take(chars).collect();
+                measure(&text, font_size, font).0
+            };
+
+            let from = x_of(start);
+            let to = x_of(end);
+
+            bounds.push(Rectangle::new(
+                (fragment.x + from, fragment.y),
+                (to - from, fragment.height),
+            ));
+        }
+
+        bounds
+    }
+
+    /// Gets the url of the link at the given position.
+    pub fn link_at(&self, fragments: &[TextFragment], position: Point) -> Option<&str> {
+        fragments
+            .iter()
+            .find(|fragment| fragment.bounds().contains(position))
+            .and_then(|fragment| self.spans[fragment.span].link.as_deref())
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for RichText {
+    fn from(s: &str) -> RichText {
+        RichText::new().span(s)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(s: String) -> RichText {
+        RichText::new().span(s)
+    }
+}
+
+impl From<Vec<TextSpan>> for RichText {
+    fn from(spans: Vec<TextSpan>) -> RichText {
+        RichText { spans }
+    }
+}
+
+// --- Conversions ---
+
+// --- Helpers --
+
+// Splits a text into words with their trailing whitespace and line breaks.
+fn words(text: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    let mut whitespace = false;
+
+    for (index, c) in text.char_indices() {
+        if c == '\n' {
+            if start < index {
+                words.push(&text[start..index]);
+            }
+
+            words.push(&text[index..index + 1]);
+            start = index + 1;
+            whitespace = false;
+            continue;
+        }
+
+        if !c.is_whitespace() && whitespace && start < index {
+            words.push(&text[start..index]);
+            start = index;
+        }
+
+        whitespace = c.is_whitespace();
+    }
+
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+
+    words
+}
+
+// Finds the index of the pattern in the chars starting at `from`.
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    (from..chars.len()).find(|i| chars[*i..].starts_with(&pattern))
+}
+
+// Parses a Markdown link `[label](url)` at the index. Returns the label, the url and the index after the link.
+fn parse_link(chars: &[char], index: usize) -> Option<(String, String, usize)> {
+    let label_end = find(chars, index + 1, "](")?;
+    let url_end = find(chars, label_end + 2, ")")?;
+
+    let label: String = chars[index + 1..label_end].iter().collect();
+    let url: String = chars[label_end + 2..url_end].iter().collect();
+
+    if label.contains('\n') || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, url_end + 1))
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style() -> RichTextStyle {
+        RichTextStyle {
+            font: String::from("regular"),
+            bold_font: String::from("bold"),
+            font_size: 10.0,
+            foreground: Brush::from("#000000"),
+            link_foreground: Brush::from("#0000ff"),
+        }
+    }
+
+    // each character is as wide as the font size, bold characters are twice as wide
+    fn measure(text: &str, font_size: f64, font: &str) -> (f64, f64) {
+        let width = text.chars().count() as f64 * font_size;
+
+        if font == "bold" {
+            (width * 2.0, font_size)
+        } else {
+            (width, font_size)
+        }
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words("a bc  d"), vec!["a ", "bc  ", "d"]);
+        assert_eq!(words(" a\nb\n"), vec![" ", "a", "\n", "b", "\n"]);
+        assert!(words("").is_empty());
+    }
+
+    #[test]
+    fn test_from_markdown() {
+        let rich_text = RichText::from_markdown(
+            "Hello **bold** and [**a** link](https://redox-os.org) `*x*` \\*",
+        );
+        let spans = rich_text.spans();
+
+        assert_eq!(rich_text.text(), "Hello bold and a link *x* *");
+        assert_eq!(spans.len(), 6);
+        assert!(spans[1].bold);
+        assert_eq!(spans[1].text, "bold");
+        assert!(spans[3].bold);
+        assert_eq!(spans[3].link.as_deref(), Some("https://redox-os.org"));
+        assert_eq!(spans[4].text, " link");
+        assert!(!spans[4].bold);
+        assert_eq!(spans[4].link.as_deref(), Some("https://redox-os.org"));
+
+        assert_eq!(
+            RichText::from_markdown("[no link] (x)").text(),
+            "[no link] (x)"
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        let rich_text = RichText::new()
+            .span("aa bb ")
+            .span(TextSpan::new("cc").bold())
+            .span("\ndd");
+        let (fragments, size) = rich_text.layout(&style(), 60.0, measure);
+
+        // "aa bb " fits into the first line, the bold "cc" is 40 wide and wraps
+        assert_eq!(fragments.len(), 3);
+        assert_eq!(fragments[0].text, "aa bb ");
+        assert_eq!(fragments[1].text, "cc");
+        assert_eq!(fragments[1].line, 1);
+        assert!((fragments[1].x).abs() < f64::EPSILON);
+        assert_eq!(fragments[1].start, 6);
+        assert_eq!(fragments[2].text, "dd");
+        assert_eq!(fragments[2].line, 2);
+        assert_eq!(fragments[2].start, 9);
+        assert!((fragments[2].y - 20.0).abs() < f64::EPSILON);
+        assert_eq!(size, (60.0, 30.0));
+
+        let (fragments, size) = rich_text.layout(&style(), f64::MAX, measure);
+        assert_eq!(fragments[1].line, 0);
+        assert_eq!(size, (100.0, 20.0));
+    }
+
+    #[test]
+    fn test_index_at() {
+        let rich_text = RichText::from("ab cd");
+        let (fragments, _) = rich_text.layout(&style(), 30.0, measure);
+
+        assert_eq!(
+            rich_text.index_at(&style(), &fragments, Point::new(0.0, 0.0), measure),
+            0
+        );
+        assert_eq!(
+            rich_text.index_at(&style(), &fragments, Point::new(16.0, 5.0), measure),
+            2
+        );
+        assert_eq!(
+            rich_text.index_at(&style(), &fragments, Point::new(50.0, 5.0), measure),
+            2
+        );
+        assert_eq!(
+            rich_text.index_at(&style(), &fragments, Point::new(4.0, 15.0), measure),
+            3
+        );
+        assert_eq!(
+            rich_text.index_at(&style(), &fragments, Point::new(50.0, 50.0), measure),
+            5
+        );
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let rich_text = RichText::from("ab cd");
+        let (fragments, _) = rich_text.layout(&style(), 30.0, measure);
+        let bounds = rich_text.range_bounds(&style(), &fragments, 1, 4, measure);
+
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new((10.0, 0.0), (20.0, 10.0)),
+                Rectangle::new((0.0, 10.0), (10.0, 10.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_link_at() {
+        let rich_text = RichText::new()
+            .span("go ")
+            .span(TextSpan::new("home").link("app://home"));
+        let (fragments, _) = rich_text.layout(&style(), f64::MAX, measure);
+
+        assert_eq!(rich_text.link_at(&fragments, Point::new(5.0, 5.0)), None);
+        assert_eq!(
+            rich_text.link_at(&fragments, Point::new(35.0, 5.0)),
+            Some("app://home")
+        );
+    }
+}
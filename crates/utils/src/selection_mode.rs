@@ -1,9 +1,18 @@
 /// Represents a selection mode.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum SelectionMode {
+    /// No item could be selected.
     None,
+
+    /// One item could be selected. A click toggles the selection of the item.
     Single,
+
+    /// Many items could be selected. A click toggles the selection of the item.
     Multiple,
+
+    /// Many items could be selected. A click selects only the clicked item, Ctrl+click toggles the item and
+    /// Shift+click selects the range of items from the last clicked item.
+    Extended,
 }
 
 impl Default for SelectionMode {
@@ -17,6 +26,7 @@ impl From<&str> for SelectionMode {
         match t {
             "Single" | "single" => SelectionMode::Single,
             "Multiple" | "multiple" => SelectionMode::Multiple,
+            "Extended" | "extended" => SelectionMode::Extended,
             _ => SelectionMode::None,
         }
     }
@@ -40,6 +50,12 @@ mod tests {
         let selection_mode: SelectionMode = "multiple".into();
         assert_eq!(selection_mode, SelectionMode::Multiple);
 
+        let selection_mode: SelectionMode = "Extended".into();
+        assert_eq!(selection_mode, SelectionMode::Extended);
+
+        let selection_mode: SelectionMode = "extended".into();
+        assert_eq!(selection_mode, SelectionMode::Extended);
+
         let selection_mode: SelectionMode = "None".into();
         assert_eq!(selection_mode, SelectionMode::None);
 
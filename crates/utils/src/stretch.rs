@@ -0,0 +1,104 @@
+/// Describes how an image is resized to fill its bounds.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Stretch {
+    /// The image keeps its original size.
+    None,
+
+    /// The image is resized to fill the bounds. The aspect ratio is not preserved.
+    Fill,
+
+    /// The image is resized to fit inside of the bounds. The aspect ratio is preserved.
+    Uniform,
+
+    /// The image is resized to fill the bounds, the parts outside of the bounds are clipped. The aspect ratio is
+    /// preserved.
+    UniformToFill,
+}
+
+impl Default for Stretch {
+    fn default() -> Self {
+        Stretch::None
+    }
+}
+
+impl Stretch {
+    /// Calculates the size (width, height) of an image with the given size inside of the available size.
+    pub fn size(self, size: (f64, f64), available_size: (f64, f64)) -> (f64, f64) {
+        if size.0 <= 0.0 || size.1 <= 0.0 {
+            return size;
+        }
+
+        let scale_x = available_size.0 / size.0;
+        let scale_y = available_size.1 / size.1;
+
+        match self {
+            Stretch::None => size,
+            Stretch::Fill => available_size,
+            Stretch::Uniform => {
+                let scale = scale_x.min(scale_y);
+                (size.0 * scale, size.1 * scale)
+            }
+            Stretch::UniformToFill => {
+                let scale = scale_x.max(scale_y);
+                (size.0 * scale, size.1 * scale)
+            }
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Stretch {
+    fn from(t: &str) -> Self {
+        match t {
+            "Fill" | "fill" => Stretch::Fill,
+            "Uniform" | "uniform" => Stretch::Uniform,
+            "UniformToFill" | "uniform_to_fill" => Stretch::UniformToFill,
+            _ => Stretch::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(
+            Stretch::None.size((100.0, 50.0), (200.0, 200.0)),
+            (100.0, 50.0)
+        );
+        assert_eq!(
+            Stretch::Fill.size((100.0, 50.0), (200.0, 200.0)),
+            (200.0, 200.0)
+        );
+        assert_eq!(
+            Stretch::Uniform.size((100.0, 50.0), (200.0, 200.0)),
+            (200.0, 100.0)
+        );
+        assert_eq!(
+            Stretch::UniformToFill.size((100.0, 50.0), (200.0, 200.0)),
+            (400.0, 200.0)
+        );
+        assert_eq!(
+            Stretch::Uniform.size((0.0, 50.0), (200.0, 200.0)),
+            (0.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let stretch: Stretch = "Fill".into();
+        assert_eq!(stretch, Stretch::Fill);
+
+        let stretch: Stretch = "uniform".into();
+        assert_eq!(stretch, Stretch::Uniform);
+
+        let stretch: Stretch = "uniform_to_fill".into();
+        assert_eq!(stretch, Stretch::UniformToFill);
+
+        let stretch: Stretch = "other".into();
+        assert_eq!(stretch, Stretch::None);
+    }
+}
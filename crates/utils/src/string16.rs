@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// A UTF-16 encoded, growable string.
 ///
@@ -89,6 +90,85 @@ impl String16 {
     pub fn as_string(&self) -> String {
         String::from_utf16_lossy(&self.utf16)
     }
+
+    /// Removes the part that begins with the `start` and ends with the `end` index.
+    pub fn remove_range(&mut self, start: usize, end: usize) {
+        let end = end.min(self.utf16.len());
+
+        if start < end {
+            self.utf16.drain(start..end);
+        }
+    }
+
+    /// Returns the indices of all grapheme cluster boundaries including the start and the end of the string. A
+    /// grapheme cluster is a character as the user perceives it, e.g. a letter with combining marks or an emoji.
+    pub fn grapheme_boundaries(&self) -> Vec<usize> {
+        let text = self.as_string();
+        let mut boundaries = vec![0];
+        let mut index = 0;
+
+        for grapheme in text.graphemes(true) {
+            index += grapheme.encode_utf16().count();
+            boundaries.push(index);
+        }
+
+        boundaries
+    }
+
+    /// Returns the start index of the grapheme cluster before the given index.
+    pub fn prev_grapheme_boundary(&self, index: usize) -> usize {
+        self.grapheme_boundaries()
+            .into_iter()
+            .rev()
+            .find(|boundary| *boundary < index)
+            .unwrap_or(0)
+    }
+
+    /// Returns the end index of the grapheme cluster after the given index.
+    pub fn next_grapheme_boundary(&self, index: usize) -> usize {
+        self.grapheme_boundaries()
+            .into_iter()
+            .find(|boundary| *boundary > index)
+            .unwrap_or_else(|| self.len())
+    }
+
+    /// Returns the start index of the word before the given index. Spaces and punctuation between are skipped.
+    pub fn prev_word_boundary(&self, index: usize) -> usize {
+        self.words()
+            .into_iter()
+            .rev()
+            .map(|(start, _)| start)
+            .find(|start| *start < index)
+            .unwrap_or(0)
+    }
+
+    /// Returns the end index of the word after the given index. Spaces and punctuation between are skipped.
+    pub fn next_word_boundary(&self, index: usize) -> usize {
+        self.words()
+            .into_iter()
+            .map(|(_, end)| end)
+            .find(|end| *end > index)
+            .unwrap_or_else(|| self.len())
+    }
+
+    // Returns the start and the end index of each word.
+    fn words(&self) -> Vec<(usize, usize)> {
+        let text = self.as_string();
+        let mut words = vec![];
+        let mut index = 0;
+
+        for segment in text.split_word_bounds() {
+            let len = segment.encode_utf16().count();
+
+            if segment.chars().any(char::is_alphanumeric) {
+                words.push((index, index + len));
+            }
+
+            index += len;
+        }
+
+        words
+    }
 }
 
 impl From<&str> for String16 {
@@ -152,4 +232,42 @@ mod tests {
         string16.push('𝕊');
         assert_eq!(string16, String16::from("Bar𝕊"));
     }
+
+    #[test]
+    fn remove_range() {
+        let mut string16 = String16::from("Hello World");
+        string16.remove_range(5, 11);
+        assert_eq!(string16, String16::from("Hello"));
+
+        string16.remove_range(3, 20);
+        assert_eq!(string16, String16::from("Hel"));
+    }
+
+    #[test]
+    fn grapheme_boundary() {
+        // e with combining acute accent, emoji with skin tone modifier
+        let string16 = String16::from("ae\u{301}👍🏽b");
+        assert_eq!(string16.grapheme_boundaries(), vec![0, 1, 3, 7, 8]);
+
+        assert_eq!(string16.next_grapheme_boundary(1), 3);
+        assert_eq!(string16.next_grapheme_boundary(3), 7);
+        assert_eq!(string16.next_grapheme_boundary(8), 8);
+        assert_eq!(string16.prev_grapheme_boundary(7), 3);
+        assert_eq!(string16.prev_grapheme_boundary(3), 1);
+        assert_eq!(string16.prev_grapheme_boundary(0), 0);
+    }
+
+    #[test]
+    fn word_boundary() {
+        let string16 = String16::from("one two,  three");
+
+        assert_eq!(string16.next_word_boundary(0), 3);
+        assert_eq!(string16.next_word_boundary(3), 7);
+        assert_eq!(string16.next_word_boundary(7), 15);
+        assert_eq!(string16.next_word_boundary(15), 15);
+        assert_eq!(string16.prev_word_boundary(15), 10);
+        assert_eq!(string16.prev_word_boundary(10), 4);
+        assert_eq!(string16.prev_word_boundary(5), 4);
+        assert_eq!(string16.prev_word_boundary(4), 0);
+    }
 }
@@ -0,0 +1,405 @@
+use std::collections::HashSet;
+
+/// Describes the kind of a highlighted part of a source code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A keyword of the language, e.g. `fn` or `let`.
+    Keyword,
+
+    /// The name of a type, e.g. `String`.
+    Type,
+
+    /// A string or character literal.
+    String,
+
+    /// A number literal.
+    Number,
+
+    /// A line or block comment.
+    Comment,
+
+    /// An operator like `+` or `==`.
+    Operator,
+}
+
+impl TokenKind {
+    /// Gets the key of the brush property that is used to draw tokens of this kind, e.g. `keyword_brush`.
+    pub fn brush_key(self) -> &'static str {
+        match self {
+            TokenKind::Keyword => "keyword_brush",
+            TokenKind::Type => "type_brush",
+            TokenKind::String => "string_brush",
+            TokenKind::Number => "number_brush",
+            TokenKind::Comment => "comment_brush",
+            TokenKind::Operator => "operator_brush",
+        }
+    }
+}
+
+/// A highlighted part of a source code. `start` and `end` are character indices, the text between tokens is drawn
+/// as plain text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyntaxToken {
+    /// The kind of the token.
+    pub kind: TokenKind,
+
+    /// The index of the first character of the token.
+    pub start: usize,
+
+    /// The index behind the last character of the token.
+    pub end: usize,
+}
+
+impl SyntaxToken {
+    /// Creates a new token.
+    pub fn new(kind: TokenKind, start: usize, end: usize) -> Self {
+        SyntaxToken { kind, start, end }
+    }
+}
+
+/// Used to implement a syntax highlighter. It splits a source code into tokens, the tokens are sorted and do not
+/// overlap.
+pub trait SyntaxHighlighter {
+    /// Gets the tokens of the given text.
+    fn highlight(&self, text: &str) -> Vec<SyntaxToken>;
+}
+
+impl<F> SyntaxHighlighter for F
+where
+    F: Fn(&str) -> Vec<SyntaxToken>,
+{
+    fn highlight(&self, text: &str) -> Vec<SyntaxToken> {
+        self(text)
+    }
+}
+
+/// A simple syntax highlighter that is configured with the keywords, types, comments and string quotes of a
+/// language.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// let highlighter = KeywordHighlighter::new()
+///     .keywords(&["def", "return", "if", "else"])
+///     .line_comment("#")
+///     .quotes(&['"', '\'']);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeywordHighlighter {
+    keywords: HashSet<String>,
+    types: HashSet<String>,
+    capitalized_types: bool,
+    line_comment: Option<String>,
+    block_comment: Option<(String, String)>,
+    quotes: Vec<char>,
+    char_literals: bool,
+}
+
+impl KeywordHighlighter {
+    /// Creates a highlighter without keywords that highlights only numbers and operators.
+    pub fn new() -> Self {
+        KeywordHighlighter::default()
+    }
+
+    /// Creates a highlighter for Rust.
+    pub fn rust() -> Self {
+        KeywordHighlighter::new()
+            .keywords(&[
+                "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else",
+                "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match",
+                "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct",
+                "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+            ])
+            .types(&[
+                "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "str",
+                "u8", "u16", "u32", "u64", "u128", "usize",
+            ])
+            .capitalized_types(true)
+            .line_comment("//")
+            .block_comment("/*", "*/")
+            .quotes(&['"'])
+            .char_literals(true)
+    }
+
+    /// Builder method that adds keywords.
+    pub fn keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords
+            .extend(keywords.iter().map(|keyword| keyword.to_string()));
+        self
+    }
+
+    /// Builder method that adds type names.
+    pub fn types(mut self, types: &[&str]) -> Self {
+        self.types.extend(types.iter().map(|t| t.to_string()));
+        self
+    }
+
+    /// Builder method that highlights all identifiers that start with an upper case letter as types.
+    pub fn capitalized_types(mut self, capitalized_types: bool) -> Self {
+        self.capitalized_types = capitalized_types;
+        self
+    }
+
+    /// Builder method that sets the start of a line comment, e.g. `//`.
+    pub fn line_comment(mut self, start: impl Into<String>) -> Self {
+        self.line_comment = Some(start.into());
+        self
+    }
+
+    /// Builder method that sets the start and the end of a block comment, e.g. `/*` and `*/`.
+    pub fn block_comment(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.block_comment = Some((start.into(), end.into()));
+        self
+    }
+
+    /// Builder method that sets the quotes of string literals. A `\` escapes the next character.
+    pub fn quotes(mut self, quotes: &[char]) -> Self {
+        self.quotes = quotes.to_vec();
+        self
+    }
+
+    /// Builder method that highlights character literals like `'a'` or `'\n'`. A `'` that does not start a
+    /// character literal, e.g. of a lifetime, is not highlighted.
+    pub fn char_literals(mut self, char_literals: bool) -> Self {
+        self.char_literals = char_literals;
+        self
+    }
+
+    // Gets the kind of an identifier.
+    fn identifier_kind(&self, identifier: &str) -> Option<TokenKind> {
+        if self.keywords.contains(identifier) {
+            return Some(TokenKind::Keyword);
+        }
+
+        if self.types.contains(identifier)
+            || (self.capitalized_types
+                && identifier.chars().next().map(char::is_uppercase) == Some(true))
+        {
+            return Some(TokenKind::Type);
+        }
+
+        None
+    }
+}
+
+impl SyntaxHighlighter for KeywordHighlighter {
+    fn highlight(&self, text: &str) -> Vec<SyntaxToken> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut tokens = vec![];
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if let Some((start, end)) = &self.block_comment {
+                if starts_with(&chars, i, start) {
+                    let end = find(&chars, i + start.chars().count(), end)
+                        .map(|index| index + end.chars().count())
+                        .unwrap_or(chars.len());
+                    tokens.push(SyntaxToken::new(TokenKind::Comment, i, end));
+                    i = end;
+                    continue;
+                }
+            }
+
+            if let Some(start) = &self.line_comment {
+                if starts_with(&chars, i, start) {
+                    let end = (i..chars.len())
+                        .find(|index| chars[*index] == '\n')
+                        .unwrap_or(chars.len());
+                    tokens.push(SyntaxToken::new(TokenKind::Comment, i, end));
+                    i = end;
+                    continue;
+                }
+            }
+
+            if self.quotes.contains(&c) {
+                let end = string_end(&chars, i + 1, c);
+                tokens.push(SyntaxToken::new(TokenKind::String, i, end));
+                i = end;
+                continue;
+            }
+
+            if c == '\'' && self.char_literals {
+                if let Some(end) = char_literal_end(&chars, i) {
+                    tokens.push(SyntaxToken::new(TokenKind::String, i, end));
+                    i = end;
+                    continue;
+                }
+            }
+
+            if c.is_ascii_digit() {
+                let mut end = i + 1;
+
+                while end < chars.len()
+                    && (is_identifier_char(chars[end])
+                        || (chars[end] == '.'
+                            && matches!(chars.get(end + 1), Some(next) if next.is_ascii_digit())))
+                {
+                    end += 1;
+                }
+
+                tokens.push(SyntaxToken::new(TokenKind::Number, i, end));
+                i = end;
+                continue;
+            }
+
+            if is_identifier_char(c) {
+                let mut end = i + 1;
+
+                while end < chars.len() && is_identifier_char(chars[end]) {
+                    end += 1;
+                }
+
+                let identifier: String = chars[i..end].iter().collect();
+
+                if let Some(kind) = self.identifier_kind(&identifier) {
+                    tokens.push(SyntaxToken::new(kind, i, end));
+                }
+
+                i = end;
+                continue;
+            }
+
+            if is_operator(c) {
+                let mut end = i + 1;
+
+                while end < chars.len() && is_operator(chars[end]) {
+                    end += 1;
+                }
+
+                tokens.push(SyntaxToken::new(TokenKind::Operator, i, end));
+                i = end;
+                continue;
+            }
+
+            i += 1;
+        }
+
+        tokens
+    }
+}
+
+// --- Helpers --
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_operator(c: char) -> bool {
+    "+-*/%=<>!&|^~?".contains(c)
+}
+
+fn starts_with(chars: &[char], index: usize, pattern: &str) -> bool {
+    !pattern.is_empty()
+        && pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(index + offset) == Some(&c))
+}
+
+// Finds the index of the pattern in the chars starting at `from`.
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    (from..chars.len()).find(|index| starts_with(chars, *index, pattern))
+}
+
+// Gets the index behind the closing quote of a string, or the end of the text if the string is not closed.
+fn string_end(chars: &[char], from: usize, quote: char) -> usize {
+    let mut i = from;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    chars.len()
+}
+
+// Gets the index behind a character literal like `'a'` or `'\u{e5c5}'` that starts at the index.
+fn char_literal_end(chars: &[char], index: usize) -> Option<usize> {
+    match chars.get(index + 1) {
+        Some('\\') => (index + 2..chars.len().min(index + 12))
+            .find(|i| chars[*i] == '\'' && *i > index + 2)
+            .map(|i| i + 1),
+        Some('\'') | Some('\n') | None => None,
+        Some(_) if chars.get(index + 2) == Some(&'\'') => Some(index + 3),
+        _ => None,
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(text: &str, tokens: &[SyntaxToken]) -> Vec<(TokenKind, String)> {
+        let chars: Vec<char> = text.chars().collect();
+
+        tokens
+            .iter()
+            .map(|token| (token.kind, chars[token.start..token.end].iter().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_rust_highlighter() {
+        let text =
+            "/* doc */ fn main<'a>(x: &'a str) -> u32 {\n    let c = '\\n'; // comment\n    \
+                    String::from(\"a \\\" b\").len() as u32 + 1.5e3\n}";
+        let tokens = KeywordHighlighter::rust().highlight(text);
+
+        assert_eq!(
+            kinds(text, &tokens),
+            vec![
+                (TokenKind::Comment, String::from("/* doc */")),
+                (TokenKind::Keyword, String::from("fn")),
+                (TokenKind::Operator, String::from("<")),
+                (TokenKind::Operator, String::from(">")),
+                (TokenKind::Operator, String::from("&")),
+                (TokenKind::Type, String::from("str")),
+                (TokenKind::Operator, String::from("->")),
+                (TokenKind::Type, String::from("u32")),
+                (TokenKind::Keyword, String::from("let")),
+                (TokenKind::Operator, String::from("=")),
+                (TokenKind::String, String::from("'\\n'")),
+                (TokenKind::Comment, String::from("// comment")),
+                (TokenKind::Type, String::from("String")),
+                (TokenKind::String, String::from("\"a \\\" b\"")),
+                (TokenKind::Keyword, String::from("as")),
+                (TokenKind::Type, String::from("u32")),
+                (TokenKind::Operator, String::from("+")),
+                (TokenKind::Number, String::from("1.5e3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_highlighter() {
+        let text = "def f(): # todo\n  return 'x'";
+        let highlighter = KeywordHighlighter::new()
+            .keywords(&["def", "return"])
+            .line_comment("#")
+            .quotes(&['"', '\'']);
+
+        assert_eq!(
+            kinds(text, &highlighter.highlight(text)),
+            vec![
+                (TokenKind::Keyword, String::from("def")),
+                (TokenKind::Comment, String::from("# todo")),
+                (TokenKind::Keyword, String::from("return")),
+                (TokenKind::String, String::from("'x'")),
+            ]
+        );
+
+        let closure = |text: &str| vec![SyntaxToken::new(TokenKind::Keyword, 0, text.len())];
+        assert_eq!(
+            closure.highlight("ab"),
+            vec![SyntaxToken::new(TokenKind::Keyword, 0, 2)]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+
+/// The count of minutes of a day.
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// Represents a time of the day with hour and minute.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// Creates a new time. The hour is limited to 0 - 23 and the minute to 0 - 59.
+    pub fn new(hour: u32, minute: u32) -> Self {
+        Time {
+            hour: hour.min(23),
+            minute: minute.min(59),
+        }
+    }
+
+    /// Creates a time from the count of minutes since midnight. The minutes wrap around the day.
+    pub fn from_minutes(minutes: i64) -> Self {
+        let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+
+        Time {
+            hour: (minutes / 60) as u32,
+            minute: (minutes % 60) as u32,
+        }
+    }
+
+    /// Parses a time in the format `HH:MM` or `h:MM AM` / `h:MM PM`. Returns `None` if the text is not a valid time.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+
+        let (text, pm) = if let Some(text) = text.strip_suffix("am") {
+            (text, Some(false))
+        } else if let Some(text) = text.strip_suffix("pm") {
+            (text, Some(true))
+        } else {
+            (text.as_str(), None)
+        };
+
+        let mut parts = text.trim().splitn(2, ':');
+
+        let hour = parts.next()?.trim().parse::<u32>().ok()?;
+        let minute = parts.next()?.trim().parse::<u32>().ok()?;
+
+        if minute > 59 {
+            return None;
+        }
+
+        let hour = match pm {
+            Some(pm) => {
+                if !(1..=12).contains(&hour) {
+                    return None;
+                }
+
+                hour % 12 + if pm { 12 } else { 0 }
+            }
+            None if hour > 23 => return None,
+            None => hour,
+        };
+
+        Some(Time { hour, minute })
+    }
+
+    /// Gets the hour (0 - 23).
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Gets the hour of the 12-hour clock (1 - 12).
+    pub fn hour_12(&self) -> u32 {
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+
+    /// Returns `true` if the time is after noon.
+    pub fn is_pm(&self) -> bool {
+        self.hour >= 12
+    }
+
+    /// Gets the minute (0 - 59).
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the count of minutes since midnight.
+    pub fn minutes(&self) -> i64 {
+        i64::from(self.hour) * 60 + i64::from(self.minute)
+    }
+
+    /// Returns the time that is the given count of minutes later (or earlier if negative). The time wraps around
+    /// the day.
+    pub fn add_minutes(&self, minutes: i64) -> Self {
+        Time::from_minutes(self.minutes() + minutes)
+    }
+
+    /// Returns the time with the hour changed by the given count. The hour wraps around the day, the minute is
+    /// kept.
+    pub fn add_hours(&self, hours: i32) -> Self {
+        Time::new(
+            (self.hour as i32 + hours).rem_euclid(24) as u32,
+            self.minute,
+        )
+    }
+
+    /// Returns the time with the minute changed by the given count. The minute wraps around the hour, the hour is
+    /// kept.
+    pub fn add_minutes_of_hour(&self, minutes: i32) -> Self {
+        Time::new(
+            self.hour,
+            (self.minute as i32 + minutes).rem_euclid(60) as u32,
+        )
+    }
+
+    /// Formats the time in the given clock format.
+    pub fn format(&self, clock_format: ClockFormat) -> String {
+        match clock_format {
+            ClockFormat::TwentyFourHour => self.to_string(),
+            ClockFormat::TwelveHour => format!(
+                "{}:{:02} {}",
+                self.hour_12(),
+                self.minute,
+                if self.is_pm() { "PM" } else { "AM" }
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl From<(u32, u32)> for Time {
+    fn from(t: (u32, u32)) -> Self {
+        Time::new(t.0, t.1)
+    }
+}
+
+impl From<&str> for Time {
+    fn from(t: &str) -> Self {
+        Time::parse(t).unwrap_or_default()
+    }
+}
+
+/// Describes if a time is shown with 24 hours or with 12 hours and AM / PM.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ClockFormat {
+    /// Hours from 0 to 23.
+    TwentyFourHour,
+
+    /// Hours from 1 to 12 with AM / PM.
+    TwelveHour,
+}
+
+impl Default for ClockFormat {
+    fn default() -> Self {
+        ClockFormat::TwentyFourHour
+    }
+}
+
+impl From<&str> for ClockFormat {
+    fn from(t: &str) -> Self {
+        match t {
+            "TwelveHour" | "twelve_hour" | "12h" => ClockFormat::TwelveHour,
+            _ => ClockFormat::TwentyFourHour,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Time::new(25, 61), Time::new(23, 59));
+        assert_eq!(Time::new(7, 5).to_string(), "07:05");
+    }
+
+    #[test]
+    fn test_add_minutes() {
+        assert_eq!(Time::new(23, 50).add_minutes(15), Time::new(0, 5));
+        assert_eq!(Time::new(0, 5).add_minutes(-10), Time::new(23, 55));
+        assert_eq!(Time::new(10, 0).add_minutes(-24 * 60), Time::new(10, 0));
+    }
+
+    #[test]
+    fn test_add_hours_and_minutes_of_hour() {
+        assert_eq!(Time::new(23, 30).add_hours(1), Time::new(0, 30));
+        assert_eq!(Time::new(0, 30).add_hours(-1), Time::new(23, 30));
+        assert_eq!(Time::new(10, 55).add_minutes_of_hour(10), Time::new(10, 5));
+        assert_eq!(Time::new(10, 0).add_minutes_of_hour(-1), Time::new(10, 59));
+    }
+
+    #[test]
+    fn test_twelve_hour() {
+        assert_eq!(Time::new(0, 15).format(ClockFormat::TwelveHour), "12:15 AM");
+        assert_eq!(Time::new(12, 0).format(ClockFormat::TwelveHour), "12:00 PM");
+        assert_eq!(Time::new(18, 45).format(ClockFormat::TwelveHour), "6:45 PM");
+        assert_eq!(
+            Time::new(18, 45).format(ClockFormat::TwentyFourHour),
+            "18:45"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Time::parse("07:05"), Some(Time::new(7, 5)));
+        assert_eq!(Time::parse(" 23:59 "), Some(Time::new(23, 59)));
+        assert_eq!(Time::parse("12:15 am"), Some(Time::new(0, 15)));
+        assert_eq!(Time::parse("6:45 PM"), Some(Time::new(18, 45)));
+        assert_eq!(Time::parse("12:00PM"), Some(Time::new(12, 0)));
+        assert_eq!(Time::parse("24:00"), None);
+        assert_eq!(Time::parse("13:00 PM"), None);
+        assert_eq!(Time::parse("10:60"), None);
+        assert_eq!(Time::parse("time"), None);
+    }
+}
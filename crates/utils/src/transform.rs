@@ -0,0 +1,355 @@
+use std::f64::consts::PI;
+
+use crate::prelude::*;
+
+/// Describes an affine transformation of a widget like `transform` of CSS. The matrix maps the point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::prelude::*;
+/// let transform = Transform::rotate(45.0).then(Transform::scale(2.0, 2.0));
+/// let transform = Transform::from("translate(10px, 0) rotate(45deg) scale(2)");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+}
+
+impl Transform {
+    /// Creates a new transform from the given matrix values.
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform { a, b, c, d, e, f }
+    }
+
+    /// Creates a transform that moves by the given distance.
+    pub fn translate(x: f64, y: f64) -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Creates a transform that scales by the given factors.
+    pub fn scale(x: f64, y: f64) -> Self {
+        Transform::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a transform that rotates clockwise by the given angle in degrees.
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Transform::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates a transform that skews by the given angles in degrees.
+    pub fn skew(x: f64, y: f64) -> Self {
+        Transform::new(
+            1.0,
+            y.to_radians().tan(),
+            x.to_radians().tan(),
+            1.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    /// Checks if the transform doesn't change anything.
+    pub fn is_identity(&self) -> bool {
+        *self == Transform::default()
+    }
+
+    /// Returns the transform that applies the given transform first and this transform after it.
+    pub fn multiply(&self, other: Transform) -> Transform {
+        Transform::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.e + self.c * other.f + self.e,
+            self.b * other.e + self.d * other.f + self.f,
+        )
+    }
+
+    /// Returns the transform that applies this transform first and the given transform after it.
+    pub fn then(&self, other: Transform) -> Transform {
+        other.multiply(*self)
+    }
+
+    /// Returns the transform applied around the given origin instead of `(0, 0)`, e.g. to rotate around the center of
+    /// a widget.
+    pub fn around(&self, origin: impl Into<Point>) -> Transform {
+        let origin = origin.into();
+
+        Transform::translate(origin.x(), origin.y())
+            .multiply(*self)
+            .multiply(Transform::translate(-origin.x(), -origin.y()))
+    }
+
+    /// Returns the inverse transform or `None` if the transform could not be inverted, e.g. if it scales to zero.
+    pub fn invert(&self) -> Option<Transform> {
+        let determinant = self.a * self.d - self.b * self.c;
+
+        if determinant.abs() < f64::EPSILON {
+            return None;
+        }
+
+        Some(Transform::new(
+            self.d / determinant,
+            -self.b / determinant,
+            -self.c / determinant,
+            self.a / determinant,
+            (self.c * self.f - self.d * self.e) / determinant,
+            (self.b * self.e - self.a * self.f) / determinant,
+        ))
+    }
+
+    /// Transforms the given point.
+    pub fn apply(&self, point: impl Into<Point>) -> Point {
+        let point = point.into();
+
+        Point::new(
+            self.a * point.x() + self.c * point.y() + self.e,
+            self.b * point.x() + self.d * point.y() + self.f,
+        )
+    }
+
+    // Splits the transform into translation, rotation in radians, scale and skew, the transform is
+    // translate * rotate * [scale_x skew; 0 scale_y].
+    fn decompose(&self) -> (f64, f64, f64, f64, f64, f64) {
+        let rotation = self.b.atan2(self.a);
+        let (sin, cos) = rotation.sin_cos();
+
+        (
+            self.e,
+            self.f,
+            rotation,
+            self.a.hypot(self.b),
+            cos * self.d - sin * self.c,
+            cos * self.c + sin * self.d,
+        )
+    }
+
+    fn compose(x: f64, y: f64, rotation: f64, scale_x: f64, scale_y: f64, skew: f64) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+
+        Transform::new(
+            cos * scale_x,
+            sin * scale_x,
+            cos * skew - sin * scale_y,
+            sin * skew + cos * scale_y,
+            x,
+            y,
+        )
+    }
+}
+
+// --- Trait implementations ---
+
+/// Parses a list of CSS transform functions like `translate(10px, 20px) rotate(45deg) scale(2)`. The functions
+/// `matrix`, `translate`, `translateX`, `translateY`, `scale`, `scaleX`, `scaleY`, `rotate`, `skew`, `skewX` and
+/// `skewY` are supported. `none` and unknown functions are ignored.
+impl From<&str> for Transform {
+    fn from(s: &str) -> Transform {
+        let mut transform = Transform::default();
+
+        for function in s.split(')') {
+            let mut parts = function.splitn(2, '(');
+            let name = parts.next().unwrap_or_default().trim();
+            let arguments: Vec<&str> = parts
+                .next()
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .collect();
+
+            let length = |i: usize| {
+                arguments
+                    .get(i)
+                    .and_then(|a| a.trim_end_matches("px").trim().parse::<f64>().ok())
+            };
+            let angle = |i: usize| arguments.get(i).and_then(|a| parse_angle(a));
+
+            let next = match (name, arguments.len()) {
+                ("matrix", 6) => Transform::new(
+                    length(0).unwrap_or(1.0),
+                    length(1).unwrap_or_default(),
+                    length(2).unwrap_or_default(),
+                    length(3).unwrap_or(1.0),
+                    length(4).unwrap_or_default(),
+                    length(5).unwrap_or_default(),
+                ),
+                ("translate", 1) | ("translate", 2) => Transform::translate(
+                    length(0).unwrap_or_default(),
+                    length(1).unwrap_or_default(),
+                ),
+                ("translateX", 1) => Transform::translate(length(0).unwrap_or_default(), 0.0),
+                ("translateY", 1) => Transform::translate(0.0, length(0).unwrap_or_default()),
+                ("scale", 1) | ("scale", 2) => {
+                    let x = length(0).unwrap_or(1.0);
+                    Transform::scale(x, length(1).unwrap_or(x))
+                }
+                ("scaleX", 1) => Transform::scale(length(0).unwrap_or(1.0), 1.0),
+                ("scaleY", 1) => Transform::scale(1.0, length(0).unwrap_or(1.0)),
+                ("rotate", 1) => Transform::rotate(angle(0).unwrap_or_default()),
+                ("skew", 1) | ("skew", 2) => {
+                    Transform::skew(angle(0).unwrap_or_default(), angle(1).unwrap_or_default())
+                }
+                ("skewX", 1) => Transform::skew(angle(0).unwrap_or_default(), 0.0),
+                ("skewY", 1) => Transform::skew(0.0, angle(0).unwrap_or_default()),
+                _ => continue,
+            };
+
+            transform = transform.multiply(next);
+        }
+
+        transform
+    }
+}
+
+impl From<String> for Transform {
+    fn from(s: String) -> Transform {
+        Transform::from(s.as_str())
+    }
+}
+
+impl From<Value> for Transform {
+    fn from(v: Value) -> Self {
+        Transform::from(v.get::<String>())
+    }
+}
+
+impl From<(f64, f64, f64, f64, f64, f64)> for Transform {
+    fn from(t: (f64, f64, f64, f64, f64, f64)) -> Self {
+        Transform::new(t.0, t.1, t.2, t.3, t.4, t.5)
+    }
+}
+
+/// Interpolates the translation, the rotation, the scale and the skew of the transforms like CSS, so a rotation
+/// keeps its size while it runs.
+impl Interpolate for Transform {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        let (x, y, rotation, scale_x, scale_y, skew) = self.decompose();
+        let (to_x, to_y, to_rotation, to_scale_x, to_scale_y, to_skew) = to.decompose();
+
+        // rotate the short way around
+        let mut delta = to_rotation - rotation;
+
+        if delta > PI {
+            delta -= 2.0 * PI;
+        } else if delta < -PI {
+            delta += 2.0 * PI;
+        }
+
+        Transform::compose(
+            x.interpolate(&to_x, progress),
+            y.interpolate(&to_y, progress),
+            rotation + delta * progress,
+            scale_x.interpolate(&to_scale_x, progress),
+            scale_y.interpolate(&to_scale_y, progress),
+            skew.interpolate(&to_skew, progress),
+        )
+    }
+}
+
+// --- Helpers --
+
+// Parses an angle like `45deg`, `0.25turn`, `1.2rad` or `100grad` to degrees, numbers without unit are degrees.
+fn parse_angle(s: &str) -> Option<f64> {
+    if let Some(turn) = s.strip_suffix("turn") {
+        return turn.trim().parse::<f64>().ok().map(|t| t * 360.0);
+    }
+
+    if let Some(rad) = s.strip_suffix("rad") {
+        if let Some(grad) = rad.strip_suffix('g') {
+            return grad.trim().parse::<f64>().ok().map(|g| g * 0.9);
+        }
+
+        return rad.trim().parse::<f64>().ok().map(f64::to_degrees);
+    }
+
+    s.trim_end_matches("deg").trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(left: Transform, right: Transform) {
+        for (l, r) in [
+            (left.a, right.a),
+            (left.b, right.b),
+            (left.c, right.c),
+            (left.d, right.d),
+            (left.e, right.e),
+            (left.f, right.f),
+        ]
+        .iter()
+        {
+            assert!((l - r).abs() < 1e-6, "{:?} != {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_near(
+            Transform::from("translate(10px, 20px) scale(2)"),
+            Transform::new(2.0, 0.0, 0.0, 2.0, 10.0, 20.0),
+        );
+        assert_near(Transform::from("rotate(0.25turn)"), Transform::rotate(90.0));
+        assert_near(
+            Transform::from("rotate(90deg)"),
+            Transform::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0),
+        );
+        assert!(Transform::from("none").is_identity());
+    }
+
+    #[test]
+    fn test_apply() {
+        let transform = Transform::rotate(90.0).around((10.0, 10.0));
+        let point = transform.apply((20.0, 10.0));
+        assert!((point.x() - 10.0).abs() < 1e-6 && (point.y() - 20.0).abs() < 1e-6);
+
+        let transform = Transform::translate(5.0, 0.0).then(Transform::scale(2.0, 2.0));
+        assert_eq!(transform.apply((1.0, 1.0)), Point::new(12.0, 2.0));
+    }
+
+    #[test]
+    fn test_invert() {
+        let transform = Transform::from("translate(10px, 5px) rotate(30deg) scale(2, 3)");
+        assert_near(
+            transform.multiply(transform.invert().unwrap()),
+            Transform::default(),
+        );
+        assert!(Transform::scale(0.0, 1.0).invert().is_none());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_near(
+            Transform::default().interpolate(&Transform::rotate(90.0), 0.5),
+            Transform::rotate(45.0),
+        );
+        assert_near(
+            Transform::rotate(170.0).interpolate(&Transform::rotate(-170.0), 0.5),
+            Transform::rotate(180.0),
+        );
+        assert_near(
+            Transform::scale(1.0, 1.0).interpolate(&Transform::scale(3.0, 2.0), 0.5),
+            Transform::scale(2.0, 1.5),
+        );
+
+        let transform =
+            Transform::from("translate(10px, 5px) rotate(30deg) scale(2, 3) skewX(10deg)");
+        assert_near(transform.interpolate(&transform, 0.5), transform);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+/// Describes the transition of a property like `transition: background 150ms ease` of CSS. If the property changes
+/// the value runs from the old to the new value over the duration instead of snapping.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Transition {
+    /// The name of the property or `all` for all properties that could be interpolated.
+    pub property: String,
+
+    /// The duration in milliseconds.
+    pub duration: f64,
+
+    /// The delay in milliseconds before the transition starts.
+    pub delay: f64,
+
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new transition of the given property.
+    pub fn new(property: impl Into<String>, duration: f64, easing: Easing) -> Self {
+        Transition {
+            property: property.into(),
+            duration,
+            delay: 0.0,
+            easing,
+        }
+    }
+
+    /// Parses a comma separated list of transitions like `background 150ms ease, padding 0.2s linear`. Each
+    /// transition consists of the property, the duration, an optional easing and an optional delay. Invalid entries
+    /// are skipped.
+    pub fn parse_list(s: &str) -> Vec<Transition> {
+        split(s, |c| c == ',')
+            .into_iter()
+            .filter_map(Transition::parse)
+            .collect()
+    }
+
+    /// Checks if the transition is used for the given property.
+    pub fn matches(&self, property: &str) -> bool {
+        self.property == property || self.property == "all"
+    }
+
+    fn parse(s: &str) -> Option<Transition> {
+        let mut words = split(s, char::is_whitespace).into_iter();
+        let mut transition =
+            Transition::new(words.next()?, parse_time(words.next()?)?, Easing::Ease);
+
+        for word in words {
+            match parse_time(word) {
+                Some(delay) => transition.delay = delay,
+                None => transition.easing = Easing::from(word),
+            }
+        }
+
+        Some(transition)
+    }
+}
+
+impl From<Value> for Vec<Transition> {
+    fn from(v: Value) -> Self {
+        Transition::parse_list(&v.get::<String>())
+    }
+}
+
+// --- Helpers --
+
+// Splits the string at the given separators, separators inside of parentheses like in `cubic-bezier(...)` are
+// skipped.
+fn split(s: &str, is_separator: fn(char) -> bool) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if depth == 0 && is_separator(c) => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&s[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+// Parses a time in milliseconds (`150ms`) or seconds (`0.2s`).
+fn parse_time(s: &str) -> Option<f64> {
+    if let Some(ms) = s.strip_suffix("ms") {
+        return ms.parse().ok();
+    }
+
+    s.strip_suffix('s')?.parse::<f64>().ok().map(|s| s * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        let transitions = Transition::parse_list(
+            "background 150ms ease-in, padding 0.2s cubic-bezier(0.1, 0.7, 1.0, 0.1) 50ms, invalid",
+        );
+
+        assert_eq!(
+            transitions,
+            vec![
+                Transition::new("background", 150.0, Easing::EaseIn),
+                Transition {
+                    property: "padding".to_string(),
+                    duration: 200.0,
+                    delay: 50.0,
+                    easing: Easing::CubicBezier(0.1, 0.7, 1.0, 0.1),
+                }
+            ]
+        );
+
+        assert!(Transition::new("all", 100.0, Easing::Linear).matches("foreground"));
+    }
+}
@@ -0,0 +1,447 @@
+use std::sync::mpsc;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_AUTO_COMPLETE_BOX: &str = "auto_complete_box";
+static STYLE_SUGGESTION: &str = "list_view_item";
+
+// --- KEYS --
+
+/// Delivers the suggestions of a suggestion provider to its `AutoCompleteBox` and wakes up the window. It could be
+/// moved to another thread, e.g. to answer after a web request has been finished.
+#[derive(Clone)]
+pub struct SuggestionSender {
+    request: u64,
+    sender: mpsc::Sender<(u64, Vec<String>)>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl SuggestionSender {
+    /// Sends the suggestions. Suggestions of an outdated query are ignored by the `AutoCompleteBox`.
+    pub fn send(&self, suggestions: Vec<String>) {
+        let _ = self.sender.send((self.request, suggestions));
+        let _ = self.window_sender.send(WindowRequest::Redraw);
+    }
+}
+
+/// Used to define a suggestion provider. It is called with the current text and sends its suggestions by the given
+/// `SuggestionSender`.
+pub type SuggestionProviderFn = dyn Fn(String, SuggestionSender) + 'static;
+
+#[derive(Copy, Clone)]
+enum AutoCompleteAction {
+    TextChanged,
+    Key(Key),
+    Accept(usize),
+    CheckMouseUpOutside(Point),
+}
+
+/// The `AutoCompleteBoxState` filters the suggestions and handles the suggestion popup of the `AutoCompleteBox`.
+#[derive(Default, AsAny)]
+pub struct AutoCompleteBoxState {
+    actions: Vec<AutoCompleteAction>,
+    provider: Option<Box<SuggestionProviderFn>>,
+    channel: Option<(
+        mpsc::Sender<(u64, Vec<String>)>,
+        mpsc::Receiver<(u64, Vec<String>)>,
+    )>,
+    request: u64,
+    suggestions: Vec<String>,
+    items: Vec<Entity>,
+    highlighted: Option<usize>,
+    accepted: Option<String>,
+    text_box: Entity,
+    popup: Entity,
+    items_panel: Entity,
+}
+
+impl AutoCompleteBoxState {
+    fn action(&mut self, action: AutoCompleteAction) {
+        self.actions.push(action);
+    }
+
+    // Requests new suggestions for the current text.
+    fn request_suggestions(&mut self, ctx: &mut Context) {
+        let text = ctx.widget().clone::<String16>("text").as_string();
+
+        // the text of an accepted suggestion is not completed again
+        if self.accepted.take().as_ref() == Some(&text) {
+            return;
+        }
+
+        self.request += 1;
+
+        if text.is_empty() || !*ctx.get_widget(self.text_box).get::<bool>("focused") {
+            self.show_suggestions(ctx, vec![]);
+            return;
+        }
+
+        if let Some(provider) = &self.provider {
+            if let Some((sender, _)) = &self.channel {
+                provider(
+                    text,
+                    SuggestionSender {
+                        request: self.request,
+                        sender: sender.clone(),
+                        window_sender: ctx.window_sender(),
+                    },
+                );
+            }
+
+            return;
+        }
+
+        let max_suggestions = *ctx.widget().get::<usize>("max_suggestions");
+        let suggestions = filter_suggestions(
+            ctx.widget().get::<Vec<String>>("suggestions"),
+            &text,
+            max_suggestions,
+        );
+
+        self.show_suggestions(ctx, suggestions);
+    }
+
+    // Takes the suggestions of the provider that belong to the current query.
+    fn receive_suggestions(&mut self, ctx: &mut Context) {
+        let mut suggestions = None;
+
+        if let Some((_, receiver)) = &self.channel {
+            while let Ok((request, received)) = receiver.try_recv() {
+                if request == self.request {
+                    suggestions = Some(received);
+                }
+            }
+        }
+
+        if let Some(mut suggestions) = suggestions {
+            suggestions.truncate(*ctx.widget().get::<usize>("max_suggestions"));
+            self.show_suggestions(ctx, suggestions);
+        }
+    }
+
+    // Rebuilds the items of the popup and opens it if there are suggestions.
+    fn show_suggestions(&mut self, ctx: &mut Context, suggestions: Vec<String>) {
+        let entity = ctx.entity;
+
+        ctx.clear_children_of(self.items_panel);
+        self.items.clear();
+        self.highlighted = None;
+
+        for (index, suggestion) in suggestions.iter().enumerate() {
+            let build_context = &mut ctx.build_context();
+
+            let item = Button::new()
+                .style(STYLE_SUGGESTION)
+                .text(suggestion.as_str())
+                .h_align("stretch")
+                .on_click(move |states, _| {
+                    states
+                        .get_mut::<AutoCompleteBoxState>(entity)
+                        .action(AutoCompleteAction::Accept(index));
+                    true
+                })
+                .build(build_context);
+
+            build_context.append_child(self.items_panel, item);
+            self.items.push(item);
+        }
+
+        ctx.widget().set("open", !suggestions.is_empty());
+        self.suggestions = suggestions;
+    }
+
+    fn handle_key(&mut self, ctx: &mut Context, key: Key) {
+        if !*ctx.widget().get::<bool>("open")
+            || !*ctx.get_widget(self.text_box).get::<bool>("focused")
+        {
+            return;
+        }
+
+        match key {
+            Key::Down => self.highlight(ctx, next_highlight(self.highlighted, self.items.len())),
+            Key::Up => self.highlight(ctx, previous_highlight(self.highlighted, self.items.len())),
+            Key::Enter => {
+                if let Some(index) = self.highlighted {
+                    self.accept(ctx, index);
+                }
+            }
+            Key::Escape => ctx.widget().set("open", false),
+            _ => {}
+        }
+    }
+
+    fn highlight(&mut self, ctx: &mut Context, highlighted: Option<usize>) {
+        self.highlighted = highlighted;
+
+        for (index, item) in self.items.iter().enumerate() {
+            let mut item = ctx.get_widget(*item);
+
+            {
+                let selector = item.get_mut::<Selector>("selector");
+
+                if Some(index) == highlighted {
+                    selector.set_state("selected");
+                } else {
+                    selector.clear_state();
+                }
+            }
+
+            item.update(false);
+        }
+    }
+
+    // Replaces the text by the suggestion and closes the popup.
+    fn accept(&mut self, ctx: &mut Context, index: usize) {
+        let suggestion = match self.suggestions.get(index) {
+            Some(suggestion) => suggestion.clone(),
+            None => return,
+        };
+
+        let length = suggestion.chars().count();
+        self.accepted = Some(suggestion.clone());
+
+        let mut text_box = ctx.get_widget(self.text_box);
+        text_box.set("text", String16::from(suggestion));
+        text_box.set(
+            "text_selection",
+            TextSelection {
+                start_index: length,
+                length: 0,
+            },
+        );
+        ctx.widget().set("open", false);
+    }
+
+    // Closes the popup on mouse up outside of the auto complete box and the popup.
+    fn check_mouse_up_outside(&mut self, ctx: &mut Context, position: Point) {
+        if !*ctx.widget().get::<bool>("open") {
+            return;
+        }
+
+        let inside = [ctx.entity, self.popup].iter().any(|entity| {
+            let widget = ctx.get_widget(*entity);
+            Rectangle::new(
+                widget.clone::<Point>("position"),
+                widget.get::<Rectangle>("bounds").size(),
+            )
+            .contains(position)
+        });
+
+        if !inside {
+            ctx.widget().set("open", false);
+        }
+    }
+}
+
+impl State for AutoCompleteBoxState {
+    fn init(&mut self, _: &mut Registry, _: &mut Context) {
+        self.channel = Some(mpsc::channel());
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<AutoCompleteAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                AutoCompleteAction::TextChanged => self.request_suggestions(ctx),
+                AutoCompleteAction::Key(key) => self.handle_key(ctx, key),
+                AutoCompleteAction::Accept(index) => self.accept(ctx, index),
+                AutoCompleteAction::CheckMouseUpOutside(position) => {
+                    self.check_mouse_up_outside(ctx, position)
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.receive_suggestions(ctx);
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.popup);
+    }
+}
+
+widget!(
+    /// The `AutoCompleteBox` is a text box that shows a popup with suggestions for the typed text. The suggestions
+    /// could be chosen by a click or by the Up and Down keys and accepted by Enter. Escape closes the popup.
+    ///
+    /// The suggestions are taken from the `suggestions` list, the entries that start with the text are shown first.
+    /// Use `suggestion_provider` to load the suggestions from another source, e.g. a web request.
+    ///
+    /// **style:** `auto_complete_box`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// AutoCompleteBox::new()
+    ///     .suggestions(vec!["Berlin".to_string(), "Bern".to_string(), "Paris".to_string()])
+    ///     .water_mark("City")
+    ///     .build(ctx)
+    /// ```
+    AutoCompleteBox<AutoCompleteBoxState>: KeyDownHandler, MouseHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the water_mark text property.
+        water_mark: String16,
+
+        /// Sets or shares the list of suggestions that is filtered by the text.
+        suggestions: Vec<String>,
+
+        /// Sets or shares the maximum count of shown suggestions.
+        max_suggestions: usize,
+
+        /// Sets or shares the flag if the suggestion popup is open.
+        open: bool
+    }
+);
+
+impl AutoCompleteBox {
+    /// Sets a provider that is called with the text on each change instead of filtering the `suggestions` list. The
+    /// provider sends the suggestions by the given `SuggestionSender`, that could be moved to another thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// AutoCompleteBox::new()
+    ///     .suggestion_provider(|text, sender| {
+    ///         std::thread::spawn(move || sender.send(search(&text)));
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    pub fn suggestion_provider<F: Fn(String, SuggestionSender) + 'static>(
+        mut self,
+        provider: F,
+    ) -> Self {
+        self.state_mut().provider = Some(Box::new(provider));
+        self
+    }
+}
+
+impl Template for AutoCompleteBox {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let text_box = TextBox::new()
+            .text(id)
+            .water_mark(id)
+            .lost_focus_on_activation(false)
+            .on_changed("text", move |states, _| {
+                states
+                    .get_mut::<AutoCompleteBoxState>(id)
+                    .action(AutoCompleteAction::TextChanged);
+            })
+            .build(ctx);
+
+        let items_panel = Stack::new().orientation("vertical").build(ctx);
+
+        let popup = Popup::new()
+            .open(id)
+            .target(text_box.0)
+            .child(items_panel)
+            .build(ctx);
+
+        let _ = ctx.append_child_to_overlay(popup);
+
+        self.state_mut().text_box = text_box;
+        self.state_mut().items_panel = items_panel;
+        self.state_mut().popup = popup;
+
+        self.name("AutoCompleteBox")
+            .style(STYLE_AUTO_COMPLETE_BOX)
+            .text("")
+            .water_mark("")
+            .suggestions(Vec::<String>::new())
+            .max_suggestions(8)
+            .open(false)
+            .min_width(128.0)
+            .height(32.0)
+            .child(text_box)
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<AutoCompleteBoxState>(id)
+                    .action(AutoCompleteAction::Key(event.key));
+                false
+            })
+            .on_global_mouse_up(move |states, e| {
+                states
+                    .get_mut::<AutoCompleteBoxState>(id)
+                    .action(AutoCompleteAction::CheckMouseUpOutside(e.position));
+            })
+    }
+}
+
+// --- Helpers --
+
+// Returns the suggestions that contain the query, ignoring the case. Suggestions that start with the query come
+// first.
+fn filter_suggestions(suggestions: &[String], query: &str, max: usize) -> Vec<String> {
+    let query = query.to_lowercase();
+
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let (mut starts_with, contains): (Vec<&String>, Vec<&String>) = suggestions
+        .iter()
+        .filter(|suggestion| suggestion.to_lowercase().contains(&query))
+        .partition(|suggestion| suggestion.to_lowercase().starts_with(&query));
+
+    starts_with.extend(contains);
+    starts_with.into_iter().take(max).cloned().collect()
+}
+
+fn next_highlight(highlighted: Option<usize>, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(highlighted.map_or(0, |index| (index + 1).min(count - 1)))
+}
+
+fn previous_highlight(highlighted: Option<usize>, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(highlighted.map_or(count - 1, |index| index.saturating_sub(1)))
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_suggestions() {
+        let suggestions: Vec<String> = vec!["Bern", "Berlin", "Hamburg", "Amberg"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(
+            filter_suggestions(&suggestions, "ber", 8),
+            vec!["Bern", "Berlin", "Amberg"]
+        );
+        assert_eq!(filter_suggestions(&suggestions, "BERL", 8), vec!["Berlin"]);
+        assert_eq!(
+            filter_suggestions(&suggestions, "ber", 2),
+            vec!["Bern", "Berlin"]
+        );
+        assert!(filter_suggestions(&suggestions, "", 8).is_empty());
+        assert!(filter_suggestions(&suggestions, "xyz", 8).is_empty());
+    }
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(next_highlight(None, 0), None);
+        assert_eq!(next_highlight(None, 3), Some(0));
+        assert_eq!(next_highlight(Some(1), 3), Some(2));
+        assert_eq!(next_highlight(Some(2), 3), Some(2));
+        assert_eq!(previous_highlight(None, 3), Some(2));
+        assert_eq!(previous_highlight(Some(1), 3), Some(0));
+        assert_eq!(previous_highlight(Some(0), 3), Some(0));
+    }
+}
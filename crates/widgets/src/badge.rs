@@ -0,0 +1,149 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_BADGE: &str = "badge";
+static ID_PILL: &str = "id_pill";
+
+// --- KEYS --
+
+/// The `BadgeState` updates the text of the badge and hides it if the count is `0`.
+#[derive(Default, AsAny)]
+pub struct BadgeState;
+
+impl BadgeState {
+    fn update_pill(&self, ctx: &mut Context) {
+        let count = *badge(ctx.widget()).count();
+        let max_count = *badge(ctx.widget()).max_count();
+
+        badge(ctx.widget()).set_text(String16::from(badge_text(count, max_count)));
+
+        let visibility = if count > 0 {
+            Visibility::Visible
+        } else {
+            Visibility::Collapsed
+        };
+
+        ctx.child(ID_PILL).set("visibility", visibility);
+    }
+}
+
+impl State for BadgeState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_pill(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_pill(ctx);
+    }
+}
+
+widget!(
+    /// The `Badge` widget attaches a small counter to the top right corner of its child, e.g. the number of unread
+    /// messages of a button. The badge is hidden if `count` is `0` and shows `99+` if it is greater than `max_count`.
+    ///
+    /// **style:** `badge`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Badge::new()
+    ///     .count(3)
+    ///     .child(Button::new().text("Inbox").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Badge<BadgeState> {
+        /// Sets or shares the count.
+        count: usize,
+
+        /// Sets or shares the largest count that is shown, greater counts are shown as `max_count+`.
+        max_count: usize,
+
+        /// Sets or shares the text of the badge. It is set by the badge itself.
+        text: String16,
+
+        /// Sets or shares the background property of the badge.
+        background: Brush,
+
+        /// Sets or shares the border radius property of the badge.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the padding property of the badge.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property of the badge.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String
+    }
+);
+
+impl Template for Badge {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        // the badge is the last child, so it is drawn on top of the content
+        self.name("Badge")
+            .style(STYLE_BADGE)
+            .count(0)
+            .max_count(99)
+            .text("")
+            .background("#d6453d")
+            .border_radius(8.0)
+            .padding((4.0, 0.0, 4.0, 0.0))
+            .foreground("#ffffff")
+            .font_size(10.0)
+            .font("Roboto-Medium")
+            .child(
+                Container::new()
+                    .id(ID_PILL)
+                    .h_align("end")
+                    .v_align("start")
+                    .min_width(16.0)
+                    .height(16.0)
+                    .background(id)
+                    .border_radius(id)
+                    .padding(id)
+                    .child(
+                        TextBlock::new()
+                            .h_align("center")
+                            .v_align("center")
+                            .text(id)
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Gets the text of the badge for the given count, e.g. `99+` if the count is greater than `max_count`.
+fn badge_text(count: usize, max_count: usize) -> String {
+    if count > max_count {
+        format!("{}+", max_count)
+    } else {
+        count.to_string()
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_text() {
+        assert_eq!(badge_text(0, 99), "0");
+        assert_eq!(badge_text(7, 99), "7");
+        assert_eq!(badge_text(99, 99), "99");
+        assert_eq!(badge_text(100, 99), "99+");
+        assert_eq!(badge_text(12, 9), "9+");
+    }
+}
@@ -79,7 +79,7 @@ widget!(
     /// The `MouseBehavior` widget is used to handle internal the pressed behavior of a widget.
     ///
     /// **style:** `check-box`
-    MouseBehavior<MouseBehaviorState>: MouseHandler {
+    MouseBehavior<MouseBehaviorState>: MouseHandler, ScrollHandler {
         /// Sets or shares the target of the behavior.
         target: u32,
 
@@ -108,10 +108,10 @@ impl Template for MouseBehavior {
                     .action(Action::Release(m));
                 false
             })
-            .on_scroll(move |states, p| {
+            .on_scroll(move |states, delta| {
                 states
                     .get_mut::<MouseBehaviorState>(id)
-                    .action(Action::Scroll(p));
+                    .action(Action::Scroll(delta.pixels));
                 false
             })
     }
@@ -1,16 +1,18 @@
 use crate::{
     api::prelude::*,
     proc_macros::*,
-    shell::prelude::{Key, KeyEvent},
+    shell::prelude::{Clipboard, Key, KeyEvent, MouseButton, WindowRequest},
     theme::fonts,
 };
 
-use super::MouseBehavior;
+use super::{MouseBehavior, TextEditKind, TextHistory, TextSnapshot, DEFAULT_HISTORY_DEPTH};
 
 #[derive(Clone)]
 enum TextAction {
     Key(KeyEvent),
     Mouse(Mouse),
+    Drag(Point),
+    Composition(ImeEvent),
 }
 
 /// The `TextBehaviorState` handles the text processing of the `TextBehavior` widget.
@@ -21,6 +23,9 @@ pub struct TextBehaviorState {
     cursor: Entity,
     target: Entity,
     focused: bool,
+    anchor: usize,
+    dragging: bool,
+    history: TextHistory,
 }
 
 impl TextBehaviorState {
@@ -28,7 +33,10 @@ impl TextBehaviorState {
         self.action = Some(action);
     }
 
-    fn request_focus(&self, ctx: &mut Context, p: Mouse) {
+    fn request_focus(&mut self, ctx: &mut Context, p: Mouse) {
+        // the clicked position is the anchor of a following drag selection
+        self.anchor = self.get_new_caret_position(ctx, p);
+
         ctx.push_event_by_window(FocusEvent::RequestFocus(self.target));
 
         // select all text if there is text and the element is not focused yet.
@@ -45,7 +53,7 @@ impl TextBehaviorState {
         {
             ctx.widget()
                 .get_mut::<TextSelection>("text_selection")
-                .start_index = self.get_new_caret_position(ctx, p);
+                .start_index = self.anchor;
             ctx.widget()
                 .get_mut::<TextSelection>("text_selection")
                 .length = 0;
@@ -54,6 +62,30 @@ impl TextBehaviorState {
         }
     }
 
+    // selects the text between the anchor and the current mouse position
+    fn drag(&mut self, ctx: &mut Context, position: Point) {
+        if !*ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        let caret = self.get_new_caret_position(
+            ctx,
+            Mouse {
+                position,
+                button: MouseButton::Left,
+            },
+        );
+        self.select(ctx, caret);
+    }
+
+    // selects the text between the anchor and the given caret index
+    fn select(&mut self, ctx: &mut Context, caret: usize) {
+        let selection = selection_between(self.anchor, caret);
+        ctx.widget().set("text_selection", selection);
+        ctx.get_widget(self.cursor)
+            .set("expanded", selection.length > 0);
+    }
+
     // Get new position for the caret based on current mouse position
     fn get_new_caret_position(&self, ctx: &mut Context, p: Mouse) -> usize {
         if let Some((index, _x)) = self
@@ -80,18 +112,15 @@ impl TextBehaviorState {
         let font: String = ctx.widget().clone_or_default::<String>("font");
         let font_size: f64 = ctx.widget().clone_or_default::<f64>("font_size");
 
-        for i in 0..text.len() {
+        // the caret could only be placed between grapheme clusters
+        for i in text.grapheme_boundaries().into_iter().skip(1) {
             let bound_width: f64 = ctx
                 .render_context_2_d()
-                .measure(
-                    &text.get_string(0, i + 1).unwrap().as_str(),
-                    font_size,
-                    &font,
-                )
+                .measure(&text.get_string(0, i).unwrap().as_str(), font_size, &font)
                 .width;
             let next_position: f64 = start_position + bound_width;
 
-            position_index.push((i + 1, next_position));
+            position_index.push((i, next_position));
         }
 
         // for (index, _) in text.chars().u.enumerate() {}
@@ -104,30 +133,161 @@ impl TextBehaviorState {
         ctx.widget().set("text_selection", TextSelection::default());
     }
 
-    fn check_outside_update(&self, ctx: &mut Context) {
+    fn check_outside_update(&mut self, ctx: &mut Context) {
         let len = ctx.widget().get::<String16>("text").len();
         if self.len != len && self.len > len {
             self.reset(ctx);
+            self.history.clear();
+        }
+    }
+
+    fn snapshot(&self, ctx: &mut Context) -> TextSnapshot {
+        TextSnapshot::new(
+            ctx.widget().get::<String16>("text").as_string(),
+            *ctx.widget().get::<TextSelection>("text_selection"),
+        )
+    }
+
+    fn restore(&mut self, snapshot: TextSnapshot, ctx: &mut Context) {
+        ctx.get_widget(self.target)
+            .set("text", String16::from(snapshot.text));
+        ctx.widget().set("text_selection", snapshot.selection);
+        ctx.get_widget(self.cursor)
+            .set("expanded", snapshot.selection.length > 0);
+    }
+
+    fn undo(&mut self, ctx: &mut Context) {
+        let current = self.snapshot(ctx);
+
+        if let Some(snapshot) = self.history.undo(current) {
+            self.restore(snapshot, ctx);
         }
     }
 
-    fn handle_key_event(&mut self, key_event: KeyEvent, ctx: &mut Context) {
+    fn redo(&mut self, ctx: &mut Context) {
+        let current = self.snapshot(ctx);
+
+        if let Some(snapshot) = self.history.redo(current) {
+            self.restore(snapshot, ctx);
+        }
+    }
+
+    fn handle_composition(&mut self, event: ImeEvent, ctx: &mut Context) {
         if !ctx.widget().get::<bool>("focused") {
             return;
         }
 
+        match event {
+            ImeEvent::Start => {
+                // the composition replaces the selected text
+                if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
+                    let before = self.snapshot(ctx);
+                    self.clear_selection(ctx);
+                    let depth = *ctx.widget().get::<usize>("history_depth");
+                    self.history.record(before, TextEditKind::Delete, depth);
+                }
+
+                ctx.widget().set("composition", String16::default());
+                self.update_ime_position(ctx);
+            }
+            ImeEvent::Update(composition) => {
+                ctx.widget()
+                    .set("composition", String16::from(single_line(&composition)));
+                self.update_ime_position(ctx);
+            }
+            ImeEvent::Commit(text) => {
+                ctx.widget().set("composition", String16::default());
+
+                let text = single_line(&text);
+                if text.is_empty() {
+                    return;
+                }
+
+                let before = self.snapshot(ctx);
+                self.insert_text(text.as_str(), ctx);
+                let depth = *ctx.widget().get::<usize>("history_depth");
+                self.history.record(before, TextEditKind::Insert, depth);
+            }
+        }
+    }
+
+    // moves the candidate window of the input method editor below the caret
+    fn update_ime_position(&self, ctx: &mut Context) {
+        let cursor = ctx.get_widget(self.cursor);
+        let position = *cursor.get::<Point>("position");
+        let height = cursor.get::<Rectangle>("bounds").height();
+
+        ctx.send_window_request(WindowRequest::SetImePosition(Point::new(
+            position.x(),
+            position.y() + height,
+        )));
+    }
+
+    fn handle_key_event(
+        &mut self,
+        key_event: KeyEvent,
+        registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        if !ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        let ctrl_down = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_ctrl_down();
+        let shift_down = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_shift_down();
+
+        let edit_kind = match key_event.key {
+            Key::Z(..) | Key::Y(..) if ctrl_down => None,
+            Key::Backspace | Key::Delete => Some(TextEditKind::Delete),
+            _ if ctrl_down => Some(TextEditKind::Other),
+            _ => Some(TextEditKind::Insert),
+        };
+        let before = self.snapshot(ctx);
+
         match key_event.key {
+            Key::Left if shift_down => {
+                self.expand_selection_left(ctx, ctrl_down);
+            }
+            Key::Right if shift_down => {
+                self.expand_selection_right(ctx, ctrl_down);
+            }
             Key::Left => {
-                self.move_cursor_left(ctx);
+                self.move_cursor_left(ctx, ctrl_down);
             }
             Key::Right => {
-                self.move_cursor_right(ctx);
+                self.move_cursor_right(ctx, ctrl_down);
+            }
+            Key::C(..) if ctrl_down => {
+                self.copy(registry, ctx);
+            }
+            Key::X(..) if ctrl_down => {
+                self.cut(registry, ctx);
+            }
+            Key::V(..) if ctrl_down => {
+                self.paste(registry, ctx);
+            }
+            Key::Z(..) if ctrl_down && shift_down => {
+                self.redo(ctx);
+            }
+            Key::Z(..) if ctrl_down => {
+                self.undo(ctx);
+            }
+            Key::Y(..) if ctrl_down => {
+                self.redo(ctx);
             }
             Key::Backspace => {
-                self.back_space(ctx);
+                self.back_space(ctx, ctrl_down);
             }
             Key::Delete => {
-                self.delete(ctx);
+                self.delete(ctx, ctrl_down);
             }
             Key::Enter => {
                 self.activate(ctx);
@@ -153,14 +313,129 @@ impl TextBehaviorState {
                 {
                     self.select_all(ctx);
                 } else {
-                    self.insert_char(key_event, ctx);
+                    self.insert_text(key_event.text.as_str(), ctx);
                 }
                 // }
             }
             _ => {
-                self.insert_char(key_event, ctx);
+                self.insert_text(key_event.text.as_str(), ctx);
             }
         }
+
+        let kind = if let Some(kind) = edit_kind {
+            kind
+        } else {
+            return;
+        };
+
+        if ctx.widget().get::<String16>("text").as_string() == before.text {
+            // caret movements end the current group of typed characters
+            self.history.break_group();
+        } else {
+            let depth = *ctx.widget().get::<usize>("history_depth");
+            self.history.record(before, kind, depth);
+        }
+    }
+
+    // returns the caret index of the selection end that is moved by shift + arrow keys.
+    fn selection_caret(&mut self, ctx: &mut Context) -> usize {
+        let selection = *ctx.widget().get::<TextSelection>("text_selection");
+
+        if selection.length == 0 || !*ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            self.anchor = selection.start_index;
+            return selection.start_index;
+        }
+
+        if self.anchor == selection.start_index {
+            selection.start_index + selection.length
+        } else {
+            self.anchor = selection.start_index + selection.length;
+            selection.start_index
+        }
+    }
+
+    fn expand_selection_left(&mut self, ctx: &mut Context, word: bool) {
+        let caret = self.selection_caret(ctx);
+        let caret = prev_boundary(ctx.widget().get::<String16>("text"), caret, word);
+        self.select(ctx, caret);
+    }
+
+    fn expand_selection_right(&mut self, ctx: &mut Context, word: bool) {
+        let caret = self.selection_caret(ctx);
+        let caret = next_boundary(ctx.widget().get::<String16>("text"), caret, word);
+        self.select(ctx, caret);
+    }
+
+    // returns the selected text or `None` if nothing is selected.
+    fn selected_text(&self, ctx: &mut Context) -> Option<String> {
+        let selection = *ctx.widget().get::<TextSelection>("text_selection");
+
+        if selection.length == 0 || !*ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            return None;
+        }
+
+        ctx.widget().get::<String16>("text").get_string(
+            selection.start_index,
+            selection.start_index + selection.length,
+        )
+    }
+
+    // obscured text like the input of a `PasswordBox` could not be copied.
+    fn is_obscured(&self, ctx: &mut Context) -> bool {
+        ctx.get_widget(self.target).try_clone::<bool>("revealed") == Some(false)
+    }
+
+    fn copy(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if self.is_obscured(ctx) {
+            return;
+        }
+
+        if let Some(text) = self.selected_text(ctx) {
+            registry.get::<Clipboard>("clipboard").set(text);
+        }
+    }
+
+    fn cut(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if self.is_obscured(ctx) {
+            return;
+        }
+
+        if let Some(text) = self.selected_text(ctx) {
+            registry.get::<Clipboard>("clipboard").set(text);
+            self.clear_selection(ctx);
+        }
+    }
+
+    fn paste(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let value = if let Some(value) = registry.get::<Clipboard>("clipboard").get() {
+            single_line(&value)
+        } else {
+            return;
+        };
+
+        if value.is_empty() {
+            return;
+        }
+
+        if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            self.clear_selection(ctx);
+        }
+
+        let index = ctx
+            .widget()
+            .get::<TextSelection>("text_selection")
+            .start_index;
+        let mut text = ctx.widget().clone::<String16>("text");
+        text.insert_str(index, value.as_str());
+        ctx.get_widget(self.target).set("text", text);
+
+        if let Some(selection) = ctx
+            .get_widget(self.cursor)
+            .try_get_mut::<TextSelection>("text_selection")
+        {
+            selection.start_index = index + value.encode_utf16().count();
+            selection.length = 0;
+        }
     }
 
     fn select_all(&self, ctx: &mut Context) {
@@ -174,52 +449,50 @@ impl TextBehaviorState {
         ctx.get_widget(self.cursor).set("expanded", len > 0);
     }
 
-    fn move_cursor_left(&mut self, ctx: &mut Context) {
-        if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index = 0;
-                selection.length = 0;
-            }
-        }
+    fn move_cursor_left(&mut self, ctx: &mut Context, word: bool) {
+        let selection = *ctx.widget().get::<TextSelection>("text_selection");
+
+        // the caret is placed at the start of the selection
+        let index = if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            selection.start_index
+        } else {
+            prev_boundary(
+                ctx.widget().get::<String16>("text"),
+                selection.start_index,
+                word,
+            )
+        };
 
         if let Some(selection) = ctx
             .get_widget(self.cursor)
             .try_get_mut::<TextSelection>("text_selection")
         {
-            selection.start_index = (selection.start_index as i32 - 1).max(0) as usize;
+            selection.start_index = index;
             selection.length = 0;
         }
 
         ctx.get_widget(self.cursor).set("expanded", false);
     }
 
-    fn move_cursor_right(&mut self, ctx: &mut Context) {
-        let text_len = ctx.widget().get::<String16>("text").len();
-
-        if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index = text_len;
-                selection.length = 0;
-            }
+    fn move_cursor_right(&mut self, ctx: &mut Context, word: bool) {
+        let selection = *ctx.widget().get::<TextSelection>("text_selection");
 
-            ctx.get_widget(self.cursor).set("expanded", false);
-
-            return;
-        }
+        // the caret is placed at the end of the selection
+        let index = if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            selection.start_index + selection.length
+        } else {
+            next_boundary(
+                ctx.widget().get::<String16>("text"),
+                selection.start_index,
+                word,
+            )
+        };
 
         if let Some(selection) = ctx
             .get_widget(self.cursor)
             .try_get_mut::<TextSelection>("text_selection")
         {
-            if selection.start_index < text_len {
-                selection.start_index = (selection.start_index + 1).min(text_len);
-            }
+            selection.start_index = index;
             selection.length = 0;
         }
 
@@ -243,7 +516,7 @@ impl TextBehaviorState {
         ctx.get_widget(self.cursor).set("expanded", false);
     }
 
-    fn back_space(&mut self, ctx: &mut Context) {
+    fn back_space(&mut self, ctx: &mut Context, word: bool) {
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
             self.clear_selection(ctx);
         } else {
@@ -253,16 +526,17 @@ impl TextBehaviorState {
                 .start_index;
             if index > 0 {
                 let mut text = ctx.widget().clone::<String16>("text");
-                text.remove(index - 1);
+                let start = prev_boundary(&text, index, word);
+                text.remove_range(start, index);
                 ctx.get_widget(self.target).set("text", text);
                 ctx.widget()
                     .get_mut::<TextSelection>("text_selection")
-                    .start_index = index - 1;
+                    .start_index = start;
             }
         }
     }
 
-    fn delete(&mut self, ctx: &mut Context) {
+    fn delete(&mut self, ctx: &mut Context, word: bool) {
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
             self.clear_selection(ctx);
         } else {
@@ -272,7 +546,8 @@ impl TextBehaviorState {
                 .start_index;
             if index < ctx.widget().get::<String16>("text").len() {
                 let mut text = ctx.widget().clone::<String16>("text");
-                text.remove(index);
+                let end = next_boundary(&text, index, word);
+                text.remove_range(index, end);
                 ctx.get_widget(self.target).set("text", text);
 
                 ctx.widget()
@@ -294,39 +569,32 @@ impl TextBehaviorState {
         )
     }
 
-    fn insert_char(&mut self, key_event: KeyEvent, ctx: &mut Context) {
-        if key_event.text.is_empty() {
+    fn insert_text(&mut self, value: &str, ctx: &mut Context) {
+        if value.is_empty() {
             return;
         }
 
-        if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
-            ctx.get_widget(self.target)
-                .set("text", String16::from(key_event.text));
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index = 1;
-                selection.length = 0
-            }
-            ctx.get_widget(self.cursor).set("expanded", false);
-        } else {
-            let current_selection = *ctx
-                .get_widget(self.cursor)
-                .get::<TextSelection>("text_selection");
-
-            let mut text = ctx.widget().clone::<String16>("text");
-            text.insert_str(current_selection.start_index, key_event.text.as_str());
-            ctx.get_widget(self.target).set("text", text);
-
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index =
-                    current_selection.start_index + key_event.text.encode_utf16().count();
-            }
+        let mut selection = *ctx
+            .get_widget(self.cursor)
+            .get::<TextSelection>("text_selection");
+
+        if !*ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            selection.length = 0;
         }
+
+        let mut text = ctx.widget().clone::<String16>("text");
+        let index = replace_selection(&mut text, selection, value);
+        ctx.get_widget(self.target).set("text", text);
+
+        if let Some(selection) = ctx
+            .get_widget(self.cursor)
+            .try_get_mut::<TextSelection>("text_selection")
+        {
+            selection.start_index = index;
+            selection.length = 0;
+        }
+
+        ctx.get_widget(self.cursor).set("expanded", false);
     }
 }
 
@@ -334,8 +602,8 @@ impl State for TextBehaviorState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.cursor = Entity::from(
             ctx.widget()
-                .try_clone::<u32>("cursor")
-                .expect("TextBehaviorState.init: cursor could not be found."),
+                .try_clone::<u32>("text_cursor")
+                .expect("TextBehaviorState.init: text_cursor could not be found."),
         );
         self.target = Entity::from(
             ctx.widget()
@@ -353,7 +621,7 @@ impl State for TextBehaviorState {
         }
     }
 
-    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
         self.check_outside_update(ctx);
 
         let focused = *ctx.widget().get::<bool>("focused");
@@ -385,11 +653,18 @@ impl State for TextBehaviorState {
         if let Some(action) = self.action.clone() {
             match action {
                 TextAction::Key(event) => {
-                    self.handle_key_event(event, ctx);
+                    self.handle_key_event(event, registry, ctx);
                 }
                 TextAction::Mouse(p) => {
+                    self.history.break_group();
                     self.request_focus(ctx, p);
                 }
+                TextAction::Drag(p) => {
+                    self.drag(ctx, p);
+                }
+                TextAction::Composition(event) => {
+                    self.handle_composition(event, ctx);
+                }
             }
 
             self.action = None;
@@ -419,10 +694,16 @@ widget!(
     /// Attaching to a widget makes it able to handle text input like:
     /// * input characters by keyboard
     /// * select all text with Ctrl+A key combination
+    /// * select text with Shift+Left / Shift+Right or by dragging the mouse
+    /// * move the cursor and select word-wise with Ctrl+Left / Ctrl+Right, delete words with Ctrl+Backspace / Ctrl+Delete
+    /// * edit grapheme clusters as a whole, e.g. letters with combining marks and emojis
+    /// * copy, cut and paste the selected text with Ctrl+C, Ctrl+X and Ctrl+V
+    /// * undo and redo edits with Ctrl+Z and Ctrl+Y (or Ctrl+Shift+Z)
     /// * delete selected text with Backspace or Delete
     /// * move cursor by the left or right arrow keys or clicking with mouse
     /// * delete characters by pressing the Backspace or the Delete key
     /// * run on_activate() callback on pressing the Enter key
+    /// * compose text with an input method editor, the composition is shown underlined at the caret
     ///
     /// TextBehavior needs the following prerequisites to able to work:
     /// * a `cursor`: the [`Entity`] of a [`Cursor`] widget
@@ -470,7 +751,7 @@ widget!(
     ///            .build(ctx);
     ///
     ///        let text_behavior = TextBehavior::new()
-    ///            .cursor(cursor.0)
+    ///            .text_cursor(cursor.0)
     ///            .focused(id)
     ///            .font(id)
     ///            .font_size(id)
@@ -488,9 +769,9 @@ widget!(
     ///
     /// [`Entity`]: https://docs.rs/dces/0.2.0/dces/entity/struct.Entity.html
     /// [`Cursor`]: ../struct.Cursor.html
-    TextBehavior<TextBehaviorState>: ActivateHandler, KeyDownHandler {
+    TextBehavior<TextBehaviorState>: ActivateHandler, CompositionHandler, KeyDownHandler {
         /// Sets or shares the entity of the Cursor widget property.
-        cursor: u32,
+        text_cursor: u32,
 
         /// Sets or shares the focused property.
         focused: bool,
@@ -514,7 +795,13 @@ widget!(
         text: String16,
 
         /// Sets or shares the text selection property.
-        text_selection: TextSelection
+        text_selection: TextSelection,
+
+        /// Sets or shares the maximum number of undo steps.
+        history_depth: usize,
+
+        /// Sets or shares the composition string of an input method editor that is not committed yet.
+        composition: String16
     }
 );
 
@@ -525,6 +812,8 @@ impl Template for TextBehavior {
             .font("Roboto-Regular")
             .text("")
             .text_selection(TextSelection::default())
+            .history_depth(DEFAULT_HISTORY_DEPTH)
+            .composition("")
             .focused(false)
             .lost_focus_on_activation(true)
             .child(
@@ -532,11 +821,26 @@ impl Template for TextBehavior {
                     .visibility(id)
                     .enabled(id)
                     .on_mouse_down(move |states, m| {
+                        let state = states.get_mut::<TextBehaviorState>(id);
+                        state.action(TextAction::Mouse(m));
+                        state.dragging = m.button == MouseButton::Left;
+                        true
+                    })
+                    .on_mouse_move(move |states, p| {
+                        if !states.get::<TextBehaviorState>(id).dragging {
+                            return false;
+                        }
+
                         states
                             .get_mut::<TextBehaviorState>(id)
-                            .action(TextAction::Mouse(m));
+                            .action(TextAction::Drag(p));
                         true
                     })
+                    .on_global_mouse_up(move |states, _| {
+                        if states.get::<TextBehaviorState>(id).dragging {
+                            states.get_mut::<TextBehaviorState>(id).dragging = false;
+                        }
+                    })
                     .build(ctx),
             )
             .on_key_down(move |states, event| -> bool {
@@ -545,5 +849,100 @@ impl Template for TextBehavior {
                     .action(TextAction::Key(event));
                 false
             })
+            .on_composition(move |states, event| -> bool {
+                states
+                    .get_mut::<TextBehaviorState>(id)
+                    .action(TextAction::Composition(event));
+                false
+            })
+    }
+}
+
+// -- Helpers --
+
+// Returns the selection between the anchor and the caret index.
+fn selection_between(anchor: usize, caret: usize) -> TextSelection {
+    TextSelection {
+        start_index: anchor.min(caret),
+        length: (anchor as i64 - caret as i64).abs() as usize,
+    }
+}
+
+// Replaces the selected text with the given value and returns the caret index after the inserted value.
+fn replace_selection(text: &mut String16, selection: TextSelection, value: &str) -> usize {
+    text.remove_range(
+        selection.start_index,
+        selection.start_index + selection.length,
+    );
+    text.insert_str(selection.start_index, value);
+
+    selection.start_index + value.encode_utf16().count()
+}
+
+// Returns the caret index before the given index, the start of the previous grapheme cluster or word.
+fn prev_boundary(text: &String16, index: usize, word: bool) -> usize {
+    if word {
+        text.prev_word_boundary(index)
+    } else {
+        text.prev_grapheme_boundary(index)
+    }
+}
+
+// Returns the caret index after the given index, the end of the next grapheme cluster or word.
+fn next_boundary(text: &String16, index: usize, word: bool) -> usize {
+    if word {
+        text.next_word_boundary(index)
+    } else {
+        text.next_grapheme_boundary(index)
+    }
+}
+
+// Removes line breaks from pasted text, the text behavior handles only a single line.
+fn single_line(text: &str) -> String {
+    text.chars().filter(|c| *c != '\n' && *c != '\r').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_between() {
+        assert_eq!(selection_between(2, 5), TextSelection::from((2, 3)));
+        assert_eq!(selection_between(5, 2), TextSelection::from((2, 3)));
+        assert_eq!(selection_between(4, 4), TextSelection::from((4, 0)));
+    }
+
+    #[test]
+    fn test_replace_selection() {
+        let mut text = String16::from("hello world");
+        assert_eq!(
+            replace_selection(&mut text, TextSelection::from((6, 5)), "there"),
+            11
+        );
+        assert_eq!(text.to_string(), "hello there");
+
+        let mut text = String16::from("hello");
+        assert_eq!(
+            replace_selection(&mut text, TextSelection::from((5, 0)), "!"),
+            6
+        );
+        assert_eq!(text.to_string(), "hello!");
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(single_line("one\r\ntwo"), "onetwo");
+        assert_eq!(single_line("text"), "text");
+    }
+
+    #[test]
+    fn test_boundary() {
+        let text = String16::from("see\u{301} you");
+
+        assert_eq!(prev_boundary(&text, 4, false), 2);
+        assert_eq!(next_boundary(&text, 2, false), 4);
+        assert_eq!(prev_boundary(&text, 8, true), 5);
+        assert_eq!(next_boundary(&text, 0, true), 4);
     }
 }
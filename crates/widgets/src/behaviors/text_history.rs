@@ -0,0 +1,166 @@
+use crate::api::prelude::*;
+
+/// The default number of undo steps of a text input.
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+/// Describes the kind of a text edit. Consecutive edits of the same kind are grouped into one undo step.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextEditKind {
+    /// Characters are typed.
+    Insert,
+
+    /// Characters are removed by Backspace or Delete.
+    Delete,
+
+    /// Other edits like cut or paste. They are never grouped.
+    Other,
+}
+
+/// Stores the text and the selection of a text input before an edit.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSnapshot {
+    /// The text before the edit.
+    pub text: String,
+
+    /// The selection before the edit.
+    pub selection: TextSelection,
+}
+
+impl TextSnapshot {
+    /// Creates a new snapshot.
+    pub fn new(text: impl Into<String>, selection: TextSelection) -> Self {
+        TextSnapshot {
+            text: text.into(),
+            selection,
+        }
+    }
+}
+
+/// The `TextHistory` is the undo / redo stack of text input widgets.
+#[derive(Clone, Debug, Default)]
+pub struct TextHistory {
+    undo_stack: Vec<TextSnapshot>,
+    redo_stack: Vec<TextSnapshot>,
+    last_kind: Option<TextEditKind>,
+}
+
+impl TextHistory {
+    /// Creates a new empty history.
+    pub fn new() -> Self {
+        TextHistory::default()
+    }
+
+    /// Records the snapshot taken before an edit. If the edit continues a group of the same kind it is merged into
+    /// the previous undo step. The oldest steps are dropped if the history exceeds the given depth.
+    pub fn record(&mut self, snapshot: TextSnapshot, kind: TextEditKind, depth: usize) {
+        self.redo_stack.clear();
+
+        if kind != TextEditKind::Other && self.last_kind == Some(kind) {
+            return;
+        }
+
+        self.last_kind = Some(kind);
+        self.undo_stack.push(snapshot);
+
+        if self.undo_stack.len() > depth {
+            let overflow = self.undo_stack.len() - depth;
+            self.undo_stack.drain(0..overflow);
+        }
+    }
+
+    /// Ends the current group of edits, e.g. after the caret is moved.
+    pub fn break_group(&mut self) {
+        self.last_kind = None;
+    }
+
+    /// Removes all undo and redo steps.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_kind = None;
+    }
+
+    /// Returns the snapshot to restore for an undo. The current state is moved to the redo stack.
+    pub fn undo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.undo_stack.pop()?;
+        self.redo_stack.push(current);
+        self.last_kind = None;
+        Some(snapshot)
+    }
+
+    /// Returns the snapshot to restore for a redo. The current state is moved to the undo stack.
+    pub fn redo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.redo_stack.pop()?;
+        self.undo_stack.push(current);
+        self.last_kind = None;
+        Some(snapshot)
+    }
+
+    /// Returns `true` if there is a step to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is a step to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(text: &str) -> TextSnapshot {
+        TextSnapshot::new(text, TextSelection::from((text.len(), 0)))
+    }
+
+    #[test]
+    fn test_group() {
+        let mut history = TextHistory::new();
+
+        history.record(snapshot(""), TextEditKind::Insert, 10);
+        history.record(snapshot("a"), TextEditKind::Insert, 10);
+        history.record(snapshot("ab"), TextEditKind::Insert, 10);
+
+        assert_eq!(history.undo(snapshot("abc")), Some(snapshot("")));
+        assert!(!history.can_undo());
+
+        history.record(snapshot("abc"), TextEditKind::Insert, 10);
+        history.break_group();
+        history.record(snapshot("abcd"), TextEditKind::Insert, 10);
+        history.record(snapshot("abcde"), TextEditKind::Delete, 10);
+
+        assert_eq!(history.undo(snapshot("abcd")), Some(snapshot("abcde")));
+        assert_eq!(history.undo(snapshot("abcde")), Some(snapshot("abcd")));
+        assert_eq!(history.undo(snapshot("abcd")), Some(snapshot("abc")));
+    }
+
+    #[test]
+    fn test_redo() {
+        let mut history = TextHistory::new();
+
+        history.record(snapshot(""), TextEditKind::Other, 10);
+        assert_eq!(history.undo(snapshot("a")), Some(snapshot("")));
+        assert_eq!(history.redo(snapshot("")), Some(snapshot("a")));
+        assert!(!history.can_redo());
+
+        // a new edit clears the redo stack
+        history.undo(snapshot("a"));
+        history.record(snapshot(""), TextEditKind::Other, 10);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_depth() {
+        let mut history = TextHistory::new();
+
+        for text in &["", "a", "ab", "abc"] {
+            history.record(snapshot(text), TextEditKind::Other, 2);
+        }
+
+        assert_eq!(history.undo(snapshot("abcd")), Some(snapshot("abc")));
+        assert_eq!(history.undo(snapshot("abc")), Some(snapshot("ab")));
+        assert_eq!(history.undo(snapshot("ab")), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+/// The factor of a change if the Shift key is pressed.
+pub const SHIFT_FACTOR: f64 = 10.0;
+
+#[derive(Copy, Clone)]
+enum ValueChangedAction {
+    Scroll(f64),
+    Key(Key),
+}
+
+/// The `ValueChangedBehaviorState` changes the value of the `ValueChangedBehavior` widget by the mouse wheel and
+/// the Up and Down keys.
+#[derive(Default, AsAny)]
+pub struct ValueChangedBehaviorState {
+    actions: Vec<ValueChangedAction>,
+}
+
+impl ValueChangedBehaviorState {
+    fn action(&mut self, action: ValueChangedAction) {
+        self.actions.push(action);
+    }
+}
+
+impl State for ValueChangedBehaviorState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<ValueChangedAction> = self.actions.drain(..).collect();
+
+        if actions.is_empty() || !*ctx.widget().get::<bool>("enabled") {
+            return;
+        }
+
+        let focused = *ctx.widget().get::<bool>("focused");
+
+        for action in actions {
+            let direction = match action {
+                ValueChangedAction::Scroll(delta) if delta > 0.0 => 1.0,
+                ValueChangedAction::Scroll(delta) if delta < 0.0 => -1.0,
+                ValueChangedAction::Key(Key::Up) if focused => 1.0,
+                ValueChangedAction::Key(Key::Down) if focused => -1.0,
+                _ => continue,
+            };
+
+            let shift = ctx
+                .window()
+                .get::<Global>("global")
+                .keyboard_state
+                .is_shift_down();
+
+            let val = change_val(
+                *ctx.widget().get::<f64>("val"),
+                direction,
+                *ctx.widget().get::<f64>("step"),
+                shift,
+                *ctx.widget().get::<f64>("min"),
+                *ctx.widget().get::<f64>("max"),
+            );
+
+            ctx.widget().set("val", val);
+        }
+
+        // the target handles the shared value in its own state
+        let target: Entity = (*ctx.widget().get::<u32>("target")).into();
+        ctx.get_widget(target).update_dirty(true);
+    }
+}
+
+widget!(
+    /// The `ValueChangedBehavior` widget is used to change the value of a numeric widget by the mouse wheel and by
+    /// the Up and Down keys if the widget is focused. Each change adds or subtracts `step`, with the Shift key
+    /// pressed ten times the `step`. The value is kept inside of `min` and `max`.
+    ///
+    /// Share `val`, `min`, `max`, `step` and `focused` with the target.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ValueChangedBehavior::new()
+    ///     .target(id.0)
+    ///     .val(id)
+    ///     .min(id)
+    ///     .max(id)
+    ///     .step(id)
+    ///     .focused(id)
+    ///     .build(ctx)
+    /// ```
+    ValueChangedBehavior<ValueChangedBehaviorState>: KeyDownHandler, MouseHandler, ScrollHandler {
+        /// Sets or shares the target of the behavior.
+        target: u32,
+
+        /// Sets or shares the value.
+        val: f64,
+
+        /// Sets or shares the minimum of the value.
+        min: f64,
+
+        /// Sets or shares the maximum of the value.
+        max: f64,
+
+        /// Sets or shares the value that is added or subtracted by each change.
+        step: f64,
+
+        /// Sets or shares the focused property. The keys only change the value if it is `true`.
+        focused: bool
+    }
+);
+
+impl Template for ValueChangedBehavior {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("ValueChangedBehavior")
+            .val(0.0)
+            .min(0.0)
+            .max(100.0)
+            .step(1.0)
+            .focused(false)
+            .on_scroll(move |states, delta| {
+                states
+                    .get_mut::<ValueChangedBehaviorState>(id)
+                    .action(ValueChangedAction::Scroll(delta.lines.y()));
+                false
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<ValueChangedBehaviorState>(id)
+                    .action(ValueChangedAction::Key(event.key));
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+// Adds the step in the given direction to the value, with shift the step is multiplied by the `SHIFT_FACTOR`. The
+// result is kept inside of min and max.
+fn change_val(val: f64, direction: f64, step: f64, shift: bool, min: f64, max: f64) -> f64 {
+    let factor = if shift { SHIFT_FACTOR } else { 1.0 };
+
+    (val + direction * step * factor).max(min).min(max)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_change_val() {
+        assert!((6.0 - change_val(5.0, 1.0, 1.0, false, 0.0, 100.0)).abs() < ERROR);
+        assert!((4.0 - change_val(5.0, -1.0, 1.0, false, 0.0, 100.0)).abs() < ERROR);
+        assert!((15.0 - change_val(5.0, 1.0, 1.0, true, 0.0, 100.0)).abs() < ERROR);
+        assert!((0.0 - change_val(5.0, -1.0, 1.0, true, 0.0, 100.0)).abs() < ERROR);
+        assert!((100.0 - change_val(99.0, 1.0, 2.0, false, 0.0, 100.0)).abs() < ERROR);
+    }
+}
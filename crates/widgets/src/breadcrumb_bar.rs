@@ -0,0 +1,442 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_BREADCRUMB_BAR: &str = "breadcrumb_bar";
+pub static STYLE_BREADCRUMB_SEGMENT: &str = "breadcrumb_segment";
+static STYLE_BREADCRUMB_DROP_DOWN_ITEM: &str = "list_view_item";
+static ID_SEGMENTS: &str = "id_segments";
+static OVERFLOW_TEXT: &str = "...";
+
+// --- KEYS --
+
+// The overflow button has a fixed width, it could not be measured while it is collapsed.
+const OVERFLOW_WIDTH: f64 = 56.0;
+
+#[derive(Debug, Copy, Clone)]
+enum BreadcrumbAction {
+    Activate(usize),
+    ToggleDropDown,
+    CheckMouseUpOutside(Point),
+}
+
+/// The `BreadcrumbBarState` builds the segments of a `BreadcrumbBar` and collapses them into the drop-down if there
+/// is not enough space.
+#[derive(Default, AsAny)]
+pub struct BreadcrumbBarState {
+    actions: Vec<BreadcrumbAction>,
+    segments: Vec<String>,
+    items: Vec<Entity>,
+    widths: Vec<f64>,
+    collapsed: usize,
+    segments_panel: Entity,
+    overflow: Entity,
+    popup: Entity,
+    items_panel: Entity,
+}
+
+impl BreadcrumbBarState {
+    fn action(&mut self, action: BreadcrumbAction) {
+        self.actions.push(action);
+    }
+
+    /// Activates the segment with the given index, like a click on it.
+    pub fn activate(&mut self, index: usize) {
+        self.action(BreadcrumbAction::Activate(index));
+    }
+
+    // Rebuilds the segments if the path has changed.
+    fn update_segments(&mut self, ctx: &mut Context) {
+        if ctx.widget().get::<Vec<String>>("segments") == &self.segments {
+            return;
+        }
+
+        self.segments = ctx.widget().clone::<Vec<String>>("segments");
+        self.collapsed = 0;
+        self.widths = vec![0.0; self.segments.len()];
+
+        let entity = ctx.entity;
+        let segments_panel = self.segments_panel;
+        ctx.clear_children_of(segments_panel);
+        self.items.clear();
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let build_context = &mut ctx.build_context();
+            let item = segment_item(build_context, entity, index, segment);
+
+            build_context.append_child(segments_panel, item);
+            self.items.push(item);
+
+            // the overflow button is placed behind the root segment
+            if index == 0 {
+                self.overflow = overflow_item(build_context, entity);
+                build_context.append_child(segments_panel, self.overflow);
+            }
+        }
+
+        // places the drop-down below the overflow button
+        if !self.items.is_empty() {
+            ctx.get_widget(self.popup).set("target", self.overflow.0);
+        }
+
+        self.update_drop_down(ctx);
+    }
+
+    // Collapses the segments that do not fit into the available width.
+    fn update_overflow(&mut self, ctx: &mut Context) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        // the widths of collapsed segments are kept from the last layout they were visible
+        for (index, item) in self.items.iter().enumerate() {
+            if !self.is_collapsed(index) {
+                self.widths[index] = ctx.get_widget(*item).get::<Rectangle>("bounds").width();
+            }
+        }
+
+        let padding = *ctx.widget().get::<Thickness>("padding");
+        let available =
+            ctx.widget().get::<Rectangle>("bounds").width() - padding.left() - padding.right();
+        let spacing = *ctx.widget().get::<f64>("spacing");
+        let collapsed = collapsed_segments(&self.widths, spacing, OVERFLOW_WIDTH, available);
+
+        if collapsed == self.collapsed {
+            return;
+        }
+
+        self.collapsed = collapsed;
+        self.update_drop_down(ctx);
+    }
+
+    // Shows the visible segments and fills the drop-down with the collapsed ones.
+    fn update_drop_down(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+
+        ctx.clear_children_of(self.items_panel);
+
+        if self.collapsed == 0 {
+            ctx.widget().set("open", false);
+        }
+
+        if self.items.is_empty() {
+            return;
+        }
+
+        for (index, item) in self.items.iter().enumerate() {
+            let visibility = if self.is_collapsed(index) {
+                Visibility::Collapsed
+            } else {
+                Visibility::Visible
+            };
+
+            ctx.get_widget(*item).set("visibility", visibility);
+        }
+
+        ctx.get_widget(self.overflow).set(
+            "visibility",
+            if self.collapsed > 0 {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            },
+        );
+
+        for index in 1..=self.collapsed {
+            let build_context = &mut ctx.build_context();
+            let item = Button::new()
+                .style(STYLE_BREADCRUMB_DROP_DOWN_ITEM)
+                .text(self.segments[index].as_str())
+                .h_align("stretch")
+                .on_click(move |states, _| {
+                    states.get_mut::<BreadcrumbBarState>(entity).activate(index);
+                    true
+                })
+                .build(build_context);
+
+            build_context.append_child(self.items_panel, item);
+        }
+    }
+
+    fn is_collapsed(&self, index: usize) -> bool {
+        index > 0 && index <= self.collapsed
+    }
+
+    // Closes the drop-down on mouse up outside of the overflow button and the drop-down.
+    fn check_mouse_up_outside(&mut self, ctx: &mut Context, position: Point) {
+        if !*ctx.widget().get::<bool>("open") {
+            return;
+        }
+
+        let inside = [self.overflow, self.popup].iter().any(|entity| {
+            let widget = ctx.get_widget(*entity);
+            Rectangle::new(
+                widget.clone::<Point>("position"),
+                widget.get::<Rectangle>("bounds").size(),
+            )
+            .contains(position)
+        });
+
+        if !inside {
+            ctx.widget().set("open", false);
+        }
+    }
+}
+
+impl State for BreadcrumbBarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.segments_panel = ctx
+            .entity_of_child(ID_SEGMENTS)
+            .expect("BreadcrumbBarState.init: segments child could not be found.");
+        self.update_segments(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_segments(ctx);
+
+        let actions: Vec<BreadcrumbAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                BreadcrumbAction::Activate(index) => {
+                    if index >= self.segments.len() {
+                        continue;
+                    }
+
+                    ctx.widget().set("open", false);
+                    ctx.push_event_strategy_by_entity(
+                        SegmentActivatedEvent(ctx.entity, index),
+                        ctx.entity,
+                        EventStrategy::Direct,
+                    );
+                }
+                BreadcrumbAction::ToggleDropDown => {
+                    let open = !*ctx.widget().get::<bool>("open");
+                    ctx.widget().set("open", open);
+                }
+                BreadcrumbAction::CheckMouseUpOutside(position) => {
+                    self.check_mouse_up_outside(ctx, position)
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_overflow(ctx);
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.popup);
+    }
+}
+
+widget!(
+    /// The `BreadcrumbBar` shows a path as a row of clickable segments, e.g. the folders of a file manager. A click
+    /// on a segment raises the `SegmentActivatedEvent` with the index of the segment.
+    ///
+    /// If the segments do not fit into the bar, the segments after the root are collapsed into a drop-down that is
+    /// opened by the overflow button. The root and the last segment are always visible.
+    ///
+    /// **style:** `breadcrumb_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// BreadcrumbBar::new()
+    ///     .segments(vec!["home".to_string(), "user".to_string(), "documents".to_string()])
+    ///     .on_segment_activated(move |states, _, index| {
+    ///         states.get_mut::<MainViewState>(id).open_folder(index);
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    BreadcrumbBar<BreadcrumbBarState>: SegmentActivatedHandler, MouseHandler {
+        /// Sets or shares the segments of the path.
+        segments: Vec<String>,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the brush of the separators between the segments.
+        icon_brush: Brush,
+
+        /// Sets or share the size of the separators.
+        icon_size: f64,
+
+        /// Sets or shares the space between the segments and the separators.
+        spacing: f64,
+
+        /// Sets or shares the flag if the drop-down with the collapsed segments is open.
+        open: bool
+    }
+);
+
+impl Template for BreadcrumbBar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::new().orientation("vertical").build(ctx);
+
+        let popup = Popup::new()
+            .open(id)
+            .target(0)
+            .child(items_panel)
+            .build(ctx);
+
+        let _ = ctx.append_child_to_overlay(popup);
+
+        self.state_mut().items_panel = items_panel;
+        self.state_mut().popup = popup;
+
+        self.name("BreadcrumbBar")
+            .style(STYLE_BREADCRUMB_BAR)
+            .segments(Vec::<String>::new())
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(0.0)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .spacing(4.0)
+            .open(false)
+            .height(32.0)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::new()
+                            .id(ID_SEGMENTS)
+                            .orientation("horizontal")
+                            .v_align("center")
+                            .spacing(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_global_mouse_up(move |states, e| {
+                states
+                    .get_mut::<BreadcrumbBarState>(id)
+                    .action(BreadcrumbAction::CheckMouseUpOutside(e.position));
+            })
+    }
+}
+
+// --- Helpers --
+
+// Builds a segment with the separator in front, the root segment has no separator.
+fn segment_item(ctx: &mut BuildContext, bar: Entity, index: usize, text: &str) -> Entity {
+    let mut item = Stack::new()
+        .orientation("horizontal")
+        .spacing(("spacing", bar));
+
+    if index > 0 {
+        item = item.child(separator(ctx, bar));
+    }
+
+    item.child(
+        Button::new()
+            .style(STYLE_BREADCRUMB_SEGMENT)
+            .text(text)
+            .on_click(move |states, _| {
+                states.get_mut::<BreadcrumbBarState>(bar).activate(index);
+                true
+            })
+            .build(ctx),
+    )
+    .build(ctx)
+}
+
+// Builds the button that opens the drop-down with the collapsed segments.
+fn overflow_item(ctx: &mut BuildContext, bar: Entity) -> Entity {
+    let button = Button::new()
+        .style(STYLE_BREADCRUMB_SEGMENT)
+        .text(OVERFLOW_TEXT)
+        .on_click(move |states, _| {
+            states
+                .get_mut::<BreadcrumbBarState>(bar)
+                .action(BreadcrumbAction::ToggleDropDown);
+            true
+        })
+        .build(ctx);
+
+    Stack::new()
+        .orientation("horizontal")
+        .spacing(("spacing", bar))
+        .width(OVERFLOW_WIDTH)
+        .visibility(Visibility::Collapsed)
+        .child(separator(ctx, bar))
+        .child(button)
+        .build(ctx)
+}
+
+fn separator(ctx: &mut BuildContext, bar: Entity) -> Entity {
+    FontIconBlock::new()
+        .v_align("center")
+        .icon(material_icons_font::MD_CHEVRON_RIGHT)
+        .icon_brush(("icon_brush", bar))
+        .icon_size(("icon_size", bar))
+        .build(ctx)
+}
+
+// Returns the count of segments after the root that must be collapsed into the drop-down, so that the visible
+// segments and the overflow button fit into the available width. The root and the last segment are never collapsed.
+fn collapsed_segments(widths: &[f64], spacing: f64, overflow_width: f64, available: f64) -> usize {
+    if widths.len() < 3 {
+        return 0;
+    }
+
+    let mut width = widths.iter().sum::<f64>() + spacing * (widths.len() - 1) as f64;
+
+    if width <= available {
+        return 0;
+    }
+
+    width += overflow_width + spacing;
+
+    let mut collapsed = 0;
+
+    while collapsed < widths.len() - 2 && width > available {
+        width -= widths[collapsed + 1] + spacing;
+        collapsed += 1;
+    }
+
+    collapsed
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsed_segments() {
+        let widths = vec![40.0, 60.0, 60.0, 60.0, 80.0];
+
+        // all segments fit
+        assert_eq!(collapsed_segments(&widths, 4.0, 32.0, 316.0), 0);
+
+        // the overflow button takes the place of the first collapsed segment
+        assert_eq!(collapsed_segments(&widths, 4.0, 32.0, 300.0), 1);
+        assert_eq!(collapsed_segments(&widths, 4.0, 32.0, 200.0), 3);
+
+        // the root and the last segment stay visible
+        assert_eq!(collapsed_segments(&widths, 4.0, 32.0, 10.0), 3);
+        assert_eq!(collapsed_segments(&[40.0, 80.0], 4.0, 32.0, 10.0), 0);
+        assert_eq!(collapsed_segments(&[], 4.0, 32.0, 10.0), 0);
+    }
+}
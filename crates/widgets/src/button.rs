@@ -5,13 +5,16 @@ use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 widget!(
     /// The `Button` widget can be clicked by user. It's used to perform an action.
     ///
+    /// The character after an underscore of the text is the mnemonic of the button, e.g. `"_Save"`. It is underlined
+    /// while Alt is pressed and Alt+S clicks the button. Set `mnemonic` to `false` to display underscores as they are.
+    ///
     /// **style:** `button`
     Button: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -50,7 +53,20 @@ widget!(
         pressed: bool,
 
         /// Sets or shares the spacing between icon and text.
-        spacing: f64
+        spacing: f64,
+
+        /// Sets or shares the flag if the text contains a mnemonic.
+        mnemonic: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
     }
 );
 
@@ -58,6 +74,9 @@ impl Template for Button {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Button")
             .style("button")
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .height(36.0)
             .min_width(64.0)
             .background(colors::LYNCH_COLOR)
@@ -75,6 +94,7 @@ impl Template for Button {
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
             .spacing(8.0)
+            .mnemonic(true)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
@@ -111,6 +131,7 @@ impl Template for Button {
                                             .font_size(id)
                                             .font(id)
                                             .opacity(id)
+                                            .mnemonic(id)
                                             .build(ctx),
                                     )
                                     .build(ctx),
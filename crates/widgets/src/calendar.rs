@@ -0,0 +1,380 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_CALENDAR: &str = "calendar";
+pub static STYLE_CALENDAR_DAY: &str = "calendar_day";
+
+// --- KEYS --
+
+static MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+static WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// The count of the day cells of the month view (six weeks).
+const DAY_COUNT: usize = 42;
+
+#[derive(Copy, Clone)]
+enum CalendarAction {
+    Navigate(i32),
+    Select(usize),
+}
+
+/// The `CalendarState` handles the navigation and the day selection of the `Calendar` widget.
+#[derive(Default, AsAny)]
+pub struct CalendarState {
+    actions: Vec<CalendarAction>,
+    title: Entity,
+    days: Vec<Entity>,
+    selected_date: Option<Date>,
+    shown: Option<(Date, Date)>,
+}
+
+impl CalendarState {
+    fn action(&mut self, action: CalendarAction) {
+        self.actions.push(action);
+    }
+
+    fn handle_actions(&mut self, ctx: &mut Context) {
+        let actions: Vec<CalendarAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            let display_date = ctx.widget().clone::<Date>("display_date");
+
+            match action {
+                CalendarAction::Navigate(months) => {
+                    ctx.widget()
+                        .set("display_date", display_date.add_months(months));
+                }
+                CalendarAction::Select(index) => {
+                    let date = month_grid_start(display_date).add_days(index as i64);
+                    ctx.widget().set("selected_date", date);
+                }
+            }
+        }
+    }
+
+    // Shows the month of the selected date if the selected date has been changed.
+    fn follow_selected_date(&mut self, ctx: &mut Context) {
+        let selected_date = ctx.widget().clone::<Date>("selected_date");
+
+        if self.selected_date == Some(selected_date) {
+            return;
+        }
+
+        self.selected_date = Some(selected_date);
+
+        let display_date = ctx.widget().clone::<Date>("display_date");
+
+        if display_date.first_of_month() != selected_date.first_of_month() {
+            ctx.widget().set("display_date", selected_date);
+        }
+    }
+
+    fn update_days(&mut self, ctx: &mut Context) {
+        let display_date = ctx.widget().clone::<Date>("display_date");
+        let selected_date = ctx.widget().clone::<Date>("selected_date");
+
+        if self.shown == Some((display_date, selected_date)) {
+            return;
+        }
+
+        self.shown = Some((display_date, selected_date));
+
+        ctx.get_widget(self.title).set(
+            "text",
+            String16::from(format!(
+                "{} {}",
+                MONTH_NAMES[display_date.month() as usize - 1],
+                display_date.year()
+            )),
+        );
+
+        let start = month_grid_start(display_date);
+
+        for (index, day) in self.days.iter().enumerate() {
+            let date = start.add_days(index as i64);
+            let mut day = ctx.get_widget(*day);
+
+            day.set("text", String16::from(date.day().to_string()));
+
+            // days of the previous and next month are dimmed
+            day.set(
+                "opacity",
+                if date.month() == display_date.month() {
+                    1.0_f32
+                } else {
+                    0.5
+                },
+            );
+
+            {
+                let selector = day.get_mut::<Selector>("selector");
+
+                if date == selected_date {
+                    selector.set_state("selected");
+                } else {
+                    selector.clear_state();
+                }
+            }
+
+            day.update(false);
+        }
+    }
+}
+
+impl State for CalendarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // without an explicit display date the month of the selected date is shown
+        if *ctx.widget().get::<Date>("display_date") == Date::default() {
+            let selected_date = ctx.widget().clone::<Date>("selected_date");
+            ctx.widget().set("display_date", selected_date);
+        }
+
+        self.selected_date = Some(ctx.widget().clone::<Date>("selected_date"));
+        self.update_days(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.handle_actions(ctx);
+        self.follow_selected_date(ctx);
+        self.update_days(ctx);
+    }
+}
+
+widget!(
+    /// The `Calendar` shows the days of a month. The shown month could be changed by the navigation buttons of the
+    /// header, the outer buttons change the year. A click on a day selects it.
+    ///
+    /// **style:** `calendar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Calendar::new()
+    ///     .selected_date((2020, 6, 1))
+    ///     .on_changed("selected_date", |_, id| println!("Date of {:?} changed", id))
+    ///     .build(ctx)
+    /// ```
+    Calendar<CalendarState> {
+        /// Sets or shares the selected date.
+        selected_date: Date,
+
+        /// Sets or shares the date whose month is shown. If the selected date changes to another month, its month
+        /// is shown.
+        display_date: Date,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64
+    }
+);
+
+impl Calendar {
+    // Creates a header button that navigates by the given count of months.
+    fn navigation_button(
+        id: Entity,
+        ctx: &mut BuildContext,
+        icon: &str,
+        column: usize,
+        months: i32,
+    ) -> Entity {
+        Button::new()
+            .style("button_icon_only")
+            .attach(Grid::column(column))
+            .v_align("center")
+            .icon(icon)
+            .on_click(move |states, _| {
+                states
+                    .get_mut::<CalendarState>(id)
+                    .action(CalendarAction::Navigate(months));
+                false
+            })
+            .build(ctx)
+    }
+}
+
+impl Template for Calendar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let title = TextBlock::new()
+            .attach(Grid::column(2))
+            .v_align("center")
+            .h_align("center")
+            .foreground(id)
+            .font_size(id)
+            .build(ctx);
+        self.state_mut().title = title;
+
+        let header = Grid::new()
+            .columns(
+                Columns::create()
+                    .push(24.0)
+                    .push(24.0)
+                    .push("*")
+                    .push(24.0)
+                    .push(24.0),
+            )
+            .height(32.0)
+            .child(Calendar::navigation_button(
+                id,
+                ctx,
+                material_icons_font::MD_FIRST_PAGE,
+                0,
+                -12,
+            ))
+            .child(Calendar::navigation_button(
+                id,
+                ctx,
+                material_icons_font::MD_CHEVRON_LEFT,
+                1,
+                -1,
+            ))
+            .child(title)
+            .child(Calendar::navigation_button(
+                id,
+                ctx,
+                material_icons_font::MD_CHEVRON_RIGHT,
+                3,
+                1,
+            ))
+            .child(Calendar::navigation_button(
+                id,
+                ctx,
+                material_icons_font::MD_LAST_PAGE,
+                4,
+                12,
+            ))
+            .build(ctx);
+
+        let mut weekdays = Grid::new()
+            .columns(Columns::create().repeat(32.0, 7))
+            .height(24.0);
+
+        for (column, name) in WEEKDAY_NAMES.iter().enumerate() {
+            weekdays = weekdays.child(
+                TextBlock::new()
+                    .attach(Grid::column(column))
+                    .v_align("center")
+                    .h_align("center")
+                    .text(*name)
+                    .foreground(id)
+                    .font_size(id)
+                    .opacity(0.7)
+                    .build(ctx),
+            );
+        }
+
+        let mut days = Grid::new()
+            .columns(Columns::create().repeat(32.0, 7))
+            .rows(Rows::create().repeat(28.0, 6));
+
+        for index in 0..DAY_COUNT {
+            let day = Button::new()
+                .focusable(false)
+                .style(STYLE_CALENDAR_DAY)
+                .attach(Grid::column(index % 7))
+                .attach(Grid::row(index / 7))
+                .min_width(0.0)
+                .text("")
+                .on_click(move |states, _| {
+                    states
+                        .get_mut::<CalendarState>(id)
+                        .action(CalendarAction::Select(index));
+                    false
+                })
+                .build(ctx);
+
+            self.state_mut().days.push(day);
+            days = days.child(day);
+        }
+
+        self.name("Calendar")
+            .style(STYLE_CALENDAR)
+            .selected_date(Date::default())
+            .display_date(Date::default())
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(4.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::new()
+                            .child(header)
+                            .child(weekdays.build(ctx))
+                            .child(days.build(ctx))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Returns the first date of the month view, the monday of the week that contains the first day of the month.
+fn month_grid_start(date: Date) -> Date {
+    let first = date.first_of_month();
+    first.add_days(-i64::from(first.weekday()))
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_grid_start() {
+        assert_eq!(
+            month_grid_start(Date::new(2020, 6, 15)),
+            Date::new(2020, 6, 1)
+        );
+        assert_eq!(
+            month_grid_start(Date::new(2020, 7, 31)),
+            Date::new(2020, 6, 29)
+        );
+        assert_eq!(
+            month_grid_start(Date::new(2020, 3, 1)),
+            Date::new(2020, 2, 24)
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `CanvasPanel` places its children on explicit positions. The position of a child is set by the attached
+    /// `x` and `y` properties relative to the top left corner of the panel, the children keep their desired size.
+    /// Overlapping children are stacked by their `z_index`: children with a greater `z_index` are drawn above the
+    /// others and receive mouse events first.
+    ///
+    /// **style:** `canvas_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// CanvasPanel::new()
+    ///     .child(
+    ///         Button::new()
+    ///             .text("Click me")
+    ///             .attach(CanvasPanel::x(20.0))
+    ///             .attach(CanvasPanel::y(40.0))
+    ///             .z_index(1)
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    CanvasPanel {
+        /// Sets or shares the background property.
+        background: Brush
+
+        attached_properties: {
+            /// Attach the horizontal position to a widget.
+            x: f64,
+
+            /// Attach the vertical position to a widget.
+            y: f64
+        }
+    }
+);
+
+impl Template for CanvasPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("CanvasPanel")
+            .style("canvas_panel")
+            .background("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(CanvasLayout::new())
+    }
+}
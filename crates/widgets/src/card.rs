@@ -0,0 +1,121 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+// --- KEYS --
+
+pub static STYLE_CARD: &str = "card";
+
+// --- KEYS --
+
+/// The `CardState` handles the hover of a `Card`.
+#[derive(Default, AsAny)]
+pub struct CardState {
+    hovered: bool,
+}
+
+impl CardState {
+    // Raises the card to its hover elevation while the mouse is over it.
+    fn update_hover(&mut self, ctx: &mut Context) {
+        let mouse_position = ctx.window().get::<Global>("global").mouse_position;
+        let hovered = *ctx.widget().get::<bool>("enabled")
+            && check_mouse_condition(mouse_position, &ctx.widget());
+
+        // the card gets no mouse move if the mouse leaves it, therefore it checks the hover on the next frame again
+        if hovered {
+            ctx.request_next_frame();
+        }
+
+        if hovered == self.hovered {
+            return;
+        }
+
+        self.hovered = hovered;
+
+        ctx.widget().set("hover", hovered);
+        toggle_flag("hover", &mut ctx.widget());
+        ctx.widget().update(false);
+    }
+}
+
+impl State for CardState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_hover(ctx);
+    }
+}
+
+widget!(
+    /// The `Card` widget is a `Container` with rounded corners that floats above its parent. The height is defined
+    /// by the `elevation`, it is drawn as soft drop shadow. On hover the card is raised to the `hover_elevation`, set
+    /// it to the `elevation` to disable the effect.
+    ///
+    /// **style:** `card`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Card::new()
+    ///     .elevation(2.0)
+    ///     .hover_elevation(8.0)
+    ///     .child(TextBlock::new().text("Content").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Card<CardState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the elevation, it defines the offset and the blur of the shadow.
+        elevation: f64,
+
+        /// Sets or shares the elevation while the mouse is over the card.
+        hover_elevation: f64,
+
+        /// Sets or shares the brush of the shadow.
+        shadow_brush: Brush,
+
+        /// Sets or shares an additional drop shadow of the box, e.g. `"0px 2px 8px 0px #66000000"`.
+        box_shadow: BoxShadow,
+
+        /// Gets the flag if the mouse is over the card.
+        hover: bool
+    }
+);
+
+impl Template for Card {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("Card")
+            .style(STYLE_CARD)
+            .padding(16.0)
+            .background("#444e55")
+            .border_radius(8.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .elevation(2.0)
+            .hover_elevation(8.0)
+            .shadow_brush("#66000000")
+            .hover(false)
+            .on_mouse_move(move |states, _| {
+                // marks the card as dirty to update the hover
+                states.get_mut::<CardState>(id);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(CardRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
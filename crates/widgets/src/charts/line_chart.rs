@@ -0,0 +1,94 @@
+use super::ChartState;
+
+use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_LINE_CHART: &str = "line_chart";
+
+// --- KEYS --
+
+widget!(
+    /// The `LineChart` widget draws each series of its `data` as a line through the values of the categories. The
+    /// value axis is scaled to the values automatically. The value of the data point under the mouse is shown in a
+    /// tool tip.
+    ///
+    /// Series without a brush are drawn with the colors of the `CHART_PALETTE`, a legend shows the names of the
+    /// series.
+    ///
+    /// **style:** `line_chart`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// LineChart::new()
+    ///     .data(
+    ///         ChartData::new()
+    ///             .labels(vec!["Jan", "Feb", "Mar"])
+    ///             .series(DataSeries::new("Income", vec![12.0, 18.5, 9.0]))
+    ///             .series(DataSeries::new("Costs", vec![8.0, 11.0, 10.5])),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    LineChart<ChartState>: MouseHandler {
+        /// Sets or shares the data of the chart.
+        data: ChartData,
+
+        /// Sets or shares the foreground of the labels and the tool tip.
+        foreground: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the brush of the grid lines.
+        border_brush: Brush,
+
+        /// Sets or shares the background of the tool tip.
+        tooltip_background: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Gets the tool tip text of the data point under the mouse, it is empty if no point is hovered.
+        hover_text: String,
+
+        /// Gets the position of the hovered data point relative to the chart.
+        hover_position: Point
+    }
+);
+
+impl Template for LineChart {
+    fn template(mut self, id: Entity, _: &mut BuildContext) -> Self {
+        self.state_mut().kind = ChartKind::Line;
+
+        self.name("LineChart")
+            .style(STYLE_LINE_CHART)
+            .data(ChartData::default())
+            .foreground(colors::LINK_WATER_COLOR)
+            .background("transparent")
+            .border_brush(colors::BOMBAY_COLOR)
+            .tooltip_background(colors::LYNCH_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .padding(8.0)
+            .hover_text("")
+            .hover_position(0.0)
+            .min_width(128.0)
+            .min_height(96.0)
+            .on_mouse_move(move |states, _| {
+                // marks the chart as dirty to update the tool tip
+                states.get_mut::<ChartState>(id);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(LineChartRenderObject)
+    }
+}
@@ -0,0 +1,83 @@
+//! Chart widgets draw the values of `ChartData` as lines, bars or slices of a pie. The value axis of the line and
+//! bar charts is scaled to the data automatically and the value of the data point under the mouse is shown in a
+//! tool tip.
+
+use crate::{api::prelude::*, proc_macros::*};
+
+pub use self::bar_chart::*;
+pub use self::line_chart::*;
+pub use self::pie_chart::*;
+
+mod bar_chart;
+mod line_chart;
+mod pie_chart;
+
+/// The `ChartState` handles the hover tool tip of the `LineChart`, `BarChart` and `PieChart` widgets.
+#[derive(Default, AsAny)]
+pub struct ChartState {
+    kind: ChartKind,
+    hit: Option<ChartHit>,
+}
+
+impl ChartState {
+    // Sets the `hover_text` and the `hover_position` of the data point under the mouse.
+    fn update_hover(&mut self, ctx: &mut Context) {
+        let mouse_position = ctx.window().get::<Global>("global").mouse_position;
+        let (hovered, position, bounds, padding, data, font, font_size) = {
+            let widget = ctx.widget();
+            (
+                check_mouse_condition(mouse_position, &widget),
+                *widget.get::<Point>("position"),
+                *widget.get::<Rectangle>("bounds"),
+                widget.clone_or_default::<Thickness>("padding"),
+                widget.clone_or_default::<ChartData>("data"),
+                widget.clone_or_default::<String>("font"),
+                widget.clone_or_default::<f64>("font_size"),
+            )
+        };
+
+        let hit = if hovered {
+            let layout = chart_layout(
+                ctx.render_context_2_d(),
+                self.kind,
+                &data,
+                (bounds.width(), bounds.height()),
+                padding,
+                font.as_str(),
+                font_size,
+            );
+
+            chart_hit(
+                self.kind,
+                &data,
+                &layout,
+                Point::new(
+                    mouse_position.x() - position.x(),
+                    mouse_position.y() - position.y(),
+                ),
+            )
+        } else {
+            None
+        };
+
+        if hit == self.hit {
+            return;
+        }
+
+        self.hit = hit;
+
+        match hit {
+            Some(hit) => {
+                ctx.widget().set("hover_text", chart_hit_text(&data, &hit));
+                ctx.widget().set("hover_position", hit.position);
+            }
+            None => ctx.widget().set("hover_text", String::new()),
+        }
+    }
+}
+
+impl State for ChartState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_hover(ctx);
+    }
+}
@@ -0,0 +1,84 @@
+use super::ChartState;
+
+use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_PIE_CHART: &str = "pie_chart";
+
+// --- KEYS --
+
+widget!(
+    /// The `PieChart` widget draws the values of the first series of its `data` as slices of a circle. The slices
+    /// start at the top and run clockwise, values that are not positive are skipped. The value of the slice under the
+    /// mouse is shown in a tool tip.
+    ///
+    /// The slices are drawn with the colors of the `CHART_PALETTE`, a legend shows the labels of the categories.
+    ///
+    /// **style:** `pie_chart`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// PieChart::new()
+    ///     .data(vec![("Rust", 60.0), ("C++", 25.0), ("Go", 15.0)])
+    ///     .build(ctx)
+    /// ```
+    PieChart<ChartState>: MouseHandler {
+        /// Sets or shares the data of the chart.
+        data: ChartData,
+
+        /// Sets or shares the foreground of the labels and the tool tip.
+        foreground: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the background of the tool tip.
+        tooltip_background: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Gets the tool tip text of the slice under the mouse, it is empty if no slice is hovered.
+        hover_text: String,
+
+        /// Gets the position of the mouse over the hovered slice relative to the chart.
+        hover_position: Point
+    }
+);
+
+impl Template for PieChart {
+    fn template(mut self, id: Entity, _: &mut BuildContext) -> Self {
+        self.state_mut().kind = ChartKind::Pie;
+
+        self.name("PieChart")
+            .style(STYLE_PIE_CHART)
+            .data(ChartData::default())
+            .foreground(colors::LINK_WATER_COLOR)
+            .background("transparent")
+            .tooltip_background(colors::LYNCH_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .padding(8.0)
+            .hover_text("")
+            .hover_position(0.0)
+            .min_width(128.0)
+            .min_height(96.0)
+            .on_mouse_move(move |states, _| {
+                // marks the chart as dirty to update the tool tip
+                states.get_mut::<ChartState>(id);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(PieChartRenderObject)
+    }
+}
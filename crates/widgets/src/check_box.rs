@@ -1,16 +1,126 @@
-use super::behaviors::{MouseBehavior, SelectionBehavior};
+use super::behaviors::MouseBehavior;
 use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 
+// --- KEYS --
+static ID_ICON: &str = "CHECK_BOX_ICON";
+// --- KEYS --
+
+/// The `CheckBoxState` handles the selected and indeterminate state of the `CheckBox` widget.
+#[derive(Default, AsAny)]
+pub struct CheckBoxState {
+    toggle: bool,
+    selected: bool,
+    indeterminate: bool,
+    icon: Entity,
+}
+
+impl CheckBoxState {
+    fn toggle(&mut self) {
+        self.toggle = true;
+    }
+
+    // Updates the selector and the icon of the check box.
+    fn update_visual(&mut self, ctx: &mut Context) {
+        let icon = if self.indeterminate {
+            ctx.widget().clone::<String>("indeterminate_icon")
+        } else {
+            ctx.widget().clone::<String>("icon")
+        };
+
+        ctx.get_widget(self.icon).set("icon", icon);
+
+        {
+            let mut widget = ctx.widget();
+            let selector = widget.get_mut::<Selector>("selector");
+
+            if self.indeterminate {
+                selector.set_state("indeterminate");
+            } else if self.selected {
+                selector.set_state("selected");
+            } else {
+                selector.clear_state();
+            }
+        }
+
+        ctx.widget().update(false);
+    }
+}
+
+impl State for CheckBoxState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.icon = ctx
+            .entity_of_child(ID_ICON)
+            .expect("CheckBoxState.init: Icon child could not be found.");
+
+        self.indeterminate = *ctx.widget().get::<bool>("indeterminate");
+        self.selected = *ctx.widget().get::<bool>("selected") && !self.indeterminate;
+
+        if *ctx.widget().get::<bool>("selected") != self.selected {
+            ctx.widget().set("selected", self.selected);
+        }
+
+        self.update_visual(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let mut selected = *ctx.widget().get::<bool>("selected");
+        let mut indeterminate = *ctx.widget().get::<bool>("indeterminate");
+
+        if self.toggle {
+            self.toggle = false;
+
+            if *ctx.widget().get::<bool>("enabled") {
+                let cycle = *ctx.widget().get::<CheckCycle>("check_cycle");
+                let next = cycle.next(selected, indeterminate);
+                selected = next.0;
+                indeterminate = next.1;
+            }
+        } else if indeterminate && !self.indeterminate {
+            // an indeterminate check box is not selected
+            selected = false;
+        } else if selected && !self.selected {
+            indeterminate = false;
+        }
+
+        if selected != *ctx.widget().get::<bool>("selected") {
+            ctx.widget().set("selected", selected);
+        }
+
+        if indeterminate != *ctx.widget().get::<bool>("indeterminate") {
+            ctx.widget().set("indeterminate", indeterminate);
+        }
+
+        self.selected = selected;
+        self.indeterminate = indeterminate;
+        self.update_visual(ctx);
+    }
+}
+
 widget!(
     /// The `CheckBox` widget can be switch its selected state. It contains a selection box and a text.
     ///
+    /// Besides selected and not selected a check box could be indeterminate, e.g. for a "select all" check box of
+    /// partly selected items. An indeterminate check box is not selected. The `check_cycle` property describes in
+    /// which order a click cycles through the states, by default a click switches only between not selected and
+    /// selected.
+    ///
     /// **style:** `check-box`
-    CheckBox: MouseHandler {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// CheckBox::new()
+    ///     .text("Select all")
+    ///     .indeterminate(true)
+    ///     .check_cycle("checked_indeterminate")
+    ///     .build(ctx)
+    /// ```
+    CheckBox<CheckBoxState>: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -48,8 +158,27 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the icon that is shown if the check box is indeterminate.
+        indeterminate_icon: String,
+
         /// Sets or shares the selected property.
-        selected: bool
+        selected: bool,
+
+        /// Sets or shares the indeterminate property.
+        indeterminate: bool,
+
+        /// Sets or shares the order in which a click cycles through the states.
+        check_cycle: CheckCycle,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
     }
 );
 
@@ -57,7 +186,12 @@ impl Template for CheckBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("CheckBox")
             .style("check_box")
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .selected(false)
+            .indeterminate(false)
+            .check_cycle("two_state")
             .height(24.0)
             .background(colors::LYNCH_COLOR)
             .border_radius(2.0)
@@ -69,58 +203,56 @@ impl Template for CheckBox {
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
             .icon(material_icons_font::MD_CHECK)
+            .indeterminate_icon(material_icons_font::MD_REMOVE)
             .icon_font("MaterialIcons-Regular")
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .on_click(move |states, _| {
+                states.get_mut::<CheckBoxState>(id).toggle();
+                false
+            })
             .child(
                 MouseBehavior::new()
                     .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
-                        SelectionBehavior::new()
-                            .selected(id)
-                            .enabled(id)
-                            .target(id.0)
+                        Stack::new()
+                            .orientation("horizontal")
+                            .spacing(8.0)
                             .child(
-                                Stack::new()
-                                    .orientation("horizontal")
-                                    .spacing(8.0)
-                                    .child(
-                                        Container::new()
-                                            .size(24.0, 24.0)
-                                            .background(id)
-                                            .border_radius(id)
-                                            .border_width(id)
-                                            .border_brush(id)
-                                            .padding(id)
-                                            .opacity(id)
-                                            .child(
-                                                FontIconBlock::new()
-                                                    .v_align("center")
-                                                    .h_align("center")
-                                                    .icon(id)
-                                                    .icon_brush(id)
-                                                    .icon_size(id)
-                                                    .icon_font(id)
-                                                    .opacity(id)
-                                                    .build(ctx),
-                                            )
-                                            .build(ctx),
-                                    )
+                                Container::new()
+                                    .size(24.0, 24.0)
+                                    .background(id)
+                                    .border_radius(id)
+                                    .border_width(id)
+                                    .border_brush(id)
+                                    .padding(id)
+                                    .opacity(id)
                                     .child(
-                                        TextBlock::new()
+                                        FontIconBlock::new()
+                                            .id(ID_ICON)
                                             .v_align("center")
-                                            .foreground(id)
-                                            .text(id)
-                                            .font_size(id)
-                                            .font(id)
+                                            .h_align("center")
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
                                             .opacity(id)
                                             .build(ctx),
                                     )
                                     .build(ctx),
                             )
+                            .child(
+                                TextBlock::new()
+                                    .v_align("center")
+                                    .foreground(id)
+                                    .text(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .opacity(id)
+                                    .build(ctx),
+                            )
                             .build(ctx),
                     )
                     .build(ctx),
@@ -0,0 +1,237 @@
+use super::behaviors::MouseBehavior;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_CHIP: &str = "chip";
+static ID_DELETE: &str = "id_delete";
+
+// --- KEYS --
+
+/// The `ChipState` handles the selection and the delete button of a `Chip`.
+#[derive(Default, AsAny)]
+pub struct ChipState {
+    toggle_selection: bool,
+    delete: bool,
+    selected: bool,
+}
+
+impl ChipState {
+    fn toggle_selection(&mut self) {
+        self.toggle_selection = true;
+    }
+
+    fn delete(&mut self) {
+        self.delete = true;
+    }
+
+    fn update_delete_button(&self, ctx: &mut Context) {
+        let visibility = if *chip(ctx.widget()).deletable() {
+            Visibility::Visible
+        } else {
+            Visibility::Collapsed
+        };
+
+        ctx.child(ID_DELETE).set("visibility", visibility);
+    }
+}
+
+impl State for ChipState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.selected = *chip(ctx.widget()).selected();
+        self.update_delete_button(ctx);
+        toggle_flag("selected", &mut ctx.widget());
+        ctx.widget().update(false);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_delete_button(ctx);
+
+        if self.delete {
+            self.delete = false;
+            ctx.push_event_strategy_by_entity(
+                DeleteEvent(ctx.entity),
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+        }
+
+        if self.toggle_selection {
+            self.toggle_selection = false;
+
+            if *chip(ctx.widget()).enabled() && *chip(ctx.widget()).selectable() {
+                let selected = *chip(ctx.widget()).selected();
+                chip(ctx.widget()).set_selected(!selected);
+            }
+        }
+
+        if self.selected == *chip(ctx.widget()).selected() {
+            return;
+        }
+
+        self.selected = *chip(ctx.widget()).selected();
+        toggle_flag("selected", &mut ctx.widget());
+        ctx.widget().update(false);
+    }
+}
+
+widget!(
+    /// The `Chip` widget is a small tag with a text and an optional icon, e.g. to show the active filters of a list.
+    /// If `selectable` is `true` a click toggles its `selected` state, if `deletable` is `true` it shows a delete
+    /// button that raises the `on_delete` event.
+    ///
+    /// **style:** `chip`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Chip::new()
+    ///     .text("Rust")
+    ///     .deletable(true)
+    ///     .on_delete(move |states, _| {
+    ///         states.get_mut::<MainViewState>(id).remove_filter("Rust");
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    Chip<ChipState>: MouseHandler, DeleteHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon property.
+        icon: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or shares the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the flag if a click toggles the selected property.
+        selectable: bool,
+
+        /// Sets or shares the flag if the delete button is shown.
+        deletable: bool,
+
+        /// Sets or shares the spacing between icon, text and delete button.
+        spacing: f64
+    }
+);
+
+impl Template for Chip {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("Chip")
+            .style(STYLE_CHIP)
+            .height(28.0)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(14.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((12.0, 0.0, 6.0, 0.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon("")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .pressed(false)
+            .selected(false)
+            .selectable(true)
+            .deletable(false)
+            .spacing(4.0)
+            .on_click(move |states, _| {
+                states.get_mut::<ChipState>(id).toggle_selection();
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Container::new()
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .spacing(id)
+                                    .v_align("center")
+                                    .child(
+                                        FontIconBlock::new()
+                                            .v_align("center")
+                                            .icon(id)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .v_align("center")
+                                            .foreground(id)
+                                            .text(id)
+                                            .font_size(id)
+                                            .font(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        // returns true, so the click does not toggle the chip
+                                        Button::new()
+                                            .id(ID_DELETE)
+                                            .style("button_icon_only")
+                                            .v_align("center")
+                                            .icon(material_icons_font::MD_CLOSE)
+                                            .icon_brush(("foreground", id))
+                                            .on_click(move |states, _| {
+                                                states.get_mut::<ChipState>(id).delete();
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
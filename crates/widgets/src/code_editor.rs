@@ -0,0 +1,750 @@
+use super::behaviors::{TextEditKind, TextHistory, TextSnapshot, DEFAULT_HISTORY_DEPTH};
+
+use crate::{
+    api::prelude::*,
+    proc_macros::*,
+    shell::prelude::{Clipboard, Key, KeyEvent, MouseButton},
+    theme::prelude::*,
+};
+
+// --- KEYS --
+
+pub static STYLE_CODE_EDITOR: &str = "code_editor";
+
+// --- KEYS --
+
+/// The distance in pixels that is scrolled for one unit of the mouse wheel.
+const SCROLL_SPEED: f64 = 16.0;
+
+#[derive(Clone)]
+enum CodeEditorAction {
+    Press(Point),
+    Drag(Point),
+    Release,
+    Scroll(Point),
+    Key(KeyEvent),
+}
+
+/// The `CodeEditorState` handles the editing, the selection, the scrolling and the syntax highlighting of the
+/// `CodeEditor` widget.
+#[derive(Default, AsAny)]
+pub struct CodeEditorState {
+    actions: Vec<CodeEditorAction>,
+    highlighter: Option<Box<dyn SyntaxHighlighter>>,
+    highlighted_text: Option<String>,
+    history: TextHistory,
+    anchor: usize,
+    column: Option<usize>,
+    dragging: bool,
+}
+
+impl CodeEditorState {
+    fn action(&mut self, action: CodeEditorAction) {
+        self.actions.push(action);
+    }
+
+    fn text(&self, ctx: &mut Context) -> Vec<char> {
+        ctx.widget()
+            .get::<String16>("text")
+            .as_string()
+            .chars()
+            .collect()
+    }
+
+    // Gets the start and the end of the selection.
+    fn selection(&self, ctx: &mut Context) -> (usize, usize) {
+        let selection = *ctx.widget().get::<TextSelection>("text_selection");
+        (
+            selection.start_index,
+            selection.start_index + selection.length,
+        )
+    }
+
+    // Selects the text between the anchor and the caret and scrolls the caret into view.
+    fn select(&mut self, ctx: &mut Context, anchor: usize, caret: usize) {
+        self.anchor = anchor;
+        ctx.widget().set("caret", caret);
+        ctx.widget().set(
+            "text_selection",
+            TextSelection::from((anchor.min(caret), anchor.max(caret) - anchor.min(caret))),
+        );
+        self.scroll_into_view(ctx, caret);
+    }
+
+    // Moves the caret, the selection is expanded if shift is pressed.
+    fn move_caret(&mut self, ctx: &mut Context, caret: usize, shift_down: bool) {
+        let anchor = if shift_down { self.anchor } else { caret };
+        self.history.break_group();
+        self.select(ctx, anchor, caret);
+    }
+
+    // Moves the caret by the given number of lines and keeps its column.
+    fn move_lines(&mut self, ctx: &mut Context, lines: isize, shift_down: bool) {
+        let text = self.text(ctx);
+        let caret = *ctx.widget().get::<usize>("caret");
+        let (line, column) = line_column(&text, caret);
+        let column = *self.column.get_or_insert(column);
+        let line = (line as isize + lines).max(0) as usize;
+
+        self.move_caret(ctx, index_of(&text, line, column), shift_down);
+        self.column = Some(column);
+    }
+
+    // Replaces the selection by the given text.
+    fn insert(&mut self, ctx: &mut Context, insert: &str, kind: TextEditKind) {
+        if *ctx.widget().get::<bool>("read_only") {
+            return;
+        }
+
+        let (start, end) = self.selection(ctx);
+        let mut text = self.text(ctx);
+
+        if start == end && insert.is_empty() {
+            return;
+        }
+
+        let depth = *ctx.widget().get::<usize>("history_depth");
+        self.history.record(self.snapshot(ctx), kind, depth);
+
+        let end = end.min(text.len());
+        let start = start.min(end);
+        text.splice(start..end, insert.chars());
+
+        let caret = start + insert.chars().count();
+        ctx.widget()
+            .set("text", String16::from(text.iter().collect::<String>()));
+        self.select(ctx, caret, caret);
+    }
+
+    // Removes the selection or the character before or after the caret.
+    fn delete(&mut self, ctx: &mut Context, forward: bool) {
+        let (start, end) = self.selection(ctx);
+
+        if start == end {
+            let len = self.text(ctx).len();
+
+            if forward && start < len {
+                ctx.widget()
+                    .set("text_selection", TextSelection::from((start, 1)));
+            } else if !forward && start > 0 {
+                ctx.widget()
+                    .set("text_selection", TextSelection::from((start - 1, 1)));
+            } else {
+                return;
+            }
+        }
+
+        self.insert(ctx, "", TextEditKind::Delete);
+    }
+
+    // Starts a new line with the indentation of the current line.
+    fn new_line(&mut self, ctx: &mut Context) {
+        let text = self.text(ctx);
+        let (start, _) = self.selection(ctx);
+        let (line_start, _) = line_bounds(&text, start);
+        let indentation: String = text[line_start..start.min(text.len())]
+            .iter()
+            .take_while(|c| **c == ' ' || **c == '\t')
+            .collect();
+
+        self.insert(ctx, &format!("\n{}", indentation), TextEditKind::Other);
+    }
+
+    // Inserts spaces up to the next tab stop.
+    fn tab(&mut self, ctx: &mut Context) {
+        let text = self.text(ctx);
+        let (start, _) = self.selection(ctx);
+        let (_, column) = line_column(&text, start);
+        let tab_size = (*ctx.widget().get::<usize>("tab_size")).max(1);
+
+        self.insert(
+            ctx,
+            &" ".repeat(tab_size - column % tab_size),
+            TextEditKind::Insert,
+        );
+    }
+
+    fn copy(&self, registry: &mut Registry, ctx: &mut Context) {
+        let (start, end) = self.selection(ctx);
+
+        if start == end {
+            return;
+        }
+
+        let text = self.text(ctx);
+        registry
+            .get::<Clipboard>("clipboard")
+            .set(text[start..end.min(text.len())].iter().collect::<String>());
+    }
+
+    fn paste(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if let Some(value) = registry.get::<Clipboard>("clipboard").get() {
+            self.insert(ctx, &value.replace("\r\n", "\n"), TextEditKind::Other);
+        }
+    }
+
+    fn snapshot(&self, ctx: &mut Context) -> TextSnapshot {
+        TextSnapshot::new(
+            ctx.widget().get::<String16>("text").as_string(),
+            *ctx.widget().get::<TextSelection>("text_selection"),
+        )
+    }
+
+    fn undo(&mut self, ctx: &mut Context, redo: bool) {
+        if *ctx.widget().get::<bool>("read_only") {
+            return;
+        }
+
+        let current = self.snapshot(ctx);
+        let snapshot = if redo {
+            self.history.redo(current)
+        } else {
+            self.history.undo(current)
+        };
+
+        if let Some(snapshot) = snapshot {
+            let selection = snapshot.selection;
+            ctx.widget().set("text", String16::from(snapshot.text));
+            self.select(
+                ctx,
+                selection.start_index,
+                selection.start_index + selection.length,
+            );
+        }
+    }
+
+    fn handle_key(&mut self, registry: &mut Registry, ctx: &mut Context, event: KeyEvent) {
+        if !*ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        let (ctrl_down, shift_down) = {
+            let window = ctx.window();
+            let keyboard_state = &window.get::<Global>("global").keyboard_state;
+            (
+                keyboard_state.is_ctrl_down(),
+                keyboard_state.is_shift_down(),
+            )
+        };
+
+        let text = self.text(ctx);
+        let caret = (*ctx.widget().get::<usize>("caret")).min(text.len());
+        let (line_start, line_end) = line_bounds(&text, caret);
+
+        if !matches!(event.key, Key::Up | Key::Down | Key::PageUp | Key::PageDown) {
+            self.column = None;
+        }
+
+        match event.key {
+            Key::Left => self.move_caret(ctx, caret.saturating_sub(1), shift_down),
+            Key::Right => self.move_caret(ctx, (caret + 1).min(text.len()), shift_down),
+            Key::Up => self.move_lines(ctx, -1, shift_down),
+            Key::Down => self.move_lines(ctx, 1, shift_down),
+            Key::PageUp => {
+                let page = self.page_lines(ctx);
+                self.move_lines(ctx, -page, shift_down)
+            }
+            Key::PageDown => {
+                let page = self.page_lines(ctx);
+                self.move_lines(ctx, page, shift_down)
+            }
+            Key::Home if ctrl_down => self.move_caret(ctx, 0, shift_down),
+            Key::End if ctrl_down => self.move_caret(ctx, text.len(), shift_down),
+            Key::Home => {
+                // toggles between the first non whitespace character and the start of the line
+                let indentation = text[line_start..line_end]
+                    .iter()
+                    .take_while(|c| c.is_whitespace())
+                    .count();
+                let home = if caret == line_start + indentation {
+                    line_start
+                } else {
+                    line_start + indentation
+                };
+                self.move_caret(ctx, home, shift_down)
+            }
+            Key::End => self.move_caret(ctx, line_end, shift_down),
+            Key::A(..) if ctrl_down => self.select(ctx, 0, text.len()),
+            Key::C(..) if ctrl_down => self.copy(registry, ctx),
+            Key::X(..) if ctrl_down => {
+                self.copy(registry, ctx);
+                self.insert(ctx, "", TextEditKind::Other);
+            }
+            Key::V(..) if ctrl_down => self.paste(registry, ctx),
+            Key::Z(..) if ctrl_down => self.undo(ctx, shift_down),
+            Key::Y(..) if ctrl_down => self.undo(ctx, true),
+            Key::Backspace => self.delete(ctx, false),
+            Key::Delete => self.delete(ctx, true),
+            Key::Enter => self.new_line(ctx),
+            _ if ctrl_down => {}
+            _ if event.text == "\t" => self.tab(ctx),
+            _ if !event.text.is_empty() && !event.text.chars().any(char::is_control) => {
+                self.insert(ctx, &event.text, TextEditKind::Insert)
+            }
+            _ => {}
+        }
+    }
+
+    // Gets the number of lines that fit into the widget.
+    fn page_lines(&self, ctx: &mut Context) -> isize {
+        let (style, height) = {
+            let widget = ctx.widget();
+            (
+                code_style(&widget),
+                widget.get::<Rectangle>("bounds").height(),
+            )
+        };
+        let layout = style.layout(ctx.render_context_2_d(), 1);
+
+        ((height / layout.line_height.max(1.0)) as isize - 1).max(1)
+    }
+
+    // Gets the index of the character boundary at the given window position.
+    fn index_at(&self, ctx: &mut Context, position: Point) -> usize {
+        let text = self.text(ctx);
+        let (style, widget_position) = {
+            let widget = ctx.widget();
+            (code_style(&widget), *widget.get::<Point>("position"))
+        };
+        let line_count = text.iter().filter(|c| **c == '\n').count() + 1;
+        let render_context_2_d = ctx.render_context_2_d();
+        let layout = style.layout(render_context_2_d, line_count);
+        let line = layout.line_at(position.y() - widget_position.y(), line_count);
+        let x = position.x() - widget_position.x() - layout.text_x;
+
+        let line_start = index_of(&text, line, 0);
+        let (_, line_end) = line_bounds(&text, line_start);
+        let mut previous_x = 0.0;
+
+        for index in line_start..line_end {
+            let prefix: String = text[line_start..=index].iter().collect();
+            let next_x = style.measure(render_context_2_d, &prefix);
+
+            if x < (previous_x + next_x) / 2.0 {
+                return index;
+            }
+
+            previous_x = next_x;
+        }
+
+        line_end
+    }
+
+    // Changes the scroll offset so that the caret is visible.
+    fn scroll_into_view(&mut self, ctx: &mut Context, caret: usize) {
+        let text = self.text(ctx);
+        let (style, bounds) = {
+            let widget = ctx.widget();
+            (code_style(&widget), *widget.get::<Rectangle>("bounds"))
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 {
+            return;
+        }
+
+        let (line, column) = line_column(&text, caret);
+        let line_count = text.iter().filter(|c| **c == '\n').count() + 1;
+        let render_context_2_d = ctx.render_context_2_d();
+        let layout = style.layout(render_context_2_d, line_count);
+        let (line_start, _) = line_bounds(&text, caret);
+        let prefix: String = text[line_start..line_start + column].iter().collect();
+        let caret_x = layout.text_x + style.measure(render_context_2_d, &prefix);
+        let caret_y = layout.line_y(line);
+
+        let mut offset = style.scroll_offset;
+        let left = layout.gutter_width + style.padding.left();
+        let right = bounds.width() - style.padding.right() - 1.0;
+        let bottom = bounds.height() - style.padding.bottom();
+
+        if caret_x < left {
+            offset.set_x(offset.x() - (left - caret_x));
+        } else if caret_x > right {
+            offset.set_x(offset.x() + caret_x - right);
+        }
+
+        if caret_y < style.padding.top() {
+            offset.set_y(offset.y() - (style.padding.top() - caret_y));
+        } else if caret_y + layout.line_height > bottom {
+            offset.set_y(offset.y() + caret_y + layout.line_height - bottom);
+        }
+
+        offset.set_x(offset.x().max(0.0));
+        offset.set_y(offset.y().max(0.0));
+        ctx.widget().set("scroll_offset", offset);
+    }
+
+    // Scrolls by the given mouse wheel delta, with shift the vertical wheel scrolls horizontally.
+    fn scroll(&mut self, ctx: &mut Context, delta: Point) {
+        let text = ctx.widget().get::<String16>("text").as_string();
+        let (style, bounds) = {
+            let widget = ctx.widget();
+            (code_style(&widget), *widget.get::<Rectangle>("bounds"))
+        };
+        let shift_down = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_shift_down();
+        let delta = if shift_down && delta.x() == 0.0 {
+            Point::new(delta.y(), 0.0)
+        } else {
+            delta
+        };
+
+        let lines = code_lines(&text);
+        let render_context_2_d = ctx.render_context_2_d();
+        let layout = style.layout(render_context_2_d, lines.len());
+        let content_width = lines
+            .iter()
+            .map(|(_, line)| style.measure(render_context_2_d, line))
+            .fold(0.0, f64::max)
+            + style.padding.left()
+            + style.padding.right();
+        let content_height =
+            lines.len() as f64 * layout.line_height + style.padding.top() + style.padding.bottom();
+
+        let max_x = (content_width - (bounds.width() - layout.gutter_width)).max(0.0);
+        let max_y = (content_height - bounds.height()).max(0.0);
+
+        let offset = Point::new(
+            (style.scroll_offset.x() - delta.x() * SCROLL_SPEED).clamp(0.0, max_x),
+            (style.scroll_offset.y() - delta.y() * SCROLL_SPEED).clamp(0.0, max_y),
+        );
+
+        ctx.widget().set("scroll_offset", offset);
+    }
+
+    // Highlights the text if it has been changed.
+    fn highlight(&mut self, ctx: &mut Context) {
+        let text = ctx.widget().get::<String16>("text").as_string();
+
+        if self.highlighted_text.as_ref() == Some(&text) {
+            return;
+        }
+
+        let len = text.chars().count();
+
+        // the text was changed from outside
+        if *ctx.widget().get::<usize>("caret") > len {
+            self.history.clear();
+            self.select(ctx, len, len);
+        }
+
+        let tokens = match &self.highlighter {
+            Some(highlighter) => highlighter.highlight(&text),
+            None => vec![],
+        };
+
+        ctx.widget().set("syntax_tokens", tokens);
+        self.highlighted_text = Some(text);
+    }
+}
+
+impl State for CodeEditorState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.highlight(ctx);
+    }
+
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        for action in std::mem::take(&mut self.actions) {
+            match action {
+                CodeEditorAction::Press(position) => {
+                    if !*ctx.widget().get::<bool>("focused") {
+                        ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+                    }
+
+                    let index = self.index_at(ctx, position);
+                    let shift_down = ctx
+                        .window()
+                        .get::<Global>("global")
+                        .keyboard_state
+                        .is_shift_down();
+                    self.column = None;
+                    self.dragging = true;
+                    self.move_caret(ctx, index, shift_down);
+                }
+                CodeEditorAction::Drag(position) => {
+                    let index = self.index_at(ctx, position);
+                    let anchor = self.anchor;
+                    self.select(ctx, anchor, index);
+                }
+                CodeEditorAction::Release => self.dragging = false,
+                CodeEditorAction::Scroll(delta) => self.scroll(ctx, delta),
+                CodeEditorAction::Key(event) => self.handle_key(registry, ctx, event),
+            }
+        }
+
+        self.highlight(ctx);
+    }
+}
+
+widget!(
+    /// The `CodeEditor` widget is a multi line text editor for source code with line numbers, syntax highlighting
+    /// and horizontal scrolling.
+    ///
+    /// The text is highlighted by a `SyntaxHighlighter` that is set with `syntax_highlighter`, e.g. a
+    /// `KeywordHighlighter` or a closure. Each `TokenKind` is drawn with its own brush property like
+    /// `keyword_brush` or `comment_brush`, the brushes could be set by the theme. The indices of `caret` and
+    /// `text_selection` count characters.
+    ///
+    /// Lines are not wrapped, long lines are scrolled horizontally with shift and the mouse wheel. The editor looks
+    /// best with a monospace font that is registered in the render context and set with `font`.
+    ///
+    /// **style:** `code_editor`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// CodeEditor::new()
+    ///     .text("fn main() {\n    println!(\"Hello\");\n}")
+    ///     .syntax_highlighter(KeywordHighlighter::rust())
+    ///     .build(ctx)
+    /// ```
+    CodeEditor<CodeEditorState>: MouseHandler, KeyDownHandler, ScrollHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the selected part of the text.
+        text_selection: TextSelection,
+
+        /// Sets or shares the character index of the caret.
+        caret: usize,
+
+        /// Sets or shares the highlighted parts of the text. It is set by the syntax highlighter.
+        syntax_tokens: Vec<SyntaxToken>,
+
+        /// Sets or shares the foreground of the plain text and the caret.
+        foreground: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the background of the line numbers.
+        gutter_background: Brush,
+
+        /// Sets or shares the foreground of the line numbers.
+        line_number_foreground: Brush,
+
+        /// Sets or shares the brush of the selected text.
+        selection_brush: Brush,
+
+        /// Sets or shares the brush of keywords.
+        keyword_brush: Brush,
+
+        /// Sets or shares the brush of types.
+        type_brush: Brush,
+
+        /// Sets or shares the brush of string literals.
+        string_brush: Brush,
+
+        /// Sets or shares the brush of number literals.
+        number_brush: Brush,
+
+        /// Sets or shares the brush of comments.
+        comment_brush: Brush,
+
+        /// Sets or shares the brush of operators.
+        operator_brush: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the flag if the line numbers are shown.
+        show_line_numbers: bool,
+
+        /// Sets or shares the number of spaces that are inserted by the tab key.
+        tab_size: usize,
+
+        /// Sets or shares the scroll offset of the text.
+        scroll_offset: Point,
+
+        /// Sets or shares the flag if the text could not be changed.
+        read_only: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
+        /// Sets or shares the maximum number of undo steps.
+        history_depth: usize
+    }
+);
+
+impl CodeEditor {
+    /// Sets the syntax highlighter that splits the text into highlighted tokens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// CodeEditor::new()
+    ///     .syntax_highlighter(|text: &str| {
+    ///         text.match_indices("TODO")
+    ///             .map(|(start, _)| SyntaxToken::new(TokenKind::Comment, start, start + 4))
+    ///             .collect()
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    pub fn syntax_highlighter(mut self, highlighter: impl SyntaxHighlighter + 'static) -> Self {
+        self.state_mut().highlighter = Some(Box::new(highlighter));
+        self
+    }
+}
+
+impl Template for CodeEditor {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("CodeEditor")
+            .style(STYLE_CODE_EDITOR)
+            .cursor(CursorIcon::Text)
+            .text("")
+            .text_selection(TextSelection::default())
+            .caret(0)
+            .syntax_tokens(vec![])
+            .foreground(colors::LINK_WATER_COLOR)
+            .background(colors::LYNCH_COLOR)
+            .gutter_background(colors::LYNCH_COLOR)
+            .line_number_foreground(colors::LINK_WATER_COLOR)
+            .selection_brush("transparent")
+            .keyword_brush(colors::LINK_WATER_COLOR)
+            .type_brush(colors::LINK_WATER_COLOR)
+            .string_brush(colors::LINK_WATER_COLOR)
+            .number_brush(colors::LINK_WATER_COLOR)
+            .comment_brush(colors::LINK_WATER_COLOR)
+            .operator_brush(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .padding(4.0)
+            .show_line_numbers(true)
+            .tab_size(4)
+            .scroll_offset(0.0)
+            .read_only(false)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
+            .history_depth(DEFAULT_HISTORY_DEPTH)
+            .clip(true)
+            .min_width(128.0)
+            .min_height(64.0)
+            .on_mouse_down(move |states, m| {
+                if m.button == MouseButton::Left {
+                    states
+                        .get_mut::<CodeEditorState>(id)
+                        .action(CodeEditorAction::Press(m.position));
+                }
+                true
+            })
+            .on_mouse_move(move |states, p| {
+                let state = states.get_mut::<CodeEditorState>(id);
+
+                if state.dragging {
+                    state.action(CodeEditorAction::Drag(p));
+                }
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                if states.get::<CodeEditorState>(id).dragging {
+                    states
+                        .get_mut::<CodeEditorState>(id)
+                        .action(CodeEditorAction::Release);
+                }
+            })
+            .on_scroll(move |states, delta| {
+                states
+                    .get_mut::<CodeEditorState>(id)
+                    .action(CodeEditorAction::Scroll(delta.pixels));
+                true
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<CodeEditorState>(id)
+                    .action(CodeEditorAction::Key(event));
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(CodeRenderObject)
+    }
+}
+
+// --- Helpers --
+
+// Gets the start and the end of the line that contains the character index.
+fn line_bounds(text: &[char], index: usize) -> (usize, usize) {
+    let index = index.min(text.len());
+    let start = text[..index]
+        .iter()
+        .rposition(|c| *c == '\n')
+        .map_or(0, |i| i + 1);
+    let end = text[index..]
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(text.len(), |i| index + i);
+
+    (start, end)
+}
+
+// Gets the line and the column of the character index.
+fn line_column(text: &[char], index: usize) -> (usize, usize) {
+    let index = index.min(text.len());
+    let line = text[..index].iter().filter(|c| **c == '\n').count();
+    let (start, _) = line_bounds(text, index);
+
+    (line, index - start)
+}
+
+// Gets the character index of the line and the column. Both are clamped to the text.
+fn index_of(text: &[char], line: usize, column: usize) -> usize {
+    let mut start = 0;
+
+    for _ in 0..line {
+        match text[start..].iter().position(|c| *c == '\n') {
+            Some(i) => start += i + 1,
+            None => break,
+        }
+    }
+
+    let (_, end) = line_bounds(text, start);
+    (start + column).min(end)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        let text: Vec<char> = "fn main() {\n    a\n\n}".chars().collect();
+
+        assert_eq!(line_bounds(&text, 0), (0, 11));
+        assert_eq!(line_bounds(&text, 11), (0, 11));
+        assert_eq!(line_bounds(&text, 12), (12, 17));
+        assert_eq!(line_bounds(&text, 18), (18, 18));
+        assert_eq!(line_column(&text, 16), (1, 4));
+        assert_eq!(line_column(&text, 18), (2, 0));
+        assert_eq!(line_column(&text, 100), (3, 1));
+    }
+
+    #[test]
+    fn test_index_of() {
+        let text: Vec<char> = "fn main() {\n    a\n\n}".chars().collect();
+
+        assert_eq!(index_of(&text, 0, 3), 3);
+        assert_eq!(index_of(&text, 1, 100), 17);
+        assert_eq!(index_of(&text, 2, 4), 18);
+        assert_eq!(index_of(&text, 10, 0), 19);
+    }
+}
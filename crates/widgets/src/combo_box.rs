@@ -5,7 +5,7 @@ use std::{
 
 use super::behaviors::{MouseBehavior, SelectionBehavior};
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
 
 // --- KEYS --
 
@@ -19,6 +19,7 @@ type SelectedItem = Option<Entity>;
 #[derive(Debug, Copy, Clone)]
 enum Action {
     CheckMouseUpOutside { position: Point },
+    Key(Key),
 }
 
 /// The `ComboBoxItemState` handles the interaction an selection of a `ComboBoxItem`.
@@ -99,7 +100,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -197,6 +198,7 @@ pub struct ComboBoxState {
     action: Option<Action>,
     builder: Option<Arc<RefCell<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>>>,
     count: usize,
+    items: Vec<Entity>,
     items_panel: Entity,
     selected_container: Entity,
 }
@@ -214,18 +216,60 @@ impl ComboBoxState {
         let combo_box_global_bounds = Rectangle::new(combo_box_position, combo_box_bounds.size());
 
         if !combo_box_global_bounds.contains(p) {
-            ctx.widget().set("selected", false);
-            ctx.widget().get_mut::<Selector>("selector").clear_state();
-            ctx.get_widget(self.popup)
-                .set("visibility", Visibility::Collapsed);
-            ctx.get_widget(self.popup).update(false);
-            ctx.widget().update(false);
+            self.close(ctx);
+        }
+    }
+
+    // closes the popup.
+    fn close(&mut self, ctx: &mut Context) {
+        ctx.widget().set("selected", false);
+        ctx.widget().get_mut::<Selector>("selector").clear_state();
+        ctx.get_widget(self.popup)
+            .set("visibility", Visibility::Collapsed);
+        ctx.get_widget(self.popup).update(false);
+        ctx.widget().update(false);
+    }
+
+    // handles the keyboard navigation inside of the open drop-down.
+    fn handle_key(&mut self, ctx: &mut Context, key: Key) {
+        if !*ctx.widget().get::<bool>("selected") {
+            return;
+        }
+
+        let selected_index = *ctx.widget().get::<i32>("selected_index");
+
+        let index = match key {
+            Key::Up => previous_index(selected_index, self.count),
+            Key::Down => next_index(selected_index, self.count),
+            Key::Home => first_index(self.count),
+            Key::End => last_index(self.count),
+            Key::Enter | Key::Escape => {
+                self.close(ctx);
+                return;
+            }
+            _ => return,
+        };
+
+        if index == selected_index || index < 0 {
+            return;
+        }
+
+        ctx.widget().set("selected_index", index);
+
+        // mark the item as dirty, its state will handle the selection.
+        if let Some(item) = self.items.get(index as usize) {
+            ctx.get_widget(*item).update_dirty(true);
         }
     }
 }
 
 impl State for ComboBoxState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(Action::Key(key)) = self.action {
+            self.handle_key(ctx, key);
+            self.action = None;
+        }
+
         let count = ctx.widget().clone_or_default::<usize>("count");
         let entity = ctx.entity;
 
@@ -233,6 +277,7 @@ impl State for ComboBoxState {
         if count != self.count {
             if let Some(builder) = &self.builder {
                 ctx.clear_children_of(self.items_panel);
+                self.items.clear();
 
                 for i in 0..count {
                     let item = {
@@ -272,6 +317,7 @@ impl State for ComboBoxState {
                         item
                     };
                     ctx.get_widget(item).update_widget(entity, false, false);
+                    self.items.push(item);
                 }
             }
 
@@ -284,12 +330,8 @@ impl State for ComboBoxState {
             return;
         }
 
-        if let Some(action) = self.action {
-            match action {
-                Action::CheckMouseUpOutside { position } => {
-                    self.close_popup(ctx, position);
-                }
-            }
+        if let Some(Action::CheckMouseUpOutside { position }) = self.action {
+            self.close_popup(ctx, position);
         }
     }
 
@@ -303,13 +345,15 @@ impl State for ComboBoxState {
 widget!(
     /// The `ComboBox` represents an selection widget with a drop-down list.
     ///
+    /// If the drop-down is open the selection could be changed by the Up, Down, Home and End keys. Enter and Escape closes the drop-down.
+    ///
     /// **style:** `combo_box`
-    ComboBox<ComboBoxState>: MouseHandler {
+    ComboBox<ComboBoxState>: KeyDownHandler, MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -354,7 +398,17 @@ widget!(
         icon_size: f64,
 
         /// Sets or shares the icon font property.
-        icon_font: String
+        icon_font: String,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
     }
 );
 
@@ -433,6 +487,9 @@ impl Template for ComboBox {
 
         self.name("ComboBox")
             .style("combo_box")
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .icon(material_icons_font::MD_ARROW_DROP_DOWN)
             .icon_font("MaterialIcons-Regular")
             .icon_size(fonts::ICON_FONT_SIZE_12)
@@ -456,6 +513,12 @@ impl Template for ComboBox {
                     )
                     .build(ctx),
             )
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<ComboBoxState>(id)
+                    .action(Action::Key(event.key));
+                false
+            })
             .on_global_mouse_up(move |states, e| {
                 states
                     .get_mut::<ComboBoxState>(id)
@@ -465,3 +528,64 @@ impl Template for ComboBox {
             })
     }
 }
+
+// --- Helpers --
+
+fn previous_index(selected_index: i32, count: usize) -> i32 {
+    if count == 0 {
+        return -1;
+    }
+
+    (selected_index - 1).max(0)
+}
+
+fn next_index(selected_index: i32, count: usize) -> i32 {
+    if count == 0 {
+        return -1;
+    }
+
+    (selected_index + 1).min(count as i32 - 1)
+}
+
+fn first_index(count: usize) -> i32 {
+    if count == 0 {
+        return -1;
+    }
+
+    0
+}
+
+fn last_index(count: usize) -> i32 {
+    count as i32 - 1
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_index() {
+        assert_eq!(previous_index(-1, 0), -1);
+        assert_eq!(previous_index(-1, 5), 0);
+        assert_eq!(previous_index(0, 5), 0);
+        assert_eq!(previous_index(3, 5), 2);
+    }
+
+    #[test]
+    fn test_next_index() {
+        assert_eq!(next_index(-1, 0), -1);
+        assert_eq!(next_index(-1, 5), 0);
+        assert_eq!(next_index(3, 5), 4);
+        assert_eq!(next_index(4, 5), 4);
+    }
+
+    #[test]
+    fn test_first_and_last_index() {
+        assert_eq!(first_index(0), -1);
+        assert_eq!(first_index(5), 0);
+        assert_eq!(last_index(0), -1);
+        assert_eq!(last_index(5), 4);
+    }
+}
@@ -1,13 +1,31 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
 
 widget!(
     /// The `Container` layout widget surrounds its child with a padding. Draws a box around the child.
-    Container {
+    ///
+    /// If a `border_image` is set it is drawn instead of the box as nine-slice image. The `border_image_slice`
+    /// defines the size of the corners inside of the image, the corners keep their size and the edges and the
+    /// center are stretched. The border image could also be set by the theme with the path of the image.
+    ///
+    /// A container could be used as drop target of a drag-and-drop operation, see `DragDropHandler`. Double clicks,
+    /// long presses and swipes on the container are handled by the `GestureHandler`, touch points, pinches and
+    /// rotations by the `TouchHandler`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Container::new()
+    ///     .border_image("res/border.png")
+    ///     .border_image_slice(8.0)
+    ///     .padding(8.0)
+    ///     .build(ctx)
+    /// ```
+    Container: DragDropHandler, GestureHandler, TouchHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -15,8 +33,17 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the drop shadow of the box, e.g. `"0px 2px 8px 0px #66000000"`.
+        box_shadow: BoxShadow,
+
         /// Sets or shares the padding property.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the image that is drawn as nine-slice border and background.
+        border_image: Image,
+
+        /// Sets or shares the size of the fixed corners of the border image (left, top, right, bottom).
+        border_image_slice: Thickness
     }
 );
 
@@ -28,6 +55,8 @@ impl Template for Container {
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_image(Image::default())
+            .border_image_slice(0.0)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
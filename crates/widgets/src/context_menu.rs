@@ -0,0 +1,271 @@
+use std::{mem, rc::Rc};
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_CONTEXT_MENU: &str = "context_menu";
+
+// --- KEYS --
+
+#[derive(Debug, Copy, Clone)]
+enum ContextMenuAction {
+    Open(Point),
+    Close,
+    Hover(Entity),
+    CheckMouseUpOutside,
+}
+
+/// The `ContextMenuState` handles the open and close behavior of the `ContextMenu` widget.
+#[derive(Default, AsAny)]
+pub struct ContextMenuState {
+    action: Option<ContextMenuAction>,
+    items: Vec<Entity>,
+    position: Point,
+    open: bool,
+    keep_open: bool,
+    ignore_release: bool,
+    bounds: Rectangle,
+}
+
+impl ContextMenuState {
+    fn action(&mut self, action: ContextMenuAction) {
+        self.action = Some(action);
+    }
+
+    /// Opens the context menu at the given position.
+    pub fn open(&mut self, position: Point) {
+        self.action(ContextMenuAction::Open(position));
+    }
+
+    // opens the context menu by a long press, the release of the press doesn't close it.
+    fn open_by_long_press(&mut self, position: Point) {
+        self.ignore_release = true;
+        self.open(position);
+    }
+
+    /// Closes the context menu.
+    pub fn close(&mut self) {
+        self.action(ContextMenuAction::Close);
+    }
+
+    // opens the hovered sub menu and closes all other sub menus.
+    pub(crate) fn hover(&mut self, hovered: Entity) {
+        self.action(ContextMenuAction::Hover(hovered));
+    }
+
+    // prevents the close of the context menu if the mouse is released inside of a sub menu.
+    pub(crate) fn keep_open(&mut self) {
+        self.keep_open = true;
+    }
+
+    // synchronizes the visibility with the open property and closes all sub menus on close.
+    fn update_open(&mut self, ctx: &mut Context) {
+        let open = *context_menu(ctx.widget()).open();
+
+        if open == self.open {
+            return;
+        }
+
+        self.open = open;
+
+        if open {
+            ctx.widget().set("visibility", Visibility::Visible);
+        } else {
+            ctx.widget().set("visibility", Visibility::Hidden);
+
+            for item in &self.items {
+                let mut item_widget = ctx.get_widget(*item);
+
+                if item_widget.has::<bool>("selected") {
+                    item_widget.set("selected", false);
+                }
+            }
+        }
+    }
+}
+
+impl State for ContextMenuState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        ctx.widget().set("visibility", Visibility::Hidden);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                ContextMenuAction::Open(position) => {
+                    self.position = position;
+                    context_menu(ctx.widget()).set_open(true);
+                }
+                ContextMenuAction::Close => context_menu(ctx.widget()).set_open(false),
+                ContextMenuAction::Hover(hovered) => {
+                    for item in &self.items {
+                        let mut item_widget = ctx.get_widget(*item);
+
+                        if item_widget.has::<bool>("selected") {
+                            item_widget.set("selected", *item == hovered);
+                        }
+                    }
+                }
+                ContextMenuAction::CheckMouseUpOutside => {
+                    if !self.keep_open {
+                        context_menu(ctx.widget()).set_open(false);
+                    }
+                }
+            }
+        }
+
+        self.keep_open = false;
+        self.update_open(ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if !self.open {
+            return;
+        }
+
+        // the context menu is anchored at the mouse position.
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_x(self.position.x());
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_y(self.position.y());
+        self.bounds = *ctx.widget().get::<Rectangle>("bounds");
+    }
+}
+
+widget!(
+    /// The `ContextMenu` displays menu items in a popup at the mouse position. It is attached to a widget with the
+    /// `context_menu` method of the `ContextMenuHandler` and opened by a right click or a long press on the widget. The
+    /// context menu is closed on activation of an item, a click outside of the menu or by the Escape key.
+    ///
+    /// This example attaches a ContextMenu:
+    /// ```rust
+    /// TextBlock::new()
+    ///     .text("Right click me")
+    ///     .context_menu(
+    ///         ContextMenu::new()
+    ///             .item(MenuItem::new().id("copy").text("Copy").build(ctx))
+    ///             .on_activated(|_, _, id| println!("{} activated", id))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    ///
+    /// **style:** `context_menu`
+    ContextMenu<ContextMenuState>: ActivatedHandler, KeyDownHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the value if the context menu is open and visible.
+        open: bool
+    }
+);
+
+impl ContextMenu {
+    /// Adds an item to the context menu. An item could be a `MenuItem`, a `MenuSeparator` or a `Menu`.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for ContextMenu {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut items_panel = Stack::new().orientation("vertical");
+
+        for item in &self.state().items {
+            ctx.register_property::<u32>("menu", *item, id.0);
+            items_panel = items_panel.child(*item);
+        }
+
+        let _ = ctx.append_child_to_overlay(id);
+
+        self.name("ContextMenu")
+            .style(STYLE_CONTEXT_MENU)
+            .open(false)
+            .h_align("start")
+            .v_align("start")
+            .padding(0.0)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .child(items_panel.build(ctx))
+            .on_mouse_down(|_, _| true)
+            .on_key_down(move |states, event| -> bool {
+                if !states.get::<ContextMenuState>(id).open || event.key != Key::Escape {
+                    return false;
+                }
+
+                states.get_mut::<ContextMenuState>(id).close();
+                true
+            })
+            .on_global_mouse_up(move |states, e| {
+                if e.button != MouseButton::Left {
+                    return;
+                }
+
+                let state = states.get_mut::<ContextMenuState>(id);
+
+                if mem::take(&mut state.ignore_release) {
+                    return;
+                }
+
+                if state.open && !state.bounds.contains(e.position) {
+                    state.action(ContextMenuAction::CheckMouseUpOutside);
+                }
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PopupLayout::new())
+    }
+}
+
+/// The `ContextMenuHandler` is implemented for all widgets and is used to attach a `ContextMenu`.
+pub trait ContextMenuHandler: Sized + Widget {
+    /// Attaches the given context menu. It is opened on right click or on a long press, e.g. on touch screens, at
+    /// the mouse position.
+    fn context_menu(self, context_menu: Entity) -> Self {
+        self.insert_handler(MouseDownEventHandler {
+            handler: Rc::new(move |states, mouse| {
+                if mouse.button != MouseButton::Right {
+                    return false;
+                }
+
+                states
+                    .get_mut::<ContextMenuState>(context_menu)
+                    .open(mouse.position);
+                true
+            }),
+        })
+        .insert_handler(LongPressEventHandler {
+            handler: Rc::new(move |states, position| {
+                states
+                    .get_mut::<ContextMenuState>(context_menu)
+                    .open_by_long_press(position);
+                true
+            }),
+        })
+    }
+}
+
+impl<W: Widget> ContextMenuHandler for W {}
@@ -0,0 +1,219 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_DATE_PICKER: &str = "date_picker";
+
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum DatePickerAction {
+    Toggle,
+    Commit,
+    Select,
+    CheckMouseUpOutside(Point),
+}
+
+/// The `DatePickerState` synchronizes the text field and the calendar popup of the `DatePicker`.
+#[derive(Default, AsAny)]
+pub struct DatePickerState {
+    actions: Vec<DatePickerAction>,
+    text_box: Entity,
+    popup: Entity,
+    selected_date: Option<Date>,
+}
+
+impl DatePickerState {
+    fn action(&mut self, action: DatePickerAction) {
+        self.actions.push(action);
+    }
+
+    // Parses the text of the text field. An invalid text is replaced by the selected date.
+    fn commit(&mut self, ctx: &mut Context) {
+        let text = ctx.get_widget(self.text_box).clone::<String16>("text");
+
+        match Date::parse(&text.as_string()) {
+            Some(date) if Some(date) != self.selected_date => {
+                ctx.widget().set("selected_date", date);
+            }
+            _ => self.update_text(ctx),
+        }
+    }
+
+    fn update_text(&mut self, ctx: &mut Context) {
+        let selected_date = ctx.widget().clone::<Date>("selected_date");
+
+        ctx.get_widget(self.text_box)
+            .set("text", String16::from(selected_date.to_string()));
+    }
+
+    // Closes the popup on mouse up outside of the date picker and the popup.
+    fn check_mouse_up_outside(&mut self, ctx: &mut Context, position: Point) {
+        if !*ctx.widget().get::<bool>("open") {
+            return;
+        }
+
+        let inside = [ctx.entity, self.popup].iter().any(|entity| {
+            let widget = ctx.get_widget(*entity);
+            Rectangle::new(
+                widget.clone::<Point>("position"),
+                widget.get::<Rectangle>("bounds").size(),
+            )
+            .contains(position)
+        });
+
+        if !inside {
+            ctx.widget().set("open", false);
+        }
+    }
+}
+
+impl State for DatePickerState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.selected_date = Some(ctx.widget().clone::<Date>("selected_date"));
+        self.update_text(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<DatePickerAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                DatePickerAction::Toggle => {
+                    let open = !*ctx.widget().get::<bool>("open");
+                    ctx.widget().set("open", open);
+                }
+                DatePickerAction::Commit => self.commit(ctx),
+                DatePickerAction::Select => {
+                    // the calendar shares the selected date, the changed event is raised for the date picker too
+                    ctx.push_event_strategy_by_entity(
+                        ChangedEvent(ctx.entity, String::from("selected_date")),
+                        ctx.entity,
+                        EventStrategy::Direct,
+                    );
+                    ctx.widget().set("open", false);
+                }
+                DatePickerAction::CheckMouseUpOutside(position) => {
+                    self.check_mouse_up_outside(ctx, position)
+                }
+            }
+        }
+
+        let selected_date = ctx.widget().clone::<Date>("selected_date");
+
+        if self.selected_date != Some(selected_date) {
+            self.selected_date = Some(selected_date);
+            self.update_text(ctx);
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.popup);
+    }
+}
+
+widget!(
+    /// The `DatePicker` combines a text field with a `Calendar` popup to select a date. The date could be typed
+    /// in the format `YYYY-MM-DD` or picked from the calendar that is opened by the button beside the text field.
+    ///
+    /// **style:** `date_picker`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// DatePicker::new()
+    ///     .selected_date((2020, 6, 1))
+    ///     .on_changed("selected_date", |_, id| println!("Date of {:?} changed", id))
+    ///     .build(ctx)
+    /// ```
+    DatePicker<DatePickerState>: MouseHandler {
+        /// Sets or shares the selected date. The default is today.
+        selected_date: Date,
+
+        /// Sets or shares the flag if the calendar popup is open.
+        open: bool,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64
+    }
+);
+
+impl Template for DatePicker {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let text_box = TextBox::new()
+            .attach(Grid::column(0))
+            .min_width(0.0)
+            .foreground(id)
+            .font_size(id)
+            .water_mark("YYYY-MM-DD")
+            .on_activate(move |states, _| {
+                states
+                    .get_mut::<DatePickerState>(id)
+                    .action(DatePickerAction::Commit);
+            })
+            .on_changed("focused", move |states, _| {
+                states
+                    .get_mut::<DatePickerState>(id)
+                    .action(DatePickerAction::Commit);
+            })
+            .build(ctx);
+        self.state_mut().text_box = text_box;
+
+        let container = Grid::new()
+            .columns(Columns::create().push("*").push(4.0).push("auto"))
+            .child(text_box)
+            .child(
+                Button::new()
+                    .style("button_icon_only")
+                    .attach(Grid::column(2))
+                    .v_align("center")
+                    .icon(material_icons_font::MD_EVENT)
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<DatePickerState>(id)
+                            .action(DatePickerAction::Toggle);
+                        false
+                    })
+                    .build(ctx),
+            )
+            .build(ctx);
+
+        let popup = Popup::new()
+            .open(id)
+            .target(container.0)
+            .child(
+                Calendar::new()
+                    .selected_date(id)
+                    .on_changed("selected_date", move |states, _| {
+                        states
+                            .get_mut::<DatePickerState>(id)
+                            .action(DatePickerAction::Select);
+                    })
+                    .build(ctx),
+            )
+            .build(ctx);
+        self.state_mut().popup = popup;
+
+        let _ = ctx.append_child_to_overlay(popup);
+
+        let today = Date::from_days((now() / 86_400_000.0).floor() as i64);
+
+        self.name("DatePicker")
+            .style(STYLE_DATE_PICKER)
+            .selected_date(today)
+            .open(false)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .min_width(160.0)
+            .height(32.0)
+            .child(container)
+            .on_global_mouse_up(move |states, e| {
+                states
+                    .get_mut::<DatePickerState>(id)
+                    .action(DatePickerAction::CheckMouseUpOutside(e.position));
+            })
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_DRAG_GHOST: &str = "drag_ghost";
+
+// --- KEYS --
+
+// The distance between the mouse pointer and the drag ghost.
+const DRAG_GHOST_OFFSET: f64 = 12.0;
+
+/// The `DragGhostState` shows the active drag of the `DragDropService` and moves the ghost with the mouse.
+#[derive(Default, AsAny)]
+pub struct DragGhostState {
+    dragging: bool,
+}
+
+impl DragGhostState {
+    fn hide(&mut self, ctx: &mut Context) {
+        if self.dragging {
+            self.dragging = false;
+            ctx.widget().set("visibility", Visibility::Hidden);
+        }
+    }
+
+    fn show(&mut self, ctx: &mut Context, text: &str) {
+        self.dragging = true;
+        drag_ghost(ctx.widget()).set_text(String16::from(text));
+        ctx.widget().set("visibility", Visibility::Visible);
+    }
+
+    // Shows if the widget under the mouse accepts the drag.
+    fn update_accepted(&self, ctx: &mut Context, accepted: bool) {
+        if *drag_ghost(ctx.widget()).accepted() == accepted {
+            return;
+        }
+
+        drag_ghost(ctx.widget()).set_accepted(accepted);
+        drag_ghost(ctx.widget()).set_icon(if accepted {
+            material_icons_font::MD_DRAG_INDICATOR.to_string()
+        } else {
+            material_icons_font::MD_BLOCK.to_string()
+        });
+        toggle_flag("accepted", &mut ctx.widget());
+        ctx.widget().update(false);
+    }
+}
+
+impl State for DragGhostState {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        registry
+            .get_mut::<DragDropService>("drag_drop")
+            .set_host(ctx.entity);
+        ctx.widget().set("visibility", Visibility::Hidden);
+    }
+
+    fn update_post_layout(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let service = registry.get::<DragDropService>("drag_drop");
+
+        // drags of other applications are shown by the operating system
+        let drag = match service.drag() {
+            Some(drag) if !drag.data.is_external() => drag,
+            _ => {
+                self.hide(ctx);
+                return;
+            }
+        };
+
+        self.update_accepted(ctx, service.accepted());
+
+        if !self.dragging {
+            self.show(ctx, drag.data.get_text());
+
+            // the new text is measured before the ghost is placed
+            ctx.request_next_frame();
+        }
+
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_x(drag.position.x() + DRAG_GHOST_OFFSET);
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_y(drag.position.y() + DRAG_GHOST_OFFSET);
+    }
+}
+
+widget!(
+    /// The `DragGhost` follows the mouse during a drag-and-drop operation and shows the text of the dragged data.
+    /// The icon shows if the widget under the mouse accepts the drag. There is one drag ghost per window, it is
+    /// created by the `Window` and placed in the overlay.
+    ///
+    /// **style:** `drag_ghost`
+    DragGhost<DragGhostState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon property.
+        icon: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Gets the flag if the widget under the mouse accepts the drag.
+        accepted: bool
+    }
+);
+
+impl Template for DragGhost {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("DragGhost")
+            .style(STYLE_DRAG_GHOST)
+            .h_align("start")
+            .v_align("start")
+            .padding((8.0, 4.0, 8.0, 4.0))
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon(material_icons_font::MD_BLOCK)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_font("MaterialIcons-Regular")
+            .accepted(false)
+            .child(
+                Stack::new()
+                    .orientation("horizontal")
+                    .spacing(4.0)
+                    .child(
+                        FontIconBlock::new()
+                            .v_align("center")
+                            .icon(id)
+                            .icon_brush(id)
+                            .icon_size(id)
+                            .icon_font(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(id)
+                            .font(id)
+                            .font_size(id)
+                            .foreground(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
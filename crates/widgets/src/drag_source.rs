@@ -0,0 +1,139 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+/// The distance in pixels the mouse has to move with pressed button until the drag starts.
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+#[derive(Debug, Copy, Clone)]
+enum DragSourceAction {
+    Press(Point),
+    Move(Point),
+    Release,
+    End,
+}
+
+/// The `DragSourceState` starts a drag with the data of the `DragSource` if the mouse is moved with pressed button.
+#[derive(Default, AsAny)]
+pub struct DragSourceState {
+    actions: Vec<DragSourceAction>,
+    data: Option<DragData>,
+    pressed: Option<Point>,
+}
+
+impl DragSourceState {
+    fn action(&mut self, action: DragSourceAction) {
+        self.actions.push(action);
+    }
+
+    /// Sets the data that is dragged by the next drag.
+    pub fn set_data(&mut self, data: DragData) {
+        self.data = Some(data);
+    }
+
+    // Starts the drag if the mouse has moved far enough from the position where it was pressed.
+    fn move_to(&mut self, ctx: &mut Context, position: Point) {
+        let start = match self.pressed {
+            Some(start) => start,
+            None => return,
+        };
+
+        if (position.x() - start.x()).abs() < DRAG_THRESHOLD
+            && (position.y() - start.y()).abs() < DRAG_THRESHOLD
+        {
+            return;
+        }
+
+        self.pressed = None;
+
+        if let Some(data) = self.data.clone() {
+            ctx.start_drag(data);
+            self.set_dragging(ctx, true);
+        }
+    }
+
+    fn set_dragging(&self, ctx: &mut Context, dragging: bool) {
+        drag_source(ctx.widget()).set_dragging(dragging);
+        toggle_flag("dragging", &mut ctx.widget());
+        ctx.widget().update(false);
+    }
+}
+
+impl State for DragSourceState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<DragSourceAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                DragSourceAction::Press(position) => {
+                    if *ctx.widget().get::<bool>("enabled") {
+                        self.pressed = Some(position);
+                    }
+                }
+                DragSourceAction::Move(position) => self.move_to(ctx, position),
+                DragSourceAction::Release => self.pressed = None,
+                DragSourceAction::End => self.set_dragging(ctx, false),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `DragSource` starts a drag-and-drop operation with its data if the mouse is pressed on its child and
+    /// moved. While the data is dragged the `dragging` property is `true`. Use `on_drag_end` to get notified if the
+    /// data was dropped on a target. The drag stays inside of the window, dragging data to other applications is not
+    /// supported by the shells.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// DragSource::new()
+    ///     .data(DragData::new(task_id).text("Write docs"))
+    ///     .child(TextBlock::new().text("Write docs").build(ctx))
+    ///     .on_drag_end(move |states, drag, dropped| {
+    ///         if dropped {
+    ///             states.get_mut::<MainViewState>(id).remove_task(drag.data);
+    ///         }
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    DragSource<DragSourceState>: DragDropHandler, MouseHandler {
+        /// Gets the flag if the data of the source is dragged.
+        dragging: bool
+    }
+);
+
+impl DragSource {
+    /// Sets the data that is dragged.
+    pub fn data(mut self, data: DragData) -> Self {
+        self.state_mut().set_data(data);
+        self
+    }
+}
+
+impl Template for DragSource {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("DragSource")
+            .dragging(false)
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<DragSourceState>(id)
+                    .action(DragSourceAction::Press(m.position));
+                false
+            })
+            .on_mouse_move(move |states, position| {
+                states
+                    .get_mut::<DragSourceState>(id)
+                    .action(DragSourceAction::Move(position));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<DragSourceState>(id)
+                    .action(DragSourceAction::Release);
+            })
+            .on_drag_end(move |states, _, _| {
+                states
+                    .get_mut::<DragSourceState>(id)
+                    .action(DragSourceAction::End);
+            })
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_EXPANDER: &str = "expander";
+pub static STYLE_EXPANDER_HEADER: &str = "expander_header";
+static ID_HEADER: &str = "EXPANDER_HEADER";
+static ID_ICON: &str = "EXPANDER_ICON";
+static ID_CONTENT_PANEL: &str = "EXPANDER_CONTENT_PANEL";
+
+// --- KEYS --
+
+/// The default duration of the expand and collapse animation in milliseconds.
+pub const EXPANDER_DURATION: f64 = 150.0;
+
+#[derive(Copy, Clone)]
+enum ExpanderAction {
+    Press(Point),
+    Release(Point),
+}
+
+#[derive(Copy, Clone)]
+struct ExpanderAnimation {
+    from: f64,
+    start: f64,
+}
+
+/// The `ExpanderState` handles the `Expander` widget.
+#[derive(Default, AsAny)]
+pub struct ExpanderState {
+    actions: Vec<ExpanderAction>,
+    content: Option<Entity>,
+    header: Entity,
+    icon: Entity,
+    content_panel: Entity,
+    pressed: bool,
+    expanded: bool,
+    openness: f64,
+    animation: Option<ExpanderAnimation>,
+}
+
+impl ExpanderState {
+    fn action(&mut self, action: ExpanderAction) {
+        self.actions.push(action);
+    }
+
+    // Expands or collapses the expander if the header is clicked.
+    fn handle_actions(&mut self, ctx: &mut Context) {
+        let actions: Vec<ExpanderAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                ExpanderAction::Press(position) => {
+                    self.pressed = check_mouse_condition(position, &ctx.get_widget(self.header));
+                }
+                ExpanderAction::Release(position) => {
+                    if self.pressed && check_mouse_condition(position, &ctx.get_widget(self.header))
+                    {
+                        let expanded = !*ctx.widget().get::<bool>("expanded");
+                        ctx.widget().set("expanded", expanded);
+                    }
+
+                    self.pressed = false;
+                }
+            }
+        }
+    }
+
+    // Starts the animation if the expanded property has been changed.
+    fn update_expanded(&mut self, ctx: &mut Context) {
+        let expanded = *ctx.widget().get::<bool>("expanded");
+
+        if expanded == self.expanded {
+            return;
+        }
+
+        self.expanded = expanded;
+        self.animation = Some(ExpanderAnimation {
+            from: self.openness,
+            start: now(),
+        });
+
+        ctx.get_widget(self.icon).set(
+            "icon",
+            String::from(if expanded {
+                material_icons_font::MD_KEYBOARD_ARROW_DOWN
+            } else {
+                material_icons_font::MD_KEYBOARD_ARROW_RIGHT
+            }),
+        );
+    }
+
+    fn animate(&mut self, ctx: &mut Context) {
+        let animation = match self.animation {
+            Some(animation) => animation,
+            None => return,
+        };
+
+        let duration = *ctx.widget().get::<f64>("duration");
+        let progress = if duration > 0. {
+            ((now() - animation.start) / duration).min(1.).max(0.)
+        } else {
+            1.
+        };
+
+        self.openness = openness(animation.from, self.expanded, progress);
+        self.apply_openness(ctx);
+
+        if progress < 1. {
+            ctx.request_next_frame();
+        } else {
+            self.animation = None;
+        }
+    }
+
+    // Limits the height of the content panel to the visible part of the content.
+    fn apply_openness(&mut self, ctx: &mut Context) {
+        let content_height = match self.content {
+            Some(content) => {
+                let content = ctx.get_widget(content);
+                let margin = *content.get::<Thickness>("margin");
+                content.get::<Rectangle>("bounds").height() + margin.top() + margin.bottom()
+            }
+            None => 0.,
+        };
+
+        let mut content_panel = ctx.get_widget(self.content_panel);
+        let padding = *content_panel.get::<Thickness>("padding");
+
+        if self.openness <= 0. {
+            content_panel.set("visibility", Visibility::Collapsed);
+            return;
+        }
+
+        content_panel.set("visibility", Visibility::Visible);
+
+        let max_height = if self.openness >= 1. {
+            f64::MAX
+        } else {
+            // the content keeps its height and is clipped, a panel without any height is still measured
+            ((content_height + padding.top() + padding.bottom()) * self.openness).max(f64::EPSILON)
+        };
+
+        content_panel
+            .get_mut::<Constraint>("constraint")
+            .set_max_height(max_height);
+    }
+}
+
+impl State for ExpanderState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.header = ctx
+            .entity_of_child(ID_HEADER)
+            .expect("ExpanderState.init(): Header could not be found.");
+        self.icon = ctx
+            .entity_of_child(ID_ICON)
+            .expect("ExpanderState.init(): Icon could not be found.");
+        self.content_panel = ctx
+            .entity_of_child(ID_CONTENT_PANEL)
+            .expect("ExpanderState.init(): Content panel could not be found.");
+
+        // the initial state is shown without animation
+        self.expanded = !*ctx.widget().get::<bool>("expanded");
+        self.update_expanded(ctx);
+        self.animation = None;
+        self.openness = if self.expanded { 1. } else { 0. };
+        self.apply_openness(ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.handle_actions(ctx);
+        self.update_expanded(ctx);
+        self.animate(ctx);
+    }
+}
+
+widget!(
+    /// The `Expander` shows a clickable header and a content below it. A click on the header expands or collapses
+    /// the content with an animated height transition. The `expanded` property could be set, shared or bound to
+    /// expand or collapse the content from outside.
+    ///
+    /// **style:** `expander`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Expander::new()
+    ///     .header("Details")
+    ///     .expanded(true)
+    ///     .content(TextBlock::new().text("Content").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Expander<ExpanderState>: MouseHandler {
+        /// Sets or shares the text of the header.
+        header: String16,
+
+        /// Sets or shares the expanded property. If `true` the content is shown.
+        expanded: bool,
+
+        /// Sets or shares the duration of the expand and collapse animation in milliseconds.
+        duration: f64,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the foreground property of the header.
+        foreground: Brush,
+
+        /// Sets or shares the font size property of the header.
+        font_size: f64,
+
+        /// Sets or shares the brush of the header icon.
+        icon_brush: Brush
+    }
+);
+
+impl Expander {
+    /// Sets the content that is shown below the header if the expander is expanded.
+    pub fn content(mut self, content: Entity) -> Self {
+        self.state_mut().content = Some(content);
+        self
+    }
+}
+
+impl Template for Expander {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut content_panel = Container::new().id(ID_CONTENT_PANEL).clip(true);
+
+        if let Some(content) = self.state().content {
+            // the content keeps its own height during the animation
+            ctx.register_property("v_align", content, Alignment::Start);
+            content_panel = content_panel.child(content);
+        }
+
+        self.name("Expander")
+            .style(STYLE_EXPANDER)
+            .header("")
+            .expanded(false)
+            .duration(EXPANDER_DURATION)
+            .background("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<ExpanderState>(id)
+                    .action(ExpanderAction::Press(m.position));
+                false
+            })
+            .on_global_mouse_up(move |states, m| {
+                states
+                    .get_mut::<ExpanderState>(id)
+                    .action(ExpanderAction::Release(m.position));
+            })
+            .child(
+                Stack::new()
+                    .child(
+                        Container::new()
+                            .id(ID_HEADER)
+                            .style(STYLE_EXPANDER_HEADER)
+                            .height(32.0)
+                            .padding((8.0, 0.0, 8.0, 0.0))
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .spacing(8.0)
+                                    .v_align("center")
+                                    .child(
+                                        FontIconBlock::new()
+                                            .id(ID_ICON)
+                                            .v_align("center")
+                                            .icon(material_icons_font::MD_KEYBOARD_ARROW_RIGHT)
+                                            .icon_brush(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .v_align("center")
+                                            .text(("header", id))
+                                            .foreground(id)
+                                            .font_size(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(content_panel.build(ctx))
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
+
+// --- Helpers --
+
+// Returns how far the content is expanded at the given progress of the animation, starting at `from`.
+fn openness(from: f64, expanded: bool, progress: f64) -> f64 {
+    let to = if expanded { 1. } else { 0. };
+    let progress = progress * progress * (3. - 2. * progress);
+
+    from + (to - from) * progress
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openness() {
+        assert!((openness(0., true, 0.) - 0.).abs() < f64::EPSILON);
+        assert!((openness(0., true, 0.5) - 0.5).abs() < f64::EPSILON);
+        assert!((openness(0., true, 1.) - 1.).abs() < f64::EPSILON);
+        assert!((openness(1., false, 1.) - 0.).abs() < f64::EPSILON);
+        assert!((openness(0.5, false, 0.5) - 0.25).abs() < f64::EPSILON);
+    }
+}
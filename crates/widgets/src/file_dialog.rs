@@ -0,0 +1,445 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc,
+};
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_FILE_DIALOG: &str = "file_dialog";
+static ID_FILE_NAME: &str = "file_dialog_file_name";
+static ID_FILES_PANEL: &str = "file_dialog_files_panel";
+static ID_ACCEPT: &str = "file_dialog_accept";
+static ID_DIRECTORY: &str = "file_dialog_directory";
+
+// --- KEYS --
+
+/// Describes the current result of a file dialog.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileDialogResult {
+    /// The dialog is still open.
+    Pending,
+
+    /// The dialog was closed without a chosen path.
+    Canceled,
+
+    /// The path chosen by the user.
+    Path(PathBuf),
+}
+
+/// The `FileDialogHandle` delivers the result of an opened file dialog to the calling state. It should be checked in
+/// `update_post_layout` of the state, that runs each time the window is woken up by the closed dialog.
+pub struct FileDialogHandle {
+    receiver: mpsc::Receiver<Option<PathBuf>>,
+}
+
+impl FileDialogHandle {
+    /// Returns the result of the dialog. After the dialog has returned its result the handle should be dropped.
+    pub fn result(&self) -> FileDialogResult {
+        match self.receiver.try_recv() {
+            Ok(Some(path)) => FileDialogResult::Path(path),
+            Ok(None) | Err(mpsc::TryRecvError::Disconnected) => FileDialogResult::Canceled,
+            Err(mpsc::TryRecvError::Empty) => FileDialogResult::Pending,
+        }
+    }
+}
+
+/// The `FileDialog` shows the file picker of the platform. On Redox the built-in `FileDialogView` is shown in a new
+/// window.
+///
+/// # Example
+///
+/// ```rust
+/// // in State::update
+/// self.dialog = Some(FileDialog::open(ctx, &["*.txt"]));
+///
+/// // in State::update_post_layout
+/// if let Some(FileDialogResult::Path(path)) = self.dialog.as_ref().map(|d| d.result()) {
+///     println!("{:?}", path);
+/// }
+/// ```
+pub struct FileDialog;
+
+impl FileDialog {
+    /// Shows a file dialog to choose an existing file. The filters are a list of patterns like `*.txt`.
+    pub fn open(ctx: &mut Context, filters: &[&str]) -> FileDialogHandle {
+        FileDialog::show(ctx, FileDialogMode::Open, filters)
+    }
+
+    /// Shows a file dialog to choose the path of a file to save. The filters are a list of patterns like `*.txt`.
+    pub fn save(ctx: &mut Context, filters: &[&str]) -> FileDialogHandle {
+        FileDialog::show(ctx, FileDialogMode::Save, filters)
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "redox")))]
+    fn show(ctx: &mut Context, mode: FileDialogMode, filters: &[&str]) -> FileDialogHandle {
+        FileDialogHandle {
+            receiver: show_file_dialog(mode, title(mode), filters, ctx.window_sender()),
+        }
+    }
+
+    #[cfg(target_os = "redox")]
+    fn show(ctx: &mut Context, mode: FileDialogMode, filters: &[&str]) -> FileDialogHandle {
+        let (sender, receiver) = mpsc::channel();
+        let window_sender = ctx.window_sender();
+        let filters: Vec<String> = filters.iter().map(|f| f.to_string()).collect();
+        let directory = std::env::current_dir()
+            .map(|d| d.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        ctx.show_window(move |ctx| {
+            Window::new()
+                .title(title(mode))
+                .position((120.0, 120.0))
+                .size(400.0, 400.0)
+                .child(
+                    FileDialogView::new()
+                        .mode(mode)
+                        .directory(directory.clone())
+                        .filters(filters.clone())
+                        .result_sender(sender.clone(), window_sender.clone())
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        FileDialogHandle { receiver }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn show(_: &mut Context, _: FileDialogMode, _: &[&str]) -> FileDialogHandle {
+        // there is no file system access on web
+        let (sender, receiver) = mpsc::channel();
+        let _ = sender.send(None);
+
+        FileDialogHandle { receiver }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum FileDialogAction {
+    Select(usize),
+    Accept,
+    Cancel,
+}
+
+/// The `FileDialogViewState` sends the chosen path of the `FileDialogView` and closes its window.
+#[derive(Default, AsAny)]
+pub struct FileDialogViewState {
+    action: Option<FileDialogAction>,
+    files: Vec<String>,
+    files_panel: Entity,
+    sender: Option<mpsc::Sender<Option<PathBuf>>>,
+    window_sender: Option<mpsc::Sender<WindowRequest>>,
+}
+
+impl FileDialogViewState {
+    fn action(&mut self, action: FileDialogAction) {
+        self.action = Some(action);
+    }
+
+    // lists the files of the directory as buttons.
+    fn generate_files(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+        let directory = file_dialog_view(ctx.widget()).clone_directory();
+        let filters = ctx.widget().clone::<Vec<String>>("filters");
+
+        self.files = list_files(&directory, &filters);
+        ctx.clear_children_of(self.files_panel);
+
+        for (index, file) in self.files.iter().enumerate() {
+            let build_context = &mut ctx.build_context();
+
+            let item = Button::new()
+                .style("list_view_item")
+                .text(file.as_str())
+                .h_align("stretch")
+                .on_click(move |states, _| {
+                    states
+                        .get_mut::<FileDialogViewState>(entity)
+                        .action(FileDialogAction::Select(index));
+                    true
+                })
+                .build(build_context);
+
+            build_context.append_child(self.files_panel, item);
+        }
+    }
+
+    // sends the result to the calling window and wakes it up.
+    fn send_result(&mut self, path: Option<PathBuf>) {
+        if let Some(sender) = self.sender.take() {
+            let _ = sender.send(path);
+        }
+
+        if let Some(window_sender) = self.window_sender.take() {
+            let _ = window_sender.send(WindowRequest::Redraw);
+        }
+    }
+
+    // sends the result and closes the dialog window.
+    fn finish(&mut self, path: Option<PathBuf>, ctx: &mut Context) {
+        self.send_result(path);
+        ctx.send_window_request(WindowRequest::Close);
+    }
+}
+
+impl State for FileDialogViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.files_panel = ctx
+            .entity_of_child(ID_FILES_PANEL)
+            .expect("FileDialogViewState.init: files panel could not be found.");
+
+        let accept_text = match *file_dialog_view(ctx.widget()).mode() {
+            FileDialogMode::Open => "Open",
+            FileDialogMode::Save => "Save",
+        };
+        button(ctx.child(ID_ACCEPT)).set_text(String16::from(accept_text));
+
+        let directory = file_dialog_view(ctx.widget()).clone_directory();
+        text_block(ctx.child(ID_DIRECTORY)).set_text(String16::from(directory));
+
+        self.generate_files(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                FileDialogAction::Select(index) => {
+                    if let Some(file) = self.files.get(index) {
+                        text_box(ctx.child(ID_FILE_NAME)).set_text(String16::from(file.as_str()));
+                    }
+                }
+                FileDialogAction::Accept => {
+                    let file_name = text_box(ctx.child(ID_FILE_NAME)).text().as_string();
+
+                    if file_name.is_empty() {
+                        return;
+                    }
+
+                    let directory = file_dialog_view(ctx.widget()).clone_directory();
+                    self.finish(Some(resolve_path(&directory, &file_name)), ctx);
+                }
+                FileDialogAction::Cancel => self.finish(None, ctx),
+            }
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, _: &mut Context) {
+        // closing the window is handled like a cancel
+        self.send_result(None);
+    }
+}
+
+widget!(
+    /// The `FileDialogView` is the built-in fallback of the `FileDialog` on platforms without a native file picker.
+    /// It lists the files of a directory and sends the chosen path to the calling window.
+    ///
+    /// **style:** `file_dialog`
+    FileDialogView<FileDialogViewState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the directory that is listed.
+        directory: String,
+
+        /// Sets or shares the file patterns like `*.txt` that are listed. If empty all files are listed.
+        filters: Vec<String>,
+
+        /// Sets or shares the mode that describes if a file is opened or saved.
+        mode: FileDialogMode
+    }
+);
+
+impl FileDialogView {
+    /// Sets the sender of the chosen path and the sender used to wake up the calling window.
+    pub fn result_sender(
+        mut self,
+        sender: mpsc::Sender<Option<PathBuf>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) -> Self {
+        self.state_mut().sender = Some(sender);
+        self.state_mut().window_sender = Some(window_sender);
+        self
+    }
+}
+
+impl Template for FileDialogView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("FileDialogView")
+            .style(STYLE_FILE_DIALOG)
+            .background("transparent")
+            .directory("")
+            .filters(Vec::<String>::new())
+            .mode(FileDialogMode::Open)
+            .child(
+                Grid::new()
+                    .margin(8.0)
+                    .rows(
+                        Rows::create()
+                            .push("auto")
+                            .push("*")
+                            .push("auto")
+                            .push("auto"),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .id(ID_DIRECTORY)
+                            .attach(Grid::row(0))
+                            .margin((0.0, 0.0, 0.0, 8.0))
+                            .build(ctx),
+                    )
+                    .child(
+                        ScrollViewer::new()
+                            .attach(Grid::row(1))
+                            .mode(("disabled", "auto"))
+                            .child(
+                                Stack::new()
+                                    .id(ID_FILES_PANEL)
+                                    .orientation("vertical")
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBox::new()
+                            .id(ID_FILE_NAME)
+                            .attach(Grid::row(2))
+                            .margin((0.0, 8.0, 0.0, 8.0))
+                            .water_mark("File name")
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::new()
+                            .attach(Grid::row(3))
+                            .orientation("horizontal")
+                            .h_align("end")
+                            .child(
+                                Button::new()
+                                    .text("Cancel")
+                                    .margin((0.0, 0.0, 8.0, 0.0))
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<FileDialogViewState>(id)
+                                            .action(FileDialogAction::Cancel);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .id(ID_ACCEPT)
+                                    .style("button_primary")
+                                    .text("Open")
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<FileDialogViewState>(id)
+                                            .action(FileDialogAction::Accept);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
+
+// -- Helpers --
+
+#[cfg(any(not(target_arch = "wasm32"), test))]
+fn title(mode: FileDialogMode) -> &'static str {
+    match mode {
+        FileDialogMode::Open => "Open file",
+        FileDialogMode::Save => "Save file",
+    }
+}
+
+// Checks if the file name matches one of the given patterns like `*.txt`. Without patterns all files match.
+fn matches_filters(file_name: &str, filters: &[String]) -> bool {
+    if filters.is_empty() {
+        return true;
+    }
+
+    filters.iter().any(|filter| {
+        if filter == "*" || filter == "*.*" {
+            return true;
+        }
+
+        if filter.starts_with('*') {
+            return file_name.ends_with(&filter[1..]);
+        }
+
+        file_name == filter
+    })
+}
+
+// Lists the sorted names of all files in the directory that match the filters.
+fn list_files(directory: &str, filters: &[String]) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_file())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| matches_filters(name, filters))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+    files
+}
+
+// Joins a relative file name with the directory. Absolute paths are returned unchanged.
+fn resolve_path(directory: &str, file_name: &str) -> PathBuf {
+    let path = Path::new(file_name);
+
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    Path::new(directory).join(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_filters() {
+        assert!(matches_filters("main.rs", &[]));
+        assert!(matches_filters("main.rs", &["*.rs".to_string()]));
+        assert!(matches_filters("main.rs", &["*".to_string()]));
+        assert!(matches_filters("Cargo.toml", &["Cargo.toml".to_string()]));
+        assert!(!matches_filters(
+            "main.rs",
+            &["*.txt".to_string(), "*.md".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        assert_eq!(
+            resolve_path("/home/user", "file.txt"),
+            PathBuf::from("/home/user/file.txt")
+        );
+        assert_eq!(
+            resolve_path("/home/user", "/tmp/file.txt"),
+            PathBuf::from("/tmp/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title(FileDialogMode::Open), "Open file");
+        assert_eq!(title(FileDialogMode::Save), "Save file");
+    }
+}
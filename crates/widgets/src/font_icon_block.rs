@@ -1,13 +1,75 @@
-use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::CONSOLE, theme::prelude::*};
+
+/// The `FontIconBlockState` resolves the `icon_name` of the `FontIconBlock` to its glyph.
+#[derive(Default, AsAny)]
+pub struct FontIconBlockState {
+    icon_name: String,
+    icon_font: String,
+}
+
+impl FontIconBlockState {
+    // Looks up the glyph in the registered icon fonts if the name or the font has been changed.
+    fn update_icon(&mut self, ctx: &mut Context) {
+        let icon_name = ctx.widget().clone::<String>("icon_name");
+        let icon_font = ctx.widget().clone::<String>("icon_font");
+
+        if icon_name.is_empty() || (icon_name == self.icon_name && icon_font == self.icon_font) {
+            return;
+        }
+
+        match icon_glyph(&icon_font, &icon_name) {
+            Some(glyph) => ctx.widget().set("icon", glyph),
+            None => CONSOLE.log(format!(
+                "FontIconBlock: icon {} not found in icon font {}",
+                icon_name, icon_font
+            )),
+        }
+
+        self.icon_name = icon_name;
+        self.icon_font = icon_font;
+    }
+}
+
+impl State for FontIconBlockState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_icon(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_icon(ctx);
+    }
+}
 
 widget!(
-    /// The `FontIconBlock` widget is used to draw text. It is not interactive.
+    /// The `FontIconBlock` widget is used to draw an icon of an icon font. It is not interactive.
+    ///
+    /// The icon is set by its glyph with `icon` or by its name with `icon_name`. Names are looked up in the manifest of
+    /// the `icon_font` that is registered by `register_icon_font` or `load_icon_font`, the manifest of
+    /// `MaterialIcons-Regular` is registered by default. The size and the color of the icon are set by `icon_size` and
+    /// `icon_brush`.
     ///
     /// **style:** `font-icon-block`
-    FontIconBlock {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// FontIconBlock::new()
+    ///     .icon(material_icons_font::MD_SAVE)
+    ///     .build(ctx)
+    ///
+    /// FontIconBlock::new()
+    ///     .icon_name("arrow_drop_down")
+    ///     .icon_size(32.0)
+    ///     .icon_brush("#f8de4c")
+    ///     .build(ctx)
+    /// ```
+    FontIconBlock<FontIconBlockState> {
         /// Sets or shares the icon property.
         icon: String,
 
+        /// Sets or shares the name of the icon in the manifest of the `icon_font`. If it is set it replaces `icon`.
+        icon_name: String,
+
         /// Sets or shares the icon brush property.
         icon_brush: Brush,
 
@@ -24,6 +86,7 @@ impl Template for FontIconBlock {
         self.name("FontIconBlock")
             .style("font-icon-block")
             .icon("")
+            .icon_name("")
             .icon_brush(colors::LINK_WATER_COLOR)
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_font("MaterialIcons-Regular")
@@ -0,0 +1,109 @@
+use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_GAUGE: &str = "gauge";
+
+// --- KEYS --
+
+widget!(
+    /// The `Gauge` widget shows a value on a circular arc from `min` to `max`, e.g. for dashboards and monitoring
+    /// applications. The value is drawn as filled arc or, if `needle` is `true`, as needle.
+    ///
+    /// The arc starts at `start_angle` and runs clockwise by `sweep_angle`, both in degrees where `0` points to the
+    /// right. Color `zones` mark ranges of values inside of the track, the indicator takes the brush of the zone that
+    /// contains the value.
+    ///
+    /// **style:** `gauge`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Gauge::new()
+    ///     .val(72.0)
+    ///     .max(100.0)
+    ///     .zones(vec![
+    ///         GaugeZone::new(60.0, 80.0, "#ffc000"),
+    ///         GaugeZone::new(80.0, 100.0, "#e05f8a"),
+    ///     ])
+    ///     .build(ctx)
+    /// ```
+    Gauge {
+        /// Sets or shares the current value.
+        val: f64,
+
+        /// Sets or shares the value at the start of the arc.
+        min: f64,
+
+        /// Sets or shares the value at the end of the arc.
+        max: f64,
+
+        /// Sets or shares the angle in degrees where the arc starts.
+        start_angle: f64,
+
+        /// Sets or shares the angle in degrees of the arc, negative values run counterclockwise.
+        sweep_angle: f64,
+
+        /// Sets or shares the width of the arc.
+        thickness: f64,
+
+        /// Sets or shares the flag if the value is shown as needle instead of a filled arc.
+        needle: bool,
+
+        /// Sets or shares the color zones.
+        zones: Vec<GaugeZone>,
+
+        /// Sets or shares the flag if the value is shown as text.
+        show_value: bool,
+
+        /// Sets or shares the number of decimals of the value text.
+        decimals: usize,
+
+        /// Sets or shares the foreground of the value text.
+        foreground: Brush,
+
+        /// Sets or shares the brush of the track.
+        track_brush: Brush,
+
+        /// Sets or shares the brush of the filled arc or the needle.
+        indicator_brush: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for Gauge {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Gauge")
+            .style(STYLE_GAUGE)
+            .val(0.0)
+            .min(0.0)
+            .max(1.0)
+            .start_angle(135.0)
+            .sweep_angle(270.0)
+            .thickness(12.0)
+            .needle(false)
+            .zones(vec![])
+            .show_value(true)
+            .decimals(0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .track_brush(colors::LYNCH_COLOR)
+            .indicator_brush("#efd035")
+            .font_size(16.0)
+            .font("Roboto-Regular")
+            .padding(4.0)
+            .width(128.0)
+            .height(128.0)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(GaugeRenderObject)
+    }
+}
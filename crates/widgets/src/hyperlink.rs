@@ -0,0 +1,220 @@
+use super::behaviors::MouseBehavior;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_HYPERLINK: &str = "hyperlink";
+static ID_UNDERLINE: &str = "id_underline";
+
+// --- KEYS --
+
+/// The `HyperlinkState` handles the hover and the navigation of the `Hyperlink` widget.
+#[derive(Default, AsAny)]
+pub struct HyperlinkState {
+    navigate: bool,
+    hovered: bool,
+    underline: Entity,
+}
+
+impl HyperlinkState {
+    fn navigate(&mut self) {
+        self.navigate = true;
+    }
+
+    // Raises the navigate event and opens the url in the browser if `open_in_browser` is set.
+    fn handle_navigate(&mut self, ctx: &mut Context) {
+        if !self.navigate {
+            return;
+        }
+
+        self.navigate = false;
+
+        let url = ctx.widget().clone::<String>("url");
+
+        if url.is_empty() {
+            return;
+        }
+
+        ctx.push_event_strategy_by_entity(
+            NavigateEvent(ctx.entity, url.clone()),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+
+        if !*ctx.widget().get::<bool>("visited") {
+            ctx.widget().set("visited", true);
+            toggle_flag("visited", &mut ctx.widget());
+            ctx.widget().update(false);
+        }
+
+        if *ctx.widget().get::<bool>("open_in_browser") {
+            if let Err(e) = open_url(&url) {
+                CONSOLE.log(format!("Hyperlink: {}", e));
+            }
+        }
+    }
+
+    // Shows the underline while the mouse is over the link.
+    fn update_hover(&mut self, ctx: &mut Context) {
+        let mouse_position = ctx.window().get::<Global>("global").mouse_position;
+        let hovered = *ctx.widget().get::<bool>("enabled")
+            && check_mouse_condition(mouse_position, &ctx.widget());
+
+        if hovered == self.hovered {
+            return;
+        }
+
+        self.hovered = hovered;
+
+        ctx.widget().set("hover", hovered);
+        toggle_flag("hover", &mut ctx.widget());
+        ctx.widget().update(false);
+
+        ctx.get_widget(self.underline).set(
+            "visibility",
+            if hovered {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },
+        );
+    }
+}
+
+impl State for HyperlinkState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.underline = ctx
+            .entity_of_child(ID_UNDERLINE)
+            .expect("HyperlinkState.init: underline child could not be found.");
+
+        toggle_flag("visited", &mut ctx.widget());
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.handle_navigate(ctx);
+        self.update_hover(ctx);
+    }
+}
+
+widget!(
+    /// The `Hyperlink` widget shows a text that is styled like a link. On hover the text is underlined and the
+    /// mouse cursor changes to a pointer.
+    ///
+    /// A click raises a `NavigateEvent` with the `url`, use `on_navigate` for navigation inside of the
+    /// application. If `open_in_browser` is `true` (default) the url is opened in the browser of the system too. After
+    /// the first click the link is marked as `visited`.
+    ///
+    /// **style:** `hyperlink`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Hyperlink::new()
+    ///     .text("OrbTk on GitHub")
+    ///     .url("https://github.com/redox-os/orbtk")
+    ///     .build(ctx)
+    ///
+    /// Hyperlink::new()
+    ///     .text("Settings")
+    ///     .url("app://settings")
+    ///     .open_in_browser(false)
+    ///     .on_navigate(|_, _, url| println!("Navigate to {}", url))
+    ///     .build(ctx)
+    /// ```
+    Hyperlink<HyperlinkState>: MouseHandler, NavigateHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the url of the link.
+        url: String,
+
+        /// Sets or shares the flag if the url is opened in the browser of the system on click.
+        open_in_browser: bool,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Gets the flag if the mouse is over the link.
+        hover: bool,
+
+        /// Sets or shares the flag if the link has been opened. It sets the `visited` state that is used by the theme.
+        visited: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
+    }
+);
+
+impl Template for Hyperlink {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("Hyperlink")
+            .style(STYLE_HYPERLINK)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
+            .cursor(CursorIcon::Pointer)
+            .text("")
+            .url("")
+            .open_in_browser(true)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .pressed(false)
+            .hover(false)
+            .visited(false)
+            .on_click(move |states, _| {
+                states.get_mut::<HyperlinkState>(id).navigate();
+                true
+            })
+            .on_mouse_move(move |states, _| {
+                // marks the link as dirty to update the hover
+                states.get_mut::<HyperlinkState>(id);
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Stack::new()
+                            .h_align("start")
+                            .child(
+                                TextBlock::new()
+                                    .text(id)
+                                    .foreground(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .build(ctx),
+                            )
+                            .child(
+                                Container::new()
+                                    .id(ID_UNDERLINE)
+                                    .height(1.0)
+                                    .background(("foreground", id))
+                                    .visibility(Visibility::Hidden)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
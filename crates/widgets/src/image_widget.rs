@@ -1,23 +1,206 @@
-use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
+use crate::{api::prelude::*, proc_macros::*, render::prelude::*, shell::prelude::*};
+
+/// The delay in milliseconds of animation frames without a delay or with a very short delay, like browsers do.
+pub const DEFAULT_FRAME_DELAY: f64 = 100.0;
+
+/// The `ImageWidgetState` plays the `animation` of the `ImageWidget`.
+#[derive(Default, AsAny)]
+pub struct ImageWidgetState {
+    animation: Option<AnimatedImage>,
+    frame: usize,
+    next_frame: Option<f64>,
+    finished: bool,
+}
+
+impl ImageWidgetState {
+    // Shows the first frame if the animation has been changed.
+    fn update_animation(&mut self, ctx: &mut Context) {
+        let animation = {
+            let widget = ctx.widget();
+            match widget.try_get::<AnimatedImage>("animation") {
+                Some(animation)
+                    if !animation.is_empty() && self.animation.as_ref() != Some(animation) =>
+                {
+                    animation.clone()
+                }
+                _ => return,
+            }
+        };
+
+        self.frame = 0;
+        self.next_frame = None;
+        self.finished = false;
+        ctx.widget()
+            .set("image", animation.frames()[0].image.clone());
+        self.animation = Some(animation);
+    }
+
+    fn show_frame(&mut self, ctx: &mut Context, frame: usize) {
+        self.frame = frame;
+
+        if let Some(animation) = &self.animation {
+            ctx.widget()
+                .set("image", animation.frames()[frame].image.clone());
+        }
+    }
+
+    fn animate(&mut self, ctx: &mut Context) {
+        let count = match &self.animation {
+            Some(animation) if animation.len() > 1 => animation.len(),
+            _ => return,
+        };
+
+        if !*ctx.widget().get::<bool>("play") {
+            // a paused animation continues with the current frame
+            self.next_frame = None;
+            return;
+        }
+
+        // a finished animation starts again if it is played
+        if self.finished {
+            self.finished = false;
+            self.show_frame(ctx, 0);
+        }
+
+        let time = now();
+        let next_frame = match self.next_frame {
+            Some(next_frame) => next_frame,
+            None => time + self.delay(),
+        };
+
+        if time < next_frame {
+            self.next_frame = Some(next_frame);
+            ctx.request_next_frame();
+            return;
+        }
+
+        match next_frame_index(self.frame, count, *ctx.widget().get::<bool>("looping")) {
+            Some(frame) => {
+                self.show_frame(ctx, frame);
+                self.next_frame = Some(time + self.delay());
+                ctx.request_next_frame();
+            }
+            None => {
+                // the last frame is kept at the end of the animation
+                self.next_frame = None;
+                self.finished = true;
+                ctx.widget().set("play", false);
+            }
+        }
+    }
+
+    fn delay(&self) -> f64 {
+        let delay = self
+            .animation
+            .as_ref()
+            .and_then(|animation| animation.frames().get(self.frame))
+            .map(|frame| frame.delay)
+            .unwrap_or_default();
+
+        frame_delay(delay)
+    }
+}
+
+impl State for ImageWidgetState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_animation(ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_animation(ctx);
+        self.animate(ctx);
+    }
+}
 
 widget!(
     /// The `ImageWidget` widget is used to draw an image. It is not interactive.
     ///
+    /// With the `stretch` property the image is resized to the bounds of the widget, the image is aligned inside of
+    /// the bounds by `image_h_align` and `image_v_align`. Parts of the image outside of the bounds are clipped.
+    ///
+    /// An animated GIF or PNG (APNG) is played by setting the `animation` property. The animation is paused by
+    /// setting `play` to `false`. Without `looping` the animation stops at its last frame and `play` is set to
+    /// `false`.
+    ///
+    /// A SVG image is set by the `vector_image` property. It is drawn instead of `image` and stays crisp at any size and
+    /// scale factor.
+    ///
     /// **style:** `image-widget`
-    ImageWidget {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ImageWidget::new()
+    ///     .image("res/orbtk-space.png")
+    ///     .width(200.0)
+    ///     .height(100.0)
+    ///     .stretch("uniform_to_fill")
+    ///     .image_v_align("start")
+    ///     .build(ctx)
+    ///
+    /// ImageWidget::new()
+    ///     .animation("res/loading.gif")
+    ///     .looping(false)
+    ///     .build(ctx)
+    ///
+    /// ImageWidget::new()
+    ///     .vector_image("res/icon.svg")
+    ///     .width(48.0)
+    ///     .height(48.0)
+    ///     .stretch("uniform")
+    ///     .build(ctx)
+    /// ```
+    ImageWidget<ImageWidgetState> {
         /// Sets or shares the image property.
         ///
         /// Set image property:
         /// * &str: `Image::new().image("path/to/image.png").build(xt)`
         /// * String: `Image::new().image(String::from()).build(xt)`
         /// * (width: u32, height: u32, data: Vec<u32>): `Image::new().image((width, height, vec![0; width * height]));`
-        image: Image
+        image: Image,
+
+        /// Sets or shares the animation. The current frame of the animation is shown as `image`.
+        ///
+        /// Set animation property:
+        /// * &str: `ImageWidget::new().animation("path/to/image.gif").build(ctx)`
+        /// * Vec<ImageFrame>: `ImageWidget::new().animation(frames).build(ctx)`
+        animation: AnimatedImage,
+
+        /// Sets or shares the vector image. It is drawn instead of `image` if it is not empty.
+        ///
+        /// Set vector image property:
+        /// * &str: `ImageWidget::new().vector_image("path/to/image.svg").build(ctx)`
+        /// * &str: `ImageWidget::new().vector_image("<svg viewBox=\"0 0 24 24\">...</svg>").build(ctx)`
+        vector_image: VectorImage,
+
+        /// Sets or shares the flag if the animation is playing. Set it to `false` to pause the animation.
+        play: bool,
+
+        /// Sets or shares the flag if the animation starts again after the last frame.
+        looping: bool,
+
+        /// Sets or shares how the image is resized to the bounds of the widget. The default is `None`.
+        stretch: Stretch,
+
+        /// Sets or shares the horizontal alignment of the image inside of the bounds. The default is `Center`.
+        image_h_align: Alignment,
+
+        /// Sets or shares the vertical alignment of the image inside of the bounds. The default is `Center`.
+        image_v_align: Alignment
     }
 );
 
 impl Template for ImageWidget {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("ImageWidget").style("image-widget").image("")
+        self.name("ImageWidget")
+            .style("image-widget")
+            .image(Image::default())
+            .play(true)
+            .looping(true)
+            .stretch(Stretch::None)
+            .image_h_align("center")
+            .image_v_align("center")
+            .clip(true)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -28,3 +211,48 @@ impl Template for ImageWidget {
         Box::new(FixedSizeLayout::new())
     }
 }
+
+// --- Helpers --
+
+// Returns the index of the frame after the given frame, `None` at the end of an animation without looping.
+fn next_frame_index(frame: usize, count: usize, looping: bool) -> Option<usize> {
+    if frame + 1 < count {
+        Some(frame + 1)
+    } else if looping && count > 0 {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+// Returns the time in milliseconds a frame with the given delay is shown.
+fn frame_delay(delay: f64) -> f64 {
+    if delay < 20.0 {
+        DEFAULT_FRAME_DELAY
+    } else {
+        delay
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_frame_index() {
+        assert_eq!(next_frame_index(0, 3, false), Some(1));
+        assert_eq!(next_frame_index(1, 3, false), Some(2));
+        assert_eq!(next_frame_index(2, 3, false), None);
+        assert_eq!(next_frame_index(2, 3, true), Some(0));
+        assert_eq!(next_frame_index(0, 0, true), None);
+    }
+
+    #[test]
+    fn test_frame_delay() {
+        assert!((frame_delay(0.0) - DEFAULT_FRAME_DELAY).abs() < f64::EPSILON);
+        assert!((frame_delay(10.0) - DEFAULT_FRAME_DELAY).abs() < f64::EPSILON);
+        assert!((frame_delay(40.0) - 40.0).abs() < f64::EPSILON);
+    }
+}
@@ -3,42 +3,128 @@ use crate::{api::prelude::*, proc_macros::*, theme::prelude::*, Stack};
 #[derive(Default, AsAny)]
 pub struct ItemsWidgetState {
     builder: WidgetBuildContext,
+    items_source: ItemsSourceContext,
+    version: Option<usize>,
     count: usize,
+    items_panel: Entity,
+    items: Vec<Entity>,
 }
 
 impl ItemsWidgetState {
     fn generate_items(&mut self, ctx: &mut Context) {
-        let count: usize = ctx.widget().clone_or_default("count");
-        let request_update: bool = *ctx.widget().get("request_update");
+        let request_update = ctx.widget().clone_or_default::<bool>("request_update");
 
-        if count != self.count || request_update {
-            if let Some(builder) = &self.builder {
-                if let Some(items_panel) = ctx.entity_of_child("items_panel") {
-                    ctx.clear_children_of(items_panel);
+        if request_update {
+            ctx.widget().set("request_update", false);
+        }
+
+        let source_changes = if let Some(items_source) = &self.items_source {
+            items_source(ctx, self.version.unwrap_or_default())
+        } else {
+            None
+        };
 
-                    for i in 0..count {
-                        let bctx = &mut ctx.build_context();
+        if let Some(source_changes) = source_changes {
+            if ctx.widget().clone_or_default::<usize>("count") != source_changes.count {
+                ctx.widget().set("count", source_changes.count);
+            }
+
+            match source_changes.changes {
+                // a reset is handled by rebuilding all items with the current count
+                Some(changes)
+                    if !request_update
+                        && self.version.is_some()
+                        && !changes.contains(&ItemsChange::Reset) =>
+                {
+                    if changes.is_empty() {
+                        return;
+                    }
 
-                        let child = builder(bctx, i);
-                        bctx.append_child(items_panel, child);
+                    for change in changes {
+                        self.apply_change(change, ctx);
                     }
                 }
+                _ => self.rebuild(source_changes.count, ctx),
             }
 
+            // the new items are measured on the next run
+            ctx.request_next_frame();
+
+            self.count = source_changes.count;
+            self.version = Some(source_changes.version);
+            return;
+        }
+
+        let count: usize = ctx.widget().clone_or_default("count");
+
+        if count != self.count || request_update {
+            self.rebuild(count, ctx);
             self.count = count;
-            ctx.widget().set("request_update", false);
+        }
+    }
+
+    fn rebuild(&mut self, count: usize, ctx: &mut Context) {
+        ctx.clear_children_of(self.items_panel);
+        self.items.clear();
+
+        for i in 0..count {
+            self.insert_item(i, ctx);
+        }
+    }
+
+    fn apply_change(&mut self, change: ItemsChange, ctx: &mut Context) {
+        match change {
+            ItemsChange::Insert(index) => self.insert_item(index, ctx),
+            ItemsChange::Remove(index) => self.remove_item(index, ctx),
+            ItemsChange::Update(index) => {
+                self.remove_item(index, ctx);
+                self.insert_item(index, ctx);
+            }
+            ItemsChange::Reset => {}
+        }
+    }
+
+    fn insert_item(&mut self, index: usize, ctx: &mut Context) {
+        if let Some(builder) = &self.builder {
+            let child = {
+                let bctx = &mut ctx.build_context();
+
+                let child = builder(bctx, index);
+                bctx.append_child(self.items_panel, child);
+                child
+            };
+
+            ctx.move_child_to_index(child, self.items_panel, index);
+            self.items.insert(index.min(self.items.len()), child);
+        }
+    }
+
+    fn remove_item(&mut self, index: usize, ctx: &mut Context) {
+        if index < self.items.len() {
+            let child = self.items.remove(index);
+            ctx.remove_child_from(child, self.items_panel);
         }
     }
 }
 
 impl State for ItemsWidgetState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child("items_panel")
+            .expect("ItemsWidgetState.init: ItemsPanel child could not be found.");
         self.generate_items(ctx);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.generate_items(ctx);
     }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // changes of a bound items source are not marking this widget as dirty
+        if self.items_source.is_some() {
+            self.generate_items(ctx);
+        }
+    }
 }
 
 widget!(
@@ -50,7 +136,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -73,6 +159,18 @@ widget!(
 );
 
 impl ItemsWidget {
+    /// Binds the items to the `ItemsSource<T>` property with the given key of the source widget. If the source
+    /// is changed only the affected items are updated. The count of the items widget is taken from the source.
+    pub fn items_source<T: Component>(mut self, source: Entity, key: impl Into<String>) -> Self {
+        let key = key.into();
+        self.state_mut().items_source = Some(Box::new(move |ctx, version| {
+            ctx.get_widget(source)
+                .try_get::<ItemsSource<T>>(&key)
+                .map(|items_source| items_source.changes(version))
+        }));
+        self
+    }
+
     pub fn items_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
         mut self,
         builder: F,
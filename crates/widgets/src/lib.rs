@@ -10,57 +10,123 @@ pub(crate) use orbtk_render as render;
 pub(crate) use orbtk_shell as shell;
 pub(crate) use orbtk_theme as theme;
 
+pub use self::auto_complete_box::*;
+pub use self::badge::*;
+pub use self::breadcrumb_bar::*;
 pub use self::button::*;
+pub use self::calendar::*;
 pub use self::canvas::*;
+pub use self::canvas_panel::*;
+pub use self::card::*;
+pub use self::charts::*;
 pub use self::check_box::*;
+pub use self::chip::*;
+pub use self::code_editor::*;
 pub use self::combo_box::*;
 pub use self::container::*;
+pub use self::context_menu::*;
 pub use self::cursor::*;
+pub use self::date_picker::*;
+pub use self::drag_ghost::*;
+pub use self::drag_source::*;
+pub use self::expander::*;
+pub use self::file_dialog::*;
 pub use self::font_icon_block::*;
+pub use self::gauge::*;
 pub use self::grid::*;
+pub use self::hyperlink::*;
 pub use self::image_widget::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
+pub use self::markdown_view::*;
+pub use self::menu::*;
+pub use self::menu_bar::*;
 pub use self::numeric_box::*;
 pub use self::password_box::*;
 pub use self::popup::*;
 pub use self::progress_bar::*;
+pub use self::radio_button::*;
+pub use self::range_slider::*;
+pub use self::rich_text_block::*;
 pub use self::scroll_bar::*;
 pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
 pub use self::slider::*;
+pub use self::splitter::*;
 pub use self::stack::*;
 pub use self::switch::*;
 pub use self::tab_widget::*;
+pub use self::table_view::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
+pub use self::tick_bar::*;
+pub use self::time_picker::*;
+pub use self::toast::*;
 pub use self::toggle_button::*;
+pub use self::tool_tip::*;
+pub use self::tree_view::*;
 pub use self::window::*;
+pub use self::window_title_bar::*;
+pub use self::wizard::*;
+pub use self::wrap_panel::*;
 
 pub mod behaviors;
+mod auto_complete_box;
+mod badge;
+mod breadcrumb_bar;
 mod button;
+mod calendar;
 mod canvas;
+mod canvas_panel;
+mod card;
+mod charts;
 mod check_box;
+mod chip;
+mod code_editor;
 mod combo_box;
 mod container;
+mod context_menu;
 mod cursor;
+mod date_picker;
+mod drag_ghost;
+mod drag_source;
+mod expander;
+mod file_dialog;
 mod font_icon_block;
+mod gauge;
 mod grid;
+mod hyperlink;
 mod image_widget;
 mod items_widget;
 mod list_view;
+mod markdown_view;
+mod menu;
+mod menu_bar;
 mod numeric_box;
 mod password_box;
 mod popup;
 mod progress_bar;
+mod radio_button;
+mod range_slider;
+mod rich_text_block;
 mod scroll_bar;
 mod scroll_indicator;
 mod scroll_viewer;
 mod slider;
+mod splitter;
 mod stack;
 mod switch;
 mod tab_widget;
+mod table_view;
 mod text_block;
 mod text_box;
+mod tick_bar;
+mod time_picker;
+mod toast;
 mod toggle_button;
+mod tool_tip;
+mod tree_view;
 mod window;
+mod window_title_bar;
+mod wizard;
+mod wrap_panel;
@@ -1,110 +1,561 @@
-use std::{
-    cell::Cell,
-    collections::{HashMap, HashSet},
-};
+use std::{cell::Cell, collections::HashSet};
 
 use super::behaviors::MouseBehavior;
-use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
 
 static ITEMS_PANEL: &str = "items_panel";
+static ITEMS_EXTENT: &str = "items_extent";
+static SCROLL_VIEWER: &str = "scroll_viewer";
+static INSERTION_INDICATOR: &str = "insertion_indicator";
+
+/// The number of items that are realized before and after the visible items of a virtualized `ListView`.
+pub const LIST_VIEW_ITEMS_BUFFER: usize = 4;
+
+// The height of the line that shows where a dragged item is inserted.
+const INSERTION_INDICATOR_HEIGHT: f64 = 2.0;
+
+// The drag data of the drag handle of an item in a reorderable list.
+#[derive(Copy, Clone, Debug)]
+struct ListViewItemDrag {
+    list: Entity,
+    item: Entity,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ListViewAction {
+    DragOver(Point),
+    DragLeave,
+    Drop(Entity, Point),
+    Key(Key),
+    Focus,
+}
+
+// Describes an item widget that is realized for the given index.
+#[derive(Copy, Clone)]
+struct RealizedItem {
+    index: usize,
+    item: Entity,
+    content_parent: Entity,
+}
 
 /// The `ListViewState` generates the list box items and handles the selected indices.
+///
+/// Only the items inside of the visible area (and a small buffer) are realized. If the list is scrolled the item
+/// widgets that are moved out of the visible area are recycled for the items that become visible.
 #[derive(Default, AsAny)]
 pub struct ListViewState {
+    actions: Vec<ListViewAction>,
     builder: WidgetBuildContext,
+    items_source: ItemsSourceContext,
+    version: Option<usize>,
     count: usize,
-    selected_entities: RefCell<HashSet<Entity>>,
+    range: (usize, usize),
+    realized: Vec<RealizedItem>,
+    pool: Vec<RealizedItem>,
     items_panel: Entity,
+    items_extent: Entity,
+    scroll_viewer: Entity,
+    insertion_indicator: Entity,
+    reorderable: bool,
 }
 
 impl ListViewState {
+    fn action(&mut self, action: ListViewAction) {
+        self.actions.push(action);
+    }
+
     fn generate_items(&mut self, ctx: &mut Context) {
-        let count = ctx.widget().clone_or_default::<usize>("count");
-        let entity = ctx.entity;
+        let request_update = ctx.widget().clone_or_default::<bool>("request_update");
 
-        if count != self.count || *ctx.widget().get::<bool>("request_update") {
+        if request_update {
             ctx.widget().set("request_update", false);
-            if let Some(builder) = &self.builder {
-                ctx.clear_children_of(self.items_panel);
-
-                for i in 0..count {
-                    let item = {
-                        let build_context = &mut ctx.build_context();
-                        let child = builder(build_context, i);
-                        let item = ListViewItem::new().parent(entity.0).build(build_context);
-
-                        let mouse_behavior =
-                            MouseBehavior::new().target(item.0).build(build_context);
-                        build_context.register_shared_property::<Selector>(
-                            "selector",
-                            mouse_behavior,
-                            item,
-                        );
-                        build_context.register_shared_property::<bool>(
-                            "pressed",
-                            mouse_behavior,
-                            item,
-                        );
-                        build_context.append_child(item, mouse_behavior);
-
-                        build_context.register_shared_property::<Brush>("foreground", child, item);
-                        build_context.register_shared_property::<f32>("opacity", item, entity);
-                        build_context.register_shared_property::<f32>("opacity", child, entity);
-                        build_context.register_shared_property::<f64>("font_size", child, item);
-                        build_context.append_child(self.items_panel, item);
-                        build_context.append_child(mouse_behavior, child);
-
-                        item
-                    };
-                    ctx.get_widget(item).update_widget(entity, false, false);
+        }
+
+        let source_changes = if let Some(items_source) = &self.items_source {
+            items_source(ctx, self.version.unwrap_or_default())
+        } else {
+            None
+        };
+
+        if let Some(source_changes) = source_changes {
+            if ctx.widget().clone_or_default::<usize>("count") != source_changes.count {
+                ctx.widget().set("count", source_changes.count);
+            }
+
+            match source_changes.changes {
+                Some(changes) if !request_update && self.version.is_some() => {
+                    if !changes.is_empty() {
+                        self.apply_changes(&changes, ctx);
+                        self.count = source_changes.count;
+                        self.update_extent(ctx);
+
+                        let range = self.visible_range(ctx);
+                        self.realize(range, ctx);
+                        ctx.request_next_frame();
+                    }
                 }
+                _ => self.reset(source_changes.count, ctx),
+            }
+
+            self.version = Some(source_changes.version);
+        } else {
+            let count = ctx.widget().clone_or_default::<usize>("count");
+
+            if count != self.count || request_update {
+                self.reset(count, ctx);
             }
+        }
+
+        // the items are created again with or without drag handle
+        let reorderable = *ctx.widget().get::<bool>("reorderable");
+
+        if reorderable != self.reorderable {
+            self.reorderable = reorderable;
+            self.reset(self.count, ctx);
+        }
 
-            self.count = count;
+        let range = self.visible_range(ctx);
+
+        if range != self.range {
+            self.realize(range, ctx);
+
+            // the new items are measured on the next run
+            ctx.request_next_frame();
         }
     }
-}
 
-impl State for ListViewState {
-    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
-        self.items_panel = ctx
-            .entity_of_child(ITEMS_PANEL)
-            .expect("ListViewState.init: ItemsPanel child could not be found.");
+    // removes all items. They are generated again on the next call of realize.
+    fn reset(&mut self, count: usize, ctx: &mut Context) {
+        ctx.clear_children_of(self.items_panel);
+        self.realized.clear();
+        self.pool.clear();
+        self.range = (0, 0);
+        self.count = count;
+        self.update_extent(ctx);
+    }
 
-        self.generate_items(ctx);
+    // applies the changes of the items source to the realized items and the selected indices.
+    fn apply_changes(&mut self, changes: &[ItemsChange], ctx: &mut Context) {
+        for change in changes {
+            let (removed, realized): (Vec<RealizedItem>, Vec<RealizedItem>) =
+                self.realized.drain(..).partition(|r| match *change {
+                    ItemsChange::Remove(index) | ItemsChange::Update(index) => r.index == index,
+                    ItemsChange::Reset => true,
+                    ItemsChange::Insert(_) => false,
+                });
+
+            self.realized = realized;
+
+            for item in removed {
+                self.recycle(item, ctx);
+            }
+
+            for item in &mut self.realized {
+                item.index = shift_index(item.index, *change).unwrap_or(item.index);
+            }
+
+            let old_indices = ctx
+                .widget()
+                .get::<SelectedIndices>("selected_indices")
+                .0
+                .clone();
+            let selected_indices = old_indices
+                .iter()
+                .filter_map(|i| shift_index(*i, *change))
+                .collect::<HashSet<usize>>();
+
+            if selected_indices != old_indices {
+                ctx.widget()
+                    .set("selected_indices", SelectedIndices(selected_indices));
+            }
+        }
     }
 
-    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        for index in ctx
-            .widget()
-            .get::<SelectedEntities>("selected_entities")
-            .0
-            .clone()
-            .symmetric_difference(&*self.selected_entities.borrow())
-        {
-            let mut widget = ctx.get_widget(*index);
+    fn recycle(&mut self, item: RealizedItem, ctx: &mut Context) {
+        ctx.get_widget(item.item)
+            .set("visibility", Visibility::Collapsed);
+        self.pool.push(item);
+    }
+
+    fn is_virtualized(ctx: &mut Context) -> bool {
+        *ctx.widget().get::<Orientation>("orientation") == Orientation::Vertical
+    }
+
+    // calculates the scroll extent of the items panel from the item count and the item height.
+    fn update_extent(&self, ctx: &mut Context) {
+        let extent = if Self::is_virtualized(ctx) {
+            self.count as f64 * *ctx.widget().get::<f64>("item_height")
+        } else {
+            0.0
+        };
+
+        let mut constraint = *ctx
+            .get_widget(self.items_extent)
+            .get::<Constraint>("constraint");
+        constraint.set_height(extent);
+        ctx.get_widget(self.items_extent)
+            .set("constraint", constraint);
+    }
+
+    fn visible_range(&self, ctx: &mut Context) -> (usize, usize) {
+        if !Self::is_virtualized(ctx) {
+            return (0, self.count);
+        }
+
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let scroll_viewer = ctx.get_widget(self.scroll_viewer);
+        let offset = -scroll_viewer.get::<Thickness>("padding").top();
+        let view_port = scroll_viewer.get::<Rectangle>("bounds").height();
 
-            if !widget.has::<bool>("selected") {
+        visible_range(
+            offset,
+            view_port,
+            item_height,
+            self.count,
+            LIST_VIEW_ITEMS_BUFFER,
+        )
+    }
+
+    // realizes the items of the given range. Items outside of the range are moved to the pool and reused.
+    fn realize(&mut self, range: (usize, usize), ctx: &mut Context) {
+        let (recycled, realized): (Vec<RealizedItem>, Vec<RealizedItem>) = self
+            .realized
+            .drain(..)
+            .partition(|r| r.index < range.0 || r.index >= range.1);
+
+        for item in recycled {
+            self.recycle(item, ctx);
+        }
+
+        self.realized = realized;
+
+        for index in range.0..range.1 {
+            if self.realized.iter().any(|r| r.index == index) {
                 continue;
             }
 
-            let selected = !widget.get::<bool>("selected");
-            widget.set("selected", selected);
+            let mut item = if let Some(item) = self.pool.pop() {
+                ctx.clear_children_of(item.content_parent);
+                ctx.get_widget(item.item)
+                    .set("visibility", Visibility::Visible);
+                item
+            } else {
+                self.create_item(ctx)
+            };
+
+            item.index = index;
+            self.build_content(item, ctx);
+            self.realized.push(item);
+        }
+
+        self.realized.sort_by_key(|r| r.index);
+
+        for (i, item) in self.realized.iter().enumerate() {
+            ctx.move_child_to_index(item.item, self.items_panel, i);
+
+            let mut widget = ctx.get_widget(item.item);
+
+            if *widget.get::<usize>("index") != item.index {
+                widget.set("index", item.index);
+            }
+        }
+
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let offset = if Self::is_virtualized(ctx) {
+            range.0 as f64 * item_height
+        } else {
+            0.0
+        };
+        ctx.get_widget(self.items_panel)
+            .set("margin", Thickness::new(0.0, offset, 0.0, 0.0));
+
+        self.range = range;
+    }
+
+    fn create_item(&self, ctx: &mut Context) -> RealizedItem {
+        let entity = ctx.entity;
+        let virtualized = Self::is_virtualized(ctx);
+        let item_height = *ctx.widget().get::<f64>("item_height");
+
+        let build_context = &mut ctx.build_context();
+
+        let mut item = ListViewItem::new().parent(entity.0);
+
+        if virtualized {
+            item = item.height(item_height);
+        }
+
+        let item = item.build(build_context);
+
+        let mouse_behavior = MouseBehavior::new().target(item.0).build(build_context);
+        build_context.register_shared_property::<Selector>("selector", mouse_behavior, item);
+        build_context.register_shared_property::<bool>("pressed", mouse_behavior, item);
+        build_context.append_child(item, mouse_behavior);
+        build_context.register_shared_property::<f32>("opacity", item, entity);
+        build_context.append_child(self.items_panel, item);
+
+        // the content of a reorderable item is placed beside of its drag handle
+        let content_parent = if self.reorderable {
+            let handle_icon = FontIconBlock::new()
+                .v_align("center")
+                .margin((0.0, 0.0, 4.0, 0.0))
+                .icon(material_icons_font::MD_DRAG_INDICATOR)
+                .build(build_context);
+            build_context.register_shared_property_by_source_key::<Brush>(
+                "icon_brush",
+                "foreground",
+                handle_icon,
+                item,
+            );
+
+            let handle = DragSource::new()
+                .data(DragData::new(ListViewItemDrag { list: entity, item }))
+                .attach(Grid::column(0))
+                .child(handle_icon)
+                .build(build_context);
+            let content = Container::new()
+                .attach(Grid::column(1))
+                .build(build_context);
+            let grid = Grid::new()
+                .columns(Columns::create().push("auto").push("*"))
+                .child(handle)
+                .child(content)
+                .build(build_context);
+            build_context.append_child(mouse_behavior, grid);
+
+            content
+        } else {
+            mouse_behavior
+        };
+
+        RealizedItem {
+            index: 0,
+            item,
+            content_parent,
+        }
+    }
+
+    fn build_content(&self, item: RealizedItem, ctx: &mut Context) {
+        let entity = ctx.entity;
+        let selected = ctx
+            .widget()
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .contains(&item.index);
+
+        if let Some(builder) = &self.builder {
+            let build_context = &mut ctx.build_context();
+            let child = builder(build_context, item.index);
+
+            build_context.register_shared_property::<Brush>("foreground", child, item.item);
+            build_context.register_shared_property::<f32>("opacity", child, entity);
+            build_context.register_shared_property::<f64>("font_size", child, item.item);
+            build_context.append_child(item.content_parent, child);
+        }
+
+        let mut widget = ctx.get_widget(item.item);
+        widget.set("index", item.index);
+        select(&mut widget, selected);
+        widget.update_widget(entity, false, false);
+    }
+
+    // synchronizes the selected state of the realized items with the selected indices.
+    fn update_selection(&self, ctx: &mut Context) {
+        let selected_indices = ctx
+            .widget()
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .clone();
+        let mut selected_entities = HashSet::new();
+
+        for item in &self.realized {
+            let selected = selected_indices.contains(&item.index);
 
             if selected {
-                widget.get_mut::<Selector>("selector").set_state("selected");
-            } else {
-                widget.get_mut::<Selector>("selector").clear_state();
+                selected_entities.insert(item.item);
             }
 
-            widget.update(false);
+            let mut widget = ctx.get_widget(item.item);
+
+            if *widget.get::<bool>("selected") != selected {
+                select(&mut widget, selected);
+                widget.update(false);
+            }
         }
 
-        *self.selected_entities.borrow_mut() = ctx
+        if ctx.widget().get::<SelectedEntities>("selected_entities").0 != selected_entities {
+            ctx.widget()
+                .set("selected_entities", SelectedEntities(selected_entities));
+        }
+    }
+
+    // returns the index where a dragged item is inserted if it is dropped at the given position.
+    fn insertion_index(&self, position: Point, ctx: &mut Context) -> usize {
+        let top = ctx
+            .get_widget(self.items_extent)
+            .get::<Point>("position")
+            .y();
+        let item_height = *ctx.widget().get::<f64>("item_height");
+
+        insertion_index(position.y() - top, item_height, self.count)
+    }
+
+    // shows the insertion indicator above the item with the given index or hides it.
+    fn update_insertion_indicator(&self, index: Option<usize>, ctx: &mut Context) {
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let mut indicator = ctx.get_widget(self.insertion_indicator);
+
+        if let Some(index) = index {
+            let top = (index as f64 * item_height - INSERTION_INDICATOR_HEIGHT / 2.0).max(0.0);
+            indicator.set("margin", Thickness::new(0.0, top, 0.0, 0.0));
+            indicator.set("visibility", Visibility::Visible);
+        } else {
+            indicator.set("visibility", Visibility::Collapsed);
+        }
+    }
+
+    // request the keyboard focus for the list
+    fn request_focus(&self, ctx: &mut Context) {
+        if !*ctx.widget().get::<bool>("focused") {
+            ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+        }
+    }
+
+    // selects the item that is reached by the given key and scrolls it into view.
+    fn handle_key(&self, key: Key, ctx: &mut Context) {
+        if !*ctx.widget().get::<bool>("focused")
+            || *ctx.widget().get::<SelectionMode>("selection_mode") == SelectionMode::None
+        {
+            return;
+        }
+
+        let old_indices = ctx
             .widget()
-            .get::<SelectedEntities>("selected_entities")
+            .get::<SelectedIndices>("selected_indices")
             .0
             .clone();
+
+        // the keyboard moves from the last clicked item
+        let current = if old_indices.is_empty() {
+            None
+        } else {
+            Some(*ctx.widget().get::<usize>("selection_anchor"))
+        };
+
+        let index = match key_index(key, current, self.count) {
+            Some(index) => index,
+            None => return,
+        };
+
+        ctx.widget().set("selection_anchor", index);
+        self.scroll_into_view(index, ctx);
+
+        let new_indices: HashSet<usize> = [index].iter().copied().collect();
+
+        if new_indices == old_indices {
+            return;
+        }
+
+        let entity = ctx.entity;
+        let change = selection_change(&old_indices, &new_indices);
+        ctx.widget()
+            .set("selected_indices", SelectedIndices(new_indices));
+
+        ctx.push_event_strategy_by_entity(
+            SelectionChangedEvent(entity, change),
+            entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // scrolls the item with the given index into the view port. The item could be not realized yet, so its position
+    // is calculated from the item height.
+    fn scroll_into_view(&self, index: usize, ctx: &mut Context) {
+        if !Self::is_virtualized(ctx) {
+            return;
+        }
+
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let mut scroll_viewer = ctx.get_widget(self.scroll_viewer);
+        let view_port = scroll_viewer.get::<Rectangle>("bounds").height();
+        let offset = *scroll_viewer.get::<Point>("scroll_offset");
+
+        let new_offset = Point::new(
+            offset.x(),
+            scroll_into_view_offset(
+                offset.y(),
+                view_port,
+                index as f64 * item_height,
+                item_height,
+            ),
+        );
+
+        if new_offset != offset {
+            scroll_viewer.set("scroll_offset", new_offset);
+        }
+    }
+
+    // moves the dragged item to the drop position by an `ItemMovedEvent`, the data is moved by the handler.
+    fn drop_item(&self, item: Entity, position: Point, ctx: &mut Context) {
+        self.update_insertion_indicator(None, ctx);
+
+        // the item could be recycled if the list was scrolled during the drag
+        let from = match self.realized.iter().find(|r| r.item == item) {
+            Some(realized) => realized.index,
+            None => return,
+        };
+
+        if let Some(to) = move_target(from, self.insertion_index(position, ctx)) {
+            let entity = ctx.entity;
+            ctx.push_event_strategy_by_entity(
+                ItemMovedEvent(entity, from, to),
+                entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+}
+
+impl State for ListViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ITEMS_PANEL)
+            .expect("ListViewState.init: ItemsPanel child could not be found.");
+        self.items_extent = ctx
+            .entity_of_child(ITEMS_EXTENT)
+            .expect("ListViewState.init: ItemsExtent child could not be found.");
+        self.scroll_viewer = ctx
+            .entity_of_child(SCROLL_VIEWER)
+            .expect("ListViewState.init: ScrollViewer child could not be found.");
+        self.insertion_indicator = ctx
+            .entity_of_child(INSERTION_INDICATOR)
+            .expect("ListViewState.init: InsertionIndicator child could not be found.");
+        self.reorderable = *ctx.widget().get::<bool>("reorderable");
+
+        self.update_extent(ctx);
+        self.generate_items(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<ListViewAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                ListViewAction::DragOver(position) => {
+                    let index = self.insertion_index(position, ctx);
+                    self.update_insertion_indicator(Some(index), ctx);
+                }
+                ListViewAction::DragLeave => self.update_insertion_indicator(None, ctx),
+                ListViewAction::Drop(item, position) => self.drop_item(item, position, ctx),
+                ListViewAction::Key(key) => self.handle_key(key, ctx),
+                ListViewAction::Focus => self.request_focus(ctx),
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.generate_items(ctx);
+        self.update_selection(ctx);
     }
 }
 
@@ -127,67 +578,46 @@ impl State for ListViewItemState {
         }
         self.request_selection_toggle.set(false);
 
-        let selected = *ctx.widget().get::<bool>("selected");
-
-        let entity = ctx.entity;
-        let index = ctx.index_as_child(entity).unwrap();
-
+        let index = *ctx.widget().get::<usize>("index");
         let parent_entity: Entity = (*ctx.widget().get::<u32>("parent")).into();
 
+        let (ctrl_down, shift_down) = {
+            let window = ctx.window();
+            let keyboard_state = &window.get::<Global>("global").keyboard_state;
+            (
+                keyboard_state.is_ctrl_down(),
+                keyboard_state.is_shift_down(),
+            )
+        };
+
         let mut parent = ctx.get_widget(parent_entity);
 
         let selection_mode = *parent.get::<SelectionMode>("selection_mode");
-        // deselect item
-        if selected {
-            parent
-                .get_mut::<SelectedEntities>("selected_entities")
-                .0
-                .remove(&entity);
-            parent
-                .get_mut::<SelectedIndices>("selected_indices")
-                .0
-                .remove(&index);
-            return;
-        }
+        let anchor = *parent.get::<usize>("selection_anchor");
+        let old_indices = parent.get::<SelectedIndices>("selected_indices").0.clone();
+        let new_indices = click_selection(
+            selection_mode,
+            &old_indices,
+            anchor,
+            index,
+            ctrl_down,
+            shift_down,
+        );
 
-        if parent
-            .get::<SelectedEntities>("selected_entities")
-            .0
-            .contains(&entity)
-            || selection_mode == SelectionMode::None
-        {
-            return;
+        // a range selection keeps the anchor to expand or shrink the range by the next shift click
+        if selection_mode != SelectionMode::Extended || !shift_down {
+            parent.set("selection_anchor", index);
         }
 
-        if selection_mode == SelectionMode::Single {
-            parent
-                .get_mut::<SelectedEntities>("selected_entities")
-                .0
-                .clear();
-            parent
-                .get_mut::<SelectedIndices>("selected_indices")
-                .0
-                .clear();
+        if new_indices == old_indices {
+            return;
         }
 
-        parent
-            .get_mut::<SelectedEntities>("selected_entities")
-            .0
-            .insert(entity);
-        parent
-            .get_mut::<SelectedIndices>("selected_indices")
-            .0
-            .insert(index);
-
-        let selected_indices: Vec<usize> = parent
-            .get::<SelectedIndices>("selected_indices")
-            .0
-            .iter()
-            .copied()
-            .collect();
+        let change = selection_change(&old_indices, &new_indices);
+        parent.set("selected_indices", SelectedIndices(new_indices));
 
         ctx.push_event_strategy_by_entity(
-            SelectionChangedEvent(parent_entity, selected_indices),
+            SelectionChangedEvent(parent_entity, change),
             parent_entity,
             EventStrategy::Direct,
         );
@@ -203,7 +633,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -230,7 +660,10 @@ widget!(
         selected: bool,
 
         /// Sets or shares the parent id.
-        parent: u32
+        parent: u32,
+
+        /// Sets or shares the index of the item inside of the list.
+        index: usize
     }
 );
 
@@ -242,6 +675,7 @@ impl Template for ListViewItem {
             .height(24.0)
             .selected(false)
             .pressed(false)
+            .index(0)
             .padding(0.0)
             .background("transparent")
             .border_radius(0.0)
@@ -275,13 +709,26 @@ impl Template for ListViewItem {
 widget!(
     /// The `ListView` is an items drawer widget with selectable items.
     ///
+    /// A vertical `ListView` is virtualized: only the visible items are created. All items must have the same
+    /// height, that is defined by `item_height`.
+    ///
+    /// How items are selected by a click depends on the `selection_mode`. If the selection is changed by a click a
+    /// `SelectionChangedEvent` with the selected, added and removed indices is raised.
+    ///
+    /// If `reorderable` is set the items of a vertical list get a drag handle. An item that is dragged by its handle
+    /// and dropped at a new position raises an `ItemMovedEvent` with the old and the new index, the handler has to
+    /// move the item in the backing data.
+    ///
+    /// If the list is focused the Up / Down keys select the previous / next item and Home / End the first / last
+    /// item. The selected item is scrolled into view.
+    ///
     /// **style:** `items-widget`
-    ListView<ListViewState> : SelectionChangedHandler {
+    ListView<ListViewState> : SelectionChangedHandler, DragDropHandler, ItemMovedHandler, KeyDownHandler, MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -298,21 +745,56 @@ widget!(
         /// Sets or shares the item count.
         count: usize,
 
+        /// Sets or shares the height of each item. It is used to calculate the visible items and the scroll extent.
+        item_height: f64,
+
         /// Sets or shares the selection mode property.
         selection_mode: SelectionMode,
 
         /// Sets or shares the selected indices.
         selected_indices: SelectedIndices,
 
+        /// Sets or shares the index of the item where a range selection by Shift+click starts.
+        selection_anchor: usize,
+
         /// Sets or shares the list of selected indices.
         selected_entities: SelectedEntities,
 
+        /// Sets or shares the flag if the items can be dragged to new positions by their drag handle.
+        reorderable: bool,
+
+        /// Sets or shares the brush of the line that shows where a dragged item is inserted.
+        indicator_brush: Brush,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
         /// Use this flag to force the redrawing of the items.
         request_update: bool
     }
 );
 
 impl ListView {
+    /// Binds the items of the list to the `ItemsSource<T>` property with the given key of the source widget.
+    /// If the source is changed only the affected items are updated. The count of the list view is taken from
+    /// the source.
+    pub fn items_source<T: Component>(mut self, source: Entity, key: impl Into<String>) -> Self {
+        let key = key.into();
+        self.state_mut().items_source = Some(Box::new(move |ctx, version| {
+            ctx.get_widget(source)
+                .try_get::<ItemsSource<T>>(&key)
+                .map(|items_source| items_source.changes(version))
+        }));
+        self
+    }
+
     /// Define the template build function for the content of the ListViewItems.
     pub fn items_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
         mut self,
@@ -331,9 +813,25 @@ impl Template for ListView {
             .orientation(id)
             .build(ctx);
 
+        let insertion_indicator = Container::new()
+            .id(INSERTION_INDICATOR)
+            .v_align("start")
+            .height(INSERTION_INDICATOR_HEIGHT)
+            .background(("indicator_brush", id))
+            .visibility("collapsed")
+            .build(ctx);
+
+        let items_extent = Grid::new()
+            .v_align("start")
+            .id(ITEMS_EXTENT)
+            .child(items_panel)
+            .child(insertion_indicator)
+            .build(ctx);
+
         let scroll_viewer = ScrollViewer::new()
+            .id(SCROLL_VIEWER)
             .mode(("disabled", "auto"))
-            .child(items_panel)
+            .child(items_extent)
             .build(ctx);
 
         self.name("ListView")
@@ -345,8 +843,58 @@ impl Template for ListView {
             .padding(2.0)
             .selection_mode("single")
             .selected_indices(HashSet::new())
+            .selection_anchor(0)
             .selected_entities(HashSet::new())
             .orientation("vertical")
+            .item_height(24.0)
+            .reorderable(false)
+            .indicator_brush(colors::SUNFLOWER_COLOR)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
+            .on_mouse_down(move |states, _| {
+                states
+                    .get_mut::<ListViewState>(id)
+                    .action(ListViewAction::Focus);
+                false
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<ListViewState>(id)
+                    .action(ListViewAction::Key(event.key));
+                false
+            })
+            // only items of the list itself are accepted
+            .on_drag_over(move |states, drag| {
+                if !drag
+                    .data
+                    .get::<ListViewItemDrag>()
+                    .map_or(false, |d| d.list == id)
+                {
+                    return false;
+                }
+
+                states
+                    .get_mut::<ListViewState>(id)
+                    .action(ListViewAction::DragOver(drag.position));
+                true
+            })
+            .on_drag_leave(move |states, _| {
+                states
+                    .get_mut::<ListViewState>(id)
+                    .action(ListViewAction::DragLeave);
+            })
+            .on_drop(move |states, drag| {
+                let item = match drag.data.get::<ListViewItemDrag>() {
+                    Some(d) if d.list == id => d.item,
+                    _ => return false,
+                };
+
+                states
+                    .get_mut::<ListViewState>(id)
+                    .action(ListViewAction::Drop(item, drag.position));
+                true
+            })
             .child(
                 Container::new()
                     .background(id)
@@ -359,7 +907,7 @@ impl Template for ListView {
                     .child(
                         ScrollIndicator::new()
                             .padding(2.0)
-                            .content_bounds(("bounds", items_panel))
+                            .content_bounds(("bounds", items_extent))
                             .view_port_bounds(("bounds", scroll_viewer))
                             .scroll_padding(("padding", scroll_viewer))
                             .mode(scroll_viewer)
@@ -370,3 +918,253 @@ impl Template for ListView {
             )
     }
 }
+
+// --- Helpers --
+
+fn select(widget: &mut WidgetContainer, selected: bool) {
+    widget.set("selected", selected);
+
+    if selected {
+        widget.get_mut::<Selector>("selector").set_state("selected");
+    } else {
+        widget.get_mut::<Selector>("selector").clear_state();
+    }
+}
+
+// returns the index of an item after the given change or `None` if the item is removed.
+fn shift_index(index: usize, change: ItemsChange) -> Option<usize> {
+    match change {
+        ItemsChange::Insert(i) if index >= i => Some(index + 1),
+        ItemsChange::Remove(i) if index == i => None,
+        ItemsChange::Remove(i) if index > i => Some(index - 1),
+        ItemsChange::Reset => None,
+        _ => Some(index),
+    }
+}
+
+// returns the selected indices after the item with the given index is clicked.
+fn click_selection(
+    selection_mode: SelectionMode,
+    selected_indices: &HashSet<usize>,
+    anchor: usize,
+    index: usize,
+    ctrl_down: bool,
+    shift_down: bool,
+) -> HashSet<usize> {
+    let mut toggled = selected_indices.clone();
+
+    if !toggled.remove(&index) {
+        toggled.insert(index);
+    }
+
+    match selection_mode {
+        SelectionMode::None => selected_indices.clone(),
+        SelectionMode::Single if selected_indices.contains(&index) => HashSet::new(),
+        SelectionMode::Single => [index].iter().copied().collect(),
+        SelectionMode::Multiple => toggled,
+        SelectionMode::Extended if shift_down => {
+            let mut indices = if ctrl_down {
+                selected_indices.clone()
+            } else {
+                HashSet::new()
+            };
+            indices.extend(anchor.min(index)..=anchor.max(index));
+            indices
+        }
+        SelectionMode::Extended if ctrl_down => toggled,
+        SelectionMode::Extended => [index].iter().copied().collect(),
+    }
+}
+
+// returns the sorted selected, added and removed indices of a selection change.
+fn selection_change(old_indices: &HashSet<usize>, new_indices: &HashSet<usize>) -> SelectionChange {
+    let mut selected: Vec<usize> = new_indices.iter().copied().collect();
+    let mut added: Vec<usize> = new_indices.difference(old_indices).copied().collect();
+    let mut removed: Vec<usize> = old_indices.difference(new_indices).copied().collect();
+
+    selected.sort_unstable();
+    added.sort_unstable();
+    removed.sort_unstable();
+
+    SelectionChange {
+        selected,
+        added,
+        removed,
+    }
+}
+
+// returns the range of items that are visible at the given scroll offset including the buffer before and after.
+fn visible_range(
+    offset: f64,
+    view_port: f64,
+    item_height: f64,
+    count: usize,
+    buffer: usize,
+) -> (usize, usize) {
+    if item_height <= 0.0 {
+        return (0, count);
+    }
+
+    let first = (offset.max(0.0) / item_height).floor() as usize;
+    let last = ((offset.max(0.0) + view_port) / item_height).ceil() as usize;
+    let end = (last + buffer).min(count);
+
+    (first.saturating_sub(buffer).min(end), end)
+}
+
+// returns the index of the gap between two items that is nearest to the given offset from the top of the first item.
+fn insertion_index(offset: f64, item_height: f64, count: usize) -> usize {
+    if item_height <= 0.0 {
+        return count;
+    }
+
+    ((offset.max(0.0) / item_height).round() as usize).min(count)
+}
+
+// returns the new index of a moved item that is inserted at the given gap or `None` if the position doesn't change.
+fn move_target(from: usize, insertion_index: usize) -> Option<usize> {
+    if insertion_index == from || insertion_index == from + 1 {
+        return None;
+    }
+
+    if insertion_index > from {
+        Some(insertion_index - 1)
+    } else {
+        Some(insertion_index)
+    }
+}
+
+// returns the index of the item that is selected by the given key or `None` if the key doesn't select an item.
+fn key_index(key: Key, index: Option<usize>, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let index = index.map(|i| i.min(count - 1));
+
+    match (key, index) {
+        (Key::Up, Some(index)) => Some(index.saturating_sub(1)),
+        (Key::Down, Some(index)) => Some((index + 1).min(count - 1)),
+        (Key::Up, None) | (Key::Down, None) | (Key::Home, _) => Some(0),
+        (Key::End, _) => Some(count - 1),
+        _ => None,
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(indices: &[usize]) -> HashSet<usize> {
+        indices.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_click_selection() {
+        let selected = indices(&[1, 2]);
+
+        assert_eq!(
+            click_selection(SelectionMode::None, &selected, 0, 4, false, false),
+            selected
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Single, &selected, 0, 4, false, false),
+            indices(&[4])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Single, &indices(&[4]), 0, 4, false, false),
+            indices(&[])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Multiple, &selected, 0, 4, false, false),
+            indices(&[1, 2, 4])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Multiple, &selected, 0, 2, false, false),
+            indices(&[1])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Extended, &selected, 0, 4, false, false),
+            indices(&[4])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Extended, &selected, 0, 4, true, false),
+            indices(&[1, 2, 4])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Extended, &selected, 5, 3, false, true),
+            indices(&[3, 4, 5])
+        );
+        assert_eq!(
+            click_selection(SelectionMode::Extended, &selected, 5, 4, true, true),
+            indices(&[1, 2, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_selection_change() {
+        let change = selection_change(&indices(&[1, 2]), &indices(&[2, 4, 3]));
+
+        assert_eq!(change.selected, vec![2, 3, 4]);
+        assert_eq!(change.added, vec![3, 4]);
+        assert_eq!(change.removed, vec![1]);
+    }
+
+    #[test]
+    fn test_shift_index() {
+        assert_eq!(shift_index(2, ItemsChange::Insert(2)), Some(3));
+        assert_eq!(shift_index(1, ItemsChange::Insert(2)), Some(1));
+        assert_eq!(shift_index(2, ItemsChange::Remove(2)), None);
+        assert_eq!(shift_index(3, ItemsChange::Remove(2)), Some(2));
+        assert_eq!(shift_index(1, ItemsChange::Remove(2)), Some(1));
+        assert_eq!(shift_index(2, ItemsChange::Update(2)), Some(2));
+        assert_eq!(shift_index(2, ItemsChange::Reset), None);
+    }
+
+    #[test]
+    fn test_key_index() {
+        assert_eq!(key_index(Key::Down, None, 5), Some(0));
+        assert_eq!(key_index(Key::Up, None, 5), Some(0));
+        assert_eq!(key_index(Key::Down, Some(2), 5), Some(3));
+        assert_eq!(key_index(Key::Down, Some(4), 5), Some(4));
+        assert_eq!(key_index(Key::Up, Some(2), 5), Some(1));
+        assert_eq!(key_index(Key::Up, Some(0), 5), Some(0));
+        assert_eq!(key_index(Key::Up, Some(9), 5), Some(3));
+        assert_eq!(key_index(Key::Home, Some(3), 5), Some(0));
+        assert_eq!(key_index(Key::End, None, 5), Some(4));
+        assert_eq!(key_index(Key::Left, Some(2), 5), None);
+        assert_eq!(key_index(Key::Down, None, 0), None);
+    }
+
+    #[test]
+    fn test_visible_range() {
+        assert_eq!(visible_range(0.0, 100.0, 20.0, 10_000, 2), (0, 7));
+        assert_eq!(visible_range(1000.0, 100.0, 20.0, 10_000, 2), (48, 57));
+        assert_eq!(visible_range(1010.0, 100.0, 20.0, 10_000, 2), (48, 58));
+        assert_eq!(visible_range(0.0, 100.0, 20.0, 3, 2), (0, 3));
+        assert_eq!(visible_range(1000.0, 100.0, 20.0, 3, 2), (3, 3));
+        assert_eq!(visible_range(0.0, 100.0, 0.0, 3, 2), (0, 3));
+    }
+
+    #[test]
+    fn test_insertion_index() {
+        assert_eq!(insertion_index(0.0, 20.0, 5), 0);
+        assert_eq!(insertion_index(-12.0, 20.0, 5), 0);
+        assert_eq!(insertion_index(9.0, 20.0, 5), 0);
+        assert_eq!(insertion_index(11.0, 20.0, 5), 1);
+        assert_eq!(insertion_index(45.0, 20.0, 5), 2);
+        assert_eq!(insertion_index(500.0, 20.0, 5), 5);
+        assert_eq!(insertion_index(10.0, 0.0, 5), 5);
+    }
+
+    #[test]
+    fn test_move_target() {
+        assert_eq!(move_target(2, 2), None);
+        assert_eq!(move_target(2, 3), None);
+        assert_eq!(move_target(2, 0), Some(0));
+        assert_eq!(move_target(2, 4), Some(3));
+        assert_eq!(move_target(0, 5), Some(4));
+    }
+}
@@ -0,0 +1,497 @@
+use std::path::Path;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, render::prelude::Image};
+
+// --- KEYS --
+
+pub static STYLE_MARKDOWN_VIEW: &str = "markdown_view";
+static STYLE_HEADING: &str = "markdown_heading_";
+static STYLE_CODE: &str = "markdown_code";
+static STYLE_QUOTE: &str = "markdown_quote";
+static STYLE_QUOTE_TEXT: &str = "markdown_quote_text";
+static STYLE_RULE: &str = "markdown_rule";
+static ID_CONTENT: &str = "id_content";
+
+// --- KEYS --
+
+/// Describes a block of a Markdown document.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarkdownBlock {
+    /// A heading with its level from 1 to 6 and its inline Markdown.
+    Heading(usize, String),
+
+    /// A paragraph with its inline Markdown.
+    Paragraph(String),
+
+    /// An item of a list with its nesting level, its marker (`•` or the number) and its inline Markdown.
+    ListItem {
+        level: usize,
+        marker: String,
+        text: String,
+    },
+
+    /// A fenced code block.
+    Code(String),
+
+    /// A block quote with its inline Markdown.
+    Quote(String),
+
+    /// An image that stands in its own paragraph.
+    Image { alt: String, path: String },
+
+    /// A horizontal rule.
+    Rule,
+}
+
+/// Parses the blocks of a Markdown document. Supported are headings, paragraphs, ordered and unordered lists, fenced
+/// code blocks, block quotes, images and horizontal rules. The inline Markdown of the blocks is read by
+/// `RichText::from_markdown`.
+pub fn parse_markdown(markdown: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = vec![];
+    let mut paragraph: Option<String> = None;
+    let mut quote: Option<String> = None;
+    let mut code: Option<(String, String)> = None;
+    let mut in_list = false;
+
+    for line in markdown.lines() {
+        // fenced code blocks are taken as they are
+        if let Some((fence, text)) = &mut code {
+            if line.trim_start().starts_with(fence.as_str()) {
+                blocks.push(MarkdownBlock::Code(text.trim_end_matches('\n').to_string()));
+                code = None;
+            } else {
+                text.push_str(line);
+                text.push('\n');
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            flush(&mut blocks, &mut paragraph, &mut quote);
+            in_list = false;
+            code = Some((trimmed[..3].to_string(), String::new()));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush(&mut blocks, &mut paragraph, &mut quote);
+            in_list = false;
+            continue;
+        }
+
+        if let Some(text) = trimmed.strip_prefix('>') {
+            if let Some(paragraph) = paragraph.take() {
+                blocks.push(MarkdownBlock::Paragraph(paragraph));
+            }
+            append_line(&mut quote, text.trim_start(), line);
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            flush(&mut blocks, &mut paragraph, &mut quote);
+            in_list = false;
+            blocks.push(MarkdownBlock::Rule);
+            continue;
+        }
+
+        if let Some((level, text)) = heading(trimmed) {
+            flush(&mut blocks, &mut paragraph, &mut quote);
+            in_list = false;
+            blocks.push(MarkdownBlock::Heading(level, text.to_string()));
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+
+        if let Some((marker, text)) = list_marker(trimmed) {
+            flush(&mut blocks, &mut paragraph, &mut quote);
+            in_list = true;
+            blocks.push(MarkdownBlock::ListItem {
+                level: indent / 2,
+                marker,
+                text: text.to_string(),
+            });
+            continue;
+        }
+
+        // continuation of a list item
+        if in_list {
+            if let Some(MarkdownBlock::ListItem { text, .. }) = blocks.last_mut() {
+                text.push(' ');
+                text.push_str(trimmed);
+                continue;
+            }
+        }
+
+        if let Some((alt, path)) = image(trimmed) {
+            if paragraph.is_none() {
+                flush(&mut blocks, &mut paragraph, &mut quote);
+                blocks.push(MarkdownBlock::Image { alt, path });
+                continue;
+            }
+        }
+
+        if quote.is_some() {
+            append_line(&mut quote, trimmed, line);
+            continue;
+        }
+
+        append_line(&mut paragraph, trimmed, line);
+    }
+
+    if let Some((_, text)) = code {
+        blocks.push(MarkdownBlock::Code(text.trim_end_matches('\n').to_string()));
+    }
+
+    flush(&mut blocks, &mut paragraph, &mut quote);
+
+    blocks
+}
+
+/// The `MarkdownViewState` builds the widgets of the blocks of the `markdown` and forwards the navigation of links.
+#[derive(Default, AsAny)]
+pub struct MarkdownViewState {
+    markdown: Option<String>,
+    content: Entity,
+    navigate: Vec<String>,
+}
+
+impl MarkdownViewState {
+    fn navigate(&mut self, url: String) {
+        self.navigate.push(url);
+    }
+
+    // Rebuilds the blocks if the markdown or the base path has been changed.
+    fn update_blocks(&mut self, ctx: &mut Context) {
+        let markdown = ctx.widget().clone::<String>("markdown");
+        let base_path = ctx.widget().clone::<String>("base_path");
+        let source = format!("{}\n{}", base_path, markdown);
+
+        if self.markdown.as_ref() == Some(&source) {
+            return;
+        }
+
+        self.markdown = Some(source);
+
+        let entity = ctx.entity;
+        let content = self.content;
+        ctx.clear_children_of(content);
+
+        for block in parse_markdown(&markdown) {
+            let build_context = &mut ctx.build_context();
+            let child = build_block(build_context, entity, block, &base_path);
+            build_context.append_child(content, child);
+        }
+    }
+
+    // Raises the navigate events of the links of the blocks on the view.
+    fn forward_navigation(&mut self, ctx: &mut Context) {
+        for url in self.navigate.drain(..) {
+            ctx.push_event_strategy_by_entity(
+                NavigateEvent(ctx.entity, url),
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+}
+
+impl State for MarkdownViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.content = ctx
+            .entity_of_child(ID_CONTENT)
+            .expect("MarkdownViewState.init: content child could not be found.");
+        self.update_blocks(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_blocks(ctx);
+        self.forward_navigation(ctx);
+    }
+}
+
+widget!(
+    /// The `MarkdownView` widget shows a Markdown document, e.g. for help screens and changelogs.
+    ///
+    /// The blocks of the document are parsed by `parse_markdown`: headings, paragraphs, lists and quotes are drawn
+    /// by `RichTextBlock`s, code blocks by a `Container`, images by an `ImageWidget` and rules by a line. Image paths
+    /// are relative to `base_path`. To scroll a long document, put the view into a `ScrollViewer` that scrolls only
+    /// vertically.
+    ///
+    /// A click on a link raises a `NavigateEvent` with the url of the link, if `open_in_browser` is `true` the url
+    /// is opened in the browser of the system too.
+    ///
+    /// **style:** `markdown_view`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// MarkdownView::new()
+    ///     .markdown("# Help\n\nRead the [documentation](https://docs.rs/orbtk).\n\n* one\n* two")
+    ///     .build(ctx)
+    /// ```
+    MarkdownView<MarkdownViewState>: NavigateHandler {
+        /// Sets or shares the Markdown document.
+        markdown: String,
+
+        /// Sets or shares the directory of relative image paths.
+        base_path: String,
+
+        /// Sets or shares the space between the blocks.
+        spacing: f64,
+
+        /// Sets or shares the flag if links are opened in the browser of the system.
+        open_in_browser: bool
+    }
+);
+
+impl Template for MarkdownView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MarkdownView")
+            .style(STYLE_MARKDOWN_VIEW)
+            .markdown("")
+            .base_path("")
+            .spacing(8.0)
+            .open_in_browser(true)
+            .child(Stack::new().id(ID_CONTENT).spacing(id).build(ctx))
+    }
+}
+
+// --- Helpers --
+
+// Builds the widget of a block.
+fn build_block(
+    ctx: &mut BuildContext,
+    view: Entity,
+    block: MarkdownBlock,
+    base_path: &str,
+) -> Entity {
+    match block {
+        MarkdownBlock::Heading(level, text) => text_block(view, RichText::from_markdown(&text))
+            .style(format!("{}{}", STYLE_HEADING, level.min(3)))
+            .font("Roboto-Medium")
+            .build(ctx),
+        MarkdownBlock::Paragraph(text) => {
+            text_block(view, RichText::from_markdown(&text)).build(ctx)
+        }
+        MarkdownBlock::ListItem {
+            level,
+            marker,
+            text,
+        } => {
+            let mut rich_text = RichText::new().span(format!("{}  ", marker));
+
+            for span in RichText::from_markdown(&text).spans() {
+                rich_text.push(span.clone());
+            }
+
+            text_block(view, rich_text)
+                .margin((level as f64 * 16.0 + 8.0, 0.0, 0.0, 0.0))
+                .build(ctx)
+        }
+        MarkdownBlock::Code(code) => Container::new()
+            .style(STYLE_CODE)
+            .child(text_block(view, RichText::from(code)).build(ctx))
+            .build(ctx),
+        MarkdownBlock::Quote(text) => Container::new()
+            .style(STYLE_QUOTE)
+            .child(
+                text_block(view, RichText::from_markdown(&text))
+                    .style(STYLE_QUOTE_TEXT)
+                    .build(ctx),
+            )
+            .build(ctx),
+        MarkdownBlock::Image { alt, path } => {
+            let full_path = if base_path.is_empty() || Path::new(&path).is_absolute() {
+                path.clone()
+            } else {
+                Path::new(base_path)
+                    .join(&path)
+                    .to_string_lossy()
+                    .to_string()
+            };
+
+            match Image::from_path(&full_path) {
+                Ok(image) => ImageWidget::new().image(image).h_align("start").build(ctx),
+                // the alternative text is shown if the image could not be loaded
+                Err(_) => text_block(view, RichText::from(alt)).build(ctx),
+            }
+        }
+        MarkdownBlock::Rule => Container::new().style(STYLE_RULE).height(1.0).build(ctx),
+    }
+}
+
+// Creates a rich text block that forwards its links to the view.
+fn text_block(view: Entity, rich_text: RichText) -> RichTextBlock {
+    RichTextBlock::new()
+        .rich_text(rich_text)
+        .open_in_browser(view)
+        .on_navigate(move |states, _, url| {
+            states.get_mut::<MarkdownViewState>(view).navigate(url);
+        })
+}
+
+// Adds the blocks of the open paragraph and quote.
+fn flush(
+    blocks: &mut Vec<MarkdownBlock>,
+    paragraph: &mut Option<String>,
+    quote: &mut Option<String>,
+) {
+    if let Some(paragraph) = paragraph.take() {
+        blocks.push(MarkdownBlock::Paragraph(paragraph));
+    }
+
+    if let Some(quote) = quote.take() {
+        blocks.push(MarkdownBlock::Quote(quote));
+    }
+}
+
+// Appends a line to a block. Lines are joined by a space, a line that ends with two spaces or a backslash breaks
+// the line.
+fn append_line(block: &mut Option<String>, text: &str, line: &str) {
+    let hard_break = line.ends_with("  ") || text.ends_with('\\');
+    let text = text.trim_end_matches('\\');
+
+    match block {
+        Some(block) => {
+            if !block.ends_with('\n') {
+                block.push(' ');
+            }
+            block.push_str(text);
+        }
+        None => *block = Some(text.to_string()),
+    }
+
+    if hard_break {
+        if let Some(block) = block {
+            block.push('\n');
+        }
+    }
+}
+
+// Returns `true` for a line of three or more `-`, `*` or `_`.
+fn is_rule(line: &str) -> bool {
+    let chars: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+
+    chars.len() >= 3 && ['-', '*', '_'].iter().any(|m| chars.iter().all(|c| c == m))
+}
+
+// Returns the level and the text of a heading like `## Title`.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let text = &line[level..];
+
+    if !text.is_empty() && !text.starts_with(' ') {
+        return None;
+    }
+
+    Some((level, text.trim().trim_end_matches('#').trim_end()))
+}
+
+// Returns the marker and the text of a list item like `- item` or `1. item`.
+fn list_marker(line: &str) -> Option<(String, &str)> {
+    for bullet in &["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some((String::from("•"), text.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+
+    if digits > 0 && digits < 10 {
+        if let Some(text) = line[digits..].strip_prefix(". ") {
+            return Some((line[..digits + 1].to_string(), text.trim_start()));
+        }
+    }
+
+    None
+}
+
+// Returns the alternative text and the path of a line that contains only an image like `![alt](path)`.
+fn image(line: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix("![")?.strip_suffix(')')?;
+    let (alt, path) = rest.split_once("](")?;
+
+    if alt.contains(']') || path.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((alt.to_string(), path.to_string()))
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown() {
+        let blocks = parse_markdown(
+            "# Title #\n\nSome **text**\nin two lines.  \nBreak\n\n- one\n  continued\n  - nested\n2. two\n\n\
+             ```rust\nlet a = 1;\n\nlet b = 2;\n```\n> quoted\n> text\n\n---\n![logo](res/logo.png)\n#no heading",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading(1, String::from("Title")),
+                MarkdownBlock::Paragraph(String::from("Some **text** in two lines.\nBreak")),
+                MarkdownBlock::ListItem {
+                    level: 0,
+                    marker: String::from("•"),
+                    text: String::from("one continued")
+                },
+                MarkdownBlock::ListItem {
+                    level: 1,
+                    marker: String::from("•"),
+                    text: String::from("nested")
+                },
+                MarkdownBlock::ListItem {
+                    level: 0,
+                    marker: String::from("2."),
+                    text: String::from("two")
+                },
+                MarkdownBlock::Code(String::from("let a = 1;\n\nlet b = 2;")),
+                MarkdownBlock::Quote(String::from("quoted text")),
+                MarkdownBlock::Rule,
+                MarkdownBlock::Image {
+                    alt: String::from("logo"),
+                    path: String::from("res/logo.png")
+                },
+                MarkdownBlock::Paragraph(String::from("#no heading")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(heading("### Three"), Some((3, "Three")));
+        assert_eq!(heading("#"), Some((1, "")));
+        assert_eq!(heading("#tag"), None);
+        assert_eq!(heading("####### seven"), None);
+    }
+
+    #[test]
+    fn test_is_rule() {
+        assert!(is_rule("---"));
+        assert!(is_rule("* * *"));
+        assert!(!is_rule("--"));
+        assert!(!is_rule("-*-"));
+    }
+
+    #[test]
+    fn test_list_marker() {
+        assert_eq!(list_marker("* item"), Some((String::from("•"), "item")));
+        assert_eq!(list_marker("12. item"), Some((String::from("12."), "item")));
+        assert_eq!(list_marker("*bold*"), None);
+        assert_eq!(list_marker("1.5 times"), None);
+    }
+}
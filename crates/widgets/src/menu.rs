@@ -0,0 +1,691 @@
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_MENU: &str = "menu";
+pub static STYLE_MENU_POPUP: &str = "menu_popup";
+pub static STYLE_MENU_ITEM: &str = "menu_item";
+pub static STYLE_MENU_SEPARATOR: &str = "menu_separator";
+static ARROW_ICON: &str = "arrow_icon";
+static CHECK_ICON: &str = "check_icon";
+
+// --- KEYS --
+
+#[derive(Debug, Copy, Clone)]
+enum MenuAction {
+    Toggle,
+    Hover(Entity),
+    CheckMouseUpOutside,
+}
+
+/// The `MenuState` handles the open and close behavior of a `Menu` and its sub menus.
+#[derive(Default, AsAny)]
+pub struct MenuState {
+    action: Option<MenuAction>,
+    items: Vec<Entity>,
+    popup: Entity,
+    parent: Option<Entity>,
+    submenu: bool,
+    open: bool,
+    keep_open: bool,
+    bounds: Rectangle,
+    popup_bounds: Rectangle,
+}
+
+impl MenuState {
+    fn action(&mut self, action: MenuAction) {
+        self.action = Some(action);
+    }
+
+    // checks if the given position is inside of the menu header or the open drop-down.
+    fn contains(&self, position: Point) -> bool {
+        self.bounds.contains(position) || (self.open && self.popup_bounds.contains(position))
+    }
+
+    // opens the hovered sub menu and closes all other sub menus.
+    fn hover(&mut self, ctx: &mut Context, hovered: Entity) {
+        for item in &self.items {
+            let mut item_widget = ctx.get_widget(*item);
+
+            if !item_widget.has::<bool>("selected") {
+                continue;
+            }
+
+            item_widget.set("selected", *item == hovered);
+        }
+    }
+
+    // synchronizes the visual state with the selected property and closes all sub menus on close.
+    fn update_open(&mut self, ctx: &mut Context) {
+        let open = *menu(ctx.widget()).selected();
+
+        if open == self.open {
+            return;
+        }
+
+        self.open = open;
+
+        if open {
+            ctx.widget()
+                .get_mut::<Selector>("selector")
+                .set_state("selected");
+        } else {
+            ctx.widget().get_mut::<Selector>("selector").clear_state();
+
+            for item in &self.items {
+                let mut item_widget = ctx.get_widget(*item);
+
+                if item_widget.has::<bool>("selected") {
+                    item_widget.set("selected", false);
+                }
+            }
+        }
+
+        ctx.widget().update(false);
+    }
+}
+
+impl State for MenuState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.parent = parent_menu(&ctx.widget());
+        self.submenu = ctx.parent_entity_by_style(STYLE_MENU_POPUP).is_some()
+            || ctx.parent_entity_by_style(STYLE_CONTEXT_MENU).is_some();
+
+        if self.submenu {
+            ctx.child(ARROW_ICON).set("visibility", Visibility::Visible);
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                MenuAction::Toggle => {
+                    let open = self.submenu || !*menu(ctx.widget()).selected();
+                    menu(ctx.widget()).set_selected(open);
+                }
+                MenuAction::Hover(hovered) => self.hover(ctx, hovered),
+                MenuAction::CheckMouseUpOutside => {
+                    if !self.keep_open {
+                        menu(ctx.widget()).set_selected(false);
+                    }
+                }
+            }
+        }
+
+        self.keep_open = false;
+        self.update_open(ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let position = ctx.widget().clone::<Point>("position");
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        self.bounds = Rectangle::new(position, size);
+
+        if !self.open {
+            return;
+        }
+
+        // sub menus are opened on the right, all other menus below the header.
+        let (x, y) = if self.submenu {
+            (position.x() + size.width(), position.y())
+        } else {
+            (position.x(), position.y() + size.height())
+        };
+
+        let mut popup = ctx.get_widget(self.popup);
+        popup.get_mut::<Rectangle>("bounds").set_x(x);
+        popup.get_mut::<Rectangle>("bounds").set_y(y);
+        self.popup_bounds = *popup.get::<Rectangle>("bounds");
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.popup);
+    }
+}
+
+widget!(
+    /// The `Menu` displays a header that opens a drop-down with menu items on click. A `Menu` could be used as
+    /// item of another `Menu`, then it is opened on the right of its header on click or hover.
+    ///
+    /// If an item of the menu or one of its sub menus is activated an `ActivatedEvent` is raised.
+    ///
+    /// The character after an underscore of the text is the mnemonic of the menu, e.g. `"_File"`. Alt+F opens the
+    /// menu, the mnemonics of its items activate the items while it is open.
+    ///
+    /// **style:** `menu`
+    Menu<MenuState>: ActivatedHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the flag if the drop-down is open.
+        selected: bool,
+
+        /// Sets or shares the entity of the parent menu. It is set by the parent `Menu` or `MenuBar`.
+        menu: u32,
+
+        /// Sets or shares the flag if the text contains a mnemonic.
+        mnemonic: bool
+    }
+);
+
+impl Menu {
+    /// Adds an item to the drop-down of the menu. An item could be a `MenuItem`, a `MenuSeparator` or another `Menu`.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for Menu {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut items_panel = Stack::new().orientation("vertical");
+
+        for item in &self.state().items {
+            ctx.register_property::<u32>("menu", *item, id.0);
+            items_panel = items_panel.child(*item);
+        }
+
+        let popup = Popup::new()
+            .style(STYLE_MENU_POPUP)
+            .h_align("start")
+            .v_align("start")
+            .open(("selected", id))
+            .child(items_panel.build(ctx))
+            .build(ctx);
+        self.state_mut().popup = popup;
+
+        let _ = ctx.append_child_to_overlay(popup);
+
+        self.name("Menu")
+            .style(STYLE_MENU)
+            .selected(false)
+            .pressed(false)
+            .menu(0)
+            .mnemonic(true)
+            .height(32.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Container::new()
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .child(
+                                Grid::new()
+                                    .columns(Columns::create().push("*").push("auto"))
+                                    .child(
+                                        TextBlock::new()
+                                            .v_align("center")
+                                            .text(id)
+                                            .font(id)
+                                            .font_size(id)
+                                            .foreground(id)
+                                            .mnemonic(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        FontIconBlock::new()
+                                            .id(ARROW_ICON)
+                                            .attach(Grid::column(1))
+                                            .v_align("center")
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .visibility("collapsed")
+                                            .icon(material_icons_font::MD_KEYBOARD_ARROW_RIGHT)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_click(move |states, _| {
+                states.get_mut::<MenuState>(id).action(MenuAction::Toggle);
+                false
+            })
+            .on_mouse_move(move |states, _| {
+                let parent = states.get::<MenuState>(id).parent;
+                hover_parent(states, parent, id);
+                false
+            })
+            .on_global_mouse_up(move |states, e| {
+                let (open, contains, mut parent) = {
+                    let state = states.get::<MenuState>(id);
+                    (state.open, state.contains(e.position), state.parent)
+                };
+
+                if !open {
+                    return;
+                }
+
+                if !contains {
+                    states
+                        .get_mut::<MenuState>(id)
+                        .action(MenuAction::CheckMouseUpOutside);
+                    return;
+                }
+
+                // keeps all parent menus open if the mouse is released inside of a sub menu.
+                while let Some(entity) = parent {
+                    if let Some(parent_state) = states.try_get_mut::<MenuState>(entity) {
+                        parent_state.keep_open = true;
+                        parent = parent_state.parent;
+                    } else {
+                        if let Some(context_menu) = states.try_get_mut::<ContextMenuState>(entity) {
+                            context_menu.keep_open();
+                        }
+                        break;
+                    }
+                }
+            })
+    }
+}
+
+/// The `MenuItemState` handles the activation of a `MenuItem`.
+#[derive(Default, AsAny)]
+pub struct MenuItemState {
+    activate: bool,
+    parent: Option<Entity>,
+}
+
+impl MenuItemState {
+    fn activate(&mut self) {
+        self.activate = true;
+    }
+
+    // updates the visibility of the check icon.
+    fn update_check_icon(&self, ctx: &mut Context) {
+        let visibility = if *menu_item(ctx.widget()).checked() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+
+        ctx.child(CHECK_ICON).set("visibility", visibility);
+    }
+}
+
+impl State for MenuItemState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.parent = parent_menu(&ctx.widget());
+        self.update_check_icon(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.activate {
+            self.activate = false;
+
+            if *menu_item(ctx.widget()).checkable() {
+                let checked = !*menu_item(ctx.widget()).checked();
+                menu_item(ctx.widget()).set_checked(checked);
+            }
+
+            let id = ctx.widget().try_clone::<String>("id").unwrap_or_default();
+
+            ctx.push_event_strategy_by_entity(
+                ActivatedEvent(ctx.entity, id.clone()),
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+
+            // closes all parent menus and raises the event also on the parents.
+            let mut parent = self.parent;
+            while let Some(entity) = parent {
+                ctx.push_event_strategy_by_entity(
+                    ActivatedEvent(ctx.entity, id.clone()),
+                    entity,
+                    EventStrategy::Direct,
+                );
+
+                let mut parent_widget = ctx.get_widget(entity);
+
+                if parent_widget.has::<bool>("selected") {
+                    parent_widget.set("selected", false);
+                } else if parent_widget.has::<bool>("open") {
+                    parent_widget.set("open", false);
+                }
+
+                parent = parent_menu(&parent_widget);
+            }
+        }
+
+        self.update_check_icon(ctx);
+    }
+}
+
+widget!(
+    /// The `MenuItem` describes an item inside of a `Menu`. On click an `ActivatedEvent` with the id of the item is
+    /// raised. A checkable item toggles its `checked` property on activation.
+    ///
+    /// The `accelerator` property is used to display a keyboard shortcut, e.g. "Ctrl+S". It does not register the
+    /// shortcut, use the `Shortcuts` service for that, its `display_string` could be used as accelerator.
+    ///
+    /// The character after an underscore of the text is the mnemonic of the item, e.g. `"_Open"`. Alt+O activates the
+    /// item while its menu is open.
+    ///
+    /// **style:** `menu_item`
+    MenuItem<MenuItemState>: ActivatedHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the displayed keyboard accelerator.
+        accelerator: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the flag if the item could be checked.
+        checkable: bool,
+
+        /// Sets or shares the checked property.
+        checked: bool,
+
+        /// Sets or shares the entity of the parent menu. It is set by the parent `Menu`.
+        menu: u32,
+
+        /// Sets or shares the flag if the text contains a mnemonic.
+        mnemonic: bool
+    }
+);
+
+impl Template for MenuItem {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MenuItem")
+            .style(STYLE_MENU_ITEM)
+            .pressed(false)
+            .checkable(false)
+            .checked(false)
+            .menu(0)
+            .mnemonic(true)
+            .height(24.0)
+            .min_width(120.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .accelerator("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Container::new()
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .child(
+                                Grid::new()
+                                    .columns(Columns::create().push(20.0).push("*").push("auto"))
+                                    .child(
+                                        FontIconBlock::new()
+                                            .id(CHECK_ICON)
+                                            .v_align("center")
+                                            .visibility("hidden")
+                                            .icon(material_icons_font::MD_CHECK)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .attach(Grid::column(1))
+                                            .v_align("center")
+                                            .text(id)
+                                            .font(id)
+                                            .font_size(id)
+                                            .foreground(id)
+                                            .mnemonic(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .attach(Grid::column(2))
+                                            .v_align("center")
+                                            .margin((16.0, 0.0, 0.0, 0.0))
+                                            .text(("accelerator", id))
+                                            .font(id)
+                                            .font_size(id)
+                                            .foreground(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_click(move |states, _| {
+                states.get_mut::<MenuItemState>(id).activate();
+                false
+            })
+            .on_mouse_move(move |states, _| {
+                let parent = states.get::<MenuItemState>(id).parent;
+                hover_parent(states, parent, id);
+                false
+            })
+    }
+}
+
+widget!(
+    /// The `MenuSeparator` draws a horizontal line between the items of a `Menu`.
+    ///
+    /// **style:** `menu_separator`
+    MenuSeparator {
+        /// Sets or shares the background property.
+        background: Brush
+    }
+);
+
+impl Template for MenuSeparator {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("MenuSeparator")
+            .style(STYLE_MENU_SEPARATOR)
+            .height(1.0)
+            .margin((0.0, 4.0, 0.0, 4.0))
+            .background(colors::LYNCH_COLOR)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
+
+/// Builds the context menu of a `TrayIcon` from the items of the given `ContextMenu` or `Menu`, the same items could
+/// be shown in the window and in the system tray. An activated item is passed as `TrayEvent::MenuItem` with the id of
+/// its `MenuItem`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let menu = tray_menu(ctx, self.tray_menu);
+/// ctx.set_tray_icon(Some(TrayIcon::new().tool_tip("OrbTk").menu(menu)));
+/// ```
+pub fn tray_menu(ctx: &mut Context, menu: Entity) -> Vec<TrayMenuItem> {
+    // the items of a menu are inside of its popup in the overlay
+    let root = ctx.window().entity();
+
+    ctx.entities_by_property(root, "menu", &menu.0)
+        .into_iter()
+        .filter_map(|item| tray_menu_item(ctx, item))
+        .collect()
+}
+
+// --- Helpers --
+
+// Converts the given item of a menu to an entry of the context menu of a tray icon.
+fn tray_menu_item(ctx: &mut Context, item: Entity) -> Option<TrayMenuItem> {
+    let widget = ctx.get_widget(item);
+
+    let text = if widget.has::<String16>("text") {
+        tray_menu_text(
+            &widget.get::<String16>("text").as_string(),
+            widget.try_clone::<bool>("mnemonic").unwrap_or(false),
+        )
+    } else {
+        String::new()
+    };
+
+    let name = widget.try_clone::<String>("name").unwrap_or_default();
+
+    match name.as_str() {
+        "MenuItem" => {
+            let id = widget.try_clone::<String>("id").unwrap_or_default();
+            let enabled = *widget.get::<bool>("enabled");
+
+            let item = if *widget.get::<bool>("checkable") {
+                TrayMenuItem::checkable(id, text, *widget.get::<bool>("checked"))
+            } else {
+                TrayMenuItem::item(id, text)
+            };
+
+            Some(item.enabled(enabled))
+        }
+        "MenuSeparator" => Some(TrayMenuItem::separator()),
+        "Menu" => Some(TrayMenuItem::submenu(text, tray_menu(ctx, item))),
+        _ => None,
+    }
+}
+
+// Removes the mnemonic marker, the system tray shows the text without access key.
+fn tray_menu_text(text: &str, mnemonic: bool) -> String {
+    if mnemonic {
+        Mnemonic::parse(text).text().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// Notifies the parent menu or context menu that the given item is hovered.
+fn hover_parent(states: &mut StatesContext, parent: Option<Entity>, hovered: Entity) {
+    if let Some(parent) = parent {
+        if let Some(menu) = states.try_get_mut::<MenuState>(parent) {
+            menu.action(MenuAction::Hover(hovered));
+        } else if let Some(context_menu) = states.try_get_mut::<ContextMenuState>(parent) {
+            context_menu.hover(hovered);
+        }
+    }
+}
+
+// Returns the entity of the parent menu stored in the `menu` property of the given widget.
+fn parent_menu(widget: &WidgetContainer<'_>) -> Option<Entity> {
+    match widget.try_clone::<u32>("menu") {
+        Some(0) | None => None,
+        Some(menu) => Some(menu.into()),
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tray_menu_text() {
+        assert_eq!(tray_menu_text("_Open", true), "Open");
+        assert_eq!(tray_menu_text("Save __as", true), "Save _as");
+        assert_eq!(tray_menu_text("_Open", false), "_Open");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_MENU_BAR: &str = "menu_bar";
+
+// --- KEYS --
+
+widget!(
+    /// The `MenuBar` displays its `Menu` children in a horizontal bar. If an item of one of its menus is activated an
+    /// `ActivatedEvent` is raised.
+    ///
+    /// This example creates a MenuBar:
+    /// ```rust
+    /// MenuBar::new()
+    ///     .child(
+    ///         Menu::new()
+    ///             .text("File")
+    ///             .item(MenuItem::new().id("open").text("Open").accelerator("Ctrl+O").build(ctx))
+    ///             .item(MenuSeparator::new().build(ctx))
+    ///             .item(MenuItem::new().id("quit").text("Quit").build(ctx))
+    ///             .build(ctx),
+    ///     )
+    ///     .on_activated(|_, _, id| println!("{} activated", id))
+    ///     .build(ctx)
+    /// ```
+    ///
+    /// **style:** `menu_bar`
+    MenuBar: ActivatedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Margin between the menus of the bar.
+        spacing: f64
+    }
+);
+
+impl Template for MenuBar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        // the menus use the bar to raise the activated event.
+        for child in &self.children {
+            ctx.register_property::<u32>("menu", *child, id.0);
+        }
+
+        self.name("MenuBar")
+            .style(STYLE_MENU_BAR)
+            .orientation("horizontal")
+            .spacing(0.0)
+            .height(32.0)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(StackLayout::new())
+    }
+}
@@ -1,7 +1,7 @@
 use core::f64::MAX;
 use rust_decimal::prelude::*;
 
-use super::behaviors::MouseBehavior;
+use super::behaviors::{MouseBehavior, ValueChangedBehavior};
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
 
@@ -17,7 +17,6 @@ pub enum InputAction {
     Inc,
     Dec,
     ChangeByKey(KeyEvent),
-    ChangeByMouseScroll(Point),
     Focus,
 }
 
@@ -52,6 +51,28 @@ impl NumericBoxState {
             .set::<String16>("text", String16::from(self.current_value.to_string()));
     }
 
+    // takes over a val that has been changed by the value changed behavior
+    fn sync_val(&mut self, ctx: &mut Context) {
+        let val = *ctx.widget().get::<f64>("val");
+
+        if self.current_value.to_f64() == Some(val) {
+            return;
+        }
+
+        // the val is rounded to the decimal places of the step to drop float inaccuracies
+        if let Some(val) = Decimal::from_f64(val) {
+            let scale = self.step.scale().max(self.current_value.scale());
+            self.current_value = self.max(self.min(val.round_dp(scale)));
+
+            if let Some(val) = self.current_value.to_f64() {
+                ctx.widget().set("val", val);
+            }
+
+            ctx.get_widget(self.input)
+                .set::<String16>("text", String16::from(self.current_value.to_string()));
+        }
+    }
+
     fn min(&self, d: Decimal) -> Decimal {
         if d <= self.min {
             return self.min;
@@ -103,6 +124,8 @@ impl State for NumericBoxState {
 
     // TODO: let the user type the value, or select it for cut, copy, paste operations
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.sync_val(ctx);
+
         if let Some(action) = &self.action {
             match action {
                 InputAction::Inc => {
@@ -112,10 +135,10 @@ impl State for NumericBoxState {
                     self.change_val(self.current_value - self.step, ctx);
                 }
                 InputAction::ChangeByKey(key_event) => match key_event.key {
-                    Key::Up | Key::NumpadAdd => {
+                    Key::NumpadAdd => {
                         self.change_val(self.current_value + self.step, ctx);
                     }
-                    Key::Down | Key::NumpadSubtract => {
+                    Key::NumpadSubtract => {
                         self.change_val(self.current_value - self.step, ctx);
                     }
                     Key::Enter => {
@@ -131,13 +154,6 @@ impl State for NumericBoxState {
                     }
                     _ => {}
                 },
-                InputAction::ChangeByMouseScroll(delta) => {
-                    if delta.y() < 0.0 {
-                        self.change_val(self.current_value - self.step, ctx);
-                    } else {
-                        self.change_val(self.current_value + self.step, ctx);
-                    }
-                }
                 InputAction::Focus => {
                     self.request_focus(ctx);
                 }
@@ -152,7 +168,8 @@ widget!(
     /// the value of the input by a given, fixed value called `step` until it reaches the upper or
     /// lower bounds.
     /// The widget can be controlled by clicking on the two control buttons, or the keybaord's
-    /// Up and Down, Numpad+ and Numpad- keys, or the mouse scroll.
+    /// Up and Down, Numpad+ and Numpad- keys, or the mouse scroll. With Shift pressed the Up and Down keys
+    /// and the mouse scroll change the value by ten steps.
     /// Note: after the widget is initialized, changing the min, max or step properties has no effect.
     ///
     /// # Examples:
@@ -176,11 +193,18 @@ widget!(
         border_width: Thickness,
 
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the focused property
         focused: bool,
 
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
         /// Sets or shares the foreground color property
         foreground: Brush,
 
@@ -211,6 +235,8 @@ impl Template for NumericBox {
             .border_width(1.0)
             .border_radius(3.0)
             .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .height(32.0)
             .lost_focus_on_activation(true)
             .min(0.0)
@@ -226,12 +252,16 @@ impl Template for NumericBox {
                             .action(InputAction::Focus);
                         true
                     })
-                    .on_scroll(move |states, delta| {
-                        states
-                            .get_mut::<NumericBoxState>(id)
-                            .action(InputAction::ChangeByMouseScroll(delta));
-                        true
-                    })
+                    .build(ctx),
+            )
+            .child(
+                ValueChangedBehavior::new()
+                    .target(id.0)
+                    .val(id)
+                    .min(id)
+                    .max(id)
+                    .step(id)
+                    .focused(id)
                     .build(ctx),
             )
             .child(
@@ -258,6 +288,7 @@ impl Template for NumericBox {
                     )
                     .child(
                         Button::new()
+                            .focusable(false)
                             .style("button_small")
                             .attach(Grid::column(1))
                             .attach(Grid::row(0))
@@ -275,6 +306,7 @@ impl Template for NumericBox {
                     )
                     .child(
                         Button::new()
+                            .focusable(false)
                             .style("button_small")
                             .attach(Grid::column(1))
                             .attach(Grid::row(1))
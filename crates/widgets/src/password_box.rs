@@ -3,14 +3,19 @@ use crate::prelude::*;
 use crate::shell::prelude::KeyEvent;
 use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
 
+// --- KEYS --
+pub static STYLE_PASSWORD_BOX_REVEAL: &str = "password_box_reveal";
+static ID_REVEAL: &str = "password_box_reveal";
+// --- KEYS --
+
 enum PasswordAction {
     Key(KeyEvent),
+    ToggleReveal,
 }
 
 #[derive(Default, AsAny)]
 struct PasswordBoxState {
     action: Option<PasswordAction>,
-    echo: char,
 }
 
 impl PasswordBoxState {
@@ -18,32 +23,56 @@ impl PasswordBoxState {
         self.action = Some(action);
     }
 
+    // updates the displayed text. If the password is revealed the plain text is shown.
     fn mask(&mut self, ctx: &mut Context) {
+        let text = password_box(ctx.widget()).clone_text();
+
+        if *password_box(ctx.widget()).revealed() {
+            password_box(ctx.widget()).set_mask(text);
+            return;
+        }
+
+        let echo = *password_box(ctx.widget()).echo();
         let mut new_prompt = String16::new();
 
-        for _ in ctx.widget().get::<String16>("text").as_string().chars() {
-            new_prompt.push(self.echo);
+        for _ in text.as_string().chars() {
+            new_prompt.push(echo);
         }
 
-        ctx.widget().set::<String16>("mask", new_prompt);
+        password_box(ctx.widget()).set_mask(new_prompt);
+    }
+
+    fn update_reveal_icon(&self, ctx: &mut Context) {
+        let icon = if *password_box(ctx.widget()).revealed() {
+            material_icons_font::MD_VISIBILITY_OFF
+        } else {
+            material_icons_font::MD_VISIBILITY
+        };
+
+        button(ctx.child(ID_REVEAL)).set_icon(icon);
     }
 }
 
 impl State for PasswordBoxState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
-        self.echo = ctx.widget().clone("echo");
+        self.mask(ctx);
+        self.update_reveal_icon(ctx);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(action) = &self.action {
+        if let Some(action) = self.action.take() {
             match action {
-                PasswordAction::Key(_key_event) => {
-                    self.mask(ctx);
+                PasswordAction::Key(_key_event) => {}
+                PasswordAction::ToggleReveal => {
+                    let revealed = !*password_box(ctx.widget()).revealed();
+                    password_box(ctx.widget()).set_revealed(revealed);
                 }
             }
-
-            self.action = None;
         }
+
+        // the text or the revealed property could also be changed from outside.
+        self.mask(ctx);
+        self.update_reveal_icon(ctx);
     }
 }
 
@@ -58,9 +87,10 @@ widget!(
     /// You can process this value in your application and set the authentication logic as appropriate.
     /// It is a good practice to clear the content of the `text` property after the value is used.
     ///
+    /// The password can be shown in plain text with the reveal toggle button or by setting the `revealed` property.
+    ///
     /// Notes:
     /// * If the input is empty, it will render the content of the `water_mark` property.
-    /// * Changing the `echo` property after the `PasswordBox` is created has no effect.
     /// * The password is stored in plain text currently
     ///
     /// For an example how to use the PasswordBox, check the [`example`].
@@ -77,6 +107,9 @@ widget!(
         /// Sets or shares the text property.It holds the password.
         text: String16,
 
+        /// Sets or shares the value if the password is shown in plain text.
+        revealed: bool,
+
         /// Sets or shares the water_mark text property.
         water_mark: String16,
 
@@ -96,7 +129,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -110,6 +143,13 @@ widget!(
         /// Sets or shares the focused property.
         focused: bool,
 
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
         /// Sets or shares ta value that describes if the PasswordBox should lost focus on activation (when Enter pressed).
         lost_focus_on_activation: bool,
 
@@ -138,7 +178,7 @@ impl Template for PasswordBox {
             .build(ctx);
 
         let text_behavior = TextBehavior::new()
-            .cursor(cursor.0)
+            .text_cursor(cursor.0)
             .focused(id)
             .font(id)
             .font_size(id)
@@ -151,7 +191,9 @@ impl Template for PasswordBox {
 
         self.name("PasswordBox")
             .style(STYLE_TEXT_BOX)
+            .cursor(CursorIcon::Text)
             .echo('*')
+            .revealed(false)
             .text("")
             .mask("")
             .water_mark("Password")
@@ -167,6 +209,8 @@ impl Template for PasswordBox {
             .min_width(128.0)
             .height(32.0)
             .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .lost_focus_on_activation(true)
             .child(text_behavior)
             .child(
@@ -178,11 +222,33 @@ impl Template for PasswordBox {
                     .padding(id)
                     .child(
                         Grid::new()
-                            .clip(true)
-                            // It is important that cursor is the first child
-                            // should be refactored in the future.
-                            .child(cursor)
-                            .child(text_block)
+                            .columns(Columns::create().push("*").push("auto"))
+                            .child(
+                                Grid::new()
+                                    .clip(true)
+                                    // It is important that cursor is the first child
+                                    // should be refactored in the future.
+                                    .child(cursor)
+                                    .child(text_block)
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .focusable(false)
+                                    .id(ID_REVEAL)
+                                    .style(STYLE_PASSWORD_BOX_REVEAL)
+                                    .attach(Grid::column(1))
+                                    .v_align("center")
+                                    .min_width(24.0)
+                                    .icon(material_icons_font::MD_VISIBILITY)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<PasswordBoxState>(id)
+                                            .action(PasswordAction::ToggleReveal);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
                             .build(ctx),
                     )
                     .build(ctx),
@@ -52,7 +52,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -60,6 +60,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the drop shadow of the box, e.g. `"0px 2px 8px 0px #66000000"`.
+        box_shadow: BoxShadow,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
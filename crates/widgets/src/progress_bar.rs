@@ -1,8 +1,14 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::now};
 
 static RANGE_MIN: f64 = 0.0;
 static RANGE_MAX: f64 = 1.0;
 
+// Width of the moving indicator segment in indeterminate mode relative to the width of the bar.
+static INDETERMINATE_WIDTH: f64 = 0.25;
+
+// Time in milliseconds the indicator segment needs to move from the start to the end of the bar in indeterminate mode.
+static INDETERMINATE_DURATION: f64 = 1500.0;
+
 // --- KEYS --
 
 pub static STYLE_PROGRESS_BAR: &str = "progress_bar";
@@ -14,6 +20,7 @@ static ID_INDICATOR: &str = "PGBAR_INDICATOR";
 #[derive(Default, AsAny)]
 struct BarState {
     indicator: Entity,
+    indeterminate_start: Option<f64>,
 }
 
 impl State for BarState {
@@ -25,18 +32,57 @@ impl State for BarState {
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        let val = ctx.widget().clone_or_default::<f64>("val");
         let max_width = ctx.widget().get::<Rectangle>("bounds").width()
             - ctx.widget().get::<Thickness>("padding").left()
             - ctx.widget().get::<Thickness>("padding").right();
-        let new_width = calculate_width(val, max_width);
 
+        if *ctx.widget().get::<bool>("indeterminate") {
+            // the offset depends on the elapsed time, so the speed is the same at each frame rate
+            let time = now();
+            let start = *self.indeterminate_start.get_or_insert(time);
+            let indicator_width = max_width * INDETERMINATE_WIDTH;
+            let offset = calculate_indeterminate_offset(
+                time - start,
+                INDETERMINATE_DURATION,
+                max_width,
+                indicator_width,
+            );
+
+            ctx.get_widget(self.indicator)
+                .get_mut::<Constraint>("constraint")
+                .set_width(indicator_width);
+            ctx.get_widget(self.indicator)
+                .get_mut::<Thickness>("margin")
+                .set_left(offset);
+
+            ctx.request_next_frame();
+            return;
+        }
+
+        self.indeterminate_start = None;
+
+        let val = ctx.widget().clone_or_default::<f64>("val");
+        let min = ctx.widget().clone_or_default::<f64>("min");
+        let max = ctx.widget().clone_or_default::<f64>("max");
+        let new_width = calculate_width(calculate_progress(val, min, max), max_width);
+
+        ctx.get_widget(self.indicator)
+            .get_mut::<Thickness>("margin")
+            .set_left(0.0);
         ctx.get_widget(self.indicator)
             .get_mut::<Constraint>("constraint")
             .set_width(new_width);
     }
 }
 
+fn calculate_progress(val: f64, min: f64, max: f64) -> f64 {
+    if max - min <= 0.0 {
+        return RANGE_MAX;
+    }
+
+    (val - min) / (max - min)
+}
+
 fn calculate_width(current_progress: f64, max_width: f64) -> f64 {
     if (current_progress - RANGE_MIN).abs() < f64::EPSILON {
         return 0.01;
@@ -48,13 +94,33 @@ fn calculate_width(current_progress: f64, max_width: f64) -> f64 {
     max_width * 0.99
 }
 
+// Calculates the offset of the indicator segment after the given elapsed time, it starts again at the start of the bar
+// after each duration.
+fn calculate_indeterminate_offset(
+    elapsed: f64,
+    duration: f64,
+    max_width: f64,
+    indicator_width: f64,
+) -> f64 {
+    let distance = (max_width - indicator_width).max(0.0);
+
+    if duration <= 0.0 {
+        return 0.0;
+    }
+
+    (elapsed.max(0.0) % duration) / duration * distance
+}
+
 widget!(
     /// The `ProgressBar` widget is used to indicating a finite progress
     /// (e.g. copying a file, downloading a video from the internet).
     /// A progress is visually represented as a horizontal bar which grows when the progress advances.
-    /// The ProgressBar expects values between 0.0 and 1.0, whereas 0.0 means 0%, and 1.0 means 100%.
+    /// The ProgressBar expects values between `min` (default 0.0) and `max` (default 1.0), whereas `min` means 0%, and `max` means 100%.
     /// Any value outside of this range considered as 100%.
     ///
+    /// If the progress is unknown the `indeterminate` property could be set to `true`. Then a moving segment is shown
+    /// instead of the current progress.
+    ///
     /// This example creates a ProgressBar with default values:
     /// ```rust
     /// ProgressBar::new().build(ctx)
@@ -65,6 +131,11 @@ widget!(
     /// ProgressBar::new().val(0.25).build(ctx)
     /// ```
     ///
+    /// The next example creates a ProgressBar with a custom range and a progress of 50%:
+    /// ```rust
+    /// ProgressBar::new().min(100.0).max(200.0).val(150.0).build(ctx)
+    /// ```
+    ///
     /// The progress can be controlled by changing the value of the `val` property.
     /// (this code assumes that you have a children with id "pgbar")
     /// ```rust
@@ -76,13 +147,19 @@ widget!(
         /// Sets or shares the border color property
         border_brush: Brush,
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: BorderRadius,
         /// Sets or shares the border width property
         border_width: Thickness,
         /// Sets or shares the padding property
         padding: Thickness,
         /// Sets or shares the current progress property
-        val: f64
+        val: f64,
+        /// Sets or shares the min val of the progress range
+        min: f64,
+        /// Sets or shares the max val of the progress range
+        max: f64,
+        /// Sets or shares the indeterminate property. If it is `true` a moving segment is shown instead of the progress
+        indeterminate: bool
     }
 );
 
@@ -91,6 +168,9 @@ impl Template for ProgressBar {
         self.name("ProgressBar")
             .style(STYLE_PROGRESS_BAR)
             .val(0.0)
+            .min(RANGE_MIN)
+            .max(RANGE_MAX)
+            .indeterminate(false)
             .background("#000000")
             .border_brush("#BABABA")
             .border_radius(4)
@@ -135,4 +215,23 @@ mod tests {
         assert!((99.0 - calculate_width(1.23, 100.0)).abs() < ERROR);
         assert!((99.0 - calculate_width(-1.23, 100.0)).abs() < ERROR);
     }
+
+    #[test]
+    fn test_calculate_progress() {
+        assert!((0.0 - calculate_progress(0.0, 0.0, 1.0)).abs() < ERROR);
+        assert!((0.5 - calculate_progress(0.5, 0.0, 1.0)).abs() < ERROR);
+        assert!((0.5 - calculate_progress(150.0, 100.0, 200.0)).abs() < ERROR);
+        assert!((1.0 - calculate_progress(10.0, 10.0, 10.0)).abs() < ERROR);
+    }
+
+    #[test]
+    fn test_calculate_indeterminate_offset() {
+        assert!((0.0 - calculate_indeterminate_offset(0.0, 1000.0, 100.0, 25.0)).abs() < ERROR);
+        assert!((37.5 - calculate_indeterminate_offset(500.0, 1000.0, 100.0, 25.0)).abs() < ERROR);
+        assert!(
+            (18.75 - calculate_indeterminate_offset(1250.0, 1000.0, 100.0, 25.0)).abs() < ERROR
+        );
+        assert!((0.0 - calculate_indeterminate_offset(500.0, 1000.0, 20.0, 25.0)).abs() < ERROR);
+        assert!((0.0 - calculate_indeterminate_offset(500.0, 0.0, 100.0, 25.0)).abs() < ERROR);
+    }
 }
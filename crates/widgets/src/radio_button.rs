@@ -0,0 +1,228 @@
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_RADIO_BUTTON: &str = "radio_button";
+
+// --- KEYS --
+
+/// The `RadioButtonState` handles the selection of the `RadioButton` widget and deselects the other radio buttons
+/// of its group.
+#[derive(Default, AsAny)]
+pub struct RadioButtonState {
+    select: bool,
+    selected: bool,
+}
+
+impl RadioButtonState {
+    fn select(&mut self) {
+        self.select = true;
+    }
+
+    // Deselects all other selected radio buttons of the same group. Radio buttons without a group are grouped by
+    // their parent.
+    fn deselect_group(&mut self, ctx: &mut Context) {
+        let group = ctx.widget().clone::<String>("group");
+
+        let scope = if group.is_empty() {
+            ctx.entity_of_parent()
+        } else {
+            Some(ctx.window().entity())
+        };
+
+        let scope = match scope {
+            Some(scope) => scope,
+            None => return,
+        };
+
+        for radio_button in ctx.entities_by_property(scope, "group", &group) {
+            if radio_button == ctx.entity {
+                continue;
+            }
+
+            let mut radio_button = ctx.get_widget(radio_button);
+
+            if radio_button.try_clone::<bool>("selected") == Some(true) {
+                radio_button.set("selected", false);
+            }
+        }
+    }
+
+    fn update_visual(&mut self, ctx: &mut Context) {
+        {
+            let mut widget = ctx.widget();
+            let selector = widget.get_mut::<Selector>("selector");
+
+            if self.selected {
+                selector.set_state("selected");
+            } else {
+                selector.clear_state();
+            }
+        }
+
+        ctx.widget().update(false);
+    }
+}
+
+impl State for RadioButtonState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.selected = *ctx.widget().get::<bool>("selected");
+
+        if self.selected {
+            self.deselect_group(ctx);
+        }
+
+        self.update_visual(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // a click selects the radio button, it is only deselected by the selection of another one
+        if self.select {
+            self.select = false;
+
+            if *ctx.widget().get::<bool>("enabled") && !*ctx.widget().get::<bool>("selected") {
+                ctx.widget().set("selected", true);
+            }
+        }
+
+        let selected = *ctx.widget().get::<bool>("selected");
+
+        if selected && !self.selected {
+            self.deselect_group(ctx);
+        }
+
+        self.selected = selected;
+        self.update_visual(ctx);
+    }
+}
+
+widget!(
+    /// The `RadioButton` widget could be selected by a click. All radio buttons with the same `group` inside of a
+    /// window are mutually exclusive: if one of them is selected the others are deselected. Radio buttons without
+    /// a group are grouped with the other radio buttons inside of the same parent.
+    ///
+    /// **style:** `radio_button`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Stack::new()
+    ///     .child(RadioButton::new().text("Small").group("size").selected(true).build(ctx))
+    ///     .child(RadioButton::new().text("Large").group("size").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    RadioButton<RadioButtonState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the brush of the selection mark.
+        icon_brush: Brush,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the name of the group of mutually exclusive radio buttons.
+        group: String,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
+    }
+);
+
+impl Template for RadioButton {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("RadioButton")
+            .style(STYLE_RADIO_BUTTON)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
+            .selected(false)
+            .group("")
+            .height(24.0)
+            .background("transparent")
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon_brush("transparent")
+            .pressed(false)
+            .on_click(move |states, _| {
+                states.get_mut::<RadioButtonState>(id).select();
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .spacing(8.0)
+                            .child(
+                                Container::new()
+                                    .size(20.0, 20.0)
+                                    .v_align("center")
+                                    .background(id)
+                                    .border_radius(10.0)
+                                    .border_width(id)
+                                    .border_brush(id)
+                                    .opacity(id)
+                                    .child(
+                                        Container::new()
+                                            .size(10.0, 10.0)
+                                            .v_align("center")
+                                            .h_align("center")
+                                            .background(("icon_brush", id))
+                                            .border_radius(5.0)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .child(
+                                TextBlock::new()
+                                    .v_align("center")
+                                    .foreground(id)
+                                    .text(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .opacity(id)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -0,0 +1,421 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*};
+
+// --- KEYS --
+pub static STYLE_RANGE_SLIDER: &str = "range_slider";
+pub static STYLE_RANGE_SLIDER_SELECTION: &str = "range_slider_selection";
+static ID_LOWER_THUMB: &str = "id_lower_thumb";
+static ID_UPPER_THUMB: &str = "id_upper_thumb";
+static ID_SELECTION: &str = "id_selection";
+static ID_TRACK: &str = "id_track";
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum RangeSliderAction {
+    Move { mouse_x: f64 },
+}
+
+/// The `RangeSliderState` is used to manipulate the position of the two thumbs of the range slider widget.
+#[derive(Default, AsAny)]
+pub struct RangeSliderState {
+    action: Option<RangeSliderAction>,
+    lower_val: f64,
+    upper_val: f64,
+    min: f64,
+    max: f64,
+    lower_thumb: Entity,
+    upper_thumb: Entity,
+    selection: Entity,
+    track: Entity,
+}
+
+impl RangeSliderState {
+    // register an action
+    fn action(&mut self, action: RangeSliderAction) {
+        self.action = Some(action);
+    }
+
+    // adjust min, max, lower_val and upper_val
+    fn adjust(&mut self, ctx: &mut Context) -> bool {
+        let mut has_changes = false;
+        let error = f64::EPSILON;
+
+        let min = *ctx.widget().get::<f64>("min");
+        let max = *ctx.widget().get::<f64>("max");
+
+        if (min - self.min).abs() > error {
+            let min = adjust_min(min, max);
+            ctx.widget().set("min", min);
+            self.min = min;
+            has_changes = true;
+        }
+
+        if (max - self.max).abs() > error {
+            let max = adjust_max(*ctx.widget().get::<f64>("min"), max);
+            ctx.widget().set("max", max);
+            self.max = max;
+            has_changes = true;
+        }
+
+        let min = *ctx.widget().get::<f64>("min");
+        let max = *ctx.widget().get::<f64>("max");
+
+        if (*ctx.widget().get::<f64>("lower_val") - self.lower_val).abs() > error {
+            let lower_val = adjust_val(
+                *ctx.widget().get::<f64>("lower_val"),
+                min,
+                *ctx.widget().get::<f64>("upper_val"),
+            );
+            ctx.widget().set("lower_val", lower_val);
+            self.lower_val = lower_val;
+            has_changes = true;
+        }
+
+        if (*ctx.widget().get::<f64>("upper_val") - self.upper_val).abs() > error {
+            let upper_val = adjust_val(
+                *ctx.widget().get::<f64>("upper_val"),
+                *ctx.widget().get::<f64>("lower_val"),
+                max,
+            );
+            ctx.widget().set("upper_val", upper_val);
+            self.upper_val = upper_val;
+            has_changes = true;
+        }
+
+        has_changes
+    }
+
+    // adjust the position of both thumbs and the selection between them
+    fn adjust_thumbs_x(&self, ctx: &mut Context) {
+        let lower_val = *ctx.widget().get::<f64>("lower_val");
+        let upper_val = *ctx.widget().get::<f64>("upper_val");
+        let min = *ctx.widget().get::<f64>("min");
+        let max = *ctx.widget().get::<f64>("max");
+
+        let thumb_width = ctx
+            .get_widget(self.lower_thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        let track_width = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        let lower_x = calculate_thumb_x_from_val(lower_val, min, max, track_width, thumb_width);
+        let upper_x = calculate_thumb_x_from_val(upper_val, min, max, track_width, thumb_width);
+
+        ctx.get_widget(self.lower_thumb)
+            .get_mut::<Thickness>("margin")
+            .set_left(lower_x);
+        ctx.get_widget(self.upper_thumb)
+            .get_mut::<Thickness>("margin")
+            .set_left(upper_x);
+
+        self.adjust_selection(ctx, lower_x, upper_x, thumb_width);
+    }
+
+    // adjust the highlighted track segment between the two thumbs
+    fn adjust_selection(&self, ctx: &mut Context, lower_x: f64, upper_x: f64, thumb_width: f64) {
+        ctx.get_widget(self.selection)
+            .get_mut::<Thickness>("margin")
+            .set_left(lower_x + thumb_width / 2.0);
+        ctx.get_widget(self.selection)
+            .get_mut::<Constraint>("constraint")
+            .set_width(calculate_selection_width(lower_x, upper_x));
+    }
+}
+
+impl State for RangeSliderState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.lower_thumb = ctx
+            .entity_of_child(ID_LOWER_THUMB)
+            .expect("RangeSliderState.init: Lower thumb child could not be found.");
+        self.upper_thumb = ctx
+            .entity_of_child(ID_UPPER_THUMB)
+            .expect("RangeSliderState.init: Upper thumb child could not be found.");
+        self.selection = ctx
+            .entity_of_child(ID_SELECTION)
+            .expect("RangeSliderState.init: Selection child could not be found.");
+        self.track = ctx
+            .entity_of_child(ID_TRACK)
+            .expect("RangeSliderState.init: Track child could not be found.");
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action {
+            match action {
+                RangeSliderAction::Move { mouse_x } => {
+                    let lower_pressed = *ctx.get_widget(self.lower_thumb).get::<bool>("pressed");
+                    let upper_pressed = *ctx.get_widget(self.upper_thumb).get::<bool>("pressed");
+
+                    if lower_pressed || upper_pressed {
+                        let thumb_width = ctx
+                            .get_widget(self.lower_thumb)
+                            .get::<Rectangle>("bounds")
+                            .width();
+                        let track_width = ctx
+                            .get_widget(self.track)
+                            .get::<Rectangle>("bounds")
+                            .width();
+                        let slider_x = ctx.widget().get::<Point>("position").x();
+
+                        let mut lower_x = ctx
+                            .get_widget(self.lower_thumb)
+                            .get::<Thickness>("margin")
+                            .left();
+                        let mut upper_x = ctx
+                            .get_widget(self.upper_thumb)
+                            .get::<Thickness>("margin")
+                            .left();
+
+                        let thumb_x =
+                            calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
+
+                        let min = *ctx.widget().get("min");
+                        let max = *ctx.widget().get("max");
+
+                        if lower_pressed {
+                            lower_x = thumb_x.min(upper_x);
+                            ctx.get_widget(self.lower_thumb)
+                                .get_mut::<Thickness>("margin")
+                                .set_left(lower_x);
+                            let lower_val =
+                                calculate_val(lower_x, min, max, thumb_width, track_width);
+                            self.lower_val = lower_val;
+                            ctx.widget().set("lower_val", lower_val);
+                        } else {
+                            upper_x = thumb_x.max(lower_x);
+                            ctx.get_widget(self.upper_thumb)
+                                .get_mut::<Thickness>("margin")
+                                .set_left(upper_x);
+                            let upper_val =
+                                calculate_val(upper_x, min, max, thumb_width, track_width);
+                            self.upper_val = upper_val;
+                            ctx.widget().set("upper_val", upper_val);
+                        }
+
+                        self.adjust_selection(ctx, lower_x, upper_x, thumb_width);
+                    } else {
+                        ctx.widget().clear_dirty();
+                    }
+                }
+            }
+
+            self.action = None;
+            return;
+        }
+
+        if self.adjust(ctx) {
+            self.adjust_thumbs_x(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `RangeSlider` allows to select a lower and an upper val inside of a range of values.
+    ///
+    /// Each change of `lower_val` or `upper_val` raises a `ChangedEvent` with the corresponding key.
+    ///
+    /// **style:** `range_slider`
+    RangeSlider<RangeSliderState>: MouseHandler {
+        /// Sets or shares the min val of the range.
+        min: f64,
+
+        /// Sets or shares the max val of the range.
+        max: f64,
+
+        /// Sets or shares the lower val of the selected range.
+        lower_val: f64,
+
+        /// Sets or shares the upper val of the selected range.
+        upper_val: f64,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
+    }
+);
+
+impl Template for RangeSlider {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("RangeSlider")
+            .style(STYLE_RANGE_SLIDER)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
+            .min(0.0)
+            .max(100.0)
+            .lower_val(0.0)
+            .upper_val(100.0)
+            .height(24.0)
+            .border_radius(2.0)
+            .child(
+                Grid::new()
+                    .margin((8, 0))
+                    .id(ID_TRACK)
+                    .child(
+                        Container::new()
+                            .border_radius(id)
+                            .background(id)
+                            .v_align("center")
+                            .height(2.0)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .style(STYLE_RANGE_SLIDER_SELECTION)
+                            .id(ID_SELECTION)
+                            .border_radius(id)
+                            .v_align("center")
+                            .h_align("start")
+                            .height(2.0)
+                            .width(0.0)
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .focusable(false)
+                            .style("thumb")
+                            .id(ID_LOWER_THUMB)
+                            .v_align("center")
+                            .h_align("start")
+                            .max_width(24.0)
+                            .max_height(24.0)
+                            .border_radius(12.0)
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .focusable(false)
+                            .style("thumb")
+                            .id(ID_UPPER_THUMB)
+                            .v_align("center")
+                            .h_align("start")
+                            .max_width(24.0)
+                            .max_height(24.0)
+                            .border_radius(12.0)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<RangeSliderState>(id)
+                    .action(RangeSliderAction::Move { mouse_x: p.x() });
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+fn adjust_val(val: f64, min: f64, max: f64) -> f64 {
+    if val < min {
+        return min;
+    }
+
+    if val > max {
+        return max;
+    }
+
+    val
+}
+
+fn adjust_min(min: f64, max: f64) -> f64 {
+    if min > max {
+        return max;
+    }
+
+    min
+}
+
+fn adjust_max(min: f64, max: f64) -> f64 {
+    if max < min {
+        return min;
+    }
+
+    max
+}
+
+fn calculate_thumb_x(mouse_x: f64, thumb_width: f64, slider_x: f64, track_width: f64) -> f64 {
+    (mouse_x - slider_x - thumb_width)
+        .max(0.0)
+        .min(track_width - thumb_width)
+}
+
+fn calculate_val(thumb_x: f64, min: f64, max: f64, thumb_width: f64, track_width: f64) -> f64 {
+    min + thumb_x / (track_width - thumb_width) * (max - min)
+}
+
+fn calculate_thumb_x_from_val(
+    val: f64,
+    min: f64,
+    max: f64,
+    track_width: f64,
+    thumb_width: f64,
+) -> f64 {
+    ((val - min) / (max - min)) * (track_width - thumb_width)
+}
+
+fn calculate_selection_width(lower_x: f64, upper_x: f64) -> f64 {
+    (upper_x - lower_x).max(0.0)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_calculate_val() {
+        assert!((0.0 - calculate_val(0.0, 0.0, 100.0, 32.0, 100.0)).abs() < ERROR);
+        assert!((50.0 - calculate_val(34.0, 0.0, 100.0, 32.0, 100.0)).abs() < ERROR);
+        assert!((100.0 - calculate_val(68.0, 0.0, 100.0, 32.0, 100.0)).abs() < ERROR);
+        assert!((-50.0 - calculate_val(0.0, -50.0, 50.0, 32.0, 100.0)).abs() < ERROR);
+        assert!((0.0 - calculate_val(34.0, -50.0, 50.0, 32.0, 100.0)).abs() < ERROR);
+        assert!((50.0 - calculate_val(68.0, -50.0, 50.0, 32.0, 100.0)).abs() < ERROR);
+    }
+
+    #[test]
+    fn test_calculate_thumb_x_from_val() {
+        assert!((0.0 - calculate_thumb_x_from_val(0.0, 0.0, 100.0, 100.0, 32.0)).abs() < ERROR);
+        assert!((34.0 - calculate_thumb_x_from_val(50.0, 0.0, 100.0, 100.0, 32.0)).abs() < ERROR);
+        assert!((68.0 - calculate_thumb_x_from_val(100.0, 0.0, 100.0, 100.0, 32.0)).abs() < ERROR);
+        assert!((34.0 - calculate_thumb_x_from_val(0.0, -50.0, 50.0, 100.0, 32.0)).abs() < ERROR);
+    }
+
+    #[test]
+    fn test_calculate_selection_width() {
+        assert!((0.0 - calculate_selection_width(10.0, 10.0)).abs() < ERROR);
+        assert!((40.0 - calculate_selection_width(10.0, 50.0)).abs() < ERROR);
+        assert!((0.0 - calculate_selection_width(50.0, 10.0)).abs() < ERROR);
+    }
+
+    #[test]
+    fn test_adjust_val() {
+        assert!((0.0 - adjust_val(-10.0, 0.0, 100.0)).abs() < ERROR);
+        assert!((10.0 - adjust_val(10.0, 0.0, 100.0)).abs() < ERROR);
+        assert!((100.0 - adjust_val(500.0, 0.0, 100.0)).abs() < ERROR);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{
+    api::prelude::*,
+    proc_macros::*,
+    shell::prelude::{open_url, Clipboard, Key, KeyEvent, MouseButton, CONSOLE},
+    theme::prelude::*,
+};
+
+// --- KEYS --
+
+pub static STYLE_RICH_TEXT_BLOCK: &str = "rich_text_block";
+
+// --- KEYS --
+
+#[derive(Clone)]
+enum RichTextAction {
+    Press(Point),
+    Drag(Point),
+    Release(Point),
+    Move(Point),
+    Key(KeyEvent),
+}
+
+/// The `RichTextBlockState` handles the selection and the links of the `RichTextBlock` widget.
+#[derive(Default, AsAny)]
+pub struct RichTextBlockState {
+    actions: Vec<RichTextAction>,
+    dragging: bool,
+    anchor: usize,
+    pressed_link: Option<String>,
+    over_link: bool,
+}
+
+impl RichTextBlockState {
+    fn action(&mut self, action: RichTextAction) {
+        self.actions.push(action);
+    }
+
+    // Returns the rich text, its style and the fragments of its lines with the current bounds.
+    fn layout(&self, ctx: &mut Context) -> (RichText, RichTextStyle, Vec<TextFragment>) {
+        let (rich_text, style, width) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<RichText>("rich_text"),
+                rich_text_style(&widget),
+                widget.get::<Rectangle>("bounds").width(),
+            )
+        };
+
+        let (fragments, _) =
+            rich_text.layout(&style, width, rich_text_measure(ctx.render_context_2_d()));
+
+        (rich_text, style, fragments)
+    }
+
+    // Converts a position of the window to a position relative to the widget.
+    fn local_position(&self, ctx: &mut Context, position: Point) -> Point {
+        let widget_position = *ctx.widget().get::<Point>("position");
+        Point::new(
+            position.x() - widget_position.x(),
+            position.y() - widget_position.y(),
+        )
+    }
+
+    fn index_at(&self, ctx: &mut Context, position: Point) -> usize {
+        let position = self.local_position(ctx, position);
+        let (rich_text, style, fragments) = self.layout(ctx);
+
+        rich_text.index_at(
+            &style,
+            &fragments,
+            position,
+            rich_text_measure(ctx.render_context_2_d()),
+        )
+    }
+
+    fn link_at(&self, ctx: &mut Context, position: Point) -> Option<String> {
+        let position = self.local_position(ctx, position);
+        let (rich_text, _, fragments) = self.layout(ctx);
+
+        rich_text.link_at(&fragments, position).map(String::from)
+    }
+
+    fn press(&mut self, ctx: &mut Context, position: Point) {
+        self.pressed_link = self.link_at(ctx, position);
+
+        if !*ctx.widget().get::<bool>("selectable") {
+            return;
+        }
+
+        if !*ctx.widget().get::<bool>("focused") {
+            ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+        }
+
+        self.anchor = self.index_at(ctx, position);
+        self.dragging = true;
+        ctx.widget()
+            .set("text_selection", TextSelection::from((self.anchor, 0)));
+    }
+
+    fn drag(&mut self, ctx: &mut Context, position: Point) {
+        if !self.dragging {
+            return;
+        }
+
+        let caret = self.index_at(ctx, position);
+
+        if caret != self.anchor {
+            // a selection does not open a link
+            self.pressed_link = None;
+        }
+
+        let start = self.anchor.min(caret);
+        let end = self.anchor.max(caret);
+        ctx.widget()
+            .set("text_selection", TextSelection::from((start, end - start)));
+    }
+
+    fn release(&mut self, ctx: &mut Context, position: Point) {
+        self.dragging = false;
+
+        if let Some(url) = self.pressed_link.take() {
+            if self.link_at(ctx, position).as_ref() == Some(&url) {
+                self.navigate(ctx, url);
+            }
+        }
+    }
+
+    // Raises the navigate event and opens the url in the browser if `open_in_browser` is set.
+    fn navigate(&mut self, ctx: &mut Context, url: String) {
+        ctx.push_event_strategy_by_entity(
+            NavigateEvent(ctx.entity, url.clone()),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+
+        if *ctx.widget().get::<bool>("open_in_browser") {
+            if let Err(e) = open_url(&url) {
+                CONSOLE.log(format!("RichTextBlock: {}", e));
+            }
+        }
+    }
+
+    // Shows the pointer cursor while the mouse is over a link.
+    fn update_cursor(&mut self, ctx: &mut Context, position: Point) {
+        let over_link = self.link_at(ctx, position).is_some();
+
+        if over_link == self.over_link {
+            return;
+        }
+
+        self.over_link = over_link;
+
+        ctx.widget().set(
+            "cursor",
+            if over_link {
+                CursorIcon::Pointer
+            } else {
+                CursorIcon::Default
+            },
+        );
+    }
+
+    fn handle_key(&mut self, registry: &mut Registry, ctx: &mut Context, event: KeyEvent) {
+        if !*ctx.widget().get::<bool>("focused") || !*ctx.widget().get::<bool>("selectable") {
+            return;
+        }
+
+        let ctrl_down = ctx
+            .window()
+            .get::<Global>("global")
+            .keyboard_state
+            .is_ctrl_down();
+
+        if !ctrl_down {
+            return;
+        }
+
+        match event.key {
+            Key::A(..) => {
+                let len = ctx.widget().get::<RichText>("rich_text").len();
+                ctx.widget()
+                    .set("text_selection", TextSelection::from((0, len)));
+            }
+            Key::C(..) => {
+                let selection = *ctx.widget().get::<TextSelection>("text_selection");
+
+                if selection.length == 0 {
+                    return;
+                }
+
+                let text = ctx.widget().get::<RichText>("rich_text").text_range(
+                    selection.start_index,
+                    selection.start_index + selection.length,
+                );
+                registry.get::<Clipboard>("clipboard").set(text);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl State for RichTextBlockState {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        for action in std::mem::take(&mut self.actions) {
+            match action {
+                RichTextAction::Press(position) => self.press(ctx, position),
+                RichTextAction::Drag(position) => self.drag(ctx, position),
+                RichTextAction::Release(position) => self.release(ctx, position),
+                RichTextAction::Move(position) => self.update_cursor(ctx, position),
+                RichTextAction::Key(event) => self.handle_key(registry, ctx, event),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `RichTextBlock` widget draws formatted text with different fonts, weights, colors and inline links. The
+    /// lines are wrapped at the width of the widget.
+    ///
+    /// The text is set by the `rich_text` property that is made of `TextSpan`s or created from a subset of Markdown
+    /// with `RichText::from_markdown`. Spans without own style use `font`, `bold_font`, `font_size`, `foreground` and
+    /// `link_foreground`.
+    ///
+    /// A click on a link raises a `NavigateEvent` with the url of the link, if `open_in_browser` is `true` the url
+    /// is opened in the browser of the system too. If `selectable` is `true` the text could be selected with the
+    /// mouse and copied with `Ctrl+C`.
+    ///
+    /// **style:** `rich_text_block`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// RichTextBlock::new()
+    ///     .rich_text(
+    ///         RichText::new()
+    ///             .span("Visit ")
+    ///             .span(TextSpan::new("OrbTk").bold().link("https://github.com/redox-os/orbtk"))
+    ///             .span(TextSpan::new(" on GitHub").foreground("#f8de4c")),
+    ///     )
+    ///     .build(ctx)
+    ///
+    /// RichTextBlock::new()
+    ///     .rich_text(RichText::from_markdown("Some **bold** text and a [link](app://settings)."))
+    ///     .open_in_browser(false)
+    ///     .on_navigate(|_, _, url| println!("Navigate to {}", url))
+    ///     .build(ctx)
+    /// ```
+    RichTextBlock<RichTextBlockState>: MouseHandler, KeyDownHandler, NavigateHandler {
+        /// Sets or shares the rich text property.
+        rich_text: RichText,
+
+        /// Sets or shares the foreground of spans without own foreground.
+        foreground: Brush,
+
+        /// Sets or shares the foreground of links without own foreground.
+        link_foreground: Brush,
+
+        /// Sets or shares the brush of the selected text.
+        selection_brush: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the font of bold spans.
+        bold_font: String,
+
+        /// Sets or shares the selected part of the text.
+        text_selection: TextSelection,
+
+        /// Sets or shares the flag if the text could be selected.
+        selectable: bool,
+
+        /// Sets or shares the flag if links are opened in the browser of the system.
+        open_in_browser: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool
+    }
+);
+
+impl Template for RichTextBlock {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("RichTextBlock")
+            .style(STYLE_RICH_TEXT_BLOCK)
+            .cursor(CursorIcon::Default)
+            .rich_text(RichText::default())
+            .foreground(colors::LINK_WATER_COLOR)
+            .link_foreground(colors::LINK_WATER_COLOR)
+            .selection_brush("transparent")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .bold_font("Roboto-Medium")
+            .text_selection(TextSelection::default())
+            .selectable(true)
+            .open_in_browser(true)
+            .focused(false)
+            .on_mouse_down(move |states, m| {
+                if m.button == MouseButton::Left {
+                    states
+                        .get_mut::<RichTextBlockState>(id)
+                        .action(RichTextAction::Press(m.position));
+                }
+                true
+            })
+            .on_mouse_move(move |states, p| {
+                let state = states.get_mut::<RichTextBlockState>(id);
+
+                if state.dragging {
+                    state.action(RichTextAction::Drag(p));
+                }
+
+                state.action(RichTextAction::Move(p));
+                false
+            })
+            .on_global_mouse_up(move |states, m| {
+                if states.get::<RichTextBlockState>(id).dragging
+                    || states.get::<RichTextBlockState>(id).pressed_link.is_some()
+                {
+                    states
+                        .get_mut::<RichTextBlockState>(id)
+                        .action(RichTextAction::Release(m.position));
+                }
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<RichTextBlockState>(id)
+                    .action(RichTextAction::Key(event));
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RichTextRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(RichTextLayout::new())
+    }
+}
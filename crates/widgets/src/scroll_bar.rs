@@ -3,6 +3,7 @@ use crate::{api::prelude::*, proc_macros::*};
 // --- KEYS --
 
 pub static STYLE_SCROLL_BAR: &str = "scroll_bar";
+pub static STYLE_SCROLL_TRACK: &str = "scroll_track";
 
 // --- KEYS --
 
@@ -10,12 +11,12 @@ widget!(
     /// The `ScrollBar` widget represents a position inside of a scroll container.
     ///
     /// **style:** `scroll_bar`
-    ScrollBar {
+    ScrollBar: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64
+        border_radius: BorderRadius
     }
 );
 
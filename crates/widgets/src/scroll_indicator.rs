@@ -3,13 +3,144 @@ use crate::{api::prelude::*, prelude::*, proc_macros::*};
 // --- KEYS --
 static ID_SCROLL_BAR_HORIZONTAL: &str = "scroll_bar_horizontal";
 static ID_SCROLL_BAR_VERTICAL: &str = "scroll_bar_vertical";
+static ID_SCROLL_TRACK_HORIZONTAL: &str = "scroll_track_horizontal";
+static ID_SCROLL_TRACK_VERTICAL: &str = "scroll_track_vertical";
 // --- KEYS --
 
+#[derive(Copy, Clone)]
+enum ScrollIndicatorAction {
+    Press(Orientation, Point),
+    Page(Orientation, Point),
+    Drag(Point),
+    EndDrag,
+}
+
 /// The `ScrollIndicatorState` handles the `ScrollIndicator` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollIndicatorState {
+    actions: Vec<ScrollIndicatorAction>,
     horizontal_scroll_bar: Entity,
     vertical_scroll_bar: Entity,
+    horizontal_scroll_track: Entity,
+    vertical_scroll_track: Entity,
+    interactive: bool,
+    drag: Option<(Orientation, Point, Thickness)>,
+}
+
+impl ScrollIndicatorState {
+    // Queues an action if the scroll bars are interactive. Returns `true` if the action is queued.
+    fn action(&mut self, action: ScrollIndicatorAction) -> bool {
+        if !self.interactive {
+            return false;
+        }
+
+        self.actions.push(action);
+        true
+    }
+
+    // Scrolls one view port page in direction of the given position.
+    fn page(&self, ctx: &mut Context, orientation: Orientation, position: Point) {
+        let position = match orientation {
+            Orientation::Vertical => position.y(),
+            Orientation::Horizontal => position.x(),
+        };
+
+        let (content_size, view_port_size, thumb_start, thumb_size, offset) =
+            self.scroll_values(ctx, orientation);
+
+        let page = if position < thumb_start {
+            view_port_size
+        } else if position > thumb_start + thumb_size {
+            -view_port_size
+        } else {
+            return;
+        };
+
+        self.set_scroll_offset(
+            ctx,
+            orientation,
+            clamp_offset(content_size, view_port_size, offset + page),
+        );
+    }
+
+    // Moves the content by the distance the thumb is dragged.
+    fn drag(&self, ctx: &mut Context, position: Point) {
+        if let Some((orientation, start, start_padding)) = self.drag {
+            let size = ctx.widget().get::<Rectangle>("bounds").size();
+            let content_size = ctx.widget().get::<Rectangle>("content_bounds").size();
+            let view_port_size = ctx.widget().get::<Rectangle>("view_port_bounds").size();
+
+            let offset = match orientation {
+                Orientation::Vertical => thumb_drag_offset(
+                    size.height(),
+                    content_size.height(),
+                    view_port_size.height(),
+                    start_padding.top(),
+                    position.y() - start.y(),
+                ),
+                Orientation::Horizontal => thumb_drag_offset(
+                    size.width(),
+                    content_size.width(),
+                    view_port_size.width(),
+                    start_padding.left(),
+                    position.x() - start.x(),
+                ),
+            };
+
+            self.set_scroll_offset(ctx, orientation, offset);
+        }
+    }
+
+    // Returns content size, view port size, absolute thumb start, thumb size and scroll offset for the given orientation.
+    fn scroll_values(
+        &self,
+        ctx: &mut Context,
+        orientation: Orientation,
+    ) -> (f64, f64, f64, f64, f64) {
+        let position = *ctx.widget().get::<Point>("position");
+        let padding = *ctx.widget().get::<Thickness>("padding");
+        let content_size = ctx.widget().get::<Rectangle>("content_bounds").size();
+        let view_port_size = ctx.widget().get::<Rectangle>("view_port_bounds").size();
+        let scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+
+        match orientation {
+            Orientation::Vertical => {
+                let thumb = *ctx
+                    .get_widget(self.vertical_scroll_bar)
+                    .get::<Rectangle>("bounds");
+                (
+                    content_size.height(),
+                    view_port_size.height(),
+                    position.y() + padding.top() + thumb.y(),
+                    thumb.height(),
+                    scroll_padding.top(),
+                )
+            }
+            Orientation::Horizontal => {
+                let thumb = *ctx
+                    .get_widget(self.horizontal_scroll_bar)
+                    .get::<Rectangle>("bounds");
+                (
+                    content_size.width(),
+                    view_port_size.width(),
+                    position.x() + padding.left() + thumb.x(),
+                    thumb.width(),
+                    scroll_padding.left(),
+                )
+            }
+        }
+    }
+
+    fn set_scroll_offset(&self, ctx: &mut Context, orientation: Orientation, offset: f64) {
+        let mut scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+
+        match orientation {
+            Orientation::Vertical => scroll_padding.set_top(offset),
+            Orientation::Horizontal => scroll_padding.set_left(offset),
+        }
+
+        ctx.widget().set("scroll_padding", scroll_padding);
+    }
 }
 
 impl State for ScrollIndicatorState {
@@ -18,11 +149,49 @@ impl State for ScrollIndicatorState {
             .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
             .expect("ScrollIndicatorState.init: scroll_bar_horizontal child could not be found.");
         self.vertical_scroll_bar = ctx
-            .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
+            .entity_of_child(ID_SCROLL_BAR_VERTICAL)
             .expect("ScrollIndicatorState.init: scroll_bar_vertical child could not be found.");
+        self.horizontal_scroll_track = ctx
+            .entity_of_child(ID_SCROLL_TRACK_HORIZONTAL)
+            .expect("ScrollIndicatorState.init: scroll_track_horizontal child could not be found.");
+        self.vertical_scroll_track = ctx
+            .entity_of_child(ID_SCROLL_TRACK_VERTICAL)
+            .expect("ScrollIndicatorState.init: scroll_track_vertical child could not be found.");
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<ScrollIndicatorAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                ScrollIndicatorAction::Press(orientation, position) => {
+                    let scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+                    self.drag = Some((orientation, position, scroll_padding));
+                }
+                ScrollIndicatorAction::Page(orientation, position) => {
+                    self.page(ctx, orientation, position);
+                }
+                ScrollIndicatorAction::Drag(position) => {
+                    self.drag(ctx, position);
+                }
+                ScrollIndicatorAction::EndDrag => {
+                    self.drag = None;
+                }
+            }
+        }
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let interactive = *ctx.widget().get::<bool>("interactive");
+
+        if interactive != self.interactive {
+            self.interactive = interactive;
+
+            if !interactive {
+                self.drag = None;
+            }
+        }
+
         let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
         if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
@@ -38,6 +207,8 @@ impl State for ScrollIndicatorState {
         // adjust vertical scroll bar
         if mode.vertical != ScrollMode::Disabled && content_size.height() > view_port_size.height()
         {
+            set_track_visibility(ctx, self.vertical_scroll_track, interactive);
+
             let mut scroll_bar = ctx.get_widget(self.vertical_scroll_bar);
 
             if *scroll_bar.get::<Visibility>("visibility") != Visibility::Visible {
@@ -58,11 +229,17 @@ impl State for ScrollIndicatorState {
                 content_size.height(),
                 scroll_padding.top(),
             ));
+        } else {
+            // the content fits into the view port, the track must not cover it
+            set_visibility(ctx, self.vertical_scroll_track, Visibility::Collapsed);
+            set_visibility(ctx, self.vertical_scroll_bar, Visibility::Collapsed);
         }
 
         // adjust horizontal scroll bar
         if mode.horizontal != ScrollMode::Disabled && content_size.width() > view_port_size.width()
         {
+            set_track_visibility(ctx, self.horizontal_scroll_track, interactive);
+
             let mut scroll_bar = ctx.get_widget(self.horizontal_scroll_bar);
 
             if *scroll_bar.get::<Visibility>("visibility") != Visibility::Visible {
@@ -83,13 +260,20 @@ impl State for ScrollIndicatorState {
                 content_size.width(),
                 scroll_padding.left(),
             ));
+        } else {
+            // the content fits into the view port, the track must not cover it
+            set_visibility(ctx, self.horizontal_scroll_track, Visibility::Collapsed);
+            set_visibility(ctx, self.horizontal_scroll_bar, Visibility::Collapsed);
         }
     }
 }
 
 widget!(
     /// The `ScrollIndicator` widget contains two scroll bars.
-    ScrollIndicator<ScrollIndicatorState> {
+    ///
+    /// If `interactive` is set to `true` the thumbs of the scroll bars could be dragged and a click on the track
+    /// scrolls the content by one page. The scroll position is written back to the shared `scroll_padding`.
+    ScrollIndicator<ScrollIndicatorState>: MouseHandler {
         /// Shares the mode of the `ScrollViewer`.
         mode: ScrollViewerMode,
 
@@ -103,7 +287,10 @@ widget!(
         view_port_bounds: Rectangle,
 
         /// Sets or shares the padding property.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the value that describes if the scroll bars could be dragged and clicked.
+        interactive: bool
     }
 );
 
@@ -113,27 +300,85 @@ impl Template for ScrollIndicator {
             .v_align("stretch")
             .h_align("stretch")
             .padding(0.0)
+            .interactive(false)
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<ScrollIndicatorState>(id)
+                    .action(ScrollIndicatorAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<ScrollIndicatorState>(id)
+                    .action(ScrollIndicatorAction::EndDrag);
+            })
             .child(
                 Grid::new()
                     .child(
                         ScrollBar::new()
-                            .id(ID_SCROLL_BAR_HORIZONTAL)
+                            .id(ID_SCROLL_TRACK_VERTICAL)
+                            .style(STYLE_SCROLL_TRACK)
+                            .visibility("collapsed")
+                            .width(8.0)
+                            .margin((0.0, 0.0, 0.0, 6.0))
+                            .h_align("end")
+                            .on_mouse_down(move |states, m| {
+                                states.get_mut::<ScrollIndicatorState>(id).action(
+                                    ScrollIndicatorAction::Page(Orientation::Vertical, m.position),
+                                )
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        ScrollBar::new()
+                            .id(ID_SCROLL_TRACK_HORIZONTAL)
+                            .style(STYLE_SCROLL_TRACK)
+                            .visibility("collapsed")
+                            .height(8.0)
+                            .margin((0.0, 0.0, 6.0, 0.0))
+                            .v_align("end")
+                            .on_mouse_down(move |states, m| {
+                                states.get_mut::<ScrollIndicatorState>(id).action(
+                                    ScrollIndicatorAction::Page(
+                                        Orientation::Horizontal,
+                                        m.position,
+                                    ),
+                                )
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        ScrollBar::new()
+                            .id(ID_SCROLL_BAR_VERTICAL)
                             .visibility("collapsed")
                             .min_height(8.0)
                             .margin((0.0, 0.0, 0.0, 6.0))
                             .h_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                states.get_mut::<ScrollIndicatorState>(id).action(
+                                    ScrollIndicatorAction::Press(Orientation::Vertical, m.position),
+                                )
+                            })
                             .build(ctx),
                     )
                     .child(
                         ScrollBar::new()
-                            .id(ID_SCROLL_BAR_VERTICAL)
+                            .id(ID_SCROLL_BAR_HORIZONTAL)
                             .visibility("collapsed")
                             .min_width(8.0)
                             .margin((0.0, 0.0, 6.0, 0.0))
                             .height(4.0)
                             .v_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                states.get_mut::<ScrollIndicatorState>(id).action(
+                                    ScrollIndicatorAction::Press(
+                                        Orientation::Horizontal,
+                                        m.position,
+                                    ),
+                                )
+                            })
                             .build(ctx),
                     )
                     .build(ctx),
@@ -155,6 +400,48 @@ fn offset(size: f64, content_size: f64, offset: f64) -> f64 {
     size * offset / content_size
 }
 
+fn clamp_offset(content_size: f64, view_port_size: f64, offset: f64) -> f64 {
+    offset.max(view_port_size - content_size).min(0.)
+}
+
+// Translates the distance the thumb is dragged to the new scroll offset of the content.
+fn thumb_drag_offset(
+    size: f64,
+    content_size: f64,
+    view_port_size: f64,
+    start_offset: f64,
+    delta: f64,
+) -> f64 {
+    if size <= 0. {
+        return start_offset;
+    }
+
+    clamp_offset(
+        content_size,
+        view_port_size,
+        start_offset - delta * content_size / size,
+    )
+}
+
+fn set_track_visibility(ctx: &mut Context, track: Entity, interactive: bool) {
+    let visibility = if interactive {
+        Visibility::Visible
+    } else {
+        Visibility::Collapsed
+    };
+
+    set_visibility(ctx, track, visibility);
+}
+
+// Sets the visibility of the given child if it has changed.
+fn set_visibility(ctx: &mut Context, entity: Entity, visibility: Visibility) {
+    let mut widget = ctx.get_widget(entity);
+
+    if *widget.get::<Visibility>("visibility") != visibility {
+        widget.set("visibility", visibility);
+    }
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -182,4 +469,32 @@ mod tests {
 
         assert!((offset(size, content_size, offset_in) - 2.).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_clamp_offset() {
+        assert!((clamp_offset(200., 80., -50.) + 50.).abs() < f64::EPSILON);
+        assert!((clamp_offset(200., 80., -150.) + 120.).abs() < f64::EPSILON);
+        assert!((clamp_offset(200., 80., 10.) - 0.).abs() < f64::EPSILON);
+        assert!((clamp_offset(50., 80., -10.) - 0.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_thumb_drag_offset() {
+        let size = 100.;
+        let content_size = 400.;
+        let view_port_size = 100.;
+
+        assert!(
+            (thumb_drag_offset(size, content_size, view_port_size, 0., 10.) + 40.).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (thumb_drag_offset(size, content_size, view_port_size, -40., -5.) + 20.).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (thumb_drag_offset(size, content_size, view_port_size, 0., 200.) + 300.).abs()
+                < f64::EPSILON
+        );
+    }
 }
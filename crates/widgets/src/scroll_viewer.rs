@@ -1,64 +1,328 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::*};
+
+/// The deceleration of kinetic scrolling in pixels per square millisecond.
+pub const KINETIC_DECELERATION: f64 = 0.003;
+
+/// The minimum velocity in pixels per millisecond that is needed to start kinetic scrolling on release.
+pub const KINETIC_MIN_VELOCITY: f64 = 0.1;
+
+// If the pointer rests longer than this time in milliseconds before release no kinetic scrolling is started.
+const KINETIC_RELEASE_TIME: f64 = 100.0;
+
+#[derive(Copy, Clone)]
+enum ScrollViewerAction {
+    Press(Point),
+    Drag(Point),
+    Release,
+    ScrollTo(Point, f64),
+}
+
+#[derive(Copy, Clone)]
+struct Pan {
+    start: Point,
+    start_offset: Point,
+    position: Point,
+    time: f64,
+    velocity: Point,
+}
+
+#[derive(Copy, Clone)]
+enum ScrollAnimation {
+    Kinetic {
+        velocity: Point,
+        time: f64,
+    },
+    Offset {
+        from: Point,
+        to: Point,
+        start: f64,
+        duration: f64,
+    },
+}
 
 /// The `ScrollViewerState` handles the `ScrollViewer` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
     delta: Option<Point>,
+    offset: Point,
+    actions: Vec<ScrollViewerAction>,
+    pan: Option<Pan>,
+    animation: Option<ScrollAnimation>,
 }
 
 impl ScrollViewerState {
+    /// Animates the scroll offset to the given value. The `duration` is given in milliseconds, if it is `0` the
+    /// offset is set immediately.
+    pub fn scroll_to(&mut self, offset: impl Into<Point>, duration: f64) {
+        self.actions
+            .push(ScrollViewerAction::ScrollTo(offset.into(), duration));
+    }
+
     fn scroll(&mut self, delta: Point) {
         self.delta = Some(delta);
     }
-}
 
-impl State for ScrollViewerState {
-    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
-            let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+    fn action(&mut self, action: ScrollViewerAction) {
+        self.actions.push(action);
+    }
+
+    fn handle_actions(&mut self, ctx: &mut Context) {
+        let actions: Vec<ScrollViewerAction> = self.actions.drain(..).collect();
+        let kinetic = *ctx.widget().get::<bool>("kinetic");
+
+        for action in actions {
+            match action {
+                ScrollViewerAction::Press(position) => {
+                    if !kinetic {
+                        continue;
+                    }
+
+                    self.animation = None;
+                    self.pan = Some(Pan {
+                        start: position,
+                        start_offset: self.offset,
+                        position,
+                        time: now(),
+                        velocity: Point::default(),
+                    });
+                }
+                ScrollViewerAction::Drag(position) => {
+                    if let Some(mut pan) = self.pan {
+                        let time = now();
+                        let elapsed = time - pan.time;
+
+                        if elapsed > 0. {
+                            pan.velocity = Point::new(
+                                (pan.position.x() - position.x()) / elapsed,
+                                (pan.position.y() - position.y()) / elapsed,
+                            );
+                        }
+
+                        pan.position = position;
+                        pan.time = time;
+                        self.pan = Some(pan);
+
+                        self.set_offset(
+                            ctx,
+                            Point::new(
+                                pan.start_offset.x() + pan.start.x() - position.x(),
+                                pan.start_offset.y() + pan.start.y() - position.y(),
+                            ),
+                        );
+                    }
+                }
+                ScrollViewerAction::Release => {
+                    if let Some(pan) = self.pan.take() {
+                        let time = now();
+                        let speed = pan.velocity.x().abs().max(pan.velocity.y().abs());
+
+                        if time - pan.time < KINETIC_RELEASE_TIME && speed > KINETIC_MIN_VELOCITY {
+                            self.animation = Some(ScrollAnimation::Kinetic {
+                                velocity: pan.velocity,
+                                time,
+                            });
+                        }
+                    }
+                }
+                ScrollViewerAction::ScrollTo(offset, duration) => {
+                    self.pan = None;
 
-            if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
-                return;
+                    if duration <= 0. {
+                        self.animation = None;
+                        self.set_offset(ctx, offset);
+                    } else {
+                        self.animation = Some(ScrollAnimation::Offset {
+                            from: self.offset,
+                            to: offset,
+                            start: now(),
+                            duration,
+                        });
+                    }
+                }
             }
+        }
+    }
 
-            let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
-            let mut padding = *ctx.widget().get::<Thickness>("padding");
+    // Moves the content one animation step.
+    fn animate(&mut self, ctx: &mut Context) {
+        let animation = if let Some(animation) = self.animation {
+            animation
+        } else {
+            return;
+        };
 
-            if let Some(child) = &mut ctx.try_child_from_index(0) {
-                let child_size = child.get::<Rectangle>("bounds").size();
+        let time = now();
 
-                if mode.vertical == ScrollMode::Auto && child_size.height() > size.height() {
-                    padding.set_top(offset(
-                        size.height(),
-                        child_size.height(),
-                        padding.top(),
-                        delta.y() * speed,
-                    ));
+        self.animation = match animation {
+            ScrollAnimation::Kinetic {
+                velocity,
+                time: last,
+            } => {
+                let elapsed = time - last;
+                let next = Point::new(
+                    decelerate(velocity.x(), KINETIC_DECELERATION, elapsed),
+                    decelerate(velocity.y(), KINETIC_DECELERATION, elapsed),
+                );
+                let target = Point::new(
+                    self.offset.x() + (velocity.x() + next.x()) / 2. * elapsed,
+                    self.offset.y() + (velocity.y() + next.y()) / 2. * elapsed,
+                );
+                let old_offset = self.offset;
+                self.set_offset(ctx, target);
+
+                // stops at the end of the velocity or if the content is scrolled to its border
+                if next == Point::default() || (elapsed > 0. && self.offset == old_offset) {
+                    None
+                } else {
+                    Some(ScrollAnimation::Kinetic {
+                        velocity: next,
+                        time,
+                    })
                 }
+            }
+            ScrollAnimation::Offset {
+                from,
+                to,
+                start,
+                duration,
+            } => {
+                let progress = ((time - start) / duration).min(1.).max(0.);
+                self.set_offset(ctx, interpolate(from, to, Easing::EaseOut.ease(progress)));
 
-                if mode.horizontal == ScrollMode::Auto && child_size.width() > size.width() {
-                    padding.set_left(offset(
-                        size.width(),
-                        child_size.width(),
-                        padding.left(),
-                        delta.x() * speed,
-                    ));
+                if progress < 1. {
+                    Some(animation)
+                } else {
+                    None
                 }
+            }
+        };
+
+        if self.animation.is_some() {
+            ctx.request_next_frame();
+        }
+    }
+
+    // Sets the scroll offset of the scrollable directions.
+    fn set_offset(&mut self, ctx: &mut Context, offset: Point) {
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+
+        let offset = Point::new(
+            if mode.horizontal == ScrollMode::Auto {
+                offset.x()
+            } else {
+                self.offset.x()
+            },
+            if mode.vertical == ScrollMode::Auto {
+                offset.y()
             } else {
-                return;
+                self.offset.y()
+            },
+        );
+
+        ctx.widget().set("scroll_offset", offset);
+        self.sync_offset(ctx);
+    }
+
+    // Moves the padding of the child by the given scroll delta.
+    fn scroll_by_delta(&mut self, ctx: &mut Context, delta: Point) {
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+
+        if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+            return;
+        }
+
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let speed = *ctx.widget().get::<f64>("speed");
+        let mut padding = *ctx.widget().get::<Thickness>("padding");
+
+        if let Some(child) = &mut ctx.try_child_from_index(0) {
+            let child_size = child.get::<Rectangle>("bounds").size();
+
+            if mode.vertical == ScrollMode::Auto && child_size.height() > size.height() {
+                padding.set_top(offset(
+                    size.height(),
+                    child_size.height(),
+                    padding.top(),
+                    delta.y() * speed,
+                ));
+            }
+
+            if mode.horizontal == ScrollMode::Auto && child_size.width() > size.width() {
+                padding.set_left(offset(
+                    size.width(),
+                    child_size.width(),
+                    padding.left(),
+                    delta.x() * speed,
+                ));
+            }
+        } else {
+            return;
+        }
+
+        ctx.widget().set("padding", padding);
+    }
+
+    // Keeps the scroll_offset property and the padding of the child in sync.
+    fn sync_offset(&mut self, ctx: &mut Context) {
+        let scroll_offset = *ctx.widget().get::<Point>("scroll_offset");
+
+        if scroll_offset != self.offset {
+            // scroll_offset is changed from outside
+            let size = ctx.widget().get::<Rectangle>("bounds").size();
+            let mut padding = *ctx.widget().get::<Thickness>("padding");
+
+            if let Some(child) = &mut ctx.try_child_from_index(0) {
+                let child_size = child.get::<Rectangle>("bounds").size();
+
+                padding.set_top(clamp_offset(
+                    size.height(),
+                    child_size.height(),
+                    -scroll_offset.y(),
+                ));
+                padding.set_left(clamp_offset(
+                    size.width(),
+                    child_size.width(),
+                    -scroll_offset.x(),
+                ));
             }
 
             ctx.widget().set("padding", padding);
         }
+
+        let padding = *ctx.widget().get::<Thickness>("padding");
+        let offset = Point::new(-padding.left(), -padding.top());
+
+        if offset != scroll_offset {
+            ctx.widget().set("scroll_offset", offset);
+        }
+
+        self.offset = offset;
+    }
+}
+
+impl State for ScrollViewerState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(delta) = self.delta.take() {
+            self.animation = None;
+            self.scroll_by_delta(ctx, delta);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.sync_offset(ctx);
+        self.handle_actions(ctx);
+        self.animate(ctx);
     }
 }
 
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
     /// Only the first child of the scroll viewer can be scrolled.
-    ScrollViewer<ScrollViewerState>: MouseHandler {
+    ///
+    /// If `kinetic` is set to `true` the child could be dragged by mouse or touch and keeps scrolling with
+    /// deceleration after release. Use `ScrollViewerState::scroll_to` to animate the scroll offset.
+    /// Use `Context::scroll_into_view` to make a child of the scroll viewer visible.
+    ScrollViewer<ScrollViewerState>: MouseHandler, ScrollHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
 
@@ -66,7 +330,13 @@ widget!(
         speed: f64,
 
         /// Sets or shares padding, that is used to scroll the first child.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the scroll offset of the first child. It is kept in sync with the padding.
+        scroll_offset: Point,
+
+        /// Sets or shares the value that describes if the child could be dragged and keeps scrolling after release.
+        kinetic: bool
     }
 );
 
@@ -74,13 +344,32 @@ impl Template for ScrollViewer {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("ScrollViewer")
             .padding(0)
+            .scroll_offset(Point::default())
             .speed(2)
             .clip(true)
             .mode(ScrollViewerMode::default())
-            .on_scroll(move |states, p| {
-                states.get_mut::<ScrollViewerState>(id).scroll(p);
+            .kinetic(false)
+            .on_scroll(move |states, delta| {
+                states.get_mut::<ScrollViewerState>(id).scroll(delta.pixels);
+                false
+            })
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<ScrollViewerState>(id)
+                    .action(ScrollViewerAction::Press(m.position));
                 false
             })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<ScrollViewerState>(id)
+                    .action(ScrollViewerAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<ScrollViewerState>(id)
+                    .action(ScrollViewerAction::Release);
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
@@ -94,6 +383,27 @@ fn offset(size: f64, child_size: f64, current_offset: f64, delta: f64) -> f64 {
     (current_offset + delta).min(0.).max(size - child_size)
 }
 
+fn clamp_offset(size: f64, child_size: f64, offset: f64) -> f64 {
+    if child_size <= size {
+        return 0.;
+    }
+
+    offset.min(0.).max(size - child_size)
+}
+
+// Reduces the given velocity by the deceleration over the elapsed time without changing its direction.
+fn decelerate(velocity: f64, deceleration: f64, elapsed: f64) -> f64 {
+    let speed = (velocity.abs() - deceleration * elapsed).max(0.);
+    speed * velocity.signum()
+}
+
+fn interpolate(from: Point, to: Point, progress: f64) -> Point {
+    Point::new(
+        from.x() + (to.x() - from.x()) * progress,
+        from.y() + (to.y() - from.y()) * progress,
+    )
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -109,4 +419,28 @@ mod tests {
         assert!((offset(width, child_width, 0., -200.) + 100.).abs() < f64::EPSILON);
         assert!((offset(width, child_width, 0., 200.) + 0.).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_clamp_offset() {
+        assert!((clamp_offset(100., 200., -50.) + 50.).abs() < f64::EPSILON);
+        assert!((clamp_offset(100., 200., -150.) + 100.).abs() < f64::EPSILON);
+        assert!((clamp_offset(100., 200., 20.) - 0.).abs() < f64::EPSILON);
+        assert!((clamp_offset(100., 50., -20.) - 0.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_decelerate() {
+        assert!((decelerate(2., 0.01, 100.) - 1.).abs() < f64::EPSILON);
+        assert!((decelerate(-2., 0.01, 100.) + 1.).abs() < f64::EPSILON);
+        assert!((decelerate(0.5, 0.01, 100.) - 0.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let from = Point::new(0., 100.);
+        let to = Point::new(50., 0.);
+
+        assert_eq!(interpolate(from, to, 0.5), Point::new(25., 50.));
+        assert_eq!(interpolate(from, to, 1.), to);
+    }
 }
@@ -1,4 +1,6 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use super::behaviors::ValueChangedBehavior;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
 
 // --- KEYS --
 pub static STYLE_SLIDER: &str = "slider";
@@ -9,6 +11,8 @@ static ID_TRACK: &str = "id_track";
 #[derive(Copy, Clone)]
 enum SliderAction {
     Move { mouse_x: f64 },
+    Key(Key),
+    Focus,
 }
 
 /// The `SliderState` is used to manipulate the position of the thumb of the slider widget.
@@ -28,6 +32,42 @@ impl SliderState {
         self.action = Some(action);
     }
 
+    // request the keyboard focus for the slider
+    fn request_focus(&self, ctx: &mut Context) {
+        if !slider(ctx.widget()).focused() {
+            ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+        }
+    }
+
+    // change the val by the given key
+    fn change_val_by_key(&self, key: Key, ctx: &mut Context) {
+        if !slider(ctx.widget()).focused() {
+            return;
+        }
+
+        let val = *slider(ctx.widget()).val();
+        let min = *slider(ctx.widget()).min();
+        let max = *slider(ctx.widget()).max();
+        let small_change = *slider(ctx.widget()).small_change();
+        let large_change = *slider(ctx.widget()).large_change();
+        let step = *slider(ctx.widget()).step();
+
+        // with a step the arrow keys move the val to the next multiple of the step
+        let small_change = if step > 0.0 { step } else { small_change };
+
+        let new_val = match key {
+            Key::Left => snap_val(val - small_change, min, max, step),
+            Key::Right => snap_val(val + small_change, min, max, step),
+            Key::PageDown => snap_val(val - large_change, min, max, step),
+            Key::PageUp => snap_val(val + large_change, min, max, step),
+            Key::Home => min,
+            Key::End => max,
+            _ => return,
+        };
+
+        slider(ctx.widget()).set_val(new_val);
+    }
+
     // adjust min, max and val
     fn adjust(&mut self, ctx: &mut Context) -> bool {
         let mut has_changes = false;
@@ -105,39 +145,59 @@ impl State for SliderState {
             .expect("SliderState.init: Track child could not be found.");
     }
 
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        match self.action {
+            Some(SliderAction::Key(key)) => self.change_val_by_key(key, ctx),
+            Some(SliderAction::Focus) => self.request_focus(ctx),
+            _ => return,
+        }
+
+        self.action = None;
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(action) = self.action {
-            match action {
-                SliderAction::Move { mouse_x } => {
-                    if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
-                        let thumb_width = ctx
-                            .get_widget(self.thumb)
-                            .get::<Rectangle>("bounds")
-                            .width();
-                        let track_width = ctx
-                            .get_widget(self.track)
-                            .get::<Rectangle>("bounds")
-                            .width();
-                        let slider_x = ctx.widget().get::<Point>("position").x();
-
-                        let thumb_x =
-                            calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
-
-                        ctx.get_widget(self.thumb)
-                            .get_mut::<Thickness>("margin")
-                            .set_left(thumb_x);
-
-                        let min = *ctx.widget().get("min");
-                        let max = *ctx.widget().get("max");
-
-                        ctx.widget().set(
-                            "val",
-                            calculate_val(thumb_x, min, max, thumb_width, track_width),
-                        );
-                    } else {
-                        ctx.widget().clear_dirty();
-                    }
-                }
+        if let Some(SliderAction::Move { mouse_x }) = self.action {
+            if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
+                let thumb_width = ctx
+                    .get_widget(self.thumb)
+                    .get::<Rectangle>("bounds")
+                    .width();
+                let track_width = ctx
+                    .get_widget(self.track)
+                    .get::<Rectangle>("bounds")
+                    .width();
+                let slider_x = ctx.widget().get::<Point>("position").x();
+
+                let min = *ctx.widget().get("min");
+                let max = *ctx.widget().get("max");
+                let step = *ctx.widget().get("step");
+
+                let val = snap_val(
+                    calculate_val(
+                        calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width),
+                        min,
+                        max,
+                        thumb_width,
+                        track_width,
+                    ),
+                    min,
+                    max,
+                    step,
+                );
+
+                ctx.get_widget(self.thumb)
+                    .get_mut::<Thickness>("margin")
+                    .set_left(calculate_thumb_x_from_val(
+                        val,
+                        min,
+                        max,
+                        track_width,
+                        thumb_width,
+                    ));
+
+                ctx.widget().set("val", val);
+            } else {
+                ctx.widget().clear_dirty();
             }
 
             self.action = None;
@@ -153,8 +213,13 @@ impl State for SliderState {
 widget!(
     /// The `Slider` allows to use a val in a range of values.
     ///
+    /// If the slider is focused its val could be changed by the keyboard. The Left / Right keys change the val by
+    /// `small_change` or by `step` if it is set, Page Up / Page Down by `large_change` and Home / End jumps to `min` / `max`. The mouse wheel
+    /// changes the val by `small_change` too, with Shift pressed the wheel and the Up / Down keys change it ten times
+    /// as much.
+    ///
     /// **style:** `slider`
-    Slider<SliderState>: MouseHandler {
+    Slider<SliderState>: KeyDownHandler, MouseHandler {
         /// Sets or shares the min val of the range.
         min: f64,
 
@@ -164,11 +229,34 @@ widget!(
         /// Sets or shares the current val of the range.
         val: f64,
 
+        /// Sets or shares the val that is added or subtracted if an arrow key is pressed.
+        small_change: f64,
+
+        /// Sets or shares the val that is added or subtracted if Page Up or Page Down is pressed.
+        large_change: f64,
+
+        /// Sets or shares the step property. If it is greater than `0.0` dragging the thumb and the Left / Right keys snap the val to
+        /// multiples of the step.
+        step: f64,
+
+        /// Sets or shares the distance between two tick marks on the track. If it is `0.0` no ticks are drawn.
+        tick_frequency: f64,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -185,12 +273,39 @@ impl Template for Slider {
             .min(0.0)
             .max(100.0)
             .val(0.0)
+            .small_change(1.0)
+            .large_change(10.0)
+            .step(0.0)
+            .tick_frequency(0.0)
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .height(24.0)
             .border_radius(2.0)
+            .child(
+                ValueChangedBehavior::new()
+                    .target(id.0)
+                    .val(id)
+                    .min(id)
+                    .max(id)
+                    .step(("small_change", id))
+                    .focused(id)
+                    .build(ctx),
+            )
             .child(
                 Grid::new()
                     .margin((8, 0))
                     .id(ID_TRACK)
+                    .child(
+                        TickBar::new()
+                            .min(id)
+                            .max(id)
+                            .tick_frequency(id)
+                            .v_align("center")
+                            .height(12.0)
+                            .margin((12, 0))
+                            .build(ctx),
+                    )
                     .child(
                         Container::new()
                             .border_radius(id)
@@ -201,6 +316,7 @@ impl Template for Slider {
                     )
                     .child(
                         Button::new()
+                            .focusable(false)
                             .style("thumb")
                             .id(ID_THUMB)
                             .v_align("center")
@@ -212,12 +328,24 @@ impl Template for Slider {
                     )
                     .build(ctx),
             )
+            .on_mouse_down(move |states, _| {
+                states
+                    .get_mut::<SliderState>(id)
+                    .action(SliderAction::Focus);
+                false
+            })
             .on_mouse_move(move |states, p| {
                 states
                     .get_mut::<SliderState>(id)
                     .action(SliderAction::Move { mouse_x: p.x() });
                 false
             })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<SliderState>(id)
+                    .action(SliderAction::Key(event.key));
+                false
+            })
     }
 }
 
@@ -251,6 +379,14 @@ fn adjust_max(min: f64, max: f64) -> f64 {
     max
 }
 
+fn snap_val(val: f64, min: f64, max: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return val;
+    }
+
+    adjust_val(min + ((val - min) / step).round() * step, min, max)
+}
+
 fn calculate_thumb_x(mouse_x: f64, thumb_width: f64, slider_x: f64, track_width: f64) -> f64 {
     (mouse_x - slider_x - thumb_width)
         .max(0.0)
@@ -299,6 +435,15 @@ mod tests {
         assert!((100.0 - calculate_val(68.0, -50.0, 50.0, 32.0, 100.0)).abs() < ERROR);
     }
 
+    #[test]
+    fn test_snap_val() {
+        assert!((33.3 - snap_val(33.3, 0.0, 100.0, 0.0)).abs() < ERROR);
+        assert!((30.0 - snap_val(33.3, 0.0, 100.0, 10.0)).abs() < ERROR);
+        assert!((40.0 - snap_val(35.0, 0.0, 100.0, 10.0)).abs() < ERROR);
+        assert!((100.0 - snap_val(130.0, 0.0, 100.0, 30.0)).abs() < ERROR);
+        assert!((-5.0 - snap_val(-4.0, -10.0, 10.0, 5.0)).abs() < ERROR);
+    }
+
     #[test]
     fn test_adjust_val() {
         assert!((0.0 - adjust_val(-10.0, 0.0, 100.0)).abs() < ERROR);
@@ -0,0 +1,383 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+// --- KEYS --
+
+pub static STYLE_SPLITTER: &str = "splitter";
+
+// --- KEYS --
+
+/// The default thickness of a `Splitter`.
+pub const SPLITTER_SIZE: f64 = 6.0;
+
+#[derive(Copy, Clone)]
+enum SplitterAction {
+    Press(Point),
+    Drag(Point),
+    EndDrag,
+}
+
+#[derive(Copy, Clone)]
+struct SplitterDrag {
+    start: Point,
+    sizes: (f64, f64),
+}
+
+/// The `SplitterState` handles the `Splitter` widget.
+#[derive(Default, AsAny)]
+pub struct SplitterState {
+    actions: Vec<SplitterAction>,
+    drag: Option<SplitterDrag>,
+}
+
+impl SplitterState {
+    fn action(&mut self, action: SplitterAction) {
+        self.actions.push(action);
+    }
+
+    // Returns the grid, the index of the splitter inside of the grid and the orientation.
+    fn target(&self, ctx: &mut Context) -> Option<(Entity, usize, Orientation)> {
+        let orientation = *ctx.widget().get::<Orientation>("orientation");
+        let key = match orientation {
+            Orientation::Horizontal => "column",
+            Orientation::Vertical => "row",
+        };
+
+        let index = ctx.widget().try_clone::<usize>(key)?;
+
+        if index == 0 {
+            return None;
+        }
+
+        let grid = ctx.entity_of_parent()?;
+
+        Some((grid, index, orientation))
+    }
+
+    fn press(&mut self, ctx: &mut Context, position: Point) {
+        let (grid, index, orientation) = match self.target(ctx) {
+            Some(target) => target,
+            None => return,
+        };
+
+        let sizes = match orientation {
+            Orientation::Horizontal => {
+                let columns = ctx.get_widget(grid).clone::<Columns>("columns");
+                match (columns.get(index - 1), columns.get(index + 1)) {
+                    (Some(previous), Some(next)) => {
+                        (previous.current_width(), next.current_width())
+                    }
+                    _ => return,
+                }
+            }
+            Orientation::Vertical => {
+                let rows = ctx.get_widget(grid).clone::<Rows>("rows");
+                match (rows.get(index - 1), rows.get(index + 1)) {
+                    (Some(previous), Some(next)) => {
+                        (previous.current_height(), next.current_height())
+                    }
+                    _ => return,
+                }
+            }
+        };
+
+        self.drag = Some(SplitterDrag {
+            start: position,
+            sizes,
+        });
+    }
+
+    fn drag(&mut self, ctx: &mut Context, position: Point) {
+        let drag = match self.drag {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        let (grid, index, orientation) = match self.target(ctx) {
+            Some(target) => target,
+            None => return,
+        };
+
+        let min_size = *ctx.widget().get::<f64>("min_size");
+
+        let sizes = match orientation {
+            Orientation::Horizontal => {
+                let mut columns = ctx.get_widget(grid).clone::<Columns>("columns");
+                let sizes = resize_columns(
+                    &mut columns,
+                    index,
+                    drag.sizes,
+                    position.x() - drag.start.x(),
+                    min_size,
+                );
+                ctx.get_widget(grid).set("columns", columns);
+                sizes
+            }
+            Orientation::Vertical => {
+                let mut rows = ctx.get_widget(grid).clone::<Rows>("rows");
+                let sizes = resize_rows(
+                    &mut rows,
+                    index,
+                    drag.sizes,
+                    position.y() - drag.start.y(),
+                    min_size,
+                );
+                ctx.get_widget(grid).set("rows", rows);
+                sizes
+            }
+        };
+
+        ctx.widget().set("sizes", vec![sizes.0, sizes.1]);
+    }
+}
+
+impl State for SplitterState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let orientation = *ctx.widget().get::<Orientation>("orientation");
+
+        // a vertical splitter resizes rows
+        if orientation == Orientation::Vertical
+            && *ctx.widget().get::<CursorIcon>("cursor") == CursorIcon::ResizeHorizontal
+        {
+            ctx.widget().set("cursor", CursorIcon::ResizeVertical);
+        }
+
+        let mut widget = ctx.widget();
+        let constraint = widget.get_mut::<Constraint>("constraint");
+
+        // the splitter is a thin bar along its orientation if no size is set
+        match orientation {
+            Orientation::Horizontal if constraint.width() == 0.0 => {
+                constraint.set_width(SPLITTER_SIZE)
+            }
+            Orientation::Vertical if constraint.height() == 0.0 => {
+                constraint.set_height(SPLITTER_SIZE)
+            }
+            _ => {}
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<SplitterAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                SplitterAction::Press(position) => self.press(ctx, position),
+                SplitterAction::Drag(position) => self.drag(ctx, position),
+                SplitterAction::EndDrag => self.drag = None,
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `Splitter` is placed in its own column or row of a `Grid`, between the two columns or rows it should
+    /// resize. Dragging the splitter moves the space between the column or row before and after it. A horizontal
+    /// splitter resizes columns, a vertical splitter resizes rows.
+    ///
+    /// The minimum sizes of the columns or rows are respected. Each resize updates the `sizes` property, use
+    /// `on_changed("sizes", ...)` to get notified.
+    ///
+    /// **style:** `splitter`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Grid::new()
+    ///     .columns(Columns::create().push(200).push("auto").push("*"))
+    ///     .child(Container::new().attach(Grid::column(0)).build(ctx))
+    ///     .child(Splitter::new().attach(Grid::column(1)).build(ctx))
+    ///     .child(Container::new().attach(Grid::column(2)).build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Splitter<SplitterState>: MouseHandler {
+        /// Sets or shares the orientation property. Horizontal splitters resize columns, vertical splitters rows.
+        orientation: Orientation,
+
+        /// Sets or shares the minimum size of the resized columns or rows.
+        min_size: f64,
+
+        /// Gets the sizes of the column or row before and after the splitter after the last resize.
+        sizes: Vec<f64>,
+
+        /// Sets or shares the background property.
+        background: Brush
+    }
+);
+
+impl Template for Splitter {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("Splitter")
+            .style(STYLE_SPLITTER)
+            .cursor(CursorIcon::ResizeHorizontal)
+            .orientation("horizontal")
+            .min_size(32.0)
+            .sizes(vec![])
+            .background("transparent")
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<SplitterState>(id)
+                    .action(SplitterAction::Press(m.position));
+                true
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<SplitterState>(id)
+                    .action(SplitterAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<SplitterState>(id)
+                    .action(SplitterAction::EndDrag);
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
+
+// --- Helpers --
+
+// Moves the given delta from the size after to the size before the splitter. Both sizes keep inside of their
+// minimum and maximum (if it is greater than 0).
+fn split_sizes(sizes: (f64, f64), delta: f64, min: (f64, f64), max: (f64, f64)) -> (f64, f64) {
+    let total = sizes.0 + sizes.1;
+
+    let mut lower = min.0.max(total - if max.1 > 0. { max.1 } else { total });
+    let mut upper = (total - min.1).min(if max.0 > 0. { max.0 } else { total });
+
+    if lower > upper {
+        // not enough space to respect all limits
+        lower = sizes.0;
+        upper = sizes.0;
+    }
+
+    let first = (sizes.0 + delta).max(lower).min(upper);
+
+    (first, total - first)
+}
+
+fn resize_columns(
+    columns: &mut Columns,
+    index: usize,
+    sizes: (f64, f64),
+    delta: f64,
+    min_size: f64,
+) -> (f64, f64) {
+    let (previous, next) = match (columns.get(index - 1), columns.get(index + 1)) {
+        (Some(previous), Some(next)) => (*previous, *next),
+        _ => return sizes,
+    };
+
+    let sizes = split_sizes(
+        sizes,
+        delta,
+        (
+            previous.min_width.max(min_size),
+            next.min_width.max(min_size),
+        ),
+        (previous.max_width, next.max_width),
+    );
+
+    // stretched columns keep stretching and take the rest of the space
+    if next.width != ColumnWidth::Stretch {
+        if let Some(column) = columns.get_mut(index + 1) {
+            column.width = ColumnWidth::Width(sizes.1);
+        }
+    }
+
+    if next.width == ColumnWidth::Stretch || previous.width != ColumnWidth::Stretch {
+        if let Some(column) = columns.get_mut(index - 1) {
+            column.width = ColumnWidth::Width(sizes.0);
+        }
+    }
+
+    sizes
+}
+
+fn resize_rows(
+    rows: &mut Rows,
+    index: usize,
+    sizes: (f64, f64),
+    delta: f64,
+    min_size: f64,
+) -> (f64, f64) {
+    let (previous, next) = match (rows.get(index - 1), rows.get(index + 1)) {
+        (Some(previous), Some(next)) => (*previous, *next),
+        _ => return sizes,
+    };
+
+    let sizes = split_sizes(
+        sizes,
+        delta,
+        (
+            previous.min_height.max(min_size),
+            next.min_height.max(min_size),
+        ),
+        (previous.max_height, next.max_height),
+    );
+
+    // stretched rows keep stretching and take the rest of the space
+    if next.height != RowHeight::Stretch {
+        if let Some(row) = rows.get_mut(index + 1) {
+            row.height = RowHeight::Height(sizes.1);
+        }
+    }
+
+    if next.height == RowHeight::Stretch || previous.height != RowHeight::Stretch {
+        if let Some(row) = rows.get_mut(index - 1) {
+            row.height = RowHeight::Height(sizes.0);
+        }
+    }
+
+    sizes
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sizes() {
+        assert_eq!(
+            split_sizes((100., 100.), 20., (10., 10.), (0., 0.)),
+            (120., 80.)
+        );
+        assert_eq!(
+            split_sizes((100., 100.), 150., (10., 30.), (0., 0.)),
+            (170., 30.)
+        );
+        assert_eq!(
+            split_sizes((100., 100.), -150., (40., 10.), (0., 0.)),
+            (40., 160.)
+        );
+        assert_eq!(
+            split_sizes((100., 100.), 50., (10., 10.), (120., 0.)),
+            (120., 80.)
+        );
+        assert_eq!(
+            split_sizes((100., 100.), -50., (10., 10.), (0., 120.)),
+            (80., 120.)
+        );
+    }
+
+    #[test]
+    fn test_resize_columns() {
+        let mut columns = Columns::create().push(100).push("auto").push("*").build();
+        let sizes = resize_columns(&mut columns, 1, (100., 200.), 50., 32.);
+
+        assert_eq!(sizes, (150., 150.));
+        assert_eq!(columns.get(0).unwrap().width, ColumnWidth::Width(150.));
+        assert_eq!(columns.get(2).unwrap().width, ColumnWidth::Stretch);
+
+        let mut columns = Columns::create().push("*").push("auto").push(100).build();
+        let sizes = resize_columns(&mut columns, 1, (200., 100.), 50., 32.);
+
+        assert_eq!(sizes, (250., 50.));
+        assert_eq!(columns.get(0).unwrap().width, ColumnWidth::Stretch);
+        assert_eq!(columns.get(2).unwrap().width, ColumnWidth::Width(50.));
+    }
+}
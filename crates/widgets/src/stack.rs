@@ -1,10 +1,92 @@
 use crate::{api::prelude::*, proc_macros::*};
 
+/// The `StackState` inserts the separators of the `Stack` between its children.
+#[derive(Default, AsAny)]
+pub struct StackState {
+    separator_builder: WidgetBuildContext,
+    separators: Vec<Entity>,
+    items: Vec<Entity>,
+}
+
+impl StackState {
+    // Rebuilds the separators if the children of the stack have changed.
+    fn update_separators(&mut self, ctx: &mut Context) {
+        if self.separator_builder.is_none() {
+            return;
+        }
+
+        let mut items = vec![];
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index) {
+            let child = child.entity();
+
+            if !self.separators.contains(&child) {
+                items.push(child);
+            }
+
+            index += 1;
+        }
+
+        if items == self.items {
+            return;
+        }
+
+        let entity = ctx.entity;
+
+        for separator in self.separators.drain(..) {
+            ctx.remove_child(separator);
+        }
+
+        if let Some(builder) = &self.separator_builder {
+            for index in 1..items.len() {
+                let separator = {
+                    let bctx = &mut ctx.build_context();
+
+                    let separator = builder(bctx, index - 1);
+                    bctx.append_child(entity, separator);
+                    separator
+                };
+
+                // the separator is placed in front of the item, each item before has a separator
+                ctx.move_child_to_index(separator, entity, 2 * index - 1);
+                self.separators.push(separator);
+            }
+        }
+
+        self.items = items;
+    }
+}
+
+impl State for StackState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_separators(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_separators(ctx);
+    }
+}
+
 widget!(
     /// The `Stack` defines a layout that is used to stack its children vertical or horizontal.
     ///
+    /// Use `spacing` to insert uniform gaps between the children, collapsed children get no gap. Use
+    /// `separator_builder` to insert a separator widget between each two children.
+    ///
     /// **style:** `stack`
-    Stack {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Stack::new()
+    ///     .spacing(4.0)
+    ///     .separator_builder(|ctx, _| MenuSeparator::new().build(ctx))
+    ///     .child(TextBlock::new().text("first").build(ctx))
+    ///     .child(TextBlock::new().text("second").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Stack<StackState> {
         /// Sets or shares the orientation property.
         orientation: Orientation,
 
@@ -13,6 +95,18 @@ widget!(
     }
 );
 
+impl Stack {
+    /// Sets a builder that is called between each two children of the stack to create a separator. The builder
+    /// gets the index of the separator. The separators are updated if children are added or removed.
+    pub fn separator_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().separator_builder = Some(Box::new(builder));
+        self
+    }
+}
+
 impl Template for Stack {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
         self.name("Stack").orientation("vertical").style("stack")
@@ -1,38 +1,131 @@
 use super::behaviors::MouseBehavior;
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
 
 // --- KEYS --
 static ID_SWITCH_TRACK: &str = "switch_track";
 static ID_SWITCH_TOGGLE: &str = "switch_toggle";
 // --- KEYS --
 
+/// The duration of the sliding animation of the switch toggle in milliseconds.
+pub const SWITCH_DURATION: f64 = 120.0;
+
+// Distance in pixels the mouse has to be moved before a press is handled as drag.
+static DRAG_THRESHOLD: f64 = 2.0;
+
+#[derive(Copy, Clone)]
+enum SwitchAction {
+    Press(Point),
+    Drag(Point),
+    Release(Point),
+}
+
+#[derive(Copy, Clone)]
+struct SwitchDrag {
+    start: Point,
+    offset: f64,
+    moved: bool,
+}
+
+#[derive(Copy, Clone)]
+struct SwitchAnimation {
+    from: f64,
+    start: f64,
+}
+
 /// State to handle the position of switch toggle.
 #[derive(Default, AsAny)]
 pub struct SwitchState {
+    actions: Vec<SwitchAction>,
     selected: bool,
     switch_toggle: Entity,
+    offset: f64,
+    drag: Option<SwitchDrag>,
+    animation: Option<SwitchAnimation>,
 }
 
 impl SwitchState {
-    fn toggle_selection(&mut self) {
-        self.selected = !self.selected;
+    fn action(&mut self, action: SwitchAction) {
+        self.actions.push(action);
     }
-}
 
-impl State for SwitchState {
-    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
-        self.switch_toggle = ctx
-            .entity_of_child(ID_SWITCH_TOGGLE)
-            .expect("SwitchState.init: Switch toggle child could not be found.");
+    // Returns the distance the toggle could be moved from the start to the end of the track.
+    fn travel(&self, ctx: &mut Context) -> f64 {
+        let width = ctx.widget().get::<Rectangle>("bounds").width();
+        let toggle_width = ctx
+            .get_widget(self.switch_toggle)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        (width - toggle_width).max(0.)
     }
 
-    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn handle_actions(&mut self, ctx: &mut Context) {
+        let actions: Vec<SwitchAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                SwitchAction::Press(position) => {
+                    if !*ctx.widget().get::<bool>("enabled") {
+                        continue;
+                    }
+
+                    self.drag = Some(SwitchDrag {
+                        start: position,
+                        offset: self.offset,
+                        moved: false,
+                    });
+                }
+                SwitchAction::Drag(position) => {
+                    if let Some(mut drag) = self.drag {
+                        let delta = position.x() - drag.start.x();
+
+                        if delta.abs() > DRAG_THRESHOLD {
+                            drag.moved = true;
+                        }
+
+                        if drag.moved {
+                            let offset = drag_offset(drag.offset, delta, self.travel(ctx));
+                            self.animation = None;
+                            self.set_offset(ctx, offset);
+                        }
+
+                        self.drag = Some(drag);
+                    }
+                }
+                SwitchAction::Release(position) => {
+                    let drag = match self.drag.take() {
+                        Some(drag) => drag,
+                        None => continue,
+                    };
+
+                    let selected = if drag.moved {
+                        // a dragged toggle is switched if it is released behind the midpoint
+                        release_selected(self.offset, self.travel(ctx))
+                    } else if check_mouse_condition(position, &ctx.widget()) {
+                        !self.selected
+                    } else {
+                        self.selected
+                    };
+
+                    if selected != *ctx.widget().get::<bool>("selected") {
+                        switch(ctx.widget()).set_selected(selected);
+                    }
+
+                    // the toggle slides from the release position to its end
+                    self.start_animation();
+                }
+            }
+        }
+    }
+
+    // Updates the toggle if the selected property has been changed.
+    fn update_selected(&mut self, ctx: &mut Context) {
         if *ctx.widget().get::<bool>("selected") == self.selected {
             return;
         }
 
-        switch(ctx.widget()).set_selected(self.selected);
+        self.selected = *ctx.widget().get::<bool>("selected");
 
         let element = ctx.widget().clone::<Selector>("selector").style.unwrap();
 
@@ -44,12 +137,10 @@ impl State for SwitchState {
             let mut switch_toggle = ctx.get_widget(self.switch_toggle);
 
             if self.selected {
-                switch_toggle.set("h_align", Alignment::from("end"));
                 switch_toggle
                     .get_mut::<Selector>("selector")
                     .set_state("selected");
             } else {
-                switch_toggle.set("h_align", Alignment::from("start"));
                 switch_toggle.get_mut::<Selector>("selector").clear_state();
             }
 
@@ -57,11 +148,86 @@ impl State for SwitchState {
         }
 
         ctx.get_widget(self.switch_toggle).update(false);
+
+        self.start_animation();
+    }
+
+    fn start_animation(&mut self) {
+        if self.drag.is_some() {
+            return;
+        }
+
+        self.animation = Some(SwitchAnimation {
+            from: self.offset,
+            start: now(),
+        });
+    }
+
+    // Moves the toggle to the end of the track of the current selection, animated if an animation is running.
+    fn animate(&mut self, ctx: &mut Context) {
+        if self.drag.is_some() {
+            return;
+        }
+
+        let travel = self.travel(ctx);
+        let target = if self.selected { travel } else { 0. };
+
+        let offset = match self.animation {
+            Some(animation) => {
+                let progress = ((now() - animation.start) / SWITCH_DURATION)
+                    .min(1.)
+                    .max(0.);
+
+                if progress < 1. {
+                    ctx.request_next_frame();
+                } else {
+                    self.animation = None;
+                }
+
+                animation.from + (target - animation.from) * Easing::EaseOut.ease(progress)
+            }
+            None => target,
+        };
+
+        self.set_offset(ctx, offset);
+    }
+
+    fn set_offset(&mut self, ctx: &mut Context, offset: f64) {
+        self.offset = offset;
+
+        let mut margin = *ctx
+            .get_widget(self.switch_toggle)
+            .get::<Thickness>("margin");
+
+        if (margin.left() - offset).abs() > f64::EPSILON {
+            margin.set_left(offset);
+            ctx.get_widget(self.switch_toggle).set("margin", margin);
+        }
+    }
+}
+
+impl State for SwitchState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.switch_toggle = ctx
+            .entity_of_child(ID_SWITCH_TOGGLE)
+            .expect("SwitchState.init: Switch toggle child could not be found.");
+
+        // the initial selection is shown without animation
+        self.selected = !*ctx.widget().get::<bool>("selected");
+        self.update_selected(ctx);
+        self.animation = None;
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.handle_actions(ctx);
+        self.update_selected(ctx);
+        self.animate(ctx);
     }
 }
 
 widget!(
-    /// The `Switch` widget can be switch between `on` and `off`.
+    /// The `Switch` widget can be switch between `on` and `off`. A click toggles the switch and the toggle slides
+    /// to the other side. The toggle could also be dragged, it switches if it is released behind the midpoint.
     ///
     /// **style:** `switch`
     Switch<SwitchState>: MouseHandler {
@@ -69,7 +235,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -84,7 +250,17 @@ widget!(
         pressed: bool,
 
         /// Sets or shares the selected property.
-        selected: bool
+        selected: bool,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
     }
 );
 
@@ -92,6 +268,9 @@ impl Template for Switch {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Switch")
             .style("switch")
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .pressed(false)
             .selected(false)
             .width(36.0)
@@ -99,15 +278,28 @@ impl Template for Switch {
             .border_radius(8.0)
             .border_width(1.0)
             .padding(4.0)
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<SwitchState>(id)
+                    .action(SwitchAction::Press(m.position));
+                false
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<SwitchState>(id)
+                    .action(SwitchAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, m| {
+                states
+                    .get_mut::<SwitchState>(id)
+                    .action(SwitchAction::Release(m.position));
+            })
             .child(
                 MouseBehavior::new()
                     .pressed(id)
                     .enabled(id)
                     .target(id.0)
-                    .on_click(move |states, _| {
-                        states.get_mut::<SwitchState>(id).toggle_selection();
-                        false
-                    })
                     .child(
                         Grid::new()
                             .child(
@@ -134,3 +326,37 @@ impl Template for Switch {
             )
     }
 }
+
+// --- Helpers --
+
+// Moves the toggle by the given delta inside of the track.
+fn drag_offset(offset: f64, delta: f64, travel: f64) -> f64 {
+    (offset + delta).min(travel).max(0.)
+}
+
+// Returns `true` if the toggle is released behind the midpoint of the track.
+fn release_selected(offset: f64, travel: f64) -> bool {
+    offset > travel / 2.
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_offset() {
+        assert!((drag_offset(0., 6., 16.) - 6.).abs() < f64::EPSILON);
+        assert!((drag_offset(10., 20., 16.) - 16.).abs() < f64::EPSILON);
+        assert!((drag_offset(10., -20., 16.) - 0.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_release_selected() {
+        assert!(!release_selected(0., 16.));
+        assert!(!release_selected(8., 16.));
+        assert!(release_selected(9., 16.));
+        assert!(release_selected(16., 16.));
+    }
+}
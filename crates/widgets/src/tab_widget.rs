@@ -57,7 +57,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -150,7 +150,7 @@ impl Template for TabHeader {
             .height(36)
             .min_width(64)
             .background(colors::LYNCH_COLOR)
-            .border_radius(4)
+            .border_radius((4, 4, 0, 0))
             .border_width(0)
             .border_brush("transparent")
             .padding((16, 0, 16, 0))
@@ -205,7 +205,12 @@ enum TabWidgetAction {
     SelectByBody(Entity),
     Add(String, Entity),
     Remove(Entity),
+    Close(Entity),
+    Move(usize, usize),
     SetCloseButtonVisibility(bool),
+    StartDrag(Entity),
+    Drag(Point),
+    EndDrag,
 }
 /**
 Through the TabWidgetState it is possible to control the behaviour of TabWidget.
@@ -224,6 +229,9 @@ pub struct TabWidgetState {
     selected: usize,
 
     close_button_visibility: bool,
+
+    //Body of the tab that is currently dragged by the user
+    dragged: Option<Entity>,
 }
 
 impl TabWidgetState {
@@ -250,6 +258,14 @@ impl TabWidgetState {
         self.actions.push(TabWidgetAction::Remove(entity));
     }
 
+    /**
+    Move the tab at index "from" to index "to". The selected tab will stay selected.
+    If one of the indices is out of range, nothing happen.
+    */
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        self.actions.push(TabWidgetAction::Move(from, to));
+    }
+
     ///Add a new tab to the widget.
     pub fn add_tab<T: Into<String>>(&mut self, header: T, body: Entity) {
         self.actions.push(TabWidgetAction::Add(header.into(), body));
@@ -389,6 +405,50 @@ impl TabWidgetState {
         }
     }
 
+    ///Move a tab from one index to another. Unlike the public "move_tab", this happen immediatly.
+    fn move_tab_internal(&mut self, ctx: &mut Context, from: usize, to: usize) {
+        if from == to || from >= self.tabs.len() || to >= self.tabs.len() {
+            return;
+        }
+
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+
+        //Move the header to the new position, the order of the bodies does not matter
+        ctx.move_child_to_index(tab.0, self.header_container, to);
+        ctx.get_widget(self.header_container).update(false);
+
+        self.selected = moved_index(self.selected, from, to);
+    }
+
+    ///Move the dragged tab to the header under the mouse position.
+    fn drag_internal(&mut self, ctx: &mut Context, position: Point) {
+        let dragged = if let Some(dragged) = self.dragged {
+            dragged
+        } else {
+            return;
+        };
+
+        let headers: Vec<(f64, f64)> = self
+            .tabs
+            .iter()
+            .map(|(header, _)| {
+                let widget = ctx.get_widget(*header);
+                (
+                    widget.get::<Point>("position").x(),
+                    widget.get::<Rectangle>("bounds").width(),
+                )
+            })
+            .collect();
+
+        if let (Some(from), Some(to)) = (
+            self.get_index(dragged),
+            header_index_at(&headers, position.x()),
+        ) {
+            self.move_tab_internal(ctx, from, to);
+        }
+    }
+
     /**
     Set the visibility of the close button on all tabs. Unlike the public "set_close_button_visibility", this happen immediatly.
     If the passed "value" is equal to "self.close_button_visibility", so the requested visibility is already present, nothing happen.
@@ -418,15 +478,16 @@ impl TabWidgetState {
             })
             .text(String16::from(text))
             .on_header_mouse_down(move |states, _| {
-                states
-                    .get_mut::<TabWidgetState>(cloned_entity)
-                    .select_by_body(body);
+                let state = states.get_mut::<TabWidgetState>(cloned_entity);
+                state.select_by_body(body);
+                state.actions.push(TabWidgetAction::StartDrag(body));
                 true
             })
             .on_close_click(move |states, _| {
                 states
                     .get_mut::<TabWidgetState>(cloned_entity)
-                    .remove_by_body(body);
+                    .actions
+                    .push(TabWidgetAction::Close(body));
                 true
             })
             .build(&mut ctx.build_context())
@@ -442,6 +503,12 @@ impl State for TabWidgetState {
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        //The selected index was changed from outside
+        let selected_index = *tab_widget(ctx.widget()).selected_index();
+        if selected_index != self.selected {
+            self.select_by_index_internal(ctx, selected_index);
+        }
+
         let actions: Vec<TabWidgetAction> = self.actions.drain(..).collect();
         for action in actions {
             match action {
@@ -459,11 +526,38 @@ impl State for TabWidgetState {
                 TabWidgetAction::Remove(body) => {
                     self.remove_tab_internal(ctx, body);
                 }
+                TabWidgetAction::Close(body) => {
+                    if let Some(index) = self.get_index(body) {
+                        self.remove_tab_internal(ctx, body);
+                        ctx.push_event_strategy_by_entity(
+                            TabClosedEvent(ctx.entity, index),
+                            ctx.entity,
+                            EventStrategy::Direct,
+                        );
+                    }
+                }
+                TabWidgetAction::Move(from, to) => {
+                    self.move_tab_internal(ctx, from, to);
+                }
                 TabWidgetAction::SetCloseButtonVisibility(value) => {
                     self.set_close_button_visibility_internal(ctx, value);
                 }
+                TabWidgetAction::StartDrag(body) => {
+                    self.dragged = Some(body);
+                }
+                TabWidgetAction::Drag(position) => {
+                    self.drag_internal(ctx, position);
+                }
+                TabWidgetAction::EndDrag => {
+                    self.dragged = None;
+                }
             }
         }
+
+        //Keep the selected_index property in sync with the selected tab
+        if *tab_widget(ctx.widget()).selected_index() != self.selected {
+            tab_widget(ctx.widget()).set_selected_index(self.selected);
+        }
     }
 }
 
@@ -479,8 +573,13 @@ widget!(
     .tab("Tab header 3",TextBlock::new().text("Tab content 3").build(ctx))
     .build(ctx)
      ```
+
+    The tabs could be reordered by dragging their headers. If a tab is closed by its close button a `TabClosedEvent` is raised.
      */
-    TabWidget<TabWidgetState> {
+    TabWidget<TabWidgetState>: MouseHandler, TabClosedHandler {
+        /// Sets or shares the index of the selected tab.
+        selected_index: usize,
+
         /// Sets or shares the spacing between tabs.
         spacing: f64,
 
@@ -488,7 +587,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -520,27 +619,97 @@ impl TabWidget {
 
 impl Template for TabWidget {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("TabWidget").style("tab_widget").child(
-            Grid::new()
-                .rows(Rows::create().push(32).push("*"))
-                .child(
-                    Stack::new()
-                        .id(HEADER_CONTAINER)
-                        .orientation("horizontal")
-                        .spacing(id)
-                        .build(ctx),
-                )
-                .child(
-                    Container::new()
-                        .id(BODY_CONTAINER)
-                        .background(id)
-                        .border_brush(id)
-                        .border_width(id)
-                        .border_radius(id)
-                        .attach(Grid::row(1))
-                        .build(ctx),
-                )
-                .build(ctx),
-        )
+        self.name("TabWidget")
+            .style("tab_widget")
+            .selected_index(0)
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<TabWidgetState>(id)
+                    .actions
+                    .push(TabWidgetAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<TabWidgetState>(id)
+                    .actions
+                    .push(TabWidgetAction::EndDrag);
+            })
+            .child(
+                Grid::new()
+                    .rows(Rows::create().push(32).push("*"))
+                    .child(
+                        Stack::new()
+                            .id(HEADER_CONTAINER)
+                            .orientation("horizontal")
+                            .spacing(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id(BODY_CONTAINER)
+                            .background(id)
+                            .border_brush(id)
+                            .border_width(id)
+                            .border_radius(id)
+                            .attach(Grid::row(1))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Returns the index of the header that contains the given x position. The headers are described by their x position and width.
+fn header_index_at(headers: &[(f64, f64)], x: f64) -> Option<usize> {
+    headers
+        .iter()
+        .position(|(header_x, width)| x >= *header_x && x < header_x + width)
+}
+
+// Returns the new index of the tab at the given index, after the tab at "from" was moved to "to".
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        return to;
+    }
+
+    if from < index && index <= to {
+        return index - 1;
+    }
+
+    if to <= index && index < from {
+        return index + 1;
+    }
+
+    index
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_index_at() {
+        let headers = vec![(0.0, 50.0), (52.0, 80.0), (134.0, 40.0)];
+
+        assert_eq!(header_index_at(&headers, 10.0), Some(0));
+        assert_eq!(header_index_at(&headers, 52.0), Some(1));
+        assert_eq!(header_index_at(&headers, 173.0), Some(2));
+        assert_eq!(header_index_at(&headers, 51.0), None);
+        assert_eq!(header_index_at(&headers, 200.0), None);
+    }
+
+    #[test]
+    fn test_moved_index() {
+        assert_eq!(moved_index(1, 1, 3), 3);
+        assert_eq!(moved_index(2, 1, 3), 1);
+        assert_eq!(moved_index(3, 1, 3), 2);
+        assert_eq!(moved_index(2, 3, 1), 3);
+        assert_eq!(moved_index(0, 1, 3), 0);
+        assert_eq!(moved_index(4, 3, 1), 4);
     }
 }
@@ -0,0 +1,461 @@
+use std::cmp::Ordering;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_TABLE_VIEW_HEADER: &str = "table_view_header";
+static HEADER_PANEL: &str = "header_panel";
+static ROWS_VIEW: &str = "rows_view";
+
+// --- KEYS --
+
+/// The minimum width of a `TableView` column.
+pub const MIN_COLUMN_WIDTH: f64 = 24.0;
+
+// Width of the area at the right edge of a column header that starts a resize.
+const RESIZE_GRIP_WIDTH: f64 = 6.0;
+
+type TableCellBuilder = Rc<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>;
+
+type TableRowCompare = Rc<dyn Fn(usize, usize) -> Ordering + 'static>;
+
+/// Describes a column of a `TableView`.
+#[derive(Clone)]
+pub struct TableColumn {
+    header: String,
+    width: f64,
+    cell_builder: Option<TableCellBuilder>,
+    compare: Option<TableRowCompare>,
+}
+
+impl TableColumn {
+    /// Creates a new column with the given header text.
+    pub fn new(header: impl Into<String>) -> Self {
+        TableColumn {
+            header: header.into(),
+            width: 100.0,
+            cell_builder: None,
+            compare: None,
+        }
+    }
+
+    /// Sets the initial width of the column.
+    pub fn width(mut self, width: impl Into<f64>) -> Self {
+        self.width = width.into().max(MIN_COLUMN_WIDTH);
+        self
+    }
+
+    /// Defines the template build function of the cells. It gets the index of the data row.
+    pub fn cell_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.cell_builder = Some(Rc::new(builder));
+        self
+    }
+
+    /// Defines the function that compares two data rows by their index. Only columns with a compare function could be
+    /// sorted by clicking on their header.
+    pub fn compare<F: Fn(usize, usize) -> Ordering + 'static>(mut self, compare: F) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum TableViewAction {
+    Press(usize, Point),
+    Click(usize),
+    Drag(Point),
+    EndDrag,
+}
+
+/// The `TableViewState` generates the column headers and handles sorting and resizing of the columns.
+#[derive(Default, AsAny)]
+pub struct TableViewState {
+    actions: Vec<TableViewAction>,
+    columns: Vec<TableColumn>,
+    headers: Vec<Entity>,
+    header_panel: Entity,
+    rows_view: Entity,
+    count: usize,
+    sort: Option<(usize, SortDirection)>,
+    sort_on_click: Option<usize>,
+    // column, start x position and start width of the resized column
+    resize: Option<(usize, f64, f64)>,
+}
+
+impl TableViewState {
+    fn action(&mut self, action: TableViewAction) {
+        self.actions.push(action);
+    }
+
+    fn generate_headers(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+        let widths = ctx.widget().clone::<Vec<f64>>("column_widths");
+
+        for (i, column) in self.columns.iter().enumerate() {
+            let header = {
+                let build_context = &mut ctx.build_context();
+
+                let header = Button::new()
+                    .style(STYLE_TABLE_VIEW_HEADER)
+                    .text(column.header.as_str())
+                    .width(widths[i])
+                    .min_width(MIN_COLUMN_WIDTH)
+                    .on_mouse_down(move |states, m| {
+                        states
+                            .get_mut::<TableViewState>(entity)
+                            .action(TableViewAction::Press(i, m.position));
+                        false
+                    })
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<TableViewState>(entity)
+                            .action(TableViewAction::Click(i));
+                        false
+                    })
+                    .build(build_context);
+                build_context.append_child(self.header_panel, header);
+                header
+            };
+
+            self.headers.push(header);
+        }
+    }
+
+    // starts a resize if the header of the column is pressed at its right edge, otherwise the column is sorted on click.
+    fn press(&mut self, column: usize, position: Point, ctx: &mut Context) {
+        let header = ctx.get_widget(self.headers[column]);
+        let width = header.get::<Rectangle>("bounds").width();
+        let right = header.get::<Point>("position").x() + width;
+
+        if position.x() >= right - RESIZE_GRIP_WIDTH {
+            self.resize = Some((column, position.x(), width));
+            self.sort_on_click = None;
+        } else {
+            self.sort_on_click = Some(column);
+        }
+    }
+
+    fn drag(&mut self, position: Point, ctx: &mut Context) {
+        if let Some((column, start_x, start_width)) = self.resize {
+            let width = (start_width + position.x() - start_x).max(MIN_COLUMN_WIDTH);
+            self.set_column_width(column, width, ctx);
+        }
+    }
+
+    fn set_column_width(&mut self, column: usize, width: f64, ctx: &mut Context) {
+        let mut widths = ctx.widget().clone::<Vec<f64>>("column_widths");
+
+        if (widths[column] - width).abs() < f64::EPSILON {
+            return;
+        }
+
+        widths[column] = width;
+        ctx.widget().set("column_widths", widths);
+
+        let mut header = ctx.get_widget(self.headers[column]);
+        let mut constraint = *header.get::<Constraint>("constraint");
+        constraint.set_width(width);
+        header.set("constraint", constraint);
+
+        self.update_rows(ctx);
+    }
+
+    // sorts the rows by the given column. If the column is already sorted the direction is reversed.
+    fn sort(&mut self, column: usize, ctx: &mut Context) {
+        if self.columns[column].compare.is_none() {
+            return;
+        }
+
+        let direction = match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        };
+
+        self.sort = Some((column, direction));
+
+        for (i, header) in self.headers.iter().enumerate() {
+            let icon = match self.sort {
+                Some((sorted, SortDirection::Ascending)) if sorted == i => {
+                    material_icons_font::MD_ARROW_DROP_UP
+                }
+                Some((sorted, SortDirection::Descending)) if sorted == i => {
+                    material_icons_font::MD_ARROW_DROP_DOWN
+                }
+                _ => "",
+            };
+
+            ctx.get_widget(*header).set("icon", String::from(icon));
+        }
+
+        let old_order = ctx.widget().clone::<Vec<usize>>("row_order");
+        self.update_order(ctx);
+
+        // the selected indices describe the displayed rows, they are moved with their data rows
+        let new_order = ctx.widget().clone::<Vec<usize>>("row_order");
+        let selected_indices = reorder_selection(
+            &ctx.widget().get::<SelectedIndices>("selected_indices").0,
+            &old_order,
+            &new_order,
+        );
+        ctx.widget()
+            .set("selected_indices", SelectedIndices(selected_indices));
+    }
+
+    fn update_order(&mut self, ctx: &mut Context) {
+        let order = match self.sort {
+            Some((column, direction)) => {
+                if let Some(compare) = &self.columns[column].compare {
+                    sorted_order(self.count, direction, &**compare)
+                } else {
+                    (0..self.count).collect()
+                }
+            }
+            None => (0..self.count).collect(),
+        };
+
+        ctx.widget().set("row_order", order);
+        self.update_rows(ctx);
+    }
+
+    fn update_rows(&self, ctx: &mut Context) {
+        ctx.get_widget(self.rows_view).set("request_update", true);
+    }
+}
+
+impl State for TableViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.header_panel = ctx
+            .entity_of_child(HEADER_PANEL)
+            .expect("TableViewState.init: HeaderPanel child could not be found.");
+        self.rows_view = ctx
+            .entity_of_child(ROWS_VIEW)
+            .expect("TableViewState.init: RowsView child could not be found.");
+
+        if ctx.widget().get::<Vec<f64>>("column_widths").len() != self.columns.len() {
+            let widths: Vec<f64> = self.columns.iter().map(|c| c.width).collect();
+            ctx.widget().set("column_widths", widths);
+        }
+
+        self.generate_headers(ctx);
+        self.count = *ctx.widget().get::<usize>("count");
+        self.update_order(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<TableViewAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                TableViewAction::Press(column, position) => self.press(column, position, ctx),
+                TableViewAction::Click(column) => {
+                    if self.sort_on_click.take() == Some(column) {
+                        self.sort(column, ctx);
+                    }
+                }
+                TableViewAction::Drag(position) => self.drag(position, ctx),
+                TableViewAction::EndDrag => self.resize = None,
+            }
+        }
+
+        let count = *ctx.widget().get::<usize>("count");
+
+        if count != self.count {
+            self.count = count;
+            self.update_order(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `TableView` displays rows of data in columns. Each column is described by a `TableColumn` with its header
+    /// text, width and the template of its cells.
+    ///
+    /// The columns could be resized by dragging the right edge of their headers. Columns with a compare function are
+    /// sorted by clicking on their header. The rows are selectable and only the visible rows are created.
+    ///
+    /// The `selected_indices` describe the displayed rows, `row_order` contains the data row of each displayed row.
+    ///
+    /// **style:** `table_view_header`
+    TableView<TableViewState>: MouseHandler {
+        /// Sets or shares the number of rows.
+        count: usize,
+
+        /// Sets or shares the height of each row.
+        row_height: f64,
+
+        /// Sets or shares the selection mode property.
+        selection_mode: SelectionMode,
+
+        /// Sets or shares the selected indices.
+        selected_indices: SelectedIndices,
+
+        /// Sets or shares the widths of the columns.
+        column_widths: Vec<f64>,
+
+        /// Sets or shares the data row index of each displayed row.
+        row_order: Vec<usize>
+    }
+);
+
+impl TableView {
+    /// Adds a column to the table.
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.state_mut().columns.push(column);
+        self
+    }
+}
+
+impl Template for TableView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let columns = self.state.columns.clone();
+
+        let rows_view = ListView::new()
+            .id(ROWS_VIEW)
+            .attach(Grid::row(1))
+            .count(id)
+            .item_height(("row_height", id))
+            .selection_mode(id)
+            .selected_indices(id)
+            .request_update(false)
+            .items_builder(move |bc, index| {
+                let widths = bc.get_widget(id).clone::<Vec<f64>>("column_widths");
+                let row = bc
+                    .get_widget(id)
+                    .get::<Vec<usize>>("row_order")
+                    .get(index)
+                    .copied()
+                    .unwrap_or(index);
+
+                let mut cells = Stack::new().orientation("horizontal");
+
+                for (column, width) in columns.iter().zip(widths.iter()) {
+                    let mut cell = Container::new().width(*width).clip(true);
+
+                    if let Some(builder) = &column.cell_builder {
+                        cell = cell.child(builder(bc, row));
+                    }
+
+                    cells = cells.child(cell.build(bc));
+                }
+
+                cells.build(bc)
+            })
+            .build(ctx);
+
+        self.name("TableView")
+            .count(0)
+            .row_height(24.0)
+            .selection_mode("single")
+            .selected_indices(HashSet::new())
+            .column_widths(vec![])
+            .row_order(vec![])
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<TableViewState>(id)
+                    .action(TableViewAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<TableViewState>(id)
+                    .action(TableViewAction::EndDrag);
+            })
+            .child(
+                Grid::new()
+                    .rows(Rows::create().push(32).push("*"))
+                    .child(
+                        Stack::new()
+                            .id(HEADER_PANEL)
+                            .orientation("horizontal")
+                            .margin((3, 0, 3, 0))
+                            .build(ctx),
+                    )
+                    .child(rows_view)
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// returns the data row indices sorted by the given compare function.
+fn sorted_order(
+    count: usize,
+    direction: SortDirection,
+    compare: &dyn Fn(usize, usize) -> Ordering,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..count).collect();
+
+    // the sort is stable, rows that are equal keep their order in both directions
+    order.sort_by(|a, b| match direction {
+        SortDirection::Ascending => compare(*a, *b),
+        SortDirection::Descending => compare(*b, *a),
+    });
+
+    order
+}
+
+// returns the displayed indices of the selected data rows after the order of the rows has changed.
+fn reorder_selection(
+    selected_indices: &HashSet<usize>,
+    old_order: &[usize],
+    new_order: &[usize],
+) -> HashSet<usize> {
+    selected_indices
+        .iter()
+        .filter_map(|index| old_order.get(*index))
+        .filter_map(|row| new_order.iter().position(|r| r == row))
+        .collect()
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_order() {
+        let values = [3, 1, 2, 1];
+        let compare = |a: usize, b: usize| values[a].cmp(&values[b]);
+
+        assert_eq!(
+            sorted_order(4, SortDirection::Ascending, &compare),
+            vec![1, 3, 2, 0]
+        );
+        assert_eq!(
+            sorted_order(4, SortDirection::Descending, &compare),
+            vec![0, 2, 1, 3]
+        );
+        assert_eq!(
+            sorted_order(0, SortDirection::Ascending, &compare),
+            Vec::<usize>::new()
+        );
+    }
+
+    #[test]
+    fn test_reorder_selection() {
+        let selected_indices: HashSet<usize> = [0, 2].iter().copied().collect();
+
+        assert_eq!(
+            reorder_selection(&selected_indices, &[1, 3, 2, 0], &[0, 2, 1, 3]),
+            [1, 2].iter().copied().collect()
+        );
+        assert_eq!(
+            reorder_selection(&selected_indices, &[], &[0, 1, 2]),
+            HashSet::new()
+        );
+    }
+}
@@ -3,6 +3,11 @@ use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
 widget!(
     /// The `TextBlock` widget is used to draw text. It is not interactive.
     ///
+    /// If `mnemonic` is set, the character after an underscore of the text is drawn as mnemonic, e.g. `"_File"` is
+    /// drawn as `File` and the `F` is underlined while Alt is pressed.
+    ///
+    /// A `composition` of an input method editor is drawn underlined at the start of the `text_selection`.
+    ///
     /// **style:** `text-block`
     TextBlock {
         /// Sets or shares the text property.
@@ -18,7 +23,16 @@ widget!(
         font_size: f64,
 
         /// Sets or shares the font property.
-        font: String
+        font: String,
+
+        /// Sets or shares the flag if the text contains a mnemonic.
+        mnemonic: bool,
+
+        /// Sets or shares the composition string of an input method editor that is not committed yet.
+        composition: String16,
+
+        /// Sets or shares the text selection property. The composition is drawn at its start.
+        text_selection: TextSelection
     }
 );
 
@@ -29,6 +43,7 @@ impl Template for TextBlock {
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .mnemonic(false)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
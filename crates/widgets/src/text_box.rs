@@ -1,4 +1,4 @@
-use super::behaviors::TextBehavior;
+use super::behaviors::{TextBehavior, DEFAULT_HISTORY_DEPTH};
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 
@@ -35,7 +35,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -49,11 +49,24 @@ widget!(
         /// Sets or shares the focused property.
         focused: bool,
 
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32,
+
         /// Sets or shares ta value that describes if the TextBox should lost focus on activation (enter).
         lost_focus_on_activation: bool,
 
         /// Used to request focus from outside. Set to `true` tor request focus.
-        request_focus: bool
+        request_focus: bool,
+
+        /// Sets or shares the maximum number of undo steps.
+        history_depth: usize,
+
+        /// Sets or shares the composition string of an input method editor that is not committed yet.
+        composition: String16
     }
 );
 
@@ -67,6 +80,8 @@ impl Template for TextBox {
             .water_mark(id)
             .font(id)
             .font_size(id)
+            .composition(id)
+            .text_selection(id)
             .build(ctx);
 
         let cursor = Cursor::new()
@@ -78,7 +93,7 @@ impl Template for TextBox {
             .build(ctx);
 
         let text_behavior = TextBehavior::new()
-            .cursor(cursor.0)
+            .text_cursor(cursor.0)
             .focused(id)
             .font(id)
             .font_size(id)
@@ -87,15 +102,19 @@ impl Template for TextBox {
             .request_focus(id)
             .text(id)
             .text_selection(id)
+            .history_depth(id)
+            .composition(id)
             .build(ctx);
 
         self.name("TextBox")
             .style(STYLE_TEXT_BOX)
+            .cursor(CursorIcon::Text)
             .text("")
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
             .text_selection(TextSelection::default())
+            .composition("")
             .padding(4.0)
             .background(colors::LYNCH_COLOR)
             .border_brush("transparent")
@@ -104,7 +123,10 @@ impl Template for TextBox {
             .min_width(128.0)
             .height(32.0)
             .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .lost_focus_on_activation(true)
+            .history_depth(DEFAULT_HISTORY_DEPTH)
             .child(text_behavior)
             .child(
                 Container::new()
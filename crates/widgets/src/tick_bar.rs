@@ -0,0 +1,35 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `TickBar` widget draws tick marks between a min and a max val. It is used to draw the ticks of a `Slider`.
+    ///
+    /// **style:** `tick_bar`
+    TickBar {
+        /// Sets or shares the min val of the range.
+        min: f64,
+
+        /// Sets or shares the max val of the range.
+        max: f64,
+
+        /// Sets or shares the distance between two ticks. If it is `0.0` no ticks are drawn.
+        tick_frequency: f64,
+
+        /// Sets or shares the foreground property that is used to draw the ticks.
+        foreground: Brush
+    }
+);
+
+impl Template for TickBar {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TickBar")
+            .style("tick_bar")
+            .min(0.0)
+            .max(100.0)
+            .tick_frequency(0.0)
+            .foreground("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(TickBarRenderObject)
+    }
+}
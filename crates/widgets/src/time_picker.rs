@@ -0,0 +1,300 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_TIME_PICKER: &str = "time_picker";
+
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum TimePickerAction {
+    Hour(i32),
+    Minute(i32),
+    ToggleMeridiem,
+}
+
+/// The `TimePickerState` handles the spinners of the `TimePicker` widget.
+#[derive(Default, AsAny)]
+pub struct TimePickerState {
+    actions: Vec<TimePickerAction>,
+    hour: Entity,
+    minute: Entity,
+    meridiem: Entity,
+    shown: Option<(Time, ClockFormat)>,
+}
+
+impl TimePickerState {
+    fn action(&mut self, action: TimePickerAction) {
+        self.actions.push(action);
+    }
+
+    fn handle_actions(&mut self, ctx: &mut Context) {
+        let actions: Vec<TimePickerAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            let time = ctx.widget().clone::<Time>("selected_time");
+            let minute_step = ctx.widget().clone::<u32>("minute_step").max(1) as i32;
+
+            let time = match action {
+                TimePickerAction::Hour(hours) => time.add_hours(hours),
+                TimePickerAction::Minute(steps) => {
+                    time.add_minutes_of_hour(step_minutes(time.minute(), steps, minute_step))
+                }
+                TimePickerAction::ToggleMeridiem => time.add_hours(12),
+            };
+
+            // the changed event is only raised for a new time
+            if time != *ctx.widget().get::<Time>("selected_time") {
+                ctx.widget().set("selected_time", time);
+            }
+        }
+    }
+
+    fn update_segments(&mut self, ctx: &mut Context) {
+        let time = ctx.widget().clone::<Time>("selected_time");
+        let clock_format = ctx.widget().clone::<ClockFormat>("clock_format");
+
+        if self.shown == Some((time, clock_format)) {
+            return;
+        }
+
+        self.shown = Some((time, clock_format));
+
+        let hour = match clock_format {
+            ClockFormat::TwentyFourHour => time.hour(),
+            ClockFormat::TwelveHour => time.hour_12(),
+        };
+
+        ctx.get_widget(self.hour)
+            .set("text", String16::from(format!("{:02}", hour)));
+        ctx.get_widget(self.minute)
+            .set("text", String16::from(format!("{:02}", time.minute())));
+
+        let mut meridiem = ctx.get_widget(self.meridiem);
+        meridiem.set(
+            "text",
+            String16::from(if time.is_pm() { "PM" } else { "AM" }),
+        );
+        meridiem.set(
+            "visibility",
+            if clock_format == ClockFormat::TwelveHour {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            },
+        );
+    }
+}
+
+impl State for TimePickerState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_segments(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.handle_actions(ctx);
+        self.update_segments(ctx);
+    }
+}
+
+widget!(
+    /// The `TimePicker` is used to select a time of the day with an hour and a minute spinner. The spinners wrap
+    /// around, the minute spinner changes the minute by `minute_step`. With the `twelve_hour` clock format the
+    /// hours are shown from 1 to 12 and a button switches between AM and PM.
+    ///
+    /// A change of the selected time raises a `ChangedEvent` for `selected_time`.
+    ///
+    /// **style:** `time_picker`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TimePicker::new()
+    ///     .selected_time((18, 30))
+    ///     .clock_format("twelve_hour")
+    ///     .on_changed("selected_time", |_, id| println!("Time of {:?} changed", id))
+    ///     .build(ctx)
+    /// ```
+    TimePicker<TimePickerState> {
+        /// Sets or shares the selected time.
+        selected_time: Time,
+
+        /// Sets or shares the clock format, 24 hours or 12 hours with AM / PM.
+        clock_format: ClockFormat,
+
+        /// Sets or shares the count of minutes that is added or removed by the minute spinner.
+        minute_step: u32,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64
+    }
+);
+
+impl TimePicker {
+    // Creates a spinner with a text and up and down buttons. The action is called with 1 for up and -1 for down.
+    fn spinner<F: Fn(i32) -> TimePickerAction + Copy + 'static>(
+        id: Entity,
+        ctx: &mut BuildContext,
+        text: Entity,
+        action: F,
+    ) -> Entity {
+        Grid::new()
+            .columns(Columns::create().push(24.0).push(16.0))
+            .rows(Rows::create().push(14.0).push(14.0))
+            .child(text)
+            .child(
+                Button::new()
+                    .style("button_small")
+                    .attach(Grid::column(1))
+                    .attach(Grid::row(0))
+                    .min_width(14)
+                    .icon(material_icons_font::MD_KEYBOARD_ARROW_UP)
+                    .on_click(move |states, _| {
+                        states.get_mut::<TimePickerState>(id).action(action(1));
+                        true
+                    })
+                    .build(ctx),
+            )
+            .child(
+                Button::new()
+                    .style("button_small")
+                    .attach(Grid::column(1))
+                    .attach(Grid::row(1))
+                    .min_width(14)
+                    .icon(material_icons_font::MD_KEYBOARD_ARROW_DOWN)
+                    .on_click(move |states, _| {
+                        states.get_mut::<TimePickerState>(id).action(action(-1));
+                        true
+                    })
+                    .build(ctx),
+            )
+            .build(ctx)
+    }
+
+    fn segment_text(id: Entity, ctx: &mut BuildContext) -> Entity {
+        TextBlock::new()
+            .attach(Grid::column(0))
+            .attach(Grid::row_span(2))
+            .v_align("center")
+            .h_align("center")
+            .foreground(id)
+            .font_size(id)
+            .build(ctx)
+    }
+}
+
+impl Template for TimePicker {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let hour = TimePicker::segment_text(id, ctx);
+        let minute = TimePicker::segment_text(id, ctx);
+        let meridiem = Button::new()
+            .style("button_single_content")
+            .v_align("center")
+            .min_width(40.0)
+            .height(24.0)
+            .text("AM")
+            .on_click(move |states, _| {
+                states
+                    .get_mut::<TimePickerState>(id)
+                    .action(TimePickerAction::ToggleMeridiem);
+                true
+            })
+            .build(ctx);
+
+        self.state_mut().hour = hour;
+        self.state_mut().minute = minute;
+        self.state_mut().meridiem = meridiem;
+
+        let hour_spinner = TimePicker::spinner(id, ctx, hour, TimePickerAction::Hour);
+        let minute_spinner = TimePicker::spinner(id, ctx, minute, TimePickerAction::Minute);
+
+        self.name("TimePicker")
+            .style(STYLE_TIME_PICKER)
+            .selected_time(Time::default())
+            .clock_format(ClockFormat::default())
+            .minute_step(1)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .height(32.0)
+            .h_align("start")
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding((4.0, 2.0, 4.0, 2.0))
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .spacing(2.0)
+                            .v_align("center")
+                            .child(hour_spinner)
+                            .child(
+                                TextBlock::new()
+                                    .v_align("center")
+                                    .text(":")
+                                    .foreground(id)
+                                    .font_size(id)
+                                    .build(ctx),
+                            )
+                            .child(minute_spinner)
+                            .child(meridiem)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Returns the count of minutes to add for the given count of spinner steps. The first step snaps to a multiple of
+// the minute step.
+fn step_minutes(minute: u32, steps: i32, minute_step: i32) -> i32 {
+    let minute = minute as i32;
+    let offset = minute.rem_euclid(minute_step);
+
+    match steps {
+        steps if steps > 0 => minute_step - offset + (steps - 1) * minute_step,
+        steps if steps < 0 && offset > 0 => -offset + (steps + 1) * minute_step,
+        steps => steps * minute_step,
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_minutes() {
+        assert_eq!(step_minutes(0, 1, 1), 1);
+        assert_eq!(step_minutes(10, -1, 1), -1);
+        assert_eq!(step_minutes(10, 1, 15), 5);
+        assert_eq!(step_minutes(10, -1, 15), -10);
+        assert_eq!(step_minutes(15, 1, 15), 15);
+        assert_eq!(step_minutes(15, -1, 15), -15);
+        assert_eq!(step_minutes(10, 2, 15), 20);
+    }
+}
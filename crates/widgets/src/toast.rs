@@ -0,0 +1,312 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_TOAST: &str = "toast";
+pub static STYLE_TOAST_INFO: &str = "toast_info";
+pub static STYLE_TOAST_SUCCESS: &str = "toast_success";
+pub static STYLE_TOAST_WARNING: &str = "toast_warning";
+pub static STYLE_TOAST_ERROR: &str = "toast_error";
+static ID_ACTION: &str = "id_action";
+static ID_TOASTS: &str = "id_toasts";
+
+// --- KEYS --
+
+/// The distance between the notifications and the borders of the window.
+const TOAST_PANEL_MARGIN: f64 = 16.0;
+
+/// The `ToastState` handles the action and the close button of a `Toast`.
+#[derive(Default, AsAny)]
+pub struct ToastState {
+    notification: Option<u64>,
+    action: Option<NotificationAction>,
+    dismiss: bool,
+}
+
+impl ToastState {
+    fn dismiss(&mut self) {
+        self.dismiss = true;
+    }
+}
+
+impl State for ToastState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.action.is_none() {
+            ctx.child(ID_ACTION)
+                .set("visibility", Visibility::Collapsed);
+        }
+    }
+
+    fn update(&mut self, registry: &mut Registry, _: &mut Context) {
+        if !self.dismiss {
+            return;
+        }
+
+        self.dismiss = false;
+
+        if let Some(id) = self.notification {
+            registry
+                .get_mut::<NotificationService>("notifications")
+                .dismiss(id);
+        }
+    }
+}
+
+widget!(
+    /// The `Toast` widget is a banner that shows the message of a `Notification` with an optional action button
+    /// and a close button. Toasts are created by the `ToastPanel` of the window, use the `NotificationService` to
+    /// show one.
+    ///
+    /// **style:** `toast`, `toast_info`, `toast_success`, `toast_warning`, `toast_error`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// registry
+    ///     .get_mut::<NotificationService>("notifications")
+    ///     .show(Toast::info("Saved"));
+    ///
+    /// registry
+    ///     .get_mut::<NotificationService>("notifications")
+    ///     .show(Toast::warning("File deleted").action("Undo", move |states| {
+    ///         states.get_mut::<MainViewState>(id).undo();
+    ///     }));
+    /// ```
+    Toast<ToastState> {
+        /// Sets or shares the message text.
+        text: String16,
+
+        /// Sets or shares the label of the action button.
+        action_text: String16,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String
+    }
+);
+
+impl Toast {
+    /// Creates an info notification with the given message.
+    pub fn info(message: impl Into<String>) -> Notification {
+        Notification::new(NotificationKind::Info, message)
+    }
+
+    /// Creates a success notification with the given message.
+    pub fn success(message: impl Into<String>) -> Notification {
+        Notification::new(NotificationKind::Success, message)
+    }
+
+    /// Creates a warning notification with the given message.
+    pub fn warning(message: impl Into<String>) -> Notification {
+        Notification::new(NotificationKind::Warning, message)
+    }
+
+    /// Creates an error notification with the given message.
+    pub fn error(message: impl Into<String>) -> Notification {
+        Notification::new(NotificationKind::Error, message)
+    }
+
+    // Shows the given notification, its kind defines the style.
+    fn notification(mut self, id: u64, notification: &Notification) -> Self {
+        self.state_mut().notification = Some(id);
+        self.state_mut().action = notification.action.clone();
+
+        let action_text = notification
+            .action
+            .as_ref()
+            .map(|action| action.label.clone())
+            .unwrap_or_default();
+
+        self.style(match notification.kind {
+            NotificationKind::Info => STYLE_TOAST_INFO,
+            NotificationKind::Success => STYLE_TOAST_SUCCESS,
+            NotificationKind::Warning => STYLE_TOAST_WARNING,
+            NotificationKind::Error => STYLE_TOAST_ERROR,
+        })
+        .text(notification.message.as_str())
+        .action_text(action_text.as_str())
+    }
+}
+
+impl Template for Toast {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("Toast")
+            .style(STYLE_TOAST)
+            .text("")
+            .action_text("")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(4.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((12.0, 4.0, 4.0, 4.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .min_width(240.0)
+            .min_height(44.0)
+            .child(
+                Grid::new()
+                    .columns(
+                        Columns::create()
+                            .push("*")
+                            .push(8)
+                            .push("auto")
+                            .push("auto"),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(id)
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .attach(Grid::column(0))
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .id(ID_ACTION)
+                            .style("button_single_content")
+                            .v_align("center")
+                            .text(("action_text", id))
+                            .attach(Grid::column(2))
+                            .on_click(move |states, _| {
+                                let action = states.get::<ToastState>(id).action.clone();
+
+                                if let Some(action) = action {
+                                    (action.handler)(states);
+                                }
+
+                                states.get_mut::<ToastState>(id).dismiss();
+                                false
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .style("button_icon_only")
+                            .v_align("center")
+                            .icon(material_icons_font::MD_CLOSE)
+                            .icon_brush(("foreground", id))
+                            .attach(Grid::column(3))
+                            .on_click(move |states, _| {
+                                states.get_mut::<ToastState>(id).dismiss();
+                                false
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+/// The `ToastPanelState` shows the visible notifications of the `NotificationService` and places them in the
+/// corner of the window.
+#[derive(Default, AsAny)]
+pub struct ToastPanelState {
+    toasts: Entity,
+    corner: NotificationCorner,
+}
+
+impl State for ToastPanelState {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        self.toasts = ctx
+            .entity_of_child(ID_TOASTS)
+            .expect("ToastPanelState.init: toasts child could not be found.");
+
+        registry
+            .get_mut::<NotificationService>("notifications")
+            .set_host(ctx.entity);
+    }
+
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let service = registry.get_mut::<NotificationService>("notifications");
+        self.corner = service.corner();
+
+        if service.update() {
+            let toasts = self.toasts;
+            ctx.clear_children_of(toasts);
+
+            for (id, notification) in service.visible() {
+                let build_context = &mut ctx.build_context();
+                let toast = Toast::new()
+                    .notification(id, notification)
+                    .build(build_context);
+                build_context.append_child(toasts, toast);
+            }
+        }
+
+        // checks the timeouts again when the next one is elapsed
+        if let Some(time) = service.next_timeout() {
+            ctx.request_update_at(time);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let window_size = ctx.window().get::<Rectangle>("bounds").size();
+
+        let x = match self.corner {
+            NotificationCorner::TopLeft | NotificationCorner::BottomLeft => TOAST_PANEL_MARGIN,
+            _ => window_size.width() - size.width() - TOAST_PANEL_MARGIN,
+        };
+        let y = match self.corner {
+            NotificationCorner::TopLeft | NotificationCorner::TopRight => TOAST_PANEL_MARGIN,
+            _ => window_size.height() - size.height() - TOAST_PANEL_MARGIN,
+        };
+
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_x(x.max(0.0));
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_y(y.max(0.0));
+    }
+}
+
+widget!(
+    /// The `ToastPanel` shows the notifications of the `NotificationService` as `Toast` banners in a corner of
+    /// the window. There is one panel per window, it is created by the `Window` and placed in the overlay.
+    ToastPanel<ToastPanelState> {
+        /// Sets or shares the space between the toasts.
+        spacing: f64
+    }
+);
+
+impl Template for ToastPanel {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("ToastPanel")
+            .h_align("start")
+            .v_align("start")
+            .spacing(8.0)
+            .child(Stack::new().id(ID_TOASTS).spacing(id).build(ctx))
+    }
+}
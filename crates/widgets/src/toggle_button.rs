@@ -12,7 +12,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: BorderRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -54,7 +54,17 @@ widget!(
         selected: bool,
 
         /// Sets or shares the spacing between icon and text.
-        spacing: f64
+        spacing: f64,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the flag if the widget gets the keyboard focus by Tab.
+        focusable: bool,
+
+        /// Sets or shares the position of the widget in the Tab order. Widgets with a positive tab index are focused
+        /// first, a negative tab index removes the widget from the Tab order.
+        tab_index: i32
     }
 );
 
@@ -62,6 +72,9 @@ impl Template for ToggleButton {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ToggleButton")
             .style("button")
+            .focused(false)
+            .focusable(true)
+            .tab_index(0)
             .selected(false)
             .height(36.0)
             .min_width(64.0)
@@ -0,0 +1,142 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_TOOL_TIP: &str = "tool_tip";
+
+// --- KEYS --
+
+/// The `ToolTipState` shows and places the tool tip requested by the `ToolTipService`.
+#[derive(Default, AsAny)]
+pub struct ToolTipState {
+    target: Option<u32>,
+}
+
+impl ToolTipState {
+    fn hide(&mut self, ctx: &mut Context) {
+        if self.target.take().is_some() {
+            ctx.widget().set("visibility", Visibility::Hidden);
+        }
+    }
+
+    fn show(&mut self, target: u32, ctx: &mut Context) {
+        self.target = Some(target);
+
+        let text = ctx
+            .get_widget(target.into())
+            .clone_or_default::<String>("tooltip");
+        tool_tip(ctx.widget()).set_text(String16::from(text));
+        ctx.widget().set("visibility", Visibility::Visible);
+    }
+}
+
+impl State for ToolTipState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        ctx.widget().set("visibility", Visibility::Hidden);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let status = ctx.tool_tip_service().status();
+
+        match status {
+            ToolTipStatus::Hidden => self.hide(ctx),
+            ToolTipStatus::Pending => {
+                self.hide(ctx);
+
+                // checks the status again when the delay is elapsed
+                if let Some(time) = ctx.tool_tip_service().show_time() {
+                    ctx.request_update_at(time);
+                }
+            }
+            ToolTipStatus::Visible(target, _) => {
+                if self.target != Some(target) {
+                    self.show(target, ctx);
+
+                    // the new text is measured before the tool tip is placed
+                    ctx.request_next_frame();
+                    return;
+                }
+
+                let size = ctx.widget().get::<Rectangle>("bounds").size();
+                let window_size = ctx.window().get::<Rectangle>("bounds").size();
+                let position = ctx.tool_tip_service().position(size, window_size);
+
+                ctx.widget()
+                    .get_mut::<Rectangle>("bounds")
+                    .set_x(position.x());
+                ctx.widget()
+                    .get_mut::<Rectangle>("bounds")
+                    .set_y(position.y());
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `ToolTip` displays the `tooltip` text of the hovered widget. There is one tool tip per window, it is
+    /// created by the `Window` and placed in the overlay. The hover delay is handled by the `ToolTipService`.
+    ///
+    /// **style:** `tool_tip`
+    ToolTip<ToolTipState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String
+    }
+);
+
+impl Template for ToolTip {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("ToolTip")
+            .style(STYLE_TOOL_TIP)
+            .h_align("start")
+            .v_align("start")
+            .padding((8.0, 4.0, 8.0, 4.0))
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .child(
+                TextBlock::new()
+                    .text(id)
+                    .font(id)
+                    .font_size(id)
+                    .foreground(id)
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
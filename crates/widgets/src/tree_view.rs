@@ -0,0 +1,490 @@
+use std::collections::HashSet;
+
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_TREE_VIEW: &str = "tree_view";
+pub static STYLE_TREE_VIEW_ITEM: &str = "tree_view_item";
+pub static STYLE_TREE_VIEW_TOGGLE: &str = "tree_view_toggle";
+pub static STYLE_TREE_VIEW_GUIDE: &str = "tree_view_guide";
+static ITEMS_PANEL: &str = "items_panel";
+
+// --- KEYS --
+
+// Width of one level of indentation.
+static INDENT: f64 = 16.0;
+
+/// Describes the position of a node inside of a tree. It contains the index of the node and the indices of all its
+/// parents, starting at the root level.
+pub type TreePath = Vec<usize>;
+
+type TreeItemBuilder = Option<Box<dyn Fn(&mut BuildContext, &[usize]) -> Entity + 'static>>;
+
+type TreeChildrenCount = Option<Box<dyn Fn(&[usize]) -> usize + 'static>>;
+
+#[derive(Debug, Clone)]
+enum TreeViewAction {
+    Toggle(TreePath),
+    Select(TreePath),
+}
+
+// Describes a visible row of the tree.
+#[derive(Debug, Clone, PartialEq)]
+struct TreeRow {
+    path: TreePath,
+    has_children: bool,
+    expanded: bool,
+}
+
+/// The `TreeViewState` generates the visible rows of the tree and handles expanding, collapsing and selection of nodes.
+#[derive(Default, AsAny)]
+pub struct TreeViewState {
+    action: Option<TreeViewAction>,
+    builder: TreeItemBuilder,
+    children_count: TreeChildrenCount,
+    expanded: HashSet<TreePath>,
+    items_panel: Entity,
+    rows: Vec<(TreePath, Entity)>,
+}
+
+impl TreeViewState {
+    fn action(&mut self, action: TreeViewAction) {
+        self.action = Some(action);
+    }
+
+    // expands a collapsed node or collapses an expanded node.
+    fn toggle(&mut self, path: TreePath) {
+        if !self.expanded.remove(&path) {
+            self.expanded.insert(path);
+        }
+    }
+
+    fn select(&mut self, ctx: &mut Context, path: TreePath) {
+        let selected_path = tree_view(ctx.widget()).clone_selected_path();
+
+        if selected_path == path {
+            return;
+        }
+
+        for (row_path, item) in &self.rows {
+            if *row_path == selected_path {
+                let mut item = ctx.get_widget(*item);
+                item.set("selected", false);
+                item.get_mut::<Selector>("selector").clear_state();
+                item.update(false);
+            }
+
+            if *row_path == path {
+                let mut item = ctx.get_widget(*item);
+                item.set("selected", true);
+                item.get_mut::<Selector>("selector").set_state("selected");
+                item.update(false);
+            }
+        }
+
+        tree_view(ctx.widget()).set_selected_path(path.clone());
+
+        ctx.push_event_strategy_by_entity(
+            SelectionChangedEvent(
+                ctx.entity,
+                SelectionChange {
+                    selected: path,
+                    ..Default::default()
+                },
+            ),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // builds the items of all visible rows. Children of collapsed nodes are not generated.
+    fn generate_rows(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+        let selected_path = tree_view(ctx.widget()).clone_selected_path();
+
+        ctx.clear_children_of(self.items_panel);
+        self.rows.clear();
+
+        let rows = if let Some(children_count) = &self.children_count {
+            visible_rows(children_count.as_ref(), &self.expanded)
+        } else {
+            return;
+        };
+
+        if let Some(builder) = &self.builder {
+            for row in rows {
+                let item = {
+                    let build_context = &mut ctx.build_context();
+                    let child = builder(build_context, &row.path);
+
+                    let select_path = row.path.clone();
+                    let item = TreeViewItem::new()
+                        .on_click(move |states, _| {
+                            states
+                                .get_mut::<TreeViewState>(entity)
+                                .action(TreeViewAction::Select(select_path.clone()));
+                            false
+                        })
+                        .build(build_context);
+
+                    let mouse_behavior = MouseBehavior::new().target(item.0).build(build_context);
+                    build_context.register_shared_property::<Selector>(
+                        "selector",
+                        mouse_behavior,
+                        item,
+                    );
+                    build_context.register_shared_property::<bool>("pressed", mouse_behavior, item);
+                    build_context.append_child(item, mouse_behavior);
+
+                    let row_panel = Stack::new().orientation("horizontal").build(build_context);
+
+                    // indentation guides
+                    for _ in 1..row.path.len() {
+                        let guide = Container::new()
+                            .width(INDENT)
+                            .child(
+                                Container::new()
+                                    .style(STYLE_TREE_VIEW_GUIDE)
+                                    .width(1.0)
+                                    .h_align("center")
+                                    .build(build_context),
+                            )
+                            .build(build_context);
+                        build_context.append_child(row_panel, guide);
+                    }
+
+                    let toggle_path = row.path.clone();
+                    let toggle = Button::new()
+                        .focusable(false)
+                        .style(STYLE_TREE_VIEW_TOGGLE)
+                        .width(INDENT)
+                        .min_width(INDENT)
+                        .padding(0.0)
+                        .v_align("center")
+                        .icon(if row.expanded {
+                            material_icons_font::MD_KEYBOARD_ARROW_DOWN
+                        } else {
+                            material_icons_font::MD_KEYBOARD_ARROW_RIGHT
+                        })
+                        .visibility(if row.has_children {
+                            Visibility::Visible
+                        } else {
+                            Visibility::Hidden
+                        })
+                        .on_click(move |states, _| {
+                            states
+                                .get_mut::<TreeViewState>(entity)
+                                .action(TreeViewAction::Toggle(toggle_path.clone()));
+                            true
+                        })
+                        .build(build_context);
+                    build_context.append_child(row_panel, toggle);
+
+                    build_context.register_shared_property::<Brush>("foreground", child, item);
+                    build_context.register_shared_property::<f32>("opacity", item, entity);
+                    build_context.register_shared_property::<f32>("opacity", child, entity);
+                    build_context.register_shared_property::<f64>("font_size", child, item);
+                    build_context.append_child(row_panel, child);
+                    build_context.append_child(mouse_behavior, row_panel);
+                    build_context.append_child(self.items_panel, item);
+
+                    item
+                };
+                ctx.get_widget(item).update_widget(entity, false, false);
+
+                if row.path == selected_path {
+                    let mut item = ctx.get_widget(item);
+                    item.set("selected", true);
+                    item.get_mut::<Selector>("selector").set_state("selected");
+                    item.update(false);
+                }
+
+                self.rows.push((row.path, item));
+            }
+        }
+    }
+}
+
+impl State for TreeViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ITEMS_PANEL)
+            .expect("TreeViewState.init: ItemsPanel child could not be found.");
+
+        self.generate_rows(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                TreeViewAction::Toggle(path) => {
+                    self.toggle(path);
+                    self.generate_rows(ctx);
+                }
+                TreeViewAction::Select(path) => self.select(ctx, path),
+            }
+        }
+
+        if *tree_view(ctx.widget()).request_update() {
+            tree_view(ctx.widget()).set_request_update(false);
+            self.generate_rows(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `TreeViewItem` describes a row inside of a `TreeView`.
+    ///
+    /// **style:** `tree_view_item`
+    TreeViewItem: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool
+    }
+);
+
+impl Template for TreeViewItem {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TreeViewItem")
+            .style(STYLE_TREE_VIEW_ITEM)
+            .min_width(64.0)
+            .height(24.0)
+            .selected(false)
+            .pressed(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(32.0)
+            .font("Roboto-Regular")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+widget!(
+    /// The `TreeView` is an items drawer widget that displays hierarchical items. Each node with children could be
+    /// expanded and collapsed.
+    ///
+    /// The children of a node are requested by the `children_count` callback and generated by the `items_builder`
+    /// callback only if the node is expanded. Both callbacks get the `TreePath` of the node, an empty path describes the
+    /// root level.
+    ///
+    /// If a node is selected a `SelectionChangedEvent` is raised. Its `selected` field contains the path of the node.
+    ///
+    /// **style:** `tree_view`
+    TreeView<TreeViewState>: SelectionChangedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the path of the selected node. If the path is empty no node is selected.
+        selected_path: TreePath,
+
+        /// Use this flag to force the redrawing of the items.
+        request_update: bool
+    }
+);
+
+impl TreeView {
+    /// Define the template build function for the content of a node, referenced by its path.
+    pub fn items_builder<F: Fn(&mut BuildContext, &[usize]) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().builder = Some(Box::new(builder));
+        self
+    }
+
+    /// Define the callback that returns the number of children of the node with the given path.
+    pub fn children_count<F: Fn(&[usize]) -> usize + 'static>(mut self, children_count: F) -> Self {
+        self.state_mut().children_count = Some(Box::new(children_count));
+        self
+    }
+}
+
+impl Template for TreeView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::new()
+            .v_align("start")
+            .id(ITEMS_PANEL)
+            .orientation("vertical")
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::new()
+            .mode(("auto", "auto"))
+            .child(items_panel)
+            .build(ctx);
+
+        self.name("TreeView")
+            .style(STYLE_TREE_VIEW)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .selected_path(TreePath::new())
+            .request_update(false)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(scroll_viewer)
+                    .child(
+                        ScrollIndicator::new()
+                            .padding(2.0)
+                            .content_bounds(("bounds", items_panel))
+                            .view_port_bounds(("bounds", scroll_viewer))
+                            .scroll_padding(("padding", scroll_viewer))
+                            .mode(scroll_viewer)
+                            .opacity(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// Calculates the visible rows of the tree. Only the children of expanded nodes are requested.
+fn visible_rows(
+    children_count: &dyn Fn(&[usize]) -> usize,
+    expanded: &HashSet<TreePath>,
+) -> Vec<TreeRow> {
+    let mut rows = vec![];
+    collect_rows(children_count, expanded, &mut vec![], &mut rows);
+    rows
+}
+
+fn collect_rows(
+    children_count: &dyn Fn(&[usize]) -> usize,
+    expanded: &HashSet<TreePath>,
+    path: &mut TreePath,
+    rows: &mut Vec<TreeRow>,
+) {
+    for i in 0..children_count(path) {
+        path.push(i);
+
+        let has_children = children_count(path) > 0;
+        let is_expanded = has_children && expanded.contains(path);
+
+        rows.push(TreeRow {
+            path: path.clone(),
+            has_children,
+            expanded: is_expanded,
+        });
+
+        if is_expanded {
+            collect_rows(children_count, expanded, path, rows);
+        }
+
+        path.pop();
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn children_count(path: &[usize]) -> usize {
+        if path.len() < 2 {
+            return 2;
+        }
+
+        0
+    }
+
+    #[test]
+    fn test_visible_rows_collapsed() {
+        let rows = visible_rows(&children_count, &HashSet::new());
+
+        assert_eq!(
+            rows,
+            vec![
+                TreeRow {
+                    path: vec![0],
+                    has_children: true,
+                    expanded: false
+                },
+                TreeRow {
+                    path: vec![1],
+                    has_children: true,
+                    expanded: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visible_rows_expanded() {
+        let mut expanded = HashSet::new();
+        expanded.insert(vec![1]);
+        expanded.insert(vec![1, 0]);
+
+        let rows = visible_rows(&children_count, &expanded);
+
+        assert_eq!(
+            rows.iter()
+                .map(|r| r.path.clone())
+                .collect::<Vec<TreePath>>(),
+            vec![vec![0], vec![1], vec![1, 0], vec![1, 1]]
+        );
+        assert!(rows[1].expanded);
+        assert!(!rows[2].has_children);
+        assert!(!rows[2].expanded);
+    }
+}
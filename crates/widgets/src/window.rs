@@ -1,6 +1,13 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use crate::{api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, theme::prelude::*};
+use crate::{
+    api::prelude::*,
+    prelude::*,
+    proc_macros::*,
+    render::prelude::Image,
+    shell::prelude::{system_color_scheme, ColorScheme, WindowRequest, DEFAULT_TOOL_TIP_DELAY},
+    theme::prelude::*,
+};
 
 // --- KEYS --
 pub static STYLE_WINDOW: &str = "window";
@@ -50,52 +57,21 @@ impl WindowState {
         // }
     }
 
-    fn request_focus(&self, entity: Entity, ctx: &mut Context) {
-        let focused_widget = ctx.widget().get::<Global>("global").focused_widget;
-
-        if (focused_widget.is_some() && focused_widget.unwrap() == entity)
-            || !*ctx.get_widget(entity).get::<bool>("enabled")
-        {
+    fn color_scheme_changed(&self, color_scheme: ColorScheme, ctx: &mut Context) {
+        if *window(ctx.widget()).color_scheme() == color_scheme {
             return;
         }
 
-        if let Some(old_focused_element) = ctx.window().get::<Global>("global").focused_widget {
-            let mut old_focused_element = ctx.get_widget(old_focused_element);
-
-            old_focused_element.set("focused", false);
-            old_focused_element
-                .get_mut::<Selector>("selector")
-                .clear_state();
-            old_focused_element.update(false);
-        }
-
-        ctx.window().get_mut::<Global>("global").focused_widget = Some(entity);
+        window(ctx.widget()).set_color_scheme(color_scheme);
 
-        if ctx.get_widget(entity).has::<bool>("focused") {
-            let mut focused_element = ctx.get_widget(entity);
-
-            focused_element.set("focused", true);
-            focused_element
-                .get_mut::<Selector>("selector")
-                .set_state("focused");
-            focused_element.update(false);
+        if *window(ctx.widget()).follow_system_theme() {
+            switch_system_theme(color_scheme, ctx);
         }
     }
 
-    fn remove_focus(&self, entity: Entity, ctx: &mut Context) {
-        if let Some(old_focused_element) = ctx.window().get::<Global>("global").focused_widget {
-            if old_focused_element != entity {
-                return;
-            }
-            let mut old_focused_element = ctx.get_widget(old_focused_element);
-            old_focused_element.set("focused", false);
-            old_focused_element
-                .get_mut::<Selector>("selector")
-                .clear_state();
-            old_focused_element.update(false);
-        }
-
-        ctx.widget().get_mut::<Global>("global").focused_widget = None;
+    // the window is redrawn with the new scale factor
+    fn scale_factor_changed(&self, scale_factor: f64, ctx: &mut Context) {
+        window(ctx.widget()).set_scale_factor(scale_factor);
     }
 
     fn set_background(&mut self, ctx: &mut Context) {
@@ -105,15 +81,29 @@ impl WindowState {
         };
         self.background = background;
     }
+
+    fn set_tool_tip_delay(&self, ctx: &mut Context) {
+        let delay = *window(ctx.widget()).tool_tip_delay();
+        ctx.tool_tip_service().set_delay(delay);
+    }
 }
 
 impl State for WindowState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // picks the matching theme before the first frame is drawn
+        if *window(ctx.widget()).follow_system_theme() {
+            let color_scheme = system_color_scheme();
+            window(ctx.widget()).set_color_scheme(color_scheme);
+            switch_system_theme(color_scheme, ctx);
+        }
+
         self.set_background(ctx);
+        self.scale_factor_changed(ctx.scale_factor(), ctx);
         self.title = ctx.widget().clone("title");
+        self.set_tool_tip_delay(ctx);
     }
 
-    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
         if self.background != *window(ctx.widget()).background() {
             self.set_background(ctx);
         }
@@ -123,6 +113,8 @@ impl State for WindowState {
             ctx.send_window_request(WindowRequest::ChangeTitle(self.title.clone()));
         }
 
+        self.set_tool_tip_delay(ctx);
+
         if let Some(action) = self.actions.pop_front() {
             match action {
                 Action::WindowEvent(window_event) => match window_event {
@@ -132,16 +124,26 @@ impl State for WindowState {
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
-                    _ => {}
-                },
-                Action::FocusEvent(focus_event) => match focus_event {
-                    FocusEvent::RequestFocus(entity) => {
-                        self.request_focus(entity, ctx);
+                    WindowEvent::ColorSchemeChanged(color_scheme) => {
+                        self.color_scheme_changed(color_scheme, ctx);
                     }
-                    FocusEvent::RemoveFocus(entity) => {
-                        self.remove_focus(entity, ctx);
+                    WindowEvent::ScaleFactorChanged(scale_factor) => {
+                        self.scale_factor_changed(scale_factor, ctx);
                     }
+                    _ => {}
                 },
+                Action::FocusEvent(focus_event) => {
+                    let focus_manager = registry.get_mut::<FocusManager>("focus_manager");
+
+                    match focus_event {
+                        FocusEvent::RequestFocus(entity) => {
+                            focus_manager.request_focus(entity, ctx);
+                        }
+                        FocusEvent::RemoveFocus(entity) => {
+                            focus_manager.remove_focus(entity, ctx);
+                        }
+                    }
+                }
             }
         }
     }
@@ -149,10 +151,13 @@ impl State for WindowState {
 
 widget!(
     /// The `Window` widget provides access to the properties of an application window.
-    /// It also contains global properties like keyboard modifier and focused widget.
+    /// It also contains global properties like keyboard modifier and focused widget. The keyboard focus is moved by
+    /// the `FocusManager` of the window. Messages of other windows, that are sent with the `Windows` service to the
+    /// `id` of the window, are handled by `on_message`. The events of the tray icon of the window, that is shown with
+    /// `Context::set_tray_icon`, are handled by `on_tray_event`.
     ///
     /// **style:** `window`
-    Window<WindowState> {
+    Window<WindowState>: MessageHandler, TrayIconHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -165,12 +170,35 @@ widget!(
         /// Sets or shares the property if this window should always be on top.
         always_on_top: bool,
 
-        /// Sets or shares the flag if the window is borderless.
+        /// Sets or shares the flag if the window is borderless. A borderless window has no decorations of the
+        /// platform, use a `WindowTitleBar` to move and close it.
         borderless: bool,
 
         /// Sets or shares a value that describes if the current window is active.
         active: bool,
 
+        /// Sets or shares the delay in milliseconds until the tool tip of a hovered widget is shown.
+        tool_tip_delay: f64,
+
+        /// Sets or shares the light or dark preference of the platform. It's updated if the preference changes.
+        color_scheme: ColorScheme,
+
+        /// Sets or shares the scale factor of the monitor the window is shown on. It's updated if the window is moved
+        /// to a monitor with another DPI. The sizes of the widgets are given in logical pixels and are scaled with it.
+        scale_factor: f64,
+
+        /// Sets or shares the flag if the built-in light or dark theme is switched to match the preference of the
+        /// platform on startup and if it changes.
+        follow_system_theme: bool,
+
+        /// Sets or shares the flag if the window is placed in the center of its owner, if it is opened with
+        /// `Context::show_owned_window` or `Context::show_modal_window`.
+        center_on_owner: bool,
+
+        /// Sets or shares the icon of the window that is shown in its title bar and on the task bar. Use
+        /// `Context::set_window_icon` to change the icon of an open window.
+        icon: Image,
+
         /// Internal property to handle dirty widgets.
         dirty_widgets: DirtyWidgets
     }
@@ -197,7 +225,16 @@ impl Window {
 }
 
 impl Template for Window {
-    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let toast_panel = ToastPanel::new().build(ctx);
+        let _ = ctx.append_child_to_overlay(toast_panel);
+
+        let tool_tip = ToolTip::new().build(ctx);
+        let _ = ctx.append_child_to_overlay(tool_tip);
+
+        let drag_ghost = DragGhost::new().build(ctx);
+        let _ = ctx.append_child_to_overlay(drag_ghost);
+
         self.name("Window")
             .background(colors::BRIGHT_GRAY_COLOR)
             .size(100.0, 100.0)
@@ -205,6 +242,10 @@ impl Template for Window {
             .title("Window")
             .resizeable(false)
             .always_on_top(false)
+            .tool_tip_delay(DEFAULT_TOOL_TIP_DELAY)
+            .follow_system_theme(false)
+            .center_on_owner(true)
+            .scale_factor(1.0)
             .on_window_event(move |ctx, event| {
                 ctx.get_mut::<WindowState>(id)
                     .push_action(Action::WindowEvent(event));
@@ -225,3 +266,15 @@ impl Template for Window {
         Box::new(GridLayout::new())
     }
 }
+
+// --- Helpers --
+
+// Switches to the built-in theme that matches the given color scheme.
+fn switch_system_theme(color_scheme: ColorScheme, ctx: &mut Context) {
+    let theme = match color_scheme {
+        ColorScheme::Light => light_theme(),
+        ColorScheme::Dark => dark_theme(),
+    };
+
+    ctx.switch_theme(theme);
+}
@@ -0,0 +1,348 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_WINDOW_TITLE_BAR: &str = "window_title_bar";
+static ID_MINIMIZE: &str = "window_title_bar_minimize";
+static ID_MAXIMIZE: &str = "window_title_bar_maximize";
+
+// --- KEYS --
+
+/// The default height of a `WindowTitleBar`.
+pub const WINDOW_TITLE_BAR_HEIGHT: f64 = 32.0;
+
+#[derive(Copy, Clone)]
+enum WindowTitleBarAction {
+    Press(Point),
+    Drag(Point),
+    EndDrag,
+    ToggleMaximize,
+    Minimize,
+    Close,
+}
+
+/// The `WindowTitleBarState` moves the window while the title bar is dragged and handles its buttons.
+#[derive(Default, AsAny)]
+pub struct WindowTitleBarState {
+    actions: Vec<WindowTitleBarAction>,
+    drag_start: Option<Point>,
+
+    // the title bar shows the title of the window if no title is set
+    follow_window_title: bool,
+}
+
+impl WindowTitleBarState {
+    fn action(&mut self, action: WindowTitleBarAction) {
+        self.actions.push(action);
+    }
+
+    fn drag(&mut self, ctx: &mut Context, position: Point) {
+        let start = match self.drag_start {
+            Some(start) => start,
+            None => return,
+        };
+
+        if start == position {
+            return;
+        }
+
+        // a maximized window is restored before it could be moved
+        if ctx.window_mode() == WindowMode::Maximized {
+            ctx.restore_window();
+            self.update_maximize_icon(ctx);
+        }
+
+        let window_position = dragged_position(ctx.window_position(), start, position);
+        ctx.move_window(window_position.x(), window_position.y());
+    }
+
+    fn toggle_maximize(&mut self, ctx: &mut Context) {
+        if !*window_title_bar(ctx.widget()).maximizable() {
+            return;
+        }
+
+        if ctx.window_mode() == WindowMode::Maximized {
+            ctx.restore_window();
+        } else {
+            ctx.maximize_window();
+        }
+
+        self.update_maximize_icon(ctx);
+    }
+
+    fn update_maximize_icon(&self, ctx: &mut Context) {
+        let icon = maximize_icon(ctx.window_mode());
+        button(ctx.child(ID_MAXIMIZE)).set_icon(String::from(icon));
+    }
+}
+
+impl State for WindowTitleBarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.follow_window_title = window_title_bar(ctx.widget()).title().is_empty();
+
+        if !*window_title_bar(ctx.widget()).minimizable() {
+            ctx.child(ID_MINIMIZE)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        if !*window_title_bar(ctx.widget()).maximizable() {
+            ctx.child(ID_MAXIMIZE)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        self.update_maximize_icon(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.follow_window_title {
+            let title = ctx.window().clone::<String>("title");
+
+            if *window_title_bar(ctx.widget()).title() != title {
+                window_title_bar(ctx.widget()).set_title(title);
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<WindowTitleBarAction> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                WindowTitleBarAction::Press(position) => self.drag_start = Some(position),
+                WindowTitleBarAction::Drag(position) => self.drag(ctx, position),
+                WindowTitleBarAction::EndDrag => self.drag_start = None,
+                WindowTitleBarAction::ToggleMaximize => self.toggle_maximize(ctx),
+                WindowTitleBarAction::Minimize => ctx.minimize_window(),
+                WindowTitleBarAction::Close => ctx.send_window_request(WindowRequest::Close),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `WindowTitleBar` replaces the decorations of the platform for a borderless `Window`. Dragging the title
+    /// bar moves the window, a double click maximizes or restores it. The bar shows the title of the window and
+    /// buttons to minimize, maximize and close it.
+    ///
+    /// The title bar should be placed at the top of the content of the window, it could be combined with other
+    /// widgets, e.g. a menu bar, to build a fully custom window chrome.
+    ///
+    /// **style:** `window_title_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Window::new()
+    ///     .title("OrbTk")
+    ///     .borderless(true)
+    ///     .child(
+    ///         Grid::new()
+    ///             .rows(Rows::create().push("auto").push("*"))
+    ///             .child(WindowTitleBar::new().build(ctx))
+    ///             .child(MainView::new().attach(Grid::row(1)).build(ctx))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    WindowTitleBar<WindowTitleBarState>: MouseHandler, GestureHandler {
+        /// Sets or shares the title. If no title is set, the title of the window is shown.
+        title: String,
+
+        /// Sets or shares the flag if the minimize button is shown.
+        minimizable: bool,
+
+        /// Sets or shares the flag if the maximize button is shown and a double click maximizes the window.
+        maximizable: bool,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for WindowTitleBar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("WindowTitleBar")
+            .style(STYLE_WINDOW_TITLE_BAR)
+            .title("")
+            .minimizable(true)
+            .maximizable(true)
+            .background(colors::LYNCH_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .padding((8.0, 0.0, 4.0, 0.0))
+            .height(WINDOW_TITLE_BAR_HEIGHT)
+            .child(
+                Grid::new()
+                    .columns(
+                        Columns::create()
+                            .push("*")
+                            .push("auto")
+                            .push("auto")
+                            .push("auto"),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(("title", id))
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .attach(Grid::column(0))
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .focusable(false)
+                            .id(ID_MINIMIZE)
+                            .style("button_icon_only")
+                            .v_align("center")
+                            .icon(material_icons_font::MD_MINIMIZE)
+                            .icon_brush(("foreground", id))
+                            .attach(Grid::column(1))
+                            .on_click(move |states, _| {
+                                states
+                                    .get_mut::<WindowTitleBarState>(id)
+                                    .action(WindowTitleBarAction::Minimize);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .focusable(false)
+                            .id(ID_MAXIMIZE)
+                            .style("button_icon_only")
+                            .v_align("center")
+                            .icon(material_icons_font::MD_CROP_SQUARE)
+                            .icon_brush(("foreground", id))
+                            .attach(Grid::column(2))
+                            .on_click(move |states, _| {
+                                states
+                                    .get_mut::<WindowTitleBarState>(id)
+                                    .action(WindowTitleBarAction::ToggleMaximize);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .focusable(false)
+                            .style("button_icon_only")
+                            .v_align("center")
+                            .icon(material_icons_font::MD_CLOSE)
+                            .icon_brush(("foreground", id))
+                            .attach(Grid::column(3))
+                            .on_click(move |states, _| {
+                                states
+                                    .get_mut::<WindowTitleBarState>(id)
+                                    .action(WindowTitleBarAction::Close);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<WindowTitleBarState>(id)
+                    .action(WindowTitleBarAction::Press(m.position));
+                true
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<WindowTitleBarState>(id)
+                    .action(WindowTitleBarAction::Drag(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<WindowTitleBarState>(id)
+                    .action(WindowTitleBarAction::EndDrag);
+            })
+            .on_double_click(move |states, _| {
+                states
+                    .get_mut::<WindowTitleBarState>(id)
+                    .action(WindowTitleBarAction::ToggleMaximize);
+                true
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+// --- Helpers --
+
+// Returns the position of the window after the mouse is moved from the pressed position to the given position
+// inside of the window.
+fn dragged_position(window_position: Point, start: Point, position: Point) -> Point {
+    Point::new(
+        window_position.x() + position.x() - start.x(),
+        window_position.y() + position.y() - start.y(),
+    )
+}
+
+// Returns the icon of the maximize button, it restores a maximized window.
+fn maximize_icon(mode: WindowMode) -> &'static str {
+    match mode {
+        WindowMode::Maximized => material_icons_font::MD_FILTER_NONE,
+        _ => material_icons_font::MD_CROP_SQUARE,
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dragged_position() {
+        assert_eq!(
+            dragged_position(
+                Point::new(100., 50.),
+                Point::new(10., 10.),
+                Point::new(30., 5.)
+            ),
+            Point::new(120., 45.)
+        );
+        assert_eq!(
+            dragged_position(
+                Point::new(100., 50.),
+                Point::new(10., 10.),
+                Point::new(10., 10.)
+            ),
+            Point::new(100., 50.)
+        );
+    }
+
+    #[test]
+    fn test_maximize_icon() {
+        assert_eq!(
+            maximize_icon(WindowMode::Maximized),
+            material_icons_font::MD_FILTER_NONE
+        );
+        assert_eq!(
+            maximize_icon(WindowMode::Normal),
+            material_icons_font::MD_CROP_SQUARE
+        );
+    }
+}
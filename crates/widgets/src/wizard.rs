@@ -0,0 +1,424 @@
+use std::rc::Rc;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_WIZARD: &str = "wizard";
+static ID_BACK: &str = "id_back";
+static ID_NEXT: &str = "id_next";
+static ID_FINISH: &str = "id_finish";
+
+// --- KEYS --
+
+/// The validation of a wizard step. It is called with the index of the step if the user leaves the step by the next
+/// or the finish button, if it returns `false` the wizard stays on the step.
+pub type WizardValidator = dyn Fn(&mut StatesContext, usize) -> bool + 'static;
+
+// A page of the wizard with its title, its validation and the widgets of its progress indicator.
+struct WizardStep {
+    title: String,
+    body: Entity,
+    validator: Option<Rc<WizardValidator>>,
+    indicator: Entity,
+    number: Entity,
+    check: Entity,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum WizardAction {
+    Next,
+    Back,
+    Finish,
+}
+
+/// The `WizardState` switches between the steps of a `Wizard` and updates its progress indicator and buttons.
+#[derive(Default, AsAny)]
+pub struct WizardState {
+    steps: Vec<WizardStep>,
+    action: Option<WizardAction>,
+    current: usize,
+}
+
+impl WizardState {
+    /// Goes to the next step. The validation of the current step is not called.
+    pub fn next(&mut self) {
+        self.action = Some(WizardAction::Next);
+    }
+
+    /// Goes back to the previous step.
+    pub fn back(&mut self) {
+        self.action = Some(WizardAction::Back);
+    }
+
+    /// Raises the finish event. The validation of the current step is not called.
+    pub fn finish(&mut self) {
+        self.action = Some(WizardAction::Finish);
+    }
+
+    /// Gets the index of the current step.
+    pub fn current_step(&self) -> usize {
+        self.current
+    }
+
+    /// Gets the number of steps.
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    // Gets the index and the validation of the current step.
+    fn current_validator(&self) -> (usize, Option<Rc<WizardValidator>>) {
+        (
+            self.current,
+            self.steps
+                .get(self.current)
+                .and_then(|step| step.validator.clone()),
+        )
+    }
+
+    // Shows the page of the current step and marks the completed steps in the progress indicator.
+    fn update_steps(&self, ctx: &mut Context) {
+        let current = self.current;
+        let last = self.steps.len().saturating_sub(1);
+        let indicator_brush = ctx.widget().clone::<Brush>("indicator_brush");
+        let track_brush = ctx.widget().clone::<Brush>("track_brush");
+
+        for (index, step) in self.steps.iter().enumerate() {
+            ctx.get_widget(step.body)
+                .set("visibility", visibility(index == current));
+
+            ctx.get_widget(step.indicator).set(
+                "background",
+                if index <= current {
+                    indicator_brush.clone()
+                } else {
+                    track_brush.clone()
+                },
+            );
+
+            // completed steps show a check mark instead of their number
+            ctx.get_widget(step.number)
+                .set("visibility", visibility(index >= current));
+            ctx.get_widget(step.check)
+                .set("visibility", visibility(index < current));
+        }
+
+        ctx.child(ID_BACK).set("enabled", current > 0);
+        ctx.child(ID_BACK).set(
+            "visibility",
+            if current > 0 {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },
+        );
+        ctx.child(ID_NEXT)
+            .set("visibility", visibility(current < last));
+        ctx.child(ID_FINISH)
+            .set("visibility", visibility(current == last));
+
+        wizard(ctx.widget()).set_current_step(current);
+    }
+}
+
+impl State for WizardState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.current =
+            (*wizard(ctx.widget()).current_step()).min(self.steps.len().saturating_sub(1));
+        self.update_steps(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let action = match self.action.take() {
+            Some(action) => action,
+            None => return,
+        };
+
+        match action {
+            WizardAction::Next => {
+                if self.current + 1 < self.steps.len() {
+                    self.current += 1;
+                }
+            }
+            WizardAction::Back => {
+                self.current = self.current.saturating_sub(1);
+            }
+            WizardAction::Finish => {
+                ctx.push_event_strategy_by_entity(
+                    FinishEvent(ctx.entity),
+                    ctx.entity,
+                    EventStrategy::Direct,
+                );
+            }
+        }
+
+        self.update_steps(ctx);
+    }
+}
+
+widget!(
+    /// The `Wizard` widget leads the user through a sequence of steps. Each step is a page with a title, the
+    /// progress indicator on top shows the completed steps. The user moves between the steps by the back, next
+    /// and finish buttons.
+    ///
+    /// A step could have a validation that is called before the user leaves it by the next or finish button, it
+    /// blocks advancing if it returns `false`. The finish button raises the `on_finish` event.
+    ///
+    /// **style:** `wizard`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Wizard::new()
+    ///     .step("Account", TextBox::new().id("name").build(ctx))
+    ///     .step_with_validation("Address", address_page, move |states, _| {
+    ///         states.get::<MainViewState>(id).address_valid()
+    ///     })
+    ///     .step("Summary", summary_page)
+    ///     .on_finish(move |states, _| {
+    ///         states.get_mut::<MainViewState>(id).submit();
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    Wizard<WizardState>: FinishHandler {
+        /// Sets or shares the index of the current step. It is updated by the wizard.
+        current_step: usize,
+
+        /// Sets or shares the text of the back button.
+        back_text: String16,
+
+        /// Sets or shares the text of the next button.
+        next_text: String16,
+
+        /// Sets or shares the text of the finish button.
+        finish_text: String16,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: BorderRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground of the step titles.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the brush of the completed steps and the current step in the progress indicator.
+        indicator_brush: Brush,
+
+        /// Sets or shares the brush of the open steps in the progress indicator.
+        track_brush: Brush,
+
+        /// Sets or shares the brush of the numbers and the check marks in the progress indicator.
+        icon_brush: Brush,
+
+        /// Sets or shares the space between the progress indicator, the page and the buttons.
+        spacing: f64
+    }
+);
+
+impl Wizard {
+    /// Adds a step with the given title and page.
+    pub fn step(mut self, title: impl Into<String>, body: Entity) -> Self {
+        self.state.steps.push(WizardStep {
+            title: title.into(),
+            body,
+            validator: None,
+            indicator: Entity::default(),
+            number: Entity::default(),
+            check: Entity::default(),
+        });
+        self
+    }
+
+    /// Adds a step with the given title, page and validation. The validation is called with the index of the step
+    /// if the user leaves the step by the next or the finish button, if it returns `false` the wizard stays on it.
+    pub fn step_with_validation<V: Fn(&mut StatesContext, usize) -> bool + 'static>(
+        mut self,
+        title: impl Into<String>,
+        body: Entity,
+        validator: V,
+    ) -> Self {
+        self = self.step(title, body);
+
+        if let Some(step) = self.state.steps.last_mut() {
+            step.validator = Some(Rc::new(validator));
+        }
+
+        self
+    }
+}
+
+impl Template for Wizard {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut progress = Stack::new()
+            .orientation("horizontal")
+            .spacing(16.0)
+            .attach(Grid::row(0));
+        let mut pages = Grid::new().attach(Grid::row(2));
+
+        for (index, step) in self.state.steps.iter_mut().enumerate() {
+            step.number = TextBlock::new()
+                .h_align("center")
+                .v_align("center")
+                .text((index + 1).to_string())
+                .foreground(("icon_brush", id))
+                .font_size(fonts::FONT_SIZE_12)
+                .font(id)
+                .build(ctx);
+            step.check = FontIconBlock::new()
+                .h_align("center")
+                .v_align("center")
+                .icon(material_icons_font::MD_CHECK)
+                .icon_brush(id)
+                .icon_size(fonts::ICON_FONT_SIZE_12)
+                .visibility(Visibility::Collapsed)
+                .build(ctx);
+            step.indicator = Container::new()
+                .width(24.0)
+                .height(24.0)
+                .border_radius(12.0)
+                .v_align("center")
+                .child(step.number)
+                .child(step.check)
+                .build(ctx);
+
+            progress = progress.child(
+                Stack::new()
+                    .orientation("horizontal")
+                    .spacing(8.0)
+                    .child(step.indicator)
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(step.title.as_str())
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            );
+
+            pages = pages.child(step.body);
+        }
+
+        self.name("Wizard")
+            .style(STYLE_WIZARD)
+            .current_step(0)
+            .back_text("Back")
+            .next_text("Next")
+            .finish_text("Finish")
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(0.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .indicator_brush("#efd035")
+            .track_brush(colors::LYNCH_COLOR)
+            .icon_brush(colors::BRIGHT_GRAY_COLOR)
+            .spacing(16.0)
+            .child(
+                Grid::new()
+                    .rows(
+                        Rows::create()
+                            .push("auto")
+                            .push(("spacing", id))
+                            .push("*")
+                            .push(("spacing", id))
+                            .push("auto"),
+                    )
+                    .child(progress.build(ctx))
+                    .child(pages.build(ctx))
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .h_align("end")
+                            .spacing(8.0)
+                            .attach(Grid::row(4))
+                            .child(
+                                Button::new()
+                                    .id(ID_BACK)
+                                    .style("button_single_content")
+                                    .text(("back_text", id))
+                                    .on_click(move |states, _| {
+                                        states.get_mut::<WizardState>(id).back();
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .id(ID_NEXT)
+                                    .style("button_primary")
+                                    .text(("next_text", id))
+                                    .on_click(move |states, _| {
+                                        if validate_current_step(states, id) {
+                                            states.get_mut::<WizardState>(id).next();
+                                        }
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .id(ID_FINISH)
+                                    .style("button_primary")
+                                    .text(("finish_text", id))
+                                    .on_click(move |states, _| {
+                                        if validate_current_step(states, id) {
+                                            states.get_mut::<WizardState>(id).finish();
+                                        }
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+// --- Helpers --
+
+// Calls the validation of the current step of the wizard, a step without validation is always valid.
+fn validate_current_step(states: &mut StatesContext, id: Entity) -> bool {
+    let (index, validator) = states.get::<WizardState>(id).current_validator();
+    validator.map_or(true, |validator| validator(states, index))
+}
+
+fn visibility(visible: bool) -> Visibility {
+    if visible {
+        Visibility::Visible
+    } else {
+        Visibility::Collapsed
+    }
+}
+
+// --- Helpers --
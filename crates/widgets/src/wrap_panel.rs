@@ -0,0 +1,28 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `WrapPanel` places its children in lines. With horizontal orientation the children are placed from left
+    /// to right and wrapped to the next line if the available width is exceeded. With vertical orientation the
+    /// children are placed from top to bottom and wrapped to the next column.
+    ///
+    /// **style:** `wrap_panel`
+    WrapPanel {
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Space between the children and between the lines.
+        spacing: f64
+    }
+);
+
+impl Template for WrapPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("WrapPanel")
+            .orientation("horizontal")
+            .style("wrap_panel")
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(WrapLayout::new())
+    }
+}
@@ -0,0 +1,68 @@
+use orbtk::prelude::*;
+
+const SIZE: u32 = 64;
+const FRAMES: u32 = 8;
+
+// Creates the frames of a square that moves from the left to the right.
+fn frames() -> Vec<ImageFrame> {
+    let square = SIZE / FRAMES;
+
+    (0..FRAMES)
+        .map(|frame| {
+            let mut data = vec![0xff_3b_43_4a; (SIZE * SIZE) as usize];
+
+            for y in (SIZE - square) / 2..(SIZE + square) / 2 {
+                for x in frame * square..(frame + 1) * square {
+                    data[(y * SIZE + x) as usize] = 0xff_ef_d0_35;
+                }
+            }
+
+            ImageFrame {
+                image: Image::from((SIZE, SIZE, data)),
+                delay: 80.0,
+            }
+        })
+        .collect()
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let play = CheckBox::new().text("Play").selected(true).build(ctx);
+        let looping = CheckBox::new().text("Loop").selected(true).build(ctx);
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    ImageWidget::new()
+                        .h_align("start")
+                        .animation(frames())
+                        .play(("selected", play))
+                        .looping(("selected", looping))
+                        .build(ctx),
+                )
+                .child(play)
+                .child(looping)
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - animated image example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,89 @@
+use std::{thread, time::Duration};
+
+use orbtk::prelude::*;
+
+static CITIES: [&str; 12] = [
+    "Amsterdam",
+    "Athens",
+    "Berlin",
+    "Bern",
+    "Brussels",
+    "Budapest",
+    "Lisbon",
+    "London",
+    "Madrid",
+    "Paris",
+    "Prague",
+    "Vienna",
+];
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Static list")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    AutoCompleteBox::new()
+                        .water_mark("City")
+                        .suggestions(
+                            CITIES
+                                .iter()
+                                .map(|city| city.to_string())
+                                .collect::<Vec<_>>(),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("Provider (delayed like a web request)")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    AutoCompleteBox::new()
+                        .water_mark("City")
+                        .suggestion_provider(|text, sender| {
+                            thread::spawn(move || {
+                                thread::sleep(Duration::from_millis(300));
+
+                                let text = text.to_lowercase();
+                                sender.send(
+                                    CITIES
+                                        .iter()
+                                        .filter(|city| city.to_lowercase().starts_with(&text))
+                                        .map(|city| city.to_string())
+                                        .collect(),
+                                );
+                            });
+                        })
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - auto complete box example")
+                .position((100.0, 100.0))
+                .size(420.0, 400.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,126 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    deleted: Vec<Entity>,
+    read: bool,
+}
+
+impl MainViewState {
+    fn read(&mut self) {
+        self.read = true;
+    }
+
+    fn delete(&mut self, chip: Entity) {
+        self.deleted.push(chip);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for chip in self.deleted.drain(..) {
+            ctx.get_widget(chip)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        if self.read {
+            self.read = false;
+            main_view(ctx.widget()).set_count(0);
+        }
+    }
+}
+
+widget!(MainView<MainViewState> { count: usize });
+
+fn filter_chip(id: Entity, ctx: &mut BuildContext, text: &str, icon: &str) -> Entity {
+    Chip::new()
+        .text(text)
+        .icon(icon)
+        .deletable(true)
+        .on_delete(move |states, chip| {
+            states.get_mut::<MainViewState>(id).delete(chip);
+        })
+        .build(ctx)
+}
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").count(3).child(
+            Stack::new()
+                .spacing(16.0)
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(
+                            Badge::new()
+                                .count(id)
+                                .child(
+                                    Button::new()
+                                        .margin((0.0, 8.0, 8.0, 0.0))
+                                        .text("Inbox")
+                                        .icon(material_icons_font::MD_MAIL)
+                                        .on_click(move |states, _| {
+                                            states.get_mut::<MainViewState>(id).read();
+                                            true
+                                        })
+                                        .build(ctx),
+                                )
+                                .build(ctx),
+                        )
+                        .child(
+                            Badge::new()
+                                .count(120)
+                                .child(
+                                    Button::new()
+                                        .margin((0.0, 8.0, 8.0, 0.0))
+                                        .text("Updates")
+                                        .build(ctx),
+                                )
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(TextBlock::new().text("Filters").build(ctx))
+                .child(
+                    WrapPanel::new()
+                        .spacing(8.0)
+                        .child(filter_chip(id, ctx, "Unread", material_icons_font::MD_MAIL))
+                        .child(filter_chip(
+                            id,
+                            ctx,
+                            "Starred",
+                            material_icons_font::MD_STAR,
+                        ))
+                        .child(filter_chip(id, ctx, "Attachments", ""))
+                        .build(ctx),
+                )
+                .child(TextBlock::new().text("Categories").build(ctx))
+                .child(
+                    WrapPanel::new()
+                        .spacing(8.0)
+                        .child(Chip::new().text("Work").selected(true).build(ctx))
+                        .child(Chip::new().text("Family").build(ctx))
+                        .child(Chip::new().text("Travel").build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - badge and chip example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::new().margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,76 @@
+use orbtk::prelude::*;
+
+const SIZE: u32 = 24;
+const CORNER: u32 = 8;
+
+// Creates a frame image with a dark border, golden corners and a light center.
+fn frame_image() -> (u32, u32, Vec<u32>) {
+    let mut data = vec![0xff_df_eb_f5; (SIZE * SIZE) as usize];
+
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let border = x < 2 || y < 2 || x >= SIZE - 2 || y >= SIZE - 2;
+            let corner = (x < CORNER || x >= SIZE - CORNER) && (y < CORNER || y >= SIZE - CORNER);
+
+            data[(y * SIZE + x) as usize] = if border {
+                0xff_3b_43_4a
+            } else if corner {
+                0xff_ef_d0_35
+            } else {
+                0xff_df_eb_f5
+            };
+        }
+    }
+
+    (SIZE, SIZE, data)
+}
+
+fn frame(ctx: &mut BuildContext, text: &str, width: f64, height: f64) -> Entity {
+    Container::new()
+        .width(width)
+        .height(height)
+        .border_image(frame_image())
+        .border_image_slice(CORNER as f64)
+        .padding(CORNER as f64)
+        .child(
+            TextBlock::new()
+                .text(text)
+                .foreground("#3b434a")
+                .v_align("center")
+                .h_align("center")
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(frame(ctx, "Small", 80.0, 40.0))
+                .child(frame(ctx, "Wide", 300.0, 40.0))
+                .child(frame(ctx, "Large", 200.0, 120.0))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - border image example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
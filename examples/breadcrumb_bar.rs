@@ -0,0 +1,119 @@
+use orbtk::prelude::*;
+
+static ID_FOLDER: &str = "folder";
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Open,
+    Activate(usize),
+}
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    action: Option<Action>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let mut path = main_view(ctx.widget()).clone_path();
+
+        match self.action.take() {
+            Some(Action::Open) => {
+                let folder = ctx.child(ID_FOLDER).clone::<String16>("text").to_string();
+
+                if folder.is_empty() {
+                    return;
+                }
+
+                path.push(folder);
+                ctx.child(ID_FOLDER).set("text", String16::default());
+            }
+            // goes back to the activated folder
+            Some(Action::Activate(index)) => path.truncate(index + 1),
+            None => return,
+        }
+
+        let text = format!("/{}", path[1..].join("/"));
+        main_view(ctx.widget()).set_path(path);
+        main_view(ctx.widget()).set_location(String16::from(text));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    path: Vec<String>,
+    location: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let path: Vec<String> = vec!["root", "home", "user", "documents", "projects", "orbtk"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        self.name("MainView")
+            .location("/home/user/documents/projects/orbtk")
+            .path(path)
+            .child(
+                Stack::new()
+                    .spacing(16.0)
+                    .child(
+                        BreadcrumbBar::new()
+                            .segments(("path", id))
+                            .on_segment_activated(move |states, _, index| {
+                                states
+                                    .get_mut::<MainViewState>(id)
+                                    .action(Action::Activate(index));
+                            })
+                            .build(ctx),
+                    )
+                    .child(TextBlock::new().text(("location", id)).build(ctx))
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .spacing(8.0)
+                            .child(
+                                TextBox::new()
+                                    .id(ID_FOLDER)
+                                    .width(160.0)
+                                    .water_mark("Folder")
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .text("Open")
+                                    .on_click(move |states, _| {
+                                        states.get_mut::<MainViewState>(id).action(Action::Open);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - breadcrumb bar example")
+                .position((100.0, 100.0))
+                .size(420.0, 240.0)
+                .resizeable(true)
+                .child(MainView::new().margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
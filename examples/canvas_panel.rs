@@ -0,0 +1,74 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    raise: Option<&'static str>,
+    top: i32,
+}
+
+impl MainViewState {
+    // Brings the card with the given id to the front on the next update
+    fn raise(&mut self, card: &'static str) {
+        self.raise = Some(card);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(card) = self.raise.take() {
+            self.top += 1;
+            ctx.child(card).set("z_index", self.top);
+        }
+    }
+}
+
+fn card(ctx: &mut BuildContext, main_view: Entity, id: &'static str, x: f64, y: f64) -> Entity {
+    Button::new()
+        .id(id)
+        .text(id)
+        .width(140.0)
+        .height(80.0)
+        .attach(CanvasPanel::x(x))
+        .attach(CanvasPanel::y(y))
+        .z_index(0)
+        .on_click(move |states, _| {
+            states.get_mut::<MainViewState>(main_view).raise(id);
+            true
+        })
+        .build(ctx)
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let first = card(ctx, id, "first", 20.0, 20.0);
+        let second = card(ctx, id, "second", 80.0, 60.0);
+        let third = card(ctx, id, "third", 140.0, 100.0);
+
+        self.name("MainView").child(
+            CanvasPanel::new()
+                .child(first)
+                .child(second)
+                .child(third)
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - canvas panel example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
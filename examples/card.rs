@@ -0,0 +1,61 @@
+use orbtk::prelude::*;
+
+fn card(ctx: &mut BuildContext, title: &str, text: &str, elevation: f64) -> Entity {
+    Card::new()
+        .width(180.0)
+        .elevation(elevation)
+        .hover_elevation(elevation + 6.0)
+        .child(
+            Stack::new()
+                .spacing(8.0)
+                .child(TextBlock::new().text(title).font_size(16.0).build(ctx))
+                .child(TextBlock::new().text(text).build(ctx))
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            WrapPanel::new()
+                .spacing(24.0)
+                .child(card(ctx, "Flat", "elevation 0", 0.0))
+                .child(card(ctx, "Resting", "elevation 2", 2.0))
+                .child(card(ctx, "Raised", "elevation 8", 8.0))
+                .child(
+                    Card::new()
+                        .width(180.0)
+                        .elevation(4.0)
+                        .hover_elevation(4.0)
+                        .child(
+                            Stack::new()
+                                .spacing(8.0)
+                                .child(TextBlock::new().text("Static").font_size(16.0).build(ctx))
+                                .child(TextBlock::new().text("no hover effect").build(ctx))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - card example")
+                .position((100.0, 100.0))
+                .size(460.0, 300.0)
+                .child(MainView::new().margin(24.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
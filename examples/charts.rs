@@ -0,0 +1,68 @@
+use orbtk::prelude::*;
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let sales = ChartData::new()
+            .labels(vec!["Jan", "Feb", "Mar", "Apr", "May", "Jun"])
+            .series(DataSeries::new(
+                "Income",
+                vec![12.0, 18.5, 9.0, 21.0, 17.5, 24.0],
+            ))
+            .series(DataSeries::new(
+                "Costs",
+                vec![8.0, 11.0, 10.5, 12.0, 9.5, 13.0],
+            ));
+
+        let balance = ChartData::new()
+            .labels(vec!["Q1", "Q2", "Q3", "Q4"])
+            .series(DataSeries::new("Balance", vec![4.5, -2.0, 7.5, 3.0]).brush("#70ad47"));
+
+        self.name("MainView").child(
+            Grid::new()
+                .rows(Rows::create().push("*").push(8).push("*"))
+                .columns(Columns::create().push("*").push(8).push("*"))
+                .child(
+                    LineChart::new()
+                        .data(sales)
+                        .attach(Grid::row(0))
+                        .attach(Grid::column(0))
+                        .attach(Grid::column_span(3))
+                        .build(ctx),
+                )
+                .child(
+                    BarChart::new()
+                        .data(balance)
+                        .attach(Grid::row(2))
+                        .attach(Grid::column(0))
+                        .build(ctx),
+                )
+                .child(
+                    PieChart::new()
+                        .data(vec![("Rust", 60.0), ("C++", 25.0), ("Go", 15.0)])
+                        .attach(Grid::row(2))
+                        .attach(Grid::column(2))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - charts example")
+                .position((100.0, 100.0))
+                .size(720.0, 560.0)
+                .resizeable(true)
+                .child(MainView::new().margin(8.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,92 @@
+use orbtk::prelude::*;
+
+static CODE: &str = r#"use orbtk::prelude::*;
+
+/* The entry point of the application. */
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    let title = "OrbTk - code editor example";
+    let size = (720.0, 480.0);
+
+    Application::new()
+        .window(move |ctx| {
+            Window::new()
+                .title(title)
+                .position((100.0, 100.0))
+                .size(size.0, size.1)
+                .child(TextBlock::new().text("Hello 'world' with a very long line that scrolls horizontally").build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
+"#;
+
+static SCRIPT: &str = "# A custom highlighter for a small script language\ndef greet(name):\n    \
+                       return \"Hello \" + name  # TODO: translate\n\ngreet('OrbTk')\n";
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Grid::new()
+                .rows(Rows::create().push("auto").push("*").push("auto").push(160))
+                .child(
+                    TextBlock::new()
+                        .text("Rust")
+                        .style("text_block_header")
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .attach(Grid::row(0))
+                        .build(ctx),
+                )
+                .child(
+                    CodeEditor::new()
+                        .text(CODE)
+                        .syntax_highlighter(KeywordHighlighter::rust())
+                        .attach(Grid::row(1))
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("Custom highlighter")
+                        .style("text_block_header")
+                        .margin((0.0, 8.0, 0.0, 8.0))
+                        .attach(Grid::row(2))
+                        .build(ctx),
+                )
+                .child(
+                    CodeEditor::new()
+                        .text(SCRIPT)
+                        .tab_size(2)
+                        .syntax_highlighter(
+                            KeywordHighlighter::new()
+                                .keywords(&["def", "return", "if", "else"])
+                                .line_comment("#")
+                                .quotes(&['"', '\'']),
+                        )
+                        .attach(Grid::row(3))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - code editor example")
+                .position((100.0, 100.0))
+                .size(720.0, 560.0)
+                .resizeable(true)
+                .child(MainView::new().margin(8.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
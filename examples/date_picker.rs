@@ -0,0 +1,86 @@
+use orbtk::prelude::*;
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("DatePicker")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    DatePicker::new()
+                        .h_align("start")
+                        .on_changed("selected_date", |_, entity| {
+                            println!("DatePicker {:?} changed", entity);
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("TimePicker")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(8.0)
+                        .child(
+                            TimePicker::new()
+                                .selected_time((9, 30))
+                                .minute_step(15)
+                                .on_changed("selected_time", |_, entity| {
+                                    println!("TimePicker {:?} changed", entity);
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            TimePicker::new()
+                                .selected_time((18, 0))
+                                .clock_format("twelve_hour")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("Calendar")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Calendar::new()
+                        .h_align("start")
+                        .selected_date((2020, 6, 1))
+                        .on_changed("selected_date", |_, entity| {
+                            println!("Calendar {:?} changed", entity);
+                        })
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - date and time picker example")
+                .position((100.0, 100.0))
+                .size(420.0, 560.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,226 @@
+use orbtk::prelude::*;
+
+static ID_TODO: &str = "todo";
+static ID_DONE: &str = "done";
+static ID_TODO_COLUMN: &str = "todo_column";
+static ID_DONE_COLUMN: &str = "done_column";
+static ID_FILES: &str = "files";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Column {
+    Todo,
+    Done,
+}
+
+// The dragged task with its column and its index inside of the column.
+#[derive(Copy, Clone, Debug)]
+struct Task {
+    column: Column,
+    index: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Action {
+    Move(Task, Column),
+    Highlight(Option<Column>),
+    DropFiles(String),
+}
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    actions: Vec<Action>,
+    todo: Vec<String>,
+    done: Vec<String>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+
+    fn tasks(&mut self, column: Column) -> &mut Vec<String> {
+        match column {
+            Column::Todo => &mut self.todo,
+            Column::Done => &mut self.done,
+        }
+    }
+
+    // Builds the task cards of both columns.
+    fn update_columns(&self, ctx: &mut Context) {
+        for (column, id, tasks) in &[
+            (Column::Todo, ID_TODO, &self.todo),
+            (Column::Done, ID_DONE, &self.done),
+        ] {
+            let panel = ctx.entity_of_child(*id).unwrap();
+            ctx.clear_children_of(panel);
+
+            for (index, text) in tasks.iter().enumerate() {
+                let build_context = &mut ctx.build_context();
+                let card = task_card(build_context, *column, index, text);
+                build_context.append_child(panel, card);
+            }
+        }
+    }
+
+    fn highlight(&self, ctx: &mut Context, column: Option<Column>) {
+        for (id, highlighted) in &[
+            (ID_TODO_COLUMN, column == Some(Column::Todo)),
+            (ID_DONE_COLUMN, column == Some(Column::Done)),
+        ] {
+            let brush = if *highlighted { "#efd035" } else { "#5b0f22" };
+            ctx.child(*id).set("border_brush", Brush::from(brush));
+        }
+    }
+}
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.todo = vec!["Write docs", "Fix layout", "Review PR"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        self.done = vec!["Add tests".to_string()];
+        self.update_columns(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let actions: Vec<Action> = self.actions.drain(..).collect();
+
+        for action in actions {
+            match action {
+                Action::Move(task, column) => {
+                    let text = self.tasks(task.column).remove(task.index);
+                    self.tasks(column).push(text);
+                    self.highlight(ctx, None);
+                    self.update_columns(ctx);
+                }
+                Action::Highlight(column) => self.highlight(ctx, column),
+                Action::DropFiles(files) => {
+                    ctx.child(ID_FILES).set("text", String16::from(files));
+                }
+            }
+        }
+    }
+}
+
+fn task_card(ctx: &mut BuildContext, column: Column, index: usize, text: &str) -> Entity {
+    DragSource::new()
+        .data(DragData::new(Task { column, index }).text(text))
+        .child(
+            Container::new()
+                .background("#3b434a")
+                .border_radius(4.0)
+                .padding(8.0)
+                .child(TextBlock::new().text(text).build(ctx))
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+fn column(ctx: &mut BuildContext, id: Entity, column: Column, title: &str) -> Entity {
+    let (column_id, panel_id) = match column {
+        Column::Todo => (ID_TODO_COLUMN, ID_TODO),
+        Column::Done => (ID_DONE_COLUMN, ID_DONE),
+    };
+
+    Container::new()
+        .id(column_id)
+        .width(180.0)
+        .padding(8.0)
+        .border_radius(4.0)
+        .border_width(2.0)
+        .border_brush("#5b0f22")
+        .child(
+            Stack::new()
+                .spacing(8.0)
+                .child(TextBlock::new().text(title).font_size(16.0).build(ctx))
+                .child(Stack::new().id(panel_id).spacing(8.0).build(ctx))
+                .build(ctx),
+        )
+        // accepts only tasks of the other column
+        .on_drag_over(move |_, drag| {
+            drag.data
+                .get::<Task>()
+                .map_or(false, |task| task.column != column)
+        })
+        .on_drag_enter(move |states, _| {
+            states
+                .get_mut::<MainViewState>(id)
+                .action(Action::Highlight(Some(column)));
+        })
+        .on_drag_leave(move |states, _| {
+            states
+                .get_mut::<MainViewState>(id)
+                .action(Action::Highlight(None));
+        })
+        .on_drop(move |states, drag| {
+            if let Some(task) = drag.data.get::<Task>() {
+                states
+                    .get_mut::<MainViewState>(id)
+                    .action(Action::Move(*task, column));
+            }
+            true
+        })
+        .build(ctx)
+}
+
+// Shows the names of files that are dropped from a file manager. Dropping files needs the pfinder feature or the
+// web shell.
+fn file_drop_zone(ctx: &mut BuildContext, id: Entity) -> Entity {
+    Container::new()
+        .padding(8.0)
+        .border_radius(4.0)
+        .border_width(2.0)
+        .border_brush("#5b0f22")
+        .child(
+            TextBlock::new()
+                .id(ID_FILES)
+                .text("Drop files here")
+                .build(ctx),
+        )
+        .on_drag_over(|_, drag| drag.data.get_files().is_some())
+        .on_drop(move |states, drag| {
+            states
+                .get_mut::<MainViewState>(id)
+                .action(Action::DropFiles(drag.data.get_text().to_string()));
+            true
+        })
+        .build(ctx)
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(16.0)
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(column(ctx, id, Column::Todo, "To do"))
+                        .child(column(ctx, id, Column::Done, "Done"))
+                        .build(ctx),
+                )
+                .child(file_drop_zone(ctx, id))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - drag and drop example")
+                .position((100.0, 100.0))
+                .size(420.0, 400.0)
+                .child(MainView::new().margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
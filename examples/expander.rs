@@ -0,0 +1,64 @@
+use orbtk::prelude::*;
+
+widget!(MainView {
+    details_expanded: bool
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").details_expanded(true).child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    Expander::new()
+                        .header("General")
+                        .content(
+                            Stack::new()
+                                .margin(8.0)
+                                .spacing(4.0)
+                                .child(TextBlock::new().text("Name: OrbTk").build(ctx))
+                                .child(TextBlock::new().text("License: MIT").build(ctx))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    Expander::new()
+                        .header("Details")
+                        // shares the expanded state with the check box below
+                        .expanded(("details_expanded", id))
+                        .content(
+                            TextBlock::new()
+                                .margin(8.0)
+                                .text("The content is expanded and collapsed with an animation.")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    CheckBox::new()
+                        .text("Show details")
+                        .selected(("details_expanded", id))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - expander example")
+                .position((100.0, 100.0))
+                .size(420.0, 400.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,95 @@
+use orbtk::prelude::*;
+
+#[derive(Copy, Clone)]
+enum Action {
+    Open,
+    Save,
+}
+
+#[derive(AsAny, Default)]
+struct MainViewState {
+    action: Option<Action>,
+    dialog: Option<FileDialogHandle>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            self.dialog = Some(match action {
+                Action::Open => FileDialog::open(ctx, &["*.rs", "*.toml"]),
+                Action::Save => FileDialog::save(ctx, &["*.txt"]),
+            });
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let result = if let Some(dialog) = &self.dialog {
+            dialog.result()
+        } else {
+            return;
+        };
+
+        let text = match result {
+            FileDialogResult::Pending => return,
+            FileDialogResult::Canceled => "Canceled".to_string(),
+            FileDialogResult::Path(path) => path.to_string_lossy().to_string(),
+        };
+
+        self.dialog = None;
+        text_block(ctx.child("path")).set_text(text);
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .margin(16.0)
+                .spacing(8.0)
+                .child(
+                    Button::new()
+                        .text("Open file")
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainViewState>(id).action(Action::Open);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    Button::new()
+                        .text("Save file")
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainViewState>(id).action(Action::Save);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::new().id("path").text("No file").build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - file dialog example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
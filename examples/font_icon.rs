@@ -0,0 +1,78 @@
+use orbtk::prelude::*;
+
+// Additional names for icons of the Material Icons font, registered at runtime.
+static CUSTOM_CODEPOINTS: &str = "
+# name code point
+store e565
+open-folder e296
+";
+
+fn icon(ctx: &mut BuildContext, name: &str, size: f64, brush: &str) -> Entity {
+    FontIconBlock::new()
+        .icon_name(name)
+        .icon_size(size)
+        .icon_brush(brush)
+        .v_align("end")
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Icons by name")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(icon(ctx, "home", 16.0, "#dfebf5"))
+                        .child(icon(ctx, "favorite", 24.0, "#e74c3c"))
+                        .child(icon(ctx, "star", 32.0, "#f8de4c"))
+                        .child(icon(ctx, "Arrow-Drop-Down", 48.0, "#5c9ded"))
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("Names loaded at runtime")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(icon(ctx, "store", 32.0, "#dfebf5"))
+                        .child(icon(ctx, "open-folder", 32.0, "#dfebf5"))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    register_icon_font("MaterialIcons-Regular", CUSTOM_CODEPOINTS);
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - font icon example")
+                .position((100.0, 100.0))
+                .size(420.0, 260.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,67 @@
+use orbtk::prelude::*;
+
+widget!(MainView { val: f64 });
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let zones = vec![
+            GaugeZone::new(60.0, 80.0, "#ffc000"),
+            GaugeZone::new(80.0, 100.0, "#e05f8a"),
+        ];
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(16.0)
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(
+                            Gauge::new()
+                                .val(id)
+                                .max(100.0)
+                                .zones(zones.clone())
+                                .build(ctx),
+                        )
+                        .child(
+                            Gauge::new()
+                                .val(id)
+                                .max(100.0)
+                                .needle(true)
+                                .start_angle(180.0)
+                                .sweep_angle(180.0)
+                                .zones(zones)
+                                .build(ctx),
+                        )
+                        .child(
+                            Gauge::new()
+                                .val(id)
+                                .max(100.0)
+                                .start_angle(-90.0)
+                                .sweep_angle(360.0)
+                                .thickness(6.0)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(Slider::new().min(0.0).max(100.0).val(id).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - gauge example")
+                .position((100.0, 100.0))
+                .size(480.0, 240.0)
+                .child(MainView::new().val(42.0).margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,100 @@
+use orbtk::prelude::*;
+
+static ID_GESTURE: &str = "gesture";
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    gesture: Option<String>,
+}
+
+impl MainViewState {
+    fn gesture(&mut self, gesture: impl Into<String>) {
+        self.gesture = Some(gesture.into());
+    }
+}
+
+impl State for MainViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // swipes must be a bit faster than the default
+        let mut settings = *ctx.gesture_recognizer().settings();
+        settings.swipe_velocity = 0.5;
+        ctx.gesture_recognizer().set_settings(settings);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(gesture) = self.gesture.take() {
+            ctx.child(ID_GESTURE).set("text", String16::from(gesture));
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Container::new()
+                .background("#3b434a")
+                .border_radius(4.0)
+                .child(
+                    TextBlock::new()
+                        .id(ID_GESTURE)
+                        .h_align("center")
+                        .v_align("center")
+                        .text("Double click, long press, swipe, pinch or rotate")
+                        .build(ctx),
+                )
+                .on_double_click(move |states, position| {
+                    states.get_mut::<MainViewState>(id).gesture(format!(
+                        "Double click at {}, {}",
+                        position.x(),
+                        position.y()
+                    ));
+                    true
+                })
+                .on_long_press(move |states, position| {
+                    states.get_mut::<MainViewState>(id).gesture(format!(
+                        "Long press at {}, {}",
+                        position.x(),
+                        position.y()
+                    ));
+                    true
+                })
+                .on_swipe(move |states, direction, velocity| {
+                    states
+                        .get_mut::<MainViewState>(id)
+                        .gesture(format!("Swipe {:?} with {:.1} px/ms", direction, velocity));
+                    true
+                })
+                .on_pinch(move |states, _, scale| {
+                    states
+                        .get_mut::<MainViewState>(id)
+                        .gesture(format!("Pinch with scale {:.2}", scale));
+                    true
+                })
+                .on_rotate(move |states, _, angle| {
+                    states
+                        .get_mut::<MainViewState>(id)
+                        .gesture(format!("Rotate by {:.1} degrees", angle.to_degrees()));
+                    true
+                })
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - gestures example")
+                .position((100.0, 100.0))
+                .size(420.0, 420.0)
+                .child(MainView::new().margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -22,7 +22,7 @@ impl Template for MainView {
         self.name("MainView").child(
             Grid::new()
                 .columns(Columns::create().push("*").push("auto").push(50))
-                .rows(Rows::create().push("*").push("*"))
+                .rows(Rows::create().push("*").push("*").push("auto"))
                 .child(
                     Grid::new()
                         .style("lynch")
@@ -84,6 +84,17 @@ impl Template for MainView {
                         )
                         .build(ctx),
                 )
+                .child(
+                    TextBlock::new()
+                        .text("(0,2) - Auto row, ColumnSpan 3")
+                        .style("body")
+                        .margin(10)
+                        .h_align("center")
+                        .attach(Grid::column(0))
+                        .attach(Grid::row(2))
+                        .attach(Grid::column_span(3))
+                        .build(ctx),
+                )
                 .build(ctx),
         )
     }
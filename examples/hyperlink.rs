@@ -0,0 +1,94 @@
+use orbtk::prelude::*;
+
+/// Shows the target of the last in-app navigation.
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    page: Option<String>,
+    page_text: Entity,
+}
+
+impl MainViewState {
+    fn navigate(&mut self, url: String) {
+        self.page = Some(url);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(page) = self.page.take() {
+            ctx.get_widget(self.page_text)
+                .set("text", String16::from(format!("Current page: {}", page)));
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let page_text = TextBlock::new().text("Current page: home").build(ctx);
+        self.state_mut().page_text = page_text;
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Open in browser")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Hyperlink::new()
+                        .text("OrbTk on GitHub")
+                        .url("https://github.com/redox-os/orbtk")
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .text("In-app navigation")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Hyperlink::new()
+                        .text("Settings")
+                        .url("app://settings")
+                        .open_in_browser(false)
+                        .on_navigate(move |states, _, url| {
+                            states.get_mut::<MainViewState>(id).navigate(url);
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    Hyperlink::new()
+                        .text("About")
+                        .url("app://about")
+                        .open_in_browser(false)
+                        .on_navigate(move |states, _, url| {
+                            states.get_mut::<MainViewState>(id).navigate(url);
+                        })
+                        .build(ctx),
+                )
+                .child(page_text)
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - hyperlink example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -1,5 +1,21 @@
 use orbtk::prelude::*;
 
+fn image_column(ctx: &mut BuildContext, column: usize, stretch: &str) -> Entity {
+    Grid::new()
+        .attach(Grid::column(column))
+        .margin(4.0)
+        .rows(Rows::create().push("auto").push("*"))
+        .child(TextBlock::new().text(stretch).build(ctx))
+        .child(
+            ImageWidget::new()
+                .attach(Grid::row(1))
+                .image("res/orbtk-space.png")
+                .stretch(stretch)
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
 fn main() {
     // use this only if you want to run it as web application.
     orbtk::initialize();
@@ -10,7 +26,16 @@ fn main() {
                 .title("OrbTk - image example")
                 .position((100.0, 100.0))
                 .size(800.0, 420.0)
-                .child(ImageWidget::new().image("res/orbtk-space.png").build(ctx))
+                .resizeable(true)
+                .child(
+                    Grid::new()
+                        .columns(Columns::create().push("*").push("*").push("*").push("*"))
+                        .child(image_column(ctx, 0, "none"))
+                        .child(image_column(ctx, 1, "fill"))
+                        .child(image_column(ctx, 2, "uniform"))
+                        .child(image_column(ctx, 3, "uniform_to_fill"))
+                        .build(ctx),
+                )
                 .build(ctx)
         })
         .run();
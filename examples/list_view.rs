@@ -0,0 +1,122 @@
+use orbtk::prelude::*;
+
+const ITEM_COUNT: usize = 100_000;
+
+#[derive(Copy, Clone)]
+enum Action {
+    Add,
+    Remove,
+    Move(usize, usize),
+}
+
+#[derive(AsAny, Default)]
+struct MainViewState {
+    action: Option<Action>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            let mut widget = ctx.widget();
+            let items = widget.get_mut::<ItemsSource<String>>("items");
+
+            match action {
+                Action::Add => {
+                    let text = format!("New item {}", items.version());
+                    items.insert(0, text);
+                }
+                Action::Remove => {
+                    if !items.is_empty() {
+                        items.remove(0);
+                    }
+                }
+                Action::Move(from, to) => {
+                    let item = items.remove(from);
+                    items.insert(to, item);
+                }
+            }
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    items: ItemsSource<String>
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items: Vec<String> = (0..ITEM_COUNT).map(|i| format!("Item {}", i)).collect();
+
+        self.name("MainView").items(items).child(
+            Grid::new()
+                .margin(16.0)
+                .rows(Rows::create().push("auto").push(8).push("*"))
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(8.0)
+                        .child(
+                            Button::new()
+                                .text("Add")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<MainViewState>(id).action(Action::Add);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::new()
+                                .text("Remove")
+                                .on_click(move |states, _| {
+                                    states.get_mut::<MainViewState>(id).action(Action::Remove);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(
+                    ListView::new()
+                        .attach(Grid::row(2))
+                        .selection_mode("extended")
+                        .reorderable(true)
+                        .items_source::<String>(id, "items")
+                        .items_builder(move |bc, index| {
+                            let text = bc.get_widget(id).get::<ItemsSource<String>>("items")[index]
+                                .clone();
+
+                            TextBlock::new().v_align("center").text(text).build(bc)
+                        })
+                        .on_item_moved(move |states, _, from, to| {
+                            states
+                                .get_mut::<MainViewState>(id)
+                                .action(Action::Move(from, to));
+                        })
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - list view example")
+                .position((100.0, 100.0))
+                .size(420.0, 600.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
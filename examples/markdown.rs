@@ -0,0 +1,110 @@
+use orbtk::prelude::*;
+
+static HELP: &str = "# OrbTk help
+
+The `MarkdownView` shows **Markdown** documents like help screens and changelogs inside of the application.
+
+## Features
+
+* headings, paragraphs and block quotes
+* ordered and unordered lists
+  * nested lists
+* fenced code blocks
+* images and horizontal rules
+* links like [settings](app://settings) or [about](app://about)
+
+![OrbTk logo](orbtk_logo.png)
+
+## Usage
+
+1. Create a `MarkdownView`.
+2. Set the `markdown` property.
+3. Put it into a `ScrollViewer`.
+
+```
+MarkdownView::new()
+    .markdown(\"# Title\")
+    .build(ctx)
+```
+
+> Links navigate inside of the application because `open_in_browser` is `false`.
+
+---
+
+Read the [documentation](https://docs.rs/orbtk) for more.";
+
+/// Shows the target of the last in-app navigation.
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    page: Option<String>,
+    page_text: Entity,
+}
+
+impl MainViewState {
+    fn navigate(&mut self, url: String) {
+        self.page = Some(url);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(page) = self.page.take() {
+            ctx.get_widget(self.page_text)
+                .set("text", String16::from(format!("Current page: {}", page)));
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let page_text = TextBlock::new()
+            .text("Current page: help")
+            .margin((8.0, 8.0, 8.0, 8.0))
+            .attach(Grid::row(0))
+            .build(ctx);
+        self.state_mut().page_text = page_text;
+
+        self.name("MainView").child(
+            Grid::new()
+                .rows(Rows::create().push("auto").push("*"))
+                .child(page_text)
+                .child(
+                    ScrollViewer::new()
+                        .mode(("disabled", "auto"))
+                        .attach(Grid::row(1))
+                        .child(
+                            MarkdownView::new()
+                                .markdown(HELP)
+                                .base_path("res")
+                                .open_in_browser(false)
+                                .margin(8.0)
+                                .on_navigate(move |states, _, url| {
+                                    states.get_mut::<MainViewState>(id).navigate(url);
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - markdown example")
+                .position((100.0, 100.0))
+                .size(520.0, 600.0)
+                .resizeable(true)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
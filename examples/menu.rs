@@ -0,0 +1,113 @@
+use orbtk::prelude::*;
+
+#[derive(AsAny, Default)]
+struct MainViewState;
+
+impl State for MainViewState {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let shortcuts = registry.get_mut::<Shortcuts>("shortcuts");
+
+        // the shortcuts send a ShortcutEvent with their name to the main view
+        for (name, chord) in &[("new", "Ctrl+N"), ("open", "Ctrl+O"), ("quit", "Ctrl+Q")] {
+            if let Err(error) = shortcuts.register_event(*name, *chord, ctx.entity) {
+                println!("{}", error);
+            }
+        }
+    }
+}
+
+widget!(MainView<MainViewState>: ShortcutHandler);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .child(
+                MenuBar::new()
+                    .v_align("start")
+                    .child(
+                        Menu::new()
+                            .text("_File")
+                            .item(
+                                MenuItem::new()
+                                    .id("new")
+                                    .text("_New")
+                                    .accelerator("Ctrl+N")
+                                    .build(ctx),
+                            )
+                            .item(
+                                MenuItem::new()
+                                    .id("open")
+                                    .text("_Open")
+                                    .accelerator("Ctrl+O")
+                                    .build(ctx),
+                            )
+                            .item(
+                                Menu::new()
+                                    .text("Open _recent")
+                                    .item(MenuItem::new().id("recent_1").text("main.rs").build(ctx))
+                                    .item(MenuItem::new().id("recent_2").text("lib.rs").build(ctx))
+                                    .build(ctx),
+                            )
+                            .item(MenuSeparator::new().build(ctx))
+                            .item(
+                                MenuItem::new()
+                                    .id("quit")
+                                    .text("_Quit")
+                                    .accelerator("Ctrl+Q")
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        Menu::new()
+                            .text("_View")
+                            .item(
+                                MenuItem::new()
+                                    .id("status_bar")
+                                    .text("_Status bar")
+                                    .checkable(true)
+                                    .checked(true)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .on_activated(|_, _, id| println!("{} activated", id))
+                    .build(ctx),
+            )
+            .child(
+                TextBlock::new()
+                    .text("Right click to open the context menu")
+                    .h_align("center")
+                    .v_align("center")
+                    .context_menu(
+                        ContextMenu::new()
+                            .item(MenuItem::new().id("cut").text("Cut").build(ctx))
+                            .item(MenuItem::new().id("copy").text("Copy").build(ctx))
+                            .item(MenuItem::new().id("paste").text("Paste").build(ctx))
+                            .on_activated(|_, _, id| println!("{} activated", id))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_shortcut(|_, name| {
+                println!("{} shortcut", name);
+                true
+            })
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - menu example")
+                .position((100.0, 100.0))
+                .size(420.0, 730.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -1,34 +1,54 @@
 /// mutli window will not work properly on web now. But it will be fixed.
 use orbtk::prelude::*;
 
+#[derive(Copy, Clone)]
+enum Action {
+    ShowWindow,
+    ClosePalette,
+}
+
 #[derive(AsAny, Default)]
 struct MainState {
-    show_window: bool,
+    action: Option<Action>,
+    message: Option<String>,
 }
 
 impl MainState {
-    fn show_window(&mut self) {
-        self.show_window = true;
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+
+    // receives the messages of the palette window
+    fn message(&mut self, message: String) {
+        self.message = Some(message);
     }
 }
 
 impl State for MainState {
-    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if self.show_window {
-            button(ctx.child("button")).set_enabled(false);
-            ctx.show_window(|ctx| {
-                Window::new()
-                    .title("Dialog")
-                    .position((120.0, 120.0))
-                    .size(100.0, 75.0)
-                    .child(
-                        Stack::new()
-                            .child(TextBlock::new().text("New window").margin(4.0).build(ctx))
-                            .build(ctx),
-                    )
-                    .build(ctx)
-            });
-            self.show_window = false;
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if let Some(message) = self.message.take() {
+            text_block(ctx.child("message")).set_text(message);
+        }
+
+        match self.action.take() {
+            Some(Action::ShowWindow) => {
+                // the input of this window is blocked until the dialog is closed
+                ctx.show_modal_window(|ctx| {
+                    Window::new()
+                        .title("Dialog")
+                        .size(200.0, 75.0)
+                        .child(
+                            Stack::new()
+                                .child(TextBlock::new().text("Modal dialog").margin(4.0).build(ctx))
+                                .build(ctx),
+                        )
+                        .build(ctx)
+                });
+            }
+            Some(Action::ClosePalette) => {
+                registry.get::<Windows>("windows").close("palette");
+            }
+            None => {}
         }
     }
 }
@@ -42,13 +62,29 @@ impl Template for MainView {
                 .child(TextBlock::new().text("Window 1").margin(4.0).build(ctx))
                 .child(
                     Button::new()
-                        .id("button")
                         .on_click(move |states, _| {
-                            states.get_mut::<MainState>(id).show_window();
+                            states.get_mut::<MainState>(id).action(Action::ShowWindow);
                             true
                         })
                         .margin(4.0)
-                        .text("Show window")
+                        .text("Show dialog")
+                        .build(ctx),
+                )
+                .child(
+                    Button::new()
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainState>(id).action(Action::ClosePalette);
+                            true
+                        })
+                        .margin(4.0)
+                        .text("Close palette")
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .id("message")
+                        .text("No color picked")
+                        .margin(4.0)
                         .build(ctx),
                 )
                 .build(ctx),
@@ -56,30 +92,77 @@ impl Template for MainView {
     }
 }
 
+#[derive(AsAny, Default)]
+struct PaletteState {
+    color: Option<&'static str>,
+}
+
+impl State for PaletteState {
+    fn update(&mut self, registry: &mut Registry, _: &mut Context) {
+        if let Some(color) = self.color.take() {
+            registry
+                .get::<Windows>("windows")
+                .send_message("main", color.to_string());
+        }
+    }
+}
+
+widget!(PaletteView<PaletteState>);
+
+impl Template for PaletteView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut stack =
+            Stack::new().child(TextBlock::new().text("Window 2").margin(4.0).build(ctx));
+
+        for color in &["Red", "Green", "Blue"] {
+            stack = stack.child(
+                Button::new()
+                    .margin(4.0)
+                    .text(*color)
+                    .on_click(move |states, _| {
+                        states.get_mut::<PaletteState>(id).color = Some(*color);
+                        true
+                    })
+                    .build(ctx),
+            );
+        }
+
+        self.child(stack.build(ctx))
+    }
+}
+
 fn main() {
     // use this only if you want to run it as web application.
     orbtk::initialize();
 
     Application::new()
         .window(|ctx| {
+            let main_view = MainView::new().build(ctx);
+
             Window::new()
+                .id("main")
                 .title("OrbTk - multi window example window 1")
                 .position((100.0, 100.0))
                 .size(420.0, 730.0)
-                .child(MainView::new().build(ctx))
+                .on_message(move |states, event| {
+                    if let Some(color) = event.downcast_ref::<String>() {
+                        states
+                            .get_mut::<MainState>(main_view)
+                            .message(format!("Picked color: {}", color));
+                    }
+                    true
+                })
+                .child(main_view)
                 .build(ctx)
         })
-        .window(|ctx| {
+        // the palette window has its own theme
+        .window_with_theme(light_theme(), |ctx| {
             Window::new()
+                .id("palette")
                 .title("OrbTk - multi window example window 2")
                 .position((600.0, 100.0))
                 .size(420.0, 730.0)
-                .child(
-                    Stack::new()
-                        .child(TextBlock::new().text("Window 2").margin(4.0).build(ctx))
-                        .child(Button::new().margin(4.0).text("Click me").build(ctx))
-                        .build(ctx),
-                )
+                .child(PaletteView::new().build(ctx))
                 .build(ctx)
         })
         .run();
@@ -0,0 +1,117 @@
+use orbtk::prelude::*;
+
+#[derive(Copy, Clone)]
+enum Action {
+    Show(NotificationKind),
+    Restore,
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<Action>,
+    deleted: usize,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let action = match self.action.take() {
+            Some(action) => action,
+            None => return,
+        };
+
+        let id = ctx.entity;
+        let notifications = registry.get_mut::<NotificationService>("notifications");
+
+        match action {
+            Action::Show(NotificationKind::Info) => {
+                notifications.show(Toast::info("Document saved"));
+            }
+            Action::Show(NotificationKind::Success) => {
+                notifications.show(Toast::success("Upload finished"));
+            }
+            Action::Show(NotificationKind::Warning) => {
+                self.deleted += 1;
+                notifications.show(Toast::warning("File moved to trash").action(
+                    "Undo",
+                    move |states| {
+                        states.get_mut::<MainViewState>(id).action(Action::Restore);
+                    },
+                ));
+            }
+            Action::Show(NotificationKind::Error) => {
+                notifications.show(Toast::error("Connection lost").timeout(0.0));
+            }
+            Action::Restore => {
+                self.deleted = self.deleted.saturating_sub(1);
+                notifications.show(Toast::info("File restored"));
+            }
+        }
+
+        main_view(ctx.widget())
+            .set_text(String16::from(format!("Files in trash: {}", self.deleted)));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    text: String16
+});
+
+fn show_button(id: Entity, ctx: &mut BuildContext, text: &str, kind: NotificationKind) -> Entity {
+    Button::new()
+        .text(text)
+        .on_click(move |states, _| {
+            states
+                .get_mut::<MainViewState>(id)
+                .action(Action::Show(kind));
+            true
+        })
+        .build(ctx)
+}
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").text("Files in trash: 0").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(show_button(id, ctx, "Info", NotificationKind::Info))
+                .child(show_button(id, ctx, "Success", NotificationKind::Success))
+                .child(show_button(
+                    id,
+                    ctx,
+                    "Warning with action",
+                    NotificationKind::Warning,
+                ))
+                .child(show_button(
+                    id,
+                    ctx,
+                    "Error without timeout",
+                    NotificationKind::Error,
+                ))
+                .child(TextBlock::new().text(id).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - notifications example")
+                .position((100.0, 100.0))
+                .size(560.0, 420.0)
+                .resizeable(true)
+                .child(MainView::new().margin(16.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -57,6 +57,12 @@ impl Template for MainView {
                         .width(512.0)
                         .build(ctx),
                 )
+                .child(
+                    ProgressBar::new()
+                        .indeterminate(true)
+                        .width(512.0)
+                        .build(ctx),
+                )
                 .child(
                     Stack::new()
                         .h_align("center")
@@ -0,0 +1,70 @@
+use orbtk::prelude::*;
+
+fn radio_button(ctx: &mut BuildContext, text: &str, group: &str, selected: bool) -> Entity {
+    RadioButton::new()
+        .text(text)
+        .group(group)
+        .selected(selected)
+        .on_changed("selected", |_, entity| {
+            println!("RadioButton {:?} changed", entity);
+        })
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let small = radio_button(ctx, "Small", "size", true);
+        let medium = radio_button(ctx, "Medium", "size", false);
+        let large = radio_button(ctx, "Large", "size", false);
+        let light = radio_button(ctx, "Light", "", true);
+        let dark = radio_button(ctx, "Dark", "", false);
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Size (group \"size\")")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(small)
+                .child(medium)
+                .child(large)
+                .child(
+                    TextBlock::new()
+                        .text("Theme (grouped by parent)")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(8.0)
+                        .child(light)
+                        .child(dark)
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - radio button example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,100 @@
+use orbtk::prelude::*;
+
+static MARKDOWN: &str =
+    "RichText could also be created from a subset of **Markdown**. It supports **bold** text, \
+`code` and [links](https://github.com/redox-os/orbtk).\nLine breaks are kept.";
+
+/// Shows the target of the last in-app navigation.
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    page: Option<String>,
+    page_text: Entity,
+}
+
+impl MainViewState {
+    fn navigate(&mut self, url: String) {
+        self.page = Some(url);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(page) = self.page.take() {
+            ctx.get_widget(self.page_text)
+                .set("text", String16::from(format!("Current page: {}", page)));
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let page_text = TextBlock::new().text("Current page: home").build(ctx);
+        self.state_mut().page_text = page_text;
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Spans")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    RichTextBlock::new()
+                        .rich_text(
+                            RichText::new()
+                                .span("Spans could have their own ")
+                                .span(TextSpan::new("colors").foreground("#f8de4c"))
+                                .span(", ")
+                                .span(TextSpan::new("weights").bold())
+                                .span(" and ")
+                                .span(TextSpan::new("font sizes").font_size(20.0))
+                                .span(". Links like ")
+                                .span(TextSpan::new("settings").bold().link("app://settings"))
+                                .span(" or ")
+                                .span(TextSpan::new("about").link("app://about"))
+                                .span(" navigate inside of the application. ")
+                                .span("Select the text with the mouse and copy it with Ctrl+C."),
+                        )
+                        .open_in_browser(false)
+                        .on_navigate(move |states, _, url| {
+                            states.get_mut::<MainViewState>(id).navigate(url);
+                        })
+                        .build(ctx),
+                )
+                .child(page_text)
+                .child(
+                    TextBlock::new()
+                        .text("Markdown")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    RichTextBlock::new()
+                        .rich_text(RichText::from_markdown(MARKDOWN))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - rich text example")
+                .position((100.0, 100.0))
+                .size(460.0, 360.0)
+                .resizeable(true)
+                .child(MainView::new().margin(8.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,71 @@
+use orbtk::prelude::*;
+
+fn pane(ctx: &mut BuildContext, text: &str, column: usize, row: usize) -> Entity {
+    Container::new()
+        .attach(Grid::column(column))
+        .attach(Grid::row(row))
+        .child(
+            TextBlock::new()
+                .text(text)
+                .h_align("center")
+                .v_align("center")
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let side_pane = pane(ctx, "Side", 0, 0);
+        let top_pane = pane(ctx, "Top", 0, 0);
+        let bottom_pane = pane(ctx, "Bottom", 0, 2);
+
+        self.name("MainView").child(
+            Grid::new()
+                .columns(Columns::create().push(160).push("auto").push("*"))
+                .child(side_pane)
+                .child(
+                    Splitter::new()
+                        .attach(Grid::column(1))
+                        .on_changed("sizes", |_, entity| {
+                            println!("Splitter {:?} changed", entity);
+                        })
+                        .build(ctx),
+                )
+                .child(
+                    Grid::new()
+                        .attach(Grid::column(2))
+                        .rows(Rows::create().push("*").push("auto").push(120))
+                        .child(top_pane)
+                        .child(
+                            Splitter::new()
+                                .orientation("vertical")
+                                .attach(Grid::row(1))
+                                .build(ctx),
+                        )
+                        .child(bottom_pane)
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - splitter example")
+                .position((100.0, 100.0))
+                .size(600.0, 400.0)
+                .resizeable(true)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
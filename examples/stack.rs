@@ -89,6 +89,27 @@ impl Template for MainView {
                         )
                         .build(ctx),
                 )
+                .child(
+                    TextBlock::new()
+                        .margin((0.0, 8.0, 0.0, 8.0))
+                        .text("Stack with separators")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .spacing(4.0)
+                        .separator_builder(|ctx, _| {
+                            Container::new()
+                                .height(1.0)
+                                .background("#8a8a8a")
+                                .build(ctx)
+                        })
+                        .child(TextBlock::new().text("first").build(ctx))
+                        .child(TextBlock::new().text("second").build(ctx))
+                        .child(TextBlock::new().text("third").build(ctx))
+                        .build(ctx),
+                )
                 .build(ctx),
         )
     }
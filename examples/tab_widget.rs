@@ -22,6 +22,7 @@ fn main() {
                             "Tab header 3",
                             TextBlock::new().text("Tab content 3").build(ctx),
                         )
+                        .on_tab_closed(|_, _, index| println!("Tab {} closed", index))
                         .build(ctx),
                 )
                 .build(ctx)
@@ -0,0 +1,65 @@
+use orbtk::prelude::*;
+
+const PLANETS: [(&str, f64, u32); 8] = [
+    ("Mercury", 0.39, 0),
+    ("Venus", 0.72, 0),
+    ("Earth", 1.0, 1),
+    ("Mars", 1.52, 2),
+    ("Jupiter", 5.2, 79),
+    ("Saturn", 9.54, 82),
+    ("Uranus", 19.2, 27),
+    ("Neptune", 30.06, 14),
+];
+
+fn cell(ctx: &mut BuildContext, text: String) -> Entity {
+    TextBlock::new()
+        .margin((8, 0, 8, 0))
+        .v_align("center")
+        .text(text)
+        .build(ctx)
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - table view example")
+                .position((100.0, 100.0))
+                .size(420.0, 400.0)
+                .child(
+                    TableView::new()
+                        .margin(8.0)
+                        .count(PLANETS.len())
+                        .selection_mode("extended")
+                        .column(
+                            TableColumn::new("Planet")
+                                .width(140.0)
+                                .cell_builder(|ctx, row| cell(ctx, PLANETS[row].0.to_string()))
+                                .compare(|a, b| PLANETS[a].0.cmp(PLANETS[b].0)),
+                        )
+                        .column(
+                            TableColumn::new("Distance (AU)")
+                                .width(120.0)
+                                .cell_builder(|ctx, row| cell(ctx, PLANETS[row].1.to_string()))
+                                .compare(|a, b| {
+                                    PLANETS[a]
+                                        .1
+                                        .partial_cmp(&PLANETS[b].1)
+                                        .unwrap_or(std::cmp::Ordering::Equal)
+                                }),
+                        )
+                        .column(
+                            TableColumn::new("Moons")
+                                .width(100.0)
+                                .cell_builder(|ctx, row| cell(ctx, PLANETS[row].2.to_string()))
+                                .compare(|a, b| PLANETS[a].2.cmp(&PLANETS[b].2)),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
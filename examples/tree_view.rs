@@ -0,0 +1,47 @@
+use orbtk::prelude::*;
+
+// Returns the number of children of the node with the given path. The tree has three levels.
+fn children_count(path: &[usize]) -> usize {
+    match path.len() {
+        0 => 3,
+        1 => 2,
+        2 if path[1] == 0 => 4,
+        _ => 0,
+    }
+}
+
+fn node_name(path: &[usize]) -> String {
+    let path: Vec<String> = path.iter().map(|i| (i + 1).to_string()).collect();
+    format!("Node {}", path.join("."))
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - tree view example")
+                .position((100.0, 100.0))
+                .size(420.0, 730.0)
+                .child(
+                    TreeView::new()
+                        .margin(8.0)
+                        .children_count(children_count)
+                        .items_builder(|ctx, path| {
+                            TextBlock::new()
+                                .margin((0.0, 0.0, 0.0, 2.0))
+                                .v_align("center")
+                                .text(node_name(path))
+                                .build(ctx)
+                        })
+                        .on_selection_changed(|_, _, change| {
+                            println!("Selected {:?}", change.selected)
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
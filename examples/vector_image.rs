@@ -0,0 +1,65 @@
+use orbtk::prelude::*;
+
+static ICON: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" width="24" height="24">
+    <circle cx="12" cy="12" r="11" fill="#5b0f22" stroke="#dfebf5" stroke-width="1.5"/>
+    <g transform="rotate(-15 12 12)" fill="#f8de4c">
+        <path d="M12 4 L14.4 9.2 L20 9.6 L15.7 13.2 L17 18.8 L12 15.8 L7 18.8 L8.3 13.2 L4 9.6 L9.6 9.2 Z"/>
+    </g>
+    <path d="M4 20 Q 12 24 20 20" fill="none" stroke="#dfebf5" stroke-opacity="0.6"/>
+</svg>"##;
+
+fn icon(ctx: &mut BuildContext, size: f64) -> Entity {
+    ImageWidget::new()
+        .vector_image(ICON)
+        .width(size)
+        .height(size)
+        .stretch("uniform")
+        .v_align("end")
+        .build(ctx)
+}
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("One SVG icon in different sizes")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(16.0)
+                        .child(icon(ctx, 16.0))
+                        .child(icon(ctx, 24.0))
+                        .child(icon(ctx, 48.0))
+                        .child(icon(ctx, 96.0))
+                        .child(icon(ctx, 192.0))
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - vector image example")
+                .position((100.0, 100.0))
+                .size(460.0, 260.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -197,6 +197,7 @@ impl Template for MainView {
                             .child(
                                 Button::new()
                                     .text("Button")
+                                    .tooltip("Shows a tool tip after a short delay")
                                     .margin((0, 8, 0, 0))
                                     .icon(material_icons_font::MD_CHECK)
                                     .attach(Grid::column(0))
@@ -249,6 +250,13 @@ impl Template for MainView {
                                     .margin((0, 8, 0, 0))
                                     .build(ctx),
                             )
+                            .child(
+                                RangeSlider::new()
+                                    .lower_val(20.0)
+                                    .upper_val(80.0)
+                                    .margin((0, 8, 0, 0))
+                                    .build(ctx),
+                            )
                             .build(ctx),
                     )
                     .child(
@@ -0,0 +1,32 @@
+use orbtk::prelude::*;
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - window title bar example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .borderless(true)
+                .child(
+                    Grid::new()
+                        .rows(Rows::create().push("auto").push("*"))
+                        .child(WindowTitleBar::new().build(ctx))
+                        .child(
+                            TextBlock::new()
+                                .attach(Grid::row(1))
+                                .text("Drag the title bar to move the window")
+                                .h_align("center")
+                                .v_align("center")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
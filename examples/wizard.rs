@@ -0,0 +1,105 @@
+use orbtk::prelude::*;
+
+static ID_NAME: &str = "name";
+static ID_EMAIL: &str = "email";
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    name: String,
+    email: String,
+    finished: bool,
+}
+
+impl MainViewState {
+    fn finish(&mut self) {
+        self.finished = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.name = ctx.child(ID_NAME).get::<String16>("text").to_string();
+        self.email = ctx.child(ID_EMAIL).get::<String16>("text").to_string();
+
+        if self.finished {
+            self.finished = false;
+            let summary = format!("Account of {} <{}> created", self.name, self.email);
+            main_view(ctx.widget()).set_summary(String16::from(summary));
+        }
+    }
+}
+
+widget!(MainView<MainViewState> {
+    summary: String16
+});
+
+fn page(ctx: &mut BuildContext, text: &str, input: Entity) -> Entity {
+    Stack::new()
+        .spacing(8.0)
+        .child(TextBlock::new().text(text).build(ctx))
+        .child(input)
+        .build(ctx)
+}
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let name = TextBox::new()
+            .id(ID_NAME)
+            .water_mark("Name")
+            .on_changed("text", move |states, _| {
+                states.get_mut::<MainViewState>(id);
+            })
+            .build(ctx);
+        let email = TextBox::new()
+            .id(ID_EMAIL)
+            .water_mark("E-mail")
+            .on_changed("text", move |states, _| {
+                states.get_mut::<MainViewState>(id);
+            })
+            .build(ctx);
+
+        let name_page = page(ctx, "Enter your name, it must not be empty.", name);
+        let email_page = page(ctx, "Enter your e-mail, it must contain an @.", email);
+        let summary_page = TextBlock::new()
+            .text("Press finish to create the account.")
+            .build(ctx);
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(16.0)
+                .child(
+                    Wizard::new()
+                        .height(200.0)
+                        .step_with_validation("Name", name_page, move |states, _| {
+                            !states.get::<MainViewState>(id).name.trim().is_empty()
+                        })
+                        .step_with_validation("E-mail", email_page, move |states, _| {
+                            states.get::<MainViewState>(id).email.contains('@')
+                        })
+                        .step("Summary", summary_page)
+                        .on_finish(move |states, _| {
+                            states.get_mut::<MainViewState>(id).finish();
+                        })
+                        .build(ctx),
+                )
+                .child(TextBlock::new().text(("summary", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - wizard example")
+                .position((100.0, 100.0))
+                .size(460.0, 320.0)
+                .child(MainView::new().margin(24.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
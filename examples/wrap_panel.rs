@@ -0,0 +1,66 @@
+use orbtk::prelude::*;
+
+static TAGS: &[&str] = &[
+    "rust",
+    "gui",
+    "toolkit",
+    "redox",
+    "web",
+    "desktop",
+    "layout",
+    "theme",
+    "widgets",
+    "entity",
+    "component",
+    "system",
+    "cross-platform",
+    "open source",
+    "mit",
+];
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let mut tags = WrapPanel::new().spacing(4.0);
+
+        for tag in TAGS {
+            tags = tags.child(
+                Button::new()
+                    .style("button_single_content")
+                    .text(*tag)
+                    .build(ctx),
+            );
+        }
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    TextBlock::new()
+                        .text("Tags")
+                        .style("text_block_header")
+                        .build(ctx),
+                )
+                .child(tags.build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - wrap panel example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .resizeable(true)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
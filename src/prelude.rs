@@ -13,7 +13,8 @@ pub use crate::{
     proc_macros::*,
     render::prelude::*,
     theme::{
-        colors, dark_theme, default_theme, fonts, light_theme, vector_graphics::material_icons_font,
+        colors, dark_theme, default_theme, fonts, light_theme,
+        vector_graphics::{icon_fonts::*, material_icons_font},
     },
     theming::prelude::*,
     tree::prelude::*,